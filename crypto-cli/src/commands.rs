@@ -0,0 +1,457 @@
+//! 서브커맨드 구현
+//!
+//! 각 함수는 결과 구조체를 반환하고, 출력(텍스트/JSON)은 `main.rs`가 담당
+//! 결과 구조체는 `Serialize`(--json)와 `Display`(텍스트)를 모두 구현
+
+use std::fmt;
+use std::ops::Range;
+
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+use crypto_lib::bip32::{master_key_from_seed, ExtendedPrivateKey, ExtendedPublicKey, HARDENED_OFFSET};
+use crypto_lib::bip39::{self, mnemonic_to_entropy, mnemonic_to_seed, Language, MnemonicType};
+use crypto_lib::bitcoin::Network;
+use crypto_lib::detect::{detect_address, ChainGuess};
+use crypto_lib::export::{derive_records, write_json, ExportRecord, ExportSpec};
+use crypto_lib::signer::{signer_for, SigningScheme};
+use crypto_lib::validate::validate_address;
+use crypto_lib::wallet::{Chain, Wallet};
+use crypto_lib::{Error, Result};
+
+// ═══════════════════════════════════════════════════════════════
+// generate
+// ═══════════════════════════════════════════════════════════════
+
+/// 새 니모닉
+#[derive(Debug, Serialize)]
+pub struct Generated {
+    /// 니모닉 (영어)
+    pub mnemonic: String,
+    /// 단어 수
+    pub words: usize,
+}
+
+impl fmt::Display for Generated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.mnemonic)
+    }
+}
+
+/// 새 영어 니모닉 (12 또는 24단어)
+pub fn generate(words: u32) -> Result<Generated> {
+    let mnemonic_type = match words {
+        12 => MnemonicType::Words12,
+        24 => MnemonicType::Words24,
+        _ => return Err(Error::InvalidInput(format!("단어 수는 12 또는 24여야 합니다: {}", words))),
+    };
+    let (mnemonic, _seed) = bip39::generate_mnemonic(mnemonic_type);
+    Ok(Generated { mnemonic, words: mnemonic_type.word_count() })
+}
+
+// ═══════════════════════════════════════════════════════════════
+// derive
+// ═══════════════════════════════════════════════════════════════
+
+/// 도출한 계정 목록 (`export` manifest와 같은 형식)
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct Derived(pub Vec<ExportRecord>);
+
+impl Derived {
+    /// `export::write_json`과 같은 JSON 배열
+    pub fn to_json(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        write_json(&self.0, &mut buffer)?;
+        String::from_utf8(buffer).map_err(|e| Error::InvalidInput(e.to_string()))
+    }
+}
+
+impl fmt::Display for Derived {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, record) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:<10} {:<24} {}", record.chain, record.path, record.address)?;
+            if let Some(private_key) = &record.private_key {
+                write!(f, " {}", Zeroizing::new(hex::encode(private_key.as_slice())).as_str())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 체인 × account 범위 × index 범위 도출
+pub fn derive(mnemonic: &str, passphrase: &str, chains: &[Chain], accounts: Range<u32>, indices: Range<u32>, private_keys: bool) -> Result<Derived> {
+    let mut spec = ExportSpec::new(chains).accounts(accounts).indices(indices);
+    if private_keys {
+        spec = spec.with_private_keys();
+    }
+    Ok(Derived(derive_records(mnemonic, passphrase, &spec)?))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// validate
+// ═══════════════════════════════════════════════════════════════
+
+/// 주소 검증 결과
+#[derive(Debug, Serialize)]
+pub struct Validation {
+    /// 입력 주소
+    pub address: String,
+    /// `--chain`으로 지정한 체인
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<&'static str>,
+    /// 유효 여부
+    pub valid: bool,
+    /// 실패 이유
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 체인 후보 (`--chain` 없이 판별한 경우)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<Candidate>,
+}
+
+/// 주소가 속할 수 있는 체인
+#[derive(Debug, Serialize)]
+pub struct Candidate {
+    /// 체인 id
+    pub chain: &'static str,
+    /// 표시 이름
+    pub name: &'static str,
+    /// 주소 형식
+    pub format: String,
+    /// 체크섬으로 확인했는지
+    pub checksum_verified: bool,
+}
+
+impl From<ChainGuess> for Candidate {
+    fn from(guess: ChainGuess) -> Self {
+        Candidate { chain: guess.chain, name: guess.name, format: format!("{:?}", guess.format), checksum_verified: guess.checksum_verified }
+    }
+}
+
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.error, self.chain) {
+            (Some(error), _) => write!(f, "유효하지 않음: {}", error)?,
+            (None, Some(chain)) => write!(f, "유효함 ({})", chain)?,
+            (None, None) => write!(f, "유효함")?,
+        }
+        for candidate in &self.candidates {
+            let verified = if candidate.checksum_verified { "체크섬 확인" } else { "형식만 일치" };
+            write!(f, "\n  {:<12} {} ({}, {})", candidate.chain, candidate.name, candidate.format, verified)?;
+        }
+        Ok(())
+    }
+}
+
+/// 주소 검증 (`chain`이 없으면 체인 자동 판별)
+pub fn validate(address: &str, chain: Option<Chain>) -> Validation {
+    let address = address.trim().to_string();
+    match chain {
+        Some(chain) => {
+            let error = validate_address(chain, &address).err().map(|e| e.to_string());
+            Validation { valid: error.is_none(), address, chain: Some(chain.id()), error, candidates: Vec::new() }
+        }
+        None => {
+            let candidates: Vec<Candidate> = detect_address(&address).into_iter().map(Candidate::from).collect();
+            let error = candidates.is_empty().then(|| "알 수 없는 주소 형식입니다".to_string());
+            Validation { valid: error.is_none(), address, chain: None, error, candidates }
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// inspect
+// ═══════════════════════════════════════════════════════════════
+
+/// 입력 분석 결과 (확장 키, 니모닉, 주소)
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Inspection {
+    /// xpub/tpub, xprv/tprv (개인키는 출력하지 않음)
+    ExtendedKey {
+        /// "xpub" 또는 "xprv"
+        kind: &'static str,
+        /// "mainnet" 또는 "testnet"
+        network: &'static str,
+        /// 깊이
+        depth: u8,
+        /// 부모 지문 hex
+        parent_fingerprint: String,
+        /// 자식 번호 (강화면 ' 표기)
+        child_number: String,
+        /// 이 키의 지문 hex
+        fingerprint: String,
+        /// 압축 공개키 hex
+        public_key: String,
+    },
+    /// BIP-39 니모닉 (단어는 출력하지 않음)
+    Mnemonic {
+        /// 언어
+        language: String,
+        /// 단어 수
+        words: usize,
+        /// 엔트로피 비트 수
+        entropy_bits: usize,
+        /// 마스터 키 지문 hex (패스프레이즈 반영)
+        master_fingerprint: String,
+    },
+    /// 주소
+    Address {
+        /// 체인 후보
+        candidates: Vec<Candidate>,
+    },
+}
+
+impl fmt::Display for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Inspection::ExtendedKey { kind, network, depth, parent_fingerprint, child_number, fingerprint, public_key } => {
+                writeln!(f, "종류:        {} ({})", kind, network)?;
+                writeln!(f, "깊이:        {}", depth)?;
+                writeln!(f, "부모 지문:   {}", parent_fingerprint)?;
+                writeln!(f, "자식 번호:   {}", child_number)?;
+                writeln!(f, "지문:        {}", fingerprint)?;
+                write!(f, "공개키:      {}", public_key)
+            }
+            Inspection::Mnemonic { language, words, entropy_bits, master_fingerprint } => {
+                writeln!(f, "종류:        BIP-39 니모닉 ({})", language)?;
+                writeln!(f, "단어 수:     {} ({}비트)", words, entropy_bits)?;
+                write!(f, "마스터 지문: {}", master_fingerprint)
+            }
+            Inspection::Address { candidates } => {
+                write!(f, "종류:        주소")?;
+                for candidate in candidates {
+                    write!(f, "\n  {:<12} {} ({})", candidate.chain, candidate.name, candidate.format)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 입력 종류를 판별해서 분석 (공백이 있으면 니모닉, xpub/xprv 접두사면 확장 키, 나머지는 주소)
+pub fn inspect(input: &str, passphrase: &str) -> Result<Inspection> {
+    let input = input.trim();
+
+    if input.split_whitespace().nth(1).is_some() {
+        return inspect_mnemonic(input, passphrase);
+    }
+
+    match input.get(..4) {
+        Some("xpub" | "tpub") => {
+            let (key, network) = ExtendedPublicKey::from_base58_with_network(input)?;
+            Ok(extended_key("xpub", network, key.depth, key.parent_fingerprint, key.child_index, key.fingerprint(), &key.public_key))
+        }
+        Some("xprv" | "tprv") => {
+            let (key, network) = ExtendedPrivateKey::from_base58_with_network(input)?;
+            Ok(extended_key("xprv", network, key.depth, key.parent_fingerprint, key.child_index, key.fingerprint(), &key.public_key()))
+        }
+        _ => {
+            let candidates: Vec<Candidate> = detect_address(input).into_iter().map(Candidate::from).collect();
+            if candidates.is_empty() {
+                return Err(Error::InvalidInput(format!("니모닉, 확장 키, 주소 중 어느 것도 아닙니다: {}", input)));
+            }
+            Ok(Inspection::Address { candidates })
+        }
+    }
+}
+
+fn inspect_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Inspection> {
+    let entropy = Zeroizing::new(mnemonic_to_entropy(mnemonic)?);
+    let language = Language::detect(mnemonic)?;
+    let master = master_key_from_seed(mnemonic_to_seed(mnemonic, passphrase).as_slice())?;
+
+    Ok(Inspection::Mnemonic {
+        language: format!("{:?}", language),
+        words: mnemonic.split_whitespace().count(),
+        entropy_bits: entropy.len() * 8,
+        master_fingerprint: hex::encode(master.fingerprint()),
+    })
+}
+
+fn extended_key(kind: &'static str, network: Network, depth: u8, parent_fingerprint: [u8; 4], child_index: u32, fingerprint: [u8; 4], public_key: &[u8; 33]) -> Inspection {
+    let child_number = if child_index >= HARDENED_OFFSET {
+        format!("{}'", child_index - HARDENED_OFFSET)
+    } else {
+        child_index.to_string()
+    };
+
+    Inspection::ExtendedKey {
+        kind,
+        network: network.name(),
+        depth,
+        parent_fingerprint: hex::encode(parent_fingerprint),
+        child_number,
+        fingerprint: hex::encode(fingerprint),
+        public_key: hex::encode(public_key),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// sign
+// ═══════════════════════════════════════════════════════════════
+
+/// 서명 결과
+#[derive(Debug, Serialize)]
+pub struct Signed {
+    /// 체인 id
+    pub chain: &'static str,
+    /// 도출 경로
+    pub path: String,
+    /// 주소
+    pub address: String,
+    /// 공개키 hex (체인 기본 직렬화)
+    pub public_key: String,
+    /// "ed25519" 또는 "secp256k1"
+    pub scheme: &'static str,
+    /// 서명 hex (64바이트)
+    pub signature: String,
+}
+
+impl fmt::Display for Signed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "주소:   {} ({})", self.address, self.path)?;
+        writeln!(f, "공개키: {}", self.public_key)?;
+        write!(f, "서명:   {} ({})", self.signature, self.scheme)
+    }
+}
+
+/// 체인의 account/index 키로 메시지 서명 (체인 기본 해시 적용: `crypto_lib::signer` 참고)
+pub fn sign(mnemonic: &str, passphrase: &str, chain: Chain, account: u32, index: u32, message: &[u8]) -> Result<Signed> {
+    let wallet = Wallet::builder().mnemonic(mnemonic).passphrase(passphrase).chain(chain).account(account).index(index).build()?;
+    let signer = signer_for(chain, *wallet.key.private_key)?;
+    let signature = signer.sign(message)?;
+
+    Ok(Signed {
+        chain: chain.id(),
+        path: wallet.key.path.clone(),
+        address: wallet.key.address.clone(),
+        public_key: hex::encode(signer.public_key_bytes()),
+        scheme: match signer.scheme() {
+            SigningScheme::Ed25519 => "ed25519",
+            SigningScheme::Secp256k1 => "secp256k1",
+        },
+        signature: signature.to_hex(),
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 인자 파싱
+// ═══════════════════════════════════════════════════════════════
+
+/// 범위 인자 파싱: "3" (3만), "0..10" (10 제외), "0..=9" (9 포함)
+pub fn parse_range(value: &str) -> std::result::Result<Range<u32>, String> {
+    let number = |s: &str| s.trim().parse::<u32>().map_err(|_| format!("숫자가 아닙니다: {}", s));
+
+    let range = if let Some((start, end)) = value.split_once("..=") {
+        number(start)?..number(end)?.checked_add(1).ok_or("범위가 너무 큽니다")?
+    } else if let Some((start, end)) = value.split_once("..") {
+        number(start)?..number(end)?
+    } else {
+        let start = number(value)?;
+        start..start.checked_add(1).ok_or("범위가 너무 큽니다")?
+    };
+
+    if range.is_empty() {
+        return Err(format!("빈 범위입니다: {}", value));
+    }
+    Ok(range)
+}
+
+/// 체인 id 파싱 (`Chain`의 `FromStr`, Cosmos 체인은 이름과 HRP도 허용)
+pub fn parse_chain(id: &str) -> std::result::Result<Chain, String> {
+    id.parse::<Chain>().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_generate() {
+        let generated = generate(24).unwrap();
+        assert_eq!(generated.words, 24);
+        assert!(bip39::validate_mnemonic(&generated.mnemonic).is_ok());
+        assert!(generate(15).is_err());
+    }
+
+    #[test]
+    fn test_derive() {
+        let chains = [parse_chain("osmosis").unwrap(), Chain::Evm];
+        let derived = derive(MNEMONIC, "", &chains, 0..1, parse_range("0..3").unwrap(), false).unwrap();
+        println!("{}", derived);
+        assert_eq!(derived.0.len(), 6);
+        assert!(derived.0[0].address.starts_with("osmo1"));
+        assert_eq!(derived.0[3].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+
+        let json = derived.to_json().unwrap();
+        assert!(json.contains("\"chain\": \"osmosis\""));
+        assert!(!json.contains("private_key"));
+    }
+
+    #[test]
+    fn test_derive_xrp() {
+        let derived = derive(MNEMONIC, "", &[parse_chain("xrp").unwrap()], 0..1, 0..1, false).unwrap();
+        assert_eq!(derived.0[0].path, "m/44'/144'/0'/0/0");
+        assert_eq!(derived.0[0].address, "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+    }
+
+    #[test]
+    fn test_validate() {
+        let valid = validate("0x9858EfFD232B4033E47d90003D41EC34EcaEda94", Some(Chain::Evm));
+        assert!(valid.valid);
+
+        let invalid = validate("0x9858efFD232B4033E47d90003D41EC34EcaEda94", Some(Chain::Evm));
+        println!("{}", invalid);
+        assert!(!invalid.valid);
+
+        let detected = validate("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", None);
+        println!("{}", detected);
+        assert!(detected.valid);
+        assert_eq!(detected.candidates[0].chain, "bitcoin");
+        assert!(!validate("hello", None).valid);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mnemonic = inspect(MNEMONIC, "").unwrap();
+        println!("{}", mnemonic);
+        assert!(matches!(mnemonic, Inspection::Mnemonic { words: 12, entropy_bits: 128, ref master_fingerprint, .. } if master_fingerprint == "73c5da0a"));
+
+        // BIP-32 테스트 벡터 1: m/0'
+        let xpub = "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw";
+        let key = inspect(xpub, "").unwrap();
+        println!("{}", key);
+        assert!(matches!(key, Inspection::ExtendedKey { kind: "xpub", depth: 1, ref child_number, ref parent_fingerprint, .. } if child_number == "0'" && parent_fingerprint == "3442193e"));
+
+        assert!(matches!(inspect("0x9858EfFD232B4033E47d90003D41EC34EcaEda94", "").unwrap(), Inspection::Address { .. }));
+        assert!(inspect("hello", "").is_err());
+        assert!(inspect("abandon abandon", "").is_err());
+    }
+
+    #[test]
+    fn test_sign() {
+        let signed = sign(MNEMONIC, "", Chain::Evm, 0, 0, b"hello").unwrap();
+        println!("{}", signed);
+        assert_eq!(signed.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(signed.signature.len(), 128);
+        assert_eq!(signed.scheme, "secp256k1");
+        assert_eq!(sign(MNEMONIC, "", Chain::Solana, 0, 0, b"hello").unwrap().scheme, "ed25519");
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("0..10").unwrap(), 0..10);
+        assert_eq!(parse_range("0..=9").unwrap(), 0..10);
+        assert_eq!(parse_range("5").unwrap(), 5..6);
+        assert!(parse_range("3..3").is_err());
+        assert!(parse_range("a..b").is_err());
+        assert!(parse_chain("dogecoin").is_err());
+    }
+}
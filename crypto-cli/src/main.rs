@@ -0,0 +1,239 @@
+//! # crypto-accounts CLI
+//!
+//! crypto-lib를 운영 스크립트에서 바로 쓰기 위한 명령줄 도구
+//!
+//! ## 명령
+//! ```text
+//! crypto-accounts generate [--words 24]
+//! crypto-accounts derive --chain osmosis,evm --index 0..10 [--account 0] [--private-keys]
+//! crypto-accounts validate <address> [--chain evm]
+//! crypto-accounts inspect [<xpub|address>]   (xprv, 니모닉은 생략하고 환경 변수나 표준 입력으로)
+//! crypto-accounts sign --chain evm [--index 0] --message "hello"   (또는 --hex 68656c6c6f)
+//! ```
+//! 모든 명령은 `--json`으로 JSON 출력
+//!
+//! ## 니모닉 입력
+//! `derive`, `sign`은 니모닉을 환경 변수나 표준 입력으로 받음 (셸 기록, 프로세스 목록에 남지 않도록)
+//! - 환경 변수 `CRYPTO_ACCOUNTS_MNEMONIC`, `CRYPTO_ACCOUNTS_PASSPHRASE`
+//! - 없으면 표준 입력의 첫 줄
+//! - `inspect`도 입력을 생략하거나 `-`로 주면 같은 방식으로 니모닉/xprv를 읽음
+//! - `--mnemonic`, `--passphrase`는 숨긴 인자로만 남기고, 쓰면 stderr에 경고 (`inspect`에 xprv/니모닉을 직접 줘도 경고)
+//!
+//! ## 종료 코드
+//! - 0: 성공
+//! - 1: 실패 (`validate`에서 유효하지 않은 주소 포함), 오류 메시지는 stderr
+
+mod commands;
+
+use std::fmt::Display;
+use std::io::BufRead;
+use std::ops::Range;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+use crypto_lib::wallet::{Chain, DEFAULT_CHAINS};
+use crypto_lib::{Error, Result};
+
+use commands::{parse_chain, parse_range};
+
+#[derive(Debug, Parser)]
+#[command(name = "crypto-accounts", version, about = "니모닉 생성, 멀티체인 도출, 주소 검증, 서명")]
+struct Cli {
+    /// JSON으로 출력
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 새 니모닉 생성
+    Generate {
+        /// 단어 수 (12 또는 24)
+        #[arg(long, default_value_t = 24)]
+        words: u32,
+    },
+    /// 체인별 주소 도출
+    Derive {
+        /// 체인 id (쉼표 구분, 없으면 기본 체인 전체)
+        #[arg(long, value_delimiter = ',', value_parser = parse_chain)]
+        chain: Vec<Chain>,
+        /// account 범위 (예: 0, 0..3, 0..=2)
+        #[arg(long, default_value = "0", value_parser = parse_range)]
+        account: Range<u32>,
+        /// 주소 index 범위 (예: 0, 0..10, 0..=9)
+        #[arg(long, default_value = "0", value_parser = parse_range)]
+        index: Range<u32>,
+        /// 개인키(hex)도 출력
+        #[arg(long)]
+        private_keys: bool,
+        #[command(flatten)]
+        seed: SeedArgs,
+    },
+    /// 주소 검증 (체인을 지정하지 않으면 체인 판별)
+    Validate {
+        /// 주소
+        address: String,
+        /// 체인 id
+        #[arg(long, value_parser = parse_chain)]
+        chain: Option<Chain>,
+    },
+    /// xpub/xprv, 주소, 니모닉 분석 (비밀값은 출력하지 않음)
+    Inspect {
+        /// 분석할 값 (생략하거나 "-"면 `CRYPTO_ACCOUNTS_MNEMONIC` 또는 표준 입력의 첫 줄)
+        input: Option<String>,
+        /// 니모닉 패스프레이즈 (마스터 지문 계산용, `CRYPTO_ACCOUNTS_PASSPHRASE`)
+        #[arg(long, hide = true)]
+        passphrase: Option<String>,
+    },
+    /// 메시지 서명 (체인 기본 해시 적용)
+    Sign {
+        /// 체인 id
+        #[arg(long, value_parser = parse_chain)]
+        chain: Chain,
+        /// BIP-44 account
+        #[arg(long, default_value_t = 0)]
+        account: u32,
+        /// 주소 index
+        #[arg(long, default_value_t = 0)]
+        index: u32,
+        /// 서명할 메시지 (UTF-8)
+        #[arg(long, required_unless_present = "hex", conflicts_with = "hex")]
+        message: Option<String>,
+        /// 서명할 메시지 (hex)
+        #[arg(long)]
+        hex: Option<String>,
+        #[command(flatten)]
+        seed: SeedArgs,
+    },
+}
+
+/// 니모닉/패스프레이즈 (환경 변수 또는 표준 입력)
+#[derive(Debug, Args)]
+struct SeedArgs {
+    /// 니모닉 (`CRYPTO_ACCOUNTS_MNEMONIC`, 없으면 표준 입력의 첫 줄)
+    #[arg(long, hide = true)]
+    mnemonic: Option<String>,
+    /// 패스프레이즈 (`CRYPTO_ACCOUNTS_PASSPHRASE`)
+    #[arg(long, hide = true)]
+    passphrase: Option<String>,
+}
+
+impl SeedArgs {
+    /// (니모닉, 패스프레이즈)
+    fn read(self) -> Result<(Zeroizing<String>, Zeroizing<String>)> {
+        let mnemonic = match self.mnemonic {
+            Some(mnemonic) => {
+                warn_argv("--mnemonic", "CRYPTO_ACCOUNTS_MNEMONIC");
+                Zeroizing::new(mnemonic)
+            }
+            None => read_mnemonic()?,
+        };
+        Ok((mnemonic, read_passphrase(self.passphrase)))
+    }
+}
+
+/// 니모닉(또는 xprv): 환경 변수, 없으면 표준 입력의 첫 줄
+fn read_mnemonic() -> Result<Zeroizing<String>> {
+    if let Ok(mnemonic) = std::env::var("CRYPTO_ACCOUNTS_MNEMONIC") {
+        return Ok(Zeroizing::new(mnemonic));
+    }
+    let mut line = Zeroizing::new(String::new());
+    std::io::stdin().lock().read_line(&mut line).map_err(|e| Error::Io(e.to_string()))?;
+    Ok(Zeroizing::new(line.trim().to_string()))
+}
+
+/// 패스프레이즈: 환경 변수, 없으면 빈 문자열 (`--passphrase`로 주면 경고 후 사용)
+fn read_passphrase(arg: Option<String>) -> Zeroizing<String> {
+    match arg {
+        Some(passphrase) => {
+            warn_argv("--passphrase", "CRYPTO_ACCOUNTS_PASSPHRASE");
+            Zeroizing::new(passphrase)
+        }
+        None => Zeroizing::new(std::env::var("CRYPTO_ACCOUNTS_PASSPHRASE").unwrap_or_default()),
+    }
+}
+
+/// `inspect` 입력: 생략하거나 "-"면 환경 변수/표준 입력, xprv나 니모닉을 인자로 주면 경고
+fn read_inspect_input(arg: Option<String>) -> Result<Zeroizing<String>> {
+    match arg {
+        None => read_mnemonic(),
+        Some(input) if input == "-" => read_mnemonic(),
+        Some(input) => {
+            let input = Zeroizing::new(input);
+            let trimmed = input.trim();
+            if trimmed.contains(char::is_whitespace) || trimmed.starts_with("xprv") || trimmed.starts_with("tprv") {
+                warn_argv("inspect 인자", "CRYPTO_ACCOUNTS_MNEMONIC");
+            }
+            Ok(input)
+        }
+    }
+}
+
+/// 비밀값을 인자로 받았을 때 stderr 경고
+fn warn_argv(argument: &str, env: &str) {
+    eprintln!("경고: {}는 셸 기록과 프로세스 목록에 남습니다. {} 환경 변수나 표준 입력을 사용하세요", argument, env);
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command, cli.json) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("오류: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 명령 실행 (`Ok(false)`: 출력은 했지만 실패로 종료)
+fn run(command: Command, json: bool) -> Result<bool> {
+    match command {
+        Command::Generate { words } => print(&commands::generate(words)?, json)?,
+        Command::Derive { chain, account, index, private_keys, seed } => {
+            let (mnemonic, passphrase) = seed.read()?;
+            let chains = if chain.is_empty() { DEFAULT_CHAINS.to_vec() } else { chain };
+            let derived = commands::derive(&mnemonic, &passphrase, &chains, account, index, private_keys)?;
+            if json {
+                println!("{}", derived.to_json()?);
+            } else {
+                println!("{}", derived);
+            }
+        }
+        Command::Validate { address, chain } => {
+            let validation = commands::validate(&address, chain);
+            print(&validation, json)?;
+            return Ok(validation.valid);
+        }
+        Command::Inspect { input, passphrase } => {
+            print(&commands::inspect(&read_inspect_input(input)?, &read_passphrase(passphrase))?, json)?
+        }
+        Command::Sign { chain, account, index, message, hex, seed } => {
+            let message = match (message, hex) {
+                (Some(message), _) => message.into_bytes(),
+                (None, Some(hex)) => hex::decode(hex.trim_start_matches("0x")).map_err(|e| Error::Hex(e.to_string()))?,
+                (None, None) => return Err(Error::InvalidInput("--message 또는 --hex가 필요합니다".to_string())),
+            };
+            let (mnemonic, passphrase) = seed.read()?;
+            print(&commands::sign(&mnemonic, &passphrase, chain, account, index, &message)?, json)?;
+        }
+    }
+    Ok(true)
+}
+
+/// 텍스트(`Display`) 또는 JSON 출력
+fn print<T: Serialize + Display>(value: &T, json: bool) -> Result<()> {
+    if json {
+        let output = serde_json::to_string_pretty(value).map_err(|e| Error::InvalidInput(e.to_string()))?;
+        println!("{}", output);
+    } else {
+        println!("{}", value);
+    }
+    Ok(())
+}
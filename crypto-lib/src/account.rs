@@ -0,0 +1,314 @@
+//! Common Account
+//!
+//! 체인마다 다른 계정 타입을 한 목록(`Vec<Box<dyn Account>>`)에 담아 조회하기 위한 공통 트레이트
+//!
+//! ## 체인별 값
+//!
+//! | 계정 | `chain_id()` | `address()` | 공개키 |
+//! |------|-------------|-------------|--------|
+//! | Bitcoin | `bitcoin` | SegWit 메인넷 (bc1q...) | 33바이트 (압축) |
+//! | EVM | `evm` | EIP-55 체크섬 (0x...) | 65바이트 (비압축) |
+//! | Solana | `solana` | Base58 | 32바이트 |
+//! | Sui | `sui` | 0x + hex | 32바이트 |
+//! | Cosmos | `cosmoshub` | Bech32 (cosmos1...) | 33바이트 (압축) |
+//! | Substrate | `substrate` | SS58 (접두사 42) | 32바이트 |
+//! | XRP | `xrp` | 클래식 주소 (r...) | 33바이트 (압축) |
+//! | `ChainAccount` | 도출한 체인 id | 도출 시 계산한 주소 | 체인 고유 형식 |
+//!
+//! `chain_id()`는 `chain` 레지스트리 id와 같으므로 `chain::get(account.chain_id())`로 체인 정보 조회 가능
+//!
+//! `caip10()`은 레지스트리 체인의 CAIP-2 식별자 + `address()`
+//! (예: `eip155:1:0x9858...`, `cosmos:cosmoshub-4:cosmos1...`, CAIP-2가 없는 `substrate`는 에러)
+//!
+//! `Signer`에도 `public_key_bytes()`가 있으므로 두 트레이트를 함께 가져오면
+//! `Account::public_key_bytes(&account)`처럼 트레이트를 지정해 호출
+//!
+//! ## Debug 출력
+//! 계정 타입의 `Debug`는 주소와 공개키(hex)만 보여주고 개인키는 `[REDACTED; 32]`로 가림
+//! 로컬 디버깅에서 개인키까지 봐야 하면 `account.debug_with_secrets()`를 명시적으로 출력
+//!
+//! ```text
+//! EvmAccount { address: "0x9858...", public_key: "04...", private_key: [REDACTED; 32] }
+//! ```
+
+use std::any;
+use std::fmt;
+
+use zeroize::Zeroizing;
+
+use crate::bitcoin::BitcoinAccount;
+use crate::caip::{AccountId, ChainId};
+use crate::chain::{self, ChainAccount};
+use crate::cosmos::CosmosAccount;
+use crate::evm::EvmAccount;
+use crate::solana::SolanaAccount;
+use crate::substrate::{SubstrateAccount, SS58_GENERIC};
+use crate::sui::SuiAccount;
+use crate::xrp::XrpAccount;
+use crate::error::{Error, Result};
+
+/// 체인 공통 계정 트레이트
+pub trait Account: fmt::Debug + Send + Sync {
+    /// 체인 id (`chain` 레지스트리 키)
+    fn chain_id(&self) -> &str;
+
+    /// 기본 형식 주소
+    fn address(&self) -> String;
+
+    /// 공개키 바이트 (체인의 기본 직렬화)
+    fn public_key_bytes(&self) -> &[u8];
+
+    /// 개인키 바이트 (32바이트, 복사하지 않고 빌려줌)
+    fn private_key_bytes(&self) -> &[u8];
+
+    /// CAIP-10 계정 식별자 (체인 레지스트리의 CAIP-2 + 기본 주소)
+    fn caip10(&self) -> Result<AccountId> {
+        let chain = chain::get(self.chain_id())
+            .ok_or_else(|| Error::Chain(format!("등록되지 않은 체인입니다: {}", self.chain_id())))?;
+        let caip2 = chain
+            .caip2()
+            .ok_or_else(|| Error::Chain(format!("CAIP-2 식별자가 없는 체인입니다: {}", self.chain_id())))?;
+        ChainId::parse(caip2)?.account(self.address())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Debug (개인키 가림)
+// ═══════════════════════════════════════════════════════════════
+
+/// 개인키까지 출력하는 `Debug` 래퍼 (`debug_with_secrets()`로 생성)
+#[derive(Clone, Copy)]
+pub struct DebugSecrets<'a, A: ?Sized>(&'a A);
+
+/// 개인키 포함 `Debug` 출력을 명시적으로 요청하는 트레이트 (모든 `Account`에 구현)
+pub trait DebugWithSecrets: Account {
+    /// 개인키(hex)를 포함해 출력하는 래퍼 반환 (로컬 디버깅 전용)
+    fn debug_with_secrets(&self) -> DebugSecrets<'_, Self> {
+        DebugSecrets(self)
+    }
+}
+
+impl<A: Account + ?Sized> DebugWithSecrets for A {}
+
+impl<A: Account + ?Sized> fmt::Debug for DebugSecrets<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // "crypto_lib::evm::EvmAccount" → "EvmAccount"
+        let name = any::type_name::<A>().rsplit("::").next().unwrap_or("Account");
+        let private_key = Zeroizing::new(hex::encode(self.0.private_key_bytes()));
+        debug_account(f, name, self.0, &[], Some(&private_key))
+    }
+}
+
+/// 계정 공통 `Debug` 형식 (`fields`: 앞에 붙일 필드, `private_key`가 `None`이면 가림)
+fn debug_account<A: Account + ?Sized>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    account: &A,
+    fields: &[(&str, &str)],
+    private_key: Option<&str>,
+) -> fmt::Result {
+    let mut debug = f.debug_struct(name);
+    for (field, value) in fields {
+        debug.field(field, value);
+    }
+    debug
+        .field("address", &account.address())
+        .field("public_key", &hex::encode(account.public_key_bytes()));
+    match private_key {
+        Some(private_key) => debug.field("private_key", &private_key),
+        None => debug.field("private_key", &format_args!("[REDACTED; {}]", account.private_key_bytes().len())),
+    };
+    debug.finish()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 계정 타입 구현
+// ═══════════════════════════════════════════════════════════════
+
+/// 계정 타입 공통 구현 (private_key, public_key 필드가 같은 구조)
+macro_rules! impl_account {
+    ($account:ty, $chain_id:literal, |$self_:ident| $address:expr) => {
+        impl fmt::Debug for $account {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                debug_account(f, stringify!($account), self, &[], None)
+            }
+        }
+
+        impl Account for $account {
+            fn chain_id(&self) -> &str {
+                $chain_id
+            }
+
+            fn address(&self) -> String {
+                let $self_ = self;
+                $address
+            }
+
+            fn public_key_bytes(&self) -> &[u8] {
+                &self.public_key
+            }
+
+            fn private_key_bytes(&self) -> &[u8] {
+                self.private_key.as_slice()
+            }
+        }
+    };
+}
+
+impl_account!(BitcoinAccount, "bitcoin", |account| account.address());
+impl_account!(EvmAccount, "evm", |account| account.address_checksummed());
+impl_account!(SolanaAccount, "solana", |account| account.address());
+impl_account!(SuiAccount, "sui", |account| account.address());
+impl_account!(CosmosAccount, "cosmoshub", |account| account.address());
+impl_account!(SubstrateAccount, "substrate", |account| account.address(SS58_GENERIC));
+impl_account!(XrpAccount, "xrp", |account| account.address());
+
+impl fmt::Debug for ChainAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_account(f, "ChainAccount", self, &[("chain_id", &self.chain_id), ("path", &self.path)], None)
+    }
+}
+
+impl Account for ChainAccount {
+    fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    fn public_key_bytes(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    fn private_key_bytes(&self) -> &[u8] {
+        self.private_key.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::mnemonic_to_seed;
+    use crate::chain::{self, SeedSource};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_heterogeneous_accounts() {
+        let accounts: Vec<Box<dyn Account>> = vec![
+            Box::new(BitcoinAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+            Box::new(EvmAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+            Box::new(SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+            Box::new(SuiAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+            Box::new(CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+            Box::new(SubstrateAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+            Box::new(XrpAccount::from_mnemonic(MNEMONIC, "").unwrap()),
+        ];
+
+        for account in &accounts {
+            println!("{:<10} {:<66} {}", account.chain_id(), account.address(), hex::encode(account.public_key_bytes()));
+            assert_eq!(account.private_key_bytes().len(), 32);
+            assert!(chain::get(account.chain_id()).is_some());
+        }
+
+        assert_eq!(accounts[0].address(), "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(accounts[1].address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(accounts[1].public_key_bytes().len(), 65);
+        assert_eq!(accounts[4].address(), "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4");
+        assert_eq!(accounts[6].address(), "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+    }
+
+    #[test]
+    fn test_caip10() {
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(evm.caip10().unwrap().to_string(), "eip155:1:0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+
+        let bitcoin = BitcoinAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(
+            bitcoin.caip10().unwrap().to_string(),
+            "bip122:000000000019d6689c085ae165831e93:bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+
+        let solana = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(solana.caip10().unwrap().chain_id().to_string(), "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp");
+        assert_eq!(solana.caip10().unwrap().address(), Account::address(&solana));
+
+        let sui = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert!(sui.caip10().unwrap().to_string().starts_with("sui:mainnet:0x"));
+
+        let xrp = XrpAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(xrp.caip10().unwrap().to_string(), "xrpl:0:rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+
+        // 레지스트리로 도출한 Cosmos 체인 계정
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let osmosis = chain::get("osmosis").unwrap();
+        let account = osmosis.derive_account(&SeedSource::new(MNEMONIC, "", seed.as_slice()), &osmosis.default_path()).unwrap();
+        assert!(account.caip10().unwrap().to_string().starts_with("cosmos:osmosis-1:osmo1"));
+
+        // 범용 SS58 접두사는 특정 체인이 아님
+        let substrate = SubstrateAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert!(matches!(substrate.caip10(), Err(Error::Chain(_))));
+    }
+
+    #[test]
+    fn test_chain_account_matches_typed_account() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let source = SeedSource::new(MNEMONIC, "", seed.as_slice());
+        let solana = chain::get("solana").unwrap();
+        let derived: Box<dyn Account> = Box::new(solana.derive_account(&source, &solana.default_path()).unwrap());
+        let typed = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        assert_eq!(derived.chain_id(), typed.chain_id());
+        assert_eq!(derived.address(), Account::address(&typed));
+        assert_eq!(derived.public_key_bytes(), Account::public_key_bytes(&typed));
+        assert_eq!(derived.private_key_bytes(), typed.private_key_bytes());
+    }
+
+    #[test]
+    fn test_debug_does_not_leak_private_key() {
+        let account: Box<dyn Account> = Box::new(EvmAccount::from_private_key([0x42; 32]).unwrap());
+        let debug = format!("{:?}", account);
+        println!("{}", debug);
+        assert!(!debug.contains(&hex::encode(account.private_key_bytes())));
+        assert!(debug.starts_with("EvmAccount { address: \"0x"));
+        assert!(debug.contains(&hex::encode(account.public_key_bytes())));
+        assert!(debug.ends_with("private_key: [REDACTED; 32] }"));
+    }
+
+    #[test]
+    fn test_xrp_debug_is_redacted() {
+        let xrp = XrpAccount::from_private_key([0x42; 32]).unwrap();
+        let debug = format!("{:?}", xrp);
+        assert!(debug.starts_with("XrpAccount { address: \"r"), "{}", debug);
+        assert!(!debug.contains(&xrp.private_key_hex()));
+        assert!(debug.ends_with("private_key: [REDACTED; 32] }"));
+    }
+
+    #[test]
+    fn test_debug_with_secrets() {
+        let account = SolanaAccount::from_private_key([0x42; 32]).unwrap();
+        let debug = format!("{:?}", account.debug_with_secrets());
+        println!("{}", debug);
+        assert!(debug.starts_with("SolanaAccount {"));
+        assert!(debug.contains(&format!("private_key: \"{}\"", "42".repeat(32))));
+
+        // dyn Account에서도 사용 가능
+        let boxed: Box<dyn Account> = Box::new(account);
+        assert!(format!("{:?}", boxed.debug_with_secrets()).contains(&"42".repeat(32)));
+    }
+
+    #[test]
+    fn test_chain_account_debug() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let source = SeedSource::new(MNEMONIC, "", seed.as_slice());
+        let osmosis = chain::get("osmosis").unwrap();
+        let account = osmosis.derive_account(&source, &osmosis.default_path()).unwrap();
+
+        let debug = format!("{:?}", account);
+        println!("{}", debug);
+        assert!(debug.starts_with("ChainAccount { chain_id: \"osmosis\", path: \"m/44'/118'/0'/0/0\", address: \"osmo1"));
+        assert!(!debug.contains(&hex::encode(account.private_key.as_slice())));
+    }
+}
@@ -0,0 +1,193 @@
+//! Lazy Account Iterator
+//!
+//! 같은 계정 레벨 부모 키에서 주소 index만 바꿔가며 계정을 하나씩 도출하는 반복자
+//!
+//! ## 왜 필요한가?
+//! `from_seed_with_path`를 index마다 호출하면 매번 마스터 키부터 경로 전체를 다시 도출함
+//! 반복자는 부모 키(예: m/44'/118'/0'/0)를 한 번만 도출하고 마지막 단계만 반복
+//! → 입금 주소 100만 개도 마스터 키 도출 1번, 메모리는 계정 1개분
+//!
+//! ## 도출 방식
+//!
+//! | 곡선 | 부모 | index마다 |
+//! |------|------|-----------|
+//! | secp256k1 (BIP-32) | m/purpose'/coin'/account'/0 | 일반 자식 i (부모 공개키 재사용) |
+//! | Ed25519 (SLIP-10) | 고정 접두 경로 | 강화 자식 i' + 고정 접미 경로 |
+//!
+//! 항목은 `Result<(index, 계정)>`, 에러가 나오면 반복 종료
+//!
+//! ```
+//! use crypto_lib::bip39::mnemonic_to_seed;
+//! use crypto_lib::cosmos::{CosmosAccount, CosmosChain};
+//!
+//! let seed = mnemonic_to_seed("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", "");
+//! for item in CosmosAccount::iter_from_seed(seed.as_slice(), CosmosChain::Osmosis).unwrap().take(3) {
+//!     let (index, account) = item.unwrap();
+//!     println!("{} {}", index, account.address_for_chain(CosmosChain::Osmosis));
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::bip32::{master_key_from_seed, ChildKeys, ChildNumber, DerivationPath, IntoDerivationPath, HARDENED_OFFSET};
+use crate::slip10::{Curve, Slip10Key};
+use crate::error::{Error, Result};
+
+/// 개인키 → 계정 변환 함수
+pub type AccountFromKey<A> = fn([u8; 32]) -> Result<A>;
+
+/// 계정을 하나씩 도출하는 반복자
+pub struct AccountIter<A> {
+    parent: Parent,
+    make: AccountFromKey<A>,
+}
+
+/// 반복하는 부모 키
+enum Parent {
+    /// BIP-32: 부모의 일반 자식 (부모 공개키 문맥은 `ChildKeys`가 보관)
+    Bip32(ChildKeys),
+    /// SLIP-10 Ed25519: 부모의 강화 자식 + 접미 경로
+    Ed25519 {
+        parent: Slip10Key,
+        suffix: DerivationPath,
+        next: Option<u32>,
+    },
+}
+
+impl<A> AccountIter<A> {
+    /// BIP-32 반복자: `parent_path`의 일반 자식 `start`, `start + 1`, ...
+    ///
+    /// 예: `parent_path = "m/44'/60'/0'/0"` → m/44'/60'/0'/0/{start..}
+    pub fn bip32(seed: &[u8], parent_path: impl IntoDerivationPath, start: u32, make: AccountFromKey<A>) -> Result<Self> {
+        let children = master_key_from_seed(seed)?.children(parent_path, start)?;
+        Ok(AccountIter { parent: Parent::Bip32(children), make })
+    }
+
+    /// SLIP-10 Ed25519 반복자: `prefix`/{start..}'/`suffix`
+    ///
+    /// 예: Solana `prefix = "m/44'/501'"`, `suffix = "m/0'"` → m/44'/501'/{i}'/0'
+    pub fn ed25519(
+        seed: &[u8],
+        prefix: impl IntoDerivationPath,
+        suffix: impl IntoDerivationPath,
+        start: u32,
+        make: AccountFromKey<A>,
+    ) -> Result<Self> {
+        if start >= HARDENED_OFFSET {
+            return Err(Error::path(start.to_string(), format!("index는 2^31 미만이어야 합니다: {}", start)));
+        }
+
+        let parent = Slip10Key::master(seed, Curve::Ed25519)?.derive_path(prefix)?;
+        let suffix = suffix.into_derivation_path()?;
+        Ok(AccountIter { parent: Parent::Ed25519 { parent, suffix, next: Some(start) }, make })
+    }
+}
+
+impl<A> Iterator for AccountIter<A> {
+    type Item = Result<(u32, A)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, private_key) = match &mut self.parent {
+            Parent::Bip32(children) => match children.next()? {
+                Ok(child) => (child.child_index, child.private_key),
+                Err(e) => return Some(Err(e)),
+            },
+            Parent::Ed25519 { parent, suffix, next } => {
+                let index = next.take()?;
+                let child = match parent.derive_child(ChildNumber::Hardened(index)).and_then(|child| child.derive_path(&*suffix)) {
+                    Ok(child) => child,
+                    Err(e) => return Some(Err(e)),
+                };
+                *next = index.checked_add(1).filter(|&next| next < HARDENED_OFFSET);
+                (index, child.private_key)
+            }
+        };
+
+        Some((self.make)(*private_key).map(|account| (index, account)))
+    }
+}
+
+impl<A> fmt::Debug for AccountIter<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.parent {
+            Parent::Bip32(children) => f.debug_tuple("AccountIter").field(children).finish(),
+            Parent::Ed25519 { suffix, next, .. } => f
+                .debug_struct("AccountIter")
+                .field("suffix", &suffix.to_string())
+                .field("next", next)
+                .finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::mnemonic_to_seed;
+    use crate::bitcoin::BitcoinAccount;
+    use crate::cosmos::{CosmosAccount, CosmosChain};
+    use crate::evm::EvmAccount;
+    use crate::solana::SolanaAccount;
+    use crate::sui::SuiAccount;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_bip32_iter_matches_paths() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+
+        let accounts: Vec<_> = CosmosAccount::iter_from_seed(seed.as_slice(), CosmosChain::Osmosis)
+            .unwrap()
+            .take(3)
+            .collect::<Result<_>>()
+            .unwrap();
+        for (index, account) in &accounts {
+            let path = format!("m/44'/118'/0'/0/{}", index);
+            let expected = CosmosAccount::from_seed_with_path(seed.as_slice(), path.as_str()).unwrap();
+            println!("{} {}", path, account.address_for_chain(CosmosChain::Osmosis));
+            assert_eq!(account.address(), expected.address());
+        }
+        assert_eq!(accounts.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 1, 2]);
+
+        let (index, evm) = EvmAccount::iter_from_seed(seed.as_slice()).unwrap().nth(4).unwrap().unwrap();
+        assert_eq!(index, 4);
+        assert_eq!(evm.address, EvmAccount::from_seed_with_path(seed.as_slice(), "m/44'/60'/0'/0/4").unwrap().address);
+
+        let (_, bitcoin) = BitcoinAccount::iter_from_seed(seed.as_slice()).unwrap().next().unwrap().unwrap();
+        assert_eq!(bitcoin.address(), "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+    }
+
+    #[test]
+    fn test_ed25519_iter_matches_paths() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+
+        for item in SolanaAccount::iter_from_seed(seed.as_slice()).unwrap().take(3) {
+            let (index, account) = item.unwrap();
+            let path = format!("m/44'/501'/{}'/0'", index);
+            let expected = SolanaAccount::from_seed_with_path(seed.as_slice(), path.as_str()).unwrap();
+            println!("{} {}", path, account.address());
+            assert_eq!(account.address(), expected.address());
+        }
+
+        for item in SuiAccount::iter_from_seed(seed.as_slice()).unwrap().take(3) {
+            let (index, account) = item.unwrap();
+            let path = format!("m/44'/784'/0'/0'/{}'", index);
+            let expected = SuiAccount::from_seed_with_path(seed.as_slice(), path.as_str()).unwrap();
+            assert_eq!(account.address(), expected.address());
+        }
+    }
+
+    #[test]
+    fn test_iter_start_and_end() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+
+        let mut iter = AccountIter::bip32(seed.as_slice(), "m/44'/60'/0'/0", 10, |key| Ok(EvmAccount::from_private_key(key))).unwrap();
+        let (index, _) = iter.next().unwrap().unwrap();
+        assert_eq!(index, 10);
+
+        let last = AccountIter::ed25519(seed.as_slice(), "m/44'/501'", "m/0'", HARDENED_OFFSET - 1, |key| Ok(SolanaAccount::from_private_key(key))).unwrap();
+        assert_eq!(last.count(), 1);
+
+        assert!(AccountIter::ed25519(seed.as_slice(), "m/44'/501'", "m", HARDENED_OFFSET, SolanaAccount::from_private_key).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! age 암호화 내보내기 (feature = "age")
+//!
+//! 니모닉이나 키 묶음을 표준 [age](https://age-encryption.org) 파일로 암호화
+//! `age -d` 등 기존 도구로 그대로 복호화 가능
+//!
+//! ## 수신자 종류
+//! - X25519: `age1...` 공개키 (복호화는 `AGE-SECRET-KEY-1...` 신원)
+//! - 비밀번호: scrypt (작업량은 age가 기기 성능에 맞춰 자동 선택)
+//!
+//! ## 출력 형식
+//! - 바이너리 (`.age`)
+//! - ASCII armor (`-----BEGIN AGE ENCRYPTED FILE-----`, 종이 백업/텍스트 파이프라인용)
+//!
+//! 복호화는 두 형식을 자동으로 구분
+//!
+//! ## 사용 예
+//! ```text
+//! let (identity, recipient) = generate_identity();
+//! let armored = encrypt_to_recipients(mnemonic.as_bytes(), &[&recipient], true)?;
+//! // $ age -d -i key.txt backup.age
+//! ```
+
+use std::io::{BufReader, Read, Write};
+use std::iter;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+use age::armor::{ArmoredReader, ArmoredWriter, Format};
+use age::secrecy::{ExposeSecret, SecretString};
+use age::{Decryptor, Encryptor};
+
+type AgeDecryptor<'a> = Decryptor<ArmoredReader<BufReader<&'a [u8]>>>;
+
+/// 새 X25519 신원 생성 → (비밀 신원 `AGE-SECRET-KEY-1...`, 공개 수신자 `age1...`)
+pub fn generate_identity() -> (String, String) {
+    let identity = age::x25519::Identity::generate();
+    let recipient = identity.to_public().to_string();
+    (identity.to_string().expose_secret().to_string(), recipient)
+}
+
+/// X25519 수신자들에게 암호화 (수신자 중 누구나 복호화 가능)
+pub fn encrypt_to_recipients(plaintext: &[u8], recipients: &[&str], armor: bool) -> Result<Vec<u8>> {
+    let recipients = recipients
+        .iter()
+        .map(|r| age::x25519::Recipient::from_str(r.trim()).map_err(|e| Error::Keystore(format!("유효하지 않은 age 수신자 {}: {}", r, e))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let encryptor = Encryptor::with_recipients(recipients.iter().map(|r| r as &dyn age::Recipient))
+        .map_err(|e| Error::Keystore(format!("age 암호화 실패: {}", e)))?;
+    write_encrypted(encryptor, plaintext, armor)
+}
+
+/// 비밀번호로 암호화 (scrypt)
+pub fn encrypt_with_passphrase(plaintext: &[u8], passphrase: &str, armor: bool) -> Result<Vec<u8>> {
+    encrypt_with_passphrase_work_factor(plaintext, passphrase, armor, None)
+}
+
+/// X25519 신원으로 복호화 (신원 중 하나라도 맞으면 성공)
+///
+/// `identities`: `AGE-SECRET-KEY-1...` 문자열 (신원 파일의 주석 줄은 미리 제거)
+pub fn decrypt_with_identities(ciphertext: &[u8], identities: &[&str]) -> Result<Vec<u8>> {
+    let identities = identities
+        .iter()
+        .map(|i| age::x25519::Identity::from_str(i.trim()).map_err(|e| Error::Keystore(format!("유효하지 않은 age 신원: {}", e))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let decryptor = open(ciphertext)?;
+    if decryptor.is_scrypt() {
+        return Err(Error::Decryption("비밀번호로 암호화된 파일입니다".to_string()));
+    }
+    read_decrypted(decryptor, identities.iter().map(|i| i as &dyn age::Identity))
+}
+
+/// 비밀번호로 복호화
+pub fn decrypt_with_passphrase(ciphertext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let decryptor = open(ciphertext)?;
+    if !decryptor.is_scrypt() {
+        return Err(Error::Decryption("비밀번호로 암호화된 파일이 아닙니다".to_string()));
+    }
+
+    let identity = age::scrypt::Identity::new(SecretString::from(passphrase.to_string()));
+    read_decrypted(decryptor, iter::once(&identity as &dyn age::Identity))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// `log_n`: scrypt 작업량 지정 (None = age 기본값, 약 1초)
+fn encrypt_with_passphrase_work_factor(
+    plaintext: &[u8],
+    passphrase: &str,
+    armor: bool,
+    log_n: Option<u8>,
+) -> Result<Vec<u8>> {
+    let mut recipient = age::scrypt::Recipient::new(SecretString::from(passphrase.to_string()));
+    if let Some(log_n) = log_n {
+        recipient.set_work_factor(log_n);
+    }
+
+    let encryptor = Encryptor::with_recipients(iter::once(&recipient as &dyn age::Recipient))
+        .map_err(|e| Error::Keystore(format!("age 암호화 실패: {}", e)))?;
+    write_encrypted(encryptor, plaintext, armor)
+}
+
+fn write_encrypted(encryptor: Encryptor, plaintext: &[u8], armor: bool) -> Result<Vec<u8>> {
+    let io_err = |e: std::io::Error| Error::Keystore(format!("age 암호화 실패: {}", e));
+    let format = if armor { Format::AsciiArmor } else { Format::Binary };
+
+    let mut out = Vec::with_capacity(plaintext.len() + 256);
+    let mut writer = encryptor.wrap_output(ArmoredWriter::wrap_output(&mut out, format).map_err(io_err)?).map_err(io_err)?;
+    writer.write_all(plaintext).map_err(io_err)?;
+    writer.finish().and_then(|armored| armored.finish()).map_err(io_err)?;
+    Ok(out)
+}
+
+fn open(ciphertext: &[u8]) -> Result<AgeDecryptor<'_>> {
+    Decryptor::new_buffered(ArmoredReader::new(ciphertext)).map_err(|e| Error::Keystore(format!("age 파일이 아닙니다: {}", e)))
+}
+
+fn read_decrypted<'a>(
+    decryptor: AgeDecryptor<'_>,
+    identities: impl Iterator<Item = &'a dyn age::Identity>,
+) -> Result<Vec<u8>> {
+    let mut reader = decryptor.decrypt(identities).map_err(|e| Error::Decryption(format!("age 복호화 실패: {}", e)))?;
+    let mut plaintext = Vec::new();
+    reader.read_to_end(&mut plaintext).map_err(|e| Error::Decryption(format!("age 복호화 실패: {}", e)))?;
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_x25519_roundtrip() {
+        let (identity, recipient) = generate_identity();
+        let (other_identity, other_recipient) = generate_identity();
+        println!("age 수신자: {}", recipient);
+        assert!(recipient.starts_with("age1"));
+        assert!(identity.starts_with("AGE-SECRET-KEY-1"));
+
+        for armor in [false, true] {
+            let encrypted = encrypt_to_recipients(MNEMONIC.as_bytes(), &[&recipient, &other_recipient], armor).unwrap();
+            if armor {
+                let text = String::from_utf8(encrypted.clone()).unwrap();
+                println!("{}", text);
+                assert!(text.starts_with("-----BEGIN AGE ENCRYPTED FILE-----"));
+            } else {
+                assert!(encrypted.starts_with(b"age-encryption.org/v1\n"));
+            }
+
+            assert_eq!(decrypt_with_identities(&encrypted, &[&identity]).unwrap(), MNEMONIC.as_bytes());
+            assert_eq!(decrypt_with_identities(&encrypted, &[&other_identity]).unwrap(), MNEMONIC.as_bytes());
+
+            let (stranger, _) = generate_identity();
+            assert!(decrypt_with_identities(&encrypted, &[&stranger]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_passphrase_roundtrip() {
+        let encrypted = encrypt_with_passphrase_work_factor(MNEMONIC.as_bytes(), "비밀번호", true, Some(10)).unwrap();
+
+        assert_eq!(decrypt_with_passphrase(&encrypted, "비밀번호").unwrap(), MNEMONIC.as_bytes());
+        assert!(decrypt_with_passphrase(&encrypted, "password").is_err());
+
+        let (identity, _) = generate_identity();
+        assert!(decrypt_with_identities(&encrypted, &[&identity]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_recipient() {
+        assert!(encrypt_to_recipients(b"data", &["age1invalid"], false).is_err());
+    }
+}
@@ -7,16 +7,177 @@
 //! 2. 체크섬 추가 (SHA-256 해시의 앞 4/8비트)
 //! 3. 11비트씩 분할하여 단어 인덱스로 변환
 //! 4. PBKDF2로 시드 생성
+//!
+//! ## 다국어 단어 목록
+//! 영어 외 언어는 feature로 활성화 (`korean`, `japanese`, ..., `all-languages`)
+//! - 단어 목록은 NFKD로 정규화된 형태로 저장
+//! - 입력 단어도 NFKD로 정규화 후 비교 (예: 한글 완성형 → 자모 분해)
+//! - 일본어는 단어 사이를 전각 공백(U+3000)으로 연결
 
 use sha2::{Sha256, Sha512, Digest};
 use pbkdf2::pbkdf2_hmac;
 use rand::RngCore;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use crate::secret::SecretBytes;
+use crate::trace;
 
 /// BIP-39 영어 단어 목록 (2048개)
 pub const WORDLIST_ENGLISH: &str = include_str!("wordlist/english.txt");
+#[cfg(feature = "korean")]
+pub const WORDLIST_KOREAN: &str = include_str!("wordlist/korean.txt");
+#[cfg(feature = "japanese")]
+pub const WORDLIST_JAPANESE: &str = include_str!("wordlist/japanese.txt");
+#[cfg(feature = "chinese-simplified")]
+pub const WORDLIST_CHINESE_SIMPLIFIED: &str = include_str!("wordlist/chinese_simplified.txt");
+#[cfg(feature = "chinese-traditional")]
+pub const WORDLIST_CHINESE_TRADITIONAL: &str = include_str!("wordlist/chinese_traditional.txt");
+#[cfg(feature = "french")]
+pub const WORDLIST_FRENCH: &str = include_str!("wordlist/french.txt");
+#[cfg(feature = "italian")]
+pub const WORDLIST_ITALIAN: &str = include_str!("wordlist/italian.txt");
+#[cfg(feature = "spanish")]
+pub const WORDLIST_SPANISH: &str = include_str!("wordlist/spanish.txt");
+#[cfg(feature = "czech")]
+pub const WORDLIST_CZECH: &str = include_str!("wordlist/czech.txt");
+#[cfg(feature = "portuguese")]
+pub const WORDLIST_PORTUGUESE: &str = include_str!("wordlist/portuguese.txt");
+
+/// 니모닉 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    #[cfg(feature = "korean")]
+    Korean,
+    #[cfg(feature = "japanese")]
+    Japanese,
+    #[cfg(feature = "chinese-simplified")]
+    ChineseSimplified,
+    #[cfg(feature = "chinese-traditional")]
+    ChineseTraditional,
+    #[cfg(feature = "french")]
+    French,
+    #[cfg(feature = "italian")]
+    Italian,
+    #[cfg(feature = "spanish")]
+    Spanish,
+    #[cfg(feature = "czech")]
+    Czech,
+    #[cfg(feature = "portuguese")]
+    Portuguese,
+}
+
+impl Language {
+    /// 활성화된 모든 언어 (자동 감지 시 이 순서로 검사)
+    pub const ALL: &'static [Language] = &[
+        Language::English,
+        #[cfg(feature = "korean")]
+        Language::Korean,
+        #[cfg(feature = "japanese")]
+        Language::Japanese,
+        #[cfg(feature = "chinese-simplified")]
+        Language::ChineseSimplified,
+        #[cfg(feature = "chinese-traditional")]
+        Language::ChineseTraditional,
+        #[cfg(feature = "french")]
+        Language::French,
+        #[cfg(feature = "italian")]
+        Language::Italian,
+        #[cfg(feature = "spanish")]
+        Language::Spanish,
+        #[cfg(feature = "czech")]
+        Language::Czech,
+        #[cfg(feature = "portuguese")]
+        Language::Portuguese,
+    ];
+
+    /// 단어 목록 원문 (줄바꿈 구분)
+    pub fn wordlist(&self) -> &'static str {
+        match self {
+            Language::English => WORDLIST_ENGLISH,
+            #[cfg(feature = "korean")]
+            Language::Korean => WORDLIST_KOREAN,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => WORDLIST_JAPANESE,
+            #[cfg(feature = "chinese-simplified")]
+            Language::ChineseSimplified => WORDLIST_CHINESE_SIMPLIFIED,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => WORDLIST_CHINESE_TRADITIONAL,
+            #[cfg(feature = "french")]
+            Language::French => WORDLIST_FRENCH,
+            #[cfg(feature = "italian")]
+            Language::Italian => WORDLIST_ITALIAN,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => WORDLIST_SPANISH,
+            #[cfg(feature = "czech")]
+            Language::Czech => WORDLIST_CZECH,
+            #[cfg(feature = "portuguese")]
+            Language::Portuguese => WORDLIST_PORTUGUESE,
+        }
+    }
+
+    /// 단어 목록 (2048개)
+    pub fn words(&self) -> Vec<&'static str> {
+        parse_wordlist(self.wordlist())
+    }
+
+    /// 니모닉 단어 구분자 (일본어만 전각 공백)
+    pub fn separator(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "japanese")]
+            Language::Japanese => "\u{3000}",
+            _ => " ",
+        }
+    }
+
+    /// 단어의 인덱스 (입력은 NFKD 정규화 후 비교)
+    pub fn word_index(&self, word: &str) -> Option<u16> {
+        let word: String = word.nfkd().collect();
+        self.words()
+            .iter()
+            .position(|&w| w == word)
+            .map(|i| i as u16)
+    }
+
+    /// 니모닉 언어 자동 감지
+    ///
+    /// 모든 단어가 들어 있는 언어가 여럿이면 (예: 중국어 간체/번체)
+    /// 체크섬이 맞는 언어를 우선
+    pub fn detect(mnemonic: &str) -> Result<Language, MnemonicError> {
+        let words: Vec<String> = mnemonic.split_whitespace().map(|w| w.nfkd().collect()).collect();
+
+        let mut candidates = Vec::new();
+        // 모든 단어가 있는 언어가 없으면, 가장 많이 맞은 언어 기준으로 오류 보고
+        let mut best: Option<(usize, MnemonicError)> = None;
+
+        for &language in Language::ALL {
+            let wordlist = language.words();
+            match words.iter().position(|w| !wordlist.contains(&w.as_str())) {
+                None => candidates.push(language),
+                Some(index) => {
+                    if best.as_ref().is_none_or(|(matched, _)| index > *matched) {
+                        let word = mnemonic.split_whitespace().nth(index).unwrap_or_default();
+                        best = Some((index, MnemonicError::UnknownWord { index, word: word.to_string() }));
+                    }
+                }
+            }
+        }
+
+        match candidates.as_slice() {
+            [] => Err(best.map(|(_, e)| e).unwrap_or(MnemonicError::InvalidWordCount(0))),
+            [language] => Ok(*language),
+            _ => Ok(candidates
+                .iter()
+                .copied()
+                .find(|&language| mnemonic_to_entropy_in(mnemonic, language).is_ok())
+                .unwrap_or(candidates[0])),
+        }
+    }
+}
 
 /// 엔트로피 크기
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MnemonicType {
     /// 128비트 → 12단어
     Words12,
@@ -146,26 +307,176 @@ pub fn indices_to_mnemonic(indices: &[u16], wordlist: &[&str]) -> String {
         .join(" ")
 }
 
+/// 니모닉 검증 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MnemonicError {
+    /// 단어 수가 12/15/18/21/24가 아님
+    InvalidWordCount(usize),
+    /// 단어 목록에 없는 단어 (0부터 시작하는 위치)
+    UnknownWord { index: usize, word: String },
+    /// 체크섬 불일치 (단어는 모두 유효하지만 조합이 틀림)
+    InvalidChecksum,
+    /// 엔트로피 길이가 16/20/24/28/32바이트가 아님
+    InvalidEntropyLength(usize),
+}
+
+impl std::fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MnemonicError::InvalidWordCount(count) => {
+                write!(f, "단어 수는 12/15/18/21/24개여야 합니다, 입력: {}개", count)
+            }
+            MnemonicError::UnknownWord { index, word } => {
+                write!(f, "{}번째 단어 '{}'는 단어 목록에 없습니다", index + 1, word)
+            }
+            MnemonicError::InvalidChecksum => write!(f, "체크섬이 일치하지 않습니다"),
+            MnemonicError::InvalidEntropyLength(len) => {
+                write!(f, "엔트로피는 16/20/24/28/32바이트여야 합니다, 입력: {}바이트", len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MnemonicError {}
+
+/// 니모닉 검증 (언어 자동 감지)
+///
+/// 1. 단어 수 (12/15/18/21/24)
+/// 2. 각 단어가 단어 목록에 있는지
+/// 3. 마지막 체크섬 비트가 SHA-256(엔트로피)와 일치하는지
+pub fn validate_mnemonic(mnemonic: &str) -> Result<(), MnemonicError> {
+    mnemonic_to_entropy(mnemonic).map(|_| ())
+}
+
+/// 엔트로피 → 니모닉
+///
+/// | 엔트로피 | 체크섬 | 단어 수 |
+/// |---------|-------|--------|
+/// | 128비트 | 4비트 | 12 |
+/// | 160비트 | 5비트 | 15 |
+/// | 192비트 | 6비트 | 18 |
+/// | 224비트 | 7비트 | 21 |
+/// | 256비트 | 8비트 | 24 |
+pub fn entropy_to_mnemonic(entropy: &[u8]) -> Result<String, MnemonicError> {
+    entropy_to_mnemonic_in(entropy, Language::English)
+}
+
+/// 엔트로피 → 특정 언어의 니모닉
+pub fn entropy_to_mnemonic_in(entropy: &[u8], language: Language) -> Result<String, MnemonicError> {
+    if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+        return Err(MnemonicError::InvalidEntropyLength(entropy.len()));
+    }
+
+    let wordlist = language.words();
+    let checksum = calculate_checksum(entropy);
+    let indices = entropy_to_indices(entropy, checksum);
+
+    Ok(indices
+        .iter()
+        .map(|&idx| wordlist[idx as usize])
+        .collect::<Vec<_>>()
+        .join(language.separator()))
+}
+
+/// 니모닉 → 엔트로피 (언어 자동 감지, 체크섬 검증 포함)
+pub fn mnemonic_to_entropy(mnemonic: &str) -> Result<Vec<u8>, MnemonicError> {
+    let word_count = mnemonic.split_whitespace().count();
+    if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+        return Err(MnemonicError::InvalidWordCount(word_count));
+    }
+
+    let language = Language::detect(mnemonic)?;
+    mnemonic_to_entropy_in(mnemonic, language)
+}
+
+/// 특정 언어의 니모닉 → 엔트로피
+///
+/// `entropy_to_indices`의 역과정: 단어 인덱스(11비트씩)를 이어붙여
+/// 엔트로피와 체크섬으로 다시 분리
+pub fn mnemonic_to_entropy_in(mnemonic: &str, language: Language) -> Result<Vec<u8>, MnemonicError> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+
+    if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+        return Err(MnemonicError::InvalidWordCount(words.len()));
+    }
+
+    // 단어 → 인덱스
+    let mut indices = Vec::with_capacity(words.len());
+    for (index, word) in words.iter().enumerate() {
+        let position = language
+            .word_index(word)
+            .ok_or_else(|| MnemonicError::UnknownWord { index, word: word.to_string() })?;
+        indices.push(position);
+    }
+
+    // 인덱스 → 비트 배열 (11비트씩, MSB first)
+    let mut bits: Vec<bool> = Vec::with_capacity(indices.len() * 11);
+    for index in &indices {
+        for i in (0..11).rev() {
+            bits.push((index >> i) & 1 == 1);
+        }
+    }
+
+    // 전체 비트 = 엔트로피 + 체크섬 (체크섬 = 엔트로피 / 32)
+    let checksum_bits = bits.len() / 33;
+    let entropy_bits = bits.len() - checksum_bits;
+
+    let entropy: Vec<u8> = bits[..entropy_bits]
+        .chunks(8)
+        .map(|byte_bits| byte_bits.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect();
+
+    let checksum = bits[entropy_bits..]
+        .iter()
+        .fold(0u8, |acc, &bit| (acc << 1) | bit as u8);
+
+    if checksum != calculate_checksum(&entropy) {
+        return Err(MnemonicError::InvalidChecksum);
+    }
+
+    Ok(entropy)
+}
+
 /// 니모닉에서 시드 생성 (PBKDF2-HMAC-SHA512)
 ///
 /// - 반복 횟수: 2048
 /// - 솔트: "mnemonic" + 패스프레이즈
-pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
-    let salt = format!("mnemonic{}", passphrase);
-    let mut seed = [0u8; 64];
+/// - 니모닉과 패스프레이즈 모두 NFKD 정규화 (일본어 전각 공백 → 일반 공백, 한글 완성형 → 자모 포함)
+///   → 같은 패스프레이즈를 NFC/NFD 어느 쪽으로 입력해도 참조 지갑과 같은 시드
+/// - 정규화한 니모닉, 솔트 같은 중간값도 사용 후 0으로 지움
+/// - 단어 목록/체크섬은 검사하지 않음 → 계정의 `from_mnemonic_unchecked`가 사용하며, 비표준 문구 전용
+///   (오타가 있어도 오류 없이 다른 지갑이 만들어짐)
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> SecretBytes<64> {
+    let _span = trace::span!(
+        "bip39.mnemonic_to_seed",
+        words = mnemonic.split_whitespace().count(),
+        passphrase = !passphrase.is_empty(),
+    );
+    let mnemonic: Zeroizing<String> = Zeroizing::new(mnemonic.nfkd().collect());
+    let salt: Zeroizing<String> = Zeroizing::new("mnemonic".chars().chain(passphrase.nfkd()).collect());
+    let mut seed = SecretBytes::new([0u8; 64]);
 
     pbkdf2_hmac::<Sha512>(
         mnemonic.as_bytes(),
         salt.as_bytes(),
         2048,
-        &mut seed,
+        &mut *seed,
     );
 
     seed
 }
 
+/// 니모닉 검증 후 시드 생성 (계정 생성자의 기본 경로)
+///
+/// 단어 수, 단어 목록, 체크섬이 틀리면 PBKDF2를 돌리지 않고 오류
+/// → 오타 난 니모닉이 조용히 다른 지갑이 되는 것을 막음
+pub fn mnemonic_to_seed_checked(mnemonic: &str, passphrase: &str) -> Result<SecretBytes<64>, MnemonicError> {
+    validate_mnemonic(mnemonic)?;
+    Ok(mnemonic_to_seed(mnemonic, passphrase))
+}
+
 /// 전체 플로우: 엔트로피 → 니모닉 → 시드
-pub fn generate_mnemonic(mnemonic_type: MnemonicType) -> (String, [u8; 64]) {
+pub fn generate_mnemonic(mnemonic_type: MnemonicType) -> (String, SecretBytes<64>) {
     let wordlist = parse_wordlist(WORDLIST_ENGLISH);
     let entropy = generate_entropy(mnemonic_type);
     let checksum = calculate_checksum(&entropy);
@@ -223,6 +534,224 @@ mod tests {
         assert_eq!(mnemonic, expected);
     }
 
+    #[test]
+    fn test_validate_mnemonic() {
+        // 유효한 니모닉 (12, 24단어)
+        assert!(validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").is_ok());
+        assert!(validate_mnemonic("legal winner thank year wave sausage worth useful legal winner thank yellow").is_ok());
+        assert!(validate_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+             abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art"
+        ).is_ok());
+
+        // 단어 수 오류
+        assert_eq!(
+            validate_mnemonic("abandon abandon abandon"),
+            Err(MnemonicError::InvalidWordCount(3))
+        );
+
+        // 단어 목록에 없는 단어 (위치 반환)
+        assert_eq!(
+            validate_mnemonic("abandon abandon abandonn abandon abandon abandon abandon abandon abandon abandon abandon about"),
+            Err(MnemonicError::UnknownWord { index: 2, word: "abandonn".to_string() })
+        );
+
+        // 체크섬 오류 (마지막 단어만 다름)
+        assert_eq!(
+            validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"),
+            Err(MnemonicError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed_checked() {
+        let valid = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(mnemonic_to_seed_checked(valid, "TREZOR").unwrap(), *mnemonic_to_seed(valid, "TREZOR"));
+
+        // 체크섬이 틀린 오타 문구는 시드를 만들지 않음
+        let typo = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert_eq!(mnemonic_to_seed_checked(typo, ""), Err(MnemonicError::InvalidChecksum));
+        assert_eq!(mnemonic_to_seed_checked("not a mnemonic", ""), Err(MnemonicError::InvalidWordCount(3)));
+    }
+
+    /// 체인별 `from_mnemonic_unchecked`: 체크섬이 틀린 문구도 받고, 올바른 문구는 `from_mnemonic`과 같은 계정
+    #[test]
+    fn test_from_mnemonic_unchecked() {
+        use crate::bitcoin::BitcoinAccount;
+        use crate::cosmos::CosmosAccount;
+        use crate::evm::EvmAccount;
+        use crate::solana::SolanaAccount;
+        use crate::sui::SuiAccount;
+        use crate::xrp::XrpAccount;
+
+        type Constructor = fn(&str, &str) -> crate::Result<Vec<u8>>;
+        type FromSeed = fn(&[u8]) -> crate::Result<Vec<u8>>;
+
+        let valid = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let typo = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        let seed = mnemonic_to_seed(typo, "TREZOR");
+        // (체인, from_mnemonic, from_mnemonic_unchecked, from_seed)
+        let cases: [(&str, Constructor, Constructor, FromSeed); 6] = [
+            (
+                "bitcoin",
+                |m, p| BitcoinAccount::from_mnemonic(m, p).map(|a| a.public_key.to_vec()),
+                |m, p| BitcoinAccount::from_mnemonic_unchecked(m, p).map(|a| a.public_key.to_vec()),
+                |s| BitcoinAccount::from_seed(s).map(|a| a.public_key.to_vec()),
+            ),
+            (
+                "evm",
+                |m, p| EvmAccount::from_mnemonic(m, p).map(|a| a.public_key.to_vec()),
+                |m, p| EvmAccount::from_mnemonic_unchecked(m, p).map(|a| a.public_key.to_vec()),
+                |s| EvmAccount::from_seed(s).map(|a| a.public_key.to_vec()),
+            ),
+            (
+                "cosmos",
+                |m, p| CosmosAccount::from_mnemonic(m, p).map(|a| a.public_key.to_vec()),
+                |m, p| CosmosAccount::from_mnemonic_unchecked(m, p).map(|a| a.public_key.to_vec()),
+                |s| CosmosAccount::from_seed(s).map(|a| a.public_key.to_vec()),
+            ),
+            (
+                "solana",
+                |m, p| SolanaAccount::from_mnemonic(m, p).map(|a| a.public_key.to_vec()),
+                |m, p| SolanaAccount::from_mnemonic_unchecked(m, p).map(|a| a.public_key.to_vec()),
+                |s| SolanaAccount::from_seed(s).map(|a| a.public_key.to_vec()),
+            ),
+            (
+                "sui",
+                |m, p| SuiAccount::from_mnemonic(m, p).map(|a| a.public_key.to_vec()),
+                |m, p| SuiAccount::from_mnemonic_unchecked(m, p).map(|a| a.public_key.to_vec()),
+                |s| SuiAccount::from_seed(s).map(|a| a.public_key.to_vec()),
+            ),
+            (
+                "xrp",
+                |m, p| XrpAccount::from_mnemonic(m, p).map(|a| a.public_key.to_vec()),
+                |m, p| XrpAccount::from_mnemonic_unchecked(m, p).map(|a| a.public_key.to_vec()),
+                |s| XrpAccount::from_seed_with_path(s, crate::xrp::XRP_PATH).map(|a| a.public_key.to_vec()),
+            ),
+        ];
+
+        for (chain, checked, unchecked, from_seed) in cases {
+            assert!(matches!(checked(typo, "TREZOR"), Err(crate::Error::InvalidMnemonic(MnemonicError::InvalidChecksum))), "{}", chain);
+            assert_eq!(unchecked(typo, "TREZOR").unwrap(), from_seed(seed.as_slice()).unwrap(), "{}", chain);
+            assert_eq!(unchecked(valid, "").unwrap(), checked(valid, "").unwrap(), "{}", chain);
+        }
+    }
+
+    #[test]
+    fn test_entropy_mnemonic_roundtrip() {
+        // 모든 유효한 엔트로피 크기 (BIP-39 테스트 벡터 엔트로피 패턴)
+        for len in [16, 20, 24, 28, 32] {
+            let entropy = vec![0x7fu8; len];
+            let mnemonic = entropy_to_mnemonic(&entropy).unwrap();
+
+            assert_eq!(mnemonic.split_whitespace().count(), len * 3 / 4);
+            assert_eq!(mnemonic_to_entropy(&mnemonic).unwrap(), entropy);
+        }
+
+        // BIP-39 테스트 벡터 (24단어)
+        let entropy = hex::decode("8080808080808080808080808080808080808080808080808080808080808080").unwrap();
+        assert_eq!(
+            entropy_to_mnemonic(&entropy).unwrap(),
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless"
+        );
+
+        assert_eq!(
+            entropy_to_mnemonic(&[0u8; 17]),
+            Err(MnemonicError::InvalidEntropyLength(17))
+        );
+    }
+
+    #[test]
+    fn test_detect_english() {
+        let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        assert_eq!(Language::detect(mnemonic).unwrap(), Language::English);
+    }
+
+    #[cfg(feature = "japanese")]
+    #[test]
+    fn test_japanese_mnemonic() {
+        // BIP-39 일본어 테스트 벡터: 엔트로피 0x00 x 16
+        let entropy = [0u8; 16];
+        let mnemonic = entropy_to_mnemonic_in(&entropy, Language::Japanese).unwrap();
+
+        // 전각 공백으로 연결 (단어 목록은 NFKD이므로 NFC로 비교)
+        assert_eq!(
+            mnemonic.nfc().collect::<String>(),
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら"
+        );
+
+        assert_eq!(Language::detect(&mnemonic).unwrap(), Language::Japanese);
+        assert_eq!(mnemonic_to_entropy(&mnemonic).unwrap(), entropy);
+    }
+
+    #[test]
+    fn test_japanese_seed_vectors() {
+        // BIP-39 공식 일본어 테스트 벡터 (bip39-vectors / test_JP_BIP39.json)
+        // 니모닉은 NFC + 전각 공백, 패스프레이즈는 호환 문자(㍍, ゞ) 포함 → 둘 다 NFKD 필요
+        let passphrase = "㍍ガバヴァぱばぐゞちぢ十人十色";
+        let vectors = [
+            (
+                "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら",
+                "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55",
+            ),
+            (
+                "そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れきだい　ほんやく　わかめ",
+                "aee025cbe6ca256862f889e48110a6a382365142f7d16f2b9545285b3af64e542143a577e9c144e101a6bdca18f8d97ec3366ebf5b088b1c1af9bc31346e60d9",
+            ),
+            (
+                "そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　いよく　そとづら　あまど　おおう　あかちゃん",
+                "e51736736ebdf77eda23fa17e31475fa1d9509c78f1deb6b4aacfbd760a7e2ad769c714352c95143b5c1241985bcb407df36d64e75dd5a2b78ca5d2ba82a3544",
+            ),
+        ];
+
+        for (mnemonic, expected) in vectors {
+            assert_eq!(hex::encode(mnemonic_to_seed(mnemonic, passphrase)), expected);
+            #[cfg(feature = "japanese")]
+            assert_eq!(hex::encode(mnemonic_to_seed_checked(mnemonic, passphrase).unwrap()), expected);
+        }
+    }
+
+    #[test]
+    fn test_passphrase_nfkd() {
+        // 한글 패스프레이즈: 완성형(NFC)과 자모(NFD) 입력이 같은 시드
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let composed = "비밀번호";
+        let decomposed: String = composed.nfd().collect();
+        assert_ne!(composed, decomposed);
+
+        assert_eq!(mnemonic_to_seed(mnemonic, composed), mnemonic_to_seed(mnemonic, &decomposed));
+        assert_ne!(mnemonic_to_seed(mnemonic, composed), mnemonic_to_seed(mnemonic, ""));
+    }
+
+    #[cfg(feature = "korean")]
+    #[test]
+    fn test_korean_nfkd_input() {
+        let entropy = [0x7fu8; 16];
+        let mnemonic = entropy_to_mnemonic_in(&entropy, Language::Korean).unwrap();
+
+        // 사용자는 완성형(NFC)으로 입력 → NFKD로 정규화 후 비교
+        let typed: String = mnemonic.nfc().collect();
+        assert_ne!(typed, mnemonic);
+
+        assert_eq!(Language::detect(&typed).unwrap(), Language::Korean);
+        assert_eq!(mnemonic_to_entropy(&typed).unwrap(), entropy);
+        assert_eq!(mnemonic_to_seed(&typed, ""), mnemonic_to_seed(&mnemonic, ""));
+    }
+
+    #[cfg(feature = "all-languages")]
+    #[test]
+    fn test_all_languages_roundtrip() {
+        let entropy = hex::decode("9e885d952ad362caeb4efe34a8e91bd2").unwrap();
+
+        for &language in Language::ALL {
+            assert_eq!(language.words().len(), 2048);
+
+            let mnemonic = entropy_to_mnemonic_in(&entropy, language).unwrap();
+            assert_eq!(mnemonic_to_entropy_in(&mnemonic, language).unwrap(), entropy);
+            assert!(validate_mnemonic(&mnemonic).is_ok(), "{:?}", language);
+        }
+    }
+
     #[test]
     fn test_mnemonic_to_seed() {
         // ═══════════════════════════════════════════════════════════════
@@ -232,10 +761,10 @@ mod tests {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
         let seed = mnemonic_to_seed(mnemonic, "");
 
-        println!("시드: {}", hex::encode(seed));
+        println!("시드: {}", hex::encode(&seed));
 
         // BIP-39 공식 테스트 벡터의 예상 시드
         let expected_seed = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
-        assert_eq!(hex::encode(seed), expected_seed);
+        assert_eq!(hex::encode(&seed), expected_seed);
     }
 }
@@ -0,0 +1,202 @@
+//! BIP-38 Passphrase-protected Private Keys
+//!
+//! 개인키를 비밀번호로 암호화한 `6P...` 문자열 (종이 지갑 등)
+//! EC 곱셈 없는 모드(0x0142)만 지원
+//!
+//! ## 암호화 과정
+//! 1. 개인키의 P2PKH 주소(메인넷) → SHA-256d → 앞 4바이트 = addresshash
+//! 2. scrypt(NFC(비밀번호), salt = addresshash, N = 16384, r = 8, p = 8) → 64바이트
+//!    - derived1 = 앞 32바이트, derived2 = 뒤 32바이트
+//! 3. 개인키 XOR derived1을 16바이트씩 AES-256(키 = derived2) 암호화
+//! 4. Base58Check(0x01 0x42 || flag || addresshash || 암호문 32바이트)
+//!
+//! flag: 0xC0 = 비압축 공개키 주소, 0xE0 = 압축 공개키 주소
+//! 복호화 후 주소 해시를 다시 계산해 비밀번호가 맞는지 확인
+
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use aes::Aes256;
+use secp256k1::{PublicKey, SecretKey};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::ecdsa;
+use crate::encoding::base58check;
+use crate::error::{Error, Result};
+
+use super::{encode_base58check, BitcoinAccount};
+use crate::hash::{hash160, sha256d};
+
+/// EC 곱셈 없는 모드 접두사
+const PREFIX_NON_EC: [u8; 2] = [0x01, 0x42];
+/// EC 곱셈 모드 접두사 (지원하지 않음)
+const PREFIX_EC_MULTIPLY: [u8; 2] = [0x01, 0x43];
+
+const FLAG_NON_EC: u8 = 0xC0;
+const FLAG_COMPRESSED: u8 = 0x20;
+
+impl BitcoinAccount {
+    /// BIP-38 암호화 (6P...)
+    ///
+    /// `compressed`: 압축 공개키 주소 기준으로 암호화할지 여부 (가져올 때 같은 주소가 나와야 함)
+    pub fn encrypt_bip38(&self, passphrase: &str, compressed: bool) -> Result<String> {
+        let address_hash = address_hash(&self.private_key, compressed)?;
+        let (derived1, derived2) = derive_keys(passphrase, &address_hash)?;
+
+        let cipher = Aes256::new(&derived2.into());
+        let mut encrypted = [0u8; 32];
+        for (i, block) in encrypted.chunks_exact_mut(16).enumerate() {
+            for (j, byte) in block.iter_mut().enumerate() {
+                *byte = self.private_key[i * 16 + j] ^ derived1[i * 16 + j];
+            }
+            cipher.encrypt_block(block.into());
+        }
+
+        let flag = if compressed { FLAG_NON_EC | FLAG_COMPRESSED } else { FLAG_NON_EC };
+        let mut payload = vec![PREFIX_NON_EC[1], flag];
+        payload.extend_from_slice(&address_hash);
+        payload.extend_from_slice(&encrypted);
+        Ok(encode_base58check(PREFIX_NON_EC[0], &payload))
+    }
+
+    /// BIP-38 복호화
+    ///
+    /// 반환: (계정, 압축 공개키 주소 여부)
+    pub fn decrypt_bip38(encrypted: &str, passphrase: &str) -> Result<(Self, bool)> {
+        let payload = base58check::decode(encrypted)?;
+        if payload.len() != 39 {
+            return Err(Error::Keystore(format!("BIP-38 키는 39바이트여야 합니다 (현재 {}바이트)", payload.len())));
+        }
+        if payload[..2] == PREFIX_EC_MULTIPLY {
+            return Err(Error::Keystore("EC 곱셈 모드 BIP-38 키는 지원하지 않습니다".to_string()));
+        }
+        if payload[..2] != PREFIX_NON_EC {
+            return Err(Error::Keystore("BIP-38 키가 아닙니다 (접두사 불일치)".to_string()));
+        }
+
+        let flag = payload[2];
+        if flag & !FLAG_COMPRESSED != FLAG_NON_EC {
+            return Err(Error::Keystore(format!("유효하지 않은 BIP-38 flag: 0x{:02x}", flag)));
+        }
+        let compressed = flag & FLAG_COMPRESSED != 0;
+        let expected_hash: [u8; 4] = payload[3..7].try_into().unwrap();
+
+        let (derived1, derived2) = derive_keys(passphrase, &expected_hash)?;
+        let cipher = Aes256::new(&derived2.into());
+        let mut private_key: [u8; 32] = payload[7..39].try_into().unwrap();
+        for (i, block) in private_key.chunks_exact_mut(16).enumerate() {
+            cipher.decrypt_block(block.into());
+            for (j, byte) in block.iter_mut().enumerate() {
+                *byte ^= derived1[i * 16 + j];
+            }
+        }
+
+        // 비밀번호가 틀리면 주소 해시가 달라짐 (유효하지 않은 개인키도 같은 에러)
+        if address_hash(&private_key, compressed).ok() != Some(expected_hash) {
+            return Err(Error::Decryption("비밀번호가 틀렸습니다 (주소 해시 불일치)".to_string()));
+        }
+
+        Ok((Self::from_private_key(private_key)?, compressed))
+    }
+}
+
+/// 개인키 → P2PKH 주소(메인넷) → SHA-256d 앞 4바이트
+fn address_hash(private_key: &[u8; 32], compressed: bool) -> Result<[u8; 4]> {
+    let secret = SecretKey::from_slice(private_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 개인키".to_string()))?;
+    let public = PublicKey::from_secret_key(ecdsa::context(), &secret);
+    let pubkey_hash = if compressed {
+        hash160(&public.serialize())
+    } else {
+        hash160(&public.serialize_uncompressed())
+    };
+
+    let address = encode_base58check(0x00, &pubkey_hash);
+    Ok(sha256d(address.as_bytes())[..4].try_into().unwrap())
+}
+
+/// scrypt(NFC(비밀번호), addresshash) → (derived1, derived2)
+fn derive_keys(passphrase: &str, address_hash: &[u8; 4]) -> Result<([u8; 32], [u8; 32])> {
+    let passphrase: String = passphrase.nfc().collect();
+    let params = scrypt::Params::new(14, 8, 8, 64).map_err(|e| Error::Keystore(e.to_string()))?;
+
+    let mut derived = [0u8; 64];
+    scrypt::scrypt(passphrase.as_bytes(), address_hash, &params, &mut derived).map_err(|e| Error::Keystore(e.to_string()))?;
+    Ok((derived[..32].try_into().unwrap(), derived[32..].try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitcoin::Network;
+
+    // BIP-38 테스트 벡터 (EC 곱셈 없음)
+    // (암호화된 키, 비밀번호, 개인키 hex, 압축 여부, WIF)
+    const VECTORS: &[(&str, &str, &str, bool, &str)] = &[
+        (
+            "6PRVWUbkzzsbcVac2qwfssoUJAN1Xhrg6bNk8J7Nzm5H7kxEbn2Nh2ZoGg",
+            "TestingOneTwoThree",
+            "cbf4b9f70470856bb4f40f80b87edb90865997ffee6df315ab166d713af433a5",
+            false,
+            "5KN7MzqK5wt2TP1fQCYyHBtDrXdJuXbUzm4A9rKAteGu3Qi5CVR",
+        ),
+        (
+            "6PRNFFkZc2NZ6dJqFfhRoFNMR9Lnyj7dYGrzdgXXVMXcxoKTePPX1dWByq",
+            "Satoshi",
+            "09c2686880095b1a4c249ee3ac4eea8a014f11e6f986d0b5025ac1f39afbd9ae",
+            false,
+            "5HtasZ6ofTHP6HCwTqTkLDuLQisYPah7aUnSKfC7h4hMUVw2gi5",
+        ),
+        (
+            "6PYNKZ1EAgYgmQfmNVamxyXVWHzK5s6DGhwP4J5o44cvXdoY7sRzhtpUeo",
+            "TestingOneTwoThree",
+            "cbf4b9f70470856bb4f40f80b87edb90865997ffee6df315ab166d713af433a5",
+            true,
+            "L44B5gGEpqEDRS9vVPz7QT35jcBG2r3CZwSwQ4fCewXAhAhqGVpP",
+        ),
+        (
+            "6PYLtMnXvfG3oJde97zRyLYFZCYizPU5T3LwgdYJz1fRhh16bU7u6PPmY7",
+            "Satoshi",
+            "09c2686880095b1a4c249ee3ac4eea8a014f11e6f986d0b5025ac1f39afbd9ae",
+            true,
+            "KwYgW8gcxj1JWJXhPSu4Fqwzfhp5Yfi42mdYmMa4XqK7NJxXUSK7",
+        ),
+    ];
+
+    #[test]
+    fn test_bip38_vectors() {
+        for (encrypted, passphrase, private_key, compressed, wif) in VECTORS {
+            let (account, is_compressed) = BitcoinAccount::decrypt_bip38(encrypted, passphrase).unwrap();
+            println!("{} → {}", encrypted, account.private_key_wif(Network::Mainnet, is_compressed));
+
+            assert_eq!(account.private_key_hex(), *private_key);
+            assert_eq!(is_compressed, *compressed);
+            assert_eq!(account.private_key_wif(Network::Mainnet, is_compressed), *wif);
+
+            // 암호화는 결정적 (salt = 주소 해시)
+            assert_eq!(account.encrypt_bip38(passphrase, *compressed).unwrap(), *encrypted);
+        }
+    }
+
+    #[test]
+    fn test_unicode_passphrase() {
+        // 비밀번호 "\u{03D2}\u{0301}\u{0000}\u{010400}\u{01F4A9}" → NFC 정규화 후 사용
+        let (account, compressed) = BitcoinAccount::decrypt_bip38(
+            "6PRW5o9FLp4gJDDVqJQKJFTpMvdsSGJxMYHtHaQBF3ooa8mwD69bapcDQn",
+            "\u{03D2}\u{0301}\u{0000}\u{010400}\u{01F4A9}",
+        )
+        .unwrap();
+
+        assert!(!compressed);
+        assert_eq!(account.private_key_wif(Network::Mainnet, false), "5Jajm8eQ22H3pGWLEVCXyvND8dQZhiQhoLJNKjYXk9roUFTMSZ4");
+    }
+
+    #[test]
+    fn test_wrong_passphrase() {
+        let err = BitcoinAccount::decrypt_bip38(VECTORS[0].0, "wrong").unwrap_err();
+        assert!(matches!(err, Error::Decryption(_)));
+        assert!(err.to_string().contains("비밀번호"));
+
+        // EC 곱셈 모드 (BIP-38 테스트 벡터)
+        let err = BitcoinAccount::decrypt_bip38("6PfQu77ygVyJLZjfvMLyhLMQbYnu5uguoJJ4kMCLqWwPEdfpwANVS76gTX", "TestingOneTwoThree")
+            .unwrap_err();
+        assert!(err.to_string().contains("EC 곱셈"));
+    }
+}
@@ -0,0 +1,478 @@
+//! Bitcoin Message Signing
+//!
+//! 주소 소유 증명 (거래소 출금 주소 인증 등)
+//!
+//! ## BIP-137 (Legacy, P2PKH)
+//! ```text
+//! 해시 = SHA256d(varint(24) || "Bitcoin Signed Message:\n" || varint(메시지 길이) || 메시지)
+//! 서명 = base64(헤더 || r || s)   (65바이트)
+//! ```
+//! 헤더 = 27 + recovery_id + 주소 종류 오프셋
+//!
+//! | 헤더 | 주소 종류 |
+//! |------|----------|
+//! | 27~30 | P2PKH (비압축 공개키) |
+//! | 31~34 | P2PKH (압축 공개키) |
+//! | 35~38 | P2SH-P2WPKH |
+//! | 39~42 | P2WPKH |
+//!
+//! ## BIP-322 simple (SegWit, Taproot)
+//! 가상의 트랜잭션 두 개로 "이 주소의 코인을 쓸 수 있음"을 증명
+//! ```text
+//! to_spend: 입력 scriptSig = OP_0 PUSH32(H_tag("BIP0322-signed-message", 메시지))
+//!           출력 = 0 BTC → 증명할 주소의 scriptPubKey
+//! to_sign:  입력 = to_spend:0, 출력 = 0 BTC → OP_RETURN
+//! 서명 = base64(to_sign 입력의 witness 스택)
+//! ```
+//! - P2WPKH: BIP-143 sighash, witness = [DER 서명 || SIGHASH_ALL, 압축 공개키]
+//! - P2TR: BIP-341 sighash (SIGHASH_DEFAULT), witness = [Schnorr 서명 64바이트]
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use secp256k1::{Message, PublicKey, SecretKey};
+
+use super::transaction::{
+    write_bytes, write_compact_size, OutPoint, Reader, Transaction, TxIn, TxOut, SIGHASH_ALL, SIGHASH_DEFAULT,
+};
+use super::{AddressType, BitcoinAccount, Network};
+use crate::hash::{hash160, sha256d};
+use crate::ecdsa;
+use crate::encoding::base58check;
+use crate::schnorr;
+use crate::error::{Error, Result};
+
+/// BIP-137 메시지 접두사
+const MESSAGE_MAGIC: &[u8] = b"Bitcoin Signed Message:\n";
+
+impl BitcoinAccount {
+    /// 메시지 서명
+    ///
+    /// - `Legacy`: BIP-137 (압축 공개키 P2PKH 헤더)
+    /// - `SegWit`: BIP-322 simple (P2WPKH)
+    /// - `Taproot`: BIP-322 simple (P2TR 키 경로)
+    ///
+    /// 반환값: base64 서명
+    pub fn sign_message(&self, message: &[u8], address_type: AddressType) -> Result<String> {
+        match address_type {
+            AddressType::Legacy => self.sign_message_bip137(message),
+            AddressType::SegWit => self.sign_message_bip322_p2wpkh(message),
+            AddressType::Taproot => self.sign_message_bip322_p2tr(message),
+        }
+    }
+
+    /// BIP-137 서명 (P2PKH, 압축 공개키)
+    fn sign_message_bip137(&self, message: &[u8]) -> Result<String> {
+        let secret = self.secret_key()?;
+        let signature = ecdsa::context()
+            .sign_ecdsa_recoverable(&Message::from_digest(bip137_message_hash(message)), &secret);
+        let (recovery_id, compact) = signature.serialize_compact();
+
+        let mut data = Vec::with_capacity(65);
+        data.push(31 + recovery_id.to_i32() as u8);
+        data.extend_from_slice(&compact);
+        Ok(BASE64.encode(data))
+    }
+
+    /// BIP-322 simple 서명 (P2WPKH)
+    fn sign_message_bip322_p2wpkh(&self, message: &[u8]) -> Result<String> {
+        let script_pubkey = p2wpkh_script(&self.pubkey_hash);
+        let to_spend = to_spend_txid(message, &script_pubkey);
+        let sighash = bip143_sighash(&to_spend, &self.pubkey_hash, SIGHASH_ALL)?;
+
+        // Bitcoin Core처럼 low-R 서명 (DER 70바이트 이하)
+        let signature = ecdsa::context()
+            .sign_ecdsa_low_r(&Message::from_digest(sighash), &self.secret_key()?);
+
+        let mut signature = signature.serialize_der().to_vec();
+        signature.push(SIGHASH_ALL);
+        Ok(BASE64.encode(encode_witness(&[&signature, &self.public_key])))
+    }
+
+    /// BIP-322 simple 서명 (P2TR 키 경로, 스크립트 트리 없음)
+    fn sign_message_bip322_p2tr(&self, message: &[u8]) -> Result<String> {
+        let (output_key, _) = schnorr::taproot_tweak_public_key(&self.x_only_public_key(), None)?;
+        let script_pubkey = p2tr_script(&output_key);
+        let to_spend = to_spend_txid(message, &script_pubkey);
+        let sighash = taproot_sighash(&to_spend, &script_pubkey, SIGHASH_DEFAULT)?;
+
+        let tweaked = schnorr::taproot_tweak_private_key(&self.private_key, None)?;
+        let signature = schnorr::sign(&tweaked, &sighash, None)?;
+        Ok(BASE64.encode(encode_witness(&[&signature])))
+    }
+
+    /// 이 계정의 주소(`address_type`)에 대한 서명인지 검증
+    pub fn verify_message(&self, message: &[u8], signature: &str, address_type: AddressType) -> Result<()> {
+        let address = match address_type {
+            AddressType::Legacy => self.address_legacy(Network::Mainnet),
+            AddressType::SegWit => self.address_segwit(Network::Mainnet),
+            AddressType::Taproot => self.address_taproot(Network::Mainnet)?,
+        };
+        verify_message(&address, message, signature)
+    }
+
+    fn secret_key(&self) -> Result<SecretKey> {
+        SecretKey::from_slice(self.private_key.as_slice()).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 개인키".to_string()))
+    }
+}
+
+/// 메시지 서명 검증
+///
+/// 주소 형식으로 방식 결정
+/// - 1..., 3..., m/n/2... : BIP-137 (65바이트 서명)
+/// - bc1q..., bc1p... : BIP-322 simple, 65바이트 BIP-137 서명도 허용 (Electrum, Trezor 호환)
+pub fn verify_message(address: &str, message: &[u8], signature: &str) -> Result<()> {
+    let payload = decode_address(address)?;
+    let signature = BASE64
+        .decode(signature.trim())
+        .map_err(|e| Error::InvalidSignature(format!("유효하지 않은 base64: {}", e)))?;
+
+    match payload {
+        AddressPayload::P2wpkh(_) | AddressPayload::P2tr(_) if signature.len() != 65 => {
+            verify_bip322(&payload, message, &signature)
+        }
+        AddressPayload::P2tr(_) => verify_bip322(&payload, message, &signature),
+        _ => verify_bip137(&payload, message, &signature),
+    }
+}
+
+/// BIP-137 메시지 해시 (SHA256d)
+pub fn bip137_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(MESSAGE_MAGIC.len() + message.len() + 10);
+    write_bytes(&mut data, MESSAGE_MAGIC);
+    write_bytes(&mut data, message);
+    sha256d(&data)
+}
+
+/// BIP-322 메시지 해시 (태그 해시)
+pub fn bip322_message_hash(message: &[u8]) -> [u8; 32] {
+    schnorr::tagged_hash("BIP0322-signed-message", message)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 주소 디코딩
+// ═══════════════════════════════════════════════════════════════
+
+/// 주소에서 꺼낸 스크립트 정보 (네트워크 무관)
+#[derive(Debug, Clone, PartialEq)]
+enum AddressPayload {
+    P2pkh([u8; 20]),
+    P2sh([u8; 20]),
+    P2wpkh([u8; 20]),
+    P2tr([u8; 32]),
+}
+
+fn decode_address(address: &str) -> Result<AddressPayload> {
+    let address = address.trim();
+
+    if let Ok((_, version, program)) = ::bech32::segwit::decode(address) {
+        return match (version.to_u8(), program.len()) {
+            (0, 20) => Ok(AddressPayload::P2wpkh(program.try_into().expect("20바이트"))),
+            (1, 32) => Ok(AddressPayload::P2tr(program.try_into().expect("32바이트"))),
+            _ => Err(Error::InvalidAddress(format!("지원하지 않는 SegWit 주소: {}", address))),
+        };
+    }
+
+    let data = base58check::decode(address).map_err(|_| Error::InvalidAddress(format!("유효하지 않은 주소: {}", address)))?;
+    if data.len() != 21 {
+        return Err(Error::Base58(format!("유효하지 않은 Base58Check 주소: {}", address)));
+    }
+
+    let hash: [u8; 20] = data[1..21].try_into().expect("20바이트");
+    match data[0] {
+        0x00 | 0x6f => Ok(AddressPayload::P2pkh(hash)),
+        0x05 | 0xc4 => Ok(AddressPayload::P2sh(hash)),
+        version => Err(Error::InvalidAddress(format!("지원하지 않는 주소 버전: 0x{:02x}", version))),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 검증
+// ═══════════════════════════════════════════════════════════════
+
+fn verify_bip137(payload: &AddressPayload, message: &[u8], signature: &[u8]) -> Result<()> {
+    if signature.len() != 65 {
+        return Err(Error::InvalidSignature(format!("BIP-137 서명은 65바이트여야 합니다, 입력: {}바이트", signature.len())));
+    }
+
+    let header = signature[0];
+    if !(27..=42).contains(&header) {
+        return Err(Error::InvalidSignature(format!("유효하지 않은 BIP-137 헤더: {}", header)));
+    }
+
+    let recovery_id = RecoveryId::from_i32(((header - 27) % 4) as i32).map_err(|_| Error::InvalidSignature("유효하지 않은 복원 ID".to_string()))?;
+    let signature = RecoverableSignature::from_compact(&signature[1..], recovery_id)
+        .map_err(|_| Error::InvalidSignature("유효하지 않은 ECDSA 서명".to_string()))?;
+    let public_key = ecdsa::context()
+        .recover_ecdsa(&Message::from_digest(bip137_message_hash(message)), &signature)
+        .map_err(|_| Error::InvalidKey("공개키 복원 실패".to_string()))?;
+
+    let public_key = if header < 31 {
+        public_key.serialize_uncompressed().to_vec()
+    } else {
+        public_key.serialize().to_vec()
+    };
+    let pubkey_hash = hash160(&public_key);
+
+    // 헤더가 가리키는 주소 종류 (압축 P2PKH 헤더는 SegWit 주소에도 허용)
+    let matches = match payload {
+        AddressPayload::P2pkh(hash) => *hash == pubkey_hash,
+        AddressPayload::P2sh(hash) => header >= 31 && *hash == hash160(&p2wpkh_script(&pubkey_hash)),
+        AddressPayload::P2wpkh(hash) => header >= 31 && *hash == pubkey_hash,
+        AddressPayload::P2tr(_) => false,
+    };
+
+    if matches {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature("서명자가 주소와 다릅니다".to_string()))
+    }
+}
+
+fn verify_bip322(payload: &AddressPayload, message: &[u8], witness: &[u8]) -> Result<()> {
+    let witness = decode_witness(witness)?;
+    let secp = ecdsa::context();
+
+    match payload {
+        AddressPayload::P2wpkh(pubkey_hash) => {
+            let [signature, public_key] = witness.as_slice() else {
+                return Err(Error::InvalidSignature(format!("P2WPKH witness는 항목 2개여야 합니다, 입력: {}개", witness.len())));
+            };
+            let Some((&sighash_type, der)) = signature.split_last() else {
+                return Err(Error::InvalidSignature("빈 서명".to_string()));
+            };
+            if sighash_type != SIGHASH_ALL {
+                return Err(Error::InvalidSignature(format!("지원하지 않는 sighash 타입: 0x{:02x}", sighash_type)));
+            }
+            if hash160(public_key) != *pubkey_hash {
+                return Err(Error::InvalidSignature("공개키가 주소와 다릅니다".to_string()));
+            }
+
+            let to_spend = to_spend_txid(message, &p2wpkh_script(pubkey_hash));
+            let sighash = bip143_sighash(&to_spend, pubkey_hash, sighash_type)?;
+            let public_key = PublicKey::from_slice(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 공개키".to_string()))?;
+            let signature = secp256k1::ecdsa::Signature::from_der(der).map_err(|_| Error::InvalidSignature("유효하지 않은 DER 서명".to_string()))?;
+
+            secp.verify_ecdsa(&Message::from_digest(sighash), &signature, &public_key)
+                .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+        }
+        AddressPayload::P2tr(output_key) => {
+            let [signature] = witness.as_slice() else {
+                return Err(Error::InvalidSignature(format!("P2TR 키 경로 witness는 항목 1개여야 합니다, 입력: {}개", witness.len())));
+            };
+            let (signature, sighash_type) = match signature.len() {
+                64 => (&signature[..], SIGHASH_DEFAULT),
+                65 if signature[64] == SIGHASH_ALL => (&signature[..64], SIGHASH_ALL),
+                _ => return Err(Error::InvalidSignature("유효하지 않은 Schnorr 서명 길이 또는 sighash 타입".to_string())),
+            };
+
+            let script_pubkey = p2tr_script(output_key);
+            let to_spend = to_spend_txid(message, &script_pubkey);
+            let sighash = taproot_sighash(&to_spend, &script_pubkey, sighash_type)?;
+            schnorr::verify(output_key, &sighash, signature.try_into().expect("64바이트"))
+        }
+        _ => Err(Error::InvalidAddress("BIP-322는 SegWit/Taproot 주소만 지원합니다".to_string())),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// BIP-322 가상 트랜잭션
+// ═══════════════════════════════════════════════════════════════
+
+/// P2WPKH scriptPubKey: OP_0 PUSH20(pubkey_hash)
+fn p2wpkh_script(pubkey_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![0x00, 0x14];
+    script.extend_from_slice(pubkey_hash);
+    script
+}
+
+/// P2TR scriptPubKey: OP_1 PUSH32(output_key)
+fn p2tr_script(output_key: &[u8; 32]) -> Vec<u8> {
+    let mut script = vec![0x51, 0x20];
+    script.extend_from_slice(output_key);
+    script
+}
+
+/// to_spend 트랜잭션 ID (내부 바이트 순서)
+fn to_spend_txid(message: &[u8], script_pubkey: &[u8]) -> [u8; 32] {
+    // scriptSig = OP_0 PUSH32(message_hash)
+    let mut script_sig = vec![0x00, 0x20];
+    script_sig.extend_from_slice(&bip322_message_hash(message));
+
+    let to_spend = Transaction {
+        version: 0,
+        inputs: vec![TxIn {
+            previous_output: OutPoint { txid: [0u8; 32], vout: 0xffffffff },
+            script_sig,
+            sequence: 0,
+            witness: Vec::new(),
+        }],
+        outputs: vec![TxOut { value: 0, script_pubkey: script_pubkey.to_vec() }],
+        lock_time: 0,
+    };
+    to_spend.txid()
+}
+
+/// to_sign 트랜잭션 (to_spend:0 → 0 BTC OP_RETURN)
+fn to_sign(to_spend: &[u8; 32]) -> Transaction {
+    Transaction {
+        version: 0,
+        inputs: vec![TxIn {
+            previous_output: OutPoint { txid: *to_spend, vout: 0 },
+            script_sig: Vec::new(),
+            sequence: 0,
+            witness: Vec::new(),
+        }],
+        outputs: vec![TxOut { value: 0, script_pubkey: vec![0x6a] }],
+        lock_time: 0,
+    }
+}
+
+/// to_sign의 BIP-143 sighash (P2WPKH, 금액 0)
+fn bip143_sighash(to_spend: &[u8; 32], pubkey_hash: &[u8; 20], sighash_type: u8) -> Result<[u8; 32]> {
+    // scriptCode = OP_DUP OP_HASH160 PUSH20(pubkey_hash) OP_EQUALVERIFY OP_CHECKSIG
+    let mut script_code = vec![0x76, 0xa9, 0x14];
+    script_code.extend_from_slice(pubkey_hash);
+    script_code.extend_from_slice(&[0x88, 0xac]);
+
+    to_sign(to_spend).segwit_v0_sighash(0, &script_code, 0, sighash_type)
+}
+
+/// to_sign의 BIP-341 키 경로 sighash
+fn taproot_sighash(to_spend: &[u8; 32], script_pubkey: &[u8], sighash_type: u8) -> Result<[u8; 32]> {
+    let prevout = TxOut { value: 0, script_pubkey: script_pubkey.to_vec() };
+    to_sign(to_spend).taproot_key_spend_sighash(0, &[prevout], sighash_type)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// witness 직렬화
+// ═══════════════════════════════════════════════════════════════
+
+/// witness 스택 직렬화 (항목 수 || (길이 || 항목)*)
+fn encode_witness(items: &[&[u8]]) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_compact_size(&mut out, items.len() as u64);
+    for item in items {
+        write_bytes(&mut out, item);
+    }
+    out
+}
+
+fn decode_witness(data: &[u8]) -> Result<Vec<Vec<u8>>> {
+    let mut reader = Reader::new(data);
+    let count = reader.read_compact_size()?;
+
+    let mut items = Vec::new();
+    for _ in 0..count {
+        items.push(reader.read_var_bytes()?);
+    }
+
+    reader.finish()?;
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// BIP-322 테스트 벡터 키 (WIF L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k)
+    fn bip322_account() -> BitcoinAccount {
+        let wif = bs58::decode("L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k").into_vec().unwrap();
+        BitcoinAccount::from_private_key(wif[1..33].try_into().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_bip322_message_hash() {
+        assert_eq!(
+            hex::encode(bip322_message_hash(b"")),
+            "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+        );
+        assert_eq!(
+            hex::encode(bip322_message_hash(b"Hello World")),
+            "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+        );
+    }
+
+    #[test]
+    fn test_bip322_to_spend() {
+        let account = bip322_account();
+        let script = p2wpkh_script(&account.pubkey_hash);
+
+        // txid는 화면 표시용으로 뒤집어서 비교
+        let mut txid = to_spend_txid(b"", &script);
+        txid.reverse();
+        assert_eq!(hex::encode(txid), "c5680aa69bb8d860bf82d4e9cd3504b55dde018de765a91bb566283c545a99a7");
+
+        let mut txid = to_spend_txid(b"Hello World", &script);
+        txid.reverse();
+        assert_eq!(hex::encode(txid), "b79d196740ad5217771c1098fc4a4b51e0535c32236c71f1ea4d61a2d603352b");
+    }
+
+    #[test]
+    fn test_bip322_p2wpkh_vectors() {
+        let account = bip322_account();
+        let address = account.address_segwit(Network::Mainnet);
+        assert_eq!(address, "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l");
+
+        let vectors = [
+            (&b""[..], "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI="),
+            (&b"Hello World"[..], "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI="),
+        ];
+
+        for (message, expected) in vectors {
+            assert_eq!(account.sign_message(message, AddressType::SegWit).unwrap(), expected);
+            assert!(verify_message(&address, message, expected).is_ok());
+        }
+
+        assert!(verify_message(&address, b"Hello World", vectors[0].1).is_err());
+
+        // 계정 메서드
+        assert!(account.verify_message(b"Hello World", vectors[1].1, AddressType::SegWit).is_ok());
+        assert!(account.verify_message(b"Hello World", vectors[1].1, AddressType::Taproot).is_err());
+    }
+
+    #[test]
+    fn test_bip322_p2tr() {
+        let account = bip322_account();
+        let address = account.address_taproot(Network::Mainnet).unwrap();
+        assert_eq!(address, "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3");
+
+        // BIP-322 벡터 (aux_rand가 달라서 서명 값 자체는 재현 불가 → 검증만)
+        let expected = "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==";
+        assert!(verify_message(&address, b"Hello World", expected).is_ok());
+
+        let signature = account.sign_message(b"Hello World", AddressType::Taproot).unwrap();
+        println!("Taproot 서명: {}", signature);
+        assert!(verify_message(&address, b"Hello World", &signature).is_ok());
+        assert!(verify_message(&address, b"Hello", &signature).is_err());
+    }
+
+    #[test]
+    fn test_bip137() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = BitcoinAccount::from_mnemonic_legacy(mnemonic, "").unwrap();
+        let address = account.address_legacy(Network::Mainnet);
+
+        let signature = account.sign_message(b"proof of ownership", AddressType::Legacy).unwrap();
+        println!("{} BIP-137 서명: {}", address, signature);
+        assert_eq!(BASE64.decode(&signature).unwrap().len(), 65);
+
+        assert!(verify_message(&address, b"proof of ownership", &signature).is_ok());
+        assert!(verify_message(&address, b"other", &signature).is_err());
+
+        // 압축 P2PKH 헤더는 같은 키의 SegWit 주소 검증에도 허용 (Electrum 방식)
+        assert!(verify_message(&account.address_segwit(Network::Mainnet), b"proof of ownership", &signature).is_ok());
+
+        // 다른 계정 주소
+        let other = BitcoinAccount::from_mnemonic(mnemonic, "").unwrap();
+        assert!(verify_message(&other.address_legacy(Network::Mainnet), b"proof of ownership", &signature).is_err());
+    }
+
+    #[test]
+    fn test_witness_roundtrip() {
+        let encoded = encode_witness(&[&[1, 2, 3], &[0u8; 300]]);
+        let decoded = decode_witness(&encoded).unwrap();
+        assert_eq!(decoded, vec![vec![1, 2, 3], vec![0u8; 300]]);
+        assert!(decode_witness(&encoded[..encoded.len() - 1]).is_err());
+    }
+}
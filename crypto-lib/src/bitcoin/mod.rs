@@ -5,6 +5,7 @@
 //! - 주소 형식:
 //!   - Legacy P2PKH: 1... (Base58Check)
 //!   - SegWit P2WPKH: bc1... (Bech32)
+//! - 네트워크: Mainnet, Testnet, Signet, Regtest (`Network`가 HRP, 버전 바이트, WIF, 확장 키 버전 결정)
 //! - BIP-44 경로:
 //!   - Legacy: m/44'/0'/0'/0/0
 //!   - SegWit: m/84'/0'/0'/0/0
@@ -14,20 +15,47 @@
 //! 2. 공개키 → SHA-256 → RIPEMD-160 = 공개키 해시 (20바이트)
 //! 3. Legacy: 버전(0x00) + 해시 → Base58Check
 //! 4. SegWit: Bech32 인코딩 (witness version 0)
-
-use sha2::{Sha256, Digest};
-use ripemd::Ripemd160;
-use secp256k1::{Secp256k1, SecretKey, PublicKey};
-
-use crate::bip32::{master_key_from_seed, ExtendedPrivateKey};
-use crate::bip39::mnemonic_to_seed;
-use crate::utils::bech32::encode_bech32;
+//!
+//! ## Taproot 출력 키
+//! `taproot_output_key(merkle_root)`: 주소 없이 BIP-341 출력 키, tweak, 패리티만 필요할 때
+//! (PSBT 공동 서명자, 스크립트 경로 control block 작성)
+//!
+//! ## Watch-only 계정
+//! 계정 xpub(m/84'/0'/0')만으로 입금 주소 m/84'/0'/0'/0/i를 도출
+//! (개인키를 보관하지 않는 서버용, tpub이면 테스트넷 주소)
+//! 계정 xpub은 `CachedWallet::account_xpub(_with_purpose)`로 내보냄
+//!
+//! ## 하위 모듈
+//! - `bip38`: 비밀번호로 암호화한 개인키 (6P...)
+//! - `message`: 메시지 서명 (BIP-137 Legacy, BIP-322 SegWit/Taproot)
+//! - `psbt`: PSBT v0/v2 파싱, 직렬화, 부분 서명 (BIP-174, BIP-370)
+//! - `transaction`: 트랜잭션 직렬화, sighash (Legacy, BIP-143, BIP-341)
+
+pub mod bip38;
+pub mod message;
+pub mod psbt;
+pub mod transaction;
+
+use zeroize::Zeroizing;
+
+use crate::bip32::{master_key_from_seed, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, IntoDerivationPath};
+use crate::account_iter::AccountIter;
+use crate::bip39::{mnemonic_to_seed, mnemonic_to_seed_checked};
+use crate::ecdsa::{self, RecoverableSignature};
+use crate::schnorr::{self, TaprootOutputKey};
+use crate::encoding::base58check;
+use crate::hash::hash160;
+use crate::encoding::bech32::{self, encode_bech32, Variant};
+use crate::secret::SecretBytes;
+use crate::error::Result;
 
 /// Bitcoin 계정
-#[derive(Debug, Clone)]
+///
+/// `Debug`는 개인키를 가림 (`account` 모듈에서 구현)
+#[derive(Clone)]
 pub struct BitcoinAccount {
     /// 개인키 (32바이트)
-    pub private_key: [u8; 32],
+    pub private_key: SecretBytes<32>,
     /// 압축 공개키 (33바이트)
     pub public_key: [u8; 33],
     /// 공개키 해시 (20바이트) - HASH160(pubkey)
@@ -40,54 +68,174 @@ pub const BITCOIN_SEGWIT_PATH: &str = "m/84'/0'/0'/0/0";
 pub const BITCOIN_LEGACY_PATH: &str = "m/44'/0'/0'/0/0";
 
 /// 네트워크 타입
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// | 네트워크 | Bech32 HRP | P2PKH / P2SH 버전 | WIF 버전 | 확장 키 |
+/// |----------|------------|-------------------|----------|---------|
+/// | Mainnet | `bc` | 0x00 / 0x05 | 0x80 | xpub/xprv |
+/// | Testnet | `tb` | 0x6f / 0xc4 | 0xef | tpub/tprv |
+/// | Signet | `tb` | 0x6f / 0xc4 | 0xef | tpub/tprv |
+/// | Regtest | `bcrt` | 0x6f / 0xc4 | 0xef | tpub/tprv |
+///
+/// 테스트 네트워크들은 Base58 버전과 확장 키 버전이 같으므로 tpub을 파싱하면 항상 `Testnet`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Network {
     /// 메인넷
     Mainnet,
-    /// 테스트넷
+    /// 테스트넷 (testnet3, testnet4)
     Testnet,
+    /// Signet (BIP-325)
+    Signet,
+    /// 로컬 회귀 테스트 네트워크
+    Regtest,
+}
+
+impl Network {
+    /// 모든 네트워크
+    pub const ALL: [Network; 4] = [Network::Mainnet, Network::Testnet, Network::Signet, Network::Regtest];
+
+    /// 이름 ("mainnet", "testnet", "signet", "regtest")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+            Network::Signet => "signet",
+            Network::Regtest => "regtest",
+        }
+    }
+
+    /// 이름 → 네트워크 (대소문자 무시, Bitcoin Core의 "main"/"test"도 허용)
+    pub fn from_name(name: &str) -> Option<Network> {
+        match name.to_ascii_lowercase().as_str() {
+            "mainnet" | "main" | "bitcoin" => Some(Network::Mainnet),
+            "testnet" | "test" | "testnet3" | "testnet4" => Some(Network::Testnet),
+            "signet" => Some(Network::Signet),
+            "regtest" => Some(Network::Regtest),
+            _ => None,
+        }
+    }
+
+    /// SegWit 주소 HRP
+    pub fn bech32_hrp(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "bc",
+            Network::Testnet | Network::Signet => "tb",
+            Network::Regtest => "bcrt",
+        }
+    }
+
+    /// P2PKH 주소 버전 바이트
+    pub fn p2pkh_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            _ => 0x6F,
+        }
+    }
+
+    /// P2SH 주소 버전 바이트
+    pub fn p2sh_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x05,
+            _ => 0xC4,
+        }
+    }
+
+    /// WIF 버전 바이트
+    pub fn wif_version(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            _ => 0xEF,
+        }
+    }
+
+    /// BIP-44 coin type (메인넷 0, 테스트 네트워크 1)
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            Network::Mainnet => 0,
+            _ => 1,
+        }
+    }
+
+    /// 메인넷 여부
+    pub fn is_mainnet(&self) -> bool {
+        *self == Network::Mainnet
+    }
+}
+
+/// 주소 종류
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AddressType {
+    /// P2PKH (1...)
+    Legacy,
+    /// P2WPKH (bc1q...)
+    SegWit,
+    /// P2TR 키 경로 (bc1p...)
+    Taproot,
 }
 
 impl BitcoinAccount {
     /// 개인키에서 Bitcoin 계정 생성
-    pub fn from_private_key(private_key: [u8; 32]) -> Self {
-        let public_key = private_key_to_public_key(&private_key);
+    ///
+    /// 개인키가 0이거나 곡선 위수 n 이상이면 오류
+    pub fn from_private_key(private_key: [u8; 32]) -> Result<Self> {
+        let public_key = ecdsa::public_key(&private_key)?.serialize();
         let pubkey_hash = hash160(&public_key);
 
-        BitcoinAccount {
-            private_key,
+        Ok(BitcoinAccount {
+            private_key: SecretBytes::new(private_key),
             public_key,
             pubkey_hash,
-        }
+        })
     }
 
     /// 확장 개인키에서 Bitcoin 계정 생성
-    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Self {
-        Self::from_private_key(extended_key.private_key)
+    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Result<Self> {
+        Self::from_private_key(*extended_key.private_key)
     }
 
     /// 시드에서 Bitcoin 계정 생성 (SegWit 기본)
-    pub fn from_seed(seed: &[u8]) -> Result<Self, String> {
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
         Self::from_seed_with_path(seed, BITCOIN_SEGWIT_PATH)
     }
 
     /// 시드에서 특정 경로로 Bitcoin 계정 생성
-    pub fn from_seed_with_path(seed: &[u8], path: &str) -> Result<Self, String> {
+    pub fn from_seed_with_path(seed: &[u8], path: impl IntoDerivationPath) -> Result<Self> {
         let master = master_key_from_seed(seed)?;
         let derived = master.derive_path(path)?;
-        Ok(Self::from_extended_key(&derived))
+        Self::from_extended_key(&derived)
+    }
+
+    /// 시드에서 SegWit 수신 주소 계정을 index 순서로 도출하는 반복자 (m/84'/0'/0'/0/i)
+    pub fn iter_from_seed(seed: &[u8]) -> Result<AccountIter<Self>> {
+        AccountIter::bip32(seed, "m/84'/0'/0'/0", 0, Self::from_private_key)
     }
 
     /// 니모닉에서 Bitcoin 계정 생성
-    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, String> {
+    ///
+    /// BIP-39 체크섬까지 검증 (틀리면 `Error::InvalidMnemonic`)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// BIP-39 검증 없이 Native SegWit 계정 생성 (`BITCOIN_SEGWIT_PATH`)
+    pub fn from_mnemonic_unchecked(mnemonic: &str, passphrase: &str) -> Result<Self> {
         let seed = mnemonic_to_seed(mnemonic, passphrase);
-        Self::from_seed(&seed)
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// 니모닉에서 SegWit 계정 생성 (m/84'/0'/{account}'/{change}/{index})
+    ///
+    /// `change`: 0 = 수신, 1 = 거스름돈. 인덱스가 2^31 이상이면 `Error::InvalidPath`
+    pub fn from_mnemonic_with_index(mnemonic: &str, passphrase: &str, account: u32, change: u32, index: u32) -> Result<Self> {
+        let path = DerivationPath::checked_bip_purpose(84, 0, account, change, index)?;
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
     }
 
     /// 니모닉에서 Legacy 계정 생성
-    pub fn from_mnemonic_legacy(mnemonic: &str, passphrase: &str) -> Result<Self, String> {
-        let seed = mnemonic_to_seed(mnemonic, passphrase);
-        Self::from_seed_with_path(&seed, BITCOIN_LEGACY_PATH)
+    pub fn from_mnemonic_legacy(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), BITCOIN_LEGACY_PATH)
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -96,20 +244,21 @@ impl BitcoinAccount {
 
     /// SegWit 주소 (bc1...) - Bech32
     pub fn address_segwit(&self, network: Network) -> String {
-        let hrp = match network {
-            Network::Mainnet => "bc",
-            Network::Testnet => "tb",
-        };
-        encode_bech32(hrp, Some(0), &self.pubkey_hash)
+        encode_bech32(network.bech32_hrp(), Some(0), &self.pubkey_hash)
     }
 
     /// Legacy 주소 (1...) - Base58Check
     pub fn address_legacy(&self, network: Network) -> String {
-        let version = match network {
-            Network::Mainnet => 0x00,
-            Network::Testnet => 0x6F,
-        };
-        encode_base58check(version, &self.pubkey_hash)
+        encode_base58check(network.p2pkh_version(), &self.pubkey_hash)
+    }
+
+    /// Taproot 주소 (bc1p...) - Bech32m
+    ///
+    /// BIP-86: 스크립트 경로 없이 내부 키를 TapTweak으로 조정한 출력 키 사용
+    /// 공개 필드로 직접 만든 계정의 공개키가 곡선 위의 점이 아니면 에러
+    pub fn address_taproot(&self, network: Network) -> Result<String> {
+        let (output_key, _) = schnorr::taproot_tweak_public_key(&self.x_only_public_key(), None)?;
+        Ok(encode_segwit_address(network, 1, &output_key))
     }
 
     /// 기본 주소 (SegWit 메인넷)
@@ -119,84 +268,147 @@ impl BitcoinAccount {
 
     /// 개인키를 WIF 형식으로 반환
     pub fn private_key_wif(&self, network: Network, compressed: bool) -> String {
-        let version = match network {
-            Network::Mainnet => 0x80,
-            Network::Testnet => 0xEF,
-        };
+        let version = network.wif_version();
 
-        let mut data = vec![version];
-        data.extend_from_slice(&self.private_key);
+        let mut data = Zeroizing::new(self.private_key.to_vec());
         if compressed {
             data.push(0x01); // 압축 공개키 표시
         }
 
         // Base58Check 인코딩
-        let checksum = double_sha256(&data);
-        data.extend_from_slice(&checksum[..4]);
-        bs58::encode(data).into_string()
+        base58check::encode(&[version], &data)
     }
 
     /// 개인키를 hex 문자열로 반환
     pub fn private_key_hex(&self) -> String {
-        hex::encode(self.private_key)
+        hex::encode(&self.private_key)
     }
 
     /// 공개키를 hex 문자열로 반환
     pub fn public_key_hex(&self) -> String {
         hex::encode(self.public_key)
     }
+
+    /// 32바이트 다이제스트에 ECDSA 서명 (RFC 6979, low-S), r || s
+    pub fn sign_ecdsa_compact(&self, digest: &[u8; 32]) -> Result<[u8; 64]> {
+        ecdsa::sign_compact(&self.private_key, digest)
+    }
+
+    /// 32바이트 다이제스트에 복원 가능한 ECDSA 서명 (RFC 6979, low-S)
+    pub fn sign_ecdsa_recoverable(&self, digest: &[u8; 32]) -> Result<RecoverableSignature> {
+        ecdsa::sign_recoverable(&self.private_key, digest)
+    }
+
+    /// x-only 공개키 (32바이트, BIP-340)
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        let mut x_only = [0u8; 32];
+        x_only.copy_from_slice(&self.public_key[1..]);
+        x_only
+    }
+
+    /// 32바이트 메시지에 BIP-340 Schnorr 서명 (aux_rand 없음 → 결정적)
+    pub fn sign_schnorr(&self, message: &[u8; 32]) -> Result<[u8; 64]> {
+        schnorr::sign(&self.private_key, message, None)
+    }
+
+    /// BIP-341 Taproot 출력 키와 패리티 (merkle_root: 스크립트 트리 루트, 키 경로 전용이면 None)
+    pub fn taproot_output_key(&self, merkle_root: Option<&[u8; 32]>) -> Result<TaprootOutputKey> {
+        TaprootOutputKey::new(&self.x_only_public_key(), merkle_root)
+    }
+
+    /// Taproot 출력 키에 대응하는 개인키 (키 경로 지출 Schnorr 서명용)
+    pub fn taproot_tweaked_private_key(&self, merkle_root: Option<&[u8; 32]>) -> Result<SecretBytes<32>> {
+        Ok(SecretBytes::new(schnorr::taproot_tweak_private_key(&self.private_key, merkle_root)?))
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
-// 내부 함수
+// Watch-only 계정 (xpub)
 // ═══════════════════════════════════════════════════════════════
 
-/// 개인키 → 압축 공개키 (secp256k1)
-fn private_key_to_public_key(private_key: &[u8; 32]) -> [u8; 33] {
-    let secp = Secp256k1::new();
-    let secret = SecretKey::from_slice(private_key).expect("유효한 개인키");
-    let public = PublicKey::from_secret_key(&secp, &secret);
-    public.serialize() // 압축 공개키 (33바이트)
+/// Bitcoin watch-only 계정 (계정 레벨 xpub m/84'/0'/0', 동작은 [`crate::bip32`] 참고)
+#[derive(Debug, Clone)]
+pub struct BitcoinWatchAccount {
+    /// 확장 공개키
+    pub xpub: ExtendedPublicKey,
+    /// 주소 네트워크 (xpub → 메인넷, tpub → 테스트넷, Signet/Regtest는 `with_network`로 지정)
+    pub network: Network,
 }
 
-/// HASH160 = RIPEMD160(SHA256(data))
-fn hash160(data: &[u8]) -> [u8; 20] {
-    let sha256_hash = Sha256::digest(data);
-    let ripemd_hash = Ripemd160::digest(sha256_hash);
+impl BitcoinWatchAccount {
+    /// xpub/tpub 문자열에서 생성 (네트워크는 버전 바이트로 결정)
+    pub fn from_xpub(xpub: &str) -> Result<Self> {
+        let (xpub, network) = ExtendedPublicKey::from_base58_with_network(xpub)?;
+        Ok(Self::from_extended_key(xpub, network))
+    }
+
+    /// 확장 공개키에서 생성
+    pub fn from_extended_key(xpub: ExtendedPublicKey, network: Network) -> Self {
+        BitcoinWatchAccount { xpub, network }
+    }
+
+    /// 주소 네트워크 변경 (tpub은 Testnet/Signet/Regtest를 구분하지 않음)
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// 상대 경로의 압축 공개키 (예: "m/0/5")
+    pub fn public_key_at(&self, path: impl IntoDerivationPath) -> Result<[u8; 33]> {
+        Ok(self.xpub.derive_path(path)?.public_key)
+    }
+
+    /// 상대 경로의 SegWit 주소 (bc1... / tb1...)
+    pub fn address_segwit_at(&self, path: impl IntoDerivationPath) -> Result<String> {
+        Ok(encode_bech32(self.network.bech32_hrp(), Some(0), &hash160(&self.public_key_at(path)?)))
+    }
+
+    /// 상대 경로의 Legacy 주소 (1... / m..., n...)
+    pub fn address_legacy_at(&self, path: impl IntoDerivationPath) -> Result<String> {
+        Ok(encode_base58check(self.network.p2pkh_version(), &hash160(&self.public_key_at(path)?)))
+    }
+
+    /// 상대 경로의 Taproot 주소 (bc1p... / tb1p..., BIP-86 계정 xpub m/86'/0'/0'용)
+    pub fn address_taproot_at(&self, path: impl IntoDerivationPath) -> Result<String> {
+        let public_key = self.public_key_at(path)?;
+        let output = TaprootOutputKey::from_public_key(&public_key, None)?;
+        Ok(encode_segwit_address(self.network, 1, &output.output_key))
+    }
+
+    /// 외부 체인(0/index) SegWit 입금 주소
+    pub fn address(&self, index: u32) -> Result<String> {
+        self.address_segwit_at(format!("m/0/{}", index))
+    }
 
-    let mut result = [0u8; 20];
-    result.copy_from_slice(&ripemd_hash);
-    result
+    /// 내부 체인(1/index) SegWit 잔돈 주소
+    pub fn change_address(&self, index: u32) -> Result<String> {
+        self.address_segwit_at(format!("m/1/{}", index))
+    }
 }
 
-/// Double SHA256
-fn double_sha256(data: &[u8]) -> [u8; 32] {
-    let first = Sha256::digest(data);
-    let second = Sha256::digest(first);
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
 
-    let mut result = [0u8; 32];
-    result.copy_from_slice(&second);
-    result
+/// SegWit 주소 인코딩 (버전 0: Bech32, 버전 1 이상: Bech32m)
+pub(crate) fn encode_segwit_address(network: Network, witness_version: u8, program: &[u8]) -> String {
+    let variant = if witness_version == 0 { Variant::Bech32 } else { Variant::Bech32m };
+    bech32::encode(network.bech32_hrp(), Some(witness_version), program, variant)
 }
 
-/// Base58Check 인코딩
+/// Base58Check 인코딩 (1바이트 버전)
 ///
 /// ## 구조
 /// version (1바이트) + payload + checksum (4바이트)
-/// checksum = double_sha256(version + payload)[0..4]
+/// checksum = sha256d(version + payload)[0..4]
 fn encode_base58check(version: u8, payload: &[u8]) -> String {
-    let mut data = vec![version];
-    data.extend_from_slice(payload);
-
-    let checksum = double_sha256(&data);
-    data.extend_from_slice(&checksum[..4]);
-
-    bs58::encode(data).into_string()
+    base58check::encode(&[version], payload)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
 
     #[test]
     fn test_bitcoin_from_mnemonic() {
@@ -220,16 +432,15 @@ mod tests {
     }
 
     #[test]
-    fn test_hash160() {
-        // 테스트 벡터: 압축 공개키의 HASH160
-        let pubkey = hex::decode("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798").unwrap();
-        let hash = hash160(&pubkey);
-
-        // 예상값: 751e76e8199196d454941c45d1b3a323f1433bd6
-        assert_eq!(
-            hex::encode(hash),
-            "751e76e8199196d454941c45d1b3a323f1433bd6"
-        );
+    fn test_invalid_private_key() {
+        // 0과 곡선 위수 n 이상은 패닉 대신 에러
+        assert_eq!(BitcoinAccount::from_private_key([0u8; 32]).unwrap_err(), Error::InvalidKey("secp256k1 개인키가 0입니다".to_string()));
+        assert_eq!(BitcoinAccount::from_private_key([0xff; 32]).unwrap_err(), Error::InvalidKey("secp256k1 개인키가 곡선 위수 n 이상입니다".to_string()));
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let account = BitcoinAccount::from_private_key(one).unwrap();
+        assert_eq!(hex::encode(account.public_key), "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
     }
 
     #[test]
@@ -264,7 +475,7 @@ mod tests {
 
         for i in 0..5 {
             let path = format!("m/84'/0'/0'/0/{}", i);
-            let account = BitcoinAccount::from_seed_with_path(&seed, &path).unwrap();
+            let account = BitcoinAccount::from_seed_with_path(seed.as_slice(), &path).unwrap();
 
             println!("경로: {}", path);
             println!("SegWit: {}", account.address_segwit(Network::Mainnet));
@@ -273,4 +484,118 @@ mod tests {
             println!();
         }
     }
+
+    #[test]
+    fn test_watch_account() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "");
+
+        let account_key = master_key_from_seed(seed.as_slice()).unwrap().derive_path("m/84'/0'/0'").unwrap();
+        let xpub = account_key.to_public().to_base58(Network::Mainnet);
+        let watch = BitcoinWatchAccount::from_xpub(&xpub).unwrap();
+        assert_eq!(watch.network, Network::Mainnet);
+
+        println!("\n=== Bitcoin watch-only ({}) ===\n", xpub);
+
+        for i in 0..3 {
+            let full = BitcoinAccount::from_seed_with_path(seed.as_slice(), format!("m/84'/0'/0'/0/{}", i)).unwrap();
+            let address = watch.address(i).unwrap();
+            println!("0/{}: {}", i, address);
+            assert_eq!(address, full.address_segwit(Network::Mainnet));
+
+            let change = BitcoinAccount::from_seed_with_path(seed.as_slice(), format!("m/84'/0'/0'/1/{}", i)).unwrap();
+            assert_eq!(watch.change_address(i).unwrap(), change.address_segwit(Network::Mainnet));
+        }
+
+        // BIP-84 테스트 벡터 (m/84'/0'/0'/0/0)
+        assert_eq!(watch.address(0).unwrap(), "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+
+        // tpub → 테스트넷 주소
+        let testnet = BitcoinWatchAccount::from_xpub(&account_key.to_public().to_base58(Network::Testnet)).unwrap();
+        assert_eq!(testnet.network, Network::Testnet);
+        assert!(testnet.address(0).unwrap().starts_with("tb1"));
+        let legacy = BitcoinAccount::from_seed_with_path(seed.as_slice(), "m/84'/0'/0'/0/0").unwrap();
+        assert_eq!(testnet.address_legacy_at("m/0/0").unwrap(), legacy.address_legacy(Network::Testnet));
+
+        assert!(watch.address_segwit_at("m/0'/0").is_err());
+    }
+
+    #[test]
+    fn test_networks() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = BitcoinAccount::from_seed_with_path(mnemonic_to_seed(mnemonic, "").as_slice(), "m/84'/1'/0'/0/0").unwrap();
+
+        assert_eq!(account.address_segwit(Network::Testnet), "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl");
+        assert_eq!(account.address_segwit(Network::Signet), account.address_segwit(Network::Testnet));
+
+        let regtest = account.address_segwit(Network::Regtest);
+        assert!(regtest.starts_with("bcrt1q"));
+        assert!(crate::validate::validate_bitcoin_address(&regtest, Network::Regtest).is_ok());
+        assert!(account.address_taproot(Network::Regtest).unwrap().starts_with("bcrt1p"));
+
+        for network in Network::ALL {
+            assert_eq!(Network::from_name(network.name()), Some(network));
+            let legacy = account.address_legacy(network);
+            assert!(crate::validate::validate_bitcoin_address(&legacy, network).is_ok());
+            assert_eq!(legacy.starts_with('1'), network.is_mainnet());
+
+            // 압축 WIF: 메인넷 K/L, 테스트 네트워크 c
+            let wif = account.private_key_wif(network, true);
+            assert_eq!(wif.starts_with('c'), !network.is_mainnet());
+        }
+        assert_eq!(Network::from_name("Main"), Some(Network::Mainnet));
+        assert_eq!(Network::from_name("testnet4"), Some(Network::Testnet));
+        assert_eq!(Network::from_name("devnet"), None);
+
+        // 확장 키: 테스트 네트워크는 모두 tpub
+        let key = master_key_from_seed(mnemonic_to_seed(mnemonic, "").as_slice()).unwrap().to_public();
+        assert_eq!(key.to_base58(Network::Regtest), key.to_base58(Network::Testnet));
+        let watch = BitcoinWatchAccount::from_xpub(&key.to_base58(Network::Regtest)).unwrap().with_network(Network::Regtest);
+        assert!(watch.address(0).unwrap().starts_with("bcrt1q"));
+    }
+
+    #[test]
+    fn test_schnorr() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = BitcoinAccount::from_seed_with_path(mnemonic_to_seed(mnemonic, "").as_slice(), "m/86'/0'/0'/0/0").unwrap();
+
+        // BIP-86 내부 키
+        assert_eq!(
+            hex::encode(account.x_only_public_key()),
+            "cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115"
+        );
+
+        let message = [0x42u8; 32];
+        let signature = account.sign_schnorr(&message).unwrap();
+        assert!(schnorr::verify(&account.x_only_public_key(), &message, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_taproot_address() {
+        // BIP-86 테스트 벡터 m/86'/0'/0'/0/0
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = BitcoinAccount::from_seed_with_path(mnemonic_to_seed(mnemonic, "").as_slice(), "m/86'/0'/0'/0/0").unwrap();
+        assert_eq!(
+            account.address_taproot(Network::Mainnet).unwrap(),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+
+        // 직접 만든 계정의 공개키가 유효하지 않으면 패닉 대신 에러 (x ≥ p)
+        let mut public_key = [0xff; 33];
+        public_key[0] = 0x02;
+        let invalid = BitcoinAccount { public_key, ..account.clone() };
+        assert!(matches!(invalid.address_taproot(Network::Mainnet), Err(Error::InvalidKey(_))));
+
+        // 주소에 들어가는 출력 키와 같고, 조정된 개인키로 서명하면 출력 키로 검증됨
+        let output = account.taproot_output_key(None).unwrap();
+        assert_eq!(hex::encode(output.output_key), "a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c");
+        assert_eq!(output.internal_key, account.x_only_public_key());
+
+        let root = [7u8; 32];
+        let output = account.taproot_output_key(Some(&root)).unwrap();
+        let tweaked = account.taproot_tweaked_private_key(Some(&root)).unwrap();
+        let message = [1u8; 32];
+        let signature = schnorr::sign(&tweaked, &message, None).unwrap();
+        assert!(schnorr::verify(&output.output_key, &message, &signature).is_ok());
+    }
 }
@@ -0,0 +1,970 @@
+//! PSBT (Partially Signed Bitcoin Transaction)
+//!
+//! 서명 전 트랜잭션과 서명에 필요한 정보를 담아 지갑 사이에 주고받는 형식
+//! (에어갭 지갑: 온라인 지갑이 PSBT 생성 → 오프라인 기기가 서명 → 온라인 지갑이 완성/전파)
+//!
+//! ## 구조 (BIP-174)
+//! ```text
+//! "psbt" 0xff || 전역 맵 || 입력 맵* || 출력 맵*
+//! 맵 = (키 길이 || 키 타입 || 키 데이터 || 값 길이 || 값)* || 0x00
+//! ```
+//! - v0 (BIP-174): 전역 맵에 서명 전 트랜잭션 전체 (`PSBT_GLOBAL_UNSIGNED_TX`)
+//! - v2 (BIP-370): 트랜잭션을 필드로 쪼개 전역/입력/출력 맵에 나눠 담음
+//!
+//! 모르는 키도 그대로 보존 (다른 지갑이 넣은 정보를 지우지 않음)
+//!
+//! ## 서명 (Signer 역할)
+//! 입력의 키 출처 `[마스터 지문/경로]`가 이 지갑의 마스터 지문과 같으면
+//! 경로대로 키를 도출해 부분 서명을 추가 (완성(finalize)은 하지 않음)
+//! 도출한 공개키가 PSBT의 공개키와 다르면 그 키는 건너뜀 → 여러 서명자가 한 PSBT를 차례로 서명
+//!
+//! | 이전 출력 | 필요한 필드 | 결과 |
+//! |----------|------------|------|
+//! | P2PKH, P2SH | `NON_WITNESS_UTXO` (필수), (`REDEEM_SCRIPT`) | `PARTIAL_SIG` |
+//! | P2WPKH, P2WSH | `WITNESS_UTXO`, (`WITNESS_SCRIPT`) | `PARTIAL_SIG` |
+//! | P2SH-P2WPKH, P2SH-P2WSH | 위 + `REDEEM_SCRIPT` | `PARTIAL_SIG` |
+//! | P2TR 키 경로 | 모든 입력의 UTXO, `TAP_INTERNAL_KEY` | `TAP_KEY_SIG` |
+//!
+//! SegWit이 아닌 입력은 금액이 sighash에 들어가지 않으므로 `WITNESS_UTXO`만으로는 서명하지 않음
+//! (`NON_WITNESS_UTXO`의 txid를 확인해야 금액을 속이는 수수료 공격을 막음)
+//!
+//! sighash는 SIGHASH_ALL (Taproot은 SIGHASH_DEFAULT)만 지원
+
+use std::collections::BTreeMap;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use secp256k1::{Message, SecretKey};
+use sha2::{Digest, Sha256};
+
+use crate::hash::hash160;
+use super::transaction::{
+    write_bytes, write_txout, OutPoint, Reader, Transaction, TxIn, TxOut, SIGHASH_ALL,
+    SIGHASH_DEFAULT,
+};
+use crate::bip32::{ChildNumber, DerivationPath, ExtendedPrivateKey};
+use crate::ecdsa;
+use crate::schnorr;
+use crate::error::{Error, Result};
+
+/// PSBT 매직 바이트 ("psbt" 0xff)
+pub const PSBT_MAGIC: &[u8; 5] = b"psbt\xff";
+
+// 전역 키 타입
+pub const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+pub const PSBT_GLOBAL_XPUB: u8 = 0x01;
+pub const PSBT_GLOBAL_TX_VERSION: u8 = 0x02;
+pub const PSBT_GLOBAL_FALLBACK_LOCKTIME: u8 = 0x03;
+pub const PSBT_GLOBAL_INPUT_COUNT: u8 = 0x04;
+pub const PSBT_GLOBAL_OUTPUT_COUNT: u8 = 0x05;
+pub const PSBT_GLOBAL_VERSION: u8 = 0xfb;
+
+// 입력 키 타입
+pub const PSBT_IN_NON_WITNESS_UTXO: u8 = 0x00;
+pub const PSBT_IN_WITNESS_UTXO: u8 = 0x01;
+pub const PSBT_IN_PARTIAL_SIG: u8 = 0x02;
+pub const PSBT_IN_SIGHASH_TYPE: u8 = 0x03;
+pub const PSBT_IN_REDEEM_SCRIPT: u8 = 0x04;
+pub const PSBT_IN_WITNESS_SCRIPT: u8 = 0x05;
+pub const PSBT_IN_BIP32_DERIVATION: u8 = 0x06;
+pub const PSBT_IN_FINAL_SCRIPTSIG: u8 = 0x07;
+pub const PSBT_IN_FINAL_SCRIPTWITNESS: u8 = 0x08;
+pub const PSBT_IN_PREVIOUS_TXID: u8 = 0x0e;
+pub const PSBT_IN_OUTPUT_INDEX: u8 = 0x0f;
+pub const PSBT_IN_SEQUENCE: u8 = 0x10;
+pub const PSBT_IN_REQUIRED_TIME_LOCKTIME: u8 = 0x11;
+pub const PSBT_IN_REQUIRED_HEIGHT_LOCKTIME: u8 = 0x12;
+pub const PSBT_IN_TAP_KEY_SIG: u8 = 0x13;
+pub const PSBT_IN_TAP_BIP32_DERIVATION: u8 = 0x16;
+pub const PSBT_IN_TAP_INTERNAL_KEY: u8 = 0x17;
+pub const PSBT_IN_TAP_MERKLE_ROOT: u8 = 0x18;
+
+// 출력 키 타입
+pub const PSBT_OUT_REDEEM_SCRIPT: u8 = 0x00;
+pub const PSBT_OUT_WITNESS_SCRIPT: u8 = 0x01;
+pub const PSBT_OUT_BIP32_DERIVATION: u8 = 0x02;
+pub const PSBT_OUT_AMOUNT: u8 = 0x03;
+pub const PSBT_OUT_SCRIPT: u8 = 0x04;
+
+/// 키 → 값 맵 (키 = 키 타입 || 키 데이터)
+///
+/// 직렬화 시 키 순서로 정렬, 같은 키가 두 번 나오면 파싱 에러
+pub type PsbtMap = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// 맵에 추가할 (키, 값)
+type KeyValue = (Vec<u8>, Vec<u8>);
+
+/// PSBT (v0, v2)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Psbt {
+    /// 전역 맵
+    pub global: PsbtMap,
+    /// 입력별 맵 (트랜잭션 입력 순서)
+    pub inputs: Vec<PsbtMap>,
+    /// 출력별 맵 (트랜잭션 출력 순서)
+    pub outputs: Vec<PsbtMap>,
+}
+
+impl Psbt {
+    /// 서명 전 트랜잭션으로 v0 PSBT 생성 (Creator 역할)
+    ///
+    /// scriptSig와 witness는 비어 있어야 함
+    pub fn from_unsigned_tx(transaction: &Transaction) -> Result<Self> {
+        check_unsigned(transaction)?;
+
+        let mut global = PsbtMap::new();
+        global.insert(vec![PSBT_GLOBAL_UNSIGNED_TX], transaction.serialize_without_witness());
+
+        Ok(Psbt {
+            global,
+            inputs: vec![PsbtMap::new(); transaction.inputs.len()],
+            outputs: vec![PsbtMap::new(); transaction.outputs.len()],
+        })
+    }
+
+    /// 바이너리 PSBT 파싱
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data);
+        if reader.read_bytes(PSBT_MAGIC.len()).ok() != Some(&PSBT_MAGIC[..]) {
+            return Err(Error::Transaction("PSBT 매직 바이트가 없습니다".to_string()));
+        }
+
+        let global = read_map(&mut reader)?;
+        let mut psbt = Psbt { global, inputs: Vec::new(), outputs: Vec::new() };
+
+        let (input_count, output_count) = match psbt.version()? {
+            0 => {
+                for key in [PSBT_GLOBAL_TX_VERSION, PSBT_GLOBAL_INPUT_COUNT, PSBT_GLOBAL_OUTPUT_COUNT] {
+                    if psbt.global.contains_key(&vec![key]) {
+                        return Err(Error::Transaction(format!("PSBT v0에 v2 전용 필드가 있습니다: 0x{:02x}", key)));
+                    }
+                }
+                let transaction = psbt.unsigned_tx_v0()?;
+                check_unsigned(&transaction)?;
+                (transaction.inputs.len() as u64, transaction.outputs.len() as u64)
+            }
+            _ => {
+                if psbt.global.contains_key(&vec![PSBT_GLOBAL_UNSIGNED_TX]) {
+                    return Err(Error::Transaction("PSBT v2에는 PSBT_GLOBAL_UNSIGNED_TX가 없어야 합니다".to_string()));
+                }
+                (
+                    global_compact_size(&psbt.global, PSBT_GLOBAL_INPUT_COUNT)?,
+                    global_compact_size(&psbt.global, PSBT_GLOBAL_OUTPUT_COUNT)?,
+                )
+            }
+        };
+
+        for _ in 0..input_count {
+            psbt.inputs.push(read_map(&mut reader)?);
+        }
+        for _ in 0..output_count {
+            psbt.outputs.push(read_map(&mut reader)?);
+        }
+        reader.finish()?;
+
+        // v2 필수 필드 확인
+        psbt.unsigned_tx()?;
+        Ok(psbt)
+    }
+
+    /// 바이너리 직렬화
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = PSBT_MAGIC.to_vec();
+        write_map(&mut out, &self.global);
+        for map in self.inputs.iter().chain(&self.outputs) {
+            write_map(&mut out, map);
+        }
+        out
+    }
+
+    /// base64 PSBT 파싱 (지갑 간 교환에 주로 쓰는 형식)
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let data = BASE64
+            .decode(encoded.trim())
+            .map_err(|e| Error::Transaction(format!("유효하지 않은 base64: {}", e)))?;
+        Self::deserialize(&data)
+    }
+
+    /// base64로 직렬화
+    pub fn to_base64(&self) -> String {
+        BASE64.encode(self.serialize())
+    }
+
+    /// PSBT 버전 (0 또는 2)
+    pub fn version(&self) -> Result<u32> {
+        let version = match self.global.get(&vec![PSBT_GLOBAL_VERSION]) {
+            Some(value) => u32_value(value, "PSBT_GLOBAL_VERSION")?,
+            None => 0,
+        };
+
+        match version {
+            0 | 2 => Ok(version),
+            _ => Err(Error::Transaction(format!("지원하지 않는 PSBT 버전: {}", version))),
+        }
+    }
+
+    /// 서명할 트랜잭션 (v2는 필드를 모아서 구성)
+    pub fn unsigned_tx(&self) -> Result<Transaction> {
+        match self.version()? {
+            0 => self.unsigned_tx_v0(),
+            _ => self.unsigned_tx_v2(),
+        }
+    }
+
+    /// 마스터 키로 서명할 수 있는 입력에 부분 서명 추가 (Signer 역할)
+    ///
+    /// 키 출처의 지문이 마스터 지문과 같은 키만 도출해서 서명
+    /// 이미 완성된 입력(`FINAL_SCRIPTSIG`, `FINAL_SCRIPTWITNESS`)과
+    /// 도출한 공개키가 다른 키(다른 서명자의 키)는 건너뜀
+    ///
+    /// 반환값: 추가한 서명 수
+    pub fn sign(&mut self, master: &ExtendedPrivateKey) -> Result<usize> {
+        let transaction = self.unsigned_tx()?;
+        let fingerprint = master.fingerprint();
+
+        let prevouts = (0..self.inputs.len())
+            .map(|index| self.spent_output(index, &transaction))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut signed = 0;
+        for index in 0..self.inputs.len() {
+            let input = &self.inputs[index];
+            if input.contains_key(&vec![PSBT_IN_FINAL_SCRIPTSIG]) || input.contains_key(&vec![PSBT_IN_FINAL_SCRIPTWITNESS]) {
+                continue;
+            }
+
+            let mut signatures = Vec::new();
+            for (key, value) in input {
+                match key.first() {
+                    Some(&PSBT_IN_BIP32_DERIVATION) => {
+                        let (source_fingerprint, path) = parse_key_source(value)?;
+                        if source_fingerprint == fingerprint {
+                            signatures.extend(self.sign_ecdsa_input(index, &transaction, &prevouts, &key[1..], master, &path)?);
+                        }
+                    }
+                    Some(&PSBT_IN_TAP_BIP32_DERIVATION) => {
+                        // leaf 해시 목록 || 키 출처
+                        let mut reader = Reader::new(value);
+                        let leaf_count = reader.read_compact_size()?;
+                        for _ in 0..leaf_count {
+                            reader.read_bytes(32)?;
+                        }
+                        let (source_fingerprint, path) = parse_key_source(reader.read_remaining())?;
+                        if source_fingerprint == fingerprint {
+                            signatures.extend(self.sign_taproot_input(index, &transaction, &prevouts, &key[1..], master, &path)?);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            for (key, value) in signatures {
+                self.inputs[index].insert(key, value);
+                signed += 1;
+            }
+        }
+
+        Ok(signed)
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // 트랜잭션 구성
+    // ═══════════════════════════════════════════════════════════════
+
+    fn unsigned_tx_v0(&self) -> Result<Transaction> {
+        let bytes = self
+            .global
+            .get(&vec![PSBT_GLOBAL_UNSIGNED_TX])
+            .ok_or_else(|| Error::Transaction("PSBT v0에 PSBT_GLOBAL_UNSIGNED_TX가 없습니다".to_string()))?;
+        Transaction::deserialize(bytes)
+    }
+
+    fn unsigned_tx_v2(&self) -> Result<Transaction> {
+        let version = self
+            .global
+            .get(&vec![PSBT_GLOBAL_TX_VERSION])
+            .ok_or_else(|| Error::Transaction("PSBT v2에 PSBT_GLOBAL_TX_VERSION이 없습니다".to_string()))?;
+        let version = u32_value(version, "PSBT_GLOBAL_TX_VERSION")?;
+
+        let mut inputs = Vec::new();
+        for (index, input) in self.inputs.iter().enumerate() {
+            let missing = |name: &str| Error::Transaction(format!("입력 {}에 {}가 없습니다", index, name));
+            let txid = input.get(&vec![PSBT_IN_PREVIOUS_TXID]).ok_or_else(|| missing("PSBT_IN_PREVIOUS_TXID"))?;
+            let vout = input.get(&vec![PSBT_IN_OUTPUT_INDEX]).ok_or_else(|| missing("PSBT_IN_OUTPUT_INDEX"))?;
+            let sequence = match input.get(&vec![PSBT_IN_SEQUENCE]) {
+                Some(value) => u32_value(value, "PSBT_IN_SEQUENCE")?,
+                None => 0xffffffff,
+            };
+
+            inputs.push(TxIn {
+                previous_output: OutPoint {
+                    txid: txid.as_slice().try_into().map_err(|_| Error::Transaction("PSBT_IN_PREVIOUS_TXID는 32바이트여야 합니다".to_string()))?,
+                    vout: u32_value(vout, "PSBT_IN_OUTPUT_INDEX")?,
+                },
+                script_sig: Vec::new(),
+                sequence,
+                witness: Vec::new(),
+            });
+        }
+
+        let mut outputs = Vec::new();
+        for (index, output) in self.outputs.iter().enumerate() {
+            let missing = |name: &str| Error::Transaction(format!("출력 {}에 {}가 없습니다", index, name));
+            let amount = output.get(&vec![PSBT_OUT_AMOUNT]).ok_or_else(|| missing("PSBT_OUT_AMOUNT"))?;
+            let script = output.get(&vec![PSBT_OUT_SCRIPT]).ok_or_else(|| missing("PSBT_OUT_SCRIPT"))?;
+
+            outputs.push(TxOut {
+                value: u64::from_le_bytes(
+                    amount.as_slice().try_into().map_err(|_| Error::Transaction("PSBT_OUT_AMOUNT는 8바이트여야 합니다".to_string()))?,
+                ),
+                script_pubkey: script.clone(),
+            });
+        }
+
+        Ok(Transaction { version, inputs, outputs, lock_time: self.lock_time_v2()? })
+    }
+
+    /// BIP-370 잠금 시간 결정
+    ///
+    /// 입력이 요구하는 잠금 시간이 없으면 fallback, 있으면 모든 요구 입력이 지원하는 종류
+    /// (블록 높이 우선) 중 가장 큰 값
+    fn lock_time_v2(&self) -> Result<u32> {
+        let mut heights = Vec::new();
+        let mut times = Vec::new();
+        let mut required = 0;
+
+        for input in &self.inputs {
+            let height = input.get(&vec![PSBT_IN_REQUIRED_HEIGHT_LOCKTIME]);
+            let time = input.get(&vec![PSBT_IN_REQUIRED_TIME_LOCKTIME]);
+            if height.is_some() || time.is_some() {
+                required += 1;
+            }
+            if let Some(value) = height {
+                heights.push(u32_value(value, "PSBT_IN_REQUIRED_HEIGHT_LOCKTIME")?);
+            }
+            if let Some(value) = time {
+                times.push(u32_value(value, "PSBT_IN_REQUIRED_TIME_LOCKTIME")?);
+            }
+        }
+
+        if required == 0 {
+            return match self.global.get(&vec![PSBT_GLOBAL_FALLBACK_LOCKTIME]) {
+                Some(value) => u32_value(value, "PSBT_GLOBAL_FALLBACK_LOCKTIME"),
+                None => Ok(0),
+            };
+        }
+
+        if heights.len() == required {
+            Ok(heights.into_iter().max().unwrap_or(0))
+        } else if times.len() == required {
+            Ok(times.into_iter().max().unwrap_or(0))
+        } else {
+            Err(Error::Transaction("입력들이 요구하는 잠금 시간 종류가 서로 맞지 않습니다".to_string()))
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // 입력 서명
+    // ═══════════════════════════════════════════════════════════════
+
+    /// 입력이 사용하는 이전 출력
+    ///
+    /// `NON_WITNESS_UTXO`가 있으면 txid를 확인하고 그 출력을 사용 (`WITNESS_UTXO`도 있으면 같은지 확인)
+    /// 없으면 `WITNESS_UTXO` (SegWit 입력만 서명 가능, `sign_ecdsa_input`에서 확인)
+    fn spent_output(&self, index: usize, transaction: &Transaction) -> Result<Option<TxOut>> {
+        let input = &self.inputs[index];
+
+        let witness_utxo = match input.get(&vec![PSBT_IN_WITNESS_UTXO]) {
+            Some(value) => {
+                let mut reader = Reader::new(value);
+                let output = TxOut { value: reader.read_u64()?, script_pubkey: reader.read_var_bytes()? };
+                reader.finish()?;
+                Some(output)
+            }
+            None => None,
+        };
+
+        if let Some(value) = input.get(&vec![PSBT_IN_NON_WITNESS_UTXO]) {
+            let previous = Transaction::deserialize(value)?;
+            let outpoint = &transaction.inputs[index].previous_output;
+            if previous.txid() != outpoint.txid {
+                return Err(Error::Transaction(format!("입력 {}의 NON_WITNESS_UTXO가 참조하는 트랜잭션과 다릅니다", index)));
+            }
+            let output = previous
+                .outputs
+                .get(outpoint.vout as usize)
+                .ok_or_else(|| Error::Transaction(format!("입력 {}의 출력 인덱스 범위 초과: {}", index, outpoint.vout)))?;
+            if witness_utxo.as_ref().is_some_and(|witness_utxo| witness_utxo != output) {
+                return Err(Error::Transaction(format!("입력 {}의 WITNESS_UTXO가 NON_WITNESS_UTXO의 출력과 다릅니다", index)));
+            }
+            return Ok(Some(output.clone()));
+        }
+
+        Ok(witness_utxo)
+    }
+
+    /// ECDSA 부분 서명 (P2PKH, P2SH, P2WPKH, P2WSH)
+    fn sign_ecdsa_input(
+        &self,
+        index: usize,
+        transaction: &Transaction,
+        prevouts: &[Option<TxOut>],
+        public_key: &[u8],
+        master: &ExtendedPrivateKey,
+        path: &DerivationPath,
+    ) -> Result<Option<KeyValue>> {
+        let input = &self.inputs[index];
+        let prevout = prevouts[index]
+            .as_ref()
+            .ok_or_else(|| Error::Transaction(format!("입력 {}에 이전 출력 정보(UTXO)가 없습니다", index)))?;
+        if is_p2tr(&prevout.script_pubkey) {
+            return Ok(None);
+        }
+
+        let Some(key) = derive_key(master, path, public_key)? else {
+            return Ok(None);
+        };
+        let sighash_type = self.sighash_type(index, SIGHASH_ALL)?;
+
+        // P2SH는 redeem script로 교체
+        let mut script = prevout.script_pubkey.clone();
+        if is_p2sh(&script) {
+            let redeem_script = input
+                .get(&vec![PSBT_IN_REDEEM_SCRIPT])
+                .ok_or_else(|| Error::Transaction(format!("입력 {}에 PSBT_IN_REDEEM_SCRIPT가 없습니다", index)))?;
+            if hash160(redeem_script)[..] != script[2..22] {
+                return Err(Error::Transaction(format!("입력 {}의 redeem script 해시가 맞지 않습니다", index)));
+            }
+            script = redeem_script.clone();
+        }
+
+        let sighash = if is_p2wpkh(&script) {
+            let mut script_code = vec![0x76, 0xa9, 0x14];
+            script_code.extend_from_slice(&script[2..22]);
+            script_code.extend_from_slice(&[0x88, 0xac]);
+            transaction.segwit_v0_sighash(index, &script_code, prevout.value, sighash_type)?
+        } else if is_p2wsh(&script) {
+            let witness_script = input
+                .get(&vec![PSBT_IN_WITNESS_SCRIPT])
+                .ok_or_else(|| Error::Transaction(format!("입력 {}에 PSBT_IN_WITNESS_SCRIPT가 없습니다", index)))?;
+            if Sha256::digest(witness_script)[..] != script[2..34] {
+                return Err(Error::Transaction(format!("입력 {}의 witness script 해시가 맞지 않습니다", index)));
+            }
+            transaction.segwit_v0_sighash(index, witness_script, prevout.value, sighash_type)?
+        } else {
+            // 금액이 sighash에 없으므로 txid로 확인한 이전 트랜잭션이 있어야 함
+            if !input.contains_key(&vec![PSBT_IN_NON_WITNESS_UTXO]) {
+                return Err(Error::Transaction(format!("입력 {}은 SegWit이 아니므로 PSBT_IN_NON_WITNESS_UTXO가 필요합니다", index)));
+            }
+            transaction.legacy_sighash(index, &script, sighash_type)?
+        };
+
+        let secret = SecretKey::from_slice(key.private_key.as_slice()).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 개인키".to_string()))?;
+        let signature = ecdsa::context().sign_ecdsa_low_r(&Message::from_digest(sighash), &secret);
+
+        let mut key = vec![PSBT_IN_PARTIAL_SIG];
+        key.extend_from_slice(public_key);
+        let mut value = signature.serialize_der().to_vec();
+        value.push(sighash_type);
+        Ok(Some((key, value)))
+    }
+
+    /// Taproot 키 경로 서명
+    fn sign_taproot_input(
+        &self,
+        index: usize,
+        transaction: &Transaction,
+        prevouts: &[Option<TxOut>],
+        x_only_public_key: &[u8],
+        master: &ExtendedPrivateKey,
+        path: &DerivationPath,
+    ) -> Result<Option<KeyValue>> {
+        let input = &self.inputs[index];
+
+        // 내부 키가 아니면 스크립트 경로 키 (미지원)
+        if input.get(&vec![PSBT_IN_TAP_INTERNAL_KEY]).map(Vec::as_slice) != Some(x_only_public_key) {
+            return Ok(None);
+        }
+
+        let Some(key) = derive_key(master, path, x_only_public_key)? else {
+            return Ok(None);
+        };
+        let sighash_type = self.sighash_type(index, SIGHASH_DEFAULT)?;
+        let merkle_root: Option<[u8; 32]> = match input.get(&vec![PSBT_IN_TAP_MERKLE_ROOT]) {
+            Some(root) => Some(root.as_slice().try_into().map_err(|_| Error::Transaction("PSBT_IN_TAP_MERKLE_ROOT는 32바이트여야 합니다".to_string()))?),
+            None => None,
+        };
+
+        let prevouts = prevouts
+            .iter()
+            .cloned()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Error::Transaction("Taproot 서명에는 모든 입력의 이전 출력 정보(UTXO)가 필요합니다".to_string()))?;
+
+        // 출력 키가 이전 출력 스크립트와 같은지 확인
+        let internal_key: [u8; 32] = x_only_public_key.try_into().map_err(|_| Error::Transaction("x-only 공개키는 32바이트여야 합니다".to_string()))?;
+        let (output_key, _) = schnorr::taproot_tweak_public_key(&internal_key, merkle_root.as_ref())?;
+        if prevouts[index].script_pubkey[..] != p2tr_script(&output_key)[..] {
+            return Err(Error::Transaction(format!("입력 {}의 Taproot 출력 키가 이전 출력과 다릅니다", index)));
+        }
+
+        let sighash = transaction.taproot_key_spend_sighash(index, &prevouts, sighash_type)?;
+        let tweaked = schnorr::taproot_tweak_private_key(&key.private_key, merkle_root.as_ref())?;
+
+        let mut signature = schnorr::sign(&tweaked, &sighash, None)?.to_vec();
+        if sighash_type != SIGHASH_DEFAULT {
+            signature.push(sighash_type);
+        }
+        Ok(Some((vec![PSBT_IN_TAP_KEY_SIG], signature)))
+    }
+
+    /// 입력의 sighash 타입 (없으면 기본값)
+    fn sighash_type(&self, index: usize, default: u8) -> Result<u8> {
+        match self.inputs[index].get(&vec![PSBT_IN_SIGHASH_TYPE]) {
+            Some(value) => {
+                let sighash_type = u32_value(value, "PSBT_IN_SIGHASH_TYPE")?;
+                u8::try_from(sighash_type).map_err(|_| Error::Transaction(format!("지원하지 않는 sighash 타입: 0x{:x}", sighash_type)))
+            }
+            None => Ok(default),
+        }
+    }
+}
+
+impl std::str::FromStr for Psbt {
+    type Err = Error;
+
+    /// base64 PSBT 파싱
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        Self::from_base64(encoded)
+    }
+}
+
+impl std::fmt::Display for Psbt {
+    /// base64 PSBT
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_base64())
+    }
+}
+
+/// `PSBT_IN_WITNESS_UTXO` 값 (금액 || scriptPubKey)
+pub fn encode_witness_utxo(output: &TxOut) -> Vec<u8> {
+    let mut value = Vec::new();
+    write_txout(&mut value, output);
+    value
+}
+
+/// 키 출처 값 (마스터 지문 || 경로 u32 리틀엔디언*)
+pub fn encode_key_source(fingerprint: [u8; 4], path: &DerivationPath) -> Vec<u8> {
+    let mut value = fingerprint.to_vec();
+    for child in path {
+        value.extend_from_slice(&child.to_u32().to_le_bytes());
+    }
+    value
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn read_map(reader: &mut Reader) -> Result<PsbtMap> {
+    let mut map = PsbtMap::new();
+
+    loop {
+        let key = reader.read_var_bytes()?;
+        if key.is_empty() {
+            return Ok(map);
+        }
+        let value = reader.read_var_bytes()?;
+        if map.contains_key(&key) {
+            return Err(Error::Transaction(format!("중복된 PSBT 키: {}", hex::encode(&key))));
+        }
+        map.insert(key, value);
+    }
+}
+
+fn write_map(out: &mut Vec<u8>, map: &PsbtMap) {
+    for (key, value) in map {
+        write_bytes(out, key);
+        write_bytes(out, value);
+    }
+    out.push(0x00);
+}
+
+fn check_unsigned(transaction: &Transaction) -> Result<()> {
+    if transaction
+        .inputs
+        .iter()
+        .any(|input| !input.script_sig.is_empty() || !input.witness.is_empty())
+    {
+        return Err(Error::Transaction("서명 전 트랜잭션의 scriptSig와 witness는 비어 있어야 합니다".to_string()));
+    }
+    Ok(())
+}
+
+fn global_compact_size(global: &PsbtMap, key: u8) -> Result<u64> {
+    let value = global
+        .get(&vec![key])
+        .ok_or_else(|| Error::Transaction(format!("PSBT v2에 전역 필드 0x{:02x}가 없습니다", key)))?;
+    let mut reader = Reader::new(value);
+    let count = reader.read_compact_size()?;
+    reader.finish()?;
+    Ok(count)
+}
+
+fn u32_value(value: &[u8], name: &str) -> Result<u32> {
+    let bytes: [u8; 4] = value.try_into().map_err(|_| Error::Transaction(format!("{}는 4바이트여야 합니다", name)))?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// 키 출처 파싱 → (마스터 지문, 경로)
+fn parse_key_source(value: &[u8]) -> Result<([u8; 4], DerivationPath)> {
+    if value.len() < 4 || !(value.len() - 4).is_multiple_of(4) {
+        return Err(Error::Transaction(format!("유효하지 않은 키 출처 길이: {}바이트", value.len())));
+    }
+
+    let fingerprint = value[..4].try_into().expect("4바이트");
+    let path: Vec<ChildNumber> = value[4..]
+        .chunks_exact(4)
+        .map(|chunk| ChildNumber::from_u32(u32::from_le_bytes(chunk.try_into().expect("4바이트"))))
+        .collect();
+    Ok((fingerprint, DerivationPath::from(path)))
+}
+
+/// 경로대로 도출한 키의 공개키가 PSBT에 적힌 공개키(압축 33바이트 또는 x-only 32바이트)와 같으면 그 키
+///
+/// 다르면 `None` (지문만 같은 다른 서명자의 키)
+fn derive_key(master: &ExtendedPrivateKey, path: &DerivationPath, public_key: &[u8]) -> Result<Option<ExtendedPrivateKey>> {
+    let key = master.derive_path(path)?;
+    let derived = key.public_key();
+
+    let matches = match public_key.len() {
+        33 => derived[..] == *public_key,
+        32 => derived[1..] == *public_key,
+        _ => false,
+    };
+    Ok(matches.then_some(key))
+}
+
+fn is_p2sh(script: &[u8]) -> bool {
+    script.len() == 23 && script[0] == 0xa9 && script[1] == 0x14 && script[22] == 0x87
+}
+
+fn is_p2wpkh(script: &[u8]) -> bool {
+    script.len() == 22 && script[0] == 0x00 && script[1] == 0x14
+}
+
+fn is_p2wsh(script: &[u8]) -> bool {
+    script.len() == 34 && script[0] == 0x00 && script[1] == 0x20
+}
+
+fn is_p2tr(script: &[u8]) -> bool {
+    script.len() == 34 && script[0] == 0x51 && script[1] == 0x20
+}
+
+fn p2tr_script(output_key: &[u8; 32]) -> Vec<u8> {
+    let mut script = vec![0x51, 0x20];
+    script.extend_from_slice(output_key);
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip32::master_key_from_seed;
+    use crate::bip39::mnemonic_to_seed;
+    use secp256k1::PublicKey;
+
+    fn master() -> ExtendedPrivateKey {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        master_key_from_seed(mnemonic_to_seed(mnemonic, "").as_slice()).unwrap()
+    }
+
+    /// 이전 출력 하나를 쓰는 서명 전 트랜잭션
+    fn unsigned_tx(previous_txid: [u8; 32]) -> Transaction {
+        Transaction {
+            version: 2,
+            inputs: vec![TxIn {
+                previous_output: OutPoint { txid: previous_txid, vout: 0 },
+                script_sig: Vec::new(),
+                sequence: 0xfffffffd,
+                witness: Vec::new(),
+            }],
+            outputs: vec![TxOut { value: 90_000, script_pubkey: hex::decode("0014751e76e8199196d454941c45d1b3a323f1433bd6").unwrap() }],
+            lock_time: 0,
+        }
+    }
+
+    fn bip32_derivation(public_key: &[u8], fingerprint: [u8; 4], path: &DerivationPath) -> (Vec<u8>, Vec<u8>) {
+        let mut key = vec![PSBT_IN_BIP32_DERIVATION];
+        key.extend_from_slice(public_key);
+        (key, encode_key_source(fingerprint, path))
+    }
+
+    #[test]
+    fn test_sign_p2wpkh() {
+        let master = master();
+        let path = DerivationPath::bip84(0, 0, 0, 0);
+        let key = master.derive_path(&path).unwrap();
+        let public_key = key.public_key();
+
+        let mut script_pubkey = vec![0x00, 0x14];
+        script_pubkey.extend_from_slice(&hash160(&public_key));
+        let utxo = TxOut { value: 100_000, script_pubkey };
+
+        let transaction = unsigned_tx([0x11; 32]);
+        let mut psbt = Psbt::from_unsigned_tx(&transaction).unwrap();
+        psbt.inputs[0].insert(vec![PSBT_IN_WITNESS_UTXO], encode_witness_utxo(&utxo));
+        let (derivation_key, source) = bip32_derivation(&public_key, master.fingerprint(), &path);
+        psbt.inputs[0].insert(derivation_key, source);
+
+        // 직렬화 왕복
+        let encoded = psbt.to_base64();
+        println!("서명 전 PSBT: {}", encoded);
+        let mut parsed: Psbt = encoded.parse().unwrap();
+        assert_eq!(parsed, psbt);
+
+        assert_eq!(parsed.sign(&master).unwrap(), 1);
+        let mut sig_key = vec![PSBT_IN_PARTIAL_SIG];
+        sig_key.extend_from_slice(&public_key);
+        let signature = parsed.inputs[0].get(&sig_key).unwrap();
+        assert_eq!(*signature.last().unwrap(), SIGHASH_ALL);
+
+        // BIP-143 sighash로 검증
+        let mut script_code = vec![0x76, 0xa9, 0x14];
+        script_code.extend_from_slice(&hash160(&public_key));
+        script_code.extend_from_slice(&[0x88, 0xac]);
+        let sighash = transaction.segwit_v0_sighash(0, &script_code, utxo.value, SIGHASH_ALL).unwrap();
+        let der = secp256k1::ecdsa::Signature::from_der(&signature[..signature.len() - 1]).unwrap();
+        ecdsa::context()
+            .verify_ecdsa(&Message::from_digest(sighash), &der, &PublicKey::from_slice(&public_key).unwrap())
+            .unwrap();
+
+        // 다른 지갑은 서명하지 않음
+        let other = master_key_from_seed(&[7u8; 32]).unwrap();
+        let mut untouched = psbt.clone();
+        assert_eq!(untouched.sign(&other).unwrap(), 0);
+        assert_eq!(untouched, psbt);
+
+        // 지문은 같은데 공개키가 다르면 (다른 서명자의 키) 건너뜀
+        let mut other_signer = psbt.clone();
+        let (derivation_key, source) = bip32_derivation(&public_key, master.fingerprint(), &DerivationPath::bip84(0, 0, 0, 1));
+        other_signer.inputs[0] = PsbtMap::from([(derivation_key, source)]);
+        other_signer.inputs[0].insert(vec![PSBT_IN_WITNESS_UTXO], encode_witness_utxo(&utxo));
+        let before = other_signer.clone();
+        assert_eq!(other_signer.sign(&master).unwrap(), 0);
+        assert_eq!(other_signer, before);
+    }
+
+    #[test]
+    fn test_sign_p2pkh_non_witness_utxo() {
+        let master = master();
+        let path = DerivationPath::bip44(0, 0, 0, 0);
+        let public_key = master.derive_path(&path).unwrap().public_key();
+
+        let mut script_pubkey = vec![0x76, 0xa9, 0x14];
+        script_pubkey.extend_from_slice(&hash160(&public_key));
+        script_pubkey.extend_from_slice(&[0x88, 0xac]);
+        let previous = Transaction {
+            version: 1,
+            inputs: vec![TxIn {
+                previous_output: OutPoint { txid: [0x22; 32], vout: 1 },
+                script_sig: vec![0x51],
+                sequence: 0xffffffff,
+                witness: Vec::new(),
+            }],
+            outputs: vec![TxOut { value: 100_000, script_pubkey: script_pubkey.clone() }],
+            lock_time: 0,
+        };
+
+        let transaction = unsigned_tx(previous.txid());
+        let mut psbt = Psbt::from_unsigned_tx(&transaction).unwrap();
+        psbt.inputs[0].insert(vec![PSBT_IN_NON_WITNESS_UTXO], previous.serialize());
+        let (derivation_key, source) = bip32_derivation(&public_key, master.fingerprint(), &path);
+        psbt.inputs[0].insert(derivation_key, source);
+
+        let mut signed = psbt.clone();
+        assert_eq!(signed.sign(&master).unwrap(), 1);
+
+        let mut sig_key = vec![PSBT_IN_PARTIAL_SIG];
+        sig_key.extend_from_slice(&public_key);
+        let signature = signed.inputs[0].get(&sig_key).unwrap();
+        let sighash = transaction.legacy_sighash(0, &script_pubkey, SIGHASH_ALL).unwrap();
+        let der = secp256k1::ecdsa::Signature::from_der(&signature[..signature.len() - 1]).unwrap();
+        ecdsa::context()
+            .verify_ecdsa(&Message::from_digest(sighash), &der, &PublicKey::from_slice(&public_key).unwrap())
+            .unwrap();
+
+        // 참조한 트랜잭션과 다른 NON_WITNESS_UTXO
+        let mut tampered = psbt.clone();
+        let mut other = previous.clone();
+        other.lock_time = 1;
+        tampered.inputs[0].insert(vec![PSBT_IN_NON_WITNESS_UTXO], other.serialize());
+        assert!(tampered.sign(&master).is_err());
+
+        // SegWit이 아닌 입력에 WITNESS_UTXO만 있으면 금액을 확인할 수 없으므로 서명하지 않음
+        let mut witness_only = psbt.clone();
+        witness_only.inputs[0].remove(&vec![PSBT_IN_NON_WITNESS_UTXO]);
+        witness_only.inputs[0].insert(vec![PSBT_IN_WITNESS_UTXO], encode_witness_utxo(&previous.outputs[0]));
+        assert!(witness_only.sign(&master).unwrap_err().to_string().contains("NON_WITNESS_UTXO"));
+
+        // 둘 다 있으면 같은 출력이어야 함
+        let mut mismatched = psbt.clone();
+        let lying = TxOut { value: 1, ..previous.outputs[0].clone() };
+        mismatched.inputs[0].insert(vec![PSBT_IN_WITNESS_UTXO], encode_witness_utxo(&lying));
+        assert!(mismatched.sign(&master).is_err());
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // BIP-174 공식 테스트 벡터 (Creator → Updater → Signer)
+    // 2-of-2 멀티시그 두 입력: P2SH (NON_WITNESS_UTXO), P2SH-P2WSH (WITNESS_UTXO)
+    // ═══════════════════════════════════════════════════════════════
+
+    const BIP174_MASTER: &str = "tprv8ZgxMBicQKsPd9TeAdPADNnSyH9SSUUbTVeFszDE23Ki6TBB5nCefAdHkK8Fm3qMQR6sHwA56zqRmKmxnHk37JkiFzvncDqoKmPWubu7hDF";
+
+    const BIP174_CREATED: &str = "70736274ff01009a020000000258e87a21b56daf0c23be8e7070456c336f7cbaa5c8757924f545887bb2abdd750000000000ffffffff838d0427d0ec650a68aa46bb0b098aea4422c071b2ca78352a077959d07cea1d0100000000ffffffff0270aaf00800000000160014d85c2b71d0060b09c9886aeb815e50991dda124d00e1f5050000000016001400aea9a2e5f0f876a588df5546e8742d1d87008f000000000000000000";
+
+    // Updater가 UTXO, 스크립트, BIP-32 경로, SIGHASH_ALL을 추가한 PSBT
+    const BIP174_UPDATED: &str = "cHNidP8BAJoCAAAAAljoeiG1ba8MI76OcHBFbDNvfLqlyHV5JPVFiHuyq911AAAAAAD/////g40EJ9DsZQpoqka7CwmK6kQiwHGyyng1Kgd5WdB86h0BAAAAAP////8CcKrwCAAAAAAWABTYXCtx0AYLCcmIauuBXlCZHdoSTQDh9QUAAAAAFgAUAK6pouXw+HaliN9VRuh0LR2HAI8AAAAAAAEAuwIAAAABqtc5MQGL0l+ErkALaISL4J23BurCrBgpi6vucatlb4sAAAAASEcwRAIgWPb8fGoz4bMVSNSByCbAFb0wE1qtQs1neQ2rZtKtJDsCIEoc7SYExnNbY5PltBaR3XiwDwxZQvufdRhW+qk4FX26Af7///8CgPD6AgAAAAAXqRQPuUY0IWlrgsgzryQceMF9295JNIfQ8gonAQAAABepFCnKdPigj4GZlCgYXJe12FLkBj9hh2UAAAABAwQBAAAAAQRHUiEClYO/Oa4KYJdHrRma3dY0+mEIVZ1sXNObTCGD8auW4H8hAtq2H/SaFNtqfQKwzR+7ePxLGDErW05U2uTbovv+9TbXUq4iBgKVg785rgpgl0etGZrd1jT6YQhVnWxc05tMIYPxq5bgfxDZDGpPAAAAgAAAAIAAAACAIgYC2rYf9JoU22p9ArDNH7t4/EsYMStbTlTa5Nui+/71NtcQ2QxqTwAAAIAAAACAAQAAgAABASAAwusLAAAAABepFLf1+vQOPUClpFmx2zU18rcvqSHohwEDBAEAAAABBCIAIIwjUxc3Q7WV37Sge3K6jkLjeX2nTof+fZ10l+OyAokDAQVHUiEDCJ3BDHrG21T5EymvYXMz2ziM6tDCMfcjN50bmQMLAtwhAjrdkE89bc9Z3bkGsN7iNSm3/7ntUOXoYVGSaGAiHw5zUq4iBgI63ZBPPW3PWd25BrDe4jUpt/+57VDl6GFRkmhgIh8OcxDZDGpPAAAAgAAAAIADAACAIgYDCJ3BDHrG21T5EymvYXMz2ziM6tDCMfcjN50bmQMLAtwQ2QxqTwAAAIAAAACAAgAAgAAiAgOppMN/WZbTqiXbrGtXCvBlA5RJKUJGCzVHU+2e7KWHcRDZDGpPAAAAgAAAAIAEAACAACICAn9jmXV9Lv9VoTatAsaEsYOLZVbl8bazQoKpS2tQBRCWENkMak8AAACAAAAAgAUAAIAA";
+
+    // (입력, 공개키, 부분 서명) — 첫 번째 서명자 m/0'/0'/0', m/0'/0'/2', 두 번째 서명자 m/0'/0'/1', m/0'/0'/3'
+    const BIP174_SIGNATURES: [(usize, &str, &str); 4] = [
+        (0, "029583bf39ae0a609747ad199addd634fa6108559d6c5cd39b4c2183f1ab96e07f", "3044022074018ad4180097b873323c0015720b3684cc8123891048e7dbcd9b55ad679c99022073d369b740e3eb53dcefa33823c8070514ca55a7dd9544f157c167913261118c01"),
+        (1, "03089dc10c7ac6db54f91329af617333db388cead0c231f723379d1b99030b02dc", "3044022062eb7a556107a7c73f45ac4ab5a1dddf6f7075fb1275969a7f383efff784bcb202200c05dbb7470dbf2f08557dd356c7325c1ed30913e996cd3840945db12228da5f01"),
+        (0, "02dab61ff49a14db6a7d02b0cd1fbb78fc4b18312b5b4e54dae4dba2fbfef536d7", "30440220631a989fe738a92ad01986023312c19214fe2802b39e5cbc1ac3678806c692c3022039db6c387bd267716dfdb3d4d8da50b8e85d213326ba7c7daaa4c0ce41eb922301"),
+        (1, "023add904f3d6dcf59ddb906b0dee23529b7ffb9ed50e5e86151926860221f0e73", "3044022065f45ba5998b59a27ffe1a7bed016af1f1f90d54b3aa8f7450aa5f56a25103bd02207f724703ad1edb96680b284b56d4ffcb88f7fb759eabbe08aa30f29b851383d201"),
+    ];
+
+    #[test]
+    fn test_bip174_vectors() {
+        // Creator: 직렬화가 공식 벡터와 바이트 단위로 같음
+        let created = hex::decode(BIP174_CREATED).unwrap();
+        let psbt = Psbt::deserialize(&created).unwrap();
+        assert_eq!(psbt.serialize(), created);
+        assert_eq!(psbt.inputs.len(), 2);
+        assert_eq!(psbt.outputs.len(), 2);
+
+        // Updater 결과의 전역 맵(서명 전 트랜잭션)은 Creator와 같음
+        let mut updated: Psbt = BIP174_UPDATED.parse().unwrap();
+        assert_eq!(updated.to_base64(), BIP174_UPDATED);
+        assert_eq!(updated.global, psbt.global);
+
+        // Signer: 마스터 키로 네 키 모두 서명 → 공식 부분 서명과 같음 (RFC 6979)
+        let master = ExtendedPrivateKey::from_base58(BIP174_MASTER).unwrap();
+        assert_eq!(hex::encode(master.fingerprint()), "d90c6a4f");
+        assert_eq!(updated.sign(&master).unwrap(), 4);
+
+        for (index, public_key, signature) in BIP174_SIGNATURES {
+            let mut key = vec![PSBT_IN_PARTIAL_SIG];
+            key.extend_from_slice(&hex::decode(public_key).unwrap());
+            assert_eq!(hex::encode(&updated.inputs[index][&key]), signature, "입력 {} {}", index, public_key);
+        }
+    }
+
+    #[test]
+    fn test_bip174_skips_other_signers_keys() {
+        let master = ExtendedPrivateKey::from_base58(BIP174_MASTER).unwrap();
+        let mut psbt: Psbt = BIP174_UPDATED.parse().unwrap();
+
+        // 입력 1의 m/0'/0'/3' 키 출처를 다른 경로로 바꿈 → 도출한 공개키가 달라 그 키만 건너뜀
+        let (_, public_key, _) = BIP174_SIGNATURES[3];
+        let mut key = vec![PSBT_IN_BIP32_DERIVATION];
+        key.extend_from_slice(&hex::decode(public_key).unwrap());
+        psbt.inputs[1].insert(key, encode_key_source(master.fingerprint(), &"m/0'/0'/9'".parse().unwrap()));
+
+        assert_eq!(psbt.sign(&master).unwrap(), 3);
+        for (index, public_key, signature) in &BIP174_SIGNATURES[..3] {
+            let mut key = vec![PSBT_IN_PARTIAL_SIG];
+            key.extend_from_slice(&hex::decode(public_key).unwrap());
+            assert_eq!(hex::encode(&psbt.inputs[*index][&key]), *signature);
+        }
+    }
+
+    #[test]
+    fn test_sign_taproot() {
+        let master = master();
+        let path = DerivationPath::bip86(0, 0, 0, 0);
+        let internal_key = schnorr::x_only_public_key(&master.derive_path(&path).unwrap().private_key).unwrap();
+        let (output_key, _) = schnorr::taproot_tweak_public_key(&internal_key, None).unwrap();
+        let utxo = TxOut { value: 100_000, script_pubkey: p2tr_script(&output_key) };
+
+        let transaction = unsigned_tx([0x33; 32]);
+        let mut psbt = Psbt::from_unsigned_tx(&transaction).unwrap();
+        psbt.inputs[0].insert(vec![PSBT_IN_WITNESS_UTXO], encode_witness_utxo(&utxo));
+        psbt.inputs[0].insert(vec![PSBT_IN_TAP_INTERNAL_KEY], internal_key.to_vec());
+        let mut key = vec![PSBT_IN_TAP_BIP32_DERIVATION];
+        key.extend_from_slice(&internal_key);
+        let mut value = vec![0x00]; // leaf 해시 없음
+        value.extend_from_slice(&encode_key_source(master.fingerprint(), &path));
+        psbt.inputs[0].insert(key, value);
+
+        assert_eq!(psbt.sign(&master).unwrap(), 1);
+        let signature: [u8; 64] = psbt.inputs[0].get(&vec![PSBT_IN_TAP_KEY_SIG]).unwrap().as_slice().try_into().unwrap();
+
+        let sighash = transaction.taproot_key_spend_sighash(0, &[utxo], SIGHASH_DEFAULT).unwrap();
+        assert!(schnorr::verify(&output_key, &sighash, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_v2() {
+        let transaction = unsigned_tx([0x44; 32]);
+        let input = &transaction.inputs[0];
+        let output = &transaction.outputs[0];
+
+        let compact_one = vec![0x01];
+        let global = PsbtMap::from([
+            (vec![PSBT_GLOBAL_TX_VERSION], transaction.version.to_le_bytes().to_vec()),
+            (vec![PSBT_GLOBAL_INPUT_COUNT], compact_one.clone()),
+            (vec![PSBT_GLOBAL_OUTPUT_COUNT], compact_one),
+            (vec![PSBT_GLOBAL_VERSION], 2u32.to_le_bytes().to_vec()),
+        ]);
+        let mut input_map = PsbtMap::from([
+            (vec![PSBT_IN_PREVIOUS_TXID], input.previous_output.txid.to_vec()),
+            (vec![PSBT_IN_OUTPUT_INDEX], input.previous_output.vout.to_le_bytes().to_vec()),
+            (vec![PSBT_IN_SEQUENCE], input.sequence.to_le_bytes().to_vec()),
+        ]);
+        let output_map = PsbtMap::from([
+            (vec![PSBT_OUT_AMOUNT], output.value.to_le_bytes().to_vec()),
+            (vec![PSBT_OUT_SCRIPT], output.script_pubkey.clone()),
+        ]);
+
+        let psbt = Psbt { global: global.clone(), inputs: vec![input_map.clone()], outputs: vec![output_map.clone()] };
+        let parsed = Psbt::deserialize(&psbt.serialize()).unwrap();
+        assert_eq!(parsed.version().unwrap(), 2);
+        assert_eq!(parsed.unsigned_tx().unwrap(), transaction);
+
+        // 블록 높이 잠금 요구
+        input_map.insert(vec![PSBT_IN_REQUIRED_HEIGHT_LOCKTIME], 840_000u32.to_le_bytes().to_vec());
+        let locked = Psbt { global, inputs: vec![input_map.clone()], outputs: vec![output_map] };
+        assert_eq!(locked.unsigned_tx().unwrap().lock_time, 840_000);
+
+        // 필수 필드 누락
+        let mut missing = locked.clone();
+        missing.inputs[0].remove(&vec![PSBT_IN_PREVIOUS_TXID]);
+        assert!(Psbt::deserialize(&missing.serialize()).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let psbt = Psbt::from_unsigned_tx(&unsigned_tx([0x55; 32])).unwrap();
+        let bytes = psbt.serialize();
+
+        assert!(Psbt::deserialize(&bytes[1..]).is_err());
+        assert!(Psbt::deserialize(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Psbt::from_base64("not base64!").is_err());
+
+        // 중복 키: 전역 맵에 같은 키를 한 번 더 넣음
+        let mut duplicated = PSBT_MAGIC.to_vec();
+        let unsigned = &psbt.global[&vec![PSBT_GLOBAL_UNSIGNED_TX]];
+        for _ in 0..2 {
+            write_bytes(&mut duplicated, &[PSBT_GLOBAL_UNSIGNED_TX]);
+            write_bytes(&mut duplicated, unsigned);
+        }
+        duplicated.extend_from_slice(&[0x00, 0x00, 0x00]);
+        assert!(Psbt::deserialize(&duplicated).unwrap_err().to_string().contains("중복"));
+
+        // 서명이 들어간 트랜잭션으로는 만들 수 없음
+        let mut signed = unsigned_tx([0x55; 32]);
+        signed.inputs[0].script_sig = vec![0x51];
+        assert!(Psbt::from_unsigned_tx(&signed).is_err());
+    }
+}
@@ -0,0 +1,486 @@
+//! Bitcoin Transaction
+//!
+//! 서명에 필요한 만큼만 다루는 트랜잭션 구조 (직렬화, txid, sighash)
+//!
+//! ## 직렬화
+//! ```text
+//! version(4) || [0x00 0x01] || 입력 수 || 입력* || 출력 수 || 출력* || [witness*] || locktime(4)
+//! 입력 = txid(32) || vout(4) || scriptSig || sequence(4)
+//! 출력 = value(8) || scriptPubKey
+//! ```
+//! 마커/플래그(0x00 0x01)와 witness는 witness가 하나라도 있을 때만 들어감
+//! txid는 witness를 뺀 직렬화의 SHA256d (내부 바이트 순서, 화면에는 뒤집어서 표시)
+//!
+//! ## Sighash
+//! | 방식 | 대상 |
+//! |------|------|
+//! | Legacy | P2PKH, P2SH |
+//! | BIP-143 | P2WPKH, P2WSH (P2SH로 감싼 경우 포함) |
+//! | BIP-341 | P2TR 키 경로 |
+//!
+//! SIGHASH_ALL(Taproot은 SIGHASH_DEFAULT 포함)만 지원
+
+use sha2::{Digest, Sha256};
+
+use crate::hash::sha256d;
+use crate::schnorr;
+use crate::error::{Error, Result};
+
+/// SIGHASH_DEFAULT (Taproot 전용, 서명 뒤에 sighash 바이트를 붙이지 않음)
+pub const SIGHASH_DEFAULT: u8 = 0x00;
+/// SIGHASH_ALL (모든 입력과 출력에 서명)
+pub const SIGHASH_ALL: u8 = 0x01;
+
+/// 이전 트랜잭션 출력 참조
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutPoint {
+    /// 이전 트랜잭션 ID (내부 바이트 순서)
+    pub txid: [u8; 32],
+    /// 출력 인덱스
+    pub vout: u32,
+}
+
+/// 트랜잭션 입력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxIn {
+    /// 사용하는 출력
+    pub previous_output: OutPoint,
+    /// 잠금 해제 스크립트
+    pub script_sig: Vec<u8>,
+    /// 시퀀스 번호
+    pub sequence: u32,
+    /// witness 스택
+    pub witness: Vec<Vec<u8>>,
+}
+
+/// 트랜잭션 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxOut {
+    /// 금액 (satoshi)
+    pub value: u64,
+    /// 잠금 스크립트
+    pub script_pubkey: Vec<u8>,
+}
+
+/// 트랜잭션
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    /// 버전
+    pub version: u32,
+    /// 입력
+    pub inputs: Vec<TxIn>,
+    /// 출력
+    pub outputs: Vec<TxOut>,
+    /// 잠금 시간
+    pub lock_time: u32,
+}
+
+impl Transaction {
+    /// 직렬화 (witness가 있으면 BIP-144 형식)
+    pub fn serialize(&self) -> Vec<u8> {
+        let has_witness = self.inputs.iter().any(|input| !input.witness.is_empty());
+        self.serialize_with(has_witness)
+    }
+
+    /// witness를 뺀 직렬화 (txid 계산용)
+    pub fn serialize_without_witness(&self) -> Vec<u8> {
+        self.serialize_with(false)
+    }
+
+    /// 바이트에서 트랜잭션 파싱 (남는 바이트가 있으면 에러)
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data);
+        let transaction = Self::read_from(&mut reader)?;
+        reader.finish()?;
+        Ok(transaction)
+    }
+
+    /// 트랜잭션 ID (내부 바이트 순서)
+    pub fn txid(&self) -> [u8; 32] {
+        sha256d(&self.serialize_without_witness())
+    }
+
+    /// 트랜잭션 ID (화면 표시용 hex, 바이트 순서 뒤집음)
+    pub fn txid_hex(&self) -> String {
+        let mut txid = self.txid();
+        txid.reverse();
+        hex::encode(txid)
+    }
+
+    /// Legacy sighash (P2PKH, P2SH)
+    ///
+    /// 서명하는 입력의 scriptSig 자리에 `script_code`를 넣고 나머지 입력은 비운 뒤
+    /// sighash 타입(4바이트)을 붙여 SHA256d
+    pub fn legacy_sighash(&self, input_index: usize, script_code: &[u8], sighash_type: u8) -> Result<[u8; 32]> {
+        self.check_input_index(input_index)?;
+        check_sighash_all(sighash_type)?;
+
+        let mut copy = self.clone();
+        for (index, input) in copy.inputs.iter_mut().enumerate() {
+            input.script_sig = if index == input_index { script_code.to_vec() } else { Vec::new() };
+            input.witness.clear();
+        }
+
+        let mut preimage = copy.serialize_without_witness();
+        preimage.extend_from_slice(&(sighash_type as u32).to_le_bytes());
+        Ok(sha256d(&preimage))
+    }
+
+    /// BIP-143 sighash (SegWit v0)
+    ///
+    /// `script_code`: P2WPKH는 `76a914{pubkey_hash}88ac`, P2WSH는 witness script
+    /// `value`: 사용하는 출력의 금액
+    pub fn segwit_v0_sighash(
+        &self,
+        input_index: usize,
+        script_code: &[u8],
+        value: u64,
+        sighash_type: u8,
+    ) -> Result<[u8; 32]> {
+        self.check_input_index(input_index)?;
+        check_sighash_all(sighash_type)?;
+
+        let mut prevouts = Vec::new();
+        let mut sequences = Vec::new();
+        for input in &self.inputs {
+            write_outpoint(&mut prevouts, &input.previous_output);
+            sequences.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        let mut outputs = Vec::new();
+        for output in &self.outputs {
+            write_txout(&mut outputs, output);
+        }
+
+        let input = &self.inputs[input_index];
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&self.version.to_le_bytes());
+        preimage.extend_from_slice(&sha256d(&prevouts));
+        preimage.extend_from_slice(&sha256d(&sequences));
+        write_outpoint(&mut preimage, &input.previous_output);
+        write_bytes(&mut preimage, script_code);
+        preimage.extend_from_slice(&value.to_le_bytes());
+        preimage.extend_from_slice(&input.sequence.to_le_bytes());
+        preimage.extend_from_slice(&sha256d(&outputs));
+        preimage.extend_from_slice(&self.lock_time.to_le_bytes());
+        preimage.extend_from_slice(&(sighash_type as u32).to_le_bytes());
+
+        Ok(sha256d(&preimage))
+    }
+
+    /// BIP-341 키 경로 sighash (annex 없음)
+    ///
+    /// `prevouts`: 모든 입력이 사용하는 출력 (입력 순서대로) - 금액과 스크립트 전부 서명에 포함
+    pub fn taproot_key_spend_sighash(
+        &self,
+        input_index: usize,
+        prevouts: &[TxOut],
+        sighash_type: u8,
+    ) -> Result<[u8; 32]> {
+        self.check_input_index(input_index)?;
+        if sighash_type != SIGHASH_DEFAULT {
+            check_sighash_all(sighash_type)?;
+        }
+        if prevouts.len() != self.inputs.len() {
+            return Err(Error::Transaction(format!(
+                "Taproot sighash에는 모든 입력의 이전 출력이 필요합니다: {}/{}",
+                prevouts.len(),
+                self.inputs.len()
+            )));
+        }
+
+        let mut outpoints = Vec::new();
+        let mut sequences = Vec::new();
+        for input in &self.inputs {
+            write_outpoint(&mut outpoints, &input.previous_output);
+            sequences.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+        let mut amounts = Vec::new();
+        let mut scripts = Vec::new();
+        for prevout in prevouts {
+            amounts.extend_from_slice(&prevout.value.to_le_bytes());
+            write_bytes(&mut scripts, &prevout.script_pubkey);
+        }
+        let mut outputs = Vec::new();
+        for output in &self.outputs {
+            write_txout(&mut outputs, output);
+        }
+
+        let mut message = vec![0x00, sighash_type]; // epoch, hash_type
+        message.extend_from_slice(&self.version.to_le_bytes());
+        message.extend_from_slice(&self.lock_time.to_le_bytes());
+        message.extend_from_slice(&Sha256::digest(&outpoints));
+        message.extend_from_slice(&Sha256::digest(&amounts));
+        message.extend_from_slice(&Sha256::digest(&scripts));
+        message.extend_from_slice(&Sha256::digest(&sequences));
+        message.extend_from_slice(&Sha256::digest(&outputs));
+        message.push(0x00); // spend_type (키 경로, annex 없음)
+        message.extend_from_slice(&(input_index as u32).to_le_bytes());
+
+        Ok(schnorr::tagged_hash("TapSighash", &message))
+    }
+
+    pub(crate) fn read_from(reader: &mut Reader) -> Result<Self> {
+        let version = reader.read_u32()?;
+
+        // 마커 0x00 + 플래그 0x01 → witness 포함
+        let has_witness = reader.peek(2) == Some(&[0x00, 0x01][..]);
+        if has_witness {
+            reader.read_bytes(2)?;
+        }
+
+        let input_count = reader.read_compact_size()?;
+        let mut inputs = Vec::new();
+        for _ in 0..input_count {
+            let txid = reader.read_array()?;
+            let vout = reader.read_u32()?;
+            let script_sig = reader.read_var_bytes()?;
+            let sequence = reader.read_u32()?;
+            inputs.push(TxIn {
+                previous_output: OutPoint { txid, vout },
+                script_sig,
+                sequence,
+                witness: Vec::new(),
+            });
+        }
+
+        let output_count = reader.read_compact_size()?;
+        let mut outputs = Vec::new();
+        for _ in 0..output_count {
+            let value = reader.read_u64()?;
+            let script_pubkey = reader.read_var_bytes()?;
+            outputs.push(TxOut { value, script_pubkey });
+        }
+
+        if has_witness {
+            for input in &mut inputs {
+                let item_count = reader.read_compact_size()?;
+                for _ in 0..item_count {
+                    input.witness.push(reader.read_var_bytes()?);
+                }
+            }
+        }
+
+        let lock_time = reader.read_u32()?;
+        Ok(Transaction { version, inputs, outputs, lock_time })
+    }
+
+    fn serialize_with(&self, witness: bool) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&self.version.to_le_bytes());
+        if witness {
+            out.extend_from_slice(&[0x00, 0x01]);
+        }
+
+        write_compact_size(&mut out, self.inputs.len() as u64);
+        for input in &self.inputs {
+            write_outpoint(&mut out, &input.previous_output);
+            write_bytes(&mut out, &input.script_sig);
+            out.extend_from_slice(&input.sequence.to_le_bytes());
+        }
+
+        write_compact_size(&mut out, self.outputs.len() as u64);
+        for output in &self.outputs {
+            write_txout(&mut out, output);
+        }
+
+        if witness {
+            for input in &self.inputs {
+                write_compact_size(&mut out, input.witness.len() as u64);
+                for item in &input.witness {
+                    write_bytes(&mut out, item);
+                }
+            }
+        }
+
+        out.extend_from_slice(&self.lock_time.to_le_bytes());
+        out
+    }
+
+    fn check_input_index(&self, input_index: usize) -> Result<()> {
+        if input_index < self.inputs.len() {
+            Ok(())
+        } else {
+            Err(Error::Transaction(format!("입력 인덱스 범위 초과: {} (입력 {}개)", input_index, self.inputs.len())))
+        }
+    }
+}
+
+fn check_sighash_all(sighash_type: u8) -> Result<()> {
+    if sighash_type == SIGHASH_ALL {
+        Ok(())
+    } else {
+        Err(Error::Transaction(format!("지원하지 않는 sighash 타입: 0x{:02x}", sighash_type)))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 직렬화 도우미
+// ═══════════════════════════════════════════════════════════════
+
+/// Bitcoin CompactSize
+pub(crate) fn write_compact_size(out: &mut Vec<u8>, value: u64) {
+    match value {
+        0..=0xfc => out.push(value as u8),
+        0xfd..=0xffff => {
+            out.push(0xfd);
+            out.extend_from_slice(&(value as u16).to_le_bytes());
+        }
+        0x10000..=0xffff_ffff => {
+            out.push(0xfe);
+            out.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        _ => {
+            out.push(0xff);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+/// CompactSize 길이 + 바이트
+pub(crate) fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_compact_size(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn write_outpoint(out: &mut Vec<u8>, outpoint: &OutPoint) {
+    out.extend_from_slice(&outpoint.txid);
+    out.extend_from_slice(&outpoint.vout.to_le_bytes());
+}
+
+pub(crate) fn write_txout(out: &mut Vec<u8>, output: &TxOut) {
+    out.extend_from_slice(&output.value.to_le_bytes());
+    write_bytes(out, &output.script_pubkey);
+}
+
+/// 바이트 슬라이스 순차 읽기
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Reader { data, position: 0 }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.position == self.data.len()
+    }
+
+    /// 모두 읽었는지 확인
+    pub(crate) fn finish(&self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Transaction(format!("뒤에 남은 바이트가 있습니다: {}바이트", self.data.len() - self.position)))
+        }
+    }
+
+    pub(crate) fn peek(&self, length: usize) -> Option<&'a [u8]> {
+        self.data.get(self.position..self.position + length)
+    }
+
+    pub(crate) fn read_bytes(&mut self, length: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.position..self.position.saturating_add(length))
+            .ok_or_else(|| Error::Transaction("데이터가 너무 짧습니다".to_string()))?;
+        self.position += length;
+        Ok(bytes)
+    }
+
+    /// 남은 바이트 전부
+    pub(crate) fn read_remaining(&mut self) -> &'a [u8] {
+        let rest = &self.data[self.position..];
+        self.position = self.data.len();
+        rest
+    }
+
+    pub(crate) fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.read_bytes(N)?.try_into().expect("길이 확인됨"))
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub(crate) fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    pub(crate) fn read_compact_size(&mut self) -> Result<u64> {
+        match self.read_u8()? {
+            0xfd => Ok(u16::from_le_bytes(self.read_array()?) as u64),
+            0xfe => Ok(self.read_u32()? as u64),
+            0xff => self.read_u64(),
+            value => Ok(value as u64),
+        }
+    }
+
+    /// CompactSize 길이 + 바이트
+    pub(crate) fn read_var_bytes(&mut self) -> Result<Vec<u8>> {
+        let length = self.read_compact_size()?;
+        let length = usize::try_from(length).map_err(|_| Error::Transaction("길이가 너무 큽니다".to_string()))?;
+        Ok(self.read_bytes(length)?.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// BIP-143 "Native P2WPKH" 예제의 서명 전 트랜잭션
+    const BIP143_UNSIGNED: &str = "0100000002fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f0000000000eeffffffef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000ffffffff02202cb206000000001976a9148280b37df378db99f66f85c95a783a76ac7a6d5988ac9093510d000000001976a9143bde42dbee7e4dbe6a21b2d50ce2f0167faa815988ac11000000";
+
+    #[test]
+    fn test_roundtrip() {
+        let bytes = hex::decode(BIP143_UNSIGNED).unwrap();
+        let transaction = Transaction::deserialize(&bytes).unwrap();
+        assert_eq!(transaction.inputs.len(), 2);
+        assert_eq!(transaction.outputs[0].value, 112340000);
+        assert_eq!(transaction.lock_time, 17);
+        assert_eq!(transaction.serialize(), bytes);
+
+        // witness 추가 → 마커/플래그 포함 직렬화, txid는 그대로
+        let mut witnessed = transaction.clone();
+        witnessed.inputs[1].witness = vec![vec![0xaa; 71], vec![0x02; 33]];
+        let serialized = witnessed.serialize();
+        assert_eq!(&serialized[4..6], &[0x00, 0x01]);
+        assert_eq!(Transaction::deserialize(&serialized).unwrap(), witnessed);
+        assert_eq!(witnessed.txid(), transaction.txid());
+
+        assert!(Transaction::deserialize(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_bip143_sighash() {
+        let transaction = Transaction::deserialize(&hex::decode(BIP143_UNSIGNED).unwrap()).unwrap();
+
+        // 두 번째 입력: P2WPKH, 6 BTC
+        let pubkey_hash = hex::decode("1d0f172a0ecb48aee1be1f2687d2963ae33f71a1").unwrap();
+        let mut script_code = vec![0x76, 0xa9, 0x14];
+        script_code.extend_from_slice(&pubkey_hash);
+        script_code.extend_from_slice(&[0x88, 0xac]);
+
+        let sighash = transaction.segwit_v0_sighash(1, &script_code, 600_000_000, SIGHASH_ALL).unwrap();
+        assert_eq!(hex::encode(sighash), "c37af31116d1b27caf68aae9e3ac82f1477929014d5b917657d0eb49478cb670");
+
+        assert!(transaction.segwit_v0_sighash(2, &script_code, 0, SIGHASH_ALL).is_err());
+        assert!(transaction.segwit_v0_sighash(1, &script_code, 0, 0x83).is_err());
+    }
+
+    #[test]
+    fn test_compact_size() {
+        for value in [0u64, 0xfc, 0xfd, 0xffff, 0x10000, 0xffff_ffff, 0x1_0000_0000] {
+            let mut out = Vec::new();
+            write_compact_size(&mut out, value);
+            let mut reader = Reader::new(&out);
+            assert_eq!(reader.read_compact_size().unwrap(), value);
+            assert!(reader.is_empty());
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! CAIP-2 / CAIP-10 Identifiers
+//!
+//! 체인과 계정을 체인 종류와 무관한 한 가지 문자열로 표현 (WalletConnect 세션, 멀티체인 색인)
+//!
+//! ```text
+//! CAIP-2  체인:  namespace:reference            eip155:1
+//! CAIP-10 계정:  namespace:reference:address    eip155:1:0x9858EfFD232B4033E47d90003D41EC34EcaEda94
+//! ```
+//!
+//! | 부분 | 문자 | 길이 |
+//! |------|------|------|
+//! | namespace | `[-a-z0-9]` | 3~8 |
+//! | reference | `[-_a-zA-Z0-9]` | 1~32 |
+//! | address | `[-.%a-zA-Z0-9]` | 1~128 |
+//!
+//! ## 내장 체인 (메인넷)
+//!
+//! | 체인 id | CAIP-2 | 근거 |
+//! |---------|--------|------|
+//! | `bitcoin` | `bip122:000000000019d6689c085ae165831e93` | 제네시스 블록 해시 앞 32자 (CAIP-4) |
+//! | `evm` | `eip155:1` | EIP-155 체인 id (CAIP-3) |
+//! | `solana` | `solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp` | 제네시스 해시 앞 32자 (CAIP-30) |
+//! | `sui` | `sui:mainnet` | 네트워크 이름 |
+//! | `cosmoshub`, `osmosis`, ... | `cosmos:cosmoshub-4`, `cosmos:osmosis-1`, ... | Cosmos SDK chain-id (CAIP-5) |
+//! | `substrate` | 없음 | 범용 SS58 접두사(42)는 특정 체인이 아님 |
+//!
+//! 체인별 CAIP-2는 `Chain::caip2()`, 계정의 CAIP-10은 `Account::caip10()`
+//!
+//! ```
+//! use crypto_lib::account::Account;
+//! use crypto_lib::caip::AccountId;
+//! use crypto_lib::cosmos::CosmosAccount;
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let account = CosmosAccount::from_mnemonic(mnemonic, "").unwrap();
+//! let id = account.caip10().unwrap();
+//! assert_eq!(id.to_string(), "cosmos:cosmoshub-4:cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4");
+//! assert_eq!(id.to_string().parse::<AccountId>().unwrap(), id);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// CAIP-2 체인 식별자
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChainId {
+    namespace: String,
+    reference: String,
+}
+
+impl ChainId {
+    /// namespace + reference (형식 검사)
+    pub fn new(namespace: impl Into<String>, reference: impl Into<String>) -> Result<Self> {
+        let (namespace, reference) = (namespace.into(), reference.into());
+        if !valid(&namespace, 3, 8, |c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+            return Err(Error::InvalidInput(format!("CAIP-2 namespace는 [-a-z0-9] 3~8자여야 합니다: {:?}", namespace)));
+        }
+        if !valid(&reference, 1, 32, |c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(Error::InvalidInput(format!("CAIP-2 reference는 [-_a-zA-Z0-9] 1~32자여야 합니다: {:?}", reference)));
+        }
+        Ok(ChainId { namespace, reference })
+    }
+
+    /// EVM 체인 (`eip155:<chain_id>`)
+    pub fn eip155(chain_id: u64) -> Self {
+        ChainId { namespace: "eip155".to_string(), reference: chain_id.to_string() }
+    }
+
+    /// namespace (예: "eip155")
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// reference (예: "1")
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// "namespace:reference" 파싱
+    pub fn parse(s: &str) -> Result<Self> {
+        let (namespace, reference) =
+            s.split_once(':').ok_or_else(|| Error::InvalidInput(format!("CAIP-2 식별자가 아닙니다: {}", s)))?;
+        ChainId::new(namespace, reference)
+    }
+
+    /// 이 체인의 계정 식별자
+    pub fn account(&self, address: impl Into<String>) -> Result<AccountId> {
+        AccountId::new(self.clone(), address)
+    }
+}
+
+impl fmt::Display for ChainId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.reference)
+    }
+}
+
+impl FromStr for ChainId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ChainId::parse(s)
+    }
+}
+
+/// CAIP-10 계정 식별자
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountId {
+    chain_id: ChainId,
+    address: String,
+}
+
+impl AccountId {
+    /// 체인 + 주소 (형식 검사)
+    pub fn new(chain_id: ChainId, address: impl Into<String>) -> Result<Self> {
+        let address = address.into();
+        if !valid(&address, 1, 128, |c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '%')) {
+            return Err(Error::InvalidAddress(format!("CAIP-10 주소는 [-.%a-zA-Z0-9] 1~128자여야 합니다: {:?}", address)));
+        }
+        Ok(AccountId { chain_id, address })
+    }
+
+    /// CAIP-2 체인
+    pub fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    /// 체인 고유 형식 주소
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// "namespace:reference:address" 파싱
+    pub fn parse(s: &str) -> Result<Self> {
+        let (chain_id, address) =
+            s.rsplit_once(':').ok_or_else(|| Error::InvalidInput(format!("CAIP-10 식별자가 아닙니다: {}", s)))?;
+        AccountId::new(ChainId::parse(chain_id)?, address)
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.chain_id, self.address)
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        AccountId::parse(s)
+    }
+}
+
+fn valid(s: &str, min: usize, max: usize, allowed: impl Fn(char) -> bool) -> bool {
+    (min..=max).contains(&s.len()) && s.chars().all(allowed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let chain = ChainId::parse("eip155:1").unwrap();
+        assert_eq!((chain.namespace(), chain.reference()), ("eip155", "1"));
+        assert_eq!(chain, ChainId::eip155(1));
+        assert_eq!(chain.to_string(), "eip155:1");
+
+        // CAIP-10 명세 예제
+        for example in [
+            "eip155:1:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb",
+            "bip122:000000000019d6689c085ae165831e93:128Lkh3S7CkDTBZ8W7BbpsN3YYizJMp8p6",
+            "cosmos:cosmoshub-3:cosmos1t2uflqwqe0fsj0shcfkrvpukewcw40yjj6hdc0",
+            "polkadot:b0a8d493285c2df73290dfb7e61f870f:5hmuyxw9xdgbpptgypokw4thfyoe3ryenebr381z9iaegmfy",
+            "chainstd:8c3444cf8970a9e41a706fab93e7a6c4:6d9b0b4b9994e8a6afbd3dc3ed983cd51c755afb27cd1dc7825ef59c134a39f7",
+        ] {
+            let id: AccountId = example.parse().unwrap();
+            assert_eq!(id.to_string(), example);
+        }
+
+        let id = AccountId::parse("cosmos:kava_2222-10:kava1abc").unwrap();
+        assert_eq!((id.chain_id().reference(), id.address()), ("kava_2222-10", "kava1abc"));
+        assert_eq!(ChainId::eip155(137).account("0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb").unwrap().chain_id(), &ChainId::eip155(137));
+    }
+
+    #[test]
+    fn test_invalid() {
+        for bad in ["eip155", "EIP155:1", "ab:1", "toolongns:1", "eip155:", "eip155:a b", "eip155:123456789012345678901234567890123"] {
+            assert!(ChainId::parse(bad).is_err(), "{}", bad);
+        }
+        for bad in ["eip155:1", "eip155:1:", "eip155:1:0x 12", "eip155:1:a/b"] {
+            assert!(AccountId::parse(bad).is_err(), "{}", bad);
+        }
+        assert!(matches!(ChainId::eip155(1).account("x".repeat(129)), Err(Error::InvalidAddress(_))));
+    }
+}
@@ -0,0 +1,848 @@
+//! Chain Registry
+//!
+//! 체인을 `Chain` 트레이트 구현으로 다루고, 런타임 레지스트리에 등록해 이름(id)으로 찾음
+//! 내장 체인도 같은 방식으로 등록되어 있으므로 외부 크레이트가 새 체인을 추가할 때 특별 취급이 없음
+//!
+//! ## 체인이 정의하는 것
+//! - coin type (BIP-44), 곡선 (SLIP-10)
+//! - 기본 도출 경로
+//! - 공개키 → 주소 형식
+//! - (선택) 테스트 네트워크의 경로와 주소 형식 (`network_path`, `format_network_address`)
+//! - (선택) 표시용 메타데이터: 티커, 소수 자릿수, Cosmos denom, 탐색기 URL 템플릿
+//! - (선택) CAIP-2 체인 식별자 (`caip` 모듈)
+//!
+//! 계정 도출은 기본 구현(SLIP-10 도출 → 곡선별 공개키 → 주소)이 있으므로
+//! 대부분의 체인은 `id`, `name`, `coin_type`, `curve`, `format_address`만 구현하면 됨
+//!
+//! ## 내장 체인
+//!
+//! | id | 곡선 | 기본 경로 | 공개키 |
+//! |----|------|----------|--------|
+//! | `bitcoin` | secp256k1 | m/84'/0'/0'/0/0 | 33바이트 (압축) |
+//! | `evm` | secp256k1 | m/44'/60'/0'/0/0 | 65바이트 (비압축) |
+//! | `solana` | Ed25519 | m/44'/501'/0'/0' | 32바이트 |
+//! | `sui` | Ed25519 | m/44'/784'/0'/0'/0' | 32바이트 |
+//! | `xrp` | secp256k1 | m/44'/144'/0'/0/0 | 33바이트 (압축) |
+//! | `cosmoshub`, `osmosis`, ... | secp256k1 | m/44'/{coin}'/0'/0/0 | 33바이트 (압축) |
+//! | `substrate` | Ed25519 | 없음 (Secret URI 경로) | 32바이트 |
+//!
+//! ## 메타데이터 (메인넷 기준)
+//!
+//! | id | 티커 | 소수 자릿수 | denom | 탐색기 | CAIP-2 |
+//! |----|------|------------|-------|--------|--------|
+//! | `bitcoin` | BTC | 8 | - | mempool.space | `bip122:000000000019d6689c085ae165831e93` |
+//! | `evm` | ETH | 18 | - | etherscan.io | `eip155:1` |
+//! | `solana` | SOL | 9 | - | explorer.solana.com | `solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp` |
+//! | `sui` | SUI | 9 | - | suiscan.xyz | `sui:mainnet` |
+//! | `xrp` | XRP | 6 | - | livenet.xrpl.org | `xrpl:0` |
+//! | `cosmoshub`, `osmosis`, ... | ATOM, OSMO, ... | 6 (Injective 18) | `uatom`, `uosmo`, ... | mintscan.io | `cosmos:cosmoshub-4`, ... |
+//! | `substrate` | 없음 (범용 접두사) | - | - | - | - |
+//!
+//! 탐색기 템플릿은 `{address}`, `{txid}` 자리표시자를 씀 (`address_url`, `tx_url`이 치환)
+//!
+//! ## 사용 예
+//! ```text
+//! chain::register(MyChain)?;
+//! let my_chain = chain::get("mychain").unwrap();
+//! let account = my_chain.derive_account(&source, &my_chain.default_path())?;
+//! ```
+
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::bip32::DerivationPath;
+use crate::bitcoin;
+use crate::network::Network;
+use crate::cosmos::CosmosChain;
+use crate::ecdsa;
+use crate::evm;
+use crate::hash;
+use crate::secret::SecretBytes;
+use crate::slip10::{self, Curve, KeyCache};
+use crate::substrate::uri::parse_junctions;
+use crate::trace::{self, Public};
+use crate::substrate::{self, SubstrateAccount, SS58_GENERIC};
+use crate::sui::{self, SignatureScheme};
+use crate::xrp;
+use crate::encoding::bech32::encode_bech32;
+use crate::error::{Error, Result};
+
+/// 계정 도출 입력
+///
+/// BIP-39 시드는 호출자가 한 번만 계산해 여러 체인에서 재사용
+/// (Substrate처럼 엔트로피에서 직접 도출하는 체인은 니모닉을 사용)
+#[derive(Debug, Clone, Copy)]
+pub struct SeedSource<'a> {
+    /// BIP-39 니모닉
+    pub mnemonic: &'a str,
+    /// BIP-39 패스프레이즈
+    pub passphrase: &'a str,
+    /// `mnemonic_to_seed(mnemonic, passphrase)` 결과 (64바이트)
+    pub seed: &'a [u8],
+    /// 같은 시드의 마스터/부모 키 캐시 (있으면 기본 SLIP-10 도출에서 사용)
+    pub keys: Option<&'a KeyCache>,
+}
+
+impl<'a> SeedSource<'a> {
+    /// 캐시 없는 입력
+    pub fn new(mnemonic: &'a str, passphrase: &'a str, seed: &'a [u8]) -> Self {
+        SeedSource { mnemonic, passphrase, seed, keys: None }
+    }
+
+    /// 키 캐시를 쓰는 입력 (시드는 캐시의 시드)
+    pub fn with_keys(mnemonic: &'a str, passphrase: &'a str, keys: &'a KeyCache) -> Self {
+        SeedSource { mnemonic, passphrase, seed: keys.seed(), keys: Some(keys) }
+    }
+
+    /// 경로의 SLIP-10 개인키 (캐시가 있으면 캐시 사용)
+    pub fn derive_key(&self, path: &str, curve: Curve) -> Result<[u8; 32]> {
+        match self.keys {
+            Some(keys) => keys.derive_key(path, curve),
+            None => slip10::derive_key(self.seed, path, curve),
+        }
+    }
+}
+
+/// 체인에서 도출한 계정 (`Debug`는 개인키를 가림, `account` 모듈에서 구현)
+#[derive(Clone)]
+pub struct ChainAccount {
+    /// 체인 id
+    pub chain_id: String,
+    /// 도출 경로
+    pub path: String,
+    /// 개인키 (32바이트)
+    pub private_key: SecretBytes<32>,
+    /// 공개키 (체인 고유 형식)
+    pub public_key: Vec<u8>,
+    /// 주소
+    pub address: String,
+}
+
+/// 체인 정의
+pub trait Chain: Send + Sync {
+    /// 레지스트리 키 (소문자, 예: "bitcoin")
+    fn id(&self) -> &str;
+
+    /// 표시 이름
+    fn name(&self) -> &str;
+
+    /// BIP-44 coin type
+    fn coin_type(&self) -> u32;
+
+    /// 키 도출 곡선
+    fn curve(&self) -> Curve;
+
+    /// BIP-44 account, 주소 index의 도출 경로 (Ed25519는 모든 단계 강화)
+    fn path(&self, account: u32, index: u32) -> String {
+        match self.curve() {
+            Curve::Ed25519 => format!("m/44'/{}'/{}'/0'/{}'", self.coin_type(), account, index),
+            Curve::Secp256k1 | Curve::Nist256p1 => format!("m/44'/{}'/{}'/0/{}", self.coin_type(), account, index),
+        }
+    }
+
+    /// 기본 도출 경로 (account 0, index 0)
+    fn default_path(&self) -> String {
+        self.path(0, 0)
+    }
+
+    /// 개인키 → 체인 형식 공개키 (기본: Ed25519 32바이트, 그 외 압축 33바이트)
+    fn public_key(&self, private_key: &[u8; 32]) -> Result<Vec<u8>> {
+        let public_key = slip10::public_key(private_key, self.curve())?;
+        Ok(match self.curve() {
+            Curve::Ed25519 => public_key[1..].to_vec(), // SLIP-10의 0x00 접두사 제거
+            Curve::Secp256k1 | Curve::Nist256p1 => public_key.to_vec(),
+        })
+    }
+
+    /// 공개키 → 주소
+    fn format_address(&self, public_key: &[u8]) -> Result<String>;
+
+    /// 네트워크별 도출 경로 (기본: 네트워크와 무관하게 `path`)
+    fn network_path(&self, account: u32, index: u32, _network: Network) -> String {
+        self.path(account, index)
+    }
+
+    /// 네트워크별 주소 (기본: 네트워크와 무관하게 `format_address`)
+    fn format_network_address(&self, public_key: &[u8], _network: Network) -> Result<String> {
+        self.format_address(public_key)
+    }
+
+    /// 경로의 계정 도출 (기본: SLIP-10)
+    fn derive_account(&self, source: &SeedSource<'_>, path: &str) -> Result<ChainAccount> {
+        let private_key = SecretBytes::new(source.derive_key(path, self.curve())?);
+        let public_key = self.public_key(&private_key)?;
+        let address = self.format_address(&public_key)?;
+
+        Ok(ChainAccount {
+            chain_id: self.id().to_string(),
+            path: path.to_string(),
+            private_key,
+            public_key,
+            address,
+        })
+    }
+
+    /// 기본 자산 티커 (예: "BTC")
+    fn symbol(&self) -> Option<&str> {
+        None
+    }
+
+    /// 기본 자산 소수 자릿수 (예: BTC 8, ETH 18)
+    fn decimals(&self) -> Option<u8> {
+        None
+    }
+
+    /// Cosmos SDK 기본 denom (예: "uatom")
+    fn denom(&self) -> Option<&str> {
+        None
+    }
+
+    /// 주소 탐색기 URL 템플릿 (`{address}` 치환)
+    fn explorer_address_template(&self) -> Option<&str> {
+        None
+    }
+
+    /// 트랜잭션 탐색기 URL 템플릿 (`{txid}` 치환)
+    fn explorer_tx_template(&self) -> Option<&str> {
+        None
+    }
+
+    /// CAIP-2 메인넷 체인 식별자 (예: "eip155:1")
+    fn caip2(&self) -> Option<&str> {
+        None
+    }
+
+    /// 주소의 탐색기 URL
+    fn address_url(&self, address: &str) -> Option<String> {
+        self.explorer_address_template().map(|template| template.replace("{address}", address))
+    }
+
+    /// 트랜잭션의 탐색기 URL
+    fn tx_url(&self, txid: &str) -> Option<String> {
+        self.explorer_tx_template().map(|template| template.replace("{txid}", txid))
+    }
+
+    /// 네트워크를 지정한 계정 도출 (메인넷이 아니면 주소만 `format_network_address`로 다시 만듦)
+    fn derive_network_account(&self, source: &SeedSource<'_>, path: &str, network: Network) -> Result<ChainAccount> {
+        // 경로 문자열은 BIP-32 경로일 때만 기록 (Substrate `///password` 등이 섞이지 않게)
+        let _span = trace::span!(
+            "chain.derive_account",
+            chain = Public(self.id()),
+            path = path.parse::<DerivationPath>().ok(),
+        );
+        let mut account = self.derive_account(source, path)?;
+        if !network.is_mainnet() {
+            account.address = self.format_network_address(&account.public_key, network)?;
+        }
+        Ok(account)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 레지스트리
+// ═══════════════════════════════════════════════════════════════
+
+static REGISTRY: OnceLock<RwLock<Vec<Arc<dyn Chain>>>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<Vec<Arc<dyn Chain>>> {
+    REGISTRY.get_or_init(|| RwLock::new(builtin_chains()))
+}
+
+// 패닉한 스레드가 있어도 목록 자체는 항상 일관된 상태 (push 한 번뿐)
+fn read_registry() -> RwLockReadGuard<'static, Vec<Arc<dyn Chain>>> {
+    registry().read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn write_registry() -> RwLockWriteGuard<'static, Vec<Arc<dyn Chain>>> {
+    registry().write().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 체인 등록 (같은 id가 이미 있으면 에러)
+pub fn register(chain: impl Chain + 'static) -> Result<()> {
+    let id = chain.id().to_ascii_lowercase();
+    if id.is_empty() {
+        return Err(Error::Chain("체인 id가 비어 있습니다".to_string()));
+    }
+
+    let mut chains = write_registry();
+    if chains.iter().any(|c| c.id().eq_ignore_ascii_case(&id)) {
+        return Err(Error::Chain(format!("이미 등록된 체인입니다: {}", id)));
+    }
+    chains.push(Arc::new(chain));
+    Ok(())
+}
+
+/// id로 체인 찾기 (대소문자 무시)
+pub fn get(id: &str) -> Option<Arc<dyn Chain>> {
+    read_registry().iter().find(|c| c.id().eq_ignore_ascii_case(id)).cloned()
+}
+
+/// 등록된 모든 체인 (등록 순서)
+pub fn registered() -> Vec<Arc<dyn Chain>> {
+    read_registry().clone()
+}
+
+fn builtin_chains() -> Vec<Arc<dyn Chain>> {
+    let mut chains: Vec<Arc<dyn Chain>> = vec![Arc::new(BitcoinChain), Arc::new(EvmChain), Arc::new(SolanaChain), Arc::new(SuiChain), Arc::new(XrpChain)];
+    chains.extend(CosmosChain::ALL.iter().map(|&c| Arc::new(c) as Arc<dyn Chain>));
+    chains.push(Arc::new(SubstrateChain));
+    chains
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내장 체인
+// ═══════════════════════════════════════════════════════════════
+
+/// Bitcoin (Native SegWit, P2WPKH, 기본 메인넷)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BitcoinChain;
+
+impl Chain for BitcoinChain {
+    fn id(&self) -> &str {
+        "bitcoin"
+    }
+
+    fn name(&self) -> &str {
+        "Bitcoin"
+    }
+
+    fn coin_type(&self) -> u32 {
+        0
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn path(&self, account: u32, index: u32) -> String {
+        format!("m/84'/0'/{}'/0/{}", account, index)
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        self.format_network_address(public_key, Network::Mainnet)
+    }
+
+    /// 테스트 네트워크는 coin type 1 (m/84'/1'/account'/0/index)
+    fn network_path(&self, account: u32, index: u32, network: Network) -> String {
+        format!("m/84'/{}'/{}'/0/{}", network.bitcoin().coin_type(), account, index)
+    }
+
+    fn format_network_address(&self, public_key: &[u8], network: Network) -> Result<String> {
+        let public_key = compressed_secp256k1(public_key)?;
+        Ok(bitcoin::encode_segwit_address(network.bitcoin(), 0, &hash::hash160(&public_key)))
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        Some("BTC")
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        Some(8)
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        Some("https://mempool.space/address/{address}")
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        Some("https://mempool.space/tx/{txid}")
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        Some("bip122:000000000019d6689c085ae165831e93")
+    }
+}
+
+/// EVM (EIP-55 체크섬 주소)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvmChain;
+
+impl Chain for EvmChain {
+    fn id(&self) -> &str {
+        "evm"
+    }
+
+    fn name(&self) -> &str {
+        "EVM"
+    }
+
+    fn coin_type(&self) -> u32 {
+        60
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn public_key(&self, private_key: &[u8; 32]) -> Result<Vec<u8>> {
+        let secret = secp256k1::SecretKey::from_slice(private_key).map_err(|e| Error::InvalidKey(format!("유효하지 않은 개인키: {}", e)))?;
+        let public = secp256k1::PublicKey::from_secret_key(ecdsa::context(), &secret);
+        Ok(public.serialize_uncompressed().to_vec())
+    }
+
+    /// 압축(33바이트), 비압축(65바이트) 공개키 모두 허용
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        let public = secp256k1::PublicKey::from_slice(public_key).map_err(|e| Error::InvalidKey(format!("유효하지 않은 공개키: {}", e)))?;
+        Ok(evm::to_checksum_address(&evm::public_key_to_address(&public.serialize_uncompressed())))
+    }
+
+    /// Ethereum 메인넷 기준 (다른 EVM 체인은 chain id로 구분)
+    fn symbol(&self) -> Option<&str> {
+        Some("ETH")
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        Some(18)
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        Some("https://etherscan.io/address/{address}")
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        Some("https://etherscan.io/tx/{txid}")
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        Some("eip155:1")
+    }
+}
+
+/// Solana (Phantom 기본 경로)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolanaChain;
+
+impl Chain for SolanaChain {
+    fn id(&self) -> &str {
+        "solana"
+    }
+
+    fn name(&self) -> &str {
+        "Solana"
+    }
+
+    fn coin_type(&self) -> u32 {
+        501
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Ed25519
+    }
+
+    /// Phantom 방식 (m/44'/501'/account'/index')
+    fn path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/501'/{}'/{}'", account, index)
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        Ok(bs58::encode(ed25519_public_key(public_key)?).into_string())
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        Some("SOL")
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        Some(9)
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        Some("https://explorer.solana.com/address/{address}")
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        Some("https://explorer.solana.com/tx/{txid}")
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        Some("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp")
+    }
+}
+
+/// Sui (Ed25519 스킴)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SuiChain;
+
+impl Chain for SuiChain {
+    fn id(&self) -> &str {
+        "sui"
+    }
+
+    fn name(&self) -> &str {
+        "Sui"
+    }
+
+    fn coin_type(&self) -> u32 {
+        784
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Ed25519
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        let address = sui::derive_sui_address(&ed25519_public_key(public_key)?, SignatureScheme::Ed25519);
+        Ok(format!("0x{}", hex::encode(address)))
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        Some("SUI")
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        Some(9)
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        Some("https://suiscan.xyz/mainnet/account/{address}")
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        Some("https://suiscan.xyz/mainnet/tx/{txid}")
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        Some("sui:mainnet")
+    }
+}
+
+/// XRP Ledger (클래식 주소 r...)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct XrpChain;
+
+impl Chain for XrpChain {
+    fn id(&self) -> &str {
+        "xrp"
+    }
+
+    fn name(&self) -> &str {
+        "XRP Ledger"
+    }
+
+    fn coin_type(&self) -> u32 {
+        144
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        let public_key = compressed_secp256k1(public_key)?;
+        Ok(xrp::encode_address(&hash::hash160(&public_key)))
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        Some("XRP")
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        Some(6)
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        Some("https://livenet.xrpl.org/accounts/{address}")
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        Some("https://livenet.xrpl.org/transactions/{txid}")
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        Some("xrpl:0")
+    }
+}
+
+/// Cosmos SDK 체인 (HASH160 + Bech32)
+impl Chain for CosmosChain {
+    fn id(&self) -> &str {
+        CosmosChain::id(self)
+    }
+
+    fn name(&self) -> &str {
+        CosmosChain::name(self)
+    }
+
+    fn coin_type(&self) -> u32 {
+        CosmosChain::coin_type(self)
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Secp256k1
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        let public_key = compressed_secp256k1(public_key)?;
+        Ok(encode_bech32(self.hrp(), None, &hash::hash160(&public_key)))
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        Some(CosmosChain::symbol(self))
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        Some(CosmosChain::decimals(self))
+    }
+
+    fn denom(&self) -> Option<&str> {
+        Some(CosmosChain::denom(self))
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        Some(CosmosChain::explorer_address_template(self))
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        Some(CosmosChain::explorer_tx_template(self))
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        Some(CosmosChain::caip2(self))
+    }
+}
+
+/// Substrate (Ed25519, 범용 SS58 접두사 42)
+///
+/// BIP-32 시드 대신 니모닉 엔트로피로 미니 시크릿을 만들고 (substrate-bip39),
+/// 경로는 Secret URI junction 문법 (`//hard/soft`)으로 해석
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubstrateChain;
+
+impl Chain for SubstrateChain {
+    fn id(&self) -> &str {
+        "substrate"
+    }
+
+    fn name(&self) -> &str {
+        "Substrate"
+    }
+
+    fn coin_type(&self) -> u32 {
+        354
+    }
+
+    fn curve(&self) -> Curve {
+        Curve::Ed25519
+    }
+
+    /// account 0, index 0이면 도출 없음, 그 외 `//account//index`
+    fn path(&self, account: u32, index: u32) -> String {
+        if account == 0 && index == 0 {
+            String::new()
+        } else {
+            format!("//{}//{}", account, index)
+        }
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        Ok(substrate::ss58_encode(&ed25519_public_key(public_key)?, SS58_GENERIC))
+    }
+
+    fn derive_account(&self, source: &SeedSource<'_>, path: &str) -> Result<ChainAccount> {
+        let account = SubstrateAccount::from_mnemonic(source.mnemonic, source.passphrase)?.derive(&parse_junctions(path)?)?;
+
+        Ok(ChainAccount {
+            chain_id: self.id().to_string(),
+            path: path.to_string(),
+            address: self.format_address(&account.public_key)?,
+            public_key: account.public_key.to_vec(),
+            private_key: account.private_key.clone(),
+        })
+    }
+}
+
+/// 압축/비압축 secp256k1 공개키 → 압축 33바이트
+fn compressed_secp256k1(public_key: &[u8]) -> Result<[u8; 33]> {
+    let public = secp256k1::PublicKey::from_slice(public_key).map_err(|e| Error::InvalidKey(format!("유효하지 않은 공개키: {}", e)))?;
+    Ok(public.serialize())
+}
+
+fn ed25519_public_key(public_key: &[u8]) -> Result<[u8; 32]> {
+    public_key
+        .try_into()
+        .map_err(|_| Error::InvalidKey(format!("Ed25519 공개키는 32바이트여야 합니다 (현재 {}바이트)", public_key.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::mnemonic_to_seed;
+    use crate::bitcoin::BitcoinAccount;
+    use crate::cosmos::CosmosAccount;
+    use crate::evm::EvmAccount;
+    use crate::solana::SolanaAccount;
+    use crate::sui::SuiAccount;
+    use crate::xrp::XrpAccount;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// NEAR 암시적 계정: 주소 = Ed25519 공개키 hex
+    struct NearChain;
+
+    impl Chain for NearChain {
+        fn id(&self) -> &str {
+            "near"
+        }
+
+        fn name(&self) -> &str {
+            "NEAR"
+        }
+
+        fn coin_type(&self) -> u32 {
+            397
+        }
+
+        fn curve(&self) -> Curve {
+            Curve::Ed25519
+        }
+
+        fn path(&self, account: u32, _index: u32) -> String {
+            format!("m/44'/397'/{}'", account)
+        }
+
+        fn format_address(&self, public_key: &[u8]) -> Result<String> {
+            Ok(hex::encode(ed25519_public_key(public_key)?))
+        }
+    }
+
+    fn derive_default(id: &str) -> ChainAccount {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let source = SeedSource::new(MNEMONIC, "", seed.as_slice());
+        let chain = get(id).unwrap();
+        chain.derive_account(&source, &chain.default_path()).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_chains_match_accounts() {
+        for chain in registered() {
+            let account = derive_default(chain.id());
+            println!("{:<12}{:<22}{}", chain.name(), account.path, account.address);
+        }
+
+        let bitcoin = BitcoinAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let account = derive_default("bitcoin");
+        assert_eq!(account.address, bitcoin.address());
+        assert_eq!(account.public_key, bitcoin.public_key);
+        assert_eq!(account.private_key, bitcoin.private_key);
+
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(derive_default("evm").address, evm.address_checksummed());
+        assert_eq!(derive_default("evm").public_key, evm.public_key);
+
+        assert_eq!(derive_default("solana").address, SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap().address());
+        assert_eq!(derive_default("sui").address, SuiAccount::from_mnemonic(MNEMONIC, "").unwrap().address());
+
+        let xrp = XrpAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(derive_default("xrp").address, xrp.address());
+        assert_eq!(derive_default("xrp").public_key, xrp.public_key);
+
+        let terra = CosmosAccount::from_mnemonic_for_chain(MNEMONIC, "", CosmosChain::Terra).unwrap();
+        assert_eq!(derive_default("terra").address, terra.address_for_chain(CosmosChain::Terra));
+
+        let substrate = SubstrateAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(derive_default("substrate").address, substrate.address(SS58_GENERIC));
+    }
+
+    #[test]
+    fn test_paths() {
+        use crate::bitcoin::BITCOIN_SEGWIT_PATH;
+        use crate::evm::EVM_PATH;
+        use crate::solana::SOLANA_PATH;
+        use crate::sui::SUI_PATH;
+        use crate::xrp::XRP_PATH;
+
+        assert_eq!(BitcoinChain.default_path(), BITCOIN_SEGWIT_PATH);
+        assert_eq!(EvmChain.default_path(), EVM_PATH);
+        assert_eq!(SolanaChain.default_path(), SOLANA_PATH);
+        assert_eq!(SuiChain.default_path(), SUI_PATH);
+        assert_eq!(XrpChain.default_path(), XRP_PATH);
+        assert_eq!(SubstrateChain.default_path(), "");
+
+        assert_eq!(CosmosChain::Osmosis.path(2, 7), "m/44'/118'/2'/0/7");
+        assert_eq!(SuiChain.path(1, 3), "m/44'/784'/1'/0'/3'");
+        assert_eq!(SolanaChain.path(1, 3), "m/44'/501'/1'/3'");
+        assert_eq!(SubstrateChain.path(0, 5), "//0//5");
+    }
+
+    #[test]
+    fn test_format_address() {
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let compressed = secp256k1::PublicKey::from_slice(&evm.public_key).unwrap().serialize();
+        assert_eq!(EvmChain.format_address(&compressed).unwrap(), evm.address_checksummed());
+        assert_eq!(EvmChain.format_address(&evm.public_key).unwrap(), evm.address_checksummed());
+
+        assert!(BitcoinChain.format_address(&[0u8; 33]).is_err());
+        assert!(SolanaChain.format_address(&[0u8; 33]).is_err());
+    }
+
+    #[test]
+    fn test_metadata() {
+        let bitcoin = get("bitcoin").unwrap();
+        assert_eq!((bitcoin.symbol(), bitcoin.decimals(), bitcoin.denom()), (Some("BTC"), Some(8), None));
+        assert_eq!(
+            bitcoin.address_url("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu").unwrap(),
+            "https://mempool.space/address/bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+
+        let osmosis = get("osmosis").unwrap();
+        assert_eq!((osmosis.symbol(), osmosis.decimals(), osmosis.denom()), (Some("OSMO"), Some(6), Some("uosmo")));
+        assert_eq!(osmosis.tx_url("ABCD").unwrap(), "https://www.mintscan.io/osmosis/tx/ABCD");
+        assert_eq!(get("injective").unwrap().decimals(), Some(18));
+        assert_eq!(get("kava").unwrap().caip2(), Some("cosmos:kava_2222-10"));
+        assert_eq!(SubstrateChain.caip2(), None);
+
+        assert_eq!(EvmChain.decimals(), Some(18));
+        assert_eq!(SubstrateChain.symbol(), None);
+        assert_eq!(SubstrateChain.address_url("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"), None);
+        assert_eq!(NearChain.decimals(), None);
+
+        // 모든 내장 템플릿에 자리표시자가 있음
+        for chain in builtin_chains() {
+            if let Some(template) = chain.explorer_address_template() {
+                assert!(template.contains("{address}"), "{}", chain.id());
+            }
+            if let Some(template) = chain.explorer_tx_template() {
+                assert!(template.contains("{txid}"), "{}", chain.id());
+            }
+            if let Some(caip2) = chain.caip2() {
+                assert!(crate::caip::ChainId::parse(caip2).is_ok(), "{}", chain.id());
+            }
+        }
+    }
+
+    #[test]
+    fn test_substrate_path() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let source = SeedSource::new(MNEMONIC, "", seed.as_slice());
+
+        let account = SubstrateChain.derive_account(&source, "//polkadot//0").unwrap();
+        let expected = SubstrateAccount::from_uri(&format!("{}//polkadot//0", MNEMONIC)).unwrap();
+        assert_eq!(account.address, expected.address(SS58_GENERIC));
+
+        assert!(SubstrateChain.derive_account(&source, "/soft").is_err());
+    }
+
+    #[test]
+    fn test_register_custom_chain() {
+        register(NearChain).unwrap();
+        assert!(matches!(register(NearChain), Err(Error::Chain(_))));
+
+        let near = get("NEAR").unwrap();
+        assert_eq!(near.coin_type(), 397);
+
+        let account = derive_default("near");
+        println!("NEAR: {}", account.address);
+        assert_eq!(account.address.len(), 64);
+        assert_eq!(account.address, hex::encode(&account.public_key));
+
+        assert!(registered().iter().any(|c| c.id() == "near"));
+        assert!(get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_builtin_ids_are_unique() {
+        let chains = builtin_chains();
+        for (i, chain) in chains.iter().enumerate() {
+            assert!(chains[i + 1..].iter().all(|other| other.id() != chain.id()), "중복 id: {}", chain.id());
+        }
+    }
+}
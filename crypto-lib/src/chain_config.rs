@@ -0,0 +1,582 @@
+//! Chain Config
+//!
+//! 설정 파일(JSON, TOML)의 체인 정의를 런타임 레지스트리에 등록
+//! Cosmos 포크나 EVM 체인을 추가할 때 다시 컴파일하지 않아도 됨
+//!
+//! ## 형식
+//! ```text
+//! [[chains]]
+//! id = "juno"
+//! name = "Juno"
+//! curve = "secp256k1"
+//! coin_type = 118
+//! address = { format = "bech32", hrp = "juno" }
+//! symbol = "JUNO"                                 # 표시용 메타데이터는 모두 선택
+//! decimals = 6
+//! denom = "ujuno"
+//! explorer = { address = "https://www.mintscan.io/juno/address/{address}", tx = "https://www.mintscan.io/juno/tx/{txid}" }
+//! caip2 = "cosmos:juno-1"                         # CAIP-10 계정 식별자에 쓰임
+//!
+//! [[chains]]
+//! id = "dogecoin"
+//! name = "Dogecoin"
+//! curve = "secp256k1"
+//! coin_type = 3
+//! path = "m/44'/3'/{account}'/0/{index}"     # 생략하면 곡선별 BIP-44 기본 경로
+//! address = { format = "base58check", version = "1e" }
+//! ```
+//! JSON은 같은 구조 (`{"chains": [{...}]}`), TOML은 feature = "toml"
+//!
+//! ## 주소 형식
+//!
+//! | format | 곡선 | 주소 | 추가 필드 |
+//! |--------|------|------|----------|
+//! | `evm` | secp256k1 | Keccak-256(비압축 공개키)[12..] EIP-55 | 없음 |
+//! | `bech32` | secp256k1 | Bech32(hrp, HASH160(압축 공개키)) | `hrp` |
+//! | `segwit` | secp256k1 | SegWit v0 P2WPKH | `hrp` |
+//! | `base58check` | secp256k1 | Base58Check(version, HASH160) | `version` (hex, 1~4바이트) |
+//! | `base58` | ed25519 | Base58(공개키) | 없음 |
+//! | `ss58` | ed25519 | SS58(prefix, 공개키) | `prefix` (0~16383) |
+//! | `hex` | 모두 | 접두사 + hex(공개키) | `prefix` (선택) |
+//!
+//! 곡선과 맞지 않는 형식, 잘못된 HRP/버전 바이트, 경로/탐색기 템플릿 오류는
+//! 체인 id와 필드 이름을 담은 `Error::Chain`으로 반환 (하나라도 틀리면 아무것도 등록하지 않음)
+//!
+//! `ss58`은 SLIP-10 Ed25519 키를 씀 (내장 `substrate`의 substrate-bip39 도출과 다름)
+//!
+//! ```
+//! use crypto_lib::chain_config;
+//! use crypto_lib::wallet::{Chain, Wallet};
+//!
+//! let json = r#"{"chains": [{"id": "doc-juno", "name": "Juno", "curve": "secp256k1", "coin_type": 118,
+//!                            "address": {"format": "bech32", "hrp": "juno"}}]}"#;
+//! chain_config::load_json(json).unwrap();
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let chain = Chain::from_id("doc-juno").unwrap();
+//! let wallet = Wallet::builder().mnemonic(mnemonic).chain(chain).build().unwrap();
+//! assert!(wallet.address().starts_with("juno1"));
+//! ```
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::bip32::parse_path;
+use crate::hash::hash160;
+use crate::caip::ChainId;
+use crate::chain::{self, Chain, EvmChain};
+use crate::encoding::base58check;
+use crate::encoding::bech32::encode_bech32;
+use crate::slip10::Curve;
+use crate::substrate::ss58_encode;
+use crate::error::{Error, Result};
+
+/// 설정 파일 최상위 (`chains` 배열)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChainConfigFile {
+    /// 체인 정의 목록
+    pub chains: Vec<ChainConfig>,
+}
+
+/// 체인 하나의 정의
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChainConfig {
+    /// 레지스트리 id (소문자, 숫자, `-`, `_`)
+    pub id: String,
+    /// 표시 이름
+    pub name: String,
+    /// "secp256k1", "ed25519", "nist256p1"
+    pub curve: String,
+    /// BIP-44 coin type
+    pub coin_type: u32,
+    /// 경로 템플릿 (`{account}`, `{index}` 치환, 생략하면 곡선별 BIP-44 기본 경로)
+    #[serde(default)]
+    pub path: Option<String>,
+    /// 주소 형식
+    pub address: AddressConfig,
+    /// 기본 자산 티커
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// 기본 자산 소수 자릿수
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    /// Cosmos SDK 기본 denom
+    #[serde(default)]
+    pub denom: Option<String>,
+    /// 탐색기 URL 템플릿
+    #[serde(default)]
+    pub explorer: Option<ExplorerConfig>,
+    /// CAIP-2 메인넷 체인 식별자 (예: "cosmos:juno-1")
+    #[serde(default)]
+    pub caip2: Option<String>,
+}
+
+/// 탐색기 URL 템플릿
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExplorerConfig {
+    /// 주소 페이지 (`{address}` 치환)
+    #[serde(default)]
+    pub address: Option<String>,
+    /// 트랜잭션 페이지 (`{txid}` 치환)
+    #[serde(default)]
+    pub tx: Option<String>,
+}
+
+/// 공개키 → 주소 알고리즘
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "format", rename_all = "snake_case", deny_unknown_fields)]
+pub enum AddressConfig {
+    /// EVM (EIP-55 체크섬)
+    Evm,
+    /// Cosmos 방식 Bech32(hrp, HASH160)
+    Bech32 {
+        /// human-readable part
+        hrp: String,
+    },
+    /// SegWit v0 P2WPKH (Litecoin `ltc` 등)
+    Segwit {
+        /// human-readable part
+        hrp: String,
+    },
+    /// Base58Check(version, HASH160) (P2PKH 방식)
+    Base58check {
+        /// 버전 바이트 hex (1~4바이트)
+        version: String,
+    },
+    /// Base58(공개키) (Solana 방식)
+    Base58,
+    /// SS58 (Substrate 방식)
+    Ss58 {
+        /// 네트워크 접두사
+        prefix: u16,
+    },
+    /// 접두사 + hex(공개키)
+    Hex {
+        /// 주소 앞에 붙일 문자열 (예: "0x")
+        #[serde(default)]
+        prefix: String,
+    },
+}
+
+impl AddressConfig {
+    fn format_name(&self) -> &'static str {
+        match self {
+            AddressConfig::Evm => "evm",
+            AddressConfig::Bech32 { .. } => "bech32",
+            AddressConfig::Segwit { .. } => "segwit",
+            AddressConfig::Base58check { .. } => "base58check",
+            AddressConfig::Base58 => "base58",
+            AddressConfig::Ss58 { .. } => "ss58",
+            AddressConfig::Hex { .. } => "hex",
+        }
+    }
+
+    /// 이 형식이 요구하는 곡선 (`None`이면 제한 없음)
+    fn required_curve(&self) -> Option<Curve> {
+        match self {
+            AddressConfig::Evm | AddressConfig::Bech32 { .. } | AddressConfig::Segwit { .. } | AddressConfig::Base58check { .. } => Some(Curve::Secp256k1),
+            AddressConfig::Base58 | AddressConfig::Ss58 { .. } => Some(Curve::Ed25519),
+            AddressConfig::Hex { .. } => None,
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 검증된 체인
+// ═══════════════════════════════════════════════════════════════
+
+/// 검증을 통과한 설정 체인 (`Chain` 구현)
+#[derive(Debug, Clone)]
+pub struct ConfigChain {
+    config: ChainConfig,
+    curve: Curve,
+    version: Vec<u8>,
+}
+
+impl ConfigChain {
+    /// 설정 검증 (곡선/형식 조합, HRP, 버전 바이트, 경로 템플릿)
+    pub fn new(config: ChainConfig) -> Result<Self> {
+        let fail = |field: &str, message: String| Error::Chain(format!("체인 설정 '{}' {}: {}", config.id, field, message));
+
+        if config.id.is_empty() || !config.id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_') {
+            return Err(fail("id", "소문자, 숫자, '-', '_'만 쓸 수 있습니다".to_string()));
+        }
+        if config.name.trim().is_empty() {
+            return Err(fail("name", "비어 있습니다".to_string()));
+        }
+
+        let curve = match config.curve.as_str() {
+            "secp256k1" => Curve::Secp256k1,
+            "ed25519" => Curve::Ed25519,
+            "nist256p1" => Curve::Nist256p1,
+            other => return Err(fail("curve", format!("지원하지 않는 곡선입니다: {} (secp256k1, ed25519, nist256p1)", other))),
+        };
+
+        let format = config.address.format_name();
+        if let Some(required) = config.address.required_curve() {
+            if required != curve {
+                return Err(fail("address.format", format!("'{}' 형식은 {} 곡선이 필요합니다 (현재 {})", format, curve_name(required), config.curve)));
+            }
+        }
+
+        let mut version = Vec::new();
+        match &config.address {
+            AddressConfig::Bech32 { hrp } | AddressConfig::Segwit { hrp } => validate_hrp(hrp).map_err(|message| fail("address.hrp", message))?,
+            AddressConfig::Base58check { version: hex_version } => {
+                version = hex::decode(hex_version).map_err(|e| fail("address.version", format!("hex가 아닙니다: {}", e)))?;
+                if !(1..=4).contains(&version.len()) {
+                    return Err(fail("address.version", format!("1~4바이트여야 합니다 (현재 {}바이트)", version.len())));
+                }
+            }
+            AddressConfig::Ss58 { prefix } if *prefix > 16383 => {
+                return Err(fail("address.prefix", format!("SS58 접두사는 0~16383이어야 합니다: {}", prefix)));
+            }
+            _ => {}
+        }
+
+        if let Some(explorer) = &config.explorer {
+            for (field, template, placeholder) in [("explorer.address", &explorer.address, "{address}"), ("explorer.tx", &explorer.tx, "{txid}")] {
+                if let Some(template) = template {
+                    if !template.contains(placeholder) {
+                        return Err(fail(field, format!("{}가 있어야 합니다: {}", placeholder, template)));
+                    }
+                }
+            }
+        }
+
+        if let Some(caip2) = &config.caip2 {
+            ChainId::parse(caip2).map_err(|e| fail("caip2", e.to_string()))?;
+        }
+
+        let chain = ConfigChain { config, curve, version };
+        if let Some(template) = &chain.config.path {
+            chain.validate_path(template).map_err(|message| Error::Chain(format!("체인 설정 '{}' path: {}", chain.config.id, message)))?;
+        }
+        Ok(chain)
+    }
+
+    /// 원본 설정
+    pub fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    fn validate_path(&self, template: &str) -> std::result::Result<(), String> {
+        if !template.contains("{account}") || !template.contains("{index}") {
+            return Err(format!("{{account}}와 {{index}}가 모두 있어야 합니다: {}", template));
+        }
+
+        let path = self.path(0, 0);
+        let children = parse_path(&path).map_err(|e| format!("{} ({})", e, template))?;
+        if self.curve == Curve::Ed25519 && children.iter().any(|child| !child.is_hardened()) {
+            return Err(format!("Ed25519는 모든 단계가 강화 도출이어야 합니다: {}", template));
+        }
+        Ok(())
+    }
+}
+
+impl Chain for ConfigChain {
+    fn id(&self) -> &str {
+        &self.config.id
+    }
+
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    fn coin_type(&self) -> u32 {
+        self.config.coin_type
+    }
+
+    fn curve(&self) -> Curve {
+        self.curve
+    }
+
+    fn path(&self, account: u32, index: u32) -> String {
+        match &self.config.path {
+            Some(template) => template.replace("{account}", &account.to_string()).replace("{index}", &index.to_string()),
+            None => match self.curve {
+                Curve::Ed25519 => format!("m/44'/{}'/{}'/0'/{}'", self.config.coin_type, account, index),
+                Curve::Secp256k1 | Curve::Nist256p1 => format!("m/44'/{}'/{}'/0/{}", self.config.coin_type, account, index),
+            },
+        }
+    }
+
+    fn public_key(&self, private_key: &[u8; 32]) -> Result<Vec<u8>> {
+        match self.config.address {
+            AddressConfig::Evm => EvmChain.public_key(private_key),
+            _ => {
+                let public_key = crate::slip10::public_key(private_key, self.curve)?;
+                Ok(match self.curve {
+                    Curve::Ed25519 => public_key[1..].to_vec(),
+                    Curve::Secp256k1 | Curve::Nist256p1 => public_key.to_vec(),
+                })
+            }
+        }
+    }
+
+    fn format_address(&self, public_key: &[u8]) -> Result<String> {
+        Ok(match &self.config.address {
+            AddressConfig::Evm => EvmChain.format_address(public_key)?,
+            AddressConfig::Bech32 { hrp } => encode_bech32(hrp, None, &hash160(&compressed_secp256k1(public_key)?)),
+            AddressConfig::Segwit { hrp } => encode_bech32(hrp, Some(0), &hash160(&compressed_secp256k1(public_key)?)),
+            AddressConfig::Base58check { .. } => base58check::encode(&self.version, &hash160(&compressed_secp256k1(public_key)?)),
+            AddressConfig::Base58 => bs58::encode(ed25519_public_key(public_key)?).into_string(),
+            AddressConfig::Ss58 { prefix } => ss58_encode(&ed25519_public_key(public_key)?, *prefix),
+            AddressConfig::Hex { prefix } => format!("{}{}", prefix, hex::encode(public_key)),
+        })
+    }
+
+    fn symbol(&self) -> Option<&str> {
+        self.config.symbol.as_deref()
+    }
+
+    fn decimals(&self) -> Option<u8> {
+        self.config.decimals
+    }
+
+    fn denom(&self) -> Option<&str> {
+        self.config.denom.as_deref()
+    }
+
+    fn explorer_address_template(&self) -> Option<&str> {
+        self.config.explorer.as_ref()?.address.as_deref()
+    }
+
+    fn explorer_tx_template(&self) -> Option<&str> {
+        self.config.explorer.as_ref()?.tx.as_deref()
+    }
+
+    fn caip2(&self) -> Option<&str> {
+        self.config.caip2.as_deref()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 읽기 / 등록
+// ═══════════════════════════════════════════════════════════════
+
+/// JSON 설정 파싱 + 검증 (등록하지 않음)
+pub fn parse_json(json: &str) -> Result<Vec<ConfigChain>> {
+    let file: ChainConfigFile = serde_json::from_str(json).map_err(|e| Error::Chain(format!("체인 설정 JSON 파싱 실패: {}", e)))?;
+    validate_all(file)
+}
+
+/// TOML 설정 파싱 + 검증 (등록하지 않음)
+#[cfg(feature = "toml")]
+pub fn parse_toml(toml: &str) -> Result<Vec<ConfigChain>> {
+    let file: ChainConfigFile = toml::from_str(toml).map_err(|e| Error::Chain(format!("체인 설정 TOML 파싱 실패: {}", e)))?;
+    validate_all(file)
+}
+
+/// JSON 설정의 체인을 모두 등록하고 등록한 id 반환
+pub fn load_json(json: &str) -> Result<Vec<String>> {
+    register_all(parse_json(json)?)
+}
+
+/// TOML 설정의 체인을 모두 등록하고 등록한 id 반환
+#[cfg(feature = "toml")]
+pub fn load_toml(toml: &str) -> Result<Vec<String>> {
+    register_all(parse_toml(toml)?)
+}
+
+/// 확장자(.json, .toml)로 형식을 골라 파일의 체인을 등록
+pub fn load_file(path: impl AsRef<Path>) -> Result<Vec<String>> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path).map_err(|e| Error::Io(format!("{}: {}", path.display(), e)))?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => load_json(&contents),
+        #[cfg(feature = "toml")]
+        Some("toml") => load_toml(&contents),
+        _ => Err(Error::Chain(format!("지원하지 않는 체인 설정 파일입니다: {}", path.display()))),
+    }
+}
+
+/// 검증된 체인 등록 (이미 등록된 id가 하나라도 있으면 아무것도 등록하지 않음)
+pub fn register_all(chains: Vec<ConfigChain>) -> Result<Vec<String>> {
+    if let Some(existing) = chains.iter().find(|c| chain::get(c.id()).is_some()) {
+        return Err(Error::Chain(format!("이미 등록된 체인입니다: {}", existing.id())));
+    }
+
+    let mut ids = Vec::with_capacity(chains.len());
+    for config_chain in chains {
+        ids.push(config_chain.id().to_string());
+        chain::register(config_chain)?;
+    }
+    Ok(ids)
+}
+
+fn validate_all(file: ChainConfigFile) -> Result<Vec<ConfigChain>> {
+    let mut seen = HashSet::new();
+    for config in &file.chains {
+        if !seen.insert(config.id.as_str()) {
+            return Err(Error::Chain(format!("체인 설정에 같은 id가 두 번 있습니다: {}", config.id)));
+        }
+    }
+    file.chains.into_iter().map(ConfigChain::new).collect()
+}
+
+/// BIP-173 HRP: 1~83자, ASCII 33~126, 소문자
+fn validate_hrp(hrp: &str) -> std::result::Result<(), String> {
+    if hrp.is_empty() || hrp.len() > 83 {
+        return Err(format!("1~83자여야 합니다 (현재 {}자)", hrp.len()));
+    }
+    if let Some(c) = hrp.chars().find(|c| !(33..=126).contains(&(*c as u32)) || c.is_ascii_uppercase()) {
+        return Err(format!("소문자 ASCII(33~126)만 쓸 수 있습니다: '{}'", c));
+    }
+    Ok(())
+}
+
+fn curve_name(curve: Curve) -> &'static str {
+    match curve {
+        Curve::Secp256k1 => "secp256k1",
+        Curve::Ed25519 => "ed25519",
+        Curve::Nist256p1 => "nist256p1",
+    }
+}
+
+fn compressed_secp256k1(public_key: &[u8]) -> Result<[u8; 33]> {
+    let public = secp256k1::PublicKey::from_slice(public_key).map_err(|e| Error::InvalidKey(format!("유효하지 않은 공개키: {}", e)))?;
+    Ok(public.serialize())
+}
+
+fn ed25519_public_key(public_key: &[u8]) -> Result<[u8; 32]> {
+    public_key
+        .try_into()
+        .map_err(|_| Error::InvalidKey(format!("Ed25519 공개키는 32바이트여야 합니다 (현재 {}바이트)", public_key.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::mnemonic_to_seed;
+    use crate::chain::SeedSource;
+    use crate::cosmos::CosmosChain;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn derive_default(chain: &dyn Chain) -> String {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let source = SeedSource::new(MNEMONIC, "", seed.as_slice());
+        chain.derive_account(&source, &chain.default_path()).unwrap().address
+    }
+
+    fn parse_one(json: &str) -> Result<ConfigChain> {
+        parse_json(&format!(r#"{{"chains": [{}]}}"#, json)).map(|mut chains| chains.remove(0))
+    }
+
+    #[test]
+    fn test_matches_builtin_chains() {
+        // 설정으로 정의한 Osmosis, EVM = 내장 체인
+        let osmosis = parse_one(r#"{"id": "cfg-osmo", "name": "Osmo", "curve": "secp256k1", "coin_type": 118, "address": {"format": "bech32", "hrp": "osmo"}}"#).unwrap();
+        assert_eq!(derive_default(&osmosis), derive_default(&CosmosChain::Osmosis));
+
+        let evm = parse_one(r#"{"id": "cfg-evm", "name": "EVM", "curve": "secp256k1", "coin_type": 60, "address": {"format": "evm"}}"#).unwrap();
+        assert_eq!(derive_default(&evm), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+
+        let solana = parse_one(r#"{"id": "cfg-sol", "name": "Sol", "curve": "ed25519", "coin_type": 501,
+                                   "path": "m/44'/501'/{account}'/{index}'", "address": {"format": "base58"}}"#).unwrap();
+        assert_eq!(derive_default(&solana), derive_default(&chain::SolanaChain));
+    }
+
+    #[test]
+    fn test_other_formats() {
+        let dogecoin = parse_one(r#"{"id": "cfg-doge", "name": "Dogecoin", "curve": "secp256k1", "coin_type": 3,
+                                     "address": {"format": "base58check", "version": "1e"}}"#).unwrap();
+        let litecoin = parse_one(r#"{"id": "cfg-ltc", "name": "Litecoin", "curve": "secp256k1", "coin_type": 2,
+                                     "path": "m/84'/2'/{account}'/0/{index}", "address": {"format": "segwit", "hrp": "ltc"}}"#).unwrap();
+        let kusama = parse_one(r#"{"id": "cfg-ksm", "name": "Kusama", "curve": "ed25519", "coin_type": 434, "address": {"format": "ss58", "prefix": 2}}"#).unwrap();
+        let near = parse_one(r#"{"id": "cfg-near", "name": "NEAR", "curve": "ed25519", "coin_type": 397, "address": {"format": "hex"}}"#).unwrap();
+
+        for chain in [&dogecoin, &litecoin, &kusama, &near] {
+            println!("{:<10} {:<22} {}", chain.name(), chain.default_path(), derive_default(chain));
+        }
+        assert!(derive_default(&dogecoin).starts_with('D'));
+        assert!(derive_default(&litecoin).starts_with("ltc1q"));
+        assert_eq!(litecoin.path(1, 2), "m/84'/2'/1'/0/2");
+        assert_eq!(derive_default(&near).len(), 64);
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let cases = [
+            // 곡선과 형식이 맞지 않음
+            (r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "evm"}}"#, "address.format"),
+            (r#"{"id": "x", "name": "X", "curve": "secp256k1", "coin_type": 1, "address": {"format": "ss58", "prefix": 0}}"#, "address.format"),
+            (r#"{"id": "x", "name": "X", "curve": "curve448", "coin_type": 1, "address": {"format": "hex"}}"#, "curve"),
+            (r#"{"id": "X Chain", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "hex"}}"#, "id"),
+            (r#"{"id": "x", "name": "X", "curve": "secp256k1", "coin_type": 1, "address": {"format": "bech32", "hrp": "Juno"}}"#, "address.hrp"),
+            (r#"{"id": "x", "name": "X", "curve": "secp256k1", "coin_type": 1, "address": {"format": "base58check", "version": "0102030405"}}"#, "address.version"),
+            (r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "ss58", "prefix": 20000}}"#, "address.prefix"),
+            // Ed25519는 비강화 도출 불가
+            (r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "path": "m/44'/1'/{account}'/0/{index}", "address": {"format": "hex"}}"#, "path"),
+            (r#"{"id": "x", "name": "X", "curve": "secp256k1", "coin_type": 1, "path": "m/44'/1'/0'/0/0", "address": {"format": "hex"}}"#, "path"),
+            // 탐색기 템플릿에 자리표시자 없음
+            (r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "hex"}, "explorer": {"tx": "https://x.io/tx"}}"#, "explorer.tx"),
+            (r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "hex"}, "caip2": "near"}"#, "caip2"),
+        ];
+        for (json, field) in cases {
+            let err = parse_one(json).unwrap_err();
+            println!("{}", err);
+            assert!(matches!(&err, Error::Chain(message) if message.contains(field)), "{}", err);
+        }
+
+        // 알 수 없는 필드, 형식
+        assert!(parse_one(r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "cashaddr"}}"#).is_err());
+        assert!(parse_one(r#"{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "hrp": "x", "address": {"format": "hex"}}"#).is_err());
+
+        // 파일 안의 중복 id
+        let duplicate = r#"{"chains": [{"id": "x", "name": "X", "curve": "ed25519", "coin_type": 1, "address": {"format": "hex"}},
+                                       {"id": "x", "name": "Y", "curve": "ed25519", "coin_type": 2, "address": {"format": "hex"}}]}"#;
+        assert!(matches!(parse_json(duplicate), Err(Error::Chain(_))));
+    }
+
+    #[test]
+    fn test_metadata() {
+        let juno = parse_one(r#"{"id": "cfg-meta-juno", "name": "Juno", "curve": "secp256k1", "coin_type": 118, "address": {"format": "bech32", "hrp": "juno"},
+                                 "symbol": "JUNO", "decimals": 6, "denom": "ujuno",
+                                 "explorer": {"address": "https://www.mintscan.io/juno/address/{address}", "tx": "https://www.mintscan.io/juno/tx/{txid}"},
+                                 "caip2": "cosmos:juno-1"}"#).unwrap();
+        assert_eq!((juno.symbol(), juno.decimals(), juno.denom()), (Some("JUNO"), Some(6), Some("ujuno")));
+        assert_eq!(juno.address_url("juno1abc").unwrap(), "https://www.mintscan.io/juno/address/juno1abc");
+        assert_eq!(juno.tx_url("FF").unwrap(), "https://www.mintscan.io/juno/tx/FF");
+        assert_eq!(juno.caip2(), Some("cosmos:juno-1"));
+
+        // 생략하면 없음
+        let near = parse_one(r#"{"id": "cfg-meta-near", "name": "NEAR", "curve": "ed25519", "coin_type": 397, "address": {"format": "hex"}}"#).unwrap();
+        assert_eq!((near.symbol(), near.decimals(), near.address_url("x")), (None, None, None));
+    }
+
+    #[test]
+    fn test_register() {
+        let json = r#"{"chains": [{"id": "cfg-juno", "name": "Juno", "curve": "secp256k1", "coin_type": 118, "address": {"format": "bech32", "hrp": "juno"}}]}"#;
+        assert_eq!(load_json(json).unwrap(), ["cfg-juno"]);
+        assert!(derive_default(chain::get("cfg-juno").unwrap().as_ref()).starts_with("juno1"));
+
+        // 이미 등록된 id (내장 체인 포함)
+        assert!(matches!(load_json(json), Err(Error::Chain(_))));
+        let builtin = r#"{"chains": [{"id": "evm", "name": "EVM", "curve": "secp256k1", "coin_type": 60, "address": {"format": "evm"}}]}"#;
+        assert!(matches!(load_json(builtin), Err(Error::Chain(_))));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_toml() {
+        let toml = r#"
+            [[chains]]
+            id = "cfg-toml-stars"
+            name = "Stargaze"
+            curve = "secp256k1"
+            coin_type = 118
+            address = { format = "bech32", hrp = "stars" }
+        "#;
+        let chains = parse_toml(toml).unwrap();
+        assert!(derive_default(&chains[0]).starts_with("stars1"));
+        assert!(parse_toml("[[chains]]\nid = 1").is_err());
+    }
+}
@@ -0,0 +1,251 @@
+//! Fuzzy Mnemonic Word Correction
+//!
+//! 손으로 옮겨 적은 니모닉의 오타를 단어 목록 기준으로 교정
+//!
+//! ## 교정 규칙 (우선순위 순)
+//! 1. 단어 목록에 그대로 있으면 정답
+//! 2. 앞 4글자가 단어 하나의 앞 4글자와만 일치하면 그 단어
+//!    (BIP-39 영어 등은 앞 4글자만으로 단어가 유일하게 결정되도록 설계됨)
+//! 3. 편집 거리(Levenshtein)가 가장 가까운 단어들 (최대 2)
+//!
+//! 가장 가까운 후보가 하나뿐일 때만 자동 교정 (모호하면 사용자가 선택)
+//!
+//! ## 다국어
+//! 모든 비교는 NFKD 정규화 후 문자 단위로 수행
+//! (한국어는 자모 단위 → 받침 하나 오타도 거리 1)
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::bip39::{validate_mnemonic, Language, MnemonicError};
+
+/// 유일 접두사 길이
+const UNIQUE_PREFIX_LEN: usize = 4;
+
+/// 허용하는 최대 편집 거리
+const MAX_EDIT_DISTANCE: usize = 2;
+
+/// 단어 하나당 최대 제안 수
+pub const MAX_SUGGESTIONS: usize = 5;
+
+/// 입력 단어 하나에 대한 교정 제안
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordSuggestion {
+    /// 니모닉 내 위치 (0부터)
+    pub index: usize,
+    /// 입력된 단어
+    pub word: String,
+    /// 단어 목록에 있는지
+    pub is_valid: bool,
+    /// 가까운 단어들 (가까운 순)
+    pub suggestions: Vec<&'static str>,
+}
+
+/// 단어 하나에 대한 교정 후보 (가까운 순, 최대 `MAX_SUGGESTIONS`개)
+pub fn suggest_words(word: &str, language: Language) -> Vec<&'static str> {
+    ranked_suggestions(word, language)
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect()
+}
+
+/// (편집 거리, 단어) 목록 - 정확히 일치하거나 유일 접두사면 거리 0인 단어 하나
+fn ranked_suggestions(word: &str, language: Language) -> Vec<(usize, &'static str)> {
+    let word: Vec<char> = word.nfkd().flat_map(char::to_lowercase).collect();
+    let wordlist = language.words();
+
+    // 1. 정확히 일치
+    if let Some(&exact) = wordlist.iter().find(|w| w.chars().eq(word.iter().copied())) {
+        return vec![(0, exact)];
+    }
+
+    // 2. 유일 접두사 일치
+    if word.len() >= UNIQUE_PREFIX_LEN {
+        let prefix = &word[..UNIQUE_PREFIX_LEN];
+        let matches: Vec<&'static str> = wordlist
+            .iter()
+            .copied()
+            .filter(|w| w.chars().take(UNIQUE_PREFIX_LEN).eq(prefix.iter().copied()))
+            .collect();
+        if let [only] = matches.as_slice() {
+            return vec![(0, only)];
+        }
+    }
+
+    // 3. 편집 거리 (짧은 단어는 거리 1까지만)
+    let max_distance = if word.len() <= UNIQUE_PREFIX_LEN { 1 } else { MAX_EDIT_DISTANCE };
+    let mut scored: Vec<(usize, &'static str)> = wordlist
+        .iter()
+        .filter_map(|&candidate| {
+            let candidate_chars: Vec<char> = candidate.chars().collect();
+            let distance = edit_distance(&word, &candidate_chars);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+
+    // 거리 순, 같은 거리는 단어 목록 순서 유지
+    scored.sort_by_key(|(distance, _)| *distance);
+    scored.truncate(MAX_SUGGESTIONS);
+    scored
+}
+
+/// 니모닉의 각 단어에 대한 교정 제안 (언어 자동 감지)
+pub fn suggest_mnemonic(mnemonic: &str) -> (Language, Vec<WordSuggestion>) {
+    let language = detect_language_fuzzy(mnemonic);
+    let wordlist = language.words();
+
+    let suggestions = mnemonic
+        .split_whitespace()
+        .enumerate()
+        .map(|(index, word)| {
+            let normalized: String = word.nfkd().flat_map(char::to_lowercase).collect();
+            WordSuggestion {
+                index,
+                word: word.to_string(),
+                is_valid: wordlist.contains(&normalized.as_str()),
+                suggestions: suggest_words(word, language),
+            }
+        })
+        .collect();
+
+    (language, suggestions)
+}
+
+/// 모호하지 않은 오타를 자동 교정한 뒤 검증
+///
+/// 가장 가까운 후보가 하나뿐인 단어만 교정 (같은 거리의 후보가 여럿이면 모호함)
+/// 교정할 수 없는 단어가 있으면 `UnknownWord` 오류
+/// 교정 결과의 체크섬이 틀리면 `InvalidChecksum` 오류
+pub fn autocorrect_mnemonic(mnemonic: &str) -> Result<String, MnemonicError> {
+    let language = detect_language_fuzzy(mnemonic);
+
+    let mut words = Vec::new();
+    for (index, word) in mnemonic.split_whitespace().enumerate() {
+        match ranked_suggestions(word, language).as_slice() {
+            [(_, only)] => words.push(*only),
+            [(best, best_word), (next, _), ..] if best < next => words.push(*best_word),
+            _ => {
+                return Err(MnemonicError::UnknownWord {
+                    index,
+                    word: word.to_string(),
+                })
+            }
+        }
+    }
+
+    let corrected = words.join(language.separator());
+    validate_mnemonic(&corrected)?;
+    Ok(corrected)
+}
+
+/// 오타가 섞인 니모닉의 언어 감지 (단어 목록에 있는 단어가 가장 많은 언어)
+fn detect_language_fuzzy(mnemonic: &str) -> Language {
+    let words: Vec<String> = mnemonic
+        .split_whitespace()
+        .map(|w| w.nfkd().flat_map(char::to_lowercase).collect())
+        .collect();
+
+    // 같은 개수면 ALL 순서상 앞의 언어 우선
+    let mut best = (Language::English, 0);
+    for &language in Language::ALL {
+        let wordlist = language.words();
+        let matched = words.iter().filter(|w| wordlist.contains(&w.as_str())).count();
+        if matched > best.1 {
+            best = (language, matched);
+        }
+    }
+    best.0
+}
+
+/// Levenshtein 편집 거리 (삽입/삭제/치환 각 1)
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_edit_distance() {
+        let d = |a: &str, b: &str| {
+            edit_distance(&a.chars().collect::<Vec<_>>(), &b.chars().collect::<Vec<_>>())
+        };
+        assert_eq!(d("abandon", "abandon"), 0);
+        assert_eq!(d("abandn", "abandon"), 1);
+        assert_eq!(d("abadnon", "abandon"), 2);
+        assert_eq!(d("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_suggest_words() {
+        // 정확히 일치
+        assert_eq!(suggest_words("zoo", Language::English), vec!["zoo"]);
+        // 유일 접두사 (앞 4글자)
+        assert_eq!(suggest_words("aban", Language::English), vec!["abandon"]);
+        assert_eq!(suggest_words("abandonn", Language::English), vec!["abandon"]);
+        // 대문자 입력
+        assert_eq!(suggest_words("About", Language::English), vec!["about"]);
+        // 편집 거리
+        assert_eq!(suggest_words("abandn", Language::English)[0], "abandon");
+
+        // 모호한 입력 → 여러 후보
+        let suggestions = suggest_words("cet", Language::English);
+        println!("'cet' 후보: {:?}", suggestions);
+        assert!(suggestions.len() > 1);
+        assert!(suggestions.len() <= MAX_SUGGESTIONS);
+
+        // 전혀 다른 단어
+        assert!(suggest_words("xxxxxxxx", Language::English).is_empty());
+    }
+
+    #[test]
+    fn test_autocorrect_mnemonic() {
+        let typo = "abandn abandon abandon abandon abandon abandon abandon abandon abandon abandon aban abot";
+        assert_eq!(autocorrect_mnemonic(typo).unwrap(), MNEMONIC);
+
+        // 모호한 단어는 교정하지 않음
+        let ambiguous = "cet abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(matches!(
+            autocorrect_mnemonic(ambiguous),
+            Err(MnemonicError::UnknownWord { index: 0, .. })
+        ));
+
+        // 교정은 되지만 체크섬이 틀린 경우
+        let wrong = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandn";
+        assert_eq!(autocorrect_mnemonic(wrong), Err(MnemonicError::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_suggest_mnemonic() {
+        let (language, suggestions) = suggest_mnemonic("abandn abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+        assert_eq!(language, Language::English);
+        assert_eq!(suggestions.len(), 12);
+        assert!(!suggestions[0].is_valid);
+        assert_eq!(suggestions[0].suggestions[0], "abandon");
+        assert!(suggestions[1].is_valid);
+    }
+
+    #[cfg(feature = "korean")]
+    #[test]
+    fn test_korean_correction() {
+        // "가격" → "가걱" (자모 하나 오타)
+        let suggestions = suggest_words("가걱", Language::Korean);
+        let expected: String = "가격".nfkd().collect();
+        println!("'가걱' 후보: {:?}", suggestions);
+        assert!(suggestions.contains(&expected.as_str()));
+    }
+}
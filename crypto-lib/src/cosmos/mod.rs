@@ -18,20 +18,43 @@
 //! - Juno: juno1...
 //! - Terra: terra1...
 //! - Injective: inj1...
+//!
+//! ## 공개키 전용 주소
+//! `CosmosAddress::from_public_key`는 압축 공개키(33바이트)만으로 주소를 계산
+//! (사용자 공개키로 주소를 확인하는 백엔드용, 개인키 없음)
+//!
+//! ## Watch-only 계정
+//! 계정 xpub(m/44'/118'/0')만으로 입금 주소 m/44'/118'/0'/0/i를 도출
+//! (개인키를 보관하지 않는 서버용)
+//!
+//! ## 하위 모듈
+//! - `signing`: 메시지 서명/검증, ADR-036 임의 메시지 서명
+//! - `transaction`: SIGN_MODE_DIRECT SignDoc 서명, TxRaw 인코딩
 
-use sha2::{Sha256, Digest};
-use ripemd::Ripemd160;
-use secp256k1::{Secp256k1, SecretKey, PublicKey};
+pub mod signing;
+pub mod transaction;
 
-use crate::bip32::{master_key_from_seed, ExtendedPrivateKey};
-use crate::bip39::mnemonic_to_seed;
-use crate::utils::bech32::encode_bech32;
+use std::fmt;
+use std::str::FromStr;
+
+
+use crate::bip32::{master_key_from_seed, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, IntoDerivationPath};
+use crate::account_iter::AccountIter;
+use crate::bip39::{mnemonic_to_seed, mnemonic_to_seed_checked};
+use crate::ecdsa::{self, RecoverableSignature};
+use crate::schnorr;
+use crate::encoding::bech32::encode_bech32;
+use crate::hash::hash160;
+use crate::secret::SecretBytes;
+use crate::error::{Error, Result};
 
 /// Cosmos 계정
-#[derive(Debug, Clone)]
+///
+/// `Debug`는 개인키를 가림 (`account` 모듈에서 구현)
+#[derive(Clone)]
 pub struct CosmosAccount {
     /// 개인키 (32바이트)
-    pub private_key: [u8; 32],
+    pub private_key: SecretBytes<32>,
     /// 압축 공개키 (33바이트)
     pub public_key: [u8; 33],
     /// 공개키 해시 (20바이트) - HASH160(pubkey)
@@ -42,7 +65,7 @@ pub struct CosmosAccount {
 pub const COSMOS_PATH: &str = "m/44'/118'/0'/0/0";
 
 /// Cosmos SDK 체인 HRP (Human Readable Part)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CosmosChain {
     /// Cosmos Hub (cosmos1...)
     CosmosHub,
@@ -90,42 +113,202 @@ impl CosmosChain {
             CosmosChain::Kava => 459,
         }
     }
+
+    /// 체인 id (소문자, `chain` 레지스트리 키)
+    pub fn id(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "cosmoshub",
+            CosmosChain::Osmosis => "osmosis",
+            CosmosChain::Juno => "juno",
+            CosmosChain::Terra => "terra",
+            CosmosChain::Injective => "injective",
+            CosmosChain::Secret => "secret",
+            CosmosChain::Akash => "akash",
+            CosmosChain::Kava => "kava",
+        }
+    }
+
+    /// 체인 표시 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "Cosmos Hub",
+            CosmosChain::Osmosis => "Osmosis",
+            CosmosChain::Juno => "Juno",
+            CosmosChain::Terra => "Terra",
+            CosmosChain::Injective => "Injective",
+            CosmosChain::Secret => "Secret",
+            CosmosChain::Akash => "Akash",
+            CosmosChain::Kava => "Kava",
+        }
+    }
+
+    /// 기본 자산 티커
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "ATOM",
+            CosmosChain::Osmosis => "OSMO",
+            CosmosChain::Juno => "JUNO",
+            CosmosChain::Terra => "LUNA",
+            CosmosChain::Injective => "INJ",
+            CosmosChain::Secret => "SCRT",
+            CosmosChain::Akash => "AKT",
+            CosmosChain::Kava => "KAVA",
+        }
+    }
+
+    /// 기본 denom (잔액/수수료 단위)
+    pub fn denom(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "uatom",
+            CosmosChain::Osmosis => "uosmo",
+            CosmosChain::Juno => "ujuno",
+            CosmosChain::Terra => "uluna",
+            CosmosChain::Injective => "inj",
+            CosmosChain::Secret => "uscrt",
+            CosmosChain::Akash => "uakt",
+            CosmosChain::Kava => "ukava",
+        }
+    }
+
+    /// denom → 표시 단위 소수 자릿수 (u 접두사 denom은 6, Injective는 18)
+    pub fn decimals(&self) -> u8 {
+        match self {
+            CosmosChain::Injective => 18,
+            _ => 6,
+        }
+    }
+
+    /// 주소 탐색기 URL 템플릿 (Mintscan, `{address}` 치환)
+    pub fn explorer_address_template(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "https://www.mintscan.io/cosmos/address/{address}",
+            CosmosChain::Osmosis => "https://www.mintscan.io/osmosis/address/{address}",
+            CosmosChain::Juno => "https://www.mintscan.io/juno/address/{address}",
+            CosmosChain::Terra => "https://www.mintscan.io/terra/address/{address}",
+            CosmosChain::Injective => "https://www.mintscan.io/injective/address/{address}",
+            CosmosChain::Secret => "https://www.mintscan.io/secret/address/{address}",
+            CosmosChain::Akash => "https://www.mintscan.io/akash/address/{address}",
+            CosmosChain::Kava => "https://www.mintscan.io/kava/address/{address}",
+        }
+    }
+
+    /// 트랜잭션 탐색기 URL 템플릿 (Mintscan, `{txid}` 치환)
+    pub fn explorer_tx_template(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "https://www.mintscan.io/cosmos/tx/{txid}",
+            CosmosChain::Osmosis => "https://www.mintscan.io/osmosis/tx/{txid}",
+            CosmosChain::Juno => "https://www.mintscan.io/juno/tx/{txid}",
+            CosmosChain::Terra => "https://www.mintscan.io/terra/tx/{txid}",
+            CosmosChain::Injective => "https://www.mintscan.io/injective/tx/{txid}",
+            CosmosChain::Secret => "https://www.mintscan.io/secret/tx/{txid}",
+            CosmosChain::Akash => "https://www.mintscan.io/akash/tx/{txid}",
+            CosmosChain::Kava => "https://www.mintscan.io/kava/tx/{txid}",
+        }
+    }
+
+    /// CAIP-2 식별자 (`cosmos:<메인넷 chain-id>`)
+    pub fn caip2(&self) -> &'static str {
+        match self {
+            CosmosChain::CosmosHub => "cosmos:cosmoshub-4",
+            CosmosChain::Osmosis => "cosmos:osmosis-1",
+            CosmosChain::Juno => "cosmos:juno-1",
+            CosmosChain::Terra => "cosmos:phoenix-1",
+            CosmosChain::Injective => "cosmos:injective-1",
+            CosmosChain::Secret => "cosmos:secret-4",
+            CosmosChain::Akash => "cosmos:akashnet-2",
+            CosmosChain::Kava => "cosmos:kava_2222-10",
+        }
+    }
+
+    /// 지원하는 모든 Cosmos 체인
+    pub const ALL: [CosmosChain; 8] = [
+        CosmosChain::CosmosHub,
+        CosmosChain::Osmosis,
+        CosmosChain::Juno,
+        CosmosChain::Terra,
+        CosmosChain::Injective,
+        CosmosChain::Secret,
+        CosmosChain::Akash,
+        CosmosChain::Kava,
+    ];
+}
+
+impl fmt::Display for CosmosChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CosmosChain {
+    type Err = Error;
+
+    /// id, 표시 이름, HRP 파싱 (대소문자 무시: "osmosis", "Cosmos Hub", "osmo")
+    fn from_str(s: &str) -> Result<Self> {
+        CosmosChain::ALL
+            .into_iter()
+            .find(|chain| [chain.id(), chain.name(), chain.hrp()].iter().any(|name| name.eq_ignore_ascii_case(s)))
+            .ok_or_else(|| Error::Chain(format!("지원하지 않는 Cosmos 체인입니다: {}", s)))
+    }
 }
 
 impl CosmosAccount {
     /// 개인키에서 Cosmos 계정 생성
-    pub fn from_private_key(private_key: [u8; 32]) -> Self {
-        let public_key = private_key_to_public_key(&private_key);
+    ///
+    /// 개인키가 0이거나 곡선 위수 n 이상이면 오류
+    pub fn from_private_key(private_key: [u8; 32]) -> Result<Self> {
+        let public_key = ecdsa::public_key(&private_key)?.serialize();
         let pubkey_hash = hash160(&public_key);
 
-        CosmosAccount {
-            private_key,
+        Ok(CosmosAccount {
+            private_key: SecretBytes::new(private_key),
             public_key,
             pubkey_hash,
-        }
+        })
     }
 
     /// 확장 개인키에서 Cosmos 계정 생성
-    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Self {
-        Self::from_private_key(extended_key.private_key)
+    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Result<Self> {
+        Self::from_private_key(*extended_key.private_key)
     }
 
     /// 시드에서 Cosmos 계정 생성 (기본 경로)
-    pub fn from_seed(seed: &[u8]) -> Result<Self, String> {
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
         Self::from_seed_with_path(seed, COSMOS_PATH)
     }
 
     /// 시드에서 특정 경로로 Cosmos 계정 생성
-    pub fn from_seed_with_path(seed: &[u8], path: &str) -> Result<Self, String> {
+    pub fn from_seed_with_path(seed: &[u8], path: impl IntoDerivationPath) -> Result<Self> {
         let master = master_key_from_seed(seed)?;
         let derived = master.derive_path(path)?;
-        Ok(Self::from_extended_key(&derived))
+        Self::from_extended_key(&derived)
+    }
+
+    /// 시드에서 체인의 계정을 index 순서로 도출하는 반복자 (m/44'/{coin}'/0'/0/i)
+    pub fn iter_from_seed(seed: &[u8], chain: CosmosChain) -> Result<AccountIter<Self>> {
+        AccountIter::bip32(seed, format!("m/44'/{}'/0'/0", chain.coin_type()), 0, Self::from_private_key)
     }
 
     /// 니모닉에서 Cosmos 계정 생성
-    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, String> {
+    ///
+    /// BIP-39 체크섬까지 검증 (틀리면 `Error::InvalidMnemonic`)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// BIP-39 검증 없이 Cosmos Hub 계정 생성 (`COSMOS_PATH`)
+    pub fn from_mnemonic_unchecked(mnemonic: &str, passphrase: &str) -> Result<Self> {
         let seed = mnemonic_to_seed(mnemonic, passphrase);
-        Self::from_seed(&seed)
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// 니모닉에서 Cosmos Hub 계정 생성 (m/44'/118'/{account}'/{change}/{index})
+    ///
+    /// 인덱스가 2^31 이상이면 `Error::InvalidPath`
+    pub fn from_mnemonic_with_index(mnemonic: &str, passphrase: &str, account: u32, change: u32, index: u32) -> Result<Self> {
+        let path = DerivationPath::checked_bip_purpose(44, CosmosChain::CosmosHub.coin_type(), account, change, index)?;
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
     }
 
     /// 니모닉에서 특정 체인의 Cosmos 계정 생성
@@ -133,10 +316,10 @@ impl CosmosAccount {
         mnemonic: &str,
         passphrase: &str,
         chain: CosmosChain
-    ) -> Result<Self, String> {
-        let seed = mnemonic_to_seed(mnemonic, passphrase);
+    ) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
         let path = format!("m/44'/{}'/0'/0/0", chain.coin_type());
-        Self::from_seed_with_path(&seed, &path)
+        Self::from_seed_with_path(seed.as_slice(), &path)
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -160,7 +343,7 @@ impl CosmosAccount {
 
     /// 개인키를 hex 문자열로 반환
     pub fn private_key_hex(&self) -> String {
-        hex::encode(self.private_key)
+        hex::encode(&self.private_key)
     }
 
     /// 공개키를 hex 문자열로 반환
@@ -168,37 +351,170 @@ impl CosmosAccount {
         hex::encode(self.public_key)
     }
 
+    /// 비압축 공개키 (65바이트, 0x04 || x || y)
+    pub fn public_key_uncompressed(&self) -> Result<[u8; 65]> {
+        uncompress(&self.public_key)
+    }
+
+    /// 비압축 공개키를 hex 문자열로 반환
+    pub fn public_key_uncompressed_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.public_key_uncompressed()?))
+    }
+
     /// 공개키 해시를 hex 문자열로 반환
     pub fn pubkey_hash_hex(&self) -> String {
         hex::encode(self.pubkey_hash)
     }
+
+    /// 32바이트 다이제스트에 ECDSA 서명 (RFC 6979, low-S), r || s
+    pub fn sign_ecdsa_compact(&self, digest: &[u8; 32]) -> Result<[u8; 64]> {
+        ecdsa::sign_compact(&self.private_key, digest)
+    }
+
+    /// 32바이트 다이제스트에 복원 가능한 ECDSA 서명 (RFC 6979, low-S)
+    pub fn sign_ecdsa_recoverable(&self, digest: &[u8; 32]) -> Result<RecoverableSignature> {
+        ecdsa::sign_recoverable(&self.private_key, digest)
+    }
+
+    /// x-only 공개키 (32바이트, BIP-340)
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        let mut x_only = [0u8; 32];
+        x_only.copy_from_slice(&self.public_key[1..]);
+        x_only
+    }
+
+    /// 32바이트 메시지에 BIP-340 Schnorr 서명 (aux_rand 없음 → 결정적)
+    pub fn sign_schnorr(&self, message: &[u8; 32]) -> Result<[u8; 64]> {
+        schnorr::sign(&self.private_key, message, None)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
-// 내부 함수
+// 공개키 전용 주소
+// ═══════════════════════════════════════════════════════════════
+
+/// 공개키만 가진 Cosmos 주소
+///
+/// 주소 계산과 서명 검증만 가능 (개인키 없음)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CosmosAddress {
+    /// 압축 공개키 (33바이트)
+    pub public_key: [u8; 33],
+    /// 공개키 해시 (20바이트) - HASH160(pubkey)
+    pub pubkey_hash: [u8; 20],
+}
+
+impl CosmosAddress {
+    /// 압축 공개키에서 생성 (곡선 위의 점이 아니면 오류)
+    pub fn from_public_key(public_key: [u8; 33]) -> Result<Self> {
+        secp256k1::PublicKey::from_slice(&public_key)
+            .map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))?;
+
+        Ok(CosmosAddress {
+            public_key,
+            pubkey_hash: hash160(&public_key),
+        })
+    }
+
+    /// 특정 체인의 주소 반환 (Bech32)
+    pub fn address_for_chain(&self, chain: CosmosChain) -> String {
+        encode_bech32(chain.hrp(), None, &self.pubkey_hash)
+    }
+
+    /// Cosmos Hub 주소 반환 (cosmos1...)
+    pub fn address(&self) -> String {
+        self.address_for_chain(CosmosChain::CosmosHub)
+    }
+
+    /// 커스텀 HRP로 주소 반환
+    pub fn address_with_hrp(&self, hrp: &str) -> String {
+        encode_bech32(hrp, None, &self.pubkey_hash)
+    }
+
+    /// 공개키를 hex 문자열로 반환
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.public_key)
+    }
+
+    /// 비압축 공개키 (65바이트, 0x04 || x || y)
+    pub fn public_key_uncompressed(&self) -> Result<[u8; 65]> {
+        uncompress(&self.public_key)
+    }
+
+    /// 비압축 공개키를 hex 문자열로 반환
+    pub fn public_key_uncompressed_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.public_key_uncompressed()?))
+    }
+
+    /// 공개키 해시를 hex 문자열로 반환
+    pub fn pubkey_hash_hex(&self) -> String {
+        hex::encode(self.pubkey_hash)
+    }
+}
+
+impl From<&CosmosAccount> for CosmosAddress {
+    fn from(account: &CosmosAccount) -> Self {
+        CosmosAddress {
+            public_key: account.public_key,
+            pubkey_hash: account.pubkey_hash,
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════
+// Watch-only 계정 (xpub)
+// ═══════════════════════════════════════════════════════════════
+
+/// Cosmos watch-only 계정 (계정 레벨 xpub m/44'/118'/0', 동작은 [`crate::bip32`] 참고)
+#[derive(Debug, Clone)]
+pub struct CosmosWatchAccount {
+    /// 확장 공개키
+    pub xpub: ExtendedPublicKey,
+}
+
+impl CosmosWatchAccount {
+    /// xpub 문자열에서 생성 (xpub/tpub 모두 허용)
+    pub fn from_xpub(xpub: &str) -> Result<Self> {
+        Ok(Self::from_extended_key(ExtendedPublicKey::from_base58(xpub)?))
+    }
+
+    /// 확장 공개키에서 생성
+    pub fn from_extended_key(xpub: ExtendedPublicKey) -> Self {
+        CosmosWatchAccount { xpub }
+    }
 
-/// 개인키 → 압축 공개키 (secp256k1)
-fn private_key_to_public_key(private_key: &[u8; 32]) -> [u8; 33] {
-    let secp = Secp256k1::new();
-    let secret = SecretKey::from_slice(private_key).expect("유효한 개인키");
-    let public = PublicKey::from_secret_key(&secp, &secret);
-    public.serialize() // 압축 공개키 (33바이트)
+    /// 상대 경로의 압축 공개키 (예: "m/0/5")
+    pub fn public_key_at(&self, path: impl IntoDerivationPath) -> Result<[u8; 33]> {
+        Ok(self.xpub.derive_path(path)?.public_key)
+    }
+
+    /// 상대 경로의 특정 체인 주소
+    pub fn address_at(&self, path: impl IntoDerivationPath, chain: CosmosChain) -> Result<String> {
+        let pubkey_hash = hash160(&self.public_key_at(path)?);
+        Ok(encode_bech32(chain.hrp(), None, &pubkey_hash))
+    }
+
+    /// 외부 체인(0/index) Cosmos Hub 입금 주소
+    pub fn address(&self, index: u32) -> Result<String> {
+        self.address_at(format!("m/0/{}", index), CosmosChain::CosmosHub)
+    }
 }
 
-/// HASH160 = RIPEMD160(SHA256(data))
-fn hash160(data: &[u8]) -> [u8; 20] {
-    let sha256_hash = Sha256::digest(data);
-    let ripemd_hash = Ripemd160::digest(sha256_hash);
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
 
-    let mut result = [0u8; 20];
-    result.copy_from_slice(&ripemd_hash);
-    result
+/// 압축 공개키 → 비압축 공개키 (공개 필드라 직접 만든 값이면 유효하지 않을 수 있음)
+fn uncompress(public_key: &[u8; 33]) -> Result<[u8; 65]> {
+    secp256k1::PublicKey::from_slice(public_key)
+        .map(|public| public.serialize_uncompressed())
+        .map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::encoding::bech32::{decode_bech32, Variant};
 
     #[test]
     fn test_cosmos_from_mnemonic() {
@@ -268,7 +584,7 @@ mod tests {
 
         for i in 0..5 {
             let path = format!("m/44'/118'/0'/0/{}", i);
-            let account = CosmosAccount::from_seed_with_path(&seed, &path).unwrap();
+            let account = CosmosAccount::from_seed_with_path(seed.as_slice(), &path).unwrap();
 
             println!("경로: {}", path);
             println!("주소: {}", account.address());
@@ -276,19 +592,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_hash160() {
-        // 테스트 벡터: Bitcoin과 동일한 HASH160 사용
-        let pubkey = hex::decode("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798").unwrap();
-        let hash = hash160(&pubkey);
-
-        // Bitcoin과 동일한 결과
-        assert_eq!(
-            hex::encode(hash),
-            "751e76e8199196d454941c45d1b3a323f1433bd6"
-        );
-    }
-
     #[test]
     fn test_bech32_encoding() {
         // HASH160 → Cosmos 주소 테스트
@@ -299,4 +602,108 @@ mod tests {
         assert!(address.starts_with("cosmos1"));
         println!("Bech32 테스트: {}", address);
     }
+
+    #[test]
+    fn test_address_round_trip() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = CosmosAccount::from_mnemonic(mnemonic, "").unwrap();
+
+        for chain in CosmosChain::ALL {
+            let address = account.address_for_chain(chain);
+            let (hrp, data, variant) = decode_bech32(&address).unwrap();
+            assert_eq!(hrp, chain.hrp());
+            assert_eq!(data, account.pubkey_hash);
+            assert_eq!(variant, Variant::Bech32);
+        }
+    }
+
+    #[test]
+    fn test_watch_account() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "");
+
+        let account_key = master_key_from_seed(seed.as_slice()).unwrap().derive_path("m/44'/118'/0'").unwrap();
+        let xpub = account_key.to_public().to_base58(crate::bitcoin::Network::Mainnet);
+        let watch = CosmosWatchAccount::from_xpub(&xpub).unwrap();
+
+        println!("\n=== Cosmos watch-only ({}) ===\n", xpub);
+
+        for i in 0..3 {
+            let full = CosmosAccount::from_seed_with_path(seed.as_slice(), format!("m/44'/118'/0'/0/{}", i)).unwrap();
+            let address = watch.address(i).unwrap();
+            println!("0/{}: {}", i, address);
+
+            assert_eq!(address, full.address());
+            assert_eq!(
+                watch.address_at(format!("m/0/{}", i), CosmosChain::Osmosis).unwrap(),
+                full.address_for_chain(CosmosChain::Osmosis)
+            );
+        }
+
+        assert!(watch.address_at("m/0'/0", CosmosChain::CosmosHub).is_err());
+    }
+
+    #[test]
+    fn test_chain_names() {
+        for chain in CosmosChain::ALL {
+            assert_eq!(chain.id().parse::<CosmosChain>(), Ok(chain));
+            assert_eq!(chain.to_string().parse::<CosmosChain>(), Ok(chain));
+            assert_eq!(chain.hrp().parse::<CosmosChain>(), Ok(chain));
+        }
+        assert_eq!(CosmosChain::CosmosHub.to_string(), "Cosmos Hub");
+        assert_eq!("OSMO".parse::<CosmosChain>(), Ok(CosmosChain::Osmosis));
+        assert_eq!("evmos".parse::<CosmosChain>(), Err(Error::Chain("지원하지 않는 Cosmos 체인입니다: evmos".to_string())));
+    }
+
+    #[test]
+    fn test_address_from_public_key() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = CosmosAccount::from_mnemonic(mnemonic, "").unwrap();
+
+        let address = CosmosAddress::from_public_key(account.public_key).unwrap();
+        assert_eq!(address, CosmosAddress::from(&account));
+        assert_eq!(address.pubkey_hash, account.pubkey_hash);
+        for chain in CosmosChain::ALL {
+            assert_eq!(address.address_for_chain(chain), account.address_for_chain(chain));
+        }
+
+        // 곡선 위의 점이 아닌 공개키
+        let mut invalid = account.public_key;
+        invalid[0] = 0x04;
+        assert_eq!(CosmosAddress::from_public_key(invalid).unwrap_err(), Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()));
+        assert!(CosmosAddress::from_public_key([0u8; 33]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_private_key() {
+        // 0과 곡선 위수 n은 유효한 개인키가 아님 → panic 대신 오류
+        assert!(CosmosAccount::from_private_key([0u8; 32]).is_err());
+        assert!(CosmosAccount::from_private_key([0xff; 32]).is_err());
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let account = CosmosAccount::from_private_key(one).unwrap();
+        assert_eq!(account.public_key_hex(), "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+    }
+
+    #[test]
+    fn test_public_key_uncompressed() {
+        // 생성점 G (개인키 1)
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let account = CosmosAccount::from_private_key(one).unwrap();
+        let expected = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        assert_eq!(account.public_key_uncompressed_hex().unwrap(), expected);
+        assert_eq!(CosmosAddress::from(&account).public_key_uncompressed_hex().unwrap(), expected);
+
+        // 직접 만든 잘못된 공개키는 패닉 대신 에러
+        let invalid = CosmosAddress { public_key: [0x05; 33], ..CosmosAddress::from(&account) };
+        assert!(matches!(invalid.public_key_uncompressed(), Err(Error::InvalidKey(_))));
+
+        // EVM 계정과 같은 키
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = CosmosAccount::from_mnemonic_for_chain(mnemonic, "", CosmosChain::Injective).unwrap();
+        let evm = crate::evm::EvmAccount::from_mnemonic(mnemonic, "").unwrap();
+        assert_eq!(account.public_key_uncompressed().unwrap(), evm.public_key);
+    }
 }
@@ -0,0 +1,138 @@
+//! Cosmos Message Signing
+//!
+//! Cosmos SDK 서명 = SHA-256(서명 대상 바이트)에 대한 secp256k1 ECDSA
+//! - 서명 형식: r || s (64바이트, v 없음)
+//! - high-S 서명은 거부 (SDK와 동일, 서명 변형 방지)
+//! - 공개키가 서명에 들어 있지 않으므로 검증자는 압축 공개키(33바이트)를 따로 받아야 함
+//!
+//! ## ADR-036 임의 메시지 서명 (Keplr `signArbitrary`)
+//! 메시지를 체인에서 실행할 수 없는 가짜 트랜잭션(amino JSON)에 넣어 서명
+//! ```text
+//! {"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"",
+//!  "msgs":[{"type":"sign/MsgSignData","value":{"data":"<base64>","signer":"<주소>"}}],"sequence":"0"}
+//! ```
+//! (키 정렬, 공백 없는 JSON)
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use secp256k1::{Message, PublicKey};
+use sha2::{Digest, Sha256};
+
+use super::{CosmosAccount, CosmosAddress};
+use crate::hash::hash160;
+use crate::ecdsa;
+use crate::error::{Error, Result};
+
+impl CosmosAccount {
+    /// 메시지 서명 (SHA-256 후 ECDSA, r || s)
+    pub fn sign_message(&self, message: &[u8]) -> Result<[u8; 64]> {
+        ecdsa::sign_compact(&self.private_key, &Sha256::digest(message).into())
+    }
+
+    /// 이 계정의 공개키로 서명 검증
+    pub fn verify_message(&self, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+        verify_signature(&self.public_key, message, signature)
+    }
+
+    /// ADR-036 임의 메시지 서명
+    ///
+    /// `hrp`: 서명자 주소의 HRP (예: "cosmos", "osmo")
+    pub fn sign_arbitrary(&self, hrp: &str, data: &[u8]) -> Result<[u8; 64]> {
+        let sign_doc = adr036_sign_doc(&self.address_with_hrp(hrp), data);
+        self.sign_message(sign_doc.as_bytes())
+    }
+}
+
+impl CosmosAddress {
+    /// 이 주소의 공개키로 서명 검증
+    pub fn verify_message(&self, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+        verify_signature(&self.public_key, message, signature)
+    }
+}
+
+/// 서명 검증 (SHA-256 후 ECDSA, high-S 거부)
+pub fn verify_signature(public_key: &[u8; 33], message: &[u8], signature: &[u8; 64]) -> Result<()> {
+    let public = PublicKey::from_slice(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))?;
+    let signature = secp256k1::ecdsa::Signature::from_compact(signature).map_err(|_| Error::InvalidSignature("유효하지 않은 ECDSA 서명".to_string()))?;
+    let digest: [u8; 32] = Sha256::digest(message).into();
+
+    ecdsa::context()
+        .verify_ecdsa(&Message::from_digest(digest), &signature, &public)
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+}
+
+/// ADR-036 서명 검증
+///
+/// `signer`: 서명자 Bech32 주소, `public_key`가 이 주소의 공개키인지도 확인
+pub fn verify_arbitrary(signer: &str, data: &[u8], public_key: &[u8; 33], signature: &[u8; 64]) -> Result<()> {
+    let (_, pubkey_hash) = ::bech32::decode(signer.trim()).map_err(|e| Error::Bech32(format!("유효하지 않은 Bech32 주소: {}", e)))?;
+    if pubkey_hash != hash160(public_key) {
+        return Err(Error::InvalidSignature("공개키가 서명자 주소와 다릅니다".to_string()));
+    }
+
+    verify_signature(public_key, adr036_sign_doc(signer.trim(), data).as_bytes(), signature)
+}
+
+/// ADR-036 서명 대상 JSON
+pub fn adr036_sign_doc(signer: &str, data: &[u8]) -> String {
+    format!(
+        concat!(
+            r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","#,
+            r#""msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+        ),
+        BASE64.encode(data),
+        signer
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_sign_and_verify() {
+        let account = CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let signature = account.sign_message(b"sign doc bytes").unwrap();
+
+        assert!(account.verify_message(b"sign doc bytes", &signature).is_ok());
+        // 공개키만으로 검증
+        let address = CosmosAddress::from_public_key(account.public_key).unwrap();
+        assert!(address.verify_message(b"sign doc bytes", &signature).is_ok());
+        assert!(verify_signature(&account.public_key, b"other", &signature).is_err());
+
+        // high-S로 바꾼 서명 (s → n - s)은 거부
+        let s = secp256k1::SecretKey::from_slice(&signature[32..]).unwrap().negate();
+        let mut high_s = signature;
+        high_s[32..].copy_from_slice(&s.secret_bytes());
+        assert!(verify_signature(&account.public_key, b"sign doc bytes", &high_s).is_err());
+    }
+
+    #[test]
+    fn test_adr036() {
+        let account = CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let address = account.address();
+
+        let sign_doc = adr036_sign_doc(&address, b"hello");
+        println!("ADR-036 sign doc: {}", sign_doc);
+        assert_eq!(
+            sign_doc,
+            format!(
+                r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"aGVsbG8=","signer":"{}"}}}}],"sequence":"0"}}"#,
+                address
+            )
+        );
+
+        let signature = account.sign_arbitrary("cosmos", b"hello").unwrap();
+        assert!(verify_arbitrary(&address, b"hello", &account.public_key, &signature).is_ok());
+        assert!(verify_arbitrary(&address, b"hell0", &account.public_key, &signature).is_err());
+
+        // 다른 체인 주소로는 서명 대상이 달라짐
+        assert!(verify_arbitrary(&account.address_with_hrp("osmo"), b"hello", &account.public_key, &signature).is_err());
+
+        // 다른 계정의 공개키
+        let other = CosmosAccount::from_mnemonic(MNEMONIC, "passphrase").unwrap();
+        assert!(verify_arbitrary(&address, b"hello", &other.public_key, &signature).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+//! Cosmos SIGN_MODE_DIRECT Transaction Signing
+//!
+//! 온라인 기기(Keplr, CosmJS 등)가 만든 protobuf `SignDoc`에 서명해 브로드캐스트할 `TxRaw`를 만듦
+//!
+//! ## 구조 (protobuf)
+//! ```text
+//! SignDoc { 1: body_bytes, 2: auth_info_bytes, 3: chain_id, 4: account_number }
+//! TxRaw   { 1: body_bytes, 2: auth_info_bytes, 3: signatures (반복) }
+//! ```
+//! 서명 = ECDSA(SHA-256(SignDoc 바이트)), r || s 64바이트
+//! `TxRaw` 바이트를 base64로 `/cosmos/tx/v1beta1/txs`의 `tx_bytes`에 넣어 브로드캐스트
+//!
+//! 노드는 TxRaw의 body/auth_info와 자신의 chain_id, account_number로 SignDoc을 다시 만들어 검증하므로
+//! 받은 바이트가 아니라 정규 인코딩(필드 순서대로, 기본값 생략)으로 다시 직렬화해 서명
+
+use super::CosmosAccount;
+use crate::error::{Error, Result};
+
+/// SIGN_MODE_DIRECT 서명 문서
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignDoc {
+    /// 직렬화된 TxBody (메시지, memo, timeout)
+    pub body_bytes: Vec<u8>,
+    /// 직렬화된 AuthInfo (서명자 공개키, sequence, 수수료)
+    pub auth_info_bytes: Vec<u8>,
+    /// 체인 id (예: "cosmoshub-4")
+    pub chain_id: String,
+    /// 서명자 account number
+    pub account_number: u64,
+}
+
+impl SignDoc {
+    /// protobuf 바이트에서 파싱 (알 수 없는 필드는 무시)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut sign_doc = SignDoc { body_bytes: Vec::new(), auth_info_bytes: Vec::new(), chain_id: String::new(), account_number: 0 };
+        let mut reader = Reader { data: bytes };
+
+        while !reader.data.is_empty() {
+            let key = reader.varint()?;
+            match (key >> 3, key & 7) {
+                (1, 2) => sign_doc.body_bytes = reader.bytes()?.to_vec(),
+                (2, 2) => sign_doc.auth_info_bytes = reader.bytes()?.to_vec(),
+                (3, 2) => {
+                    sign_doc.chain_id = String::from_utf8(reader.bytes()?.to_vec())
+                        .map_err(|_| Error::Transaction("chain_id가 UTF-8이 아닙니다".to_string()))?
+                }
+                (4, 0) => sign_doc.account_number = reader.varint()?,
+                (_, wire_type) => reader.skip(wire_type)?,
+            }
+        }
+        Ok(sign_doc)
+    }
+
+    /// 정규 protobuf 인코딩
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        put_bytes(&mut out, 1, &self.body_bytes);
+        put_bytes(&mut out, 2, &self.auth_info_bytes);
+        put_bytes(&mut out, 3, self.chain_id.as_bytes());
+        if self.account_number != 0 {
+            put_varint(&mut out, 4 << 3);
+            put_varint(&mut out, self.account_number);
+        }
+        out
+    }
+}
+
+impl CosmosAccount {
+    /// SignDoc 서명 (SHA-256 후 ECDSA, r || s)
+    pub fn sign_direct(&self, sign_doc: &SignDoc) -> Result<[u8; 64]> {
+        self.sign_message(&sign_doc.to_bytes())
+    }
+
+    /// SignDoc 서명 → 브로드캐스트할 TxRaw 바이트
+    pub fn sign_transaction(&self, sign_doc: &SignDoc) -> Result<Vec<u8>> {
+        let signature = self.sign_direct(sign_doc)?;
+        Ok(tx_raw(&sign_doc.body_bytes, &sign_doc.auth_info_bytes, &[signature.as_slice()]))
+    }
+}
+
+/// TxRaw 인코딩 (서명은 AuthInfo의 signer_infos 순서)
+pub fn tx_raw(body_bytes: &[u8], auth_info_bytes: &[u8], signatures: &[&[u8]]) -> Vec<u8> {
+    let mut out = Vec::new();
+    put_bytes(&mut out, 1, body_bytes);
+    put_bytes(&mut out, 2, auth_info_bytes);
+    for signature in signatures {
+        // repeated 필드는 빈 값도 생략하지 않음
+        put_varint(&mut out, (3 << 3) | 2);
+        put_varint(&mut out, signature.len() as u64);
+        out.extend_from_slice(signature);
+    }
+    out
+}
+
+/// length-delimited 필드 (빈 값은 proto3 기본값이라 생략)
+fn put_bytes(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    if bytes.is_empty() {
+        return;
+    }
+    put_varint(out, (field << 3) | 2);
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// protobuf 읽기 위치
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for (i, &byte) in self.data.iter().take(10).enumerate() {
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.data = &self.data[i + 1..];
+                return Ok(value);
+            }
+        }
+        Err(Error::Transaction("유효하지 않은 protobuf varint".to_string()))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let length = self.varint()? as usize;
+        if self.data.len() < length {
+            return Err(Error::Transaction("protobuf 필드가 잘렸습니다".to_string()));
+        }
+        let (bytes, rest) = self.data.split_at(length);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, wire_type: u64) -> Result<()> {
+        let fixed = match wire_type {
+            0 => return self.varint().map(|_| ()),
+            2 => return self.bytes().map(|_| ()),
+            1 => 8,
+            5 => 4,
+            _ => return Err(Error::Transaction(format!("지원하지 않는 protobuf wire type: {}", wire_type))),
+        };
+        if self.data.len() < fixed {
+            return Err(Error::Transaction("protobuf 필드가 잘렸습니다".to_string()));
+        }
+        self.data = &self.data[fixed..];
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::signing::verify_signature;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn sample_sign_doc() -> SignDoc {
+        SignDoc {
+            body_bytes: vec![0x0a, 0x03, 0x01, 0x02, 0x03],
+            auth_info_bytes: vec![0x12, 0x02, 0x08, 0x01],
+            chain_id: "cosmoshub-4".to_string(),
+            account_number: 300,
+        }
+    }
+
+    #[test]
+    fn test_sign_doc_roundtrip() {
+        let sign_doc = sample_sign_doc();
+        let bytes = sign_doc.to_bytes();
+        assert_eq!(hex::encode(&bytes), "0a050a030102031204120208011a0b636f736d6f736875622d3420ac02");
+        assert_eq!(SignDoc::from_bytes(&bytes).unwrap(), sign_doc);
+
+        // 알 수 없는 필드(9, fixed32)는 무시
+        let mut extended = bytes.clone();
+        extended.extend_from_slice(&[0x4d, 1, 2, 3, 4]);
+        assert_eq!(SignDoc::from_bytes(&extended).unwrap(), sign_doc);
+
+        assert!(SignDoc::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(SignDoc::from_bytes(&[0x0a, 0x05, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_sign_transaction() {
+        let account = CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let sign_doc = sample_sign_doc();
+
+        let tx = account.sign_transaction(&sign_doc).unwrap();
+        let signature = account.sign_direct(&sign_doc).unwrap();
+        verify_signature(&account.public_key, &sign_doc.to_bytes(), &signature).unwrap();
+
+        let mut expected = vec![0x0a, 0x05, 0x0a, 0x03, 0x01, 0x02, 0x03, 0x12, 0x04, 0x12, 0x02, 0x08, 0x01, 0x1a, 0x40];
+        expected.extend_from_slice(&signature);
+        assert_eq!(tx, expected);
+    }
+}
@@ -0,0 +1,316 @@
+//! Address Chain Detection
+//!
+//! 사용자가 붙여넣은 주소의 형식을 보고 어느 체인의 주소일 수 있는지 추정
+//!
+//! ## 판별 기준
+//!
+//! | 형식 | 판별 | 체인 |
+//! |------|------|------|
+//! | Bech32 / Bech32m | HRP + 체크섬 + 데이터 길이 | Bitcoin (bc, tb, bcrt), Cosmos SDK (cosmos, osmo, ...) |
+//! | 0x + hex 40자 | 길이, 대소문자가 섞이면 EIP-55 체크섬 | EVM |
+//! | 0x + hex 64자 | 길이 | Sui |
+//! | Base58Check | 버전 바이트 + 이중 SHA-256 체크섬 | Bitcoin (P2PKH, P2SH), Tron |
+//! | SS58 | 접두사 + Blake2b 체크섬 | Substrate (Polkadot, Kusama, 범용) |
+//! | StrKey | 'G' + Base32 + CRC16 | Stellar |
+//! | Base58 32바이트 | 길이만 (체크섬 없음) | Solana |
+//!
+//! 체크섬이 없는 형식(Solana, Sui, 소문자 EVM)은 다른 체인 주소와 구분할 수 없으므로
+//! 결과는 후보 목록이며, 체크섬으로 확인한 후보가 앞에 옴
+//!
+//! ```
+//! use crypto_lib::detect::detect_address;
+//!
+//! let guesses = detect_address("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4");
+//! assert_eq!(guesses[0].chain, "cosmoshub");
+//!
+//! assert!(detect_address("not an address").is_empty());
+//! ```
+
+use std::fmt;
+
+use crate::hash::sha256d;
+use crate::cosmos::CosmosChain;
+use crate::evm::to_checksum_address;
+use crate::substrate::{ss58_decode, SS58_GENERIC, SS58_KUSAMA, SS58_POLKADOT};
+
+/// 주소 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressFormat {
+    /// Bech32 (BIP-173)
+    Bech32,
+    /// Bech32m (BIP-350, Taproot)
+    Bech32m,
+    /// 0x + 20바이트 hex (EIP-55 체크섬 가능)
+    Hex20,
+    /// 0x + 32바이트 hex
+    Hex32,
+    /// Base58Check (버전 바이트 + 4바이트 체크섬)
+    Base58Check,
+    /// SS58 (Substrate)
+    Ss58,
+    /// Stellar StrKey (Base32 + CRC16)
+    StrKey,
+    /// 체크섬 없는 Base58
+    Base58,
+}
+
+/// 주소가 속할 수 있는 체인 후보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainGuess {
+    /// 체인 id (레지스트리에 있는 체인은 레지스트리 id, 예: "bitcoin", "osmosis")
+    pub chain: &'static str,
+    /// 표시 이름 (네트워크 포함, 예: "Bitcoin Testnet")
+    pub name: &'static str,
+    /// 주소 형식
+    pub format: AddressFormat,
+    /// 체크섬으로 확인했는지 (false면 길이/문자 집합만 일치)
+    pub checksum_verified: bool,
+}
+
+impl ChainGuess {
+    fn new(chain: &'static str, name: &'static str, format: AddressFormat, checksum_verified: bool) -> Self {
+        ChainGuess { chain, name, format, checksum_verified }
+    }
+}
+
+impl fmt::Display for ChainGuess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({:?})", self.name, self.format)
+    }
+}
+
+/// 주소의 체인 후보 (체크섬으로 확인한 후보가 먼저, 알 수 없으면 빈 목록)
+pub fn detect_address(address: &str) -> Vec<ChainGuess> {
+    let address = address.trim();
+    let mut guesses = Vec::new();
+
+    if address.is_empty() {
+        return guesses;
+    }
+
+    detect_bech32(address, &mut guesses);
+    detect_hex(address, &mut guesses);
+    detect_base58(address, &mut guesses);
+    detect_strkey(address, &mut guesses);
+
+    // 안정 정렬 → 같은 신뢰도 안에서는 판별 순서 유지
+    guesses.sort_by_key(|guess| !guess.checksum_verified);
+    guesses
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 형식별 판별
+// ═══════════════════════════════════════════════════════════════
+
+fn detect_bech32(address: &str, guesses: &mut Vec<ChainGuess>) {
+    // Bitcoin SegWit (witness 버전에 맞는 체크섬까지 검사)
+    if let Ok((hrp, version, program)) = ::bech32::segwit::decode(address) {
+        let format = if version.to_u8() == 0 { AddressFormat::Bech32 } else { AddressFormat::Bech32m };
+        let name = match hrp.as_str() {
+            "bc" => Some("Bitcoin"),
+            "tb" => Some("Bitcoin Testnet"),
+            "bcrt" => Some("Bitcoin Regtest"),
+            _ => None,
+        };
+        if let Some(name) = name {
+            if matches!((version.to_u8(), program.len()), (0, 20) | (0, 32) | (1..=16, _)) {
+                guesses.push(ChainGuess::new("bitcoin", name, format, true));
+            }
+            return;
+        }
+    }
+
+    // Cosmos SDK (20바이트 계정 주소, 32바이트 모듈/컨트랙트 주소)
+    if let Ok((hrp, data)) = ::bech32::decode(address) {
+        if data.len() != 20 && data.len() != 32 {
+            return;
+        }
+        for chain in CosmosChain::ALL {
+            if hrp.as_str() == chain.hrp() {
+                guesses.push(ChainGuess::new(chain.id(), chain.name(), AddressFormat::Bech32, true));
+            }
+        }
+    }
+}
+
+fn detect_hex(address: &str, guesses: &mut Vec<ChainGuess>) {
+    let Some(digits) = address.strip_prefix("0x").or_else(|| address.strip_prefix("0X")) else {
+        return;
+    };
+    let Ok(bytes) = hex::decode(digits) else {
+        return;
+    };
+
+    match bytes.len() {
+        20 => {
+            let lower = digits.to_ascii_lowercase();
+            let upper = digits.to_ascii_uppercase();
+            if digits == lower || digits == upper {
+                // 체크섬 없는 형식
+                guesses.push(ChainGuess::new("evm", "EVM", AddressFormat::Hex20, false));
+            } else if to_checksum_address(&bytes.try_into().expect("20바이트")) == address {
+                guesses.push(ChainGuess::new("evm", "EVM", AddressFormat::Hex20, true));
+            }
+        }
+        32 => guesses.push(ChainGuess::new("sui", "Sui", AddressFormat::Hex32, false)),
+        _ => {}
+    }
+}
+
+fn detect_base58(address: &str, guesses: &mut Vec<ChainGuess>) {
+    let Ok(data) = bs58::decode(address).into_vec() else {
+        return;
+    };
+
+    // Base58Check: 버전 1바이트 + 20바이트 해시 + 체크섬 4바이트
+    if data.len() == 25 && sha256d(&data[..21])[..4] == data[21..] {
+        let guess = match data[0] {
+            0x00 => Some(("bitcoin", "Bitcoin (P2PKH)")),
+            0x05 => Some(("bitcoin", "Bitcoin (P2SH)")),
+            0x6f => Some(("bitcoin", "Bitcoin Testnet (P2PKH)")),
+            0xc4 => Some(("bitcoin", "Bitcoin Testnet (P2SH)")),
+            0x41 => Some(("tron", "Tron")),
+            _ => None,
+        };
+        if let Some((chain, name)) = guess {
+            guesses.push(ChainGuess::new(chain, name, AddressFormat::Base58Check, true));
+        }
+    }
+
+    if let Ok((_, prefix)) = ss58_decode(address) {
+        let name = match prefix {
+            SS58_POLKADOT => "Polkadot",
+            SS58_KUSAMA => "Kusama",
+            SS58_GENERIC => "Substrate",
+            _ => "Substrate (기타 접두사)",
+        };
+        guesses.push(ChainGuess::new("substrate", name, AddressFormat::Ss58, true));
+    }
+
+    if data.len() == 32 {
+        guesses.push(ChainGuess::new("solana", "Solana", AddressFormat::Base58, false));
+    }
+}
+
+/// Stellar 계정 주소 (G...): Base32(버전 0x30 || 공개키 32바이트 || CRC16-XModem LE)
+fn detect_strkey(address: &str, guesses: &mut Vec<ChainGuess>) {
+    if address.len() != 56 || !address.starts_with('G') {
+        return;
+    }
+    let Some(data) = base32_decode(address) else {
+        return;
+    };
+    if data.len() != 35 || data[0] != 6 << 3 {
+        return;
+    }
+
+    let checksum = crc16_xmodem(&data[..33]).to_le_bytes();
+    if data[33..] == checksum {
+        guesses.push(ChainGuess::new("stellar", "Stellar", AddressFormat::StrKey, true));
+    }
+}
+
+/// RFC 4648 Base32 (대문자, 패딩 없음)
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+    let mut result = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in input.bytes() {
+        let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+    Some(result)
+}
+
+/// CRC16-XModem (다항식 0x1021, 초기값 0)
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chains(address: &str) -> Vec<&'static str> {
+        let guesses = detect_address(address);
+        println!("{} → {:?}", address, guesses.iter().map(ToString::to_string).collect::<Vec<_>>());
+        guesses.iter().map(|guess| guess.chain).collect()
+    }
+
+    #[test]
+    fn test_bitcoin() {
+        assert_eq!(chains("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"), ["bitcoin"]);
+        assert_eq!(chains("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"), ["bitcoin"]);
+        assert_eq!(chains("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"), ["bitcoin"]);
+
+        let taproot = detect_address("bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr");
+        assert_eq!(taproot[0].format, AddressFormat::Bech32m);
+
+        let testnet = detect_address("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx");
+        assert_eq!(testnet[0].name, "Bitcoin Testnet");
+
+        // 체크섬 오류
+        assert!(detect_address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyv").is_empty());
+    }
+
+    #[test]
+    fn test_cosmos() {
+        assert_eq!(chains("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"), ["cosmoshub"]);
+        let account = crate::cosmos::CosmosAccount::from_private_key([0x42; 32]).unwrap();
+        assert_eq!(chains(&account.address_for_chain(CosmosChain::Osmosis)), ["osmosis"]);
+        assert_eq!(chains(&account.address_for_chain(CosmosChain::Injective)), ["injective"]);
+    }
+
+    #[test]
+    fn test_hex() {
+        let checksummed = detect_address("0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(checksummed, [ChainGuess::new("evm", "EVM", AddressFormat::Hex20, true)]);
+
+        let lower = detect_address("0x9858effd232b4033e47d90003d41ec34ecaeda94");
+        assert!(!lower[0].checksum_verified);
+
+        // 대소문자가 섞였는데 체크섬이 틀리면 후보 없음
+        assert!(detect_address("0x9858EfFD232B4033E47d90003D41EC34EcaEda95").is_empty());
+
+        assert_eq!(chains("0x2e62960b9c4b1f7d85b8c0f95d7b6f7bc4a1a6c1a0a2b8e1f9c2d4e8a1b3c5d7"), ["sui"]);
+    }
+
+    #[test]
+    fn test_substrate_and_solana() {
+        let guesses = detect_address("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+        assert_eq!(guesses[0].chain, "substrate");
+        assert_eq!(guesses[0].name, "Substrate");
+
+        assert_eq!(chains("HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"), ["solana"]);
+    }
+
+    #[test]
+    fn test_tron_and_stellar() {
+        assert_eq!(chains("TJCnKsPa7y5okkXvQAidZBzqx3QyQ6sxMW"), ["tron"]);
+        assert_eq!(chains("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7"), ["stellar"]);
+
+        // StrKey 체크섬 오류
+        assert!(detect_address("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN6").is_empty());
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert!(detect_address("").is_empty());
+        assert!(detect_address("hello world").is_empty());
+        assert!(detect_address("0x1234").is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+//! Account Discovery
+//!
+//! 니모닉만으로 지갑을 복원할 때 사용된 account/주소를 찾는 BIP-44 gap limit 알고리즘
+//!
+//! ## 알고리즘 (BIP-44 "Account discovery")
+//! 1. account 0부터 시작
+//! 2. 주소 index 0부터 차례로 도출해 사용 여부 확인
+//! 3. 연속으로 `gap_limit`개(기본 20)가 미사용이면 그 account의 검색 종료
+//! 4. account에 사용된 주소가 하나도 없으면 전체 검색 종료 (다음 account는 만들어지지 않았다고 봄)
+//!
+//! ## 사용 여부 확인
+//! 잔액/거래 기록 조회는 호출자가 `BalanceProvider`로 제공 (코어에 네트워크 코드 없음)
+//! 탐색기 API, 자체 인덱서, 미리 받아 둔 주소 목록 등 무엇이든 사용 가능
+//! 비동기 조회는 `AsyncBalanceProvider` + `discover_async` (`rpc-*` feature의 클라이언트가 구현)
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use crypto_lib::discovery::{discover, DiscoveryOptions};
+//! use crypto_lib::wallet::{CachedWallet, Chain};
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let wallet = CachedWallet::from_mnemonic(mnemonic, "").unwrap();
+//!
+//! // 인덱서에서 받아 둔 사용된 주소 목록
+//! let used: HashSet<String> = ["0x9858EfFD232B4033E47d90003D41EC34EcaEda94".to_string()].into();
+//!
+//! let found = discover(&wallet, Chain::Evm, &used, &DiscoveryOptions::default()).unwrap();
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].path, "m/44'/60'/0'/0/0");
+//! ```
+
+use std::collections::HashSet;
+use std::future::{self, Future};
+
+use crate::bip32::HARDENED_OFFSET;
+use crate::wallet::{CachedWallet, Chain};
+use crate::error::{Error, Result};
+
+/// BIP-44 기본 gap limit
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// 기본 최대 account 수 (사용된 account가 끝없이 이어지는 잘못된 응답 방지)
+pub const DEFAULT_MAX_ACCOUNTS: u32 = 100;
+
+/// 주소 사용 여부 조회
+pub trait BalanceProvider {
+    /// 주소에 잔액이나 거래 기록이 있는지
+    fn is_used(&self, chain: Chain, address: &str) -> Result<bool>;
+}
+
+impl<F: Fn(Chain, &str) -> Result<bool>> BalanceProvider for F {
+    fn is_used(&self, chain: Chain, address: &str) -> Result<bool> {
+        self(chain, address)
+    }
+}
+
+/// 미리 알고 있는 사용된 주소 목록
+impl BalanceProvider for HashSet<String> {
+    fn is_used(&self, _chain: Chain, address: &str) -> Result<bool> {
+        Ok(self.contains(address))
+    }
+}
+
+/// 비동기 주소 사용 여부 조회 (노드 RPC, HTTP API)
+pub trait AsyncBalanceProvider {
+    /// 주소에 잔액이나 거래 기록이 있는지
+    fn is_used(&self, chain: Chain, address: &str) -> impl Future<Output = Result<bool>> + Send;
+}
+
+impl AsyncBalanceProvider for HashSet<String> {
+    fn is_used(&self, _chain: Chain, address: &str) -> impl Future<Output = Result<bool>> + Send {
+        future::ready(Ok(self.contains(address)))
+    }
+}
+
+/// 검색 옵션
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscoveryOptions {
+    /// 연속 미사용 주소가 이만큼이면 account 검색 종료 (기본값: 20)
+    pub gap_limit: u32,
+    /// 검색할 최대 account 수 (기본값: 100)
+    pub max_accounts: u32,
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        DiscoveryOptions { gap_limit: DEFAULT_GAP_LIMIT, max_accounts: DEFAULT_MAX_ACCOUNTS }
+    }
+}
+
+impl DiscoveryOptions {
+    /// gap limit 지정
+    pub fn gap_limit(mut self, gap_limit: u32) -> Self {
+        self.gap_limit = gap_limit;
+        self
+    }
+
+    /// 최대 account 수 지정
+    pub fn max_accounts(mut self, max_accounts: u32) -> Self {
+        self.max_accounts = max_accounts;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.gap_limit == 0 {
+            return Err(Error::InvalidInput("gap limit은 1 이상이어야 합니다".to_string()));
+        }
+        if self.max_accounts == 0 || self.max_accounts > HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("최대 account 수는 1 ~ 2^31이어야 합니다: {}", self.max_accounts)));
+        }
+        Ok(())
+    }
+}
+
+/// 사용된 주소
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredAddress {
+    /// 체인
+    pub chain: Chain,
+    /// BIP-44 account
+    pub account: u32,
+    /// 주소 index
+    pub index: u32,
+    /// 도출 경로
+    pub path: String,
+    /// 주소
+    pub address: String,
+}
+
+/// 체인 하나의 사용된 주소 검색 (account, index 순서)
+pub fn discover(wallet: &CachedWallet, chain: Chain, provider: &dyn BalanceProvider, options: &DiscoveryOptions) -> Result<Vec<DiscoveredAddress>> {
+    options.validate()?;
+
+    let mut found = Vec::new();
+    for account in 0..options.max_accounts {
+        let before = found.len();
+        discover_account(wallet, chain, account, provider, options.gap_limit, &mut found)?;
+        if found.len() == before {
+            break;
+        }
+    }
+    Ok(found)
+}
+
+/// `discover`의 비동기 버전 (주소를 하나씩 차례로 조회)
+pub async fn discover_async<P: AsyncBalanceProvider + ?Sized>(
+    wallet: &CachedWallet,
+    chain: Chain,
+    provider: &P,
+    options: &DiscoveryOptions,
+) -> Result<Vec<DiscoveredAddress>> {
+    options.validate()?;
+
+    let mut found = Vec::new();
+    for account in 0..options.max_accounts {
+        let before = found.len();
+        discover_account_async(wallet, chain, account, provider, options.gap_limit, &mut found).await?;
+        if found.len() == before {
+            break;
+        }
+    }
+    Ok(found)
+}
+
+/// 여러 체인 검색 (체인 순서대로 이어 붙임)
+pub fn discover_chains(wallet: &CachedWallet, chains: &[Chain], provider: &dyn BalanceProvider, options: &DiscoveryOptions) -> Result<Vec<DiscoveredAddress>> {
+    let mut found = Vec::new();
+    for &chain in chains {
+        found.extend(discover(wallet, chain, provider, options)?);
+    }
+    Ok(found)
+}
+
+/// account 하나의 외부 주소 검색 (연속 미사용이 `gap_limit`개가 될 때까지)
+fn discover_account(
+    wallet: &CachedWallet,
+    chain: Chain,
+    account: u32,
+    provider: &dyn BalanceProvider,
+    gap_limit: u32,
+    found: &mut Vec<DiscoveredAddress>,
+) -> Result<()> {
+    let mut gap = 0;
+    let mut index = 0;
+
+    while gap < gap_limit && index < HARDENED_OFFSET {
+        let derived = wallet.derive(chain, account, index)?;
+        if provider.is_used(chain, &derived.address)? {
+            found.push(DiscoveredAddress { chain, account, index, path: derived.path.clone(), address: derived.address.clone() });
+            gap = 0;
+        } else {
+            gap += 1;
+        }
+        index += 1;
+    }
+    Ok(())
+}
+
+/// `discover_account`의 비동기 버전
+async fn discover_account_async<P: AsyncBalanceProvider + ?Sized>(
+    wallet: &CachedWallet,
+    chain: Chain,
+    account: u32,
+    provider: &P,
+    gap_limit: u32,
+    found: &mut Vec<DiscoveredAddress>,
+) -> Result<()> {
+    let mut gap = 0;
+    let mut index = 0;
+
+    while gap < gap_limit && index < HARDENED_OFFSET {
+        let derived = wallet.derive(chain, account, index)?;
+        if provider.is_used(chain, &derived.address).await? {
+            found.push(DiscoveredAddress { chain, account, index, path: derived.path.clone(), address: derived.address.clone() });
+            gap = 0;
+        } else {
+            gap += 1;
+        }
+        index += 1;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn address(wallet: &CachedWallet, chain: Chain, account: u32, index: u32) -> String {
+        wallet.derive(chain, account, index).unwrap().address
+    }
+
+    #[test]
+    fn test_gap_limit() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let options = DiscoveryOptions::default().gap_limit(5);
+
+        // account 0: index 0, 4, 9 사용 (간격 4 → 계속), index 15는 간격 5라 못 찾음
+        // account 1: index 2 사용, account 2: 미사용 → 종료 (account 3은 검색하지 않음)
+        let used: HashSet<String> = [(0, 0), (0, 4), (0, 9), (0, 15), (1, 2), (3, 0)]
+            .iter()
+            .map(|&(account, index)| address(&wallet, Chain::Evm, account, index))
+            .collect();
+
+        let found = discover(&wallet, Chain::Evm, &used, &options).unwrap();
+        for address in &found {
+            println!("{:<20} {}", address.path, address.address);
+        }
+        let positions: Vec<(u32, u32)> = found.iter().map(|a| (a.account, a.index)).collect();
+        assert_eq!(positions, vec![(0, 0), (0, 4), (0, 9), (1, 2)]);
+    }
+
+    #[test]
+    fn test_provider_calls() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let used = address(&wallet, Chain::Solana, 0, 1);
+
+        // 조회 횟수: account 0은 index 0 ~ 4 (5개), account 1은 0 ~ 2 (3개)
+        let calls = Cell::new(0);
+        let provider = |chain: Chain, address: &str| {
+            assert_eq!(chain, Chain::Solana);
+            calls.set(calls.get() + 1);
+            Ok(address == used)
+        };
+        let found = discover(&wallet, Chain::Solana, &provider, &DiscoveryOptions::default().gap_limit(3)).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].path, "m/44'/501'/0'/1'");
+        assert_eq!(calls.get(), 5 + 3);
+
+        // 빈 지갑
+        let empty = HashSet::new();
+        assert!(discover_chains(&wallet, &[Chain::Bitcoin, Chain::Evm], &empty, &DiscoveryOptions::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let empty = HashSet::new();
+        assert!(matches!(discover(&wallet, Chain::Evm, &empty, &DiscoveryOptions::default().gap_limit(0)), Err(Error::InvalidInput(_))));
+
+        // 조회 실패는 그대로 전달
+        let failing = |_: Chain, _: &str| -> Result<bool> { Err(Error::Io("timeout".to_string())) };
+        assert_eq!(discover(&wallet, Chain::Evm, &failing, &DiscoveryOptions::default()), Err(Error::Io("timeout".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_discover_async() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let options = DiscoveryOptions::default().gap_limit(4);
+        let used: HashSet<String> = [(0, 1), (0, 3), (1, 0)]
+            .iter()
+            .map(|&(account, index)| address(&wallet, Chain::Sui, account, index))
+            .collect();
+
+        let found = discover_async(&wallet, Chain::Sui, &used, &options).await.unwrap();
+        assert_eq!(found, discover(&wallet, Chain::Sui, &used, &options).unwrap());
+        assert_eq!(found.len(), 3);
+    }
+}
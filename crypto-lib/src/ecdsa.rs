@@ -0,0 +1,273 @@
+//! secp256k1 ECDSA Signing
+//!
+//! Bitcoin, EVM, Cosmos 트랜잭션 빌더가 공통으로 쓰는 다이제스트 서명
+//!
+//! ## 결정적 nonce (RFC 6979)
+//! ECDSA는 서명마다 비밀 nonce k가 필요하며, k가 재사용되거나 예측되면 개인키가 노출됨
+//! RFC 6979는 k = HMAC-DRBG(개인키, 다이제스트)로 만들어 난수 생성기 없이도 안전하고,
+//! 같은 입력이면 항상 같은 서명이 나옴 (테스트, 하드웨어 지갑과 비교 가능)
+//!
+//! ## low-S 정규화
+//! (r, s)와 (r, n - s)는 모두 유효한 서명 → 제3자가 서명을 바꿔 txid를 변조 가능
+//! Bitcoin(BIP-62/146), Ethereum(EIP-2)은 s ≤ n/2만 허용하므로 항상 작은 s로 정규화
+//!
+//! ## 서명 형식
+//! - compact: r || s (64바이트)
+//! - recoverable: r || s || recovery_id (65바이트), recovery_id(0~3)로 공개키 복원 가능
+//!   (EVM의 v = 27 + recovery_id)
+//!
+//! ## 공개키 복원 (ecrecover)
+//! r은 nonce 점 R의 x좌표 → recovery_id로 R의 y 홀짝(과 x ≥ n 여부)을 골라 R을 복원하면
+//! 공개키 Q = r⁻¹(s·R - e·G)를 계산할 수 있음 (Ethereum 트랜잭션에 공개키가 없는 이유)
+
+use std::sync::OnceLock;
+
+use secp256k1::ecdsa::RecoveryId;
+use secp256k1::{All, Message, Secp256k1, SecretKey};
+
+use crate::error::{Error, Result};
+use crate::trace;
+
+/// 공개키 복원이 가능한 ECDSA 서명
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoverableSignature {
+    /// r || s (64바이트, low-S)
+    pub signature: [u8; 64],
+    /// 복원 ID (0~3)
+    pub recovery_id: u8,
+}
+
+impl RecoverableSignature {
+    /// r || s || recovery_id (65바이트)에서 생성
+    ///
+    /// 마지막 바이트는 0~3 또는 27~30 (EVM 형식 v) 허용
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<Self> {
+        let recovery_id = match bytes[64] {
+            v @ 0..=3 => v,
+            v @ 27..=30 => v - 27,
+            v => return Err(Error::InvalidKey(format!("유효하지 않은 복원 ID: {}", v))),
+        };
+
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(&bytes[..64]);
+        Ok(RecoverableSignature {
+            signature,
+            recovery_id,
+        })
+    }
+
+    /// r || s || recovery_id (65바이트)
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&self.signature);
+        bytes[64] = self.recovery_id;
+        bytes
+    }
+
+    /// r (32바이트)
+    pub fn r(&self) -> [u8; 32] {
+        let mut r = [0u8; 32];
+        r.copy_from_slice(&self.signature[..32]);
+        r
+    }
+
+    /// s (32바이트)
+    pub fn s(&self) -> [u8; 32] {
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&self.signature[32..]);
+        s
+    }
+}
+
+/// 크레이트 전체가 공유하는 secp256k1 컨텍스트 (서명 + 검증)
+///
+/// `Secp256k1::new()`는 사전 계산 테이블 할당과 무작위화(부채널 공격 방어)를 매번 수행하므로
+/// 일괄 도출에서는 공개키 계산보다 컨텍스트 생성이 더 오래 걸림 → 처음 한 번만 만들고 재사용
+/// (컨텍스트는 `Sync`라서 스레드 간 공유 가능)
+pub fn context() -> &'static Secp256k1<All> {
+    static CONTEXT: OnceLock<Secp256k1<All>> = OnceLock::new();
+    CONTEXT.get_or_init(Secp256k1::new)
+}
+
+/// 다이제스트에 ECDSA 서명 (RFC 6979, low-S), r || s 64바이트
+pub fn sign_compact(private_key: &[u8; 32], digest: &[u8; 32]) -> Result<[u8; 64]> {
+    let _span = trace::span!("ecdsa.sign");
+    let secret = secret_key(private_key)?;
+    let signature = context().sign_ecdsa(&Message::from_digest(*digest), &secret);
+    Ok(signature.serialize_compact())
+}
+
+/// 다이제스트에 복원 가능한 ECDSA 서명 (RFC 6979, low-S)
+pub fn sign_recoverable(private_key: &[u8; 32], digest: &[u8; 32]) -> Result<RecoverableSignature> {
+    let _span = trace::span!("ecdsa.sign");
+    let secret = secret_key(private_key)?;
+    let signature = context().sign_ecdsa_recoverable(&Message::from_digest(*digest), &secret);
+    let (recovery_id, signature) = signature.serialize_compact();
+
+    Ok(RecoverableSignature {
+        signature,
+        recovery_id: recovery_id_to_u8(recovery_id),
+    })
+}
+
+/// 서명과 다이제스트에서 공개키 복원 (압축, 33바이트)
+pub fn recover_public_key(digest: &[u8; 32], signature: &RecoverableSignature) -> Result<[u8; 33]> {
+    Ok(recover(digest, signature)?.serialize())
+}
+
+/// 서명과 다이제스트에서 공개키 복원 (비압축, 65바이트)
+pub fn recover_public_key_uncompressed(
+    digest: &[u8; 32],
+    signature: &RecoverableSignature,
+) -> Result<[u8; 65]> {
+    Ok(recover(digest, signature)?.serialize_uncompressed())
+}
+
+fn recover(digest: &[u8; 32], signature: &RecoverableSignature) -> Result<secp256k1::PublicKey> {
+    let recovery_id = RecoveryId::from_i32(signature.recovery_id as i32)
+        .map_err(|_| Error::InvalidKey("유효하지 않은 복원 ID".to_string()))?;
+    let signature =
+        secp256k1::ecdsa::RecoverableSignature::from_compact(&signature.signature, recovery_id)
+            .map_err(|_| Error::InvalidSignature("유효하지 않은 ECDSA 서명".to_string()))?;
+
+    context()
+        .recover_ecdsa(&Message::from_digest(*digest), &signature)
+        .map_err(|_| Error::InvalidKey("공개키 복원 실패".to_string()))
+}
+
+/// secp256k1 개인키 검증 (1 ≤ d < n, 0과 곡선 위수 이상은 구분해서 오류)
+pub(crate) fn secret_key(private_key: &[u8; 32]) -> Result<SecretKey> {
+    SecretKey::from_slice(private_key).map_err(|_| {
+        if private_key.iter().all(|&b| b == 0) {
+            Error::InvalidKey("secp256k1 개인키가 0입니다".to_string())
+        } else {
+            Error::InvalidKey("secp256k1 개인키가 곡선 위수 n 이상입니다".to_string())
+        }
+    })
+}
+
+/// 개인키 → 공개키 (개인키 범위 검증 포함)
+pub(crate) fn public_key(private_key: &[u8; 32]) -> Result<secp256k1::PublicKey> {
+    Ok(secp256k1::PublicKey::from_secret_key(
+        context(),
+        &secret_key(private_key)?,
+    ))
+}
+
+fn recovery_id_to_u8(recovery_id: RecoveryId) -> u8 {
+    recovery_id.to_i32() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    /// 곡선 위수의 절반 (n / 2)
+    const HALF_ORDER: &str = "7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0";
+
+    fn private_key(last: u8) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        key[31] = last;
+        key
+    }
+
+    #[test]
+    fn test_rfc6979_vector() {
+        // 개인키 1, SHA-256("Satoshi Nakamoto")
+        let digest: [u8; 32] = Sha256::digest(b"Satoshi Nakamoto").into();
+        let signature = sign_compact(&private_key(1), &digest).unwrap();
+
+        assert_eq!(
+            hex::encode(signature),
+            "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+             2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+        );
+
+        // 결정적: 같은 입력 → 같은 서명
+        assert_eq!(sign_compact(&private_key(1), &digest).unwrap(), signature);
+    }
+
+    #[test]
+    fn test_recoverable_matches_compact() {
+        for i in 1..=20u8 {
+            let digest: [u8; 32] = Sha256::digest([i]).into();
+            let compact = sign_compact(&private_key(i), &digest).unwrap();
+            let recoverable = sign_recoverable(&private_key(i), &digest).unwrap();
+
+            assert_eq!(recoverable.signature, compact);
+            assert!(recoverable.recovery_id <= 3);
+            assert_eq!(recoverable.to_bytes()[64], recoverable.recovery_id);
+
+            // low-S: s ≤ n/2 (빅엔디안 바이트 비교)
+            assert!(hex::encode(recoverable.s()).as_str() <= HALF_ORDER);
+        }
+    }
+
+    #[test]
+    fn test_recover_public_key() {
+        let secp = context();
+        for i in 1..=10u8 {
+            let digest: [u8; 32] = Sha256::digest([i, i]).into();
+            let signature = sign_recoverable(&private_key(i), &digest).unwrap();
+            let public =
+                secp256k1::PublicKey::from_secret_key(secp, &secret_key(&private_key(i)).unwrap());
+
+            assert_eq!(
+                recover_public_key(&digest, &signature).unwrap(),
+                public.serialize()
+            );
+            assert_eq!(
+                recover_public_key_uncompressed(&digest, &signature).unwrap(),
+                public.serialize_uncompressed()
+            );
+
+            // 바이트 왕복 (EVM v 형식 포함)
+            let mut bytes = signature.to_bytes();
+            assert_eq!(RecoverableSignature::from_bytes(&bytes).unwrap(), signature);
+            bytes[64] += 27;
+            assert_eq!(RecoverableSignature::from_bytes(&bytes).unwrap(), signature);
+
+            // 다른 다이제스트 → 다른 공개키
+            assert_ne!(
+                recover_public_key(&[0xaa; 32], &signature).ok(),
+                Some(public.serialize())
+            );
+        }
+
+        let mut bytes = [1u8; 65];
+        bytes[64] = 4;
+        assert!(RecoverableSignature::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_invalid_private_key() {
+        assert!(sign_compact(&[0u8; 32], &[1u8; 32]).is_err());
+        assert!(sign_recoverable(&[0xff; 32], &[1u8; 32]).is_err());
+
+        assert_eq!(
+            public_key(&[0u8; 32]).unwrap_err(),
+            Error::InvalidKey("secp256k1 개인키가 0입니다".to_string())
+        );
+        // n 자체와 n 이상
+        let order = hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+            .unwrap();
+        for key in [order.try_into().unwrap(), [0xff; 32]] {
+            assert_eq!(
+                public_key(&key).unwrap_err(),
+                Error::InvalidKey("secp256k1 개인키가 곡선 위수 n 이상입니다".to_string())
+            );
+        }
+        assert!(public_key(&private_key(1)).is_ok());
+    }
+
+    #[test]
+    fn test_shared_context() {
+        // 스레드가 달라도 같은 컨텍스트
+        let main = context() as *const _ as usize;
+        let other = std::thread::spawn(|| context() as *const _ as usize)
+            .join()
+            .unwrap();
+        assert_eq!(main, other);
+    }
+}
@@ -0,0 +1,344 @@
+//! EIP-2335 BLS12-381 Keystore
+//!
+//! 이더리움 합의 레이어 검증자 키스토어 (Lighthouse, Prysm, Teku 등에서 가져오기 가능)
+//!
+//! ## 형식 (버전 4)
+//! ```text
+//! {
+//!   "crypto": {
+//!     "kdf":      { "function": "scrypt", "params": { ... }, "message": "" },
+//!     "checksum": { "function": "sha256", "params": {}, "message": "<체크섬>" },
+//!     "cipher":   { "function": "aes-128-ctr", "params": { "iv": "..." }, "message": "<암호문>" }
+//!   },
+//!   "description": "...",
+//!   "pubkey": "<BLS 공개키 48바이트 hex>",
+//!   "path": "m/12381/3600/0/0/0",
+//!   "uuid": "...",
+//!   "version": 4
+//! }
+//! ```
+//!
+//! ## V3 키스토어와 다른 점
+//! - 비밀번호: NFKD 정규화 후 제어 문자(C0, C1, DEL) 제거
+//! - 체크섬: SHA-256(키[16..32] || 암호문) (V3는 Keccak-256 MAC)
+//! - KDF 파라미터와 salt는 V3와 같은 형식 (`evm::keystore`와 공유, 파라미터 상한도 같음)
+//!
+//! 이 crate에는 BLS12-381 키 생성(EIP-2333)이 없으므로 공개키는 호출자가 넘겨야 함
+//! (비밀키 32바이트 + 검증자 클라이언트가 만든 공개키)
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use crate::evm::keystore::{decode_hex, derive_key, kdf_params_json, parse_kdf, uuid_v4, KeystoreKdf};
+use crate::error::{Error, Result};
+use crate::secret::ct_eq;
+use crate::trace;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// 검증자 서명 키 경로 (EIP-2334, i번째 검증자)
+pub fn signing_key_path(index: u32) -> String {
+    format!("m/12381/3600/{}/0/0", index)
+}
+
+/// 키스토어 메타데이터 (비밀번호 없이 읽을 수 있는 부분)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystoreInfo {
+    /// BLS 공개키 hex (48바이트, 0x 없음)
+    pub pubkey: String,
+    /// 도출 경로 (EIP-2334)
+    pub path: String,
+    /// 설명
+    pub description: String,
+    /// UUID
+    pub uuid: String,
+    /// KDF 설정
+    pub kdf: KeystoreKdf,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// JSON 구조
+// ═══════════════════════════════════════════════════════════════
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreFile {
+    crypto: CryptoSection,
+    #[serde(default)]
+    description: String,
+    pubkey: String,
+    path: String,
+    uuid: String,
+    version: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CryptoSection {
+    kdf: Module,
+    checksum: Module,
+    cipher: Module,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Module {
+    function: String,
+    params: serde_json::Value,
+    message: String,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 암호화 / 복호화
+// ═══════════════════════════════════════════════════════════════
+
+/// BLS 비밀키를 EIP-2335 키스토어 JSON으로 암호화
+pub fn encrypt(
+    secret: &[u8; 32],
+    password: &str,
+    pubkey: &[u8; 48],
+    path: &str,
+    description: &str,
+    kdf: KeystoreKdf,
+) -> Result<String> {
+    let _span = trace::span!("keystore.encrypt", format = "eip-2335");
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; 32];
+    let mut iv = [0u8; 16];
+    let mut id = [0u8; 16];
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut iv);
+    rng.fill_bytes(&mut id);
+
+    let (kdf_name, kdf_params) = kdf_params_json(&kdf, &salt)?;
+    let derived = derive_key(&Zeroizing::new(process_password(password)), &salt, &kdf)?;
+    let mut ciphertext = Zeroizing::new(*secret);
+    Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(ciphertext.as_mut());
+
+    let file = KeystoreFile {
+        crypto: CryptoSection {
+            kdf: Module { function: kdf_name.to_string(), params: kdf_params, message: String::new() },
+            checksum: Module {
+                function: "sha256".to_string(),
+                params: serde_json::json!({}),
+                message: hex::encode(checksum(&derived, ciphertext.as_ref())),
+            },
+            cipher: Module {
+                function: "aes-128-ctr".to_string(),
+                params: serde_json::json!({ "iv": hex::encode(iv) }),
+                message: hex::encode(ciphertext.as_ref()),
+            },
+        },
+        description: description.to_string(),
+        pubkey: hex::encode(pubkey),
+        path: path.to_string(),
+        uuid: uuid_v4(id),
+        version: 4,
+    };
+
+    serde_json::to_string(&file).map_err(|e| Error::Keystore(e.to_string()))
+}
+
+/// 키스토어 JSON 복호화 → (비밀키, 메타데이터)
+///
+/// 비밀키가 공개키와 맞는지는 확인하지 않음 (BLS 연산 없음), 비밀키는 drop 시 zeroize
+pub fn decrypt(json: &str, password: &str) -> Result<(Zeroizing<Vec<u8>>, KeystoreInfo)> {
+    let _span = trace::span!("keystore.decrypt", format = "eip-2335");
+    let file = parse_file(json)?;
+    let info = file_info(&file)?;
+    let crypto = &file.crypto;
+
+    if crypto.checksum.function != "sha256" {
+        return Err(Error::Keystore(format!("지원하지 않는 체크섬 함수: {}", crypto.checksum.function)));
+    }
+    if crypto.cipher.function != "aes-128-ctr" {
+        return Err(Error::Keystore(format!("지원하지 않는 암호화 방식: {}", crypto.cipher.function)));
+    }
+
+    let (salt, kdf) = parse_kdf(&crypto.kdf.function, &crypto.kdf.params)?;
+    let iv_hex = crypto.cipher.params.get("iv").and_then(|v| v.as_str()).ok_or_else(|| Error::Keystore("cipher.params.iv가 없습니다".to_string()))?;
+    let iv: [u8; 16] = decode_hex(iv_hex, "iv")?.try_into().map_err(|_| Error::Keystore("iv는 16바이트여야 합니다".to_string()))?;
+    let mut ciphertext = Zeroizing::new(decode_hex(&crypto.cipher.message, "cipher.message")?);
+    let expected = decode_hex(&crypto.checksum.message, "checksum.message")?;
+
+    let derived = derive_key(&Zeroizing::new(process_password(password)), &salt, &kdf)?;
+    if !ct_eq(&checksum(&derived, &ciphertext), &expected) {
+        return Err(Error::Decryption("체크섬 불일치 (비밀번호가 틀렸거나 파일이 손상됨)".to_string()));
+    }
+
+    Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+    Ok((ciphertext, info))
+}
+
+/// 비밀번호 없이 메타데이터만 읽기 (감사용)
+pub fn read_info(json: &str) -> Result<KeystoreInfo> {
+    file_info(&parse_file(json)?)
+}
+
+/// EIP-2335 비밀번호 처리: NFKD 후 제어 문자 제거
+pub fn process_password(password: &str) -> String {
+    password
+        .nfkd()
+        .filter(|c| !matches!(*c as u32, 0x00..=0x1F | 0x7F..=0x9F))
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn parse_file(json: &str) -> Result<KeystoreFile> {
+    let file: KeystoreFile = serde_json::from_str(json).map_err(|e| Error::Keystore(format!("키스토어 JSON 파싱 실패: {}", e)))?;
+    if file.version != 4 {
+        return Err(Error::Keystore(format!("지원하지 않는 키스토어 버전: {}", file.version)));
+    }
+    Ok(file)
+}
+
+fn file_info(file: &KeystoreFile) -> Result<KeystoreInfo> {
+    let (_, kdf) = parse_kdf(&file.crypto.kdf.function, &file.crypto.kdf.params)?;
+    Ok(KeystoreInfo {
+        pubkey: file.pubkey.trim_start_matches("0x").to_lowercase(),
+        path: file.path.clone(),
+        description: file.description.clone(),
+        uuid: file.uuid.clone(),
+        kdf,
+    })
+}
+
+/// 체크섬 = SHA-256(키[16..32] || 암호문)
+fn checksum(derived: &[u8; 32], ciphertext: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(&derived[16..]);
+    hasher.update(ciphertext);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // EIP-2335 테스트 벡터
+    const PASSWORD: &str = "𝔱𝔢𝔰𝔱𝔭𝔞𝔰𝔰𝔴𝔬𝔯𝔡🔑";
+    const SECRET: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+    const PUBKEY: &str = "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07";
+
+    const SCRYPT_VECTOR: &str = r#"{
+        "crypto": {
+            "kdf": {
+                "function": "scrypt",
+                "params": {
+                    "dklen": 32,
+                    "n": 262144,
+                    "p": 1,
+                    "r": 8,
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                },
+                "message": ""
+            },
+            "checksum": {
+                "function": "sha256",
+                "params": {},
+                "message": "d2217fe5f3e9a1e34581ef8a78f7c9928e436d36dacc5e846690a5581e8ea484"
+            },
+            "cipher": {
+                "function": "aes-128-ctr",
+                "params": { "iv": "264daa3f303d7259501c93d997d84fe6" },
+                "message": "06ae90d55fe0a6e9c5c3bc5b170827b2e5cce3929ed3f116c2811e6366dfe20f"
+            }
+        },
+        "description": "This is a test keystore that uses scrypt to secure the secret.",
+        "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+        "path": "m/12381/60/3141592653/589793238",
+        "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+        "version": 4
+    }"#;
+
+    const PBKDF2_VECTOR: &str = r#"{
+        "crypto": {
+            "kdf": {
+                "function": "pbkdf2",
+                "params": {
+                    "dklen": 32,
+                    "c": 262144,
+                    "prf": "hmac-sha256",
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                },
+                "message": ""
+            },
+            "checksum": {
+                "function": "sha256",
+                "params": {},
+                "message": "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1"
+            },
+            "cipher": {
+                "function": "aes-128-ctr",
+                "params": { "iv": "264daa3f303d7259501c93d997d84fe6" },
+                "message": "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad"
+            }
+        },
+        "description": "This is a test keystore that uses PBKDF2 to secure the secret.",
+        "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+        "path": "m/12381/60/0/0",
+        "uuid": "64625def-3331-4eea-ab6f-782f3ed16a83",
+        "version": 4
+    }"#;
+
+    #[test]
+    fn test_spec_vectors() {
+        for json in [SCRYPT_VECTOR, PBKDF2_VECTOR] {
+            let (secret, info) = decrypt(json, PASSWORD).unwrap();
+            println!("{} ({:?}) → {}", info.uuid, info.kdf, hex::encode(secret.as_slice()));
+
+            assert_eq!(hex::encode(secret.as_slice()), SECRET);
+            assert_eq!(info.pubkey, PUBKEY);
+            assert!(matches!(decrypt(json, "testpassword"), Err(Error::Decryption(_))));
+        }
+
+        let info = read_info(SCRYPT_VECTOR).unwrap();
+        assert_eq!(info.path, "m/12381/60/3141592653/589793238");
+        assert_eq!(info.kdf, KeystoreKdf::STANDARD);
+    }
+
+    #[test]
+    fn test_rejects_oversized_kdf_params() {
+        // 조작된 파일: scrypt N = 2^30 (약 1 TiB 메모리), PBKDF2 c = u32::MAX
+        let mut scrypt: serde_json::Value = serde_json::from_str(SCRYPT_VECTOR).unwrap();
+        scrypt["crypto"]["kdf"]["params"]["n"] = serde_json::json!(1u64 << 30);
+        let mut pbkdf2: serde_json::Value = serde_json::from_str(PBKDF2_VECTOR).unwrap();
+        pbkdf2["crypto"]["kdf"]["params"]["c"] = serde_json::json!(u32::MAX);
+
+        for value in [scrypt, pbkdf2] {
+            let err = decrypt(&value.to_string(), PASSWORD).unwrap_err();
+            assert!(matches!(err, Error::Keystore(_)), "{:?}", err);
+            assert!(err.to_string().contains("상한"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_process_password() {
+        // 제어 문자 제거, NFKD (𝔱 → t)
+        assert_eq!(process_password("𝔱𝔢𝔰𝔱\u{0000}pass\u{007F}\u{0085}word\n"), "testpassword");
+        assert_eq!(process_password(PASSWORD), "testpassword🔑");
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let secret: [u8; 32] = hex::decode(SECRET).unwrap().try_into().unwrap();
+        let pubkey: [u8; 48] = hex::decode(PUBKEY).unwrap().try_into().unwrap();
+
+        let json = encrypt(&secret, PASSWORD, &pubkey, &signing_key_path(0), "", KeystoreKdf::Pbkdf2 { c: 1024 }).unwrap();
+        println!("EIP-2335 키스토어: {}", json);
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], 4);
+        assert_eq!(value["path"], "m/12381/3600/0/0/0");
+        assert_eq!(value["crypto"]["kdf"]["function"], "pbkdf2");
+
+        // 비밀번호는 정규화된 형태로 비교됨
+        let (decrypted, info) = decrypt(&json, "testpassword🔑").unwrap();
+        assert_eq!(decrypted.as_slice(), secret.as_slice());
+        assert_eq!(info.pubkey, PUBKEY);
+    }
+}
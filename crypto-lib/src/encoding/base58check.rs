@@ -0,0 +1,173 @@
+//! # Base58Check Encoding
+//!
+//! 접두사(버전 바이트) + 페이로드 + 이중 SHA-256 체크섬 4바이트를 Base58로 인코딩
+//!
+//! ## 구조
+//! ```text
+//! base58(prefix || payload || sha256d(prefix || payload)[0..4])
+//! ```
+//!
+//! ## 사용처
+//!
+//! | 체인 | 접두사 | 알파벳 | 예 |
+//! |------|--------|--------|-----|
+//! | Bitcoin P2PKH / P2SH | 0x00 / 0x05 | Bitcoin | 1..., 3... |
+//! | Bitcoin WIF | 0x80 | Bitcoin | 5..., K..., L... |
+//! | BIP-32 확장 키 | 4바이트 (xprv, xpub) | Bitcoin | xprv..., xpub... |
+//! | Tron | 0x41 | Bitcoin | T... |
+//! | XRP Ledger | 0x00 | Ripple (`rpshnaf39w...`) | r... |
+//! | Tezos | 3바이트 (tz1 = 06 a1 9f) | Bitcoin | tz1... |
+//!
+//! 접두사 길이는 체인마다 다르므로 바이트 슬라이스로 받음
+//!
+//! ```
+//! use crypto_lib::encoding::base58check::{self, Alphabet};
+//!
+//! let address = base58check::encode(&[0x00], &[0u8; 20]);
+//! assert_eq!(address, "1111111111111111111114oLvT2");
+//! assert_eq!(base58check::decode_with_prefix(&address, &[0x00]).unwrap(), [0u8; 20]);
+//!
+//! let xrp = base58check::encode_with_alphabet(&[0x00], &[0u8; 20], Alphabet::Ripple);
+//! assert_eq!(xrp, "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+//! ```
+
+use crate::hash::sha256d;
+use crate::error::{Error, Result};
+
+/// 체크섬 길이 (바이트)
+pub const CHECKSUM_LEN: usize = 4;
+
+/// Base58 알파벳
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alphabet {
+    /// `123456789ABCDEFGH...` (Bitcoin, Tron, Tezos)
+    #[default]
+    Bitcoin,
+    /// `rpshnaf39wBUDNEGH...` (XRP Ledger)
+    Ripple,
+}
+
+impl Alphabet {
+    fn bs58(self) -> &'static bs58::Alphabet {
+        match self {
+            Alphabet::Bitcoin => bs58::Alphabet::BITCOIN,
+            Alphabet::Ripple => bs58::Alphabet::RIPPLE,
+        }
+    }
+}
+
+/// Base58Check 인코딩 (Bitcoin 알파벳)
+pub fn encode(prefix: &[u8], payload: &[u8]) -> String {
+    encode_with_alphabet(prefix, payload, Alphabet::Bitcoin)
+}
+
+/// Base58Check 인코딩 (알파벳 지정)
+pub fn encode_with_alphabet(prefix: &[u8], payload: &[u8], alphabet: Alphabet) -> String {
+    let mut data = Vec::with_capacity(prefix.len() + payload.len() + CHECKSUM_LEN);
+    data.extend_from_slice(prefix);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&checksum(&data));
+
+    bs58::encode(data).with_alphabet(alphabet.bs58()).into_string()
+}
+
+/// Base58Check 디코딩 (Bitcoin 알파벳)
+///
+/// 체크섬을 검증하고 `prefix || payload` 반환 (체크섬 제외)
+pub fn decode(encoded: &str) -> Result<Vec<u8>> {
+    decode_with_alphabet(encoded, Alphabet::Bitcoin)
+}
+
+/// Base58Check 디코딩 (알파벳 지정)
+pub fn decode_with_alphabet(encoded: &str, alphabet: Alphabet) -> Result<Vec<u8>> {
+    let mut data = bs58::decode(encoded.trim())
+        .with_alphabet(alphabet.bs58())
+        .into_vec()
+        .map_err(|e| Error::Base58(format!("유효하지 않은 Base58: {}", e)))?;
+
+    if data.len() < CHECKSUM_LEN {
+        return Err(Error::Base58(format!("데이터가 체크섬({}바이트)보다 짧습니다", CHECKSUM_LEN)));
+    }
+
+    let body_len = data.len() - CHECKSUM_LEN;
+    if checksum(&data[..body_len]) != data[body_len..] {
+        return Err(Error::Base58("체크섬이 일치하지 않습니다".to_string()));
+    }
+
+    data.truncate(body_len);
+    Ok(data)
+}
+
+/// 접두사를 확인하고 페이로드만 반환 (Bitcoin 알파벳)
+pub fn decode_with_prefix(encoded: &str, prefix: &[u8]) -> Result<Vec<u8>> {
+    let data = decode(encoded)?;
+    match data.strip_prefix(prefix) {
+        Some(payload) => Ok(payload.to_vec()),
+        None => Err(Error::Base58(format!(
+            "접두사가 다릅니다: {} (기대값 {})",
+            hex::encode(&data[..prefix.len().min(data.len())]),
+            hex::encode(prefix)
+        ))),
+    }
+}
+
+/// 체크섬 = sha256d(data)[0..4]
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = sha256d(data);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_vectors() {
+        let zero = [0u8; 20];
+        let cases = [
+            // Bitcoin P2PKH (HASH160 = 0)
+            (encode(&[0x00], &zero), "1111111111111111111114oLvT2"),
+            // Tron 블랙홀 주소
+            (encode(&[0x41], &zero), "T9yD14Nj9j7xAB4dbGeiX9h8unkKHxuWwb"),
+            // XRP ACCOUNT_ZERO
+            (encode_with_alphabet(&[0x00], &zero, Alphabet::Ripple), "rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            // Tezos tz1 소각 주소
+            (encode(&[0x06, 0xa1, 0x9f], &zero), "tz1Ke2h7sDdakHJQh8WX4Z372du1KChsksyU"),
+        ];
+        for (encoded, expected) in cases {
+            println!("{}", encoded);
+            assert_eq!(encoded, expected);
+        }
+
+        let bitcoin = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(encode(&[0x00], &bitcoin), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let payload = [7u8; 32];
+        for (prefix, alphabet) in [(&[0x80][..], Alphabet::Bitcoin), (&[0x04, 0x88, 0xad, 0xe4][..], Alphabet::Bitcoin), (&[0x00][..], Alphabet::Ripple)] {
+            let encoded = encode_with_alphabet(prefix, &payload, alphabet);
+            let decoded = decode_with_alphabet(&encoded, alphabet).unwrap();
+            assert_eq!(decoded[..prefix.len()], *prefix);
+            assert_eq!(decoded[prefix.len()..], payload);
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // 체크섬 오류 (마지막 문자 변경)
+        assert!(matches!(decode("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ"), Err(Error::Base58(_))));
+        // Base58 알파벳 밖의 문자 ('0')
+        assert!(matches!(decode("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAM0"), Err(Error::Base58(_))));
+        // 체크섬보다 짧음
+        assert!(decode("11").is_err());
+        // 접두사 불일치
+        let tron = encode(&[0x41], &[0u8; 20]);
+        let err = decode_with_prefix(&tron, &[0x00]).unwrap_err();
+        println!("{}", err);
+        assert!(matches!(err, Error::Base58(_)));
+        // 알파벳이 다르면 체크섬이 맞지 않음
+        assert!(decode("rrrrrrrrrrrrrrrrrrrrrhoLvTp").is_err());
+    }
+}
@@ -0,0 +1,479 @@
+//! # Bech32 Encoding
+//!
+//! BIP-173 Bech32, BIP-350 Bech32m 인코딩 구현
+//!
+//! ## 사용처
+//! - **Bitcoin SegWit**: bc1... (witness_version = Some(0))
+//! - **Bitcoin Taproot**: bc1p... (Bech32m, witness_version = Some(1))
+//! - **Cosmos SDK**: cosmos1..., osmo1... (witness_version = None)
+//! - **Sui**: suiprivkey... (witness_version = None)
+//!
+//! ## Bech32m
+//! 체크섬 마지막에 XOR하는 상수만 다름 (Bech32: 1, Bech32m: 0x2bc830a3)
+//! Bech32는 끝이 'p'일 때 'q'를 삽입/삭제해도 체크섬이 통과하는 약점이 있어 BIP-350에서 상수를 바꿈
+//!
+//! ## Bech32의 장점
+//! - **에러 검출**: 최대 4개 문자 삽입/삭제 검출 가능
+//! - **대소문자 무관**: QR 코드에 효율적
+//! - **구분자**: '1'로 HRP와 데이터 분리
+//!
+//! ## 디코딩
+//! `decode_bech32`는 체크섬을 검증하고 BIP-173 제약을 적용
+//! - 대소문자 혼용 거부, 전체 길이 90자 이하, HRP 1~83자 (ASCII 33~126)
+//! - 체크섬 상수로 Bech32 / Bech32m(BIP-350) 변형 판별
+//!
+//! ## 참고 자료
+//! - [BIP-173: Bech32](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki)
+//! - [BIP-350: Bech32m](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+
+use crate::error::{Error, Result};
+
+/// Bech32 문자 집합 (5비트 값 → 문자)
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// 역방향 테이블 (ASCII 소문자 → 5비트 값, 문자 집합 밖이면 -1)
+const CHARSET_REV: [i8; 128] = {
+    let mut table = [-1i8; 128];
+    let mut i = 0;
+    while i < CHARSET.len() {
+        table[CHARSET[i] as usize] = i as i8;
+        i += 1;
+    }
+    table
+};
+
+/// BCH 생성 다항식
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// BIP-173 최대 문자열 길이
+const MAX_LENGTH: usize = 90;
+
+/// BIP-173 Bech32 체크섬 상수
+const BECH32_CONST: u32 = 1;
+
+/// BIP-350 Bech32m 체크섬 상수
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// 체크섬 변형
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// BIP-173 (SegWit v0, Cosmos, Sui)
+    Bech32,
+    /// BIP-350 (SegWit v1+ / Taproot)
+    Bech32m,
+}
+
+impl Variant {
+    /// 체크섬 XOR 상수
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => BECH32_CONST,
+            Variant::Bech32m => BECH32M_CONST,
+        }
+    }
+}
+
+/// Bech32 인코딩
+///
+/// HRP(Human-Readable Part) + 구분자('1') + 데이터(5비트) + 체크섬(6문자)
+///
+/// # Arguments
+///
+/// * `hrp` - Human-Readable Part (예: "bc", "cosmos", "suiprivkey")
+/// * `witness_version` - Bitcoin SegWit 전용, None이면 일반 Bech32
+/// * `data` - 인코딩할 데이터 (8비트 배열)
+///
+/// # Returns
+///
+/// Bech32 인코딩된 문자열 (소문자)
+///
+/// # Examples
+///
+/// ```
+/// use crypto_lib::encoding::bech32::encode_bech32;
+///
+/// // Bitcoin SegWit (witness_version = 0)
+/// let pubkey_hash = [0u8; 20];
+/// let address = encode_bech32("bc", Some(0), &pubkey_hash);
+/// assert!(address.starts_with("bc1"));
+///
+/// // Cosmos (witness_version = None)
+/// let address = encode_bech32("cosmos", None, &pubkey_hash);
+/// assert!(address.starts_with("cosmos1"));
+/// ```
+pub fn encode_bech32(hrp: &str, witness_version: Option<u8>, data: &[u8]) -> String {
+    encode(hrp, witness_version, data, Variant::Bech32)
+}
+
+/// Bech32m 인코딩 (BIP-350)
+///
+/// `encode_bech32`와 같고 체크섬 상수만 다름
+///
+/// # Examples
+///
+/// ```
+/// use crypto_lib::encoding::bech32::encode_bech32m;
+///
+/// // Taproot (witness_version = 1, 32바이트 x-only 공개키)
+/// let address = encode_bech32m("bc", Some(1), &[0u8; 32]);
+/// assert!(address.starts_with("bc1p"));
+/// ```
+pub fn encode_bech32m(hrp: &str, witness_version: Option<u8>, data: &[u8]) -> String {
+    encode(hrp, witness_version, data, Variant::Bech32m)
+}
+
+/// 변형을 지정한 인코딩
+///
+/// 대량 주소 생성 경로이므로 중간 Vec 없이 한 번에 처리
+/// (8비트 → 5비트 변환, 체크섬 누적, 문자 변환을 같은 루프에서 수행)
+pub fn encode(hrp: &str, witness_version: Option<u8>, data: &[u8], variant: Variant) -> String {
+    let data_len = witness_version.is_some() as usize + (data.len() * 8).div_ceil(5);
+    let mut out = Vec::with_capacity(hrp.len() + 1 + data_len + 6);
+    out.extend_from_slice(hrp.as_bytes());
+    out.push(b'1');
+
+    let mut chk = hrp_polymod(hrp);
+    let mut push = |value: u8| {
+        chk = polymod_step(chk, value);
+        out.push(CHARSET[value as usize]);
+    };
+
+    if let Some(version) = witness_version {
+        push(version);
+    }
+
+    // 8비트 → 5비트 (마지막은 0으로 패딩)
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    for &byte in data {
+        acc = ((acc << 8) | byte as u32) & 0xfff;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            push(((acc >> bits) & 31) as u8);
+        }
+    }
+    if bits > 0 {
+        push(((acc << (5 - bits)) & 31) as u8);
+    }
+
+    // 체크섬 (6자리 플레이스홀더를 넣은 뒤 상수 XOR)
+    for _ in 0..6 {
+        chk = polymod_step(chk, 0);
+    }
+    chk ^= variant.constant();
+    for i in 0..6 {
+        out.push(CHARSET[((chk >> (5 * (5 - i))) & 31) as usize]);
+    }
+
+    // HRP는 호출자가 넘긴 문자열 그대로, 나머지는 문자 집합(ASCII)
+    String::from_utf8(out).expect("Bech32 문자 집합은 ASCII")
+}
+
+/// Bech32 디코딩 (체크섬 검증)
+///
+/// 5비트 데이터를 8비트로 되돌려 `(hrp, data, variant)` 반환, HRP는 소문자
+/// SegWit 주소처럼 witness 버전이 앞에 붙은 경우 `decode_segwit` 사용
+///
+/// # Examples
+///
+/// ```
+/// use crypto_lib::encoding::bech32::{decode_bech32, encode_bech32, Variant};
+///
+/// let address = encode_bech32("cosmos", None, &[7u8; 20]);
+/// let (hrp, data, variant) = decode_bech32(&address).unwrap();
+/// assert_eq!(hrp, "cosmos");
+/// assert_eq!(data, [7u8; 20]);
+/// assert_eq!(variant, Variant::Bech32);
+/// ```
+pub fn decode_bech32(encoded: &str) -> Result<(String, Vec<u8>, Variant)> {
+    let (hrp, values, variant) = decode_values(encoded)?;
+    let data = convert_bits_strict(&values)?;
+    Ok((hrp, data, variant))
+}
+
+/// SegWit 주소 디코딩: `(hrp, witness_version, program, variant)`
+///
+/// witness 버전과 체크섬 변형의 대응(v0 ↔ Bech32, v1+ ↔ Bech32m)은 검사하지 않음
+pub fn decode_segwit(encoded: &str) -> Result<(String, u8, Vec<u8>, Variant)> {
+    let (hrp, values, variant) = decode_values(encoded)?;
+    let (&version, program) = values
+        .split_first()
+        .ok_or_else(|| Error::Bech32("witness 버전이 없습니다".to_string()))?;
+    if version > 16 {
+        return Err(Error::Bech32(format!("유효하지 않은 witness 버전: {}", version)));
+    }
+    Ok((hrp, version, convert_bits_strict(program)?, variant))
+}
+
+/// 문자열 검사 + 체크섬 검증 → (소문자 HRP, 체크섬을 뺀 5비트 값, 변형)
+fn decode_values(encoded: &str) -> Result<(String, Vec<u8>, Variant)> {
+    if encoded.len() > MAX_LENGTH {
+        return Err(Error::Bech32(format!("길이 초과: {}자 (최대 {}자)", encoded.len(), MAX_LENGTH)));
+    }
+    if !encoded.bytes().all(|b| (33..=126).contains(&b)) {
+        return Err(Error::Bech32("ASCII 33~126 범위 밖의 문자가 있습니다".to_string()));
+    }
+    if encoded.bytes().any(|b| b.is_ascii_lowercase()) && encoded.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Err(Error::Bech32("대소문자가 섞여 있습니다".to_string()));
+    }
+
+    let lower = encoded.to_ascii_lowercase();
+    let separator = lower.rfind('1').ok_or_else(|| Error::Bech32("구분자 '1'이 없습니다".to_string()))?;
+    let (hrp, data_part) = (&lower[..separator], &lower[separator + 1..]);
+    if hrp.is_empty() || hrp.len() > 83 {
+        return Err(Error::Bech32(format!("HRP 길이는 1~83자여야 합니다: {}자", hrp.len())));
+    }
+    if data_part.len() < 6 {
+        return Err(Error::Bech32("데이터가 체크섬(6자)보다 짧습니다".to_string()));
+    }
+
+    // 앞에서 ASCII 33~126만 허용했으므로 테이블 범위 안
+    let values = data_part
+        .bytes()
+        .map(|b| match CHARSET_REV[b as usize] {
+            -1 => Err(Error::Bech32(format!("Bech32 문자 집합에 없는 문자: '{}'", b as char))),
+            v => Ok(v as u8),
+        })
+        .collect::<Result<Vec<u8>>>()?;
+
+    let check = values.iter().fold(hrp_polymod(hrp), |chk, &v| polymod_step(chk, v));
+    let variant = match check {
+        BECH32_CONST => Variant::Bech32,
+        BECH32M_CONST => Variant::Bech32m,
+        _ => return Err(Error::Bech32("체크섬이 일치하지 않습니다".to_string())),
+    };
+
+    let data_len = values.len() - 6;
+    let mut values = values;
+    values.truncate(data_len);
+    Ok((hrp.to_string(), values, variant))
+}
+
+/// 5비트 → 8비트 (패딩 없이, 남는 비트는 4비트 이하의 0이어야 함)
+fn convert_bits_strict(values: &[u8]) -> Result<Vec<u8>> {
+    let total_bits = values.len() * 5;
+    let data = convert_bits(values, 5, 8, false);
+    let leftover = total_bits % 8;
+    let last = values.last().copied().unwrap_or(0);
+    if leftover >= 5 || last & ((1u8 << leftover) - 1) != 0 {
+        return Err(Error::Bech32("유효하지 않은 패딩".to_string()));
+    }
+    Ok(data)
+}
+
+/// 비트 변환 (8비트 ↔ 5비트)
+///
+/// Bech32는 5비트 단위로 인코딩하므로 8비트 데이터를 5비트로 변환 필요
+///
+/// # Arguments
+///
+/// * `data` - 변환할 데이터
+/// * `from_bits` - 입력 비트 수 (보통 8)
+/// * `to_bits` - 출력 비트 수 (보통 5)
+/// * `pad` - 패딩 여부 (마지막 비트가 부족할 때)
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Vec<u8> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut result = Vec::new();
+    let max_v = (1u32 << to_bits) - 1;
+
+    for &value in data {
+        acc = (acc << from_bits) | (value as u32);
+        bits += from_bits;
+
+        while bits >= to_bits {
+            bits -= to_bits;
+            result.push(((acc >> bits) & max_v) as u8);
+        }
+    }
+
+    if pad && bits > 0 {
+        result.push(((acc << (to_bits - bits)) & max_v) as u8);
+    }
+
+    result
+}
+
+/// HRP 확장값을 다항식에 넣은 상태
+///
+/// HRP의 각 문자를 상위 3비트, 구분값 0, 하위 5비트 순서로 넣음 (BIP-173 `hrp_expand`)
+/// 확장 배열을 만들지 않고 바로 누적
+fn hrp_polymod(hrp: &str) -> u32 {
+    let bytes = hrp.as_bytes();
+    let mut chk = bytes.iter().fold(1, |chk, &c| polymod_step(chk, c >> 5));
+    chk = polymod_step(chk, 0);
+    bytes.iter().fold(chk, |chk, &c| polymod_step(chk, c & 31))
+}
+
+/// Bech32 다항식 모듈러 연산의 한 단계
+///
+/// 오류 검출 코드의 핵심 알고리즘
+/// BCH 코드 기반의 체크섬 계산 (5비트 값 하나를 누적)
+#[inline]
+fn polymod_step(chk: u32, value: u8) -> u32 {
+    let top = chk >> 25;
+    let mut chk = ((chk & 0x1ffffff) << 5) ^ (value as u32);
+    for (i, &gen) in GENERATOR.iter().enumerate() {
+        if (top >> i) & 1 == 1 {
+            chk ^= gen;
+        }
+    }
+    chk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32_bitcoin_segwit() {
+        // Bitcoin SegWit 주소 테스트
+        // HASH160 → bc1...
+        let pubkey_hash = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        let address = encode_bech32("bc", Some(0), &pubkey_hash);
+
+        // BIP-173 테스트 벡터
+        assert_eq!(address, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+    }
+
+    #[test]
+    fn test_bech32_cosmos() {
+        // Cosmos 주소 테스트
+        let pubkey_hash = [0u8; 20];
+        let address = encode_bech32("cosmos", None, &pubkey_hash);
+
+        assert!(address.starts_with("cosmos1"));
+        assert_eq!(address.len(), 45); // "cosmos1" + 38문자
+    }
+
+    #[test]
+    fn test_bech32_sui_privkey() {
+        // Sui 개인키 인코딩 테스트
+        let privkey_with_flag = [0u8; 33]; // 플래그 + 32바이트 개인키
+        let encoded = encode_bech32("suiprivkey", None, &privkey_with_flag);
+
+        assert!(encoded.starts_with("suiprivkey1"));
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let pubkey_hash = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+
+        let (hrp, version, program, variant) = decode_segwit("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap();
+        assert_eq!((hrp.as_str(), version, variant), ("bc", 0, Variant::Bech32));
+        assert_eq!(program, pubkey_hash);
+
+        let address = encode_bech32("cosmos", None, &pubkey_hash);
+        let (hrp, data, _) = decode_bech32(&address).unwrap();
+        println!("{} → {} {}", address, hrp, hex::encode(&data));
+        assert_eq!(hrp, "cosmos");
+        assert_eq!(data, pubkey_hash);
+    }
+
+    #[test]
+    fn test_decode_bech32m() {
+        // BIP-350 유효한 Bech32m 문자열
+        let (hrp, _, variant) = decode_values("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx").unwrap();
+        assert_eq!((hrp.as_str(), variant), ("abcdef", Variant::Bech32m));
+
+        let (hrp, version, program, variant) =
+            decode_segwit("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0").unwrap();
+        assert_eq!((hrp.as_str(), version, program.len(), variant), ("bc", 1, 32, Variant::Bech32m));
+    }
+
+    #[test]
+    fn test_bech32m_encoding() {
+        // BIP-350 테스트 벡터 (witness v1, 32바이트)
+        let program = hex::decode("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+        let address = encode_bech32m("bc", Some(1), &program);
+        assert_eq!(address, "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0");
+
+        // 같은 데이터라도 변형이 다르면 체크섬이 다름
+        let bech32 = encode_bech32("bc", Some(1), &program);
+        assert_ne!(address, bech32);
+        assert_eq!(decode_segwit(&bech32).unwrap().3, Variant::Bech32);
+
+        let kaspa = encode("kaspa", None, &[1u8; 32], Variant::Bech32m);
+        assert_eq!(decode_bech32(&kaspa).unwrap(), ("kaspa".to_string(), vec![1u8; 32], Variant::Bech32m));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid() {
+        let invalid = [
+            // 체크섬 오류 (마지막 문자 변경)
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+            // 대소문자 혼용
+            "bc1qW508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            // 구분자 없음
+            "pzry9x0s0muk",
+            // 빈 HRP
+            "1pzry9x0s0muk",
+            // 문자 집합 밖 ('b')
+            "x1b4n0q5v",
+            // 체크섬보다 짧음
+            "li1dgmt3",
+            // 90자 초과
+            "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+        ];
+        for s in invalid {
+            let err = decode_bech32(s).unwrap_err();
+            println!("{} → {}", s, err);
+            assert!(matches!(err, Error::Bech32(_)));
+        }
+
+        // 5비트 → 8비트 변환 시 0이 아닌 패딩
+        assert!(convert_bits_strict(&[31, 31, 31, 17]).is_err());
+        assert_eq!(convert_bits_strict(&[31, 31, 31, 16]).unwrap(), vec![0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_convert_bits() {
+        // 8비트 → 5비트 변환 테스트
+        let data = vec![0xFF, 0xFF];
+        let converted = convert_bits(&data, 8, 5, true);
+
+        // 0xFF 0xFF = 11111111 11111111 (16비트)
+        // → 11111 11111 11111 10000 (5비트 x 4, 마지막 패딩)
+        // = 31, 31, 31, 16
+        assert_eq!(converted, vec![31, 31, 31, 16]);
+    }
+
+    #[test]
+    fn test_hrp_polymod() {
+        // HRP 확장 테스트
+        // 'b' = 0x62 = 0b01100010
+        // 'c' = 0x63 = 0b01100011
+        // → [상위3비트, 상위3비트, 0, 하위5비트, 하위5비트]
+        let expanded = [3u8, 3, 0, 2, 3];
+        let expected = expanded.iter().fold(1, |chk, &v| polymod_step(chk, v));
+        assert_eq!(hrp_polymod("bc"), expected);
+    }
+
+    #[test]
+    fn test_encode_matches_reference() {
+        // BIP-173 / BIP-350 빈 데이터 벡터
+        assert_eq!(encode("a", None, &[], Variant::Bech32), "a12uel5l");
+        assert_eq!(encode("a", None, &[], Variant::Bech32m), "a1lqfn3a");
+
+        // 한 번에 처리하는 인코딩 = 5비트 변환 + 체크섬을 따로 계산한 결과
+        for len in 0..=40 {
+            let data: Vec<u8> = (0..len as u8).map(|i| i.wrapping_mul(37).wrapping_add(11)).collect();
+            for (witness_version, variant) in [(None, Variant::Bech32), (Some(0), Variant::Bech32), (Some(1), Variant::Bech32m)] {
+                let mut values: Vec<u8> = witness_version.into_iter().collect();
+                values.extend(convert_bits(&data, 8, 5, true));
+                let chk = values.iter().chain(&[0u8; 6]).fold(hrp_polymod("tb"), |chk, &v| polymod_step(chk, v)) ^ variant.constant();
+                let expected: String = values
+                    .iter()
+                    .copied()
+                    .chain((0..6).map(|i| ((chk >> (5 * (5 - i))) & 31) as u8))
+                    .map(|v| CHARSET[v as usize] as char)
+                    .collect();
+
+                let encoded = encode("tb", witness_version, &data, variant);
+                assert_eq!(encoded, format!("tb1{}", expected));
+                assert_eq!(decode_values(&encoded).unwrap(), ("tb".to_string(), values, variant));
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! # Encoding
+//!
+//! 주소/키 문자열 인코딩 공통 모듈
+//!
+//! ### bech32
+//! Bech32 (BIP-173), Bech32m (BIP-350) 인코딩/디코딩
+//! - Bitcoin SegWit v0 / Cosmos / Sui: Bech32
+//! - Taproot (SegWit v1+), Kaspa, CKB: Bech32m
+//! - 체크섬 다항식(polymod)은 이 모듈 하나만 유지
+//!
+//! ### base58check
+//! Base58 + 이중 SHA-256 체크섬, 가변 길이 접두사
+//! - Bitcoin 주소/WIF/확장 키, Tron, Tezos: Bitcoin 알파벳
+//! - XRP Ledger: Ripple 알파벳
+
+pub mod base58check;
+pub mod bech32;
@@ -0,0 +1,227 @@
+//! User-Supplied Entropy (Dice / Coin Flips)
+//!
+//! 컴퓨터의 난수 생성기를 믿지 않는 경우, 주사위나 동전으로 직접 만든 엔트로피를
+//! BIP-39 엔트로피로 변환 (같은 입력 → 같은 니모닉이므로 다른 도구로 검증 가능)
+//!
+//! ## 주사위 방식
+//!
+//! | 방식 | 변환 | 필요한 굴림 수 |
+//! |------|------|--------------|
+//! | Coldcard | SHA-256(굴림 문자열) | 12단어 50회, 24단어 99회 |
+//! | Ian Coleman | 굴림마다 편향 없는 비트로 변환 | 12단어 약 77회, 24단어 약 154회 |
+//!
+//! ### Coldcard
+//! 굴림 결과를 그대로 ASCII 문자열로 이어붙여 SHA-256 (예: "316254...")
+//! 12단어는 해시의 앞 16바이트 사용
+//!
+//! ### Ian Coleman (편향 제거)
+//! 주사위 6은 0으로 취급하고, 6가지 값을 2비트 4개 + 1비트 2개로 나눠서
+//! 각 비트가 0/1이 될 확률이 정확히 1/2이 되도록 변환
+//! ```text
+//! 1 → 01   2 → 10   3 → 11   6(0) → 00
+//! 4 → 0    5 → 1
+//! ```
+//! 굴림당 평균 5/3비트, 모은 비트는 32비트 배수(128~256)로 잘라서 사용
+//!
+//! ## 동전 던지기
+//! 앞면 = 1, 뒷면 = 0 (H/T 또는 1/0), 마찬가지로 32비트 배수로 자름
+
+use sha2::{Digest, Sha256};
+
+use crate::bip39::{entropy_to_mnemonic, MnemonicType};
+use crate::error::{Error, Result};
+
+/// 지원하는 엔트로피 크기 (비트)
+const ENTROPY_BITS: [usize; 5] = [128, 160, 192, 224, 256];
+
+/// Coldcard 12단어 최소 굴림 수 (6^50 ≈ 2^129)
+pub const COLDCARD_MIN_ROLLS_12: usize = 50;
+/// Coldcard 24단어 최소 굴림 수 (6^99 ≈ 2^256)
+pub const COLDCARD_MIN_ROLLS_24: usize = 99;
+
+/// 주사위 → 엔트로피 변환 방식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiceScheme {
+    /// Coldcard: SHA-256(굴림 문자열)
+    Coldcard(MnemonicType),
+    /// Ian Coleman BIP39 도구: 편향 없는 비트 변환 후 32비트 배수로 자름
+    IanColeman,
+}
+
+/// 주사위 굴림 → BIP-39 엔트로피
+///
+/// `rolls`: 1~6 숫자 (공백, 쉼표는 무시)
+pub fn entropy_from_dice(rolls: &str, scheme: DiceScheme) -> Result<Vec<u8>> {
+    let rolls = parse_events(rolls, "123456", "주사위")?;
+
+    match scheme {
+        DiceScheme::Coldcard(mnemonic_type) => {
+            let min_rolls = match mnemonic_type {
+                MnemonicType::Words12 => COLDCARD_MIN_ROLLS_12,
+                MnemonicType::Words24 => COLDCARD_MIN_ROLLS_24,
+            };
+            if rolls.len() < min_rolls {
+                return Err(Error::InvalidInput(format!(
+                    "{}단어에는 주사위를 {}번 이상 굴려야 합니다, 입력: {}번",
+                    mnemonic_type.word_count(),
+                    min_rolls,
+                    rolls.len()
+                )));
+            }
+
+            let rolls: String = rolls.iter().collect();
+            let hash = Sha256::digest(rolls.as_bytes());
+            Ok(hash[..mnemonic_type.entropy_bytes()].to_vec())
+        }
+        DiceScheme::IanColeman => {
+            let bits: Vec<bool> = rolls
+                .iter()
+                .flat_map(|&roll| dice_bits(roll).iter().copied())
+                .collect();
+            bits_to_entropy(&bits, rolls.len(), "주사위 굴림")
+        }
+    }
+}
+
+/// 동전 던지기 → BIP-39 엔트로피
+///
+/// `flips`: H/T 또는 1/0 (대소문자 무관, 공백/쉼표 무시), 128번 이상
+pub fn entropy_from_coin_flips(flips: &str) -> Result<Vec<u8>> {
+    let flips = parse_events(&flips.to_uppercase(), "HT10", "동전")?;
+    let bits: Vec<bool> = flips.iter().map(|&c| c == 'H' || c == '1').collect();
+    bits_to_entropy(&bits, flips.len(), "동전 던지기")
+}
+
+/// 주사위 굴림 → 영어 니모닉
+pub fn mnemonic_from_dice(rolls: &str, scheme: DiceScheme) -> Result<String> {
+    let entropy = entropy_from_dice(rolls, scheme)?;
+    Ok(entropy_to_mnemonic(&entropy)?)
+}
+
+/// 동전 던지기 → 영어 니모닉
+pub fn mnemonic_from_coin_flips(flips: &str) -> Result<String> {
+    let entropy = entropy_from_coin_flips(flips)?;
+    Ok(entropy_to_mnemonic(&entropy)?)
+}
+
+/// 허용 문자만 남기고 파싱 (공백, 쉼표는 구분자로 무시)
+fn parse_events(input: &str, allowed: &str, name: &str) -> Result<Vec<char>> {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .map(|c| {
+            if allowed.contains(c) {
+                Ok(c)
+            } else {
+                Err(Error::InvalidInput(format!("유효하지 않은 {} 값: '{}'", name, c)))
+            }
+        })
+        .collect()
+}
+
+/// Ian Coleman 방식 주사위 → 비트 (6은 0으로 취급)
+fn dice_bits(roll: char) -> &'static [bool] {
+    match roll {
+        '6' => &[false, false],
+        '1' => &[false, true],
+        '2' => &[true, false],
+        '3' => &[true, true],
+        '4' => &[false],
+        '5' => &[true],
+        _ => unreachable!("parse_events에서 검증됨"),
+    }
+}
+
+/// 비트 → 엔트로피 (지원 크기 중 가장 큰 것으로, 앞쪽 비트 사용)
+fn bits_to_entropy(bits: &[bool], events: usize, name: &str) -> Result<Vec<u8>> {
+    let entropy_bits = ENTROPY_BITS
+        .iter()
+        .rev()
+        .copied()
+        .find(|&n| n <= bits.len())
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "엔트로피가 부족합니다: {}번의 {}에서 {}비트 (최소 128비트 필요)",
+                events,
+                name,
+                bits.len()
+            ))
+        })?;
+
+    Ok(bits[..entropy_bits]
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::validate_mnemonic;
+
+    #[test]
+    fn test_coldcard_dice() {
+        let rolls = "123456".repeat(17); // 102번
+        let entropy = entropy_from_dice(&rolls, DiceScheme::Coldcard(MnemonicType::Words24)).unwrap();
+
+        // Coldcard: 굴림 문자열의 SHA-256 그대로
+        assert_eq!(entropy, Sha256::digest(rolls.as_bytes()).to_vec());
+
+        let mnemonic = mnemonic_from_dice(&rolls, DiceScheme::Coldcard(MnemonicType::Words24)).unwrap();
+        println!("Coldcard 니모닉: {}", mnemonic);
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+        assert!(validate_mnemonic(&mnemonic).is_ok());
+
+        // 12단어는 해시 앞 16바이트
+        let entropy_12 = entropy_from_dice(&rolls, DiceScheme::Coldcard(MnemonicType::Words12)).unwrap();
+        assert_eq!(entropy_12, entropy[..16]);
+
+        // 굴림 수 부족
+        let short = "1".repeat(COLDCARD_MIN_ROLLS_24 - 1);
+        assert!(entropy_from_dice(&short, DiceScheme::Coldcard(MnemonicType::Words24)).is_err());
+    }
+
+    #[test]
+    fn test_ian_coleman_dice() {
+        // 3 → 11, 굴림 128번 → 256비트 모두 1
+        let entropy = entropy_from_dice(&"3".repeat(128), DiceScheme::IanColeman).unwrap();
+        assert_eq!(entropy, vec![0xff; 32]);
+
+        // 6 → 00, 4 → 0: 2비트 × 40 + 1비트 × 50 = 130비트 → 128비트로 자름
+        let rolls = format!("{}{}", "6".repeat(40), "4".repeat(50));
+        let entropy = entropy_from_dice(&rolls, DiceScheme::IanColeman).unwrap();
+        assert_eq!(entropy, vec![0u8; 16]);
+        assert_eq!(
+            mnemonic_from_dice(&rolls, DiceScheme::IanColeman).unwrap(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+
+        // 1 → 01, 2 → 10
+        let entropy = entropy_from_dice(&"12".repeat(32), DiceScheme::IanColeman).unwrap();
+        assert_eq!(entropy, vec![0x66; 16]);
+    }
+
+    #[test]
+    fn test_coin_flips() {
+        let flips = "HT".repeat(64);
+        assert_eq!(entropy_from_coin_flips(&flips).unwrap(), vec![0xaa; 16]);
+
+        // 1/0 표기, 구분자, 소문자
+        let flips = "1, 0, ".repeat(64) + "h t";
+        assert_eq!(entropy_from_coin_flips(&flips).unwrap(), vec![0xaa; 16]);
+
+        // 160비트 이상이면 더 긴 엔트로피
+        assert_eq!(entropy_from_coin_flips(&"1".repeat(170)).unwrap().len(), 20);
+        assert_eq!(entropy_from_coin_flips(&"1".repeat(300)).unwrap().len(), 32);
+
+        assert!(entropy_from_coin_flips(&"1".repeat(127)).is_err());
+        assert!(entropy_from_coin_flips("HTX").is_err());
+    }
+
+    #[test]
+    fn test_invalid_dice() {
+        assert!(entropy_from_dice("1234567", DiceScheme::IanColeman).is_err());
+        assert!(entropy_from_dice("0", DiceScheme::IanColeman).is_err());
+        assert!(entropy_from_dice(&"5".repeat(127), DiceScheme::IanColeman).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! 크레이트 공통 에러
+//!
+//! 모든 실패 가능한 함수는 `crate::Result<T>` (= `Result<T, Error>`)를 반환
+//! 호출자는 variant로 실패 원인을 구분하고, `Display`는 기존 문자열 에러와 같은 메시지를 출력
+//!
+//! ```
+//! use crypto_lib::{bip32::DerivationPath, Error};
+//!
+//! match "m/44'/abc".parse::<DerivationPath>() {
+//!     Err(Error::InvalidPath { segment, .. }) => assert_eq!(segment, "abc"),
+//!     other => panic!("{:?}", other),
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::bip39::MnemonicError;
+
+/// 크레이트 공통 `Result`
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// 크레이트 공통 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// 니모닉 검증 실패 (단어 수, 단어 목록, 체크섬)
+    InvalidMnemonic(MnemonicError),
+    /// 도출 경로 파싱/적용 실패 (`segment`: 문제가 된 경로 구간)
+    InvalidPath { segment: String, message: String },
+    /// 유효하지 않은 개인키/공개키/확장 키
+    InvalidKey(String),
+    /// 유효하지 않은 주소
+    InvalidAddress(String),
+    /// 키에서 도출한 주소가 기대한 주소와 다름 (`derived`: 키의 주소)
+    AddressMismatch { expected: String, derived: String },
+    /// 서명 파싱/검증 실패
+    InvalidSignature(String),
+    /// Bech32/Bech32m 디코딩 실패
+    Bech32(String),
+    /// Base58/Base58Check 디코딩 실패
+    Base58(String),
+    /// hex 디코딩 실패
+    Hex(String),
+    /// 복호화 실패 (비밀번호 불일치, MAC/태그 불일치)
+    Decryption(String),
+    /// 키스토어/저장소 형식 또는 암호화 파라미터 오류
+    Keystore(String),
+    /// 트랜잭션/PSBT 파싱, 서명 오류
+    Transaction(String),
+    /// Shamir 비밀 분산 오류 (SLIP-39 포함)
+    Shamir(String),
+    /// 체인 레지스트리, 지원하지 않는 체인
+    Chain(String),
+    /// 파일 입출력 실패
+    Io(String),
+    /// 하드웨어 지갑 통신 실패, 오류 상태 응답, 주소 불일치
+    Device(String),
+    /// 체인 RPC 조회 실패 (연결, HTTP 상태, 노드 오류 응답, 응답 형식)
+    Rpc(String),
+    /// 그 밖의 잘못된 입력
+    InvalidInput(String),
+}
+
+impl Error {
+    /// 경로 에러 생성
+    pub(crate) fn path(segment: impl Into<String>, message: impl Into<String>) -> Self {
+        Error::InvalidPath { segment: segment.into(), message: message.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidMnemonic(e) => e.fmt(f),
+            Error::InvalidPath { message, .. } => f.write_str(message),
+            Error::AddressMismatch { expected, derived } => write!(f, "키의 주소가 다릅니다: {} (기대값 {})", derived, expected),
+            Error::InvalidKey(message)
+            | Error::InvalidAddress(message)
+            | Error::InvalidSignature(message)
+            | Error::Bech32(message)
+            | Error::Base58(message)
+            | Error::Hex(message)
+            | Error::Decryption(message)
+            | Error::Keystore(message)
+            | Error::Transaction(message)
+            | Error::Shamir(message)
+            | Error::Chain(message)
+            | Error::Io(message)
+            | Error::Device(message)
+            | Error::Rpc(message)
+            | Error::InvalidInput(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidMnemonic(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<MnemonicError> for Error {
+    fn from(e: MnemonicError) -> Self {
+        Error::InvalidMnemonic(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_keeps_message() {
+        let err = Error::InvalidKey("유효하지 않은 개인키".to_string());
+        assert_eq!(err.to_string(), "유효하지 않은 개인키");
+
+        let err = Error::from(MnemonicError::InvalidChecksum);
+        assert_eq!(err.to_string(), MnemonicError::InvalidChecksum.to_string());
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
@@ -0,0 +1,119 @@
+//! Contract Address Prediction
+//!
+//! 배포 트랜잭션을 보내기 전에 컨트랙트가 생길 주소를 계산
+//!
+//! ## CREATE (일반 배포, `to`가 없는 트랜잭션 또는 CREATE 옵코드)
+//! ```text
+//! 주소 = Keccak-256(RLP([deployer, nonce]))[12..]
+//! ```
+//! EOA는 배포 트랜잭션의 nonce, 컨트랙트는 자신의 nonce (EIP-161 이후 1부터 시작)
+//!
+//! ## CREATE2 (EIP-1014)
+//! ```text
+//! 주소 = Keccak-256(0xff || deployer || salt || Keccak-256(init_code))[12..]
+//! ```
+//! nonce와 무관하므로 같은 팩토리/salt/코드면 어느 체인에서든 같은 주소
+
+use super::transaction::{encode_bytes, encode_list, encode_uint};
+use super::{keccak256, parse_address, to_checksum_address};
+use crate::error::Result;
+
+/// CREATE 주소 (EIP-55 체크섬)
+pub fn create_address(deployer: &str, nonce: u64) -> Result<String> {
+    Ok(to_checksum_address(&create_address_bytes(&parse_address(deployer)?, nonce)))
+}
+
+/// CREATE2 주소 (EIP-55 체크섬)
+pub fn create2_address(deployer: &str, salt: &[u8; 32], init_code_hash: &[u8; 32]) -> Result<String> {
+    Ok(to_checksum_address(&create2_address_bytes(&parse_address(deployer)?, salt, init_code_hash)))
+}
+
+/// CREATE 주소 (20바이트)
+pub fn create_address_bytes(deployer: &[u8; 20], nonce: u64) -> [u8; 20] {
+    let encoded = encode_list(&[encode_bytes(deployer), encode_uint(&nonce.to_be_bytes())]);
+    last_20_bytes(&keccak256(&encoded))
+}
+
+/// CREATE2 주소 (20바이트)
+pub fn create2_address_bytes(deployer: &[u8; 20], salt: &[u8; 32], init_code_hash: &[u8; 32]) -> [u8; 20] {
+    let mut data = Vec::with_capacity(85);
+    data.push(0xff);
+    data.extend_from_slice(deployer);
+    data.extend_from_slice(salt);
+    data.extend_from_slice(init_code_hash);
+    last_20_bytes(&keccak256(&data))
+}
+
+fn last_20_bytes(hash: &[u8; 32]) -> [u8; 20] {
+    hash[12..].try_into().expect("32 - 12 = 20바이트")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    fn bytes32(hex_str: &str) -> [u8; 32] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_create_address() {
+        // 널리 쓰이는 예제: 0x6ac7...dbf0의 nonce 0~3 배포 주소
+        let deployer = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0";
+        let expected = [
+            "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d",
+            "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8",
+            "0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91",
+            "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c",
+        ];
+        for (nonce, address) in expected.iter().enumerate() {
+            assert_eq!(create_address(deployer, nonce as u64).unwrap().to_lowercase(), *address);
+        }
+
+        // nonce가 0x7f보다 크면 RLP 문자열로 인코딩 (경계값도 계산은 가능해야 함)
+        assert_ne!(create_address(deployer, 0x7f).unwrap(), create_address(deployer, 0x80).unwrap());
+        assert!(create_address(deployer, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_create2_eip1014_vectors() {
+        let zero = [0u8; 32];
+        let cases: [(&str, [u8; 32], Vec<u8>, &str); 7] = [
+            ("0x0000000000000000000000000000000000000000", zero, vec![0x00], "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"),
+            ("0xdeadbeef00000000000000000000000000000000", zero, vec![0x00], "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3"),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                bytes32("000000000000000000000000feed000000000000000000000000000000000000"),
+                vec![0x00],
+                "0xD04116cDd17beBE565EB2422F2497E06cC1C9833",
+            ),
+            ("0x0000000000000000000000000000000000000000", zero, hex::decode("deadbeef").unwrap(), "0x70f2b2914A2a4b783FaEFb75f459A580616Fcb5e"),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                bytes32("00000000000000000000000000000000000000000000000000000000cafebabe"),
+                hex::decode("deadbeef").unwrap(),
+                "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                bytes32("00000000000000000000000000000000000000000000000000000000cafebabe"),
+                hex::decode("deadbeef".repeat(11)).unwrap(),
+                "0x1d8bfDC5D46DC4f61D6b6115972536eBE6A8854C",
+            ),
+            ("0x0000000000000000000000000000000000000000", zero, vec![], "0xE33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0"),
+        ];
+
+        for (deployer, salt, init_code, expected) in cases {
+            assert_eq!(create2_address(deployer, &salt, &keccak256(&init_code)).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_invalid_deployer() {
+        assert!(matches!(create_address("0x1234", 0), Err(Error::InvalidAddress(_))));
+        // 대소문자가 섞였는데 체크섬이 틀림
+        assert!(create_address("0x6Ac7ea33f8831ea9dcc53393aaa88b25a785dbf0", 0).is_err());
+        assert!(create2_address("0xDeadbeef00000000000000000000000000000000", &[0; 32], &[0; 32]).is_err());
+    }
+}
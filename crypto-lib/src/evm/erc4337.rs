@@ -0,0 +1,399 @@
+//! ERC-4337 Counterfactual Smart Account Address
+//!
+//! 스마트 계정은 첫 UserOperation이 실행될 때 팩토리가 CREATE2로 배포
+//! 주소는 배포 전에 정해지므로 EOA(소유자 키)만으로 입금 주소를 미리 보여줄 수 있음
+//!
+//! ## 흐름
+//! ```text
+//! UserOperation.initCode = factory || factoryData        (v0.7은 factory, factoryData 필드)
+//! EntryPoint → factory.call(factoryData) → CREATE2(factory, salt, init_code)
+//! sender = Keccak-256(0xff || factory || salt || Keccak-256(init_code))[12..]
+//! ```
+//! salt와 init_code를 만드는 방식은 팩토리마다 다르므로 프리셋으로 제공
+//!
+//! ## 프리셋
+//!
+//! | 프리셋 | 팩토리 호출 | CREATE2 salt | init_code |
+//! |--------|------------|--------------|-----------|
+//! | SimpleAccount (v0.6, v0.7) | `createAccount(owner, index)` | `bytes32(index)` | ERC1967Proxy 생성 코드 ‖ abi.encode(구현, `initialize(owner)`) |
+//! | Kernel v3.1 | `createAccount(initData, bytes32(index))` | Keccak-256(initData ‖ bytes32(index)) | Solady ERC-1967 최소 프록시(구현) |
+//!
+//! Kernel은 95바이트 최소 프록시라 init_code를 직접 만들 수 있음
+//! SimpleAccount의 ERC1967Proxy(OpenZeppelin) 생성 코드는 컴파일 결과물이라 포함하지 않으며,
+//! `sender_address_with_proxy_code`에 팩토리의 `proxyCreationCode`/빌드 산출물을 넘겨야 함
+//! (검증된 바이트코드 출처 없이 상수로 넣으면 틀린 입금 주소를 조용히 만들 수 있음)
+//!
+//! ```
+//! use crypto_lib::evm::EvmAccount;
+//! use crypto_lib::evm::erc4337::SmartAccountFactory;
+//!
+//! let owner = EvmAccount::from_private_key([0x46; 32]).unwrap();
+//! let sender = SmartAccountFactory::KernelV31.sender_address(&owner.address_checksummed(), 0).unwrap();
+//! let call = SmartAccountFactory::KernelV31.factory_call(&owner.address_checksummed(), 0).unwrap();
+//! assert_eq!(call.factory, SmartAccountFactory::KernelV31.factory_address());
+//! assert!(sender.starts_with("0x"));
+//! ```
+
+use super::contract::create2_address_bytes;
+use super::{keccak256, parse_address, to_checksum_address, EvmAccount};
+use crate::error::{Error, Result};
+
+/// EntryPoint v0.6
+pub const ENTRY_POINT_V06: &str = "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789";
+/// EntryPoint v0.7
+pub const ENTRY_POINT_V07: &str = "0x0000000071727De22E5E9d8BAf0edAc6f37da032";
+
+/// ERC-1967 구현 주소 슬롯 (Keccak-256("eip1967.proxy.implementation") - 1)
+const ERC1967_IMPLEMENTATION_SLOT: &str = "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+/// 스마트 계정 팩토리 프리셋
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmartAccountFactory {
+    /// eth-infinitism SimpleAccountFactory (EntryPoint v0.6)
+    SimpleAccountV06,
+    /// eth-infinitism SimpleAccountFactory (EntryPoint v0.7)
+    SimpleAccountV07,
+    /// ZeroDev Kernel v3.1 + ECDSA 검증자 (EntryPoint v0.7)
+    KernelV31,
+}
+
+/// 계정을 배포하는 팩토리 호출 (UserOperation의 factory, factoryData)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FactoryCall {
+    /// 팩토리 주소 (EIP-55)
+    pub factory: String,
+    /// 팩토리 calldata
+    pub data: Vec<u8>,
+}
+
+impl FactoryCall {
+    /// v0.6 `initCode` (factory ‖ factoryData)
+    pub fn init_code(&self) -> Result<Vec<u8>> {
+        let mut init_code = parse_address(&self.factory)?.to_vec();
+        init_code.extend_from_slice(&self.data);
+        Ok(init_code)
+    }
+
+    /// factoryData (0x hex)
+    pub fn data_hex(&self) -> String {
+        format!("0x{}", hex::encode(&self.data))
+    }
+}
+
+impl SmartAccountFactory {
+    /// 모든 프리셋
+    pub const ALL: [SmartAccountFactory; 3] =
+        [SmartAccountFactory::SimpleAccountV06, SmartAccountFactory::SimpleAccountV07, SmartAccountFactory::KernelV31];
+
+    /// 팩토리 주소 (모든 EVM 체인에서 같음)
+    pub fn factory_address(self) -> &'static str {
+        match self {
+            SmartAccountFactory::SimpleAccountV06 => "0x9406Cc6185a346906296840746125a0E44976454",
+            SmartAccountFactory::SimpleAccountV07 => "0x91E60e0613810449d098b0b5Ec8b51A0FE8c8985",
+            SmartAccountFactory::KernelV31 => "0xaac5D4240AF87249B3f71BC8E4A2cae074A3E419",
+        }
+    }
+
+    /// 사용하는 EntryPoint
+    pub fn entry_point(self) -> &'static str {
+        match self {
+            SmartAccountFactory::SimpleAccountV06 => ENTRY_POINT_V06,
+            SmartAccountFactory::SimpleAccountV07 | SmartAccountFactory::KernelV31 => ENTRY_POINT_V07,
+        }
+    }
+
+    /// 프록시가 가리키는 계정 구현 주소
+    ///
+    /// SimpleAccountFactory는 생성자에서 구현을 배포하므로 CREATE(팩토리, nonce 1)
+    pub fn implementation(self) -> String {
+        match self {
+            SmartAccountFactory::SimpleAccountV06 | SmartAccountFactory::SimpleAccountV07 => {
+                let factory = parse_address(self.factory_address()).expect("프리셋 주소");
+                to_checksum_address(&super::contract::create_address_bytes(&factory, 1))
+            }
+            SmartAccountFactory::KernelV31 => "0xBAC849bB641841b44E965fB01A4Bf5F074f84b4D".to_string(),
+        }
+    }
+
+    /// 소유자 EOA와 index로 팩토리 호출 생성
+    pub fn factory_call(self, owner: &str, index: u64) -> Result<FactoryCall> {
+        let owner = parse_address(owner)?;
+        let data = match self {
+            SmartAccountFactory::SimpleAccountV06 | SmartAccountFactory::SimpleAccountV07 => {
+                let mut data = selector("createAccount(address,uint256)").to_vec();
+                data.extend(address_word(&owner));
+                data.extend(uint_word(index));
+                data
+            }
+            SmartAccountFactory::KernelV31 => {
+                let mut data = selector("createAccount(bytes,bytes32)").to_vec();
+                data.extend(uint_word(0x40));
+                data.extend(uint_word(index));
+                data.extend(bytes_tail(&kernel_init_data(&owner)));
+                data
+            }
+        };
+        Ok(FactoryCall { factory: self.factory_address().to_string(), data })
+    }
+
+    /// 배포될 계정 주소 (Kernel)
+    ///
+    /// SimpleAccount는 프록시 생성 코드가 필요하므로 `sender_address_with_proxy_code` 사용
+    pub fn sender_address(self, owner: &str, index: u64) -> Result<String> {
+        let owner = parse_address(owner)?;
+        match self {
+            SmartAccountFactory::KernelV31 => {
+                let init_data = kernel_init_data(&owner);
+                let mut salt_input = init_data;
+                salt_input.extend(uint_word(index));
+                let implementation = parse_address(&self.implementation())?;
+                Ok(self.create2(&keccak256(&salt_input), &erc1967_proxy_init_code(&implementation)))
+            }
+            _ => Err(Error::InvalidInput(format!(
+                "{:?}는 ERC1967Proxy 생성 코드가 필요합니다 (sender_address_with_proxy_code)",
+                self
+            ))),
+        }
+    }
+
+    /// 배포될 계정 주소 (SimpleAccount, `proxy_creation_code`: ERC1967Proxy 생성 코드)
+    ///
+    /// Kernel은 프록시 생성 코드를 받지 않으므로 에러 (`sender_address` 사용)
+    pub fn sender_address_with_proxy_code(self, owner: &str, index: u64, proxy_creation_code: &[u8]) -> Result<String> {
+        let owner = parse_address(owner)?;
+        match self {
+            SmartAccountFactory::SimpleAccountV06 | SmartAccountFactory::SimpleAccountV07 => {
+                Ok(self.create2(&uint_word(index), &self.simple_account_init_code(&owner, proxy_creation_code)))
+            }
+            SmartAccountFactory::KernelV31 => Err(Error::InvalidInput(
+                "KernelV31은 프록시 생성 코드를 쓰지 않습니다 (sender_address 사용)".to_string(),
+            )),
+        }
+    }
+
+    /// SimpleAccount CREATE2 init_code: 생성 코드 ‖ abi.encode(address implementation, bytes initialize(owner))
+    fn simple_account_init_code(self, owner: &[u8; 20], proxy_creation_code: &[u8]) -> Vec<u8> {
+        let implementation = parse_address(&self.implementation()).expect("프리셋 주소");
+        let mut initialize = selector("initialize(address)").to_vec();
+        initialize.extend(address_word(owner));
+
+        let mut init_code = proxy_creation_code.to_vec();
+        init_code.extend(address_word(&implementation));
+        init_code.extend(uint_word(0x40));
+        init_code.extend(bytes_tail(&initialize));
+        init_code
+    }
+
+    fn create2(self, salt: &[u8; 32], init_code: &[u8]) -> String {
+        let factory = parse_address(self.factory_address()).expect("프리셋 주소");
+        to_checksum_address(&create2_address_bytes(&factory, salt, &keccak256(init_code)))
+    }
+}
+
+impl EvmAccount {
+    /// 이 EOA가 소유하는 스마트 계정 주소 (Kernel 프리셋)
+    ///
+    /// SimpleAccount 프리셋은 에러 → `SmartAccountFactory::sender_address_with_proxy_code`에 생성 코드를 넘겨 계산
+    pub fn smart_account_address(&self, factory: SmartAccountFactory, index: u64) -> Result<String> {
+        factory.sender_address(&self.address_checksummed(), index)
+    }
+}
+
+/// Solady `LibClone.initCodeERC1967`: 구현 주소를 ERC-1967 슬롯에 저장하고 위임 호출하는 95바이트 프록시
+pub fn erc1967_proxy_init_code(implementation: &[u8; 20]) -> Vec<u8> {
+    let mut code = hex::decode("603d3d8160223d3973").expect("상수 hex");
+    code.extend_from_slice(implementation);
+    // 6009 51 55 f3: 슬롯(런타임 코드 9번째 바이트부터)에 구현 저장, 런타임 61바이트 반환
+    code.extend(hex::decode("60095155f3").expect("상수 hex"));
+    code.extend(hex::decode("363d3d373d3d363d7f").expect("상수 hex"));
+    code.extend(hex::decode(ERC1967_IMPLEMENTATION_SLOT).expect("상수 hex"));
+    code.extend(hex::decode("545af43d6000803e6038573d6000fd5b3d6000f3").expect("상수 hex"));
+    code
+}
+
+/// Kernel v3.1 `initialize(bytes21 rootValidator, address hook, bytes validatorData, bytes hookData, bytes[] initConfig)`
+///
+/// rootValidator = 0x01(검증자 타입) ‖ ECDSA 검증자, validatorData = 소유자 주소 20바이트
+fn kernel_init_data(owner: &[u8; 20]) -> Vec<u8> {
+    const ECDSA_VALIDATOR: &str = "845adb2c711129d4f3966735ed98a9f09fc4ce57";
+
+    let mut root_validator = [0u8; 32];
+    root_validator[0] = 0x01;
+    root_validator[1..21].copy_from_slice(&hex::decode(ECDSA_VALIDATOR).expect("상수 hex"));
+
+    let mut data = selector("initialize(bytes21,address,bytes,bytes,bytes[])").to_vec();
+    data.extend(root_validator);
+    data.extend([0u8; 32]); // hook 없음
+    data.extend(uint_word(0xa0)); // validatorData 위치 (머리 5워드 뒤)
+    data.extend(uint_word(0xe0)); // hookData 위치 (validatorData 길이 + 내용 1워드 뒤)
+    data.extend(uint_word(0x100)); // initConfig 위치
+    data.extend(bytes_tail(owner));
+    data.extend(uint_word(0)); // hookData = 빈 bytes
+    data.extend(uint_word(0)); // initConfig = 빈 배열
+    data
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ABI 인코딩
+// ═══════════════════════════════════════════════════════════════
+
+/// 함수 선택자 (Keccak-256(시그니처) 앞 4바이트)
+fn selector(signature: &str) -> [u8; 4] {
+    keccak256(signature.as_bytes())[..4].try_into().expect("4바이트")
+}
+
+/// uint256/bytes32 워드 (big-endian, 왼쪽 0 채움)
+fn uint_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn address_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+/// 동적 `bytes` 꼬리: 길이 워드 + 32바이트 단위로 0 채운 내용
+fn bytes_tail(bytes: &[u8]) -> Vec<u8> {
+    let mut tail = uint_word(bytes.len() as u64).to_vec();
+    tail.extend_from_slice(bytes);
+    tail.resize(32 + bytes.len().div_ceil(32) * 32, 0);
+    tail
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::is_valid_eip55;
+
+    const OWNER: &str = "0x9858EfFD232B4033E47d90003D41EC34EcaEda94";
+
+    #[test]
+    fn test_presets() {
+        for preset in SmartAccountFactory::ALL {
+            assert!(is_valid_eip55(preset.factory_address()), "{:?}", preset);
+            assert!(is_valid_eip55(&preset.implementation()), "{:?}", preset);
+        }
+        assert!(is_valid_eip55(ENTRY_POINT_V06) && is_valid_eip55(ENTRY_POINT_V07));
+
+        // SimpleAccountFactory v0.6 배포 시 생성된 구현 (CREATE(팩토리, 1))
+        assert_eq!(SmartAccountFactory::SimpleAccountV06.implementation(), "0x8ABB13360b87Be5EEb1B98647A016adD927a136c");
+    }
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(selector("createAccount(address,uint256)"), [0x5f, 0xbf, 0xb9, 0xcf]);
+        assert_eq!(selector("initialize(address)"), [0xc4, 0xd6, 0x6d, 0xe8]);
+    }
+
+    #[test]
+    fn test_simple_account_factory_call() {
+        let call = SmartAccountFactory::SimpleAccountV07.factory_call(OWNER, 7).unwrap();
+        assert_eq!(
+            call.data_hex(),
+            "0x5fbfb9cf\
+             0000000000000000000000009858effd232b4033e47d90003d41ec34ecaeda94\
+             0000000000000000000000000000000000000000000000000000000000000007"
+        );
+
+        let init_code = call.init_code().unwrap();
+        assert_eq!(&init_code[..20], &parse_address(call.factory.as_str()).unwrap());
+        assert_eq!(&init_code[20..], call.data.as_slice());
+
+        // 프록시 생성 코드가 없으면 계산할 수 없음
+        assert!(SmartAccountFactory::SimpleAccountV07.sender_address(OWNER, 0).is_err());
+
+        // 생성 코드 뒤에 붙는 생성자 인자: abi.encode(구현, initialize(owner))
+        let preset = SmartAccountFactory::SimpleAccountV06;
+        let proxy_code = [0x60, 0x80, 0x60, 0x40];
+        let init_code = preset.simple_account_init_code(&parse_address(OWNER).unwrap(), &proxy_code);
+        assert_eq!(&init_code[..4], &proxy_code);
+        assert_eq!(
+            hex::encode(&init_code[4..]),
+            "0000000000000000000000008abb13360b87be5eeb1b98647a016add927a136c\
+             0000000000000000000000000000000000000000000000000000000000000040\
+             0000000000000000000000000000000000000000000000000000000000000024\
+             c4d66de80000000000000000000000009858effd232b4033e47d90003d41ec34\
+             ecaeda9400000000000000000000000000000000000000000000000000000000"
+        );
+
+        // salt = bytes32(index), 팩토리마다 다른 주소
+        let first = preset.sender_address_with_proxy_code(OWNER, 0, &proxy_code).unwrap();
+        assert_eq!(first, preset.create2(&[0u8; 32], &init_code));
+        assert_ne!(first, preset.sender_address_with_proxy_code(OWNER, 1, &proxy_code).unwrap());
+        assert_ne!(first, SmartAccountFactory::SimpleAccountV07.sender_address_with_proxy_code(OWNER, 0, &proxy_code).unwrap());
+    }
+
+    #[test]
+    fn test_erc1967_proxy_init_code() {
+        let implementation = [0xab; 20];
+        let code = erc1967_proxy_init_code(&implementation);
+        assert_eq!(code.len(), 0x5f);
+
+        // 생성자가 0x22부터 0x3d바이트를 런타임으로 복사 → 런타임 끝은 RETURN (0xf3)
+        let runtime = &code[0x22..];
+        assert_eq!(runtime.len(), 0x3d);
+        assert_eq!(runtime[runtime.len() - 1], 0xf3);
+        // 생성자의 MLOAD(9)가 읽는 위치 = 런타임 PUSH32의 슬롯
+        assert_eq!(hex::encode(&runtime[9..41]), ERC1967_IMPLEMENTATION_SLOT);
+        let mut slot = keccak256(b"eip1967.proxy.implementation");
+        slot[31] -= 1;
+        assert_eq!(hex::encode(slot), ERC1967_IMPLEMENTATION_SLOT);
+        // JUMPI 목적지 0x38은 JUMPDEST
+        assert_eq!(runtime[0x38], 0x5b);
+    }
+
+    #[test]
+    fn test_kernel() {
+        let preset = SmartAccountFactory::KernelV31;
+        let init_data = kernel_init_data(&parse_address(OWNER).unwrap());
+        assert_eq!(init_data.len(), 4 + 32 * 9);
+        assert_eq!(&init_data[4..6], &[0x01, 0x84]);
+
+        // createAccount(bytes initData, bytes32 salt): 머리 2워드 + 길이 + initData(32바이트 단위로 채움)
+        let call = preset.factory_call(OWNER, 3).unwrap();
+        assert_eq!(call.data.len(), 4 + 32 * 3 + init_data.len().div_ceil(32) * 32);
+        assert_eq!(&call.data[4 + 32 * 3..4 + 32 * 3 + init_data.len()], init_data.as_slice());
+        assert_eq!(call.data[4 + 32 * 2 - 1], 3);
+
+        // initialize(bytes21 rootValidator, address hook, bytes validatorData, bytes hookData, bytes[] initConfig)
+        assert_eq!(
+            hex::encode(&init_data),
+            "3c3b752b\
+             01845adb2c711129d4f3966735ed98a9f09fc4ce570000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             00000000000000000000000000000000000000000000000000000000000000a0\
+             00000000000000000000000000000000000000000000000000000000000000e0\
+             0000000000000000000000000000000000000000000000000000000000000100\
+             0000000000000000000000000000000000000000000000000000000000000014\
+             9858effd232b4033e47d90003d41ec34ecaeda94000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        // 독립 구현(범용 ABI 인코더 + Keccak-256 + CREATE2)으로 계산한 값
+        let sender = preset.sender_address(OWNER, 0).unwrap();
+        assert_eq!(sender, "0x2684f492C659968c68e74010232D2fAd5D25EFdc");
+        assert_eq!(preset.sender_address(OWNER, 1).unwrap(), "0xA04bf350Ea0970E2ff8C51c3fb0BBca4b1374E12");
+
+        // Kernel은 프록시 생성 코드를 받지 않음
+        assert!(preset.sender_address_with_proxy_code(OWNER, 0, &[]).is_err());
+
+        // EOA 계정에서 바로 계산
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = EvmAccount::from_mnemonic(mnemonic, "").unwrap();
+        assert_eq!(account.smart_account_address(preset, 0).unwrap(), sender);
+
+        assert!(preset.sender_address("0x1234", 0).is_err());
+    }
+
+    #[test]
+    fn test_bytes_tail() {
+        assert_eq!(bytes_tail(&[]).len(), 32);
+        assert_eq!(bytes_tail(&[1; 20]).len(), 64);
+        assert_eq!(bytes_tail(&[1; 32]).len(), 64);
+        assert_eq!(bytes_tail(&[1; 33]).len(), 96);
+    }
+}
@@ -0,0 +1,485 @@
+//! Ethereum V3 Keystore (Web3 Secret Storage)
+//!
+//! Geth, MetaMask, MyEtherWallet 등이 쓰는 암호화된 개인키 JSON 파일
+//!
+//! ## 형식
+//! ```text
+//! {
+//!   "address": "008aeeda4d805471df9b2a5b0f38a0c3bcba786b",
+//!   "crypto": {
+//!     "cipher": "aes-128-ctr",
+//!     "cipherparams": { "iv": "..." },
+//!     "ciphertext": "...",
+//!     "kdf": "scrypt",
+//!     "kdfparams": { "dklen": 32, "n": 262144, "p": 1, "r": 8, "salt": "..." },
+//!     "mac": "..."
+//!   },
+//!   "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+//!   "version": 3
+//! }
+//! ```
+//!
+//! ## 암호화 과정
+//! 1. 비밀번호 + salt → KDF (scrypt 또는 PBKDF2-HMAC-SHA256) → 32바이트 키
+//! 2. 키[0..16]로 개인키를 AES-128-CTR 암호화
+//! 3. MAC = Keccak-256(키[16..32] || 암호문)
+//!
+//! 복호화할 때는 MAC을 먼저 확인하므로 비밀번호가 틀리면 복호화 전에 실패함
+//!
+//! ## 제한
+//! scrypt는 RFC 7914 조건 N < 2^(16r)을 지켜야 함 (Geth/MetaMask 기본값 r = 8은 문제 없음)
+//! Web3 Secret Storage 문서의 scrypt 예제(N = 2^18, r = 1)처럼 조건을 어기는 파일은 거부
+//!
+//! 파일의 KDF 파라미터는 신뢰할 수 없으므로 상한을 넘으면 도출 전에 거부
+//! (scrypt N ≤ 2^20, r ≤ 8, p ≤ 16 / PBKDF2 c ≤ 10,000,000) → 조작된 파일로 메모리/CPU를 소진시키지 못함
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::secret::ct_eq;
+use crate::trace;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use hmac::Hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use super::{keccak256, EvmAccount};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// 키스토어 KDF 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeystoreKdf {
+    /// scrypt (N = 2^log_n)
+    Scrypt { log_n: u8, r: u32, p: u32 },
+    /// PBKDF2-HMAC-SHA256
+    Pbkdf2 { c: u32 },
+}
+
+impl KeystoreKdf {
+    /// scrypt log2(N) 상한 (N = 2^20 → 약 1 GiB, r = 8 기준)
+    pub const MAX_SCRYPT_LOG_N: u8 = 20;
+    /// scrypt r 상한
+    pub const MAX_SCRYPT_R: u32 = 8;
+    /// scrypt p 상한
+    pub const MAX_SCRYPT_P: u32 = 16;
+    /// PBKDF2 반복 횟수 상한
+    pub const MAX_PBKDF2_C: u32 = 10_000_000;
+
+    /// Geth 기본값 (StandardScryptN = 2^18, p = 1)
+    pub const STANDARD: Self = KeystoreKdf::Scrypt { log_n: 18, r: 8, p: 1 };
+    /// Geth `--lightkdf` (LightScryptN = 2^12, p = 6)
+    pub const LIGHT: Self = KeystoreKdf::Scrypt { log_n: 12, r: 8, p: 6 };
+}
+
+impl Default for KeystoreKdf {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// JSON 구조
+// ═══════════════════════════════════════════════════════════════
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    // MyEtherWallet 구버전은 "Crypto"로 저장
+    #[serde(alias = "Crypto")]
+    crypto: CryptoSection,
+    #[serde(default)]
+    id: String,
+    version: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CryptoSection {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: serde_json::Value,
+    mac: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ScryptParams {
+    dklen: usize,
+    n: u64,
+    p: u32,
+    r: u32,
+    salt: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Pbkdf2Params {
+    c: u32,
+    dklen: usize,
+    prf: String,
+    salt: String,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내보내기 / 가져오기
+// ═══════════════════════════════════════════════════════════════
+
+impl EvmAccount {
+    /// 개인키를 V3 키스토어 JSON으로 암호화
+    pub fn encrypt_keystore(&self, password: &str, kdf: KeystoreKdf) -> Result<String> {
+        let _span = trace::span!("keystore.encrypt", format = "web3-v3");
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        let mut id = [0u8; 16];
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut iv);
+        rng.fill_bytes(&mut id);
+
+        let (kdf_name, kdfparams) = kdf_params_json(&kdf, &salt)?;
+
+        let derived = derive_key(password, &salt, &kdf)?;
+        // 암호화 전까지는 개인키 복사본이므로 Zeroizing
+        let mut ciphertext = Zeroizing::new(*self.private_key);
+        Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(ciphertext.as_mut());
+
+        let file = KeystoreFile {
+            address: Some(hex::encode(self.address)),
+            crypto: CryptoSection {
+                cipher: "aes-128-ctr".to_string(),
+                cipherparams: CipherParams { iv: hex::encode(iv) },
+                ciphertext: hex::encode(ciphertext.as_ref()),
+                kdf: kdf_name.to_string(),
+                kdfparams,
+                mac: hex::encode(keystore_mac(&derived, ciphertext.as_ref())),
+            },
+            id: uuid_v4(id),
+            version: 3,
+        };
+
+        serde_json::to_string(&file).map_err(|e| Error::Keystore(e.to_string()))
+    }
+
+    /// V3 키스토어 JSON을 복호화해 계정 생성
+    pub fn decrypt_keystore(json: &str, password: &str) -> Result<Self> {
+        let _span = trace::span!("keystore.decrypt", format = "web3-v3");
+        let file: KeystoreFile = serde_json::from_str(json).map_err(|e| Error::Keystore(format!("키스토어 JSON 파싱 실패: {}", e)))?;
+        if file.version != 3 {
+            return Err(Error::Keystore(format!("지원하지 않는 키스토어 버전: {}", file.version)));
+        }
+
+        let crypto = &file.crypto;
+        if crypto.cipher != "aes-128-ctr" {
+            return Err(Error::Keystore(format!("지원하지 않는 암호화 방식: {}", crypto.cipher)));
+        }
+
+        let (salt, kdf) = parse_kdf(&crypto.kdf, &crypto.kdfparams)?;
+        let iv: [u8; 16] = decode_hex(&crypto.cipherparams.iv, "iv")?
+            .try_into()
+            .map_err(|_| Error::Keystore("iv는 16바이트여야 합니다".to_string()))?;
+        let mut ciphertext = Zeroizing::new(decode_hex(&crypto.ciphertext, "ciphertext")?);
+        let mac = decode_hex(&crypto.mac, "mac")?;
+
+        let derived = derive_key(password, &salt, &kdf)?;
+        if !ct_eq(&keystore_mac(&derived, &ciphertext), &mac) {
+            return Err(Error::Decryption("MAC 불일치 (비밀번호가 틀렸거나 파일이 손상됨)".to_string()));
+        }
+
+        // 복호화 후에는 평문 개인키
+        Aes128Ctr::new(derived[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+        let private_key: Zeroizing<[u8; 32]> = Zeroizing::new(
+            ciphertext.as_slice().try_into().map_err(|_| Error::InvalidKey("개인키는 32바이트여야 합니다".to_string()))?,
+        );
+        let account = Self::from_private_key(*private_key)?;
+        if let Some(address) = &file.address {
+            let address = address.trim_start_matches("0x").to_lowercase();
+            if address != hex::encode(account.address) {
+                return Err(Error::Decryption("키스토어 주소와 복호화한 키의 주소가 다릅니다".to_string()));
+            }
+        }
+
+        Ok(account)
+    }
+
+    /// 키스토어 파일 저장
+    pub fn save_keystore(&self, path: impl AsRef<Path>, password: &str, kdf: KeystoreKdf) -> Result<()> {
+        let json = self.encrypt_keystore(password, kdf)?;
+        fs::write(path.as_ref(), json).map_err(|e| Error::Io(format!("{}: {}", path.as_ref().display(), e)))
+    }
+
+    /// 키스토어 파일 읽기
+    pub fn load_keystore(path: impl AsRef<Path>, password: &str) -> Result<Self> {
+        let json = fs::read_to_string(path.as_ref()).map_err(|e| Error::Io(format!("{}: {}", path.as_ref().display(), e)))?;
+        Self::decrypt_keystore(&json, password)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// KDF 설정 + salt → (kdf 이름, kdfparams)
+pub(crate) fn kdf_params_json(kdf: &KeystoreKdf, salt: &[u8]) -> Result<(&'static str, serde_json::Value)> {
+    let (name, params) = match *kdf {
+        KeystoreKdf::Scrypt { log_n, r, p } => (
+            "scrypt",
+            serde_json::to_value(ScryptParams {
+                dklen: 32,
+                n: 1u64.checked_shl(log_n as u32).ok_or_else(|| Error::Keystore("scrypt N이 너무 큽니다".to_string()))?,
+                p,
+                r,
+                salt: hex::encode(salt),
+            }),
+        ),
+        KeystoreKdf::Pbkdf2 { c } => (
+            "pbkdf2",
+            serde_json::to_value(Pbkdf2Params {
+                c,
+                dklen: 32,
+                prf: "hmac-sha256".to_string(),
+                salt: hex::encode(salt),
+            }),
+        ),
+    };
+    Ok((name, params.map_err(|e| Error::Keystore(e.to_string()))?))
+}
+
+/// kdf 이름 + kdfparams → (salt, KDF 설정)
+pub(crate) fn parse_kdf(kdf: &str, params: &serde_json::Value) -> Result<(Vec<u8>, KeystoreKdf)> {
+    let invalid = |e: serde_json::Error| Error::Keystore(format!("kdfparams 파싱 실패: {}", e));
+
+    match kdf {
+        "scrypt" => {
+            let params: ScryptParams = serde_json::from_value(params.clone()).map_err(invalid)?;
+            if params.dklen != 32 {
+                return Err(Error::Keystore(format!("지원하지 않는 dklen: {}", params.dklen)));
+            }
+            if !params.n.is_power_of_two() || params.n < 2 {
+                return Err(Error::Keystore(format!("scrypt N은 2의 거듭제곱이어야 합니다: {}", params.n)));
+            }
+            let kdf = KeystoreKdf::Scrypt { log_n: params.n.trailing_zeros() as u8, r: params.r, p: params.p };
+            Ok((decode_hex(&params.salt, "salt")?, kdf))
+        }
+        "pbkdf2" => {
+            let params: Pbkdf2Params = serde_json::from_value(params.clone()).map_err(invalid)?;
+            if params.dklen != 32 {
+                return Err(Error::Keystore(format!("지원하지 않는 dklen: {}", params.dklen)));
+            }
+            if params.prf != "hmac-sha256" {
+                return Err(Error::Keystore(format!("지원하지 않는 PRF: {}", params.prf)));
+            }
+            Ok((decode_hex(&params.salt, "salt")?, KeystoreKdf::Pbkdf2 { c: params.c }))
+        }
+        other => Err(Error::Keystore(format!("지원하지 않는 KDF: {}", other))),
+    }
+}
+
+/// 비밀번호 → 32바이트 키
+pub(crate) fn derive_key(password: &str, salt: &[u8], kdf: &KeystoreKdf) -> Result<Zeroizing<[u8; 32]>> {
+    check_kdf_limits(kdf)?;
+
+    let mut derived = Zeroizing::new([0u8; 32]);
+    match *kdf {
+        KeystoreKdf::Scrypt { log_n, r, p } => {
+            let _span = trace::span!("keystore.kdf", kdf = "scrypt", log_n = log_n, r = r, p = p);
+            let params = scrypt::Params::new(log_n, r, p, 32).map_err(|e| Error::Keystore(format!("유효하지 않은 scrypt 파라미터: {}", e)))?;
+            scrypt::scrypt(password.as_bytes(), salt, &params, derived.as_mut()).map_err(|e| Error::Keystore(e.to_string()))?;
+        }
+        KeystoreKdf::Pbkdf2 { c } => {
+            let _span = trace::span!("keystore.kdf", kdf = "pbkdf2", c = c);
+            pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), salt, c, derived.as_mut()).map_err(|e| Error::Keystore(e.to_string()))?;
+        }
+    }
+    Ok(derived)
+}
+
+/// KDF 파라미터 범위 검사 (파일에서 읽은 값이 메모리/CPU를 과하게 쓰지 않게)
+fn check_kdf_limits(kdf: &KeystoreKdf) -> Result<()> {
+    match *kdf {
+        KeystoreKdf::Scrypt { log_n, r, p } => {
+            if log_n > KeystoreKdf::MAX_SCRYPT_LOG_N {
+                return Err(Error::Keystore(format!("scrypt N이 상한을 넘습니다: 2^{} > 2^{}", log_n, KeystoreKdf::MAX_SCRYPT_LOG_N)));
+            }
+            if r > KeystoreKdf::MAX_SCRYPT_R {
+                return Err(Error::Keystore(format!("scrypt r이 상한을 넘습니다: {} > {}", r, KeystoreKdf::MAX_SCRYPT_R)));
+            }
+            if p > KeystoreKdf::MAX_SCRYPT_P {
+                return Err(Error::Keystore(format!("scrypt p가 상한을 넘습니다: {} > {}", p, KeystoreKdf::MAX_SCRYPT_P)));
+            }
+        }
+        KeystoreKdf::Pbkdf2 { c } => {
+            if c == 0 {
+                return Err(Error::Keystore("PBKDF2 반복 횟수는 0보다 커야 합니다".to_string()));
+            }
+            if c > KeystoreKdf::MAX_PBKDF2_C {
+                return Err(Error::Keystore(format!("PBKDF2 반복 횟수가 상한을 넘습니다: {} > {}", c, KeystoreKdf::MAX_PBKDF2_C)));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// MAC = Keccak-256(키[16..32] || 암호문)
+fn keystore_mac(derived: &[u8; 32], ciphertext: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(16 + ciphertext.len());
+    data.extend_from_slice(&derived[16..]);
+    data.extend_from_slice(ciphertext);
+    keccak256(&data)
+}
+
+pub(crate) fn decode_hex(value: &str, field: &str) -> Result<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|e| Error::Hex(format!("{} hex 디코딩 실패: {}", field, e)))
+}
+
+/// 랜덤 16바이트 → UUID v4 문자열
+pub(crate) fn uuid_v4(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Web3 Secret Storage Definition 테스트 벡터 (비밀번호 "testpassword")
+    const SPEC_PRIVATE_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    const SPEC_PBKDF2: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    #[test]
+    fn test_spec_vector_pbkdf2() {
+        let account = EvmAccount::decrypt_keystore(SPEC_PBKDF2, "testpassword").unwrap();
+        println!("PBKDF2 키스토어 주소: {}", account.address_checksummed());
+        assert_eq!(account.private_key_hex(), SPEC_PRIVATE_KEY);
+
+        assert!(matches!(EvmAccount::decrypt_keystore(SPEC_PBKDF2, "wrongpassword"), Err(Error::Decryption(_))));
+    }
+
+    // go-ethereum accounts/keystore/testdata/very-light-scrypt.json (비밀번호 "", scrypt N = 2, r = 8, p = 1)
+    const GETH_SCRYPT: &str = r#"{
+        "address": "45dea0fb0bba44f4fcf290bba71fd57d7117cbb8",
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "ciphertext": "b87781948a1befd247bff51ef4063f716cf6c2d3481163e9a8f42e1f9bb74145",
+            "cipherparams": { "iv": "dc4926b48a105133d2f16b96833abf1e" },
+            "kdf": "scrypt",
+            "kdfparams": {
+                "dklen": 32,
+                "n": 2,
+                "p": 1,
+                "r": 8,
+                "salt": "004244bbdc51cadda545b1cfa43cff9ed2ae88e08c61f1479dbb45410722f8f0"
+            },
+            "mac": "39990c1684557447940d4c69e06b1b82b2aceacb43f284df65c956daf3046b85"
+        },
+        "id": "ce541d8d-c79b-40f8-9f8c-20f59616faba",
+        "version": 3
+    }"#;
+
+    #[test]
+    fn test_geth_vector_scrypt() {
+        // 파일의 address와 복호화한 키의 주소가 같아야 성공
+        let account = EvmAccount::decrypt_keystore(GETH_SCRYPT, "").unwrap();
+        println!("scrypt 키스토어 주소: {}", account.address_checksummed());
+        assert_eq!(hex::encode(account.address), "45dea0fb0bba44f4fcf290bba71fd57d7117cbb8");
+
+        assert!(matches!(EvmAccount::decrypt_keystore(GETH_SCRYPT, "foo"), Err(Error::Decryption(_))));
+    }
+
+    #[test]
+    fn test_scrypt_rfc7914_bound() {
+        // 문서 예제의 scrypt 파라미터 (N = 2^18, r = 1)는 N < 2^(16r) 위반
+        let params = serde_json::json!({ "dklen": 32, "n": 262144, "p": 8, "r": 1, "salt": "ab0c" });
+        let (_, kdf) = parse_kdf("scrypt", &params).unwrap();
+        assert!(derive_key("testpassword", &[0xab, 0x0c], &kdf).unwrap_err().to_string().contains("scrypt"));
+    }
+
+    #[test]
+    fn test_rejects_oversized_kdf_params() {
+        let oversized = [
+            ("scrypt", serde_json::json!({ "dklen": 32, "n": 1u64 << 21, "p": 1, "r": 8, "salt": "ab0c" })),
+            ("scrypt", serde_json::json!({ "dklen": 32, "n": 1u64 << 40, "p": 1, "r": 8, "salt": "ab0c" })),
+            ("scrypt", serde_json::json!({ "dklen": 32, "n": 1024, "p": 1, "r": 9, "salt": "ab0c" })),
+            ("scrypt", serde_json::json!({ "dklen": 32, "n": 1024, "p": 17, "r": 8, "salt": "ab0c" })),
+            ("pbkdf2", serde_json::json!({ "c": 10_000_001, "dklen": 32, "prf": "hmac-sha256", "salt": "ab0c" })),
+        ];
+        for (name, params) in oversized {
+            let (salt, kdf) = parse_kdf(name, &params).unwrap();
+            let err = derive_key("testpassword", &salt, &kdf).unwrap_err();
+            assert!(matches!(err, Error::Keystore(_)), "{:?}", kdf);
+            assert!(err.to_string().contains("상한"), "{}", err);
+        }
+
+        // 파일 전체로도 복호화 전에 거부 (u32::MAX 반복을 돌리지 않음)
+        let mut value: serde_json::Value = serde_json::from_str(SPEC_PBKDF2).unwrap();
+        value["crypto"]["kdfparams"]["c"] = serde_json::json!(u32::MAX);
+        assert!(matches!(EvmAccount::decrypt_keystore(&value.to_string(), "testpassword"), Err(Error::Keystore(_))));
+
+        // 상한 값 자체는 허용
+        let limit = KeystoreKdf::Scrypt { log_n: KeystoreKdf::MAX_SCRYPT_LOG_N, r: KeystoreKdf::MAX_SCRYPT_R, p: KeystoreKdf::MAX_SCRYPT_P };
+        assert!(check_kdf_limits(&limit).is_ok());
+        assert!(check_kdf_limits(&KeystoreKdf::Pbkdf2 { c: KeystoreKdf::MAX_PBKDF2_C }).is_ok());
+        assert!(check_kdf_limits(&KeystoreKdf::STANDARD).is_ok());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let account = EvmAccount::from_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        )
+        .unwrap();
+
+        for kdf in [KeystoreKdf::Scrypt { log_n: 10, r: 8, p: 1 }, KeystoreKdf::Pbkdf2 { c: 1024 }] {
+            let json = account.encrypt_keystore("비밀번호", kdf).unwrap();
+            println!("키스토어: {}", json);
+
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(value["version"], 3);
+            assert_eq!(value["address"], "9858effd232b4033e47d90003d41ec34ecaeda94");
+            assert_eq!(value["id"].as_str().unwrap().as_bytes()[14], b'4');
+
+            let restored = EvmAccount::decrypt_keystore(&json, "비밀번호").unwrap();
+            assert_eq!(restored.private_key, account.private_key);
+            assert!(EvmAccount::decrypt_keystore(&json, "password").is_err());
+        }
+    }
+
+    #[test]
+    fn test_rejects_mismatched_address() {
+        let account = EvmAccount::from_private_key([1u8; 32]).unwrap();
+        let json = account.encrypt_keystore("pw", KeystoreKdf::Pbkdf2 { c: 1 }).unwrap();
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["address"] = serde_json::Value::String("0000000000000000000000000000000000000000".to_string());
+        assert!(EvmAccount::decrypt_keystore(&value.to_string(), "pw").is_err());
+    }
+}
@@ -11,16 +11,62 @@
 //! 3. Keccak-256 해시 (32바이트)
 //! 4. 마지막 20바이트 = 주소
 //! 5. EIP-55 체크섬 적용
-
-use tiny_keccak::{Hasher, Keccak};
-use crate::bip32::{master_key_from_seed, ExtendedPrivateKey};
-use crate::bip39::{mnemonic_to_seed};
+//!
+//! ## Watch-only 계정
+//! 계정 xpub(m/44'/60'/0')만으로 입금 주소 m/44'/60'/0'/0/i를 도출
+//! (개인키를 보관하지 않는 서버용)
+//!
+//! ## 도출 경로 관례 (`EthDerivationScheme`)
+//! 같은 니모닉이라도 지갑마다 "계정 N"을 늘리는 단계가 달라 다른 주소가 보임
+//!
+//! | 관례 | 경로 | 사용처 |
+//! |------|------|--------|
+//! | `MetaMask` | m/44'/60'/0'/0/{i} | MetaMask, Trust Wallet, 대부분의 소프트웨어 지갑 |
+//! | `LedgerLive` | m/44'/60'/{i}'/0/0 | Ledger Live |
+//! | `LedgerLegacy` | m/44'/60'/0'/{i} | 예전 Ledger Chrome 앱, MyEtherWallet "Ledger (legacy)" |
+//!
+//! 세 관례 모두 i = 0이면 MetaMask와 Ledger Live는 같은 주소 (Legacy만 다름)
+//!
+//! ## 하위 모듈
+//! - `signing`: EIP-191 personal_sign 서명, 서명자 주소 복원
+//! - `siwe`: Sign-In with Ethereum (EIP-4361) 메시지 생성/파싱, 서명, 도메인/nonce/시각 검증
+//! - `keystore`: V3 키스토어 (Web3 Secret Storage) 암호화/복호화
+//! - `transaction`: 미서명 트랜잭션(legacy, EIP-2930, EIP-1559) 서명
+//! - `contract`: 배포 전 컨트랙트 주소 계산 (CREATE, CREATE2)
+//! - `erc4337`: EOA가 소유할 ERC-4337 스마트 계정 주소 (SimpleAccount, Kernel 팩토리 프리셋)
+//! - `stealth`: ERC-5564 stealth 주소 (메타 주소, 일회용 주소/view tag 계산, 받는 쪽 개인키 복원)
+//!
+//! ## 붙여넣은 주소 검사
+//! - `is_valid_eip55`: 정확히 EIP-55 체크섬 형식인지 확인
+//! - `to_eip55`: 한 가지 대소문자(체크섬 없음) 또는 올바른 체크섬 주소 → 체크섬 주소
+//!   (대소문자가 섞였는데 체크섬이 틀리면 오타로 보고 거부)
+
+pub mod contract;
+pub mod erc4337;
+pub mod keystore;
+pub mod signing;
+pub mod siwe;
+pub mod stealth;
+pub mod transaction;
+
+use crate::bip32::{master_key_from_seed, ChildNumber, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, IntoDerivationPath};
+use crate::account_iter::AccountIter;
+use crate::bip39::{mnemonic_to_seed, mnemonic_to_seed_checked};
+use crate::ecdsa::{self, RecoverableSignature};
+use crate::schnorr;
+use crate::secret::SecretBytes;
+use crate::error::{Error, Result};
+
+// Keccak-256은 `crate::hash`로 옮김 (기존 `evm::keccak256` 경로 유지)
+pub use crate::hash::keccak256;
 
 /// EVM 계정 (Ethereum, Polygon, BSC 등)
-#[derive(Debug, Clone)]
+///
+/// `Debug`는 개인키를 가림 (`account` 모듈에서 구현)
+#[derive(Clone)]
 pub struct EvmAccount {
     /// 개인키 (32바이트)
-    pub private_key: [u8; 32],
+    pub private_key: SecretBytes<32>,
     /// 공개키 (65바이트, 비압축)
     pub public_key: [u8; 65],
     /// 주소 (20바이트)
@@ -30,40 +76,130 @@ pub struct EvmAccount {
 /// EVM 기본 도출 경로
 pub const EVM_PATH: &str = "m/44'/60'/0'/0/0";
 
+/// Ethereum 도출 경로 관례
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EthDerivationScheme {
+    /// m/44'/60'/0'/0/{i} (BIP-44 주소 index)
+    MetaMask,
+    /// m/44'/60'/{i}'/0/0 (BIP-44 account)
+    LedgerLive,
+    /// m/44'/60'/0'/{i} (4단계, 예전 Ledger/MEW)
+    LedgerLegacy,
+}
+
+impl EthDerivationScheme {
+    /// 모든 관례
+    pub const ALL: [EthDerivationScheme; 3] =
+        [EthDerivationScheme::MetaMask, EthDerivationScheme::LedgerLive, EthDerivationScheme::LedgerLegacy];
+
+    /// 표시 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            EthDerivationScheme::MetaMask => "MetaMask",
+            EthDerivationScheme::LedgerLive => "Ledger Live",
+            EthDerivationScheme::LedgerLegacy => "Ledger (legacy)",
+        }
+    }
+
+    /// i번째 계정의 경로 (i가 2^31 이상이면 `Error::InvalidPath`)
+    pub fn path(self, index: u32) -> Result<DerivationPath> {
+        match self {
+            EthDerivationScheme::MetaMask => DerivationPath::checked_bip_purpose(44, 60, 0, 0, index),
+            EthDerivationScheme::LedgerLive => DerivationPath::checked_bip_purpose(44, 60, index, 0, 0),
+            EthDerivationScheme::LedgerLegacy => Ok(DerivationPath::from(vec![
+                ChildNumber::Hardened(44),
+                ChildNumber::Hardened(60),
+                ChildNumber::Hardened(0),
+                ChildNumber::normal(index)?,
+            ])),
+        }
+    }
+}
+
 impl EvmAccount {
     /// 개인키에서 EVM 계정 생성
-    pub fn from_private_key(private_key: [u8; 32]) -> Self {
-        let public_key = private_key_to_public_key(&private_key);
+    ///
+    /// 개인키가 0이거나 곡선 위수 n 이상이면 오류
+    pub fn from_private_key(private_key: [u8; 32]) -> Result<Self> {
+        let public_key = ecdsa::public_key(&private_key)?.serialize_uncompressed();
         let address = public_key_to_address(&public_key);
 
-        EvmAccount {
-            private_key,
+        Ok(EvmAccount {
+            private_key: SecretBytes::new(private_key),
             public_key,
             address,
-        }
+        })
     }
 
     /// 확장 개인키에서 EVM 계정 생성
-    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Self {
-        Self::from_private_key(extended_key.private_key)
+    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Result<Self> {
+        Self::from_private_key(*extended_key.private_key)
     }
 
     /// 시드에서 EVM 계정 생성 (기본 경로 사용)
-    pub fn from_seed(seed: &[u8]) -> Result<Self, String> {
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
         Self::from_seed_with_path(seed, EVM_PATH)
     }
 
     /// 시드에서 특정 경로로 EVM 계정 생성
-    pub fn from_seed_with_path(seed: &[u8], path: &str) -> Result<Self, String> {
+    pub fn from_seed_with_path(seed: &[u8], path: impl IntoDerivationPath) -> Result<Self> {
         let master = master_key_from_seed(seed)?;
         let derived = master.derive_path(path)?;
-        Ok(Self::from_extended_key(&derived))
+        Self::from_extended_key(&derived)
+    }
+
+    /// 시드에서 계정을 index 순서로 도출하는 반복자 (m/44'/60'/0'/0/i)
+    pub fn iter_from_seed(seed: &[u8]) -> Result<AccountIter<Self>> {
+        AccountIter::bip32(seed, "m/44'/60'/0'/0", 0, Self::from_private_key)
     }
 
     /// 니모닉에서 EVM 계정 생성
-    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, String> {
+    ///
+    /// BIP-39 체크섬까지 검증 (틀리면 `Error::InvalidMnemonic`)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// BIP-39 검증 없이 EVM 계정 생성 (`EVM_PATH`, MetaMask 첫 계정)
+    pub fn from_mnemonic_unchecked(mnemonic: &str, passphrase: &str) -> Result<Self> {
         let seed = mnemonic_to_seed(mnemonic, passphrase);
-        Self::from_seed(&seed)
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// 니모닉에서 EVM 계정 생성 (m/44'/60'/{account}'/{change}/{index})
+    ///
+    /// MetaMask의 "계정 N"은 index = N. 인덱스가 2^31 이상이면 `Error::InvalidPath`
+    pub fn from_mnemonic_with_index(mnemonic: &str, passphrase: &str, account: u32, change: u32, index: u32) -> Result<Self> {
+        let path = DerivationPath::checked_bip_purpose(44, 60, account, change, index)?;
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
+    }
+
+    /// 니모닉에서 지갑 관례에 따른 i번째 EVM 계정 생성
+    pub fn from_mnemonic_with_scheme(mnemonic: &str, passphrase: &str, scheme: EthDerivationScheme, index: u32) -> Result<Self> {
+        let path = scheme.path(index)?;
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
+    }
+
+    /// 주소가 어느 관례의 몇 번째 계정인지 찾기 (관례마다 index 0..count 검사)
+    ///
+    /// "니모닉을 복구했는데 주소가 다르다"는 경우에 사용. 못 찾으면 `None`
+    pub fn find_scheme(mnemonic: &str, passphrase: &str, address: &str, count: u32) -> Result<Option<(EthDerivationScheme, u32)>> {
+        let target = parse_address(address)?;
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        let master = master_key_from_seed(seed.as_slice())?;
+
+        for scheme in EthDerivationScheme::ALL {
+            for index in 0..count {
+                let account = Self::from_extended_key(&master.derive_path(scheme.path(index)?)?)?;
+                if account.address == target {
+                    return Ok(Some((scheme, index)));
+                }
+            }
+        }
+        Ok(None)
     }
 
     /// 주소를 체크섬이 적용된 문자열로 반환 (EIP-55)
@@ -78,19 +214,91 @@ impl EvmAccount {
 
     /// 개인키를 hex 문자열로 반환
     pub fn private_key_hex(&self) -> String {
-        hex::encode(self.private_key)
+        hex::encode(&self.private_key)
     }
+
+    /// 비압축 공개키(65바이트)를 hex 문자열로 반환
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.public_key)
+    }
+
+    /// 압축 공개키 (33바이트)
+    pub fn public_key_compressed(&self) -> [u8; 33] {
+        let mut compressed = [0u8; 33];
+        compressed[0] = 0x02 | (self.public_key[64] & 1);
+        compressed[1..].copy_from_slice(&self.public_key[1..33]);
+        compressed
+    }
+
+    /// 압축 공개키를 hex 문자열로 반환
+    pub fn public_key_compressed_hex(&self) -> String {
+        hex::encode(self.public_key_compressed())
+    }
+
+    /// 32바이트 다이제스트에 ECDSA 서명 (RFC 6979, low-S), r || s
+    pub fn sign_ecdsa_compact(&self, digest: &[u8; 32]) -> Result<[u8; 64]> {
+        ecdsa::sign_compact(&self.private_key, digest)
+    }
+
+    /// 32바이트 다이제스트에 복원 가능한 ECDSA 서명 (RFC 6979, low-S)
+    pub fn sign_ecdsa_recoverable(&self, digest: &[u8; 32]) -> Result<RecoverableSignature> {
+        ecdsa::sign_recoverable(&self.private_key, digest)
+    }
+
+    /// x-only 공개키 (32바이트, BIP-340)
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        let mut x_only = [0u8; 32];
+        x_only.copy_from_slice(&self.public_key[1..33]);
+        x_only
+    }
+
+    /// 32바이트 메시지에 BIP-340 Schnorr 서명 (aux_rand 없음 → 결정적)
+    pub fn sign_schnorr(&self, message: &[u8; 32]) -> Result<[u8; 64]> {
+        schnorr::sign(&self.private_key, message, None)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Watch-only 계정 (xpub)
+// ═══════════════════════════════════════════════════════════════
+
+/// EVM watch-only 계정 (계정 레벨 xpub m/44'/60'/0', 동작은 [`crate::bip32`] 참고)
+#[derive(Debug, Clone)]
+pub struct EvmWatchAccount {
+    /// 확장 공개키
+    pub xpub: ExtendedPublicKey,
 }
 
-/// 개인키 → 비압축 공개키 (secp256k1)
-fn private_key_to_public_key(private_key: &[u8; 32]) -> [u8; 65] {
-    use secp256k1::{Secp256k1, SecretKey, PublicKey};
+impl EvmWatchAccount {
+    /// xpub 문자열에서 생성 (xpub/tpub 모두 허용)
+    pub fn from_xpub(xpub: &str) -> Result<Self> {
+        Ok(Self::from_extended_key(ExtendedPublicKey::from_base58(xpub)?))
+    }
+
+    /// 확장 공개키에서 생성
+    pub fn from_extended_key(xpub: ExtendedPublicKey) -> Self {
+        EvmWatchAccount { xpub }
+    }
+
+    /// 상대 경로의 비압축 공개키 (예: "m/0/5")
+    pub fn public_key_at(&self, path: impl IntoDerivationPath) -> Result<[u8; 65]> {
+        self.xpub.derive_path(path)?.public_key_uncompressed()
+    }
 
-    let secp = Secp256k1::new();
-    let secret = SecretKey::from_slice(private_key).expect("유효한 개인키");
-    let public = PublicKey::from_secret_key(&secp, &secret);
+    /// 상대 경로의 주소 (20바이트)
+    pub fn address_bytes_at(&self, path: impl IntoDerivationPath) -> Result<[u8; 20]> {
+        Ok(public_key_to_address(&self.public_key_at(path)?))
+    }
 
-    public.serialize_uncompressed()
+    /// 상대 경로의 체크섬 주소 (EIP-55)
+    pub fn address_at(&self, path: impl IntoDerivationPath) -> Result<String> {
+        Ok(to_checksum_address(&self.address_bytes_at(path)?))
+    }
+
+    /// 외부 체인(0/index) 입금 주소
+    pub fn address(&self, index: u32) -> Result<String> {
+        self.address_at(format!("m/0/{}", index))
+    }
 }
 
 /// 비압축 공개키 → EVM 주소
@@ -99,7 +307,7 @@ fn private_key_to_public_key(private_key: &[u8; 32]) -> [u8; 65] {
 /// 1. 공개키 (65바이트)에서 prefix(0x04) 제거 → 64바이트
 /// 2. Keccak-256 해시 → 32바이트
 /// 3. 마지막 20바이트 = 주소
-fn public_key_to_address(public_key: &[u8; 65]) -> [u8; 20] {
+pub(crate) fn public_key_to_address(public_key: &[u8; 65]) -> [u8; 20] {
     // ═══════════════════════════════════════════════════════════════
     // 1단계: prefix 제거 (0x04는 비압축 공개키 표시)
     // ═══════════════════════════════════════════════════════════════
@@ -108,10 +316,7 @@ fn public_key_to_address(public_key: &[u8; 65]) -> [u8; 20] {
     // ═══════════════════════════════════════════════════════════════
     // 2단계: Keccak-256 해시
     // ═══════════════════════════════════════════════════════════════
-    let mut keccak = Keccak::v256();
-    let mut hash = [0u8; 32];
-    keccak.update(public_key_no_prefix);
-    keccak.finalize(&mut hash);
+    let hash = keccak256(public_key_no_prefix);
 
     // ═══════════════════════════════════════════════════════════════
     // 3단계: 마지막 20바이트 추출
@@ -128,14 +333,11 @@ fn public_key_to_address(public_key: &[u8; 65]) -> [u8; 20] {
 /// 1. 주소를 소문자 hex로 변환 (0x 없이)
 /// 2. hex 문자열을 Keccak-256 해시
 /// 3. 해시의 각 니블(4비트)이 8 이상이면 대문자, 아니면 소문자
-fn to_checksum_address(address: &[u8; 20]) -> String {
+pub(crate) fn to_checksum_address(address: &[u8; 20]) -> String {
     let address_hex = hex::encode(address); // 소문자 40자
 
     // 소문자 주소의 Keccak-256 해시
-    let mut keccak = Keccak::v256();
-    let mut hash = [0u8; 32];
-    keccak.update(address_hex.as_bytes());
-    keccak.finalize(&mut hash);
+    let hash = keccak256(address_hex.as_bytes());
 
     // 체크섬 적용
     let mut checksummed = String::with_capacity(42);
@@ -161,18 +363,47 @@ fn to_checksum_address(address: &[u8; 20]) -> String {
     checksummed
 }
 
-/// Keccak-256 해시 유틸리티
-pub fn keccak256(data: &[u8]) -> [u8; 32] {
-    let mut keccak = Keccak::v256();
-    let mut hash = [0u8; 32];
-    keccak.update(data);
-    keccak.finalize(&mut hash);
-    hash
+/// EIP-55 체크섬 주소인지 확인 (0x + 체크섬 대소문자 40자리만 true)
+pub fn is_valid_eip55(address: &str) -> bool {
+    parse_address_hex(address).is_some_and(|bytes| to_checksum_address(&bytes) == address)
+}
+
+/// 주소 → EIP-55 체크섬 주소
+///
+/// 전부 소문자/대문자면 체크섬을 적용하고, 대소문자가 섞였으면 체크섬이 맞아야 함
+pub fn to_eip55(address: &str) -> Result<String> {
+    let bytes = parse_address_hex(address)
+        .ok_or_else(|| Error::InvalidAddress(format!("0x + 40자리 hex 주소가 아닙니다: {}", address)))?;
+    let checksummed = to_checksum_address(&bytes);
+
+    let digits = &address[2..];
+    let mixed_case = digits.bytes().any(|b| b.is_ascii_lowercase()) && digits.bytes().any(|b| b.is_ascii_uppercase());
+    if mixed_case && checksummed != address {
+        return Err(Error::InvalidAddress(format!("EIP-55 체크섬이 맞지 않습니다: {}", address)));
+    }
+    Ok(checksummed)
+}
+
+/// 주소 문자열 → 20바이트 (`to_eip55`와 같은 검사, 대소문자가 섞였으면 체크섬 확인)
+pub(crate) fn parse_address(address: &str) -> Result<[u8; 20]> {
+    let checksummed = to_eip55(address)?;
+    Ok(parse_address_hex(&checksummed).expect("to_eip55가 형식을 검사함"))
+}
+
+/// 0x + 40자리 hex → 20바이트
+fn parse_address_hex(address: &str) -> Option<[u8; 20]> {
+    let digits = address.strip_prefix("0x")?;
+    if digits.len() != 40 {
+        return None;
+    }
+    hex::decode(digits).ok()?.try_into().ok()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bip39::MnemonicError;
+    use crate::error::Error;
 
     #[test]
     fn test_evm_from_mnemonic() {
@@ -194,6 +425,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_mnemonic_checks_checksum() {
+        let typo = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(matches!(
+            EvmAccount::from_mnemonic(typo, ""),
+            Err(Error::InvalidMnemonic(MnemonicError::InvalidChecksum))
+        ));
+        assert!(EvmAccount::from_mnemonic_with_index(typo, "", 0, 0, 0).is_err());
+    }
+
     #[test]
     fn test_checksum_address() {
         // EIP-55 테스트 벡터
@@ -213,6 +454,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_eip55_helpers() {
+        // EIP-55 명세의 예제 주소
+        for address in ["0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"] {
+            assert!(is_valid_eip55(address));
+            assert_eq!(to_eip55(address).unwrap(), address);
+            assert_eq!(to_eip55(&address.to_lowercase()).unwrap(), address);
+            assert_eq!(to_eip55(&format!("0x{}", address[2..].to_uppercase())).unwrap(), address);
+            assert!(!is_valid_eip55(&address.to_lowercase()));
+        }
+
+        // 대소문자 한 글자를 바꾼 주소
+        let typo = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD";
+        assert!(!is_valid_eip55(typo));
+        assert!(matches!(to_eip55(typo), Err(Error::InvalidAddress(_))));
+
+        for invalid in ["5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea", "0xzzaeb6053f3e94c9b9a09f33669435e7ef1beaed"] {
+            assert!(!is_valid_eip55(invalid));
+            assert!(to_eip55(invalid).is_err());
+        }
+    }
+
     #[test]
     fn test_multiple_accounts() {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
@@ -220,14 +483,133 @@ mod tests {
 
         println!("\n=== EVM 계정 목록 (첫 5개) ===\n");
 
-        for i in 0..5 {
-            let path = format!("m/44'/60'/0'/0/{}", i);
-            let account = EvmAccount::from_seed_with_path(&seed, &path).unwrap();
+        // 공통 경로 m/44'/60'/0'/0은 한 번만 도출
+        let keys = master_key_from_seed(seed.as_slice()).unwrap().derive_range("m/44'/60'/0'/0", 0..5).unwrap();
+
+        for (i, key) in keys.iter().enumerate() {
+            let account = EvmAccount::from_extended_key(key).unwrap();
 
-            println!("경로: {}", path);
+            println!("경로: m/44'/60'/0'/0/{}", i);
             println!("주소: {}", account.address_checksummed());
             println!("개인키: 0x{}", account.private_key_hex());
             println!();
         }
     }
+
+    #[test]
+    fn test_invalid_private_key() {
+        // 0과 곡선 위수 n 이상은 패닉 대신 에러
+        assert_eq!(EvmAccount::from_private_key([0u8; 32]).unwrap_err(), Error::InvalidKey("secp256k1 개인키가 0입니다".to_string()));
+        assert_eq!(EvmAccount::from_private_key([0xff; 32]).unwrap_err(), Error::InvalidKey("secp256k1 개인키가 곡선 위수 n 이상입니다".to_string()));
+    }
+
+    #[test]
+    fn test_watch_account() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "");
+
+        // 계정 xpub만 내보내서 watch-only 계정 생성
+        let account_key = master_key_from_seed(seed.as_slice()).unwrap().derive_path("m/44'/60'/0'").unwrap();
+        let xpub = account_key.to_public().to_base58(crate::bitcoin::Network::Mainnet);
+        let watch = EvmWatchAccount::from_xpub(&xpub).unwrap();
+
+        println!("\n=== EVM watch-only ({}) ===\n", xpub);
+
+        for i in 0..3 {
+            let full = EvmAccount::from_seed_with_path(seed.as_slice(), format!("m/44'/60'/0'/0/{}", i)).unwrap();
+            let address = watch.address(i).unwrap();
+            println!("0/{}: {}", i, address);
+
+            assert_eq!(address, full.address_checksummed());
+            assert_eq!(watch.public_key_at(format!("m/0/{}", i)).unwrap(), full.public_key);
+        }
+        assert_eq!(watch.address(0).unwrap().to_lowercase(), "0x9858effd232b4033e47d90003d41ec34ecaeda94");
+
+        // 강화 도출은 불가능
+        assert!(watch.address_at("m/0'/0").is_err());
+        assert!(watch.address(0x80000000).is_err());
+        assert!(EvmWatchAccount::from_xpub("xpub-invalid").is_err());
+    }
+
+    #[test]
+    fn test_sign_ecdsa() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = EvmAccount::from_mnemonic(mnemonic, "").unwrap();
+        let digest = keccak256(b"hello");
+
+        let compact = account.sign_ecdsa_compact(&digest).unwrap();
+        let recoverable = account.sign_ecdsa_recoverable(&digest).unwrap();
+        println!(
+            "서명: 0x{} (recovery id {})",
+            hex::encode(compact),
+            recoverable.recovery_id
+        );
+
+        // 결정적 nonce → 두 형식의 r || s가 같음
+        assert_eq!(recoverable.signature, compact);
+        assert_eq!(account.sign_ecdsa_compact(&digest).unwrap(), compact);
+    }
+
+    #[test]
+    fn test_public_key_formats() {
+        // 생성점 G (개인키 1, y 짝수)
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let account = EvmAccount::from_private_key(one).unwrap();
+        assert_eq!(account.public_key_hex(), "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+        assert_eq!(account.public_key_compressed_hex(), "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+
+        // y 홀수 키는 0x03 접두사
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        for account in EvmAccount::iter_from_seed(mnemonic_to_seed(mnemonic, "").as_slice()).unwrap().take(4) {
+            let (_, account) = account.unwrap();
+            let expected = crate::ecdsa::public_key(&account.private_key).unwrap().serialize();
+            assert_eq!(account.public_key_compressed(), expected);
+        }
+    }
+
+    #[test]
+    fn test_derivation_schemes() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "");
+
+        let paths: Vec<String> = EthDerivationScheme::ALL.iter().map(|scheme| scheme.path(2).unwrap().to_string()).collect();
+        assert_eq!(paths, ["m/44'/60'/0'/0/2", "m/44'/60'/2'/0/0", "m/44'/60'/0'/2"]);
+
+        for scheme in EthDerivationScheme::ALL {
+            let account = EvmAccount::from_mnemonic_with_scheme(mnemonic, "", scheme, 2).unwrap();
+            let expected = EvmAccount::from_seed_with_path(seed.as_slice(), scheme.path(2).unwrap()).unwrap();
+            assert_eq!(account.address, expected.address, "{}", scheme.name());
+        }
+
+        // index 0: MetaMask = Ledger Live, Legacy만 다름
+        let metamask = EvmAccount::from_mnemonic_with_scheme(mnemonic, "", EthDerivationScheme::MetaMask, 0).unwrap();
+        let live = EvmAccount::from_mnemonic_with_scheme(mnemonic, "", EthDerivationScheme::LedgerLive, 0).unwrap();
+        let legacy = EvmAccount::from_mnemonic_with_scheme(mnemonic, "", EthDerivationScheme::LedgerLegacy, 0).unwrap();
+        assert_eq!(metamask.address_checksummed(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(live.address, metamask.address);
+        assert_ne!(legacy.address, metamask.address);
+
+        assert!(matches!(EthDerivationScheme::LedgerLive.path(1 << 31), Err(Error::InvalidPath { .. })));
+    }
+
+    #[test]
+    fn test_find_scheme() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let legacy = EvmAccount::from_mnemonic_with_scheme(mnemonic, "", EthDerivationScheme::LedgerLegacy, 3).unwrap();
+        let live = EvmAccount::from_mnemonic_with_scheme(mnemonic, "", EthDerivationScheme::LedgerLive, 4).unwrap();
+
+        assert_eq!(
+            EvmAccount::find_scheme(mnemonic, "", &legacy.address_lowercase(), 5).unwrap(),
+            Some((EthDerivationScheme::LedgerLegacy, 3))
+        );
+        assert_eq!(
+            EvmAccount::find_scheme(mnemonic, "", &live.address_checksummed(), 5).unwrap(),
+            Some((EthDerivationScheme::LedgerLive, 4))
+        );
+        // 범위 밖, 다른 패스프레이즈
+        assert_eq!(EvmAccount::find_scheme(mnemonic, "", &live.address_checksummed(), 4).unwrap(), None);
+        assert_eq!(EvmAccount::find_scheme(mnemonic, "x", &legacy.address_checksummed(), 5).unwrap(), None);
+        assert!(EvmAccount::find_scheme(mnemonic, "", "0x1234", 5).is_err());
+    }
 }
@@ -0,0 +1,162 @@
+//! EIP-191 Personal Message Signing
+//!
+//! 지갑의 `personal_sign` (MetaMask 로그인, 오프체인 인증 등)
+//!
+//! ## 서명 과정
+//! ```text
+//! 해시 = Keccak-256("\x19Ethereum Signed Message:\n" || 메시지 길이(10진수 문자열) || 메시지)
+//! 서명 = r || s || v   (65바이트, v = 27 + recovery_id)
+//! ```
+//!
+//! 접두사 때문에 서명된 메시지는 유효한 트랜잭션이 될 수 없음 (0x19는 RLP로 시작할 수 없음)
+//!
+//! ## 검증
+//! 서명과 해시에서 공개키를 복원 → 주소 계산 → 기대한 주소와 비교
+//! (일부 하드웨어 지갑은 v를 0/1로 반환하므로 둘 다 허용)
+//!
+//! ## v 값
+//! | v | 의미 |
+//! |---|------|
+//! | 0, 1 | recovery_id 그대로 (typed 트랜잭션 `yParity`, 일부 하드웨어 지갑) |
+//! | 27, 28 | 27 + recovery_id (personal_sign, legacy 트랜잭션) |
+//! | ≥ 35 | EIP-155: chain_id × 2 + 35 + recovery_id |
+
+use super::{keccak256, public_key_to_address, to_checksum_address, EvmAccount};
+use crate::ecdsa::{self, RecoverableSignature};
+use crate::error::{Error, Result};
+
+/// EIP-191 personal_sign 접두사
+const PERSONAL_MESSAGE_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+impl EvmAccount {
+    /// EIP-191 personal_sign 서명
+    ///
+    /// 반환값: r || s || v (65바이트, v ∈ {27, 28})
+    pub fn sign_personal_message(&self, message: &[u8]) -> Result<[u8; 65]> {
+        let signature = self.sign_ecdsa_recoverable(&personal_message_hash(message))?;
+
+        let mut bytes = signature.to_bytes();
+        bytes[64] += 27;
+        Ok(bytes)
+    }
+
+    /// EIP-191 personal_sign 서명 (0x hex 문자열, 지갑 반환 형식)
+    pub fn sign_personal_message_hex(&self, message: &[u8]) -> Result<String> {
+        Ok(format!("0x{}", hex::encode(self.sign_personal_message(message)?)))
+    }
+
+    /// personal_sign 서명이 이 계정의 것인지 검증
+    pub fn verify_personal_message(&self, message: &[u8], signature: &[u8; 65]) -> Result<()> {
+        verify_personal_message(&self.address_checksummed(), message, signature)
+    }
+}
+
+/// EIP-191 메시지 해시 (Keccak-256(접두사 || 길이 || 메시지))
+pub fn personal_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut data = format!("{}{}", PERSONAL_MESSAGE_PREFIX, message.len()).into_bytes();
+    data.extend_from_slice(message);
+    keccak256(&data)
+}
+
+/// personal_sign 서명에서 서명자 주소 복원 (EIP-55 체크섬 주소)
+pub fn recover_personal_message(message: &[u8], signature: &[u8; 65]) -> Result<String> {
+    recover_address(&personal_message_hash(message), signature)
+}
+
+/// ecrecover: 32바이트 다이제스트와 r || s || v 서명에서 서명자 주소 복원 (EIP-55)
+///
+/// v는 0/1, 27/28, EIP-155(≥ 35) 모두 허용
+pub fn recover_address(digest: &[u8; 32], signature: &[u8; 65]) -> Result<String> {
+    let recovery_id = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v @ 35.. => (v - 35) % 2,
+        v => return Err(Error::InvalidSignature(format!("유효하지 않은 v 값: {}", v))),
+    };
+
+    let mut bytes = *signature;
+    bytes[64] = recovery_id;
+    let public_key = ecdsa::recover_public_key_uncompressed(digest, &RecoverableSignature::from_bytes(&bytes)?)?;
+    Ok(to_checksum_address(&public_key_to_address(&public_key)))
+}
+
+/// personal_sign 서명 검증 (주소는 대소문자 무관)
+pub fn verify_personal_message(address: &str, message: &[u8], signature: &[u8; 65]) -> Result<()> {
+    let recovered = recover_personal_message(message, signature)?;
+
+    if !recovered.eq_ignore_ascii_case(address.trim()) {
+        return Err(Error::InvalidSignature(format!("서명자가 다릅니다: {}", recovered)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_personal_sign_vector() {
+        // web3.js 문서 예제: web3.eth.accounts.sign('Some data', '0x4c0883...')
+        let private_key: [u8; 32] = hex::decode("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let account = EvmAccount::from_private_key(private_key).unwrap();
+        assert_eq!(account.address_checksummed(), "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+
+        let signature = account.sign_personal_message_hex(b"Some data").unwrap();
+        assert_eq!(
+            signature,
+            "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd\
+             6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c"
+        );
+    }
+
+    #[test]
+    fn test_recover_and_verify() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = EvmAccount::from_mnemonic(mnemonic, "").unwrap();
+        let message = "로그인 요청: nonce 1234".as_bytes();
+
+        let signature = account.sign_personal_message(message).unwrap();
+        assert!(matches!(signature[64], 27 | 28));
+
+        assert_eq!(recover_personal_message(message, &signature).unwrap(), account.address_checksummed());
+        assert!(verify_personal_message(&account.address_lowercase(), message, &signature).is_ok());
+        assert!(verify_personal_message(&account.address_checksummed(), b"other", &signature).is_err());
+
+        // 계정 메서드
+        assert!(account.verify_personal_message(message, &signature).is_ok());
+        let other = EvmAccount::from_mnemonic(mnemonic, "passphrase").unwrap();
+        assert!(other.verify_personal_message(message, &signature).is_err());
+
+        // v = 0/1 형식도 허용
+        let mut raw_v = signature;
+        raw_v[64] -= 27;
+        assert!(verify_personal_message(&account.address_checksummed(), message, &raw_v).is_ok());
+
+        raw_v[64] = 5;
+        assert!(recover_personal_message(message, &raw_v).is_err());
+    }
+
+    #[test]
+    fn test_recover_address_eip155() {
+        // EIP-155 예제 트랜잭션 (chain_id 1, v = 37), 개인키 0x4646...46
+        let digest: [u8; 32] = hex::decode("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let mut signature = [0u8; 65];
+        signature[..32].copy_from_slice(&hex::decode("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276").unwrap());
+        signature[32..64].copy_from_slice(&hex::decode("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83").unwrap());
+        signature[64] = 37;
+
+        let address = recover_address(&digest, &signature).unwrap();
+        assert_eq!(address, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+        assert_eq!(address, EvmAccount::from_private_key([0x46; 32]).unwrap().address_checksummed());
+
+        // 같은 서명을 yParity 형식으로
+        signature[64] = 0;
+        assert_eq!(recover_address(&digest, &signature).unwrap(), address);
+    }
+}
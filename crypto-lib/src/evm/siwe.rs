@@ -0,0 +1,678 @@
+//! Sign-In with Ethereum (EIP-4361)
+//!
+//! 웹 서비스 로그인용 정형 메시지를 만들고 `personal_sign`(EIP-191)으로 서명/검증
+//!
+//! ## 메시지 형식
+//! ```text
+//! [scheme://]domain wants you to sign in with your Ethereum account:
+//! 0x... (EIP-55 체크섬 주소)
+//!
+//! [statement]
+//!
+//! URI: https://example.com/login
+//! Version: 1
+//! Chain ID: 1
+//! Nonce: 8자 이상 영숫자
+//! Issued At: RFC 3339 시각
+//! [Expiration Time: ...]
+//! [Not Before: ...]
+//! [Request ID: ...]
+//! [Resources:
+//! - uri ...]
+//! ```
+//!
+//! ## 검증
+//! 서명은 받은 메시지 문자열 그대로에 대해 확인 (다시 직렬화하지 않음)
+//! 1. 서명자 주소 = 메시지의 `address`
+//! 2. `domain`이 서버가 기대한 도메인과 같은지 (피싱 사이트가 받은 서명 재사용 방지)
+//! 3. `nonce`가 서버가 발급한 값과 같은지 (재전송 방지)
+//! 4. 현재 시각이 `Not Before` 이후, `Expiration Time` 이전인지
+//!
+//! ```
+//! use crypto_lib::evm::EvmAccount;
+//! use crypto_lib::evm::siwe::{SiweMessage, SiweVerification};
+//!
+//! let account = EvmAccount::from_private_key([0x46; 32]).unwrap();
+//! let message = SiweMessage::new("example.com", account.address_checksummed(), "https://example.com/login", 1)
+//!     .statement("Sign in to Example")
+//!     .nonce("k3Jx9Pq2mW")
+//!     .issued_at(1_700_000_000);
+//! let signature = account.sign_siwe(&message).unwrap();
+//!
+//! let text = message.to_string();
+//! let verified = SiweVerification::new("example.com")
+//!     .nonce("k3Jx9Pq2mW")
+//!     .at(1_700_000_060)
+//!     .verify(&text, &signature)
+//!     .unwrap();
+//! assert_eq!(verified.address, account.address_checksummed());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::distributions::{Alphanumeric, DistString};
+
+use super::signing::recover_personal_message;
+use super::{is_valid_eip55, EvmAccount};
+use crate::error::{Error, Result};
+
+/// 첫 줄 꼬리 문구
+const HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+
+/// 지원하는 메시지 버전
+const VERSION: &str = "1";
+
+/// `generate_nonce()` 길이
+const NONCE_LENGTH: usize = 17;
+
+/// SIWE 메시지
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiweMessage {
+    /// URI 스킴 (생략 가능, 예: "https")
+    pub scheme: Option<String>,
+    /// 서명을 요청한 도메인 (RFC 3986 authority, 예: "example.com:8080")
+    pub domain: String,
+    /// 서명자 주소 (EIP-55)
+    pub address: String,
+    /// 사용자에게 보여줄 안내 문구 (한 줄)
+    pub statement: Option<String>,
+    /// 로그인 대상 리소스 URI
+    pub uri: String,
+    /// 메시지 버전 (항상 "1")
+    pub version: String,
+    /// EIP-155 체인 id
+    pub chain_id: u64,
+    /// 재전송 방지 값 (8자 이상 영숫자)
+    pub nonce: String,
+    /// 발급 시각 (RFC 3339)
+    pub issued_at: String,
+    /// 만료 시각 (RFC 3339)
+    pub expiration_time: Option<String>,
+    /// 유효 시작 시각 (RFC 3339)
+    pub not_before: Option<String>,
+    /// 서버 요청 식별자
+    pub request_id: Option<String>,
+    /// 함께 승인하는 리소스 URI 목록
+    pub resources: Vec<String>,
+}
+
+impl SiweMessage {
+    /// 필수 필드로 메시지 생성 (nonce는 새로 생성, 발급 시각은 현재)
+    pub fn new(domain: impl Into<String>, address: impl Into<String>, uri: impl Into<String>, chain_id: u64) -> Self {
+        SiweMessage {
+            scheme: None,
+            domain: domain.into(),
+            address: address.into(),
+            statement: None,
+            uri: uri.into(),
+            version: VERSION.to_string(),
+            chain_id,
+            nonce: generate_nonce(),
+            issued_at: format_timestamp(unix_now()),
+            expiration_time: None,
+            not_before: None,
+            request_id: None,
+            resources: Vec::new(),
+        }
+    }
+
+    /// URI 스킴 (예: "https")
+    pub fn scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = Some(scheme.into());
+        self
+    }
+
+    /// 안내 문구
+    pub fn statement(mut self, statement: impl Into<String>) -> Self {
+        self.statement = Some(statement.into());
+        self
+    }
+
+    /// 서버가 발급한 nonce
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = nonce.into();
+        self
+    }
+
+    /// 발급 시각 (Unix 초)
+    pub fn issued_at(mut self, unix_seconds: i64) -> Self {
+        self.issued_at = format_timestamp(unix_seconds);
+        self
+    }
+
+    /// 만료 시각 (Unix 초)
+    pub fn expiration_time(mut self, unix_seconds: i64) -> Self {
+        self.expiration_time = Some(format_timestamp(unix_seconds));
+        self
+    }
+
+    /// 유효 시작 시각 (Unix 초)
+    pub fn not_before(mut self, unix_seconds: i64) -> Self {
+        self.not_before = Some(format_timestamp(unix_seconds));
+        self
+    }
+
+    /// 요청 식별자
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// 리소스 URI 추가
+    pub fn resource(mut self, resource: impl Into<String>) -> Self {
+        self.resources.push(resource.into());
+        self
+    }
+
+    /// 필드 형식 검사 (주소 체크섬, nonce, 시각, 줄바꿈)
+    pub fn validate(&self) -> Result<()> {
+        if self.domain.is_empty() || self.domain.chars().any(char::is_whitespace) {
+            return Err(invalid(format!("유효하지 않은 도메인입니다: {:?}", self.domain)));
+        }
+        if !is_valid_eip55(&self.address) {
+            return Err(Error::InvalidAddress(format!("EIP-55 체크섬 주소가 아닙니다: {}", self.address)));
+        }
+        if self.statement.as_deref().is_some_and(|statement| statement.contains('\n')) {
+            return Err(invalid("statement에 줄바꿈을 넣을 수 없습니다"));
+        }
+        if self.uri.is_empty() || self.uri.contains('\n') {
+            return Err(invalid(format!("유효하지 않은 URI입니다: {:?}", self.uri)));
+        }
+        if self.version != VERSION {
+            return Err(invalid(format!("지원하지 않는 SIWE 버전입니다: {}", self.version)));
+        }
+        if self.nonce.len() < 8 || !self.nonce.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid(format!("nonce는 8자 이상 영숫자여야 합니다: {}", self.nonce)));
+        }
+        parse_timestamp(&self.issued_at)?;
+        for time in [&self.expiration_time, &self.not_before].into_iter().flatten() {
+            parse_timestamp(time)?;
+        }
+        let fields = [self.scheme.as_deref(), self.request_id.as_deref()];
+        if fields.into_iter().flatten().chain(self.resources.iter().map(String::as_str)).any(|field| field.contains('\n')) {
+            return Err(invalid("필드에 줄바꿈을 넣을 수 없습니다"));
+        }
+        Ok(())
+    }
+
+    /// 정규 메시지 문자열 (형식 검사 후)
+    pub fn to_message(&self) -> Result<String> {
+        self.validate()?;
+        Ok(self.to_string())
+    }
+
+    /// 메시지 문자열 파싱 (`\r\n` 줄바꿈 허용)
+    pub fn parse(message: &str) -> Result<Self> {
+        let message = message.replace("\r\n", "\n");
+        let mut lines = message.split('\n').peekable();
+
+        let header = lines.next().unwrap_or_default();
+        let origin = header
+            .strip_suffix(HEADER_SUFFIX)
+            .ok_or_else(|| invalid("SIWE 메시지의 첫 줄이 아닙니다"))?;
+        let (scheme, domain) = match origin.split_once("://") {
+            Some((scheme, domain)) => (Some(scheme.to_string()), domain),
+            None => (None, origin),
+        };
+
+        let address = lines.next().ok_or_else(|| invalid("주소 줄이 없습니다"))?.to_string();
+        expect_line(lines.next(), "")?;
+
+        // 빈 줄 하나 + (statement + 빈 줄) 또는 빈 줄 하나
+        let statement = match lines.next() {
+            Some("") => None,
+            Some(statement) => {
+                expect_line(lines.next(), "")?;
+                Some(statement.to_string())
+            }
+            None => return Err(invalid("메시지가 너무 짧습니다")),
+        };
+
+        let uri = required_field(lines.next(), "URI")?;
+        let version = required_field(lines.next(), "Version")?;
+        let chain_id = required_field(lines.next(), "Chain ID")?;
+        let chain_id = chain_id.parse().map_err(|_| invalid(format!("유효하지 않은 체인 id: {}", chain_id)))?;
+        let nonce = required_field(lines.next(), "Nonce")?;
+        let issued_at = required_field(lines.next(), "Issued At")?;
+
+        let expiration_time = optional_field(&mut lines, "Expiration Time");
+        let not_before = optional_field(&mut lines, "Not Before");
+        let request_id = optional_field(&mut lines, "Request ID");
+
+        let mut resources = Vec::new();
+        if lines.next_if_eq(&"Resources:").is_some() {
+            while let Some(resource) = lines.next_if(|line| line.starts_with("- ")) {
+                resources.push(resource[2..].to_string());
+            }
+        }
+
+        // 끝의 줄바꿈 하나는 허용
+        lines.next_if_eq(&"");
+        if let Some(extra) = lines.next() {
+            return Err(invalid(format!("알 수 없는 줄입니다: {}", extra)));
+        }
+
+        let parsed = SiweMessage {
+            scheme,
+            domain: domain.to_string(),
+            address,
+            statement,
+            uri,
+            version,
+            chain_id,
+            nonce,
+            issued_at,
+            expiration_time,
+            not_before,
+            request_id,
+            resources,
+        };
+        parsed.validate()?;
+        Ok(parsed)
+    }
+
+    /// 이 메시지(정규 문자열)에 대한 서명 검증
+    pub fn verify(&self, signature: &[u8; 65], verification: &SiweVerification) -> Result<()> {
+        verification.check(self, &self.to_message()?, signature)
+    }
+}
+
+impl fmt::Display for SiweMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(scheme) = &self.scheme {
+            write!(f, "{}://", scheme)?;
+        }
+        writeln!(f, "{}{}", self.domain, HEADER_SUFFIX)?;
+        writeln!(f, "{}", self.address)?;
+        writeln!(f)?;
+        if let Some(statement) = &self.statement {
+            writeln!(f, "{}", statement)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "URI: {}", self.uri)?;
+        writeln!(f, "Version: {}", self.version)?;
+        writeln!(f, "Chain ID: {}", self.chain_id)?;
+        writeln!(f, "Nonce: {}", self.nonce)?;
+        write!(f, "Issued At: {}", self.issued_at)?;
+        if let Some(time) = &self.expiration_time {
+            write!(f, "\nExpiration Time: {}", time)?;
+        }
+        if let Some(time) = &self.not_before {
+            write!(f, "\nNot Before: {}", time)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, "\nRequest ID: {}", request_id)?;
+        }
+        if !self.resources.is_empty() {
+            write!(f, "\nResources:")?;
+            for resource in &self.resources {
+                write!(f, "\n- {}", resource)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SiweMessage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        SiweMessage::parse(s)
+    }
+}
+
+/// 서버 쪽 검증 조건
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiweVerification {
+    /// 기대하는 도메인 (대소문자 무관)
+    pub domain: String,
+    /// 서버가 발급한 nonce (None이면 검사하지 않음)
+    pub nonce: Option<String>,
+    /// 검사 기준 시각 (Unix 초, None이면 현재 시각)
+    pub time: Option<i64>,
+}
+
+impl SiweVerification {
+    /// 도메인만 검사하는 조건
+    pub fn new(domain: impl Into<String>) -> Self {
+        SiweVerification { domain: domain.into(), nonce: None, time: None }
+    }
+
+    /// 발급한 nonce 검사
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    /// 기준 시각 (Unix 초)
+    pub fn at(mut self, unix_seconds: i64) -> Self {
+        self.time = Some(unix_seconds);
+        self
+    }
+
+    /// 받은 메시지 문자열과 서명 검증, 성공하면 파싱된 메시지 반환
+    pub fn verify(&self, message: &str, signature: &[u8; 65]) -> Result<SiweMessage> {
+        let parsed = SiweMessage::parse(message)?;
+        self.check(&parsed, message, signature)?;
+        Ok(parsed)
+    }
+
+    fn check(&self, message: &SiweMessage, text: &str, signature: &[u8; 65]) -> Result<()> {
+        if !message.domain.eq_ignore_ascii_case(&self.domain) {
+            return Err(rejected(format!("도메인이 다릅니다: {} (기대값 {})", message.domain, self.domain)));
+        }
+        if let Some(nonce) = &self.nonce {
+            if &message.nonce != nonce {
+                return Err(rejected(format!("nonce가 다릅니다: {}", message.nonce)));
+            }
+        }
+
+        let now = self.time.unwrap_or_else(unix_now);
+        if let Some(expiration_time) = &message.expiration_time {
+            if now >= parse_timestamp(expiration_time)? {
+                return Err(rejected(format!("만료된 메시지입니다: {}", expiration_time)));
+            }
+        }
+        if let Some(not_before) = &message.not_before {
+            if now < parse_timestamp(not_before)? {
+                return Err(rejected(format!("아직 유효하지 않은 메시지입니다: {}", not_before)));
+            }
+        }
+
+        let recovered = recover_personal_message(text.as_bytes(), signature)?;
+        if recovered != message.address {
+            return Err(Error::InvalidSignature(format!("서명자가 다릅니다: {}", recovered)));
+        }
+        Ok(())
+    }
+}
+
+impl EvmAccount {
+    /// SIWE 메시지 서명 (personal_sign, r || s || v)
+    ///
+    /// 메시지의 주소가 이 계정의 주소가 아니면 에러
+    pub fn sign_siwe(&self, message: &SiweMessage) -> Result<[u8; 65]> {
+        let text = message.to_message()?;
+        if message.address != self.address_checksummed() {
+            return Err(Error::AddressMismatch { expected: message.address.clone(), derived: self.address_checksummed() });
+        }
+        self.sign_personal_message(text.as_bytes())
+    }
+}
+
+/// 새 nonce (17자 영숫자)
+pub fn generate_nonce() -> String {
+    Alphanumeric.sample_string(&mut rand::thread_rng(), NONCE_LENGTH)
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::InvalidInput(message.into())
+}
+
+fn rejected(message: impl Into<String>) -> Error {
+    Error::InvalidSignature(format!("SIWE 검증 실패: {}", message.into()))
+}
+
+fn expect_line(line: Option<&str>, expected: &str) -> Result<()> {
+    match line {
+        Some(line) if line == expected => Ok(()),
+        other => Err(invalid(format!("SIWE 메시지 형식이 아닙니다: {:?}", other.unwrap_or_default()))),
+    }
+}
+
+fn required_field(line: Option<&str>, tag: &str) -> Result<String> {
+    line.and_then(|line| line.strip_prefix(tag))
+        .and_then(|rest| rest.strip_prefix(": "))
+        .map(str::to_string)
+        .ok_or_else(|| invalid(format!("{} 필드가 없습니다", tag)))
+}
+
+fn optional_field<'a>(lines: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>, tag: &str) -> Option<String> {
+    let prefix = format!("{}: ", tag);
+    lines.next_if(|line| line.starts_with(&prefix)).map(|line| line[prefix.len()..].to_string())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RFC 3339 시각
+// ═══════════════════════════════════════════════════════════════
+
+fn unix_now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+/// Unix 초 → "YYYY-MM-DDTHH:MM:SSZ"
+fn format_timestamp(unix_seconds: i64) -> String {
+    let days = unix_seconds.div_euclid(86_400);
+    let seconds = unix_seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// RFC 3339 → Unix 초 (소수 초는 버림, 오프셋 `Z`/`±HH:MM`)
+fn parse_timestamp(timestamp: &str) -> Result<i64> {
+    let error = || invalid(format!("RFC 3339 시각이 아닙니다: {}", timestamp));
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't') || bytes[13] != b':' || bytes[16] != b':' {
+        return Err(error());
+    }
+    let number = |range: std::ops::Range<usize>| -> Result<i64> {
+        let digits = timestamp.get(range).ok_or_else(error)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(error());
+        }
+        digits.parse().map_err(|_| error())
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 60 {
+        return Err(error());
+    }
+
+    let mut rest = &timestamp[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(error());
+        }
+        rest = &fraction[digits..];
+    }
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return Err(error()),
+            };
+            let (hours, minutes) = (number(timestamp.len() - 5..timestamp.len() - 3)?, number(timestamp.len() - 2..timestamp.len())?);
+            if hours > 23 || minutes > 59 {
+                return Err(error());
+            }
+            sign * (hours * 3600 + minutes * 60)
+        }
+        _ => return Err(error()),
+    };
+
+    Ok(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 그레고리력 날짜 → 1970-01-01 기준 일 수 (H. Hinnant, `days_from_civil`)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// 1970-01-01 기준 일 수 → (년, 월, 일)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// EIP-4361 본문의 예제 메시지
+    const EIP_EXAMPLE: &str = "service.invalid wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+I accept the ServiceOrg Terms of Service: https://service.invalid/tos
+
+URI: https://service.invalid/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json";
+
+    fn account() -> EvmAccount {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        EvmAccount::from_mnemonic(mnemonic, "").unwrap()
+    }
+
+    #[test]
+    fn test_parse_eip_example() {
+        let message = SiweMessage::parse(EIP_EXAMPLE).unwrap();
+        assert_eq!(message.scheme, None);
+        assert_eq!(message.domain, "service.invalid");
+        assert_eq!(message.address, "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        assert_eq!(message.statement.as_deref(), Some("I accept the ServiceOrg Terms of Service: https://service.invalid/tos"));
+        assert_eq!(message.chain_id, 1);
+        assert_eq!(message.nonce, "32891756");
+        assert_eq!(message.resources.len(), 2);
+
+        // 다시 직렬화하면 같은 문자열
+        assert_eq!(message.to_message().unwrap(), EIP_EXAMPLE);
+        assert_eq!(EIP_EXAMPLE.replace('\n', "\r\n").parse::<SiweMessage>().unwrap(), message);
+    }
+
+    #[test]
+    fn test_round_trip_optional_fields() {
+        let address = account().address_checksummed();
+        let message = SiweMessage::new("localhost:3000", address.clone(), "http://localhost:3000", 137)
+            .scheme("http")
+            .nonce("abcdef1234")
+            .issued_at(1_700_000_000)
+            .expiration_time(1_700_000_600)
+            .not_before(1_699_999_990)
+            .request_id("req-42");
+
+        let text = message.to_message().unwrap();
+        assert!(text.starts_with("http://localhost:3000 wants you to sign in"));
+        // statement가 없으면 주소 뒤에 빈 줄 두 개
+        assert!(text.contains(&format!("{}\n\n\nURI: ", address)));
+        assert!(text.contains("Issued At: 2023-11-14T22:13:20Z\nExpiration Time: 2023-11-14T22:23:20Z"));
+        assert_eq!(SiweMessage::parse(&text).unwrap(), message);
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let account = account();
+        let message = SiweMessage::new("example.com", account.address_checksummed(), "https://example.com", 1)
+            .statement("로그인")
+            .issued_at(1_700_000_000)
+            .expiration_time(1_700_000_300);
+        assert_eq!(message.nonce.len(), NONCE_LENGTH);
+
+        let signature = account.sign_siwe(&message).unwrap();
+        let text = message.to_string();
+        let verification = SiweVerification::new("example.com").nonce(message.nonce.clone()).at(1_700_000_100);
+
+        assert_eq!(verification.verify(&text, &signature).unwrap(), message);
+        assert!(message.verify(&signature, &verification).is_ok());
+
+        // 도메인 바인딩
+        let err = SiweVerification::new("evil.example").at(1_700_000_100).verify(&text, &signature).unwrap_err();
+        assert!(err.to_string().contains("도메인"));
+
+        // nonce 재사용
+        assert!(SiweVerification::new("example.com").nonce("otherNonce1").at(1_700_000_100).verify(&text, &signature).is_err());
+
+        // 만료
+        assert!(SiweVerification::new("example.com").at(1_700_000_300).verify(&text, &signature).is_err());
+
+        // 본문 변조
+        let tampered = text.replace("Chain ID: 1", "Chain ID: 10");
+        assert!(verification.verify(&tampered, &signature).is_err());
+
+        // 다른 계정의 주소로 서명 요청
+        let other = SiweMessage { address: "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(), ..message };
+        assert!(matches!(account.sign_siwe(&other), Err(Error::AddressMismatch { .. })));
+    }
+
+    #[test]
+    fn test_not_before() {
+        let account = account();
+        let message = SiweMessage::new("example.com", account.address_checksummed(), "https://example.com", 1)
+            .issued_at(1_700_000_000)
+            .not_before(1_700_001_000);
+        let signature = account.sign_siwe(&message).unwrap();
+
+        let verification = SiweVerification::new("example.com");
+        assert!(message.verify(&signature, &verification.clone().at(1_700_000_999)).is_err());
+        assert!(message.verify(&signature, &verification.at(1_700_001_000)).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_messages() {
+        let address = account().address_checksummed();
+        let base = SiweMessage::new("example.com", address.clone(), "https://example.com", 1);
+
+        // 체크섬 없는 주소, 짧은 nonce, 여러 줄 statement
+        assert!(SiweMessage { address: address.to_lowercase(), ..base.clone() }.to_message().is_err());
+        assert!(base.clone().nonce("short").to_message().is_err());
+        assert!(base.clone().statement("a\nb").to_message().is_err());
+
+        assert!(SiweMessage::parse("hello").is_err());
+        assert!(SiweMessage::parse(&EIP_EXAMPLE.replace("Version: 1", "Version: 2")).is_err());
+        assert!(SiweMessage::parse(&EIP_EXAMPLE.replace("2021-09-30T16:25:24Z", "2021-02-30T16:25:24Z")).is_err());
+        assert!(SiweMessage::parse(&format!("{}\nUnknown: x", EIP_EXAMPLE)).is_err());
+    }
+
+    #[test]
+    fn test_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(parse_timestamp("2021-09-30T16:25:24Z").unwrap(), 1_633_019_124);
+        assert_eq!(parse_timestamp("2021-09-30T16:25:24.123Z").unwrap(), 1_633_019_124);
+        assert_eq!(parse_timestamp("2021-10-01T01:25:24+09:00").unwrap(), 1_633_019_124);
+        assert_eq!(parse_timestamp("2021-09-30T11:25:24-05:00").unwrap(), 1_633_019_124);
+
+        for bad in ["2021-09-30", "2021-09-30T16:25:24", "2021-13-01T00:00:00Z", "2021-09-30T16:25:24+0900"] {
+            assert!(parse_timestamp(bad).is_err(), "{}", bad);
+        }
+    }
+}
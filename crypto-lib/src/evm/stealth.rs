@@ -0,0 +1,356 @@
+//! ERC-5564 Stealth Addresses (scheme 1, secp256k1)
+//!
+//! 받는 사람이 메타 주소 하나만 공개하면, 보내는 사람이 매번 새로운 일회용 주소를 만들어 송금
+//! (온체인에서는 받는 사람과 일회용 주소의 연결이 보이지 않음)
+//!
+//! ## 메타 주소
+//! ```text
+//! st:eth:0x<spending 공개키 33바이트><viewing 공개키 33바이트>
+//! ```
+//!
+//! ## 보내는 사람
+//! ```text
+//! p       = 무작위 임시 개인키,  P = p·G (임시 공개키, Announcement로 공개)
+//! s       = p · P_view                       (ECDH 공유 비밀, 압축 33바이트)
+//! s_h     = Keccak-256(s)
+//! view tag = s_h[0]
+//! P_stealth = P_spend + s_h·G  →  주소
+//! ```
+//!
+//! ## 받는 사람
+//! ```text
+//! s = p_view · P  (보내는 사람과 같은 값)
+//! view tag 비교 → 다르면 건너뜀 (Announcement의 255/256을 점 연산 1번으로 걸러냄)
+//! p_stealth = p_spend + s_h (mod n)
+//! ```
+//!
+//! viewing 개인키만 있으면 입금을 찾을 수 있고 (`check_stealth_address`),
+//! 자금을 움직이려면 spending 개인키가 필요 (`StealthKeys::stealth_account`)
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand::RngCore;
+use secp256k1::{PublicKey, Scalar};
+
+use super::{keccak256, parse_address, public_key_to_address, to_checksum_address, EvmAccount};
+use crate::ecdsa;
+use crate::error::{Error, Result};
+use crate::schnorr::{add_tweak_private_key, add_tweak_public_key};
+use crate::secret::SecretBytes;
+
+/// ERC-5564 스킴 id (secp256k1 + view tag)
+pub const SCHEME_ID: u32 = 1;
+
+/// 메타 주소 접두사 (ERC-5564 `st:<chain>:`)
+const META_ADDRESS_PREFIX: &str = "st:eth:0x";
+
+/// 받는 사람이 공개하는 stealth 메타 주소
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StealthMetaAddress {
+    /// spending 공개키 (압축)
+    pub spending_public_key: [u8; 33],
+    /// viewing 공개키 (압축)
+    pub viewing_public_key: [u8; 33],
+}
+
+/// 보내는 사람이 계산한 일회용 주소
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StealthAddress {
+    /// 송금할 주소 (EIP-55)
+    pub address: String,
+    /// 임시 공개키 (Announcement `ephemeralPubKey`)
+    pub ephemeral_public_key: [u8; 33],
+    /// view tag (Announcement `metadata`의 첫 바이트)
+    pub view_tag: u8,
+}
+
+/// 받는 사람의 spending/viewing 개인키 쌍
+#[derive(Clone)]
+pub struct StealthKeys {
+    spending_key: SecretBytes<32>,
+    viewing_key: SecretBytes<32>,
+}
+
+impl StealthMetaAddress {
+    /// 두 공개키로 생성 (곡선 위의 점인지 검사)
+    pub fn new(spending_public_key: [u8; 33], viewing_public_key: [u8; 33]) -> Result<Self> {
+        parse_public_key(&spending_public_key)?;
+        parse_public_key(&viewing_public_key)?;
+        Ok(StealthMetaAddress { spending_public_key, viewing_public_key })
+    }
+
+    /// "st:eth:0x..." 파싱
+    ///
+    /// 공개키가 하나(33바이트)뿐이면 spending과 viewing에 같은 키를 사용 (ERC-5564 허용)
+    pub fn parse(s: &str) -> Result<Self> {
+        let digits = s
+            .strip_prefix(META_ADDRESS_PREFIX)
+            .ok_or_else(|| Error::InvalidAddress(format!("stealth 메타 주소는 {}로 시작해야 합니다: {}", META_ADDRESS_PREFIX, s)))?;
+        let bytes = hex::decode(digits).map_err(|e| Error::InvalidAddress(format!("유효하지 않은 메타 주소 hex: {}", e)))?;
+        let (spending, viewing) = match bytes.len() {
+            33 => (&bytes[..], &bytes[..]),
+            66 => bytes.split_at(33),
+            n => return Err(Error::InvalidAddress(format!("메타 주소는 33 또는 66바이트여야 합니다 (현재 {}바이트)", n))),
+        };
+        StealthMetaAddress::new(spending.try_into().expect("33바이트"), viewing.try_into().expect("33바이트"))
+    }
+
+    /// 무작위 임시 키로 일회용 주소 생성
+    pub fn generate_stealth_address(&self) -> Result<StealthAddress> {
+        let mut ephemeral_key = SecretBytes::new([0u8; 32]);
+        loop {
+            rand::thread_rng().fill_bytes(&mut *ephemeral_key);
+            if ecdsa::secret_key(&ephemeral_key).is_ok() {
+                return self.stealth_address_with_ephemeral_key(&ephemeral_key);
+            }
+        }
+    }
+
+    /// 주어진 임시 개인키로 일회용 주소 생성 (테스트 벡터, 재현용)
+    pub fn stealth_address_with_ephemeral_key(&self, ephemeral_key: &[u8; 32]) -> Result<StealthAddress> {
+        let ephemeral_public_key = ecdsa::public_key(ephemeral_key)?.serialize();
+        let hashed_secret = hashed_shared_secret(ephemeral_key, &self.viewing_public_key)?;
+        let stealth_public_key = add_tweak_public_key(&self.spending_public_key, &hashed_secret)?;
+
+        Ok(StealthAddress {
+            address: to_checksum_address(&address_of(&stealth_public_key)?),
+            ephemeral_public_key,
+            view_tag: hashed_secret[0],
+        })
+    }
+}
+
+impl fmt::Display for StealthMetaAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", META_ADDRESS_PREFIX, hex::encode(self.spending_public_key), hex::encode(self.viewing_public_key))
+    }
+}
+
+impl FromStr for StealthMetaAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        StealthMetaAddress::parse(s)
+    }
+}
+
+impl StealthKeys {
+    /// spending/viewing 개인키로 생성
+    pub fn new(spending_key: [u8; 32], viewing_key: [u8; 32]) -> Result<Self> {
+        ecdsa::secret_key(&spending_key)?;
+        ecdsa::secret_key(&viewing_key)?;
+        Ok(StealthKeys { spending_key: SecretBytes::new(spending_key), viewing_key: SecretBytes::new(viewing_key) })
+    }
+
+    /// 무작위 키 쌍 생성
+    pub fn generate() -> Self {
+        let mut rng = rand::thread_rng();
+        let mut random_key = || loop {
+            let mut key = SecretBytes::new([0u8; 32]);
+            rng.fill_bytes(&mut *key);
+            if ecdsa::secret_key(&key).is_ok() {
+                return key;
+            }
+        };
+        StealthKeys { spending_key: random_key(), viewing_key: random_key() }
+    }
+
+    /// spending 개인키
+    pub fn spending_key(&self) -> &SecretBytes<32> {
+        &self.spending_key
+    }
+
+    /// viewing 개인키 (입금 탐색만 맡길 서버에 전달 가능)
+    pub fn viewing_key(&self) -> &SecretBytes<32> {
+        &self.viewing_key
+    }
+
+    /// 공개할 메타 주소
+    pub fn meta_address(&self) -> StealthMetaAddress {
+        StealthMetaAddress {
+            spending_public_key: ecdsa::public_key(&self.spending_key).expect("생성 시 검증됨").serialize(),
+            viewing_public_key: ecdsa::public_key(&self.viewing_key).expect("생성 시 검증됨").serialize(),
+        }
+    }
+
+    /// Announcement가 이 키 쌍을 향한 것인지 확인
+    pub fn check(&self, stealth_address: &str, ephemeral_public_key: &[u8; 33], view_tag: Option<u8>) -> Result<bool> {
+        check_stealth_address(
+            stealth_address,
+            ephemeral_public_key,
+            &self.viewing_key,
+            &self.meta_address().spending_public_key,
+            view_tag,
+        )
+    }
+
+    /// 일회용 주소의 개인키 복원 (p_spend + s_h)
+    ///
+    /// 복원한 주소가 `stealth_address`와 다르면 `AddressMismatch`
+    pub fn stealth_account(&self, stealth_address: &str, ephemeral_public_key: &[u8; 33]) -> Result<EvmAccount> {
+        let expected = parse_address(stealth_address)?;
+        let hashed_secret = SecretBytes::new(hashed_shared_secret(&self.viewing_key, ephemeral_public_key)?);
+        let account = EvmAccount::from_private_key(add_tweak_private_key(&self.spending_key, &hashed_secret)?)?;
+        if account.address != expected {
+            return Err(Error::AddressMismatch {
+                expected: to_checksum_address(&expected),
+                derived: account.address_checksummed(),
+            });
+        }
+        Ok(account)
+    }
+}
+
+impl fmt::Debug for StealthKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StealthKeys").field("meta_address", &self.meta_address().to_string()).finish_non_exhaustive()
+    }
+}
+
+/// viewing 개인키와 spending 공개키만으로 Announcement 확인 (ERC-5564 `checkStealthAddress`)
+///
+/// `view_tag`를 주면 먼저 비교해서 다르면 점 덧셈 없이 `false`
+pub fn check_stealth_address(
+    stealth_address: &str,
+    ephemeral_public_key: &[u8; 33],
+    viewing_key: &[u8; 32],
+    spending_public_key: &[u8; 33],
+    view_tag: Option<u8>,
+) -> Result<bool> {
+    let expected = parse_address(stealth_address)?;
+    let hashed_secret = hashed_shared_secret(viewing_key, ephemeral_public_key)?;
+    if view_tag.is_some_and(|tag| tag != hashed_secret[0]) {
+        return Ok(false);
+    }
+    Ok(address_of(&add_tweak_public_key(spending_public_key, &hashed_secret)?)? == expected)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// Keccak-256(private_key · public_key) (압축 점)
+fn hashed_shared_secret(private_key: &[u8; 32], public_key: &[u8; 33]) -> Result<[u8; 32]> {
+    let scalar = Scalar::from(ecdsa::secret_key(private_key)?);
+    let shared = parse_public_key(public_key)?
+        .mul_tweak(ecdsa::context(), &scalar)
+        .map_err(|_| Error::InvalidKey("공유 비밀 계산 실패".to_string()))?;
+    Ok(keccak256(&shared.serialize()))
+}
+
+fn parse_public_key(public_key: &[u8; 33]) -> Result<PublicKey> {
+    PublicKey::from_slice(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))
+}
+
+fn address_of(public_key: &[u8; 33]) -> Result<[u8; 20]> {
+    Ok(public_key_to_address(&parse_public_key(public_key)?.serialize_uncompressed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys() -> StealthKeys {
+        StealthKeys::new([0x11; 32], [0x22; 32]).unwrap()
+    }
+
+    #[test]
+    fn test_meta_address_format() {
+        let meta = keys().meta_address();
+        let encoded = meta.to_string();
+        assert!(encoded.starts_with("st:eth:0x"));
+        assert_eq!(encoded.len(), "st:eth:0x".len() + 132);
+        assert_eq!(encoded.parse::<StealthMetaAddress>().unwrap(), meta);
+
+        // 공개키 하나짜리 메타 주소는 spending = viewing
+        let single = format!("st:eth:0x{}", hex::encode(meta.spending_public_key));
+        let parsed = StealthMetaAddress::parse(&single).unwrap();
+        assert_eq!(parsed.viewing_public_key, meta.spending_public_key);
+
+        assert!(StealthMetaAddress::parse(&encoded.replace("st:eth:", "st:bnb:")).is_err());
+        assert!(StealthMetaAddress::parse(&encoded[..encoded.len() - 2]).is_err());
+        // 곡선 위에 없는 점
+        assert!(StealthMetaAddress::parse(&format!("st:eth:0x02{}", "ff".repeat(32))).is_err());
+    }
+
+    #[test]
+    fn test_send_and_recover() {
+        let keys = keys();
+        let meta = keys.meta_address();
+        let stealth = meta.stealth_address_with_ephemeral_key(&[0x33; 32]).unwrap();
+
+        // 같은 임시 키면 같은 결과, 다른 임시 키면 다른 주소
+        assert_eq!(meta.stealth_address_with_ephemeral_key(&[0x33; 32]).unwrap(), stealth);
+        assert_ne!(meta.generate_stealth_address().unwrap().address, stealth.address);
+
+        assert!(keys.check(&stealth.address, &stealth.ephemeral_public_key, Some(stealth.view_tag)).unwrap());
+        assert!(keys.check(&stealth.address, &stealth.ephemeral_public_key, None).unwrap());
+        assert!(!keys.check(&stealth.address, &stealth.ephemeral_public_key, Some(stealth.view_tag.wrapping_add(1))).unwrap());
+
+        let account = keys.stealth_account(&stealth.address, &stealth.ephemeral_public_key).unwrap();
+        assert_eq!(account.address_checksummed(), stealth.address);
+
+        // 복원한 개인키로 서명한 메시지의 서명자가 일회용 주소
+        let signature = account.sign_personal_message(b"stealth").unwrap();
+        assert_eq!(crate::evm::signing::recover_personal_message(b"stealth", &signature).unwrap(), stealth.address);
+    }
+
+    #[test]
+    fn test_cross_implementation_vector() {
+        // 이 크레이트와 코드를 공유하지 않는 레퍼런스 구현(순수 Python secp256k1 + Keccak-256)으로 계산
+        // 공유 점은 stealth-address-sdk의 getSharedSecret처럼 압축 33바이트로 해시
+        let keys = keys();
+        let meta = keys.meta_address();
+        assert_eq!(
+            meta.to_string(),
+            "st:eth:0x034f355bdcb7cc0af728ef3cceb9615d90684bb5b2ca5f859ab0f0b704075871aa02466d7fcae563e5cb09a0d1870bb580344804617879a14949cf22285f1bae3f27"
+        );
+
+        let stealth = meta.stealth_address_with_ephemeral_key(&[0x33; 32]).unwrap();
+        assert_eq!(hex::encode(stealth.ephemeral_public_key), "023c72addb4fdf09af94f0c94d7fe92a386a7e70cf8a1d85916386bb2535c7b1b1");
+        assert_eq!(
+            hex::encode(hashed_shared_secret(&[0x33; 32], &meta.viewing_public_key).unwrap()),
+            "20f63cde5fe857844fbfda0a7599784a624dc4741b8bd076edfcbc857d7908c2"
+        );
+        assert_eq!(stealth.view_tag, 0x20);
+        assert_eq!(stealth.address, "0xD8606eD2ecDB71fdcb8cCA8fA1925ff84238f2a9");
+
+        let account = keys.stealth_account(&stealth.address, &stealth.ephemeral_public_key).unwrap();
+        assert_eq!(hex::encode(account.private_key.as_slice()), "32074def70f9689560d0eb1b86aa895b735ed5852c9ce187ff0dcd968e8a19d3");
+
+        // 비압축 65바이트로 해시하는 구현이면 view tag 0xda, 주소 0x953A…5c58 로 달라짐
+        assert_ne!(stealth.address, "0x953A4bb5068aEa3bE45a124814224A5b95955c58");
+    }
+
+    #[test]
+    fn test_other_receiver() {
+        let stealth = keys().meta_address().generate_stealth_address().unwrap();
+        let other = StealthKeys::generate();
+
+        assert!(!other.check(&stealth.address, &stealth.ephemeral_public_key, None).unwrap());
+        assert!(matches!(
+            other.stealth_account(&stealth.address, &stealth.ephemeral_public_key),
+            Err(Error::AddressMismatch { .. })
+        ));
+        assert!(format!("{:?}", other).contains("st:eth:0x"));
+        assert!(!format!("{:?}", other).contains(&hex::encode(other.spending_key().as_slice())));
+    }
+
+    #[test]
+    fn test_viewing_key_only() {
+        // 탐색 서버는 viewing 개인키 + spending 공개키만 보관
+        let keys = keys();
+        let meta = keys.meta_address();
+        let stealth = meta.generate_stealth_address().unwrap();
+        assert!(check_stealth_address(
+            &stealth.address,
+            &stealth.ephemeral_public_key,
+            keys.viewing_key(),
+            &meta.spending_public_key,
+            Some(stealth.view_tag),
+        )
+        .unwrap());
+        assert!(check_stealth_address("0x1234", &stealth.ephemeral_public_key, keys.viewing_key(), &meta.spending_public_key, None).is_err());
+    }
+}
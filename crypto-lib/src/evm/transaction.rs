@@ -0,0 +1,288 @@
+//! Ethereum Raw Transaction Signing
+//!
+//! 지갑/노드가 만든 미서명 트랜잭션에 서명해 `eth_sendRawTransaction`에 보낼 바이트를 만듦
+//!
+//! ## 트랜잭션 형식
+//!
+//! | 형식 | 미서명 바이트 | 서명 후 |
+//! |------|--------------|---------|
+//! | legacy | RLP([nonce, gasPrice, gas, to, value, data]) | + [v, r, s], v = 27 + recovery_id |
+//! | legacy EIP-155 | RLP([..., chainId, 0, 0]) | 뒤 3개를 [v, r, s]로 교체, v = chainId × 2 + 35 + recovery_id |
+//! | EIP-2930 (0x01), EIP-1559 (0x02) | type ‖ RLP([chainId, ...]) | type ‖ RLP([..., yParity, r, s]) |
+//!
+//! 서명 해시 = Keccak-256(미서명 바이트), 트랜잭션 해시 = Keccak-256(서명된 바이트)
+//!
+//! ## RLP
+//! - 0x00~0x7f: 바이트 하나 그대로
+//! - 0x80~0xb7: 짧은 문자열 (0x80 + 길이), 0xb8~0xbf: 긴 문자열 (길이의 길이)
+//! - 0xc0~0xf7: 짧은 리스트, 0xf8~0xff: 긴 리스트
+//! - 정수는 앞자리 0을 뺀 big-endian (0은 빈 문자열 0x80)
+
+use super::{keccak256, EvmAccount};
+use crate::error::{Error, Result};
+
+/// EIP-2930 access list 트랜잭션
+pub const TYPE_ACCESS_LIST: u8 = 0x01;
+/// EIP-1559 동적 수수료 트랜잭션
+pub const TYPE_DYNAMIC_FEE: u8 = 0x02;
+
+impl EvmAccount {
+    /// 미서명 트랜잭션 서명 → 브로드캐스트할 서명된 트랜잭션 바이트
+    pub fn sign_transaction(&self, unsigned: &[u8]) -> Result<Vec<u8>> {
+        let (tx_type, fields) = decode_unsigned(unsigned)?;
+        let signature = self.sign_ecdsa_recoverable(&signing_hash(unsigned))?;
+        let r = encode_uint(&signature.r());
+        let s = encode_uint(&signature.s());
+
+        let mut items: Vec<Vec<u8>> = fields.iter().map(|field| field.to_vec()).collect();
+        match tx_type {
+            Some(tx_type) => {
+                items.extend([encode_uint(&[signature.recovery_id]), r, s]);
+                let mut signed = vec![tx_type];
+                signed.extend(encode_list(&items));
+                Ok(signed)
+            }
+            None => {
+                let v = match fields.len() {
+                    6 => 27 + signature.recovery_id as u64,
+                    _ => {
+                        let chain_id = decode_uint(fields[6])?;
+                        items.truncate(6);
+                        chain_id
+                            .checked_mul(2)
+                            .and_then(|v| v.checked_add(35 + signature.recovery_id as u64))
+                            .ok_or_else(|| Error::Transaction(format!("chain id가 너무 큽니다: {}", chain_id)))?
+                    }
+                };
+                items.extend([encode_uint(&v.to_be_bytes()), r, s]);
+                Ok(encode_list(&items))
+            }
+        }
+    }
+}
+
+/// 서명 해시 (Keccak-256(미서명 바이트))
+pub fn signing_hash(unsigned: &[u8]) -> [u8; 32] {
+    keccak256(unsigned)
+}
+
+/// 트랜잭션 해시 (Keccak-256(서명된 바이트), 탐색기의 tx hash)
+pub fn transaction_hash(signed: &[u8]) -> [u8; 32] {
+    keccak256(signed)
+}
+
+/// 미서명 트랜잭션 → (타입, 필드 RLP 조각)
+///
+/// legacy는 타입 없음, 필드 수로 EIP-155 여부 구분 (6개 / 9개)
+fn decode_unsigned(unsigned: &[u8]) -> Result<(Option<u8>, Vec<&[u8]>)> {
+    let first = *unsigned.first().ok_or_else(|| Error::Transaction("빈 트랜잭션".to_string()))?;
+    let (tx_type, body, expected) = match first {
+        TYPE_ACCESS_LIST => (Some(first), &unsigned[1..], 8),
+        TYPE_DYNAMIC_FEE => (Some(first), &unsigned[1..], 9),
+        0xc0..=0xff => (None, unsigned, 0),
+        _ => return Err(Error::Transaction(format!("지원하지 않는 트랜잭션 타입: 0x{:02x}", first))),
+    };
+
+    let fields = decode_list(body)?;
+    let valid = match tx_type {
+        Some(_) => fields.len() == expected,
+        None => fields.len() == 6 || (fields.len() == 9 && decode_uint(fields[7])? == 0 && decode_uint(fields[8])? == 0),
+    };
+    if !valid {
+        return Err(Error::Transaction(format!("미서명 트랜잭션이 아닙니다 (필드 {}개)", fields.len())));
+    }
+    Ok((tx_type, fields))
+}
+
+/// RLP 항목 하나의 (헤더 길이, 내용 길이, 리스트 여부)
+fn decode_header(data: &[u8]) -> Result<(usize, usize, bool)> {
+    let truncated = || Error::Transaction("RLP 데이터가 잘렸습니다".to_string());
+    let prefix = *data.first().ok_or_else(truncated)?;
+
+    let (header, length, is_list) = match prefix {
+        0x00..=0x7f => (0, 1, false),
+        0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+        0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+        _ => {
+            let is_list = prefix >= 0xf8;
+            let length_of_length = (prefix - if is_list { 0xf7 } else { 0xb7 }) as usize;
+            let bytes = data.get(1..1 + length_of_length).ok_or_else(truncated)?;
+            if bytes[0] == 0 || length_of_length > 8 {
+                return Err(Error::Transaction("RLP 길이가 정규 형식이 아닙니다".to_string()));
+            }
+            let length = bytes.iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+            (1 + length_of_length, length, is_list)
+        }
+    };
+
+    if data.len() - header < length {
+        return Err(truncated());
+    }
+    Ok((header, length, is_list))
+}
+
+/// 최상위 리스트 → 각 항목의 RLP 조각 (헤더 포함, 뒤에 남는 바이트 불가)
+fn decode_list(data: &[u8]) -> Result<Vec<&[u8]>> {
+    let (header, length, is_list) = decode_header(data)?;
+    if !is_list || header + length != data.len() {
+        return Err(Error::Transaction("RLP 리스트가 아닙니다".to_string()));
+    }
+
+    let mut items = Vec::new();
+    let mut rest = &data[header..];
+    while !rest.is_empty() {
+        let (item_header, item_length, _) = decode_header(rest)?;
+        let (item, tail) = rest.split_at(item_header + item_length);
+        items.push(item);
+        rest = tail;
+    }
+    Ok(items)
+}
+
+/// RLP 정수 (최대 8바이트)
+fn decode_uint(item: &[u8]) -> Result<u64> {
+    let (header, length, is_list) = decode_header(item)?;
+    let bytes = &item[header..header + length];
+    if is_list || bytes.len() > 8 {
+        return Err(Error::Transaction("RLP 정수가 아닙니다".to_string()));
+    }
+    Ok(bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64))
+}
+
+/// big-endian 정수 → RLP 문자열 (앞자리 0 제거)
+pub(super) fn encode_uint(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    encode_bytes(&bytes[start..])
+}
+
+pub(super) fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+    let mut encoded = encode_length(bytes.len(), 0x80);
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+/// 이미 인코딩된 항목들 → RLP 리스트
+pub(super) fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut encoded = encode_length(payload.len(), 0xc0);
+    encoded.extend(payload);
+    encoded
+}
+
+fn encode_length(length: usize, offset: u8) -> Vec<u8> {
+    if length < 56 {
+        return vec![offset + length as u8];
+    }
+    let bytes = length.to_be_bytes();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+    let mut encoded = vec![offset + 55 + (bytes.len() - start) as u8];
+    encoded.extend_from_slice(&bytes[start..]);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::signing::recover_address;
+
+    /// EIP-155 예제 (개인키 0x4646...46, nonce 9, 20 gwei, 1 ETH → 0x3535...35, chain id 1)
+    const EIP155_UNSIGNED: &str = "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080";
+    const EIP155_SIGNED: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    #[test]
+    fn test_eip155_vector() {
+        let account = EvmAccount::from_private_key([0x46; 32]).unwrap();
+        let unsigned = hex::decode(EIP155_UNSIGNED).unwrap();
+        assert_eq!(
+            hex::encode(signing_hash(&unsigned)),
+            "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+        );
+
+        let signed = account.sign_transaction(&unsigned).unwrap();
+        assert_eq!(hex::encode(&signed), EIP155_SIGNED);
+        assert_eq!(
+            hex::encode(transaction_hash(&signed)),
+            "33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788"
+        );
+    }
+
+    #[test]
+    fn test_eip1559_signature_recovers_signer() {
+        let account = EvmAccount::from_private_key([0x46; 32]).unwrap();
+
+        // [chainId 1, nonce 0, tip 1 gwei, max fee 30 gwei, gas 21000, to, value 1 wei, data, accessList]
+        let fields = vec![
+            encode_uint(&[1]),
+            encode_uint(&[0]),
+            encode_uint(&1_000_000_000u64.to_be_bytes()),
+            encode_uint(&30_000_000_000u64.to_be_bytes()),
+            encode_uint(&21_000u64.to_be_bytes()),
+            encode_bytes(&[0x35; 20]),
+            encode_uint(&[1]),
+            encode_bytes(&[]),
+            encode_list(&[]),
+        ];
+        let mut unsigned = vec![TYPE_DYNAMIC_FEE];
+        unsigned.extend(encode_list(&fields));
+
+        let signed = account.sign_transaction(&unsigned).unwrap();
+        assert_eq!(signed[0], TYPE_DYNAMIC_FEE);
+
+        let items = decode_list(&signed[1..]).unwrap();
+        assert_eq!(items.len(), 12);
+        assert_eq!(items[..9], fields.iter().map(Vec::as_slice).collect::<Vec<_>>()[..]);
+
+        let y_parity = decode_uint(items[9]).unwrap() as u8;
+        let mut signature = [0u8; 65];
+        for (item, range) in [(items[10], 0..32), (items[11], 32..64)] {
+            let (header, length, _) = decode_header(item).unwrap();
+            signature[range.end - length..range.end].copy_from_slice(&item[header..]);
+            assert!(range.len() >= length);
+        }
+        signature[64] = y_parity;
+        assert_eq!(recover_address(&signing_hash(&unsigned), &signature).unwrap(), account.address_checksummed());
+    }
+
+    #[test]
+    fn test_legacy_without_chain_id() {
+        let account = EvmAccount::from_private_key([0x46; 32]).unwrap();
+        let unsigned = encode_list(&[
+            encode_uint(&[9]),
+            encode_uint(&20_000_000_000u64.to_be_bytes()),
+            encode_uint(&21_000u64.to_be_bytes()),
+            encode_bytes(&[0x35; 20]),
+            encode_uint(&[]),
+            encode_bytes(&[]),
+        ]);
+
+        let signed = account.sign_transaction(&unsigned).unwrap();
+        let items = decode_list(&signed).unwrap();
+        assert_eq!(items.len(), 9);
+        assert!(matches!(decode_uint(items[6]).unwrap(), 27 | 28));
+    }
+
+    #[test]
+    fn test_rejects_invalid_transactions() {
+        let account = EvmAccount::from_private_key([0x46; 32]).unwrap();
+        assert!(account.sign_transaction(&[]).is_err());
+        assert!(account.sign_transaction(&[0x03, 0xc0]).is_err());
+
+        // 이미 서명된 트랜잭션 (v가 0이 아님)
+        assert!(account.sign_transaction(&hex::decode(EIP155_SIGNED).unwrap()).is_err());
+
+        // 잘린 RLP
+        let unsigned = hex::decode(EIP155_UNSIGNED).unwrap();
+        assert!(account.sign_transaction(&unsigned[..unsigned.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_rlp_long_length() {
+        let long = vec![0xaa; 60];
+        let encoded = encode_bytes(&long);
+        assert_eq!(&encoded[..2], &[0xb8, 60]);
+        assert_eq!(decode_header(&encoded).unwrap(), (2, 60, false));
+    }
+}
@@ -0,0 +1,377 @@
+//! Address Export
+//!
+//! 니모닉 하나에서 여러 체인 × account 범위 × index 범위의 주소를 도출해
+//! JSON 또는 CSV manifest로 내보냄 (대사, 감사용)
+//!
+//! ## 내용
+//! - 체인 id, account, index, 도출 경로, 주소, 공개키(hex)
+//! - Solana, Sui는 네트워크 클러스터 힌트(`cluster`) 포함 (주소는 네트워크와 무관하므로 받는 쪽이 RPC를 고르는 용도)
+//! - 네트워크는 `ExportSpec::network()`로 지정 (기본값: 메인넷, `network` 모듈 참고)
+//! - 개인키는 기본적으로 넣지 않음 (`ExportSpec::with_private_keys()`로 명시적으로 요청할 때만)
+//!
+//! ## 순서
+//! 체인(지정 순서) → account → index 오름차순
+//!
+//! ```
+//! use crypto_lib::export::{export, ExportFormat, ExportSpec};
+//! use crypto_lib::wallet::Chain;
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let spec = ExportSpec::new(&[Chain::Bitcoin, Chain::Evm]).indices(0..3);
+//!
+//! let mut csv = Vec::new();
+//! export(mnemonic, "", &spec, ExportFormat::Csv, &mut csv).unwrap();
+//! let csv = String::from_utf8(csv).unwrap();
+//! assert_eq!(csv.lines().count(), 1 + 2 * 3); // 헤더 + 체인 2개 × index 3개
+//! assert!(!csv.contains("private_key"));
+//! ```
+
+use std::io::Write;
+use std::ops::Range;
+
+use serde::{Serialize, Serializer};
+use zeroize::Zeroizing;
+
+use crate::bip32::HARDENED_OFFSET;
+use crate::bip39::{mnemonic_to_seed, validate_mnemonic};
+use crate::chain::SeedSource;
+use crate::network::Network;
+use crate::secret::SecretBytes;
+use crate::slip10::KeyCache;
+use crate::wallet::{Chain, DEFAULT_CHAINS};
+use crate::error::{Error, Result};
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// JSON 배열 (들여쓰기)
+    Json,
+    /// CSV (첫 줄 헤더)
+    Csv,
+}
+
+/// 내보낼 범위
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportSpec {
+    /// 체인 (이 순서로 출력)
+    pub chains: Vec<Chain>,
+    /// BIP-44 account 범위
+    pub accounts: Range<u32>,
+    /// 주소 index 범위
+    pub indices: Range<u32>,
+    /// 네트워크 (기본값: 메인넷)
+    pub network: Network,
+    /// 개인키(hex) 포함 여부 (기본값: false)
+    pub include_private_keys: bool,
+}
+
+impl Default for ExportSpec {
+    /// 기본 체인 전체, account 0, index 0
+    fn default() -> Self {
+        Self::new(&DEFAULT_CHAINS)
+    }
+}
+
+impl ExportSpec {
+    /// 지정한 체인의 account 0, index 0
+    pub fn new(chains: &[Chain]) -> Self {
+        ExportSpec {
+            chains: chains.to_vec(),
+            accounts: 0..1,
+            indices: 0..1,
+            network: Network::Mainnet,
+            include_private_keys: false,
+        }
+    }
+
+    /// account 범위 지정
+    pub fn accounts(mut self, accounts: Range<u32>) -> Self {
+        self.accounts = accounts;
+        self
+    }
+
+    /// index 범위 지정
+    pub fn indices(mut self, indices: Range<u32>) -> Self {
+        self.indices = indices;
+        self
+    }
+
+    /// 네트워크 지정
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// 개인키까지 내보냄 (manifest를 안전하게 보관할 수 있을 때만)
+    pub fn with_private_keys(mut self) -> Self {
+        self.include_private_keys = true;
+        self
+    }
+
+    /// 범위 검증 (비어 있지 않은 일반 인덱스 범위, 등록된 체인)
+    fn validate(&self) -> Result<()> {
+        if self.chains.is_empty() {
+            return Err(Error::InvalidInput("체인을 하나 이상 지정해야 합니다".to_string()));
+        }
+        for (name, range) in [("account", &self.accounts), ("index", &self.indices)] {
+            if range.is_empty() {
+                return Err(Error::InvalidInput(format!("{} 범위가 비어 있습니다: {:?}", name, range)));
+            }
+            if range.end > HARDENED_OFFSET {
+                return Err(Error::InvalidInput(format!("{} 범위는 2^31 미만이어야 합니다: {:?}", name, range)));
+            }
+        }
+        for chain in &self.chains {
+            chain.spec()?;
+        }
+        Ok(())
+    }
+}
+
+/// manifest 한 줄
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportRecord {
+    /// 체인 id (`chain` 레지스트리 키)
+    pub chain: &'static str,
+    /// BIP-44 account
+    pub account: u32,
+    /// 주소 index
+    pub index: u32,
+    /// 도출 경로 (Substrate는 Secret URI 경로)
+    pub path: String,
+    /// 주소
+    pub address: String,
+    /// 공개키 hex (체인 고유 형식)
+    pub public_key: String,
+    /// 클러스터/네트워크 이름 (Solana, Sui만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<&'static str>,
+    /// 개인키 (`include_private_keys`일 때만)
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_private_key")]
+    pub private_key: Option<SecretBytes<32>>,
+}
+
+fn serialize_private_key<S: Serializer>(private_key: &Option<SecretBytes<32>>, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    match private_key {
+        Some(private_key) => serializer.serialize_str(&Zeroizing::new(hex::encode(private_key.as_slice()))),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// 범위의 모든 계정 도출
+///
+/// BIP-39 시드는 한 번만 계산, 곡선별 마스터 키와 부모 키는 캐시
+pub fn derive_records(mnemonic: &str, passphrase: &str, spec: &ExportSpec) -> Result<Vec<ExportRecord>> {
+    validate_mnemonic(mnemonic)?;
+    spec.validate()?;
+
+    let keys = KeyCache::new(mnemonic_to_seed(mnemonic, passphrase));
+    let source = SeedSource::with_keys(mnemonic, passphrase, &keys);
+
+    let mut records = Vec::new();
+    for chain in &spec.chains {
+        let chain_spec = chain.spec()?;
+        for account in spec.accounts.clone() {
+            for index in spec.indices.clone() {
+                let path = chain_spec.network_path(account, index, spec.network);
+                let derived = chain_spec.derive_network_account(&source, &path, spec.network)?;
+
+                records.push(ExportRecord {
+                    chain: chain.id(),
+                    account,
+                    index,
+                    path,
+                    address: derived.address,
+                    public_key: hex::encode(&derived.public_key),
+                    cluster: spec.network.cluster(*chain),
+                    private_key: spec.include_private_keys.then_some(derived.private_key),
+                });
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// JSON 배열로 쓰기
+pub fn write_json(records: &[ExportRecord], mut writer: impl Write) -> Result<()> {
+    serde_json::to_writer_pretty(&mut writer, records).map_err(|e| Error::Io(format!("JSON 쓰기 실패: {}", e)))?;
+    writeln!(writer).map_err(io_error)
+}
+
+/// CSV로 쓰기 (클러스터, 개인키 열은 값이 있는 경우에만)
+pub fn write_csv(records: &[ExportRecord], mut writer: impl Write) -> Result<()> {
+    let with_clusters = records.iter().any(|record| record.cluster.is_some());
+    let with_private_keys = records.iter().any(|record| record.private_key.is_some());
+
+    let mut header = "chain,account,index,path,address,public_key".to_string();
+    if with_clusters {
+        header.push_str(",cluster");
+    }
+    if with_private_keys {
+        header.push_str(",private_key");
+    }
+    writeln!(writer, "{}", header).map_err(io_error)?;
+
+    for record in records {
+        write!(
+            writer,
+            "{},{},{},{},{},{}",
+            csv_field(record.chain),
+            record.account,
+            record.index,
+            csv_field(&record.path),
+            csv_field(&record.address),
+            record.public_key,
+        )
+        .map_err(io_error)?;
+        if with_clusters {
+            write!(writer, ",{}", record.cluster.unwrap_or_default()).map_err(io_error)?;
+        }
+        if with_private_keys {
+            let private_key = record.private_key.as_ref().map(|key| Zeroizing::new(hex::encode(key.as_slice())));
+            write!(writer, ",{}", private_key.as_deref().map_or("", |key| key.as_str())).map_err(io_error)?;
+        }
+        writeln!(writer).map_err(io_error)?;
+    }
+    Ok(())
+}
+
+/// 도출 후 지정한 형식으로 쓰기
+pub fn export(mnemonic: &str, passphrase: &str, spec: &ExportSpec, format: ExportFormat, writer: impl Write) -> Result<()> {
+    let records = derive_records(mnemonic, passphrase, spec)?;
+    match format {
+        ExportFormat::Json => write_json(&records, writer),
+        ExportFormat::Csv => write_csv(&records, writer),
+    }
+}
+
+/// 쉼표, 따옴표, 줄바꿈이 있으면 따옴표로 감쌈 (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::Io(format!("manifest 쓰기 실패: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::CosmosChain;
+    use crate::evm::EvmAccount;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_derive_records() {
+        let spec = ExportSpec::new(&[Chain::Evm, Chain::Cosmos(CosmosChain::Osmosis)]).accounts(0..2).indices(0..3);
+        let records = derive_records(MNEMONIC, "", &spec).unwrap();
+
+        assert_eq!(records.len(), 2 * 2 * 3);
+        for record in &records {
+            println!("{:<10} {} {}", record.chain, record.path, record.address);
+            assert!(record.private_key.is_none());
+        }
+
+        let expected = EvmAccount::from_seed_with_path(mnemonic_to_seed(MNEMONIC, "").as_slice(), "m/44'/60'/1'/0/2").unwrap();
+        let record = &records[5];
+        assert_eq!((record.chain, record.account, record.index), ("evm", 1, 2));
+        assert_eq!(record.path, "m/44'/60'/1'/0/2");
+        assert_eq!(record.address, expected.address_checksummed());
+        assert_eq!(records[6].chain, "osmosis");
+    }
+
+    #[test]
+    fn test_json_and_csv() {
+        let spec = ExportSpec::new(&[Chain::Bitcoin, Chain::Substrate]).indices(0..2);
+
+        let mut json = Vec::new();
+        export(MNEMONIC, "", &spec, ExportFormat::Json, &mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 4);
+        assert_eq!(json[0]["address"], "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(json[0]["path"], "m/84'/0'/0'/0/0");
+        assert!(json[0].get("private_key").is_none());
+
+        let mut csv = Vec::new();
+        export(MNEMONIC, "", &spec, ExportFormat::Csv, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        println!("{}", csv);
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines[0], "chain,account,index,path,address,public_key");
+        assert!(lines[1].starts_with("bitcoin,0,0,m/84'/0'/0'/0/0,bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu,"));
+        assert!(lines[3].starts_with("substrate,0,0,,"));
+        assert!(lines[4].starts_with("substrate,0,1,//0//1,"));
+    }
+
+    #[test]
+    fn test_network() {
+        let spec = ExportSpec::new(&[Chain::Bitcoin, Chain::Solana, Chain::Evm]).network(Network::Testnet);
+        let records = derive_records(MNEMONIC, "", &spec).unwrap();
+        assert_eq!(records[0].path, "m/84'/1'/0'/0/0");
+        assert_eq!(records[0].address, "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl");
+        assert_eq!(records[0].cluster, None);
+        assert_eq!(records[1].address, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+        assert_eq!(records[1].cluster, Some("testnet"));
+        assert_eq!(records[2].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+
+        let mut csv = Vec::new();
+        write_csv(&records, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines[0], "chain,account,index,path,address,public_key,cluster");
+        assert!(lines[1].ends_with(','));
+        assert!(lines[2].ends_with(",testnet"));
+
+        let mut json = Vec::new();
+        write_json(&records, &mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert!(json[0].get("cluster").is_none());
+        assert_eq!(json[1]["cluster"], "testnet");
+
+        // 메인넷 Solana는 mainnet-beta
+        let records = derive_records(MNEMONIC, "", &ExportSpec::new(&[Chain::Solana])).unwrap();
+        assert_eq!(records[0].cluster, Some("mainnet-beta"));
+    }
+
+    #[test]
+    fn test_private_keys_opt_in() {
+        let spec = ExportSpec::new(&[Chain::Evm]).with_private_keys();
+        let records = derive_records(MNEMONIC, "", &spec).unwrap();
+        let private_key = hex::encode(records[0].private_key.as_ref().unwrap().as_slice());
+        assert_eq!(private_key, "1ab42cc412b618bdea3a599e3c9bae199ebf030895b039e9db1e30dafb12b727");
+
+        let mut csv = Vec::new();
+        write_csv(&records, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(csv.starts_with("chain,account,index,path,address,public_key,private_key\n"));
+        assert!(csv.contains(&private_key));
+
+        let mut json = Vec::new();
+        write_json(&records, &mut json).unwrap();
+        assert!(String::from_utf8(json).unwrap().contains(&private_key));
+    }
+
+    #[test]
+    fn test_invalid_spec() {
+        assert!(derive_records(MNEMONIC, "", &ExportSpec::new(&[])).is_err());
+        assert!(derive_records(MNEMONIC, "", &ExportSpec::default().indices(3..3)).is_err());
+        assert!(derive_records(MNEMONIC, "", &ExportSpec::default().accounts(0..HARDENED_OFFSET + 1)).is_err());
+        assert!(derive_records(MNEMONIC, "", &ExportSpec::new(&[Chain::Custom("unknown")])).is_err());
+        assert!(matches!(
+            derive_records("abandon abandon", "", &ExportSpec::default()),
+            Err(Error::InvalidMnemonic(_))
+        ));
+    }
+
+    #[test]
+    fn test_csv_field_escaping() {
+        assert_eq!(csv_field("m/44'/60'"), "m/44'/60'");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,79 @@
+//! 공용 해시 함수
+//!
+//! 여러 체인이 겹쳐 쓰는 해시를 한곳에 모음 (모듈마다 따로 구현하지 않음)
+//!
+//! | 함수 | 정의 | 사용처 |
+//! |------|------|--------|
+//! | `keccak256` | Keccak-256 (SHA-3 표준화 이전 패딩) | EVM 주소/서명, EIP-55 |
+//! | `sha3_256` | SHA3-256 (FIPS 202) | Aptos 등 SHA3 기반 주소 |
+//! | `sha256d` | SHA-256(SHA-256(x)) | Bitcoin txid/sighash, Base58Check 체크섬 |
+//! | `hash160` | RIPEMD-160(SHA-256(x)) | Bitcoin/Cosmos/XRP 주소, BIP-32 지문 |
+//! | `blake2b256` | BLAKE2b, 32바이트 출력 | Sui 주소/다이제스트, Substrate HDKD |
+//!
+//! 여러 조각을 이어 해시할 때는 `&[a, b].concat()`으로 넘김
+
+use blake2::digest::consts::U32;
+use blake2::Blake2b;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use tiny_keccak::{Hasher, Keccak, Sha3};
+
+/// Keccak-256
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut keccak = Keccak::v256();
+    let mut hash = [0u8; 32];
+    keccak.update(data);
+    keccak.finalize(&mut hash);
+    hash
+}
+
+/// SHA3-256 (Keccak-256과 패딩이 달라 결과가 다름)
+pub fn sha3_256(data: &[u8]) -> [u8; 32] {
+    let mut sha3 = Sha3::v256();
+    let mut hash = [0u8; 32];
+    sha3.update(data);
+    sha3.finalize(&mut hash);
+    hash
+}
+
+/// Double SHA-256
+pub fn sha256d(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// HASH160 = RIPEMD-160(SHA-256(data))
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// BLAKE2b-256
+pub fn blake2b256(data: &[u8]) -> [u8; 32] {
+    Blake2b::<U32>::digest(data).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_input_vectors() {
+        assert_eq!(hex::encode(keccak256(b"")), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        assert_eq!(hex::encode(sha3_256(b"")), "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        assert_eq!(hex::encode(sha256d(b"")), "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456");
+        assert_eq!(hex::encode(hash160(b"")), "b472a266d0bd89c13706a4132ccfb16f7c3b9fcb");
+        assert_eq!(hex::encode(blake2b256(b"")), "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8");
+    }
+
+    #[test]
+    fn test_hash160() {
+        // 테스트 벡터: 압축 공개키의 HASH160 (Bitcoin, Cosmos 공통)
+        let pubkey = hex::decode("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798").unwrap();
+        assert_eq!(hex::encode(hash160(&pubkey)), "751e76e8199196d454941c45d1b3a323f1433bd6");
+    }
+
+    #[test]
+    fn test_keccak_differs_from_sha3() {
+        assert_ne!(keccak256(b"abc"), sha3_256(b"abc"));
+        assert_eq!(hex::encode(sha3_256(b"abc")), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+    }
+}
@@ -0,0 +1,340 @@
+//! 암호화된 지갑 파일 (crate 자체 형식)
+//!
+//! 니모닉 또는 임의의 비밀값(개인키, 키페어 등)을 비밀번호로 암호화해 저장
+//!
+//! ## 암호화
+//! - KDF: Argon2id (비밀번호 + 16바이트 salt → 32바이트 키)
+//! - AEAD: XChaCha20-Poly1305 (24바이트 nonce, 16바이트 태그)
+//! - 헤더 전체를 AAD로 인증 → KDF 파라미터나 종류를 바꾸면 복호화 실패
+//!
+//! ## 파일 구조 (버전 1)
+//! ```text
+//! "CLKS" (4) || 버전 (1) || 종류 (1)
+//! || m_cost (4, LE) || t_cost (4, LE) || p_cost (4, LE)
+//! || salt (16) || nonce (24)
+//! || 암호문 + 태그
+//! ```
+//!
+//! 종류: 0x01 = 니모닉 (UTF-8), 0x02 = 원시 바이트
+//!
+//! 헤더의 Argon2 파라미터는 신뢰할 수 없으므로 상한(1 GiB, 16회, 병렬도 16)을 넘으면 키 유도 전에 거부
+//!
+//! 비밀값, 유도된 키, 복호화된 평문은 `Zeroizing`으로 감싸 drop 시 지움
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::trace;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use zeroize::Zeroizing;
+
+/// 파일 매직 바이트
+pub const MAGIC: &[u8; 4] = b"CLKS";
+/// 현재 파일 형식 버전
+pub const VERSION: u8 = 1;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const HEADER_LEN: usize = 4 + 1 + 1 + 12 + SALT_LEN + NONCE_LEN;
+
+const KIND_MNEMONIC: u8 = 0x01;
+const KIND_BYTES: u8 = 0x02;
+
+/// 암호화할 비밀값 (drop 시 zeroize, Debug는 길이만 출력)
+#[derive(Clone, PartialEq, Eq)]
+pub enum KeystoreSecret {
+    /// 니모닉 문장
+    Mnemonic(Zeroizing<String>),
+    /// 원시 바이트 (개인키, 키페어 등)
+    Bytes(Zeroizing<Vec<u8>>),
+}
+
+impl fmt::Debug for KeystoreSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeystoreSecret::Mnemonic(mnemonic) => write!(f, "Mnemonic(<{}바이트>)", mnemonic.len()),
+            KeystoreSecret::Bytes(bytes) => write!(f, "Bytes(<{}바이트>)", bytes.len()),
+        }
+    }
+}
+
+/// Argon2id 파라미터
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeystoreParams {
+    /// 메모리 (KiB)
+    pub m_cost: u32,
+    /// 반복 횟수
+    pub t_cost: u32,
+    /// 병렬도
+    pub p_cost: u32,
+}
+
+impl KeystoreParams {
+    /// 기본값 (64 MiB, 3회, 병렬도 1)
+    pub const DEFAULT: Self = KeystoreParams { m_cost: 64 * 1024, t_cost: 3, p_cost: 1 };
+    /// m_cost 상한 (1 GiB)
+    pub const MAX_M_COST: u32 = 1024 * 1024;
+    /// t_cost 상한
+    pub const MAX_T_COST: u32 = 16;
+    /// p_cost 상한
+    pub const MAX_P_COST: u32 = 16;
+}
+
+impl Default for KeystoreParams {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 암호화 / 복호화
+// ═══════════════════════════════════════════════════════════════
+
+/// 비밀값을 암호화해 파일 바이트로 반환
+pub fn encrypt(secret: &KeystoreSecret, password: &str, params: KeystoreParams) -> Result<Vec<u8>> {
+    let _span = trace::span!("keystore.encrypt", format = "crypto-lib");
+    let (kind, plaintext) = match secret {
+        KeystoreSecret::Mnemonic(mnemonic) => (KIND_MNEMONIC, mnemonic.as_bytes()),
+        KeystoreSecret::Bytes(bytes) => (KIND_BYTES, bytes.as_slice()),
+    };
+
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut nonce);
+
+    let mut out = Vec::with_capacity(HEADER_LEN + plaintext.len() + 16);
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    out.push(kind);
+    out.extend_from_slice(&params.m_cost.to_le_bytes());
+    out.extend_from_slice(&params.t_cost.to_le_bytes());
+    out.extend_from_slice(&params.p_cost.to_le_bytes());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+
+    let key = derive_key(password, &salt, params)?;
+    let cipher = XChaCha20Poly1305::new(key.as_ref().into());
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), Payload { msg: plaintext, aad: &out })
+        .map_err(|_| Error::Keystore("암호화 실패".to_string()))?;
+
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// 파일 바이트를 복호화
+pub fn decrypt(data: &[u8], password: &str) -> Result<KeystoreSecret> {
+    let _span = trace::span!("keystore.decrypt", format = "crypto-lib");
+    if data.len() < HEADER_LEN + 16 {
+        return Err(Error::Keystore("키스토어 파일이 너무 짧습니다".to_string()));
+    }
+    if &data[..4] != MAGIC {
+        return Err(Error::Keystore("키스토어 파일이 아닙니다 (매직 바이트 불일치)".to_string()));
+    }
+    if data[4] != VERSION {
+        return Err(Error::Keystore(format!("지원하지 않는 키스토어 버전: {}", data[4])));
+    }
+
+    let kind = data[5];
+    let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    let params = KeystoreParams { m_cost: read_u32(6), t_cost: read_u32(10), p_cost: read_u32(14) };
+    let salt = &data[18..18 + SALT_LEN];
+    let nonce = &data[18 + SALT_LEN..HEADER_LEN];
+
+    let key = derive_key(password, salt, params)?;
+    let cipher = XChaCha20Poly1305::new(key.as_ref().into());
+    let plaintext = cipher
+        .decrypt(XNonce::from_slice(nonce), Payload { msg: &data[HEADER_LEN..], aad: &data[..HEADER_LEN] })
+        .map(Zeroizing::new)
+        .map_err(|_| Error::Decryption("복호화 실패 (비밀번호가 틀렸거나 파일이 손상됨)".to_string()))?;
+
+    match kind {
+        // 평문을 복사해 변환하므로 UTF-8 에러여도 원본은 drop 시 지워짐
+        KIND_MNEMONIC => std::str::from_utf8(&plaintext)
+            .map(|mnemonic| KeystoreSecret::Mnemonic(Zeroizing::new(mnemonic.to_string())))
+            .map_err(|_| Error::Keystore("니모닉이 UTF-8이 아닙니다".to_string())),
+        KIND_BYTES => Ok(KeystoreSecret::Bytes(plaintext)),
+        other => Err(Error::Keystore(format!("알 수 없는 비밀값 종류: 0x{:02x}", other))),
+    }
+}
+
+/// 니모닉 암호화 (기본 파라미터)
+pub fn encrypt_mnemonic(mnemonic: &str, password: &str) -> Result<Vec<u8>> {
+    encrypt(&KeystoreSecret::Mnemonic(Zeroizing::new(mnemonic.to_string())), password, KeystoreParams::DEFAULT)
+}
+
+/// 니모닉 복호화 (다른 종류면 에러)
+pub fn decrypt_mnemonic(data: &[u8], password: &str) -> Result<Zeroizing<String>> {
+    match decrypt(data, password)? {
+        KeystoreSecret::Mnemonic(mnemonic) => Ok(mnemonic),
+        KeystoreSecret::Bytes(_) => Err(Error::Keystore("니모닉이 아닌 비밀값입니다".to_string())),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 파일 읽기 / 쓰기
+// ═══════════════════════════════════════════════════════════════
+
+/// 암호화된 지갑 파일 저장
+///
+/// 유닉스에서는 소유자만 읽을 수 있게(0600) 생성
+pub fn save(path: impl AsRef<Path>, secret: &KeystoreSecret, password: &str, params: KeystoreParams) -> Result<()> {
+    let data = encrypt(secret, password, params)?;
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path.as_ref()).map_err(|e| Error::Io(format!("{}: {}", path.as_ref().display(), e)))?;
+    file.write_all(&data).map_err(|e| Error::Io(format!("{}: {}", path.as_ref().display(), e)))
+}
+
+/// 암호화된 지갑 파일 읽기
+pub fn load(path: impl AsRef<Path>, password: &str) -> Result<KeystoreSecret> {
+    let data = fs::read(path.as_ref()).map_err(|e| Error::Io(format!("{}: {}", path.as_ref().display(), e)))?;
+    decrypt(&data, password)
+}
+
+/// 비밀번호 → 32바이트 키 (Argon2id)
+fn derive_key(password: &str, salt: &[u8], params: KeystoreParams) -> Result<Zeroizing<[u8; 32]>> {
+    let _span = trace::span!(
+        "keystore.kdf",
+        kdf = "argon2id",
+        m_cost = params.m_cost,
+        t_cost = params.t_cost,
+        p_cost = params.p_cost,
+    );
+    if params.m_cost > KeystoreParams::MAX_M_COST || params.t_cost > KeystoreParams::MAX_T_COST || params.p_cost > KeystoreParams::MAX_P_COST {
+        return Err(Error::Keystore(format!(
+            "Argon2 파라미터가 상한을 넘습니다: m_cost {} (≤ {}), t_cost {} (≤ {}), p_cost {} (≤ {})",
+            params.m_cost,
+            KeystoreParams::MAX_M_COST,
+            params.t_cost,
+            KeystoreParams::MAX_T_COST,
+            params.p_cost,
+            KeystoreParams::MAX_P_COST,
+        )));
+    }
+    let params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(32))
+        .map_err(|e| Error::Keystore(format!("유효하지 않은 Argon2 파라미터: {}", e)))?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, key.as_mut())
+        .map_err(|e| Error::Keystore(format!("Argon2 키 유도 실패: {}", e)))?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // 테스트용 가벼운 파라미터
+    const FAST: KeystoreParams = KeystoreParams { m_cost: 256, t_cost: 1, p_cost: 1 };
+
+    #[test]
+    fn test_mnemonic_roundtrip() {
+        let secret = KeystoreSecret::Mnemonic(Zeroizing::new(MNEMONIC.to_string()));
+        let data = encrypt(&secret, "비밀번호", FAST).unwrap();
+        println!("키스토어 ({}바이트): {}", data.len(), hex::encode(&data));
+
+        assert_eq!(&data[..4], MAGIC);
+        assert_eq!(data[4], VERSION);
+        assert_eq!(data.len(), HEADER_LEN + MNEMONIC.len() + 16);
+
+        assert_eq!(decrypt(&data, "비밀번호").unwrap(), secret);
+        assert_eq!(decrypt_mnemonic(&data, "비밀번호").unwrap().as_str(), MNEMONIC);
+        assert!(decrypt(&data, "password").is_err());
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let secret = KeystoreSecret::Bytes(Zeroizing::new(vec![0x42; 64]));
+        let data = encrypt(&secret, "pw", FAST).unwrap();
+
+        assert_eq!(decrypt(&data, "pw").unwrap(), secret);
+        assert!(decrypt_mnemonic(&data, "pw").is_err());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let debug = format!("{:?}", KeystoreSecret::Mnemonic(Zeroizing::new(MNEMONIC.to_string())));
+        assert_eq!(debug, "Mnemonic(<93바이트>)");
+        assert!(!debug.contains("abandon"));
+        assert_eq!(format!("{:?}", KeystoreSecret::Bytes(Zeroizing::new(vec![0x42; 64]))), "Bytes(<64바이트>)");
+    }
+
+    #[test]
+    fn test_header_is_authenticated() {
+        let data = encrypt(&KeystoreSecret::Mnemonic(Zeroizing::new(MNEMONIC.to_string())), "pw", FAST).unwrap();
+
+        // 종류 바이트 변조
+        let mut tampered = data.clone();
+        tampered[5] = KIND_BYTES;
+        assert!(decrypt(&tampered, "pw").is_err());
+
+        // 암호문 변조
+        let mut tampered = data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&tampered, "pw").is_err());
+
+        // 버전, 매직
+        let mut tampered = data.clone();
+        tampered[4] = 2;
+        assert!(decrypt(&tampered, "pw").unwrap_err().to_string().contains("버전"));
+        assert!(decrypt(b"not a keystore file at all, definitely not", "pw").is_err());
+    }
+
+    #[test]
+    fn test_rejects_oversized_params() {
+        let data = encrypt(&KeystoreSecret::Mnemonic(Zeroizing::new(MNEMONIC.to_string())), "pw", FAST).unwrap();
+
+        // 헤더의 m_cost / t_cost / p_cost를 상한 + 1로 바꾸면 키 유도 전에 거부
+        for (offset, value) in [
+            (6, KeystoreParams::MAX_M_COST + 1),
+            (6, u32::MAX),
+            (10, KeystoreParams::MAX_T_COST + 1),
+            (14, KeystoreParams::MAX_P_COST + 1),
+        ] {
+            let mut tampered = data.clone();
+            tampered[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+            let err = decrypt(&tampered, "pw").unwrap_err();
+            assert!(matches!(err, Error::Keystore(_)), "{:?}", err);
+            assert!(err.to_string().contains("상한"), "{}", err);
+        }
+
+        // 암호화할 때도 같은 상한
+        let params = KeystoreParams { t_cost: KeystoreParams::MAX_T_COST + 1, ..FAST };
+        assert!(encrypt(&KeystoreSecret::Bytes(Zeroizing::new(vec![1])), "pw", params).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("crypto-lib-keystore-{}.clks", std::process::id()));
+        let secret = KeystoreSecret::Mnemonic(Zeroizing::new(MNEMONIC.to_string()));
+
+        save(&path, &secret, "pw", FAST).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+        assert_eq!(load(&path, "pw").unwrap(), secret);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+//! Ledger USB HID 전송
+//!
+//! APDU를 64바이트 HID 리포트로 나눠 보내고 응답 리포트를 다시 합침
+//!
+//! ## 프레이밍
+//! ```text
+//! 첫 패킷:   채널(2) 태그 0x05(1) 순번(2) APDU 길이(2) APDU...
+//! 이후 패킷: 채널(2) 태그 0x05(1) 순번(2) APDU...
+//! ```
+//! 모든 값은 빅엔디언, 패킷은 64바이트로 0 패딩
+//!
+//! ## Linux hidraw
+//! `HidTransport`는 `/dev/hidrawN`을 직접 읽고 씀 (hidapi/libusb 불필요)
+//! 기기 접근 권한은 Ledger udev 규칙으로 설정 (<https://github.com/LedgerHQ/udev-rules>)
+//! 다른 OS에서는 `Transport`를 직접 구현해 사용 (예: hidapi 래퍼)
+
+use crate::error::{Error, Result};
+
+#[cfg(target_os = "linux")]
+pub use self::linux::HidTransport;
+
+/// Ledger USB vendor id
+pub const LEDGER_VENDOR_ID: u16 = 0x2c97;
+
+/// HID 리포트 크기
+pub const PACKET_SIZE: usize = 64;
+
+/// 통신 채널
+const CHANNEL: u16 = 0x0101;
+
+/// APDU 태그
+const TAG_APDU: u8 = 0x05;
+
+/// 패킷 헤더 크기 (채널 + 태그 + 순번)
+const HEADER_SIZE: usize = 5;
+
+/// APDU → HID 패킷들
+pub fn wrap_command(apdu: &[u8]) -> Result<Vec<[u8; PACKET_SIZE]>> {
+    let length = u16::try_from(apdu.len()).map_err(|_| Error::InvalidInput(format!("APDU가 너무 깁니다: {}바이트", apdu.len())))?;
+
+    let mut payload = length.to_be_bytes().to_vec();
+    payload.extend_from_slice(apdu);
+
+    let packets = payload
+        .chunks(PACKET_SIZE - HEADER_SIZE)
+        .enumerate()
+        .map(|(sequence, chunk)| {
+            let mut packet = [0u8; PACKET_SIZE];
+            packet[..2].copy_from_slice(&CHANNEL.to_be_bytes());
+            packet[2] = TAG_APDU;
+            packet[3..5].copy_from_slice(&(sequence as u16).to_be_bytes());
+            packet[HEADER_SIZE..HEADER_SIZE + chunk.len()].copy_from_slice(chunk);
+            packet
+        })
+        .collect();
+    Ok(packets)
+}
+
+/// HID 패킷들 → 응답 APDU
+///
+/// `read_packet`을 응답 길이만큼 채워질 때까지 호출
+pub fn read_response(mut read_packet: impl FnMut() -> Result<[u8; PACKET_SIZE]>) -> Result<Vec<u8>> {
+    let mut response = Vec::new();
+    let mut expected = None;
+    let mut sequence: u16 = 0;
+
+    loop {
+        let packet = read_packet()?;
+        if u16::from_be_bytes([packet[0], packet[1]]) != CHANNEL || packet[2] != TAG_APDU {
+            return Err(Error::Device("Ledger HID 패킷 헤더가 아닙니다".to_string()));
+        }
+        if u16::from_be_bytes([packet[3], packet[4]]) != sequence {
+            return Err(Error::Device(format!("패킷 순번이 맞지 않습니다: {} 기대", sequence)));
+        }
+
+        let mut body = &packet[HEADER_SIZE..];
+        let length = match expected {
+            Some(length) => length,
+            None => {
+                let length = u16::from_be_bytes([body[0], body[1]]) as usize;
+                body = &body[2..];
+                expected = Some(length);
+                length
+            }
+        };
+
+        let take = body.len().min(length - response.len());
+        response.extend_from_slice(&body[..take]);
+        if response.len() == length {
+            return Ok(response);
+        }
+        sequence = sequence.wrapping_add(1);
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{Read, Write};
+    use std::path::{Path, PathBuf};
+
+    use super::{read_response, wrap_command, LEDGER_VENDOR_ID, PACKET_SIZE};
+    use crate::ledger::Transport;
+    use crate::error::{Error, Result};
+
+    /// HID 사용 페이지 0xFFA0 (Ledger APDU 인터페이스의 리포트 디스크립터 시작)
+    const APDU_USAGE_PAGE: [u8; 3] = [0x06, 0xA0, 0xFF];
+
+    /// `/dev/hidrawN` 기반 전송
+    ///
+    /// 응답은 블로킹으로 기다림 (`confirm = true`면 사용자가 버튼을 누를 때까지)
+    #[derive(Debug)]
+    pub struct HidTransport {
+        device: File,
+        path: PathBuf,
+    }
+
+    impl HidTransport {
+        /// hidraw 장치 열기
+        pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+            let path = path.as_ref().to_path_buf();
+            let device = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(&path)
+                .map_err(|e| Error::Device(format!("{} 열기 실패: {}", path.display(), e)))?;
+            Ok(HidTransport { device, path })
+        }
+
+        /// 연결된 첫 Ledger 기기 열기
+        pub fn open_first() -> Result<Self> {
+            let path = Self::list()?.into_iter().next().ok_or_else(|| Error::Device("연결된 Ledger 기기가 없습니다".to_string()))?;
+            Self::open(path)
+        }
+
+        /// 연결된 Ledger APDU 인터페이스의 hidraw 경로
+        pub fn list() -> Result<Vec<PathBuf>> {
+            let entries = match fs::read_dir("/sys/class/hidraw") {
+                Ok(entries) => entries,
+                Err(_) => return Ok(Vec::new()),
+            };
+
+            let mut devices: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| is_ledger_apdu(&entry.path().join("device")))
+                .map(|entry| Path::new("/dev").join(entry.file_name()))
+                .collect();
+            devices.sort();
+            Ok(devices)
+        }
+
+        /// 장치 경로
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Transport for HidTransport {
+        fn exchange(&mut self, command: &[u8]) -> Result<Vec<u8>> {
+            for packet in wrap_command(command)? {
+                // hidraw 쓰기는 리포트 id(0)로 시작
+                let mut report = [0u8; PACKET_SIZE + 1];
+                report[1..].copy_from_slice(&packet);
+                self.device.write_all(&report).map_err(|e| Error::Device(format!("HID 쓰기 실패: {}", e)))?;
+            }
+
+            read_response(|| {
+                let mut packet = [0u8; PACKET_SIZE];
+                self.device.read_exact(&mut packet).map_err(|e| Error::Device(format!("HID 읽기 실패: {}", e)))?;
+                Ok(packet)
+            })
+        }
+    }
+
+    /// uevent의 HID_ID(버스:vendor:product)와 리포트 디스크립터로 Ledger APDU 인터페이스 판별
+    fn is_ledger_apdu(device: &Path) -> bool {
+        let vendor = format!(":{:08X}:", LEDGER_VENDOR_ID);
+        let is_ledger = fs::read_to_string(device.join("uevent"))
+            .map(|uevent| uevent.lines().any(|line| line.starts_with("HID_ID=") && line.to_ascii_uppercase().contains(&vendor)))
+            .unwrap_or(false);
+
+        is_ledger && fs::read(device.join("report_descriptor")).map(|descriptor| descriptor.starts_with(&APDU_USAGE_PAGE)).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_command() {
+        let apdu = hex::decode("e002000015058000002c8000003c800000000000000000000000").unwrap();
+        let packets = wrap_command(&apdu).unwrap();
+        assert_eq!(packets.len(), 1);
+        assert_eq!(hex::encode(&packets[0][..9]), "0101050000001ae002");
+        assert!(packets[0][7 + apdu.len()..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // 여러 패킷으로 나뉘는 길이 (2 + 150 = 59 + 59 + 34)
+        let apdu: Vec<u8> = (0..150u8).collect();
+        let packets = wrap_command(&apdu).unwrap();
+        assert_eq!(packets.len(), 3);
+        assert_eq!(&packets[2][..5], &[0x01, 0x01, 0x05, 0x00, 0x02]);
+
+        let mut iter = packets.clone().into_iter();
+        assert_eq!(read_response(|| Ok(iter.next().unwrap())).unwrap(), apdu);
+
+        // 순번이 빠진 응답
+        let mut iter = [packets[0], packets[2]].into_iter();
+        assert!(matches!(read_response(|| Ok(iter.next().unwrap())), Err(Error::Device(_))));
+
+        // 다른 채널/태그
+        assert!(read_response(|| Ok([0u8; PACKET_SIZE])).is_err());
+    }
+}
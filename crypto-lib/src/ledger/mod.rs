@@ -0,0 +1,402 @@
+//! Ledger 하드웨어 지갑 (feature = "ledger")
+//!
+//! Ledger 앱(Ethereum, Cosmos, Solana)에 APDU로 공개키/주소를 요청하고,
+//! 이 크레이트가 도출한 주소와 같은지 확인 (`verify_against_device`)
+//!
+//! ## 구성
+//! - `Transport`: APDU 한 번 주고받기 (USB HID, Speculos 에뮬레이터 등 구현 교체 가능)
+//! - `hid`: Ledger HID 프레이밍 + Linux hidraw 전송 (외부 의존성 없음)
+//! - 앱별 명령: `get_evm_address`, `get_cosmos_address`, `get_solana_address`
+//!
+//! ## 앱별 APDU
+//!
+//! | 앱 | CLA | INS | 데이터 | 응답 |
+//! |----|-----|-----|--------|------|
+//! | Ethereum | 0xE0 | 0x02 | 경로 길이 + u32 BE | 공개키 길이 + 공개키(65) + 주소 길이 + 주소 hex(40자) |
+//! | Cosmos | 0x55 | 0x04 | HRP 길이 + HRP + u32 LE × 5 | 압축 공개키(33) + bech32 주소 |
+//! | Solana | 0xE0 | 0x05 | 경로 길이 + u32 BE | 공개키(32) |
+//!
+//! 응답 끝 2바이트는 상태 워드 (0x9000 = 성공)
+//! `confirm = true`이면 기기 화면에 주소를 표시하고 사용자 확인을 기다림 (P1 = 1)
+//!
+//! ## 도출 확인
+//! 테스트용 니모닉을 넣은 기기에서 같은 니모닉으로 `CachedWallet`을 만들어 비교
+//! → 경로 해석, 곡선, 주소 인코딩이 하드웨어 지갑과 같은지 운영 환경에서 확인
+//!
+//! ```text
+//! let mut device = ledger::hid::HidTransport::open_first()?;
+//! let wallet = CachedWallet::from_mnemonic(&test_mnemonic, "")?;
+//! for index in 0..5 {
+//!     let address = ledger::verify_against_device(&mut device, &wallet, Chain::Evm, 0, index)?;
+//!     println!("{} {}", address.path, address.address);
+//! }
+//! ```
+//!
+//! ## 참고 자료
+//! - [Ledger Ethereum app APDU](https://github.com/LedgerHQ/app-ethereum/blob/develop/doc/ethapp.adoc)
+//! - [Ledger Cosmos app APDU](https://github.com/cosmos/ledger-cosmos/blob/main/docs/APDUSPEC.md)
+//! - [Ledger Solana app APDU](https://github.com/LedgerHQ/app-solana)
+
+pub mod hid;
+
+use crate::bip32::{ChildNumber, DerivationPath};
+use crate::wallet::{CachedWallet, Chain};
+use crate::error::{Error, Result};
+
+/// 성공 상태 워드
+pub const SW_OK: u16 = 0x9000;
+
+/// Ethereum 앱 CLA
+const EVM_CLA: u8 = 0xE0;
+/// Ethereum 앱 GET_PUBLIC_KEY
+const EVM_INS_GET_PUBLIC_KEY: u8 = 0x02;
+
+/// Cosmos 앱 CLA
+const COSMOS_CLA: u8 = 0x55;
+/// Cosmos 앱 GET_ADDR_SECP256K1
+const COSMOS_INS_GET_ADDR: u8 = 0x04;
+
+/// Solana 앱 CLA
+const SOLANA_CLA: u8 = 0xE0;
+/// Solana 앱 GET_PUBKEY
+const SOLANA_INS_GET_PUBKEY: u8 = 0x05;
+
+/// 경로 최대 길이 (앱 공통)
+const MAX_PATH_DEPTH: usize = 10;
+
+/// APDU 전송
+///
+/// 명령 바이트를 보내고 응답(데이터 + 상태 워드 2바이트)을 그대로 반환
+/// 클로저도 전송으로 쓸 수 있음 (에뮬레이터 TCP 연결, 테스트 등)
+pub trait Transport {
+    /// APDU 한 번 주고받기
+    fn exchange(&mut self, command: &[u8]) -> Result<Vec<u8>>;
+}
+
+impl<F: FnMut(&[u8]) -> Result<Vec<u8>>> Transport for F {
+    fn exchange(&mut self, command: &[u8]) -> Result<Vec<u8>> {
+        self(command)
+    }
+}
+
+/// APDU 명령 (ISO 7816-4 short APDU)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Apdu {
+    /// 클래스 (앱 구분)
+    pub cla: u8,
+    /// 명령
+    pub ins: u8,
+    /// 파라미터 1
+    pub p1: u8,
+    /// 파라미터 2
+    pub p2: u8,
+    /// 데이터 (최대 255바이트)
+    pub data: Vec<u8>,
+}
+
+impl Apdu {
+    /// CLA INS P1 P2 Lc 데이터
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let length = u8::try_from(self.data.len())
+            .map_err(|_| Error::InvalidInput(format!("APDU 데이터는 255바이트 이하여야 합니다: {}바이트", self.data.len())))?;
+
+        let mut bytes = Vec::with_capacity(5 + self.data.len());
+        bytes.extend_from_slice(&[self.cla, self.ins, self.p1, self.p2, length]);
+        bytes.extend_from_slice(&self.data);
+        Ok(bytes)
+    }
+}
+
+/// 기기가 알려준 공개키와 주소
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceAddress {
+    /// 도출 경로
+    pub path: String,
+    /// 공개키 (EVM: 비압축 65바이트, Cosmos: 압축 33바이트, Solana: 32바이트)
+    pub public_key: Vec<u8>,
+    /// 주소 (EVM은 EIP-55 체크섬 형식)
+    pub address: String,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 앱별 명령
+// ═══════════════════════════════════════════════════════════════
+
+/// Ethereum 앱에서 주소 조회
+pub fn get_evm_address(transport: &mut dyn Transport, path: &str, confirm: bool) -> Result<DeviceAddress> {
+    let path = parse_path(path)?;
+    let apdu = Apdu { cla: EVM_CLA, ins: EVM_INS_GET_PUBLIC_KEY, p1: confirm as u8, p2: 0, data: path_be(&path) };
+    let response = send(transport, &apdu)?;
+
+    let mut reader = Reader::new(&response);
+    let public_key = reader.take_prefixed()?.to_vec();
+    let reported = reader.take_prefixed()?;
+    let reported = std::str::from_utf8(reported).map_err(|_| Error::Device("주소가 ASCII가 아닙니다".to_string()))?;
+
+    // 기기는 0x 없이 hex 40자를 돌려줌 → 공개키로 다시 계산한 체크섬 주소와 비교
+    let address = Chain::Evm.spec()?.format_address(&public_key)?;
+    if !address[2..].eq_ignore_ascii_case(reported.trim_start_matches("0x")) {
+        return Err(Error::Device(format!("기기 응답의 주소와 공개키가 맞지 않습니다: {}", reported)));
+    }
+
+    Ok(DeviceAddress { path: path.to_string(), public_key, address })
+}
+
+/// Cosmos 앱에서 주소 조회 (경로는 m/44'/coin'/account'/change/index 5단계)
+pub fn get_cosmos_address(transport: &mut dyn Transport, hrp: &str, path: &str, confirm: bool) -> Result<DeviceAddress> {
+    let path = parse_path(path)?;
+    let components = path.as_slice();
+    if components.len() != 5 || !components[..3].iter().all(ChildNumber::is_hardened) || components[3..].iter().any(ChildNumber::is_hardened) {
+        return Err(Error::path(path.to_string(), format!("Cosmos 앱 경로는 m/44'/coin'/account'/change/index 형식이어야 합니다: {}", path)));
+    }
+    let hrp_length = u8::try_from(hrp.len()).map_err(|_| Error::InvalidInput(format!("HRP가 너무 깁니다: {}", hrp)))?;
+
+    let mut data = vec![hrp_length];
+    data.extend_from_slice(hrp.as_bytes());
+    for child in components {
+        data.extend_from_slice(&child.to_u32().to_le_bytes());
+    }
+
+    let response = send(transport, &Apdu { cla: COSMOS_CLA, ins: COSMOS_INS_GET_ADDR, p1: confirm as u8, p2: 0, data })?;
+    let mut reader = Reader::new(&response);
+    let public_key = reader.take(33)?.to_vec();
+    let address = String::from_utf8(reader.rest().to_vec()).map_err(|_| Error::Device("주소가 ASCII가 아닙니다".to_string()))?;
+
+    Ok(DeviceAddress { path: path.to_string(), public_key, address })
+}
+
+/// Solana 앱에서 주소 조회 (주소 = 공개키 Base58)
+pub fn get_solana_address(transport: &mut dyn Transport, path: &str, confirm: bool) -> Result<DeviceAddress> {
+    let path = parse_path(path)?;
+    let apdu = Apdu { cla: SOLANA_CLA, ins: SOLANA_INS_GET_PUBKEY, p1: confirm as u8, p2: 0, data: path_be(&path) };
+    let response = send(transport, &apdu)?;
+
+    let public_key = Reader::new(&response).take(32)?.to_vec();
+    let address = Chain::Solana.spec()?.format_address(&public_key)?;
+
+    Ok(DeviceAddress { path: path.to_string(), public_key, address })
+}
+
+/// 체인에 맞는 앱으로 주소 조회 (EVM, Cosmos 계열, Solana)
+pub fn get_address(transport: &mut dyn Transport, chain: Chain, path: &str, confirm: bool) -> Result<DeviceAddress> {
+    match chain {
+        Chain::Evm => get_evm_address(transport, path, confirm),
+        Chain::Cosmos(cosmos) => get_cosmos_address(transport, cosmos.hrp(), path, confirm),
+        Chain::Solana => get_solana_address(transport, path, confirm),
+        other => Err(Error::Chain(format!("Ledger 주소 조회를 지원하지 않는 체인입니다: {}", other.id()))),
+    }
+}
+
+/// 도출한 주소가 기기 주소와 같은지 확인
+///
+/// `wallet`과 기기에 같은 니모닉이 들어 있어야 함 (기기 화면 확인 없이 조회)
+/// 공개키나 주소가 다르면 `Error::Device`
+pub fn verify_against_device(transport: &mut dyn Transport, wallet: &CachedWallet, chain: Chain, account: u32, index: u32) -> Result<DeviceAddress> {
+    let derived = wallet.derive(chain, account, index)?;
+    let device = get_address(transport, chain, &derived.path, false)?;
+
+    if device.public_key != derived.public_key || device.address != derived.address {
+        return Err(Error::Device(format!(
+            "기기 주소가 다릅니다 ({}): 도출 {} / 기기 {}",
+            derived.path, derived.address, device.address
+        )));
+    }
+    Ok(device)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// 명령 전송 + 상태 워드 확인 → 응답 데이터
+fn send(transport: &mut dyn Transport, apdu: &Apdu) -> Result<Vec<u8>> {
+    let mut response = transport.exchange(&apdu.to_bytes()?)?;
+    if response.len() < 2 {
+        return Err(Error::Device(format!("응답이 너무 짧습니다: {}바이트", response.len())));
+    }
+
+    let status = u16::from_be_bytes([response[response.len() - 2], response[response.len() - 1]]);
+    if status != SW_OK {
+        return Err(Error::Device(format!("기기 오류 0x{:04X}: {}", status, status_message(status))));
+    }
+    response.truncate(response.len() - 2);
+    Ok(response)
+}
+
+/// 자주 보는 상태 워드 설명
+fn status_message(status: u16) -> &'static str {
+    match status {
+        0x6985 => "사용자가 거부했습니다",
+        0x6A80 | 0x6984 => "잘못된 데이터입니다",
+        0x6B00 => "잘못된 파라미터입니다",
+        0x6D00 | 0x6E00 | 0x6E01 => "앱이 열려 있지 않거나 지원하지 않는 명령입니다",
+        0x5515 | 0x6982 => "기기가 잠겨 있습니다",
+        _ => "알 수 없는 상태",
+    }
+}
+
+fn parse_path(path: &str) -> Result<DerivationPath> {
+    let path: DerivationPath = path.parse()?;
+    if path.is_empty() || path.len() > MAX_PATH_DEPTH {
+        return Err(Error::path(path.to_string(), format!("경로는 1~{}단계여야 합니다: {}", MAX_PATH_DEPTH, path)));
+    }
+    Ok(path)
+}
+
+/// 경로 길이 1바이트 + 각 단계 u32 BE (Ethereum, Solana 앱)
+fn path_be(path: &DerivationPath) -> Vec<u8> {
+    let mut data = vec![path.len() as u8];
+    for child in path {
+        data.extend_from_slice(&child.to_u32().to_be_bytes());
+    }
+    data
+}
+
+/// 응답 파서
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < length {
+            return Err(Error::Device(format!("응답이 너무 짧습니다: {}바이트 필요, {}바이트 남음", length, self.bytes.len())));
+        }
+        let (head, tail) = self.bytes.split_at(length);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    /// 길이 1바이트 + 값
+    fn take_prefixed(&mut self) -> Result<&'a [u8]> {
+        let length = self.take(1)?[0] as usize;
+        self.take(length)
+    }
+
+    fn rest(self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::CosmosChain;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// 니모닉으로 Ethereum/Cosmos/Solana 앱 응답을 흉내 내는 기기
+    struct MockLedger {
+        wallet: CachedWallet,
+        status: u16,
+        commands: Vec<Vec<u8>>,
+    }
+
+    impl MockLedger {
+        fn new(mnemonic: &str) -> Self {
+            MockLedger { wallet: CachedWallet::from_mnemonic(mnemonic, "").unwrap(), status: SW_OK, commands: Vec::new() }
+        }
+
+        fn respond(&self, command: &[u8]) -> Result<Vec<u8>> {
+            let (cla, ins, data) = (command[0], command[1], &command[5..]);
+            assert_eq!(command[4] as usize, data.len());
+
+            Ok(match (cla, ins) {
+                (EVM_CLA, EVM_INS_GET_PUBLIC_KEY) => {
+                    let account = self.wallet.derive_path(Chain::Evm, &path_from(&data[1..], u32::from_be_bytes))?;
+                    let mut response = vec![account.public_key.len() as u8];
+                    response.extend_from_slice(&account.public_key);
+                    response.push(40);
+                    response.extend_from_slice(account.address[2..].to_lowercase().as_bytes());
+                    response
+                }
+                (COSMOS_CLA, COSMOS_INS_GET_ADDR) => {
+                    let hrp_length = data[0] as usize;
+                    let hrp = std::str::from_utf8(&data[1..1 + hrp_length]).unwrap();
+                    let chain = CosmosChain::ALL.iter().copied().find(|c| c.hrp() == hrp).unwrap();
+                    let account = self.wallet.derive_path(Chain::Cosmos(chain), &path_from(&data[1 + hrp_length..], u32::from_le_bytes))?;
+                    let mut response = account.public_key.clone();
+                    response.extend_from_slice(account.address.as_bytes());
+                    response
+                }
+                (SOLANA_CLA, SOLANA_INS_GET_PUBKEY) => {
+                    self.wallet.derive_path(Chain::Solana, &path_from(&data[1..], u32::from_be_bytes))?.public_key
+                }
+                _ => Vec::new(),
+            })
+        }
+    }
+
+    impl Transport for MockLedger {
+        fn exchange(&mut self, command: &[u8]) -> Result<Vec<u8>> {
+            self.commands.push(command.to_vec());
+            let mut response = if self.status == SW_OK { self.respond(command)? } else { Vec::new() };
+            response.extend_from_slice(&self.status.to_be_bytes());
+            Ok(response)
+        }
+    }
+
+    fn path_from(bytes: &[u8], decode: fn([u8; 4]) -> u32) -> String {
+        let children: Vec<ChildNumber> = bytes.chunks(4).map(|c| ChildNumber::from_u32(decode(c.try_into().unwrap()))).collect();
+        DerivationPath::from(children).to_string()
+    }
+
+    #[test]
+    fn test_apdu_encoding() {
+        let mut device = MockLedger::new(MNEMONIC);
+
+        get_evm_address(&mut device, "m/44'/60'/0'/0/0", false).unwrap();
+        assert_eq!(hex::encode(&device.commands[0]), "e002000015058000002c8000003c800000000000000000000000");
+
+        get_cosmos_address(&mut device, "cosmos", "m/44'/118'/0'/0/0", true).unwrap();
+        assert_eq!(
+            hex::encode(&device.commands[1]),
+            "550401001b06636f736d6f732c00008076000080000000800000000000000000"
+        );
+
+        get_solana_address(&mut device, "m/44'/501'/0'/0'", false).unwrap();
+        assert_eq!(hex::encode(&device.commands[2]), "e005000011048000002c800001f58000000080000000");
+    }
+
+    #[test]
+    fn test_verify_against_device() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let mut device = MockLedger::new(MNEMONIC);
+
+        for chain in [Chain::Evm, Chain::Cosmos(CosmosChain::CosmosHub), Chain::Cosmos(CosmosChain::Osmosis), Chain::Solana] {
+            let address = verify_against_device(&mut device, &wallet, chain, 0, 1).unwrap();
+            println!("{:<10} {:<22} {}", chain.id(), address.path, address.address);
+        }
+        let evm = get_address(&mut device, Chain::Evm, "m/44'/60'/0'/0/0", false).unwrap();
+        assert_eq!(evm.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+
+        // 다른 니모닉의 기기
+        let mut other = MockLedger::new("legal winner thank year wave sausage worth useful legal winner thank yellow");
+        let err = verify_against_device(&mut other, &wallet, Chain::Evm, 0, 0).unwrap_err();
+        println!("{}", err);
+        assert!(matches!(err, Error::Device(_)));
+    }
+
+    #[test]
+    fn test_device_errors() {
+        let mut device = MockLedger::new(MNEMONIC);
+
+        device.status = 0x6985;
+        let err = get_evm_address(&mut device, "m/44'/60'/0'/0/0", true).unwrap_err();
+        println!("{}", err);
+        assert_eq!(err, Error::Device("기기 오류 0x6985: 사용자가 거부했습니다".to_string()));
+
+        // Cosmos 앱은 5단계 경로만 받음
+        device.status = SW_OK;
+        assert!(matches!(get_cosmos_address(&mut device, "cosmos", "m/44'/118'/0'", false), Err(Error::InvalidPath { .. })));
+        assert!(matches!(get_address(&mut device, Chain::Bitcoin, "m/84'/0'/0'/0/0", false), Err(Error::Chain(_))));
+
+        // 응답 길이 부족
+        let mut empty = |_: &[u8]| Ok(vec![0x90, 0x00]);
+        assert!(matches!(get_solana_address(&mut empty, "m/44'/501'/0'/0'", false), Err(Error::Device(_))));
+    }
+}
@@ -8,10 +8,39 @@
 //! - Solana (Ed25519)
 //! - Sui (Ed25519 + Blake2b)
 //! - Cosmos (secp256k1 + Bech32)
+//! - Substrate (Ed25519 + SS58)
 
+pub mod error;
 pub mod bip39;
 pub mod bip32;
+pub mod slip10;
+pub mod slip39;
+pub mod shamir;
+pub mod recovery;
+pub mod correction;
+pub mod entropy;
+pub mod hash;
+pub mod ecdsa;
+pub mod schnorr;
+pub mod signer;
+pub mod chain;
+pub mod chain_config;
+pub mod caip;
+pub mod account;
+pub mod account_iter;
+pub mod secret;
+mod trace;
+pub mod keystore;
+pub mod eip2335;
+pub mod threshold;
+#[cfg(feature = "age")]
+pub mod age_export;
+#[cfg(feature = "keyring")]
+pub mod os_keyring;
+#[cfg(feature = "serde")]
+pub mod serde_support;
 
+pub mod encoding;
 pub mod utils;
 
 pub mod bitcoin;
@@ -19,3 +48,25 @@ pub mod evm;
 pub mod solana;
 pub mod sui;
 pub mod cosmos;
+pub mod substrate;
+pub mod xrp;
+
+pub mod network;
+pub mod wallet;
+pub mod wallet_preset;
+pub mod discovery;
+pub mod export;
+pub mod offline;
+pub mod detect;
+pub mod validate;
+pub mod uri;
+#[cfg(feature = "qr")]
+pub mod qr;
+#[cfg(feature = "ledger")]
+pub mod ledger;
+#[cfg(any(feature = "rpc-cosmos", feature = "rpc-evm", feature = "rpc-solana", feature = "rpc-sui"))]
+pub mod rpc;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+
+pub use error::{Error, Result};
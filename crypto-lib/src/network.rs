@@ -0,0 +1,136 @@
+//! Network Selection
+//!
+//! 체인 공통 네트워크 구분 (기본값: 메인넷)
+//! 테스트 네트워크 형식이 따로 있는 체인만 결과가 달라지고, 나머지 체인은 무시함
+//!
+//! | 체인 | Mainnet | Testnet | Devnet |
+//! |------|---------|---------|--------|
+//! | Bitcoin | `bc1`, m/84'/0' | `tb1`, m/84'/1' | `bcrt1` (Regtest), m/84'/1' |
+//! | Solana | 클러스터 `mainnet-beta` | `testnet` | `devnet` |
+//! | Sui | 네트워크 `mainnet` | `testnet` | `devnet` |
+//! | EVM, Cosmos SDK, Substrate | 구분 없음 | 구분 없음 | 구분 없음 |
+//!
+//! Solana, Sui 주소는 네트워크와 관계없이 같으므로 클러스터 이름은 내보내기(`export`)의 힌트로만 사용
+//! EVM은 chain id, Cosmos SDK는 체인 HRP, Substrate는 SS58 접두사로 네트워크를 구분
+//!
+//! ```
+//! use crypto_lib::network::Network;
+//! use crypto_lib::wallet::{CachedWallet, Chain};
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let wallet = CachedWallet::from_mnemonic(mnemonic, "").unwrap().with_network(Network::Testnet);
+//!
+//! let account = wallet.derive(Chain::Bitcoin, 0, 0).unwrap();
+//! assert_eq!(account.path, "m/84'/1'/0'/0/0");
+//! assert_eq!(account.address, "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::bitcoin;
+use crate::wallet::Chain;
+use crate::error::{Error, Result};
+
+/// 네트워크
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Network {
+    /// 메인넷
+    #[default]
+    Mainnet,
+    /// 공개 테스트넷
+    Testnet,
+    /// 개발용 네트워크 (Bitcoin은 Regtest)
+    Devnet,
+}
+
+impl Network {
+    /// 모든 네트워크
+    pub const ALL: [Network; 3] = [Network::Mainnet, Network::Testnet, Network::Devnet];
+
+    /// 이름 ("mainnet", "testnet", "devnet")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+            Network::Devnet => "devnet",
+        }
+    }
+
+    /// 메인넷 여부
+    pub fn is_mainnet(&self) -> bool {
+        *self == Network::Mainnet
+    }
+
+    /// Bitcoin 네트워크 (Devnet → Regtest)
+    pub fn bitcoin(&self) -> bitcoin::Network {
+        match self {
+            Network::Mainnet => bitcoin::Network::Mainnet,
+            Network::Testnet => bitcoin::Network::Testnet,
+            Network::Devnet => bitcoin::Network::Regtest,
+        }
+    }
+
+    /// 체인별 클러스터/네트워크 이름 (Solana, Sui만, 나머지는 None)
+    pub fn cluster(&self, chain: Chain) -> Option<&'static str> {
+        match chain {
+            Chain::Solana => Some(match self {
+                Network::Mainnet => "mainnet-beta",
+                Network::Testnet => "testnet",
+                Network::Devnet => "devnet",
+            }),
+            Chain::Sui => Some(self.name()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    /// 이름 파싱 (대소문자 무시, Solana "mainnet-beta", Bitcoin "regtest"도 허용)
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" | "main" | "mainnet-beta" => Ok(Network::Mainnet),
+            "testnet" | "test" => Ok(Network::Testnet),
+            "devnet" | "regtest" | "localnet" => Ok(Network::Devnet),
+            _ => Err(Error::InvalidInput(format!("알 수 없는 네트워크: {}", s))),
+        }
+    }
+}
+
+impl From<Network> for bitcoin::Network {
+    fn from(network: Network) -> Self {
+        network.bitcoin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        for network in Network::ALL {
+            assert_eq!(network.name().parse::<Network>(), Ok(network));
+        }
+        assert_eq!("mainnet-beta".parse::<Network>(), Ok(Network::Mainnet));
+        assert_eq!("Regtest".parse::<Network>(), Ok(Network::Devnet));
+        assert!("signet".parse::<Network>().is_err());
+        assert_eq!(Network::default(), Network::Mainnet);
+    }
+
+    #[test]
+    fn test_chain_specifics() {
+        assert_eq!(bitcoin::Network::from(Network::Devnet), bitcoin::Network::Regtest);
+        assert_eq!(Network::Mainnet.cluster(Chain::Solana), Some("mainnet-beta"));
+        assert_eq!(Network::Devnet.cluster(Chain::Sui), Some("devnet"));
+        assert_eq!(Network::Testnet.cluster(Chain::Evm), None);
+    }
+}
@@ -0,0 +1,316 @@
+//! Offline Signing Workflow
+//!
+//! 온라인 기기가 미서명 트랜잭션을 봉투(JSON)로 내보내고, 에어갭 기기가 니모닉으로 서명해
+//! 브로드캐스트할 트랜잭션을 다시 봉투로 돌려줌 (파일, QR, USB 등으로 옮김)
+//!
+//! ## 흐름
+//! ```text
+//! 온라인:  트랜잭션 생성 → UnsignedTransaction::to_json()  ──┐
+//! 에어갭:  from_json() → sign(&wallet) → SignedTransaction::to_json()  ──┐
+//! 온라인:  SignedTransaction::from_json() → broadcast_tx() → 노드에 전송
+//! ```
+//!
+//! ## 체인별 payload
+//!
+//! | 체인 | payload | 서명된 트랜잭션 | 브로드캐스트 |
+//! |------|---------|----------------|-------------|
+//! | Cosmos SDK | protobuf `SignDoc` | `TxRaw` | base64 → `/cosmos/tx/v1beta1/txs` |
+//! | EVM | 미서명 트랜잭션 (legacy, EIP-2930, EIP-1559) | 서명된 트랜잭션 | 0x hex → `eth_sendRawTransaction` |
+//! | Solana | 직렬화된 메시지 | 와이어 포맷 트랜잭션 | base64 → `sendTransaction` |
+//! | Sui | BCS `TransactionData` | `TransactionData` + 직렬화 서명 | base64 → `sui_executeTransactionBlock` |
+//!
+//! ## 봉투 형식
+//! ```json
+//! {"version":1,"chain":"evm","path":"m/44'/60'/0'/0/0","signer":"0x9858...","payload":"<base64>"}
+//! {"version":1,"chain":"evm","tx":"0x02f8...","signatures":[]}
+//! ```
+//! - `chain`: 레지스트리 id (Cosmos SDK 체인은 "cosmos", "osmosis" 등 체인별 id)
+//! - `signer`(선택): 서명 전에 경로에서 도출한 주소와 비교 → 다른 계정으로 서명하는 실수 방지
+//! - `signatures`: 트랜잭션과 따로 보내는 서명 (Sui만, 나머지는 `tx`에 포함)
+//!
+//! ```
+//! use crypto_lib::offline::{SignedTransaction, UnsignedTransaction};
+//! use crypto_lib::wallet::{CachedWallet, Chain};
+//!
+//! // 온라인 기기: EIP-155 미서명 트랜잭션 (nonce 9, 1 ETH → 0x3535...35)
+//! let unsigned_tx = hex::decode("ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080").unwrap();
+//! let request = UnsignedTransaction::new(Chain::Evm, unsigned_tx, "m/44'/60'/0'/0/0")
+//!     .with_signer("0x9858EfFD232B4033E47d90003D41EC34EcaEda94")
+//!     .to_json();
+//!
+//! // 에어갭 기기
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let wallet = CachedWallet::from_mnemonic(mnemonic, "").unwrap();
+//! let response = UnsignedTransaction::from_json(&request).unwrap().sign(&wallet).unwrap().to_json();
+//!
+//! // 온라인 기기: eth_sendRawTransaction에 전달
+//! let signed = SignedTransaction::from_json(&response).unwrap();
+//! assert!(signed.broadcast_tx().starts_with("0xf86c"));
+//! ```
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::cosmos::transaction::SignDoc;
+use crate::cosmos::CosmosAccount;
+use crate::evm::EvmAccount;
+use crate::solana::SolanaAccount;
+use crate::sui::SuiAccount;
+use crate::wallet::{CachedWallet, Chain};
+use crate::error::{Error, Result};
+
+/// 봉투 형식 버전
+pub const ENVELOPE_VERSION: u32 = 1;
+
+/// 에어갭 기기로 보낼 미서명 트랜잭션
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedTransaction {
+    /// 체인
+    pub chain: Chain,
+    /// 서명할 바이트 (체인별 형식은 모듈 문서 참고)
+    pub payload: Vec<u8>,
+    /// 서명 키의 도출 경로
+    pub path: String,
+    /// 기대하는 서명자 주소 (있으면 서명 전에 확인)
+    pub signer: Option<String>,
+}
+
+/// 온라인 기기로 돌려보낼 서명된 트랜잭션
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedTransaction {
+    /// 체인
+    pub chain: Chain,
+    /// 브로드캐스트할 트랜잭션 바이트
+    pub tx_bytes: Vec<u8>,
+    /// 트랜잭션과 따로 보내는 직렬화 서명 (Sui)
+    pub signatures: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UnsignedEnvelope {
+    version: u32,
+    chain: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signer: Option<String>,
+    payload: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignedEnvelope {
+    version: u32,
+    chain: String,
+    tx: String,
+    #[serde(default)]
+    signatures: Vec<String>,
+}
+
+impl UnsignedTransaction {
+    /// 미서명 트랜잭션 생성
+    pub fn new(chain: Chain, payload: Vec<u8>, path: &str) -> Self {
+        UnsignedTransaction { chain, payload, path: path.to_string(), signer: None }
+    }
+
+    /// 기대하는 서명자 주소 지정
+    pub fn with_signer(mut self, address: &str) -> Self {
+        self.signer = Some(address.to_string());
+        self
+    }
+
+    /// 봉투 JSON
+    pub fn to_json(&self) -> String {
+        let envelope = UnsignedEnvelope {
+            version: ENVELOPE_VERSION,
+            chain: self.chain.id().to_string(),
+            path: self.path.clone(),
+            signer: self.signer.clone(),
+            payload: BASE64.encode(&self.payload),
+        };
+        serde_json::to_string(&envelope).expect("문자열 필드만 있는 구조체")
+    }
+
+    /// 봉투 JSON 파싱 (버전, 체인, base64 확인)
+    pub fn from_json(json: &str) -> Result<Self> {
+        let envelope: UnsignedEnvelope =
+            serde_json::from_str(json).map_err(|e| Error::Transaction(format!("미서명 트랜잭션 JSON 파싱 실패: {}", e)))?;
+        check_version(envelope.version)?;
+
+        Ok(UnsignedTransaction {
+            chain: parse_chain(&envelope.chain)?,
+            payload: decode_base64(&envelope.payload)?,
+            path: envelope.path,
+            signer: envelope.signer,
+        })
+    }
+
+    /// 경로의 키로 서명
+    pub fn sign(&self, wallet: &CachedWallet) -> Result<SignedTransaction> {
+        let key = wallet.derive_path(self.chain, &self.path)?;
+        if let Some(expected) = &self.signer {
+            // EVM 주소는 대소문자(EIP-55)만 다를 수 있음
+            if !key.address.eq_ignore_ascii_case(expected) {
+                return Err(Error::Transaction(format!("{}의 주소 {}가 서명자 {}와 다릅니다", self.path, key.address, expected)));
+            }
+        }
+
+        let (tx_bytes, signatures) = match self.chain {
+            Chain::Cosmos(_) => {
+                let sign_doc = SignDoc::from_bytes(&self.payload)?;
+                (CosmosAccount::from_private_key(*key.private_key)?.sign_transaction(&sign_doc)?, Vec::new())
+            }
+            Chain::Evm => (EvmAccount::from_private_key(*key.private_key)?.sign_transaction(&self.payload)?, Vec::new()),
+            Chain::Solana => (SolanaAccount::from_private_key(*key.private_key)?.sign_transaction_message(&self.payload)?, Vec::new()),
+            Chain::Sui => {
+                let signature = SuiAccount::from_private_key(*key.private_key)?.sign_transaction(&self.payload);
+                (self.payload.clone(), vec![signature])
+            }
+            chain => return Err(Error::Chain(format!("오프라인 서명을 지원하지 않는 체인입니다: {}", chain.id()))),
+        };
+
+        Ok(SignedTransaction { chain: self.chain, tx_bytes, signatures })
+    }
+}
+
+impl SignedTransaction {
+    /// 노드에 보낼 인코딩 (EVM은 0x hex, 나머지는 base64)
+    pub fn broadcast_tx(&self) -> String {
+        match self.chain {
+            Chain::Evm => format!("0x{}", hex::encode(&self.tx_bytes)),
+            _ => BASE64.encode(&self.tx_bytes),
+        }
+    }
+
+    /// 봉투 JSON
+    pub fn to_json(&self) -> String {
+        let envelope = SignedEnvelope {
+            version: ENVELOPE_VERSION,
+            chain: self.chain.id().to_string(),
+            tx: self.broadcast_tx(),
+            signatures: self.signatures.clone(),
+        };
+        serde_json::to_string(&envelope).expect("문자열 필드만 있는 구조체")
+    }
+
+    /// 봉투 JSON 파싱
+    pub fn from_json(json: &str) -> Result<Self> {
+        let envelope: SignedEnvelope =
+            serde_json::from_str(json).map_err(|e| Error::Transaction(format!("서명된 트랜잭션 JSON 파싱 실패: {}", e)))?;
+        check_version(envelope.version)?;
+
+        let chain = parse_chain(&envelope.chain)?;
+        let tx_bytes = match chain {
+            Chain::Evm => {
+                let digits = envelope.tx.strip_prefix("0x").unwrap_or(&envelope.tx);
+                hex::decode(digits).map_err(|e| Error::Transaction(format!("유효하지 않은 hex: {}", e)))?
+            }
+            _ => decode_base64(&envelope.tx)?,
+        };
+        Ok(SignedTransaction { chain, tx_bytes, signatures: envelope.signatures })
+    }
+}
+
+fn check_version(version: u32) -> Result<()> {
+    if version != ENVELOPE_VERSION {
+        return Err(Error::Transaction(format!("지원하지 않는 봉투 버전: {}", version)));
+    }
+    Ok(())
+}
+
+fn parse_chain(id: &str) -> Result<Chain> {
+    Chain::from_id(id).ok_or_else(|| Error::Chain(format!("알 수 없는 체인: {}", id)))
+}
+
+fn decode_base64(encoded: &str) -> Result<Vec<u8>> {
+    BASE64.decode(encoded.trim()).map_err(|e| Error::Transaction(format!("유효하지 않은 base64: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::CosmosChain;
+    use crate::solana::signing::{transaction_message, verify_message};
+    use crate::sui::signing::verify_transaction;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn wallet() -> CachedWallet {
+        CachedWallet::from_mnemonic(MNEMONIC, "").unwrap()
+    }
+
+    #[test]
+    fn test_envelope_roundtrip() {
+        let unsigned = UnsignedTransaction::new(Chain::Cosmos(CosmosChain::Osmosis), vec![1, 2, 3], "m/44'/118'/0'/0/0");
+        let json = unsigned.to_json();
+        assert_eq!(json, r#"{"version":1,"chain":"osmosis","path":"m/44'/118'/0'/0/0","payload":"AQID"}"#);
+        assert_eq!(UnsignedTransaction::from_json(&json).unwrap(), unsigned);
+
+        let signed = SignedTransaction { chain: Chain::Sui, tx_bytes: vec![4, 5], signatures: vec!["AA==".to_string()] };
+        assert_eq!(SignedTransaction::from_json(&signed.to_json()).unwrap(), signed);
+
+        assert!(UnsignedTransaction::from_json(r#"{"version":2,"chain":"evm","path":"m","payload":""}"#).is_err());
+        assert!(UnsignedTransaction::from_json(r#"{"version":1,"chain":"nope","path":"m","payload":""}"#).is_err());
+        assert!(UnsignedTransaction::from_json(r#"{"version":1,"chain":"evm","path":"m","payload":"!!"}"#).is_err());
+    }
+
+    #[test]
+    fn test_sign_evm() {
+        let unsigned_tx = hex::decode("ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080").unwrap();
+        let unsigned = UnsignedTransaction::new(Chain::Evm, unsigned_tx.clone(), "m/44'/60'/0'/0/0")
+            .with_signer("0x9858effd232b4033e47d90003d41ec34ecaeda94");
+
+        let signed = unsigned.sign(&wallet()).unwrap();
+        let account = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(signed.tx_bytes, account.sign_transaction(&unsigned_tx).unwrap());
+        assert!(signed.signatures.is_empty());
+        assert_eq!(SignedTransaction::from_json(&signed.to_json()).unwrap(), signed);
+    }
+
+    #[test]
+    fn test_sign_cosmos() {
+        let sign_doc = SignDoc { body_bytes: vec![0x0a, 0x00], auth_info_bytes: vec![0x12, 0x00], chain_id: "osmosis-1".to_string(), account_number: 7 };
+        let unsigned = UnsignedTransaction::new(Chain::Cosmos(CosmosChain::Osmosis), sign_doc.to_bytes(), "m/44'/118'/0'/0/0");
+
+        let signed = unsigned.sign(&wallet()).unwrap();
+        let account = CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(signed.tx_bytes, account.sign_transaction(&sign_doc).unwrap());
+    }
+
+    #[test]
+    fn test_sign_solana() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let mut message = vec![1, 0, 1, 2];
+        message.extend_from_slice(&account.public_key);
+        message.extend_from_slice(&[0u8; 32]);
+        message.extend_from_slice(&[9u8; 32]);
+        message.push(0);
+
+        let signed = UnsignedTransaction::new(Chain::Solana, message.clone(), "m/44'/501'/0'/0'").sign(&wallet()).unwrap();
+        assert_eq!(transaction_message(&signed.tx_bytes).unwrap(), message);
+
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(&signed.tx_bytes[1..65]);
+        verify_message(&account.address(), &message, &signature).unwrap();
+    }
+
+    #[test]
+    fn test_sign_sui() {
+        let tx_bytes = vec![0u8; 40];
+        let signed = UnsignedTransaction::new(Chain::Sui, tx_bytes.clone(), "m/44'/784'/0'/0'/0'").sign(&wallet()).unwrap();
+        assert_eq!(signed.tx_bytes, tx_bytes);
+        assert_eq!(signed.signatures.len(), 1);
+
+        let signer = verify_transaction(&tx_bytes, &signed.signatures[0]).unwrap();
+        assert_eq!(signer, SuiAccount::from_mnemonic(MNEMONIC, "").unwrap().address());
+    }
+
+    #[test]
+    fn test_sign_rejects_wrong_signer() {
+        let unsigned = UnsignedTransaction::new(Chain::Sui, vec![0u8; 40], "m/44'/784'/1'/0'/0'")
+            .with_signer(&SuiAccount::from_mnemonic(MNEMONIC, "").unwrap().address());
+        assert!(matches!(unsigned.sign(&wallet()), Err(Error::Transaction(_))));
+
+        let bitcoin = UnsignedTransaction::new(Chain::Bitcoin, vec![0u8; 10], "m/84'/0'/0'/0/0");
+        assert!(matches!(bitcoin.sign(&wallet()), Err(Error::Chain(_))));
+    }
+}
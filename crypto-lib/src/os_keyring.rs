@@ -0,0 +1,123 @@
+//! OS 키링 저장소 (feature = "keyring")
+//!
+//! 암호화된 시드/니모닉을 운영체제 자격 증명 저장소에 지갑 이름으로 저장
+//! - macOS: Keychain
+//! - Windows: Credential Manager
+//! - Linux: Secret Service (GNOME Keyring, KWallet)
+//!
+//! ## 저장 형식
+//! 값은 `keystore` 모듈 형식(Argon2id + XChaCha20-Poly1305)으로 암호화한 바이트
+//! → 키링이 잠금 해제된 상태에서도 비밀번호 없이는 시드를 읽을 수 없음
+//!
+//! 키링 항목: 서비스 = `service`, 계정 = 지갑 이름
+
+use keyring::Entry;
+
+use crate::keystore::{self, KeystoreParams, KeystoreSecret};
+use crate::error::{Error, Result};
+
+/// 기본 키링 서비스 이름
+pub const DEFAULT_SERVICE: &str = "crypto-lib";
+
+/// OS 키링의 지갑 저장소
+#[derive(Debug, Clone)]
+pub struct WalletKeyring {
+    /// 키링 서비스 이름 (앱별로 구분)
+    pub service: String,
+}
+
+impl Default for WalletKeyring {
+    fn default() -> Self {
+        Self::new(DEFAULT_SERVICE)
+    }
+}
+
+impl WalletKeyring {
+    /// 서비스 이름으로 저장소 생성
+    pub fn new(service: &str) -> Self {
+        WalletKeyring { service: service.to_string() }
+    }
+
+    /// 비밀값을 암호화해 저장 (같은 이름이 있으면 덮어씀)
+    pub fn save(&self, wallet_name: &str, secret: &KeystoreSecret, password: &str, params: KeystoreParams) -> Result<()> {
+        save_to_entry(&self.entry(wallet_name)?, secret, password, params)
+    }
+
+    /// 저장된 비밀값을 읽어 복호화
+    pub fn load(&self, wallet_name: &str, password: &str) -> Result<KeystoreSecret> {
+        load_from_entry(&self.entry(wallet_name)?, wallet_name, password)
+    }
+
+    /// 지갑이 저장되어 있는지 확인
+    pub fn contains(&self, wallet_name: &str) -> Result<bool> {
+        match self.entry(wallet_name)?.get_secret() {
+            Ok(_) => Ok(true),
+            Err(keyring::Error::NoEntry) => Ok(false),
+            Err(e) => Err(Error::Keystore(format!("키링 읽기 실패: {}", e))),
+        }
+    }
+
+    /// 지갑 삭제
+    pub fn delete(&self, wallet_name: &str) -> Result<()> {
+        self.entry(wallet_name)?.delete_credential().map_err(|e| match e {
+            keyring::Error::NoEntry => Error::Keystore(format!("'{}' 지갑이 키링에 없습니다", wallet_name)),
+            e => Error::Keystore(format!("키링 삭제 실패: {}", e)),
+        })
+    }
+
+    fn entry(&self, wallet_name: &str) -> Result<Entry> {
+        if wallet_name.is_empty() {
+            return Err(Error::Keystore("지갑 이름이 비어 있습니다".to_string()));
+        }
+        Entry::new(&self.service, wallet_name).map_err(|e| Error::Keystore(format!("키링 항목 생성 실패: {}", e)))
+    }
+}
+
+fn save_to_entry(entry: &Entry, secret: &KeystoreSecret, password: &str, params: KeystoreParams) -> Result<()> {
+    let data = keystore::encrypt(secret, password, params)?;
+    entry.set_secret(&data).map_err(|e| Error::Keystore(format!("키링 저장 실패: {}", e)))
+}
+
+fn load_from_entry(entry: &Entry, wallet_name: &str, password: &str) -> Result<KeystoreSecret> {
+    let data = entry.get_secret().map_err(|e| match e {
+        keyring::Error::NoEntry => Error::Keystore(format!("'{}' 지갑이 키링에 없습니다", wallet_name)),
+        e => Error::Keystore(format!("키링 읽기 실패: {}", e)),
+    })?;
+    keystore::decrypt(&data, password)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAST: KeystoreParams = KeystoreParams { m_cost: 256, t_cost: 1, p_cost: 1 };
+
+    // 실제 OS 키링 대신 keyring의 메모리 저장소 사용
+    fn mock_entry() -> Entry {
+        let credential = keyring::mock::default_credential_builder().build(None, DEFAULT_SERVICE, "test-wallet").unwrap();
+        Entry::new_with_credential(credential)
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let entry = mock_entry();
+        let secret = KeystoreSecret::Mnemonic(zeroize::Zeroizing::new(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+        ));
+
+        assert!(load_from_entry(&entry, "test-wallet", "pw").unwrap_err().to_string().contains("없습니다"));
+
+        save_to_entry(&entry, &secret, "pw", FAST).unwrap();
+        let stored = entry.get_secret().unwrap();
+        println!("키링에 저장된 바이트: {}", stored.len());
+        assert_eq!(&stored[..4], keystore::MAGIC);
+
+        assert_eq!(load_from_entry(&entry, "test-wallet", "pw").unwrap(), secret);
+        assert!(matches!(load_from_entry(&entry, "test-wallet", "wrong"), Err(Error::Decryption(_))));
+    }
+
+    #[test]
+    fn test_empty_wallet_name() {
+        assert!(WalletKeyring::default().load("", "pw").is_err());
+    }
+}
@@ -0,0 +1,344 @@
+//! QR Code Output (feature = "qr")
+//!
+//! 주소, 결제 URI, SeedQR 백업을 QR 코드로 출력
+//!
+//! ## 출력 형식
+//!
+//! | 메서드 | 결과 | 용도 |
+//! |--------|------|------|
+//! | `to_svg()` | SVG 문자열 | 웹 UI, 인쇄 |
+//! | `to_png(module_size)` | PNG 바이트 (1비트 흑백) | 파일 저장 |
+//! | `to_terminal()` | 유니코드 블록 문자열 (`▀▄█`) | CLI 출력 |
+//!
+//! 모든 출력에 4모듈 여백(quiet zone) 포함
+//!
+//! ## 주소 QR
+//! Bech32 주소는 대문자로 바꿔 영숫자 모드로 인코딩 (BIP-173 권장, QR 크기가 작아짐)
+//!
+//! ## SeedQR (SeedSigner 형식)
+//! - **Standard SeedQR**: 단어 인덱스를 4자리 10진수로 이어붙여 숫자 모드 (12단어 25×25, 24단어 29×29)
+//! - **CompactSeedQR**: 엔트로피 바이트를 그대로 바이트 모드 (12단어 21×21, 24단어 25×25)
+//!
+//! SeedQR은 니모닉 그 자체이므로 화면/파일에 남지 않게 주의
+//!
+//! ```
+//! use crypto_lib::qr::Qr;
+//!
+//! let qr = Qr::address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu").unwrap();
+//! assert!(qr.to_svg().starts_with("<?xml"));
+//! assert!(qr.to_png(4).starts_with(b"\x89PNG"));
+//! println!("{}", qr.to_terminal());
+//! ```
+
+use qrcode::bits::Bits;
+use qrcode::render::{svg, unicode};
+use qrcode::{Color, EcLevel, QrCode, Version};
+use zeroize::Zeroizing;
+
+use crate::bip39::{calculate_checksum, entropy_to_indices, entropy_to_mnemonic, mnemonic_to_entropy_in, Language};
+use crate::encoding::bech32::decode_bech32;
+use crate::uri::PaymentRequest;
+use crate::error::{Error, Result};
+
+/// 여백 (모듈 수, QR 표준 최소값)
+const QUIET_ZONE: usize = 4;
+
+/// QR 코드
+pub struct Qr {
+    code: QrCode,
+}
+
+impl Qr {
+    /// 임의 데이터 (인코딩 모드 자동 선택, 오류 정정 M)
+    pub fn new(data: impl AsRef<[u8]>) -> Result<Self> {
+        QrCode::with_error_correction_level(data, EcLevel::M).map(|code| Qr { code }).map_err(qr_error)
+    }
+
+    /// 주소 QR (Bech32 주소는 대문자 영숫자 모드)
+    pub fn address(address: &str) -> Result<Self> {
+        let address = address.trim();
+        if decode_bech32(address).is_ok() {
+            return Qr::new(address.to_ascii_uppercase());
+        }
+        Qr::new(address)
+    }
+
+    /// 결제 URI QR (BIP-21, EIP-681, Solana Pay)
+    pub fn payment(request: &PaymentRequest) -> Result<Self> {
+        Qr::new(request.to_uri()?)
+    }
+
+    /// Standard SeedQR (영어 12/24단어)
+    pub fn seed_qr(mnemonic: &str) -> Result<Self> {
+        let digits = seed_qr_digits(mnemonic)?;
+        let version = if digits.len() == 48 { 2 } else { 3 };
+
+        let mut bits = Bits::new(Version::Normal(version));
+        bits.push_numeric_data(digits.as_bytes()).map_err(qr_error)?;
+        Qr::from_bits(bits)
+    }
+
+    /// CompactSeedQR (영어 12/24단어)
+    pub fn compact_seed_qr(mnemonic: &str) -> Result<Self> {
+        let entropy = compact_seed_qr_bytes(mnemonic)?;
+        let version = if entropy.len() == 16 { 1 } else { 2 };
+
+        let mut bits = Bits::new(Version::Normal(version));
+        bits.push_byte_data(&entropy).map_err(qr_error)?;
+        Qr::from_bits(bits)
+    }
+
+    /// 한 변의 모듈 수 (여백 제외)
+    pub fn width(&self) -> usize {
+        self.code.width()
+    }
+
+    /// SVG 문자열
+    pub fn to_svg(&self) -> String {
+        self.code
+            .render::<svg::Color<'_>>()
+            .min_dimensions(200, 200)
+            .dark_color(svg::Color("#000000"))
+            .light_color(svg::Color("#ffffff"))
+            .build()
+    }
+
+    /// PNG (1비트 흑백, 모듈 하나가 `module_size` × `module_size` 픽셀)
+    pub fn to_png(&self, module_size: u32) -> Vec<u8> {
+        let module_size = module_size.max(1) as usize;
+        let modules = self.width() + 2 * QUIET_ZONE;
+        let side = modules * module_size;
+        let row_bytes = side.div_ceil(8);
+
+        // 각 행: 필터 바이트(0) + 1비트 픽셀 (1 = 흰색)
+        let mut raw = Vec::with_capacity(side * (row_bytes + 1));
+        for y in 0..side {
+            raw.push(0);
+            let mut row = vec![0u8; row_bytes];
+            for x in 0..side {
+                if !self.is_dark(x / module_size, y / module_size) {
+                    row[x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+            raw.extend_from_slice(&row);
+        }
+
+        encode_png(side as u32, &raw)
+    }
+
+    /// 터미널 출력용 문자열 (한 글자에 모듈 2행, 밝은 배경 기준)
+    pub fn to_terminal(&self) -> String {
+        self.code
+            .render::<unicode::Dense1x2>()
+            .dark_color(unicode::Dense1x2::Dark)
+            .light_color(unicode::Dense1x2::Light)
+            .build()
+    }
+
+    fn from_bits(mut bits: Bits) -> Result<Self> {
+        bits.push_terminator(EcLevel::L).map_err(qr_error)?;
+        QrCode::with_bits(bits, EcLevel::L).map(|code| Qr { code }).map_err(qr_error)
+    }
+
+    /// 여백 포함 좌표의 모듈 색
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        let width = self.width();
+        let (Some(x), Some(y)) = (x.checked_sub(QUIET_ZONE), y.checked_sub(QUIET_ZONE)) else {
+            return false;
+        };
+        x < width && y < width && self.code[(x, y)] == Color::Dark
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// SeedQR 페이로드
+// ═══════════════════════════════════════════════════════════════
+
+/// Standard SeedQR 숫자열 (단어 인덱스 4자리씩, 12단어 48자리 / 24단어 96자리)
+pub fn seed_qr_digits(mnemonic: &str) -> Result<Zeroizing<String>> {
+    let entropy = seed_entropy(mnemonic)?;
+    let indices = Zeroizing::new(entropy_to_indices(&entropy, calculate_checksum(&entropy)));
+    Ok(Zeroizing::new(indices.iter().map(|index| format!("{:04}", index)).collect()))
+}
+
+/// CompactSeedQR 바이트 (엔트로피 16/32바이트, 체크섬 제외)
+pub fn compact_seed_qr_bytes(mnemonic: &str) -> Result<Zeroizing<Vec<u8>>> {
+    seed_entropy(mnemonic)
+}
+
+/// Standard SeedQR 숫자열 → 니모닉
+pub fn seed_qr_to_mnemonic(digits: &str) -> Result<Zeroizing<String>> {
+    if !matches!(digits.len(), 48 | 96) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidInput(format!("SeedQR은 48자리 또는 96자리 숫자여야 합니다, 입력: {}자", digits.len())));
+    }
+
+    let words = Language::English.words();
+    let mnemonic = digits
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| {
+            let index: usize = std::str::from_utf8(chunk).expect("ASCII 숫자").parse().expect("4자리 숫자");
+            words.get(index).copied().ok_or_else(|| Error::InvalidInput(format!("단어 인덱스가 2047을 넘습니다: {}", index)))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(" ");
+    let mnemonic = Zeroizing::new(mnemonic);
+
+    // 체크섬 검증
+    mnemonic_to_entropy_in(&mnemonic, Language::English)?;
+    Ok(mnemonic)
+}
+
+/// CompactSeedQR 바이트 → 니모닉
+pub fn compact_seed_qr_to_mnemonic(entropy: &[u8]) -> Result<Zeroizing<String>> {
+    if !matches!(entropy.len(), 16 | 32) {
+        return Err(Error::InvalidInput(format!("CompactSeedQR은 16바이트 또는 32바이트여야 합니다, 입력: {}바이트", entropy.len())));
+    }
+    Ok(Zeroizing::new(entropy_to_mnemonic(entropy)?))
+}
+
+/// 영어 12/24단어 니모닉 → 엔트로피
+fn seed_entropy(mnemonic: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let entropy = Zeroizing::new(mnemonic_to_entropy_in(mnemonic, Language::English)?);
+    if !matches!(entropy.len(), 16 | 32) {
+        return Err(Error::InvalidInput("SeedQR은 12단어 또는 24단어 니모닉만 지원합니다".to_string()));
+    }
+    Ok(entropy)
+}
+
+fn qr_error(e: qrcode::types::QrError) -> Error {
+    Error::InvalidInput(format!("QR 코드 생성 실패: {}", e))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// PNG (무압축 deflate)
+// ═══════════════════════════════════════════════════════════════
+
+/// 1비트 흑백 PNG (`raw`: 필터 바이트가 붙은 행 데이터)
+fn encode_png(side: u32, raw: &[u8]) -> Vec<u8> {
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&side.to_be_bytes());
+    header.extend_from_slice(&side.to_be_bytes());
+    // 비트 깊이 1, 흑백(0), 압축/필터/인터레이스 0
+    header.extend_from_slice(&[1, 0, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// 길이 + 타입 + 데이터 + CRC-32(타입 || 데이터)
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// zlib 스트림 (deflate 무압축 블록, 블록당 최대 65535바이트)
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xFFFF).peekable();
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        out.push(u8::from(blocks.peek().is_none()));
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uri::PaymentScheme;
+
+    const MNEMONIC_12: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_seed_qr_payloads() {
+        // abandon = 0, about = 3
+        let digits = seed_qr_digits(MNEMONIC_12).unwrap();
+        assert_eq!(digits.as_str(), format!("{}0003", "0000".repeat(11)));
+        assert_eq!(seed_qr_to_mnemonic(&digits).unwrap().as_str(), MNEMONIC_12);
+
+        let compact = compact_seed_qr_bytes(MNEMONIC_12).unwrap();
+        assert_eq!(compact.as_slice(), [0u8; 16]);
+        assert_eq!(compact_seed_qr_to_mnemonic(&compact).unwrap().as_str(), MNEMONIC_12);
+
+        // 체크섬이 틀린 숫자열
+        assert!(seed_qr_to_mnemonic(&"0000".repeat(12)).is_err());
+        assert!(seed_qr_to_mnemonic("0000").is_err());
+        // 15단어는 SeedQR 형식이 없음
+        let fifteen = entropy_to_mnemonic(&[0u8; 20]).unwrap();
+        assert!(seed_qr_digits(&fifteen).is_err());
+    }
+
+    #[test]
+    fn test_seed_qr_sizes() {
+        let twenty_four = entropy_to_mnemonic(&[0xAB; 32]).unwrap();
+
+        assert_eq!(Qr::seed_qr(MNEMONIC_12).unwrap().width(), 25);
+        assert_eq!(Qr::seed_qr(&twenty_four).unwrap().width(), 29);
+        assert_eq!(Qr::compact_seed_qr(MNEMONIC_12).unwrap().width(), 21);
+        assert_eq!(Qr::compact_seed_qr(&twenty_four).unwrap().width(), 25);
+    }
+
+    #[test]
+    fn test_address_and_payment() {
+        let bech32 = Qr::address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu").unwrap();
+        let mixed = Qr::new("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu").unwrap();
+        println!("대문자 {} / 소문자 {}", bech32.width(), mixed.width());
+        assert!(bech32.width() <= mixed.width());
+
+        let request = PaymentRequest::new(PaymentScheme::Solana, "mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN").amount("1");
+        let qr = Qr::payment(&request).unwrap();
+        let terminal = qr.to_terminal();
+        println!("{}", terminal);
+        assert!(terminal.lines().count() >= qr.width() / 2);
+
+        assert!(Qr::new(vec![0u8; 4000]).is_err());
+    }
+
+    #[test]
+    fn test_png_structure() {
+        let qr = Qr::compact_seed_qr(MNEMONIC_12).unwrap();
+        let png = qr.to_png(2);
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        let side = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        assert_eq!(side as usize, (21 + 2 * QUIET_ZONE) * 2);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+
+        // 알려진 CRC-32 / Adler-32 값
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+}
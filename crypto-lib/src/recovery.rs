@@ -0,0 +1,370 @@
+//! Missing-Word Mnemonic Recovery
+//!
+//! 니모닉의 일부 단어(최대 2개)를 잊어버렸을 때 후보를 찾아주는 도구
+//!
+//! ## 사용법
+//! 모르는 단어 자리에 `?`를 넣음
+//! ```text
+//! abandon ? abandon abandon abandon abandon abandon abandon abandon abandon abandon about
+//! ```
+//!
+//! ## 후보 수
+//! 체크섬 때문에 모든 조합이 유효하지는 않음 (12단어 = 체크섬 4비트 → 1/16만 유효)
+//!
+//! | 빠진 단어 | 조합 | 유효 후보 (12단어) |
+//! |----------|------|------------------|
+//! | 1개 | 2048 | 약 128 |
+//! | 2개 | 약 420만 | 약 26만 |
+//!
+//! 후보가 많으면 알고 있는 주소로 좁혀야 함 → 후보마다 PBKDF2(2048회) + 키 도출이
+//! 필요하므로 모든 CPU 코어에서 병렬 처리
+
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::bip39::{mnemonic_to_seed, Language};
+use crate::bitcoin::{BitcoinAccount, Network, BITCOIN_LEGACY_PATH, BITCOIN_SEGWIT_PATH};
+use crate::cosmos::{CosmosAccount, COSMOS_PATH};
+use crate::evm::{EvmAccount, EVM_PATH};
+use crate::solana::{SolanaAccount, SOLANA_PATH};
+use crate::sui::{SuiAccount, SUI_PATH};
+use crate::error::{Error, Result};
+
+/// 모르는 단어 자리 표시
+pub const MISSING_WORD: &str = "?";
+
+/// 한 번에 복구할 수 있는 최대 빈칸 수
+pub const MAX_MISSING_WORDS: usize = 2;
+
+/// 주소 비교에 사용할 체인
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecoveryChain {
+    /// Bitcoin SegWit (bc1... / tb1...)
+    BitcoinSegwit,
+    /// Bitcoin Legacy (1... / m..., n...)
+    BitcoinLegacy,
+    /// EVM (0x...)
+    Evm,
+    /// Solana (Base58)
+    Solana,
+    /// Sui (0x...)
+    Sui,
+    /// Cosmos 계열 (HRP는 대상 주소에서 가져옴)
+    Cosmos,
+}
+
+impl RecoveryChain {
+    /// 기본 도출 경로
+    pub fn default_path(&self) -> &'static str {
+        match self {
+            RecoveryChain::BitcoinSegwit => BITCOIN_SEGWIT_PATH,
+            RecoveryChain::BitcoinLegacy => BITCOIN_LEGACY_PATH,
+            RecoveryChain::Evm => EVM_PATH,
+            RecoveryChain::Solana => SOLANA_PATH,
+            RecoveryChain::Sui => SUI_PATH,
+            RecoveryChain::Cosmos => COSMOS_PATH,
+        }
+    }
+
+    /// 시드에서 주소가 대상 주소와 같은지 검사
+    fn matches(&self, seed: &[u8], path: &str, target: &str) -> Result<bool> {
+        Ok(match self {
+            RecoveryChain::BitcoinSegwit => {
+                let network = match target.rsplit_once('1').map(|(hrp, _)| hrp.to_ascii_lowercase()).as_deref() {
+                    Some("tb") => Network::Testnet,
+                    Some("bcrt") => Network::Regtest,
+                    _ => Network::Mainnet,
+                };
+                BitcoinAccount::from_seed_with_path(seed, path)?.address_segwit(network) == target
+            }
+            RecoveryChain::BitcoinLegacy => {
+                let network = if target.starts_with(['m', 'n']) { Network::Testnet } else { Network::Mainnet };
+                BitcoinAccount::from_seed_with_path(seed, path)?.address_legacy(network) == target
+            }
+            RecoveryChain::Evm => EvmAccount::from_seed_with_path(seed, path)?
+                .address_lowercase()
+                .eq_ignore_ascii_case(target),
+            RecoveryChain::Solana => SolanaAccount::from_seed_with_path(seed, path)?.address() == target,
+            RecoveryChain::Sui => SuiAccount::from_seed_with_path(seed, path)?
+                .address()
+                .eq_ignore_ascii_case(target),
+            RecoveryChain::Cosmos => {
+                let hrp = target.rsplit_once('1').map(|(hrp, _)| hrp).unwrap_or_default();
+                CosmosAccount::from_seed_with_path(seed, path)?.address_with_hrp(hrp) == target
+            }
+        })
+    }
+}
+
+/// 후보를 좁힐 알고 있는 주소
+#[derive(Debug, Clone)]
+pub struct AddressTarget {
+    /// 체인
+    pub chain: RecoveryChain,
+    /// 도출 경로 (None이면 체인 기본 경로)
+    pub path: Option<String>,
+    /// 알고 있는 주소
+    pub address: String,
+}
+
+/// 체크섬이 맞는 모든 후보 니모닉
+///
+/// `mnemonic`: 모르는 단어 자리에 `?` (최대 2개)
+pub fn missing_word_candidates(mnemonic: &str) -> Result<Vec<String>> {
+    let template = Template::parse(mnemonic)?;
+    let words = template.language.words();
+
+    let mut candidates: Vec<Vec<u16>> = (0..2048u16)
+        .into_par_iter()
+        .flat_map_iter(|first| template.candidates_with_first(first))
+        .collect();
+    candidates.sort();
+
+    Ok(candidates
+        .iter()
+        .map(|indices| {
+            indices
+                .iter()
+                .map(|&i| words[i as usize])
+                .collect::<Vec<_>>()
+                .join(template.language.separator())
+        })
+        .collect())
+}
+
+/// 빠진 단어 복구 (알고 있는 주소로 필터링)
+///
+/// 체크섬이 맞는 후보 중 `target` 주소를 만드는 니모닉만 반환
+/// (정상이라면 1개, 빈칸 위치에 따라 드물게 여러 개 가능)
+pub fn recover_missing_words(
+    mnemonic: &str,
+    passphrase: &str,
+    target: &AddressTarget,
+) -> Result<Vec<String>> {
+    let path = target.path.as_deref().unwrap_or(target.chain.default_path());
+
+    let candidates = missing_word_candidates(mnemonic)?;
+
+    let found: Result<Vec<Option<String>>> = candidates
+        .into_par_iter()
+        .map(|candidate| {
+            let seed = mnemonic_to_seed(&candidate, passphrase);
+            Ok(target
+                .chain
+                .matches(seed.as_slice(), path, &target.address)?
+                .then_some(candidate))
+        })
+        .collect();
+
+    Ok(found?.into_iter().flatten().collect())
+}
+
+/// 빈칸이 있는 니모닉
+struct Template {
+    language: Language,
+    /// 단어 인덱스 (빈칸은 0)
+    indices: Vec<u16>,
+    /// 빈칸 위치
+    missing: Vec<usize>,
+}
+
+impl Template {
+    fn parse(mnemonic: &str) -> Result<Self> {
+        let words: Vec<&str> = mnemonic.split_whitespace().collect();
+
+        if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+            return Err(Error::InvalidInput(format!("니모닉은 12/15/18/21/24단어여야 합니다, 입력: {}단어", words.len())));
+        }
+
+        let missing: Vec<usize> = words
+            .iter()
+            .enumerate()
+            .filter(|(_, &w)| w == MISSING_WORD)
+            .map(|(i, _)| i)
+            .collect();
+
+        if missing.is_empty() {
+            return Err(Error::InvalidInput(format!("빈칸({})이 없습니다", MISSING_WORD)));
+        }
+        if missing.len() > MAX_MISSING_WORDS {
+            return Err(Error::InvalidInput(format!(
+                "빈칸은 최대 {}개까지 복구할 수 있습니다, 입력: {}개",
+                MAX_MISSING_WORDS,
+                missing.len()
+            )));
+        }
+
+        // 알고 있는 단어가 모두 들어 있는 첫 언어
+        for &language in Language::ALL {
+            let indices: Option<Vec<u16>> = words
+                .iter()
+                .map(|&w| if w == MISSING_WORD { Some(0) } else { language.word_index(w) })
+                .collect();
+
+            if let Some(indices) = indices {
+                return Ok(Template { language, indices, missing });
+            }
+        }
+
+        let unknown = words
+            .iter()
+            .find(|&&w| w != MISSING_WORD && Language::ALL.iter().all(|l| l.word_index(w).is_none()))
+            .unwrap_or(&"");
+        Err(Error::InvalidInput(format!("단어 목록에 없는 단어: {}", unknown)))
+    }
+
+    /// 첫 빈칸을 `first`로 채운 체크섬 유효 후보들
+    fn candidates_with_first(&self, first: u16) -> Vec<Vec<u16>> {
+        let mut indices = self.indices.clone();
+        indices[self.missing[0]] = first;
+
+        match self.missing.get(1) {
+            None => {
+                if checksum_valid(&indices) {
+                    vec![indices]
+                } else {
+                    vec![]
+                }
+            }
+            Some(&second_position) => (0..2048u16)
+                .filter_map(|second| {
+                    indices[second_position] = second;
+                    checksum_valid(&indices).then(|| indices.clone())
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 단어 인덱스의 체크섬 검사 (후보마다 호출되므로 할당 없이 처리)
+fn checksum_valid(indices: &[u16]) -> bool {
+    // 최대 24단어 × 11비트 = 264비트 = 33바이트
+    let mut bytes = [0u8; 33];
+    let mut acc: u32 = 0;
+    let mut acc_bits = 0;
+    let mut len = 0;
+
+    for &index in indices {
+        acc = (acc << 11) | index as u32;
+        acc_bits += 11;
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            bytes[len] = (acc >> acc_bits) as u8;
+            len += 1;
+        }
+    }
+    if acc_bits > 0 {
+        bytes[len] = (acc << (8 - acc_bits)) as u8;
+    }
+
+    // 전체 비트 = 엔트로피 + 체크섬 (체크섬 = 엔트로피 / 32)
+    let checksum_bits = indices.len() * 11 / 33;
+    let entropy_len = (indices.len() * 11 - checksum_bits) / 8;
+
+    let hash = Sha256::digest(&bytes[..entropy_len]);
+    // 체크섬은 항상 엔트로피 바로 다음 바이트의 상위 비트
+    let mask = (0xff00u16 >> checksum_bits) as u8;
+    hash[0] & mask == bytes[entropy_len] & mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::validate_mnemonic;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_checksum_valid() {
+        let words = Language::English.words();
+        let indices = |m: &str| -> Vec<u16> {
+            m.split_whitespace()
+                .map(|w| words.iter().position(|&x| x == w).unwrap() as u16)
+                .collect()
+        };
+
+        assert!(checksum_valid(&indices(MNEMONIC)));
+        assert!(!checksum_valid(&indices(&MNEMONIC.replace("about", "abandon"))));
+        assert!(checksum_valid(&indices(&format!("{} vote", ["zoo"; 23].join(" ")))));
+    }
+
+    #[test]
+    fn test_one_missing_word() {
+        let template = "abandon abandon abandon abandon abandon ? abandon abandon abandon abandon abandon about";
+        let candidates = missing_word_candidates(template).unwrap();
+        println!("후보 수: {}", candidates.len());
+
+        assert!(candidates.contains(&MNEMONIC.to_string()));
+        for candidate in &candidates {
+            assert!(validate_mnemonic(candidate).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_missing_last_word() {
+        // 12단어의 마지막 단어: 7비트 엔트로피 + 4비트 체크섬 → 정확히 128개
+        let template = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ?";
+        let candidates = missing_word_candidates(template).unwrap();
+        assert_eq!(candidates.len(), 128);
+        assert!(candidates.contains(&MNEMONIC.to_string()));
+    }
+
+    #[test]
+    fn test_two_missing_words() {
+        // 마지막 단어가 빈칸이면 첫 빈칸의 값마다 정확히 128개
+        // (전체 26만 개 열거는 디버그 빌드에서 느리므로 올바른 첫 단어만 확인)
+        let template = Template::parse(
+            "abandon abandon ? abandon abandon abandon abandon abandon abandon abandon abandon ?",
+        )
+        .unwrap();
+        assert_eq!(template.missing, vec![2, 11]);
+
+        let candidates = template.candidates_with_first(0);
+        assert_eq!(candidates.len(), 128);
+
+        let words = Language::English.words();
+        let expected: Vec<u16> = MNEMONIC
+            .split_whitespace()
+            .map(|w| words.iter().position(|&x| x == w).unwrap() as u16)
+            .collect();
+        assert!(candidates.contains(&expected));
+    }
+
+    #[test]
+    fn test_recover_with_address() {
+        // 24단어 마지막 단어: 3비트 엔트로피 + 8비트 체크섬 → 후보 8개
+        let mnemonic = format!("{} art", ["abandon"; 23].join(" "));
+        let template = format!("{} ?", ["abandon"; 23].join(" "));
+        assert_eq!(missing_word_candidates(&template).unwrap().len(), 8);
+
+        let expected = EvmAccount::from_mnemonic(&mnemonic, "").unwrap();
+        let target = AddressTarget {
+            chain: RecoveryChain::Evm,
+            path: None,
+            address: expected.address_checksummed(),
+        };
+        assert_eq!(recover_missing_words(&template, "", &target).unwrap(), vec![mnemonic.clone()]);
+
+        // 다른 체인/경로 (Cosmos 계열은 대상 주소의 HRP 사용)
+        let seed = mnemonic_to_seed(&mnemonic, "");
+        let osmosis = CosmosAccount::from_seed_with_path(seed.as_slice(), "m/44'/118'/0'/0/1").unwrap();
+        let target = AddressTarget {
+            chain: RecoveryChain::Cosmos,
+            path: Some("m/44'/118'/0'/0/1".to_string()),
+            address: osmosis.address_with_hrp("osmo"),
+        };
+        println!("대상 주소: {}", target.address);
+        assert_eq!(recover_missing_words(&template, "", &target).unwrap(), vec![mnemonic.clone()]);
+
+        // 패스프레이즈가 다르면 찾지 못함
+        assert!(recover_missing_words(&template, "wrong", &target).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        assert!(missing_word_candidates(MNEMONIC).is_err());
+        assert!(missing_word_candidates("? ? ? abandon abandon abandon abandon abandon abandon abandon abandon about").is_err());
+        assert!(missing_word_candidates("? abandon").is_err());
+        assert!(missing_word_candidates("? notaword abandon abandon abandon abandon abandon abandon abandon abandon abandon about").is_err());
+    }
+}
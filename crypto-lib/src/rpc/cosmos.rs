@@ -0,0 +1,189 @@
+//! Cosmos SDK LCD(REST) 클라이언트 (feature = "rpc-cosmos")
+//!
+//! gRPC 서비스를 그대로 노출하는 gRPC-gateway 경로를 사용 (별도 protobuf 의존성 없음)
+//!
+//! | 메서드 | 경로 | 결과 |
+//! |--------|------|------|
+//! | `balances` | `/cosmos/bank/v1beta1/balances/{주소}` | denom별 잔액 |
+//! | `account` | `/cosmos/auth/v1beta1/accounts/{주소}` | account number, sequence (서명 문서에 필요) |
+//!
+//! 온체인 계정은 처음 토큰을 받을 때 만들어지므로, 계정이 없으면(404) `account`는 `None`
+//! 사용 여부: 온체인 계정이 있거나 잔액이 있으면 사용된 주소
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{parse_decimal, Endpoint};
+use crate::discovery::AsyncBalanceProvider;
+use crate::error::{Error, Result};
+use crate::wallet::Chain;
+
+/// denom별 잔액
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coin {
+    /// denom (예: "uatom")
+    pub denom: String,
+    /// 최소 단위 수량
+    pub amount: u128,
+}
+
+/// 서명에 필요한 온체인 계정 정보
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountInfo {
+    /// 계정 생성 순서 번호 (SignDoc의 account_number)
+    pub account_number: u64,
+    /// 다음 트랜잭션의 sequence (SignDoc/AuthInfo의 sequence)
+    pub sequence: u64,
+}
+
+#[derive(Deserialize)]
+struct BalancesResponse {
+    balances: Vec<RawCoin>,
+}
+
+#[derive(Deserialize)]
+struct RawCoin {
+    denom: String,
+    amount: String,
+}
+
+#[derive(Deserialize)]
+struct AccountResponse {
+    account: Value,
+}
+
+/// Cosmos SDK 노드 LCD 클라이언트
+#[derive(Debug, Clone)]
+pub struct CosmosLcd {
+    endpoint: Endpoint,
+}
+
+impl CosmosLcd {
+    /// LCD(REST) URL로 생성
+    pub fn new(url: &str) -> Self {
+        Self::with_client(reqwest::Client::new(), url)
+    }
+
+    /// HTTP 클라이언트 지정 (타임아웃, 프록시, 연결 풀 공유)
+    pub fn with_client(client: reqwest::Client, url: &str) -> Self {
+        CosmosLcd { endpoint: Endpoint::new(client, url) }
+    }
+
+    /// LCD URL
+    pub fn url(&self) -> &str {
+        self.endpoint.url()
+    }
+
+    /// 모든 denom 잔액 (0인 denom은 응답에 없음)
+    pub async fn balances(&self, address: &str) -> Result<Vec<Coin>> {
+        let path = format!("/cosmos/bank/v1beta1/balances/{}", address);
+        let Some(response) = self.endpoint.get::<BalancesResponse>(&path).await? else {
+            return Ok(Vec::new());
+        };
+        response
+            .balances
+            .into_iter()
+            .map(|coin| Ok(Coin { amount: parse_decimal(&coin.amount)?, denom: coin.denom }))
+            .collect()
+    }
+
+    /// denom 하나의 잔액
+    pub async fn balance(&self, address: &str, denom: &str) -> Result<u128> {
+        let balances = self.balances(address).await?;
+        Ok(balances.iter().find(|coin| coin.denom == denom).map_or(0, |coin| coin.amount))
+    }
+
+    /// account number, sequence (온체인 계정이 없으면 None)
+    pub async fn account(&self, address: &str) -> Result<Option<AccountInfo>> {
+        let path = format!("/cosmos/auth/v1beta1/accounts/{}", address);
+        match self.endpoint.get::<AccountResponse>(&path).await? {
+            Some(response) => parse_account(&response.account).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl AsyncBalanceProvider for CosmosLcd {
+    async fn is_used(&self, _chain: Chain, address: &str) -> Result<bool> {
+        Ok(self.account(address).await?.is_some() || !self.balances(address).await?.is_empty())
+    }
+}
+
+/// BaseAccount 또는 base_account를 감싼 계정(vesting, module 등)에서 번호 추출
+fn parse_account(account: &Value) -> Result<AccountInfo> {
+    let base = find_base_account(account).ok_or_else(|| Error::Rpc("계정 응답에 account_number가 없습니다".to_string()))?;
+    let field = |name: &str| -> Result<u64> {
+        // proto3 JSON: 0은 생략될 수 있음, u64는 문자열
+        match base.get(name) {
+            None | Some(Value::Null) => Ok(0),
+            Some(Value::String(value)) => value.parse().map_err(|_| Error::Rpc(format!("{}가 숫자가 아닙니다: {}", name, value))),
+            Some(value) => value.as_u64().ok_or_else(|| Error::Rpc(format!("{}가 숫자가 아닙니다: {}", name, value))),
+        }
+    };
+    Ok(AccountInfo { account_number: field("account_number")?, sequence: field("sequence")? })
+}
+
+fn find_base_account(account: &Value) -> Option<&Value> {
+    let object = account.as_object()?;
+    if object.contains_key("account_number") {
+        return Some(account);
+    }
+    ["base_account", "base_vesting_account"].iter().find_map(|key| object.get(*key).and_then(find_base_account))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::mock;
+    use serde_json::json;
+
+    const ADDRESS: &str = "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4";
+
+    #[test]
+    fn test_parse_account() {
+        let base = json!({
+            "@type": "/cosmos.auth.v1beta1.BaseAccount",
+            "address": ADDRESS,
+            "pub_key": null,
+            "account_number": "12345",
+            "sequence": "7"
+        });
+        assert_eq!(parse_account(&base), Ok(AccountInfo { account_number: 12345, sequence: 7 }));
+
+        let vesting = json!({
+            "@type": "/cosmos.vesting.v1beta1.ContinuousVestingAccount",
+            "base_vesting_account": { "base_account": { "address": ADDRESS, "account_number": "9" } }
+        });
+        assert_eq!(parse_account(&vesting), Ok(AccountInfo { account_number: 9, sequence: 0 }));
+
+        assert!(parse_account(&json!({ "@type": "/unknown" })).is_err());
+        assert!(parse_account(&json!({ "account_number": "x" })).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_queries() {
+        let (url, requests) = mock::serve(vec![
+            (200, r#"{"balances":[{"denom":"uatom","amount":"1500000"},{"denom":"ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2","amount":"3"}],"pagination":{"next_key":null,"total":"2"}}"#),
+            (200, r#"{"account":{"@type":"/cosmos.auth.v1beta1.BaseAccount","address":"cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4","pub_key":null,"account_number":"42","sequence":"3"}}"#),
+            (404, r#"{"code":5,"message":"rpc error: code = NotFound desc = account not found","details":[]}"#),
+        ]);
+        let lcd = CosmosLcd::new(&url);
+
+        assert_eq!(lcd.balance(ADDRESS, "uatom").await, Ok(1_500_000));
+        assert_eq!(lcd.account(ADDRESS).await, Ok(Some(AccountInfo { account_number: 42, sequence: 3 })));
+        assert_eq!(lcd.account(ADDRESS).await, Ok(None));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].0, format!("GET /cosmos/bank/v1beta1/balances/{} HTTP/1.1", ADDRESS));
+        assert_eq!(requests[1].0, format!("GET /cosmos/auth/v1beta1/accounts/{} HTTP/1.1", ADDRESS));
+    }
+
+    #[tokio::test]
+    async fn test_is_used() {
+        let (url, _) = mock::serve(vec![
+            (404, r#"{"code":5,"message":"account not found","details":[]}"#),
+            (200, r#"{"balances":[],"pagination":{"next_key":null,"total":"0"}}"#),
+        ]);
+        assert_eq!(CosmosLcd::new(&url).is_used(Chain::Cosmos(crate::cosmos::CosmosChain::CosmosHub), ADDRESS).await, Ok(false));
+    }
+}
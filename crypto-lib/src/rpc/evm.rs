@@ -0,0 +1,128 @@
+//! EVM JSON-RPC 클라이언트 (feature = "rpc-evm")
+//!
+//! | 메서드 | JSON-RPC | 결과 |
+//! |--------|----------|------|
+//! | `balance` | `eth_getBalance` | wei (0x 16진수 → u128) |
+//! | `nonce` | `eth_getTransactionCount` | 보낸 트랜잭션 수 (서명할 트랜잭션의 nonce) |
+//! | `chain_id` | `eth_chainId` | EIP-155 chain id |
+//!
+//! 사용 여부: 잔액이 있거나 보낸 트랜잭션이 있으면 사용된 주소
+
+use serde_json::json;
+
+use super::Endpoint;
+use crate::discovery::AsyncBalanceProvider;
+use crate::error::{Error, Result};
+use crate::wallet::Chain;
+
+/// EVM 노드 JSON-RPC 클라이언트
+#[derive(Debug, Clone)]
+pub struct EvmRpc {
+    endpoint: Endpoint,
+}
+
+impl EvmRpc {
+    /// 노드 URL로 생성
+    pub fn new(url: &str) -> Self {
+        Self::with_client(reqwest::Client::new(), url)
+    }
+
+    /// HTTP 클라이언트 지정 (타임아웃, 프록시, 연결 풀 공유)
+    pub fn with_client(client: reqwest::Client, url: &str) -> Self {
+        EvmRpc { endpoint: Endpoint::new(client, url) }
+    }
+
+    /// 노드 URL
+    pub fn url(&self) -> &str {
+        self.endpoint.url()
+    }
+
+    /// 최신 블록 기준 잔액 (wei)
+    pub async fn balance(&self, address: &str) -> Result<u128> {
+        let balance: String = self.endpoint.call("eth_getBalance", json!([address, "latest"])).await?;
+        parse_quantity(&balance)
+    }
+
+    /// 최신 블록 기준 nonce (보낸 트랜잭션 수)
+    pub async fn nonce(&self, address: &str) -> Result<u64> {
+        let nonce: String = self.endpoint.call("eth_getTransactionCount", json!([address, "latest"])).await?;
+        to_u64(parse_quantity(&nonce)?)
+    }
+
+    /// EIP-155 chain id
+    pub async fn chain_id(&self) -> Result<u64> {
+        let chain_id: String = self.endpoint.call("eth_chainId", json!([])).await?;
+        to_u64(parse_quantity(&chain_id)?)
+    }
+}
+
+impl AsyncBalanceProvider for EvmRpc {
+    async fn is_used(&self, _chain: Chain, address: &str) -> Result<bool> {
+        Ok(self.balance(address).await? > 0 || self.nonce(address).await? > 0)
+    }
+}
+
+/// JSON-RPC 수량 ("0x" + 앞자리 0 없는 16진수)
+fn parse_quantity(value: &str) -> Result<u128> {
+    let digits = value.strip_prefix("0x").ok_or_else(|| Error::Rpc(format!("0x 16진수 수량이 아닙니다: {}", value)))?;
+    if digits.is_empty() {
+        return Err(Error::Rpc(format!("0x 16진수 수량이 아닙니다: {}", value)));
+    }
+    u128::from_str_radix(digits, 16).map_err(|_| Error::Rpc(format!("0x 16진수 수량이 아닙니다: {}", value)))
+}
+
+fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| Error::Rpc(format!("u64 범위를 넘는 값: {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::mock;
+
+    const ADDRESS: &str = "0x9858EfFD232B4033E47d90003D41EC34EcaEda94";
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("0x0"), Ok(0));
+        assert_eq!(parse_quantity("0x1bc16d674ec80000"), Ok(2_000_000_000_000_000_000));
+        assert!(parse_quantity("1234").is_err());
+        assert!(parse_quantity("0x").is_err());
+        assert!(parse_quantity("0xzz").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_queries() {
+        let (url, requests) = mock::serve(vec![
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x1bc16d674ec80000"}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x5"}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#),
+        ]);
+        let rpc = EvmRpc::new(&url);
+
+        assert_eq!(rpc.balance(ADDRESS).await, Ok(2_000_000_000_000_000_000));
+        assert_eq!(rpc.nonce(ADDRESS).await, Ok(5));
+        assert_eq!(rpc.chain_id().await, Ok(1));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].0, "POST / HTTP/1.1");
+        let body: serde_json::Value = serde_json::from_str(&requests[0].1).unwrap();
+        assert_eq!(body["method"], "eth_getBalance");
+        assert_eq!(body["params"], json!([ADDRESS, "latest"]));
+    }
+
+    #[tokio::test]
+    async fn test_is_used() {
+        // 잔액 0, nonce 0 → 미사용 / 잔액 0, nonce 3 → 사용 (잔액을 모두 보낸 주소)
+        let (url, _) = mock::serve(vec![
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x0"}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x0"}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x0"}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":"0x3"}"#),
+        ]);
+        let rpc = EvmRpc::new(&url);
+
+        assert_eq!(rpc.is_used(Chain::Evm, ADDRESS).await, Ok(false));
+        assert_eq!(rpc.is_used(Chain::Evm, ADDRESS).await, Ok(true));
+    }
+}
@@ -0,0 +1,224 @@
+//! Chain RPC Clients
+//!
+//! 크레이트 자체 기능에 필요한 조회만 하는 얇은 비동기 클라이언트 (체인별 feature)
+//!
+//! | feature | 클라이언트 | 조회 |
+//! |---------|-----------|------|
+//! | `rpc-cosmos` | `CosmosLcd` | 잔액, account number/sequence (서명용) |
+//! | `rpc-evm` | `EvmRpc` | 잔액, nonce, chain id |
+//! | `rpc-solana` | `SolanaRpc` | 잔액, 최근 blockhash (서명용), 거래 기록 유무 |
+//! | `rpc-sui` | `SuiRpc` | 잔액, 거래 기록 유무 |
+//!
+//! 코어는 계속 오프라인 전용: 이 모듈은 feature를 켰을 때만 컴파일되고,
+//! 네트워크 요청은 클라이언트 메서드를 호출할 때만 발생
+//!
+//! ## 비동기 런타임
+//! reqwest(hyper/tokio) 기반이라 호출자가 tokio 런타임을 제공해야 함
+//!
+//! ## Account Discovery
+//! 모든 클라이언트가 `discovery::AsyncBalanceProvider`를 구현 → `discover_async`에 바로 전달
+//!
+//! ```no_run
+//! # async fn run() -> crypto_lib::Result<()> {
+//! use crypto_lib::discovery::{discover_async, DiscoveryOptions};
+//! use crypto_lib::rpc::EvmRpc;
+//! use crypto_lib::wallet::{CachedWallet, Chain};
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let wallet = CachedWallet::from_mnemonic(mnemonic, "")?;
+//!
+//! let rpc = EvmRpc::new("https://ethereum-rpc.publicnode.com");
+//! let found = discover_async(&wallet, Chain::Evm, &rpc, &DiscoveryOptions::default()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "rpc-cosmos")]
+pub mod cosmos;
+#[cfg(feature = "rpc-evm")]
+pub mod evm;
+#[cfg(feature = "rpc-solana")]
+pub mod solana;
+#[cfg(feature = "rpc-sui")]
+pub mod sui;
+
+#[cfg(feature = "rpc-cosmos")]
+pub use self::cosmos::CosmosLcd;
+#[cfg(feature = "rpc-evm")]
+pub use self::evm::EvmRpc;
+#[cfg(feature = "rpc-solana")]
+pub use self::solana::SolanaRpc;
+#[cfg(feature = "rpc-sui")]
+pub use self::sui::SuiRpc;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+
+/// 노드 주소 + HTTP 클라이언트
+///
+/// `reqwest::Client`는 연결 풀을 공유하므로 여러 클라이언트에 같은 값을 넘겨도 됨
+#[derive(Debug, Clone)]
+pub(crate) struct Endpoint {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl Endpoint {
+    pub(crate) fn new(client: reqwest::Client, url: &str) -> Self {
+        Endpoint { client, url: url.trim_end_matches('/').to_string() }
+    }
+
+    pub(crate) fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// JSON-RPC 2.0 호출
+    #[cfg(any(feature = "rpc-evm", feature = "rpc-solana", feature = "rpc-sui"))]
+    pub(crate) async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let request = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response = self.client.post(&self.url).json(&request).send().await.map_err(|e| request_error(&self.url, e))?;
+        let body = read_json(response).await?;
+        parse_json_rpc(method, body)
+    }
+
+    /// REST GET (404는 None)
+    #[cfg(feature = "rpc-cosmos")]
+    pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>> {
+        let url = format!("{}{}", self.url, path);
+        let response = self.client.get(&url).send().await.map_err(|e| request_error(&url, e))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = read_json(response).await?;
+        serde_json::from_value(body).map(Some).map_err(|e| Error::Rpc(format!("{} 응답 형식 오류: {}", path, e)))
+    }
+}
+
+fn request_error(url: &str, e: reqwest::Error) -> Error {
+    Error::Rpc(format!("{} 요청 실패: {}", url, e))
+}
+
+/// 성공 상태의 JSON 본문 (오류 상태는 본문 일부를 메시지에 포함)
+async fn read_json(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let url = response.url().to_string();
+    let text = response.text().await.map_err(|e| request_error(&url, e))?;
+    if !status.is_success() {
+        let excerpt: String = text.chars().take(200).collect();
+        return Err(Error::Rpc(format!("{} HTTP {}: {}", url, status, excerpt)));
+    }
+    serde_json::from_str(&text).map_err(|e| Error::Rpc(format!("{} JSON이 아닌 응답: {}", url, e)))
+}
+
+/// JSON-RPC 응답 → `result` (`error`는 코드와 메시지를 담아 `Error::Rpc`)
+#[cfg(any(feature = "rpc-evm", feature = "rpc-solana", feature = "rpc-sui"))]
+pub(crate) fn parse_json_rpc<T: DeserializeOwned>(method: &str, mut body: Value) -> Result<T> {
+    if let Some(error) = body.get("error").filter(|error| !error.is_null()) {
+        let code = error.get("code").and_then(Value::as_i64).unwrap_or_default();
+        let message = error.get("message").and_then(Value::as_str).unwrap_or("알 수 없는 오류");
+        return Err(Error::Rpc(format!("{} 실패 ({}): {}", method, code, message)));
+    }
+    let result = body.get_mut("result").map(Value::take).ok_or_else(|| Error::Rpc(format!("{} 응답에 result가 없습니다", method)))?;
+    serde_json::from_value(result).map_err(|e| Error::Rpc(format!("{} 응답 형식 오류: {}", method, e)))
+}
+
+/// 10진수 문자열 수량 (Cosmos, Sui 잔액)
+#[cfg(any(feature = "rpc-cosmos", feature = "rpc-sui"))]
+pub(crate) fn parse_decimal(value: &str) -> Result<u128> {
+    value.parse().map_err(|_| Error::Rpc(format!("수량이 아닙니다: {}", value)))
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    //! 요청 본문을 기록하고 준비된 응답을 돌려주는 로컬 HTTP 서버
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// (요청 줄, 본문)
+    pub type Requests = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// `responses`를 (상태 코드, 본문) 순서대로 응답하는 서버 시작 → (주소, 받은 요청)
+    pub fn serve(responses: Vec<(u16, &str)>) -> (String, Requests) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Requests = Arc::default();
+        let responses: Vec<(u16, String)> = responses.into_iter().map(|(status, body)| (status, body.to_string())).collect();
+
+        let log = requests.clone();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut request_body = vec![0u8; length];
+                reader.read_exact(&mut request_body).unwrap();
+                log.lock().unwrap().push((request_line.trim().to_string(), String::from_utf8(request_body).unwrap()));
+
+                let response = format!(
+                    "HTTP/1.1 {} X\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, requests)
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "rpc-evm", feature = "rpc-solana", feature = "rpc-sui"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_json_rpc() {
+        let ok: u64 = parse_json_rpc("getBalance", json!({ "jsonrpc": "2.0", "id": 1, "result": 42 })).unwrap();
+        assert_eq!(ok, 42);
+
+        let error = parse_json_rpc::<u64>("getBalance", json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32602, "message": "Invalid param" } }));
+        println!("{}", error.as_ref().unwrap_err());
+        assert_eq!(error, Err(Error::Rpc("getBalance 실패 (-32602): Invalid param".to_string())));
+
+        assert!(matches!(parse_json_rpc::<u64>("getBalance", json!({ "id": 1 })), Err(Error::Rpc(_))));
+        assert!(matches!(parse_json_rpc::<u64>("getBalance", json!({ "result": "x" })), Err(Error::Rpc(_))));
+    }
+
+    #[tokio::test]
+    async fn test_http_errors() {
+        let (url, _) = mock::serve(vec![(503, "upstream unavailable"), (200, "not json")]);
+        let endpoint = Endpoint::new(reqwest::Client::new(), &format!("{}/", url));
+        assert_eq!(endpoint.url(), url);
+
+        let error = endpoint.call::<u64>("eth_chainId", json!([])).await.unwrap_err();
+        println!("{}", error);
+        assert!(matches!(&error, Error::Rpc(message) if message.contains("503")));
+        assert!(matches!(endpoint.call::<u64>("eth_chainId", json!([])).await, Err(Error::Rpc(_))));
+
+        // 연결 실패
+        let closed = Endpoint::new(reqwest::Client::new(), "http://127.0.0.1:9");
+        assert!(matches!(closed.call::<u64>("eth_chainId", json!([])).await, Err(Error::Rpc(_))));
+    }
+}
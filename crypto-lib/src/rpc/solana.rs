@@ -0,0 +1,155 @@
+//! Solana JSON-RPC 클라이언트 (feature = "rpc-solana")
+//!
+//! | 메서드 | JSON-RPC | 결과 |
+//! |--------|----------|------|
+//! | `balance` | `getBalance` | lamports |
+//! | `latest_blockhash` | `getLatestBlockhash` | 트랜잭션 메시지에 넣을 최근 blockhash + 유효 블록 높이 |
+//! | `has_history` | `getSignaturesForAddress` (limit 1) | 서명 기록 유무 |
+//!
+//! commitment는 모두 `finalized` (되돌려지지 않은 상태만 사용)
+//! 사용 여부: 잔액이 있거나 서명 기록이 있으면 사용된 주소
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::Endpoint;
+use crate::discovery::AsyncBalanceProvider;
+use crate::error::{Error, Result};
+use crate::wallet::Chain;
+
+/// 최근 blockhash (트랜잭션 메시지의 recent_blockhash)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatestBlockhash {
+    /// blockhash 32바이트
+    pub blockhash: [u8; 32],
+    /// 이 blockhash로 만든 트랜잭션이 유효한 마지막 블록 높이
+    pub last_valid_block_height: u64,
+}
+
+impl LatestBlockhash {
+    /// Base58 blockhash (RPC/탐색기 표기)
+    pub fn blockhash_base58(&self) -> String {
+        bs58::encode(self.blockhash).into_string()
+    }
+}
+
+/// `{ context, value }` 형태 응답
+#[derive(Deserialize)]
+struct WithContext<T> {
+    value: T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockhashValue {
+    blockhash: String,
+    last_valid_block_height: u64,
+}
+
+/// Solana 노드 JSON-RPC 클라이언트
+#[derive(Debug, Clone)]
+pub struct SolanaRpc {
+    endpoint: Endpoint,
+}
+
+impl SolanaRpc {
+    /// 노드 URL로 생성
+    pub fn new(url: &str) -> Self {
+        Self::with_client(reqwest::Client::new(), url)
+    }
+
+    /// HTTP 클라이언트 지정 (타임아웃, 프록시, 연결 풀 공유)
+    pub fn with_client(client: reqwest::Client, url: &str) -> Self {
+        SolanaRpc { endpoint: Endpoint::new(client, url) }
+    }
+
+    /// 노드 URL
+    pub fn url(&self) -> &str {
+        self.endpoint.url()
+    }
+
+    /// 잔액 (lamports)
+    pub async fn balance(&self, address: &str) -> Result<u64> {
+        let response: WithContext<u64> = self.endpoint.call("getBalance", json!([address, { "commitment": "finalized" }])).await?;
+        Ok(response.value)
+    }
+
+    /// 최근 blockhash (오프라인 서명할 트랜잭션 메시지에 사용)
+    pub async fn latest_blockhash(&self) -> Result<LatestBlockhash> {
+        let response: WithContext<BlockhashValue> = self.endpoint.call("getLatestBlockhash", json!([{ "commitment": "finalized" }])).await?;
+        let decoded = bs58::decode(&response.value.blockhash)
+            .into_vec()
+            .map_err(|e| Error::Rpc(format!("blockhash Base58 오류: {}", e)))?;
+        let blockhash = decoded
+            .try_into()
+            .map_err(|bytes: Vec<u8>| Error::Rpc(format!("blockhash는 32바이트여야 합니다: {}바이트", bytes.len())))?;
+        Ok(LatestBlockhash { blockhash, last_valid_block_height: response.value.last_valid_block_height })
+    }
+
+    /// 주소가 포함된 트랜잭션 서명이 있는지
+    pub async fn has_history(&self, address: &str) -> Result<bool> {
+        let signatures: Vec<Value> = self
+            .endpoint
+            .call("getSignaturesForAddress", json!([address, { "limit": 1, "commitment": "finalized" }]))
+            .await?;
+        Ok(!signatures.is_empty())
+    }
+}
+
+impl AsyncBalanceProvider for SolanaRpc {
+    async fn is_used(&self, _chain: Chain, address: &str) -> Result<bool> {
+        Ok(self.balance(address).await? > 0 || self.has_history(address).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::mock;
+
+    const ADDRESS: &str = "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk";
+
+    #[tokio::test]
+    async fn test_queries() {
+        let (url, requests) = mock::serve(vec![
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":{"context":{"slot":1},"value":1500000000}}"#),
+            (
+                200,
+                r#"{"jsonrpc":"2.0","id":1,"result":{"context":{"slot":1},"value":{"blockhash":"EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N","lastValidBlockHeight":3090}}}"#,
+            ),
+        ]);
+        let rpc = SolanaRpc::new(&url);
+
+        assert_eq!(rpc.balance(ADDRESS).await, Ok(1_500_000_000));
+
+        let latest = rpc.latest_blockhash().await.unwrap();
+        assert_eq!(latest.blockhash_base58(), "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N");
+        assert_eq!(latest.last_valid_block_height, 3090);
+
+        let requests = requests.lock().unwrap();
+        let body: Value = serde_json::from_str(&requests[0].1).unwrap();
+        assert_eq!(body["method"], "getBalance");
+        assert_eq!(body["params"][0], ADDRESS);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_blockhash() {
+        let (url, _) = mock::serve(vec![(200, r#"{"jsonrpc":"2.0","id":1,"result":{"context":{"slot":1},"value":{"blockhash":"abc","lastValidBlockHeight":1}}}"#)]);
+        assert!(matches!(SolanaRpc::new(&url).latest_blockhash().await, Err(Error::Rpc(_))));
+    }
+
+    #[tokio::test]
+    async fn test_is_used() {
+        // 잔액 0 + 서명 없음 → 미사용 / 잔액 0 + 서명 있음 → 사용
+        let (url, _) = mock::serve(vec![
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":{"context":{"slot":1},"value":0}}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":[]}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":{"context":{"slot":1},"value":0}}"#),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":[{"signature":"5h6x","slot":1,"err":null}]}"#),
+        ]);
+        let rpc = SolanaRpc::new(&url);
+
+        assert_eq!(rpc.is_used(Chain::Solana, ADDRESS).await, Ok(false));
+        assert_eq!(rpc.is_used(Chain::Solana, ADDRESS).await, Ok(true));
+    }
+}
@@ -0,0 +1,125 @@
+//! Sui JSON-RPC 클라이언트 (feature = "rpc-sui")
+//!
+//! | 메서드 | JSON-RPC | 결과 |
+//! |--------|----------|------|
+//! | `balance` | `suix_getBalance` | MIST (0x2::sui::SUI) |
+//! | `has_history` | `suix_queryTransactionBlocks` (limit 1) | 보낸/받은 트랜잭션 유무 |
+//!
+//! 사용 여부: 잔액이 있거나 트랜잭션 기록이 있으면 사용된 주소
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{parse_decimal, Endpoint};
+use crate::discovery::AsyncBalanceProvider;
+use crate::error::Result;
+use crate::wallet::Chain;
+
+/// SUI 코인 타입
+pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Balance {
+    total_balance: String,
+}
+
+#[derive(Deserialize)]
+struct Page {
+    data: Vec<Value>,
+}
+
+/// Sui 풀노드 JSON-RPC 클라이언트
+#[derive(Debug, Clone)]
+pub struct SuiRpc {
+    endpoint: Endpoint,
+}
+
+impl SuiRpc {
+    /// 풀노드 URL로 생성
+    pub fn new(url: &str) -> Self {
+        Self::with_client(reqwest::Client::new(), url)
+    }
+
+    /// HTTP 클라이언트 지정 (타임아웃, 프록시, 연결 풀 공유)
+    pub fn with_client(client: reqwest::Client, url: &str) -> Self {
+        SuiRpc { endpoint: Endpoint::new(client, url) }
+    }
+
+    /// 풀노드 URL
+    pub fn url(&self) -> &str {
+        self.endpoint.url()
+    }
+
+    /// SUI 잔액 (MIST)
+    pub async fn balance(&self, address: &str) -> Result<u128> {
+        self.coin_balance(address, SUI_COIN_TYPE).await
+    }
+
+    /// 코인 타입별 잔액
+    pub async fn coin_balance(&self, address: &str, coin_type: &str) -> Result<u128> {
+        let balance: Balance = self.endpoint.call("suix_getBalance", json!([address, coin_type])).await?;
+        parse_decimal(&balance.total_balance)
+    }
+
+    /// 주소가 보내거나 받은 트랜잭션이 있는지
+    pub async fn has_history(&self, address: &str) -> Result<bool> {
+        for filter in ["FromAddress", "ToAddress"] {
+            let query = json!({ "filter": { filter: address } });
+            let page: Page = self.endpoint.call("suix_queryTransactionBlocks", json!([query, null, 1, true])).await?;
+            if !page.data.is_empty() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl AsyncBalanceProvider for SuiRpc {
+    async fn is_used(&self, _chain: Chain, address: &str) -> Result<bool> {
+        Ok(self.balance(address).await? > 0 || self.has_history(address).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::mock;
+
+    const ADDRESS: &str = "0x5e93a736d04fbb25737aa40bee40171ef79f65fae833749e3c089fe7cc2161f1";
+
+    #[tokio::test]
+    async fn test_balance() {
+        let (url, requests) = mock::serve(vec![(
+            200,
+            r#"{"jsonrpc":"2.0","id":1,"result":{"coinType":"0x2::sui::SUI","coinObjectCount":2,"totalBalance":"30000000000","lockedBalance":{}}}"#,
+        )]);
+        assert_eq!(SuiRpc::new(&url).balance(ADDRESS).await, Ok(30_000_000_000));
+
+        let body: Value = serde_json::from_str(&requests.lock().unwrap()[0].1).unwrap();
+        assert_eq!(body["method"], "suix_getBalance");
+        assert_eq!(body["params"], json!([ADDRESS, SUI_COIN_TYPE]));
+    }
+
+    #[tokio::test]
+    async fn test_is_used() {
+        // 잔액 0, 보낸/받은 기록 없음 → 미사용 / 잔액 0, 받은 기록만 있음 → 사용
+        let empty = r#"{"jsonrpc":"2.0","id":1,"result":{"data":[],"nextCursor":null,"hasNextPage":false}}"#;
+        let zero = r#"{"jsonrpc":"2.0","id":1,"result":{"coinType":"0x2::sui::SUI","coinObjectCount":0,"totalBalance":"0","lockedBalance":{}}}"#;
+        let (url, requests) = mock::serve(vec![
+            (200, zero),
+            (200, empty),
+            (200, empty),
+            (200, zero),
+            (200, empty),
+            (200, r#"{"jsonrpc":"2.0","id":1,"result":{"data":[{"digest":"abc"}],"nextCursor":"abc","hasNextPage":true}}"#),
+        ]);
+        let rpc = SuiRpc::new(&url);
+
+        assert_eq!(rpc.is_used(Chain::Sui, ADDRESS).await, Ok(false));
+        assert_eq!(rpc.is_used(Chain::Sui, ADDRESS).await, Ok(true));
+
+        let body: Value = serde_json::from_str(&requests.lock().unwrap()[2].1).unwrap();
+        assert_eq!(body["params"][0], json!({ "filter": { "ToAddress": ADDRESS } }));
+    }
+}
@@ -0,0 +1,309 @@
+//! BIP-340 Schnorr Signatures
+//!
+//! Taproot(BIP-341) 지출과 Nostr 이벤트 서명에 사용하는 secp256k1 Schnorr 서명
+//!
+//! ## x-only 공개키
+//! 공개키 점의 x좌표만 사용 (32바이트), y는 항상 짝수라고 약속
+//! → 개인키 d의 공개키 y가 홀수이면 서명 시 d 대신 n - d 사용
+//!
+//! ## 서명 (64바이트)
+//! ```text
+//! R = k·G (y 짝수), e = H_tag("BIP0340/challenge", R.x || P.x || m)
+//! s = k + e·d (mod n)
+//! 서명 = R.x || s
+//! ```
+//! nonce k는 개인키, 메시지, 보조 난수(aux_rand)로 결정 (aux_rand가 같으면 같은 서명)
+//!
+//! ## 태그 해시
+//! `H_tag(x) = SHA256(SHA256(tag) || SHA256(tag) || x)` - 용도별로 해시 도메인 분리
+//!
+//! ## Taproot 키 조정 (BIP-341)
+//! ```text
+//! t = H_tag("TapTweak", P.x || merkle_root)   (스크립트 경로가 없으면 merkle_root 생략)
+//! Q = P + t·G                                  (출력 키, 주소에 들어감)
+//! ```
+//! `TaprootOutputKey`는 Q와 함께 y 패리티(control block 첫 바이트, MuSig2/PSBT 서명 시 부정 여부)를 보관
+
+use secp256k1::{Keypair, Message, PublicKey, Scalar, SecretKey, XOnlyPublicKey};
+use sha2::{Digest, Sha256};
+
+use crate::ecdsa;
+use crate::error::{Error, Result};
+use crate::trace;
+
+/// BIP-340 태그 해시
+pub fn tagged_hash(tag: &str, data: &[u8]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// 개인키 → x-only 공개키 (32바이트)
+pub fn x_only_public_key(private_key: &[u8; 32]) -> Result<[u8; 32]> {
+    let keypair = keypair(private_key)?;
+    Ok(keypair.x_only_public_key().0.serialize())
+}
+
+/// 32바이트 메시지에 BIP-340 서명
+///
+/// `aux_rand`: 보조 난수 (None이면 0으로 채운 32바이트 → 결정적 서명)
+pub fn sign(private_key: &[u8; 32], message: &[u8; 32], aux_rand: Option<&[u8; 32]>) -> Result<[u8; 64]> {
+    let _span = trace::span!("schnorr.sign");
+    let keypair = keypair(private_key)?;
+    let message = Message::from_digest(*message);
+
+    let signature = ecdsa::context().sign_schnorr_with_aux_rand(&message, &keypair, aux_rand.unwrap_or(&[0u8; 32]));
+    Ok(signature.serialize())
+}
+
+/// BIP-340 서명 검증
+pub fn verify(x_only_public_key: &[u8; 32], message: &[u8; 32], signature: &[u8; 64]) -> Result<()> {
+    let public = XOnlyPublicKey::from_slice(x_only_public_key).map_err(|_| Error::InvalidKey("유효하지 않은 x-only 공개키".to_string()))?;
+    let signature = secp256k1::schnorr::Signature::from_slice(signature).map_err(|_| Error::InvalidSignature("유효하지 않은 Schnorr 서명".to_string()))?;
+
+    ecdsa::context()
+        .verify_schnorr(&signature, &Message::from_digest(*message), &public)
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 키 조정 (tweak)
+// ═══════════════════════════════════════════════════════════════
+
+/// Taproot tweak 값 t = H_tag("TapTweak", P.x || merkle_root)
+pub fn taproot_tweak(x_only_public_key: &[u8; 32], merkle_root: Option<&[u8; 32]>) -> [u8; 32] {
+    let mut data = x_only_public_key.to_vec();
+    if let Some(root) = merkle_root {
+        data.extend_from_slice(root);
+    }
+    tagged_hash("TapTweak", &data)
+}
+
+/// Taproot 출력 키 Q = P + t·G
+///
+/// 반환값: (x-only 출력 키, y 홀수 여부) - 스크립트 경로 지출의 control block에 패리티 필요
+pub fn taproot_tweak_public_key(
+    internal_key: &[u8; 32],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<([u8; 32], bool)> {
+    let internal = XOnlyPublicKey::from_slice(internal_key).map_err(|_| Error::InvalidKey("유효하지 않은 x-only 공개키".to_string()))?;
+    let tweak = scalar(&taproot_tweak(internal_key, merkle_root))?;
+
+    let (output, parity) = internal
+        .add_tweak(ecdsa::context(), &tweak)
+        .map_err(|_| Error::InvalidKey("Taproot 공개키 조정 실패".to_string()))?;
+    Ok((output.serialize(), parity == secp256k1::Parity::Odd))
+}
+
+/// Taproot 출력 키에 대응하는 개인키 (키 경로 지출 서명용)
+///
+/// 내부 공개키의 y가 홀수면 개인키를 먼저 부정한 뒤 t를 더함
+pub fn taproot_tweak_private_key(private_key: &[u8; 32], merkle_root: Option<&[u8; 32]>) -> Result<[u8; 32]> {
+    let keypair = keypair(private_key)?;
+    let internal_key = keypair.x_only_public_key().0.serialize();
+    let tweak = scalar(&taproot_tweak(&internal_key, merkle_root))?;
+
+    let tweaked = keypair
+        .add_xonly_tweak(ecdsa::context(), &tweak)
+        .map_err(|_| Error::InvalidKey("Taproot 개인키 조정 실패".to_string()))?;
+    Ok(tweaked.secret_bytes())
+}
+
+/// Taproot 출력 키와 서명에 필요한 조정 정보 (BIP-341)
+///
+/// 주소 없이 출력 키만 필요한 PSBT 공동 서명자, 스크립트 작성 도구용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaprootOutputKey {
+    /// 내부 키 P (x-only)
+    pub internal_key: [u8; 32],
+    /// 스크립트 트리 merkle root (키 경로 전용이면 None)
+    pub merkle_root: Option<[u8; 32]>,
+    /// tweak 값 t
+    pub tweak: [u8; 32],
+    /// 출력 키 Q (x-only)
+    pub output_key: [u8; 32],
+    /// Q의 y 홀수 여부 (control block 첫 바이트의 최하위 비트)
+    pub parity: bool,
+}
+
+impl TaprootOutputKey {
+    /// x-only 내부 키에서 출력 키 계산
+    pub fn new(internal_key: &[u8; 32], merkle_root: Option<&[u8; 32]>) -> Result<Self> {
+        let (output_key, parity) = taproot_tweak_public_key(internal_key, merkle_root)?;
+        Ok(TaprootOutputKey {
+            internal_key: *internal_key,
+            merkle_root: merkle_root.copied(),
+            tweak: taproot_tweak(internal_key, merkle_root),
+            output_key,
+            parity,
+        })
+    }
+
+    /// 압축 공개키(33바이트)를 내부 키로 사용 (y 패리티는 버리고 x좌표만 사용)
+    pub fn from_public_key(public_key: &[u8; 33], merkle_root: Option<&[u8; 32]>) -> Result<Self> {
+        let public = PublicKey::from_slice(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))?;
+        Self::new(&public.x_only_public_key().0.serialize(), merkle_root)
+    }
+
+    /// P2TR scriptPubKey (OP_1 OP_PUSHBYTES_32 Q)
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        let mut script = vec![0x51, 0x20];
+        script.extend_from_slice(&self.output_key);
+        script
+    }
+
+    /// 스크립트 경로 control block 첫 바이트 (leaf version | 패리티)
+    pub fn control_byte(&self, leaf_version: u8) -> u8 {
+        (leaf_version & 0xfe) | self.parity as u8
+    }
+}
+
+/// 개인키 + tweak (mod n)
+pub fn add_tweak_private_key(private_key: &[u8; 32], tweak: &[u8; 32]) -> Result<[u8; 32]> {
+    let secret = SecretKey::from_slice(private_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 개인키".to_string()))?;
+    let tweaked = secret.add_tweak(&scalar(tweak)?).map_err(|_| Error::InvalidKey("개인키 조정 결과가 0입니다".to_string()))?;
+    Ok(tweaked.secret_bytes())
+}
+
+/// 압축 공개키 + tweak·G
+pub fn add_tweak_public_key(public_key: &[u8; 33], tweak: &[u8; 32]) -> Result<[u8; 33]> {
+    let public = PublicKey::from_slice(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))?;
+    let tweaked = public
+        .add_exp_tweak(ecdsa::context(), &scalar(tweak)?)
+        .map_err(|_| Error::InvalidKey("공개키 조정 결과가 무한원점입니다".to_string()))?;
+    Ok(tweaked.serialize())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn keypair(private_key: &[u8; 32]) -> Result<Keypair> {
+    Keypair::from_seckey_slice(ecdsa::context(), private_key)
+        .map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 개인키".to_string()))
+}
+
+fn scalar(bytes: &[u8; 32]) -> Result<Scalar> {
+    Scalar::from_be_bytes(*bytes).map_err(|_| Error::InvalidKey("tweak 값이 곡선 위수 이상입니다".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes32(hex_str: &str) -> [u8; 32] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_bip340_vectors() {
+        // (개인키, 공개키, aux_rand, 메시지, 서명) - BIP-340 test-vectors.csv 0, 1
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+            ),
+            (
+                "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef",
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+            ),
+        ];
+
+        for (private_key, public_key, aux_rand, message, expected) in vectors {
+            let private_key = bytes32(private_key);
+            let message = bytes32(message);
+
+            assert_eq!(hex::encode(x_only_public_key(&private_key).unwrap()), public_key);
+
+            let signature = sign(&private_key, &message, Some(&bytes32(aux_rand))).unwrap();
+            assert_eq!(hex::encode(signature), expected);
+            assert!(verify(&bytes32(public_key), &message, &signature).is_ok());
+
+            let mut tampered = signature;
+            tampered[63] ^= 1;
+            assert!(verify(&bytes32(public_key), &message, &tampered).is_err());
+        }
+    }
+
+    #[test]
+    fn test_taproot_tweak() {
+        // BIP-86 m/86'/0'/0'/0/0 (abandon ... about)
+        let internal_key = bytes32("cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115");
+        let (output_key, _) = taproot_tweak_public_key(&internal_key, None).unwrap();
+        assert_eq!(hex::encode(output_key), "a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c");
+
+        // 조정된 개인키의 x-only 공개키 = 출력 키
+        for last in 1..=10u8 {
+            let mut private_key = [0u8; 32];
+            private_key[31] = last;
+            let root = [last; 32];
+
+            let internal = x_only_public_key(&private_key).unwrap();
+            let tweaked = taproot_tweak_private_key(&private_key, Some(&root)).unwrap();
+            let (output, _) = taproot_tweak_public_key(&internal, Some(&root)).unwrap();
+            assert_eq!(x_only_public_key(&tweaked).unwrap(), output);
+        }
+    }
+
+    #[test]
+    fn test_taproot_output_key() {
+        // BIP-341 wallet-test-vectors.json scriptPubKey 0 (스크립트 없음), 1 (스크립트 트리 1개)
+        let key_path = TaprootOutputKey::new(&bytes32("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d"), None).unwrap();
+        assert_eq!(hex::encode(key_path.tweak), "b86e7be8f39bab32a6f2c0443abbc210f0edac0e2c53d501b36b64437d9c6c70");
+        assert_eq!(hex::encode(key_path.output_key), "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343");
+        assert_eq!(hex::encode(key_path.script_pubkey()), "512053a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343");
+
+        let root = bytes32("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21");
+        let script_path = TaprootOutputKey::new(&bytes32("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27"), Some(&root)).unwrap();
+        assert_eq!(script_path.merkle_root, Some(root));
+        assert_eq!(hex::encode(script_path.tweak), "cbd8679ba636c1110ea247542cfbd964131a6be84f873f7f3b62a777528ed001");
+        assert_eq!(hex::encode(script_path.output_key), "147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3");
+        assert!(script_path.parity);
+        assert_eq!(script_path.control_byte(0xc0), 0xc1);
+
+        // 압축 공개키의 y 패리티와 관계없이 같은 출력 키
+        let mut private_key = [0u8; 32];
+        private_key[31] = 3;
+        let public = PublicKey::from_secret_key(ecdsa::context(), &SecretKey::from_slice(&private_key).unwrap()).serialize();
+        let from_public = TaprootOutputKey::from_public_key(&public, None).unwrap();
+        let mut flipped = public;
+        flipped[0] ^= 1;
+        assert_eq!(TaprootOutputKey::from_public_key(&flipped, None).unwrap(), from_public);
+        assert_eq!(from_public.internal_key, x_only_public_key(&private_key).unwrap());
+
+        // 패리티는 조정된 개인키의 공개키 y와 일치 (서명 전 부정 여부 판단에 사용)
+        let tweaked = taproot_tweak_private_key(&private_key, None).unwrap();
+        let tweaked_public = PublicKey::from_secret_key(ecdsa::context(), &SecretKey::from_slice(&tweaked).unwrap()).serialize();
+        assert_eq!(tweaked_public[0] == 0x03, from_public.parity);
+    }
+
+    #[test]
+    fn test_add_tweak() {
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let mut tweak = [0u8; 32];
+        tweak[31] = 2;
+
+        // 1 + 2 = 3
+        let tweaked = add_tweak_private_key(&private_key, &tweak).unwrap();
+        assert_eq!(tweaked[31], 3);
+
+        // (1 + 2)·G = 1·G + 2·G
+        let secp = ecdsa::context();
+        let public = PublicKey::from_secret_key(secp, &SecretKey::from_slice(&private_key).unwrap()).serialize();
+        let expected = PublicKey::from_secret_key(secp, &SecretKey::from_slice(&tweaked).unwrap()).serialize();
+        assert_eq!(add_tweak_public_key(&public, &tweak).unwrap(), expected);
+
+        assert!(add_tweak_private_key(&private_key, &[0xff; 32]).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! 비밀값 보호 (Secret Bytes)
+//!
+//! 개인키, 시드 같은 비밀값을 감싸는 타입
+//! - Drop 시 메모리를 0으로 덮어씀 (`zeroize`)
+//! - `Debug`는 내용 대신 길이만 출력 → 로그에 실수로 남지 않음
+//! - `Display` 없음 (hex 문자열이 필요하면 명시적으로 `hex::encode`)
+//! - 비교는 상수 시간
+//!
+//! `Deref<Target = [u8; N]>`이므로 기존 배열처럼 `&secret`, `secret[..]`, `secret.as_slice()`로 사용
+//!
+//! ## 주의
+//! `*secret`으로 배열을 복사하면 복사본은 보호되지 않음
+//! 잠깐 쓰는 중간값은 `zeroize::Zeroizing`으로 감싸거나 직접 `zeroize()` 호출
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use crate::error::{Error, Result};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Drop 시 0으로 지워지는 고정 길이 비밀 바이트
+#[derive(Clone)]
+pub struct SecretBytes<const N: usize>([u8; N]);
+
+impl<const N: usize> SecretBytes<N> {
+    /// 배열을 감싸 보호
+    pub const fn new(bytes: [u8; N]) -> Self {
+        SecretBytes(bytes)
+    }
+
+    /// 슬라이스에서 생성 (길이가 다르면 에러)
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let array: [u8; N] = bytes
+            .try_into()
+            .map_err(|_| Error::InvalidKey(format!("비밀값은 {}바이트여야 합니다 (현재 {}바이트)", N, bytes.len())))?;
+        Ok(SecretBytes(array))
+    }
+}
+
+impl<const N: usize> Deref for SecretBytes<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for SecretBytes<N> {
+    fn deref_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for SecretBytes<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for SecretBytes<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        SecretBytes(bytes)
+    }
+}
+
+impl<const N: usize> Default for SecretBytes<N> {
+    fn default() -> Self {
+        SecretBytes([0u8; N])
+    }
+}
+
+/// 상수 시간 비교 (앞부분이 같은 정도가 시간으로 드러나지 않게)
+impl<const N: usize> PartialEq for SecretBytes<N> {
+    fn eq(&self, other: &Self) -> bool {
+        ct_eq(&self.0, &other.0)
+    }
+}
+
+impl<const N: usize> Eq for SecretBytes<N> {}
+
+impl<const N: usize> PartialEq<[u8; N]> for SecretBytes<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        *self == SecretBytes(*other)
+    }
+}
+
+/// 바이트 슬라이스 상수 시간 비교 (MAC, 체크섬 확인용)
+///
+/// 길이가 다르면 바로 `false` (길이는 비밀이 아님)
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+impl<const N: usize> fmt::Debug for SecretBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED; {}]", N)
+    }
+}
+
+impl<const N: usize> Zeroize for SecretBytes<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> Drop for SecretBytes<N> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for SecretBytes<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = SecretBytes::new([0xab; 32]);
+        let debug = format!("{:?}", secret);
+        println!("Debug: {}", debug);
+
+        assert_eq!(debug, "[REDACTED; 32]");
+        assert!(!debug.contains("ab"));
+        assert!(!debug.contains("171"));
+    }
+
+    #[test]
+    fn test_array_access() {
+        let secret = SecretBytes::new([1u8; 32]);
+        assert_eq!(secret[0], 1);
+        assert_eq!(secret.as_slice().len(), 32);
+        assert_eq!(hex::encode(&secret), "01".repeat(32));
+        assert_eq!(secret, [1u8; 32]);
+        assert_ne!(secret, SecretBytes::new([2u8; 32]));
+
+        assert!(SecretBytes::<32>::from_slice(&[0u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_account_debug_is_redacted() {
+        let account = crate::evm::EvmAccount::from_private_key([0x42; 32]).unwrap();
+        let debug = format!("{:?}", account);
+        println!("{}", debug);
+
+        assert!(debug.contains("private_key: [REDACTED; 32]"));
+        assert!(!debug.contains(&account.private_key_hex()));
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut secret = SecretBytes::new([0xff; 16]);
+        secret.zeroize();
+        assert_eq!(*secret, [0u8; 16]);
+    }
+}
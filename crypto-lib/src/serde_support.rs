@@ -0,0 +1,392 @@
+//! serde 직렬화 (feature = "serde")
+//!
+//! 도출한 주소 목록(manifest)을 JSON 등으로 내보내기 위한 `Serialize`/`Deserialize` 구현
+//!
+//! ## 개인키 처리
+//! - 계정 타입의 기본 직렬화는 **개인키를 넣지 않음** (chain, address, public_key만)
+//! - 개인키까지 넣으려면 `account.serialize_with_secrets()` 래퍼를 명시적으로 직렬화
+//! - 계정 역직렬화는 개인키가 있는 데이터만 가능 (개인키 없는 manifest는 `wallet::AccountInfo`로 읽음)
+//!   복원한 개인키에서 다시 계산한 공개키/주소가 저장된 값과 다르면 에러
+//!
+//! ## 형식
+//!
+//! | 타입 | 직렬화 |
+//! |------|--------|
+//! | `BitcoinAccount` 등 계정 | `{"chain", "address", "public_key"}` (+ `"private_key"`) |
+//! | `ChainAccount` | 위 필드 + `"path"` |
+//! | `DerivationPath` | 경로 문자열 (`"m/44'/60'/0'/0/0"`) |
+//! | `wallet::Chain`, `CosmosChain` | 레지스트리 id (`"bitcoin"`, `"osmosis"`) |
+//! | `sui::SignatureScheme` | 이름 (`"ed25519"`) |
+//! | `AccountInfo`, `MultiChainWallet` | 필드 그대로 |
+//!
+//! 바이트 값은 모두 hex 문자열
+//!
+//! ```
+//! use crypto_lib::evm::EvmAccount;
+//! use crypto_lib::serde_support::SerializeWithSecrets;
+//!
+//! let account = EvmAccount::from_private_key([0x42; 32]).unwrap();
+//!
+//! let manifest = serde_json::to_string(&account).unwrap();
+//! assert!(!manifest.contains("private_key"));
+//!
+//! let backup = serde_json::to_string(&account.serialize_with_secrets()).unwrap();
+//! let restored: EvmAccount = serde_json::from_str(&backup).unwrap();
+//! assert_eq!(restored.address, account.address);
+//! ```
+
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::account::Account;
+use crate::bip32::DerivationPath;
+use crate::bitcoin::BitcoinAccount;
+use crate::chain::{self, ChainAccount};
+use crate::cosmos::{CosmosAccount, CosmosChain};
+use crate::evm::EvmAccount;
+use crate::secret::SecretBytes;
+use crate::solana::SolanaAccount;
+use crate::substrate::SubstrateAccount;
+use crate::sui::{SignatureScheme, SuiAccount};
+use crate::wallet::Chain;
+
+// ═══════════════════════════════════════════════════════════════
+// 개인키 포함 직렬화
+// ═══════════════════════════════════════════════════════════════
+
+/// 개인키까지 직렬화하는 래퍼 (`serialize_with_secrets()`로 생성)
+#[derive(Debug, Clone, Copy)]
+pub struct WithSecrets<'a, T: ?Sized>(&'a T);
+
+/// 개인키 포함 직렬화를 명시적으로 요청하는 트레이트 (모든 계정 타입에 구현)
+pub trait SerializeWithSecrets: private::Record {
+    /// 개인키(hex)를 포함해 직렬화하는 래퍼 반환
+    fn serialize_with_secrets(&self) -> WithSecrets<'_, Self> {
+        WithSecrets(self)
+    }
+}
+
+impl<T: private::Record + ?Sized> SerializeWithSecrets for T {}
+
+impl<T: private::Record + ?Sized> Serialize for WithSecrets<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.record(true).serialize(serializer)
+    }
+}
+
+// 외부 크레이트가 구현하거나 이름을 쓸 수 없게 숨김
+mod private {
+    use serde::Serialize;
+
+    /// 계정 → 직렬화 레코드
+    pub trait Record {
+        fn record(&self, with_secrets: bool) -> AccountRecord<'_>;
+    }
+
+    /// 계정 직렬화 형식
+    #[derive(Serialize)]
+    pub struct AccountRecord<'a> {
+        pub(super) chain: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(super) path: Option<&'a str>,
+        pub(super) address: String,
+        pub(super) public_key: String,
+        #[serde(skip_serializing_if = "Option::is_none", serialize_with = "super::serialize_secret")]
+        pub(super) private_key: Option<&'a [u8]>,
+    }
+}
+
+use private::AccountRecord;
+
+impl<'a> AccountRecord<'a> {
+    fn new<A: Account + ?Sized>(account: &'a A, path: Option<&'a str>, with_secrets: bool) -> Self {
+        AccountRecord {
+            chain: account.chain_id(),
+            path,
+            address: account.address(),
+            public_key: hex::encode(account.public_key_bytes()),
+            private_key: with_secrets.then(|| account.private_key_bytes()),
+        }
+    }
+}
+
+// hex 문자열도 비밀값이므로 직렬화 후 지움
+fn serialize_secret<S: Serializer>(secret: &Option<&[u8]>, serializer: S) -> Result<S::Ok, S::Error> {
+    match secret {
+        Some(bytes) => serializer.serialize_str(&Zeroizing::new(hex::encode(bytes))),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// 역직렬화 형식 (개인키 hex는 Drop 시 지움)
+#[derive(Deserialize)]
+struct OwnedAccountRecord {
+    chain: String,
+    #[serde(default)]
+    path: Option<String>,
+    address: String,
+    public_key: String,
+    #[serde(default)]
+    private_key: Option<String>,
+}
+
+impl Drop for OwnedAccountRecord {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+impl OwnedAccountRecord {
+    /// 개인키 hex → 32바이트
+    fn private_key<E: de::Error>(&self) -> Result<SecretBytes<32>, E> {
+        let hex_key = self
+            .private_key
+            .as_deref()
+            .ok_or_else(|| E::custom("private_key가 없습니다 (serialize_with_secrets()로 저장한 데이터만 복원 가능)"))?;
+        let bytes = Zeroizing::new(hex::decode(hex_key).map_err(|e| E::custom(format!("private_key hex 디코딩 실패: {}", e)))?);
+        SecretBytes::from_slice(&bytes).map_err(E::custom)
+    }
+
+    /// 복원한 계정이 저장된 체인/주소/공개키와 같은지 확인
+    fn verify<A: Account, E: de::Error>(&self, account: A) -> Result<A, E> {
+        if !account.chain_id().eq_ignore_ascii_case(&self.chain) {
+            return Err(E::custom(format!("체인이 다릅니다: {} (기대값 {})", self.chain, account.chain_id())));
+        }
+        if account.address() != self.address {
+            return Err(E::custom(format!("개인키와 주소가 일치하지 않습니다: {}", self.address)));
+        }
+        if !hex::encode(account.public_key_bytes()).eq_ignore_ascii_case(&self.public_key) {
+            return Err(E::custom("개인키와 공개키가 일치하지 않습니다"));
+        }
+        Ok(account)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 계정 타입
+// ═══════════════════════════════════════════════════════════════
+
+/// 계정 타입 공통 구현 (`restore`: 개인키 → `crate::Result<계정>`)
+macro_rules! impl_account_serde {
+    ($account:ty, |$key:ident| $restore:expr) => {
+        impl private::Record for $account {
+            fn record(&self, with_secrets: bool) -> AccountRecord<'_> {
+                AccountRecord::new(self, None, with_secrets)
+            }
+        }
+
+        impl Serialize for $account {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                private::Record::record(self, false).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $account {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let record = OwnedAccountRecord::deserialize(deserializer)?;
+                let $key = record.private_key::<D::Error>()?;
+                let account: crate::Result<$account> = $restore;
+                let account = account.map_err(de::Error::custom)?;
+                record.verify(account)
+            }
+        }
+    };
+}
+
+impl_account_serde!(BitcoinAccount, |key| BitcoinAccount::from_private_key(*key));
+impl_account_serde!(EvmAccount, |key| EvmAccount::from_private_key(*key));
+impl_account_serde!(SolanaAccount, |key| SolanaAccount::from_private_key(*key));
+impl_account_serde!(SuiAccount, |key| SuiAccount::from_private_key(*key));
+impl_account_serde!(CosmosAccount, |key| CosmosAccount::from_private_key(*key));
+impl_account_serde!(SubstrateAccount, |key| Ok(SubstrateAccount::from_private_key(*key)));
+
+impl private::Record for ChainAccount {
+    fn record(&self, with_secrets: bool) -> AccountRecord<'_> {
+        AccountRecord::new(self, Some(&self.path), with_secrets)
+    }
+}
+
+impl Serialize for ChainAccount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        private::Record::record(self, false).serialize(serializer)
+    }
+}
+
+/// 레지스트리에 등록된 체인만 복원 (체인 구현으로 공개키/주소를 다시 계산해 검증)
+impl<'de> Deserialize<'de> for ChainAccount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let record = OwnedAccountRecord::deserialize(deserializer)?;
+        let private_key = record.private_key::<D::Error>()?;
+        let spec = chain::get(&record.chain).ok_or_else(|| de::Error::custom(format!("등록되지 않은 체인입니다: {}", record.chain)))?;
+        let public_key = spec.public_key(&private_key).map_err(de::Error::custom)?;
+        let address = spec.format_address(&public_key).map_err(de::Error::custom)?;
+
+        let account = ChainAccount {
+            chain_id: spec.id().to_string(),
+            path: record.path.clone().unwrap_or_default(),
+            private_key,
+            public_key,
+            address,
+        };
+        record.verify(account)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 경로, 체인
+// ═══════════════════════════════════════════════════════════════
+
+impl Serialize for DerivationPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DerivationPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for CosmosChain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for CosmosChain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Chain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Chain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for SignatureScheme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureScheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::mnemonic_to_seed;
+    use crate::chain::SeedSource;
+    use crate::wallet::{AccountInfo, MultiChainWallet};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_default_serialization_skips_private_key() {
+        let account = BitcoinAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let json = serde_json::to_string(&account).unwrap();
+        println!("{}", json);
+
+        assert!(!json.contains("private_key"));
+        assert!(!json.contains(&hex::encode(account.private_key.as_slice())));
+        assert!(json.contains("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"));
+
+        // 개인키가 없는 데이터로는 계정을 복원할 수 없음
+        assert!(serde_json::from_str::<BitcoinAccount>(&json).is_err());
+    }
+
+    #[test]
+    fn test_with_secrets_roundtrip() {
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let json = serde_json::to_string(&evm.serialize_with_secrets()).unwrap();
+        assert!(json.contains(&hex::encode(evm.private_key.as_slice())));
+        let restored: EvmAccount = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.private_key, evm.private_key);
+
+        let cosmos = CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let json = serde_json::to_string(&cosmos.serialize_with_secrets()).unwrap();
+        let restored: CosmosAccount = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.address(), cosmos.address());
+
+        let substrate = SubstrateAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let json = serde_json::to_string(&substrate.serialize_with_secrets()).unwrap();
+        let restored: SubstrateAccount = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.public_key, substrate.public_key);
+    }
+
+    #[test]
+    fn test_tampered_address_rejected() {
+        let solana = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let json = serde_json::to_string(&solana.serialize_with_secrets()).unwrap();
+        let tampered = json.replace(&solana.address(), "11111111111111111111111111111111");
+        assert!(serde_json::from_str::<SolanaAccount>(&tampered).is_err());
+
+        // 다른 체인 계정으로 읽기
+        assert!(serde_json::from_str::<SuiAccount>(&json).is_err());
+    }
+
+    #[test]
+    fn test_chain_account_roundtrip() {
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let source = SeedSource::new(MNEMONIC, "", seed.as_slice());
+        let osmosis = chain::get("osmosis").unwrap();
+        let account = osmosis.derive_account(&source, &osmosis.default_path()).unwrap();
+
+        let manifest = serde_json::to_string(&account).unwrap();
+        println!("{}", manifest);
+        assert!(manifest.contains(r#""path":"m/44'/118'/0'/0/0""#));
+        assert!(!manifest.contains("private_key"));
+
+        let json = serde_json::to_string(&account.serialize_with_secrets()).unwrap();
+        let restored: ChainAccount = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.address, account.address);
+        assert_eq!(restored.path, account.path);
+    }
+
+    #[test]
+    fn test_path_and_chain() {
+        let path: DerivationPath = "m/44'/60'/0'/0/5".parse().unwrap();
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(json, r#""m/44'/60'/0'/0/5""#);
+        assert_eq!(serde_json::from_str::<DerivationPath>(&json).unwrap(), path);
+        assert!(serde_json::from_str::<DerivationPath>(r#""m/44'/abc""#).is_err());
+
+        let chains = [Chain::Bitcoin, Chain::Cosmos(CosmosChain::Osmosis), Chain::Substrate];
+        let json = serde_json::to_string(&chains).unwrap();
+        assert_eq!(json, r#"["bitcoin","osmosis","substrate"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Chain>>(&json).unwrap(), chains);
+        assert!(serde_json::from_str::<Chain>(r#""unknown""#).is_err());
+
+        assert_eq!(serde_json::from_str::<CosmosChain>(r#""osmo""#).unwrap(), CosmosChain::Osmosis);
+        assert_eq!(serde_json::to_string(&SignatureScheme::Secp256r1).unwrap(), r#""secp256r1""#);
+        assert_eq!(serde_json::from_str::<SignatureScheme>(r#""ed25519""#).unwrap(), SignatureScheme::Ed25519);
+        assert!(serde_json::from_str::<SignatureScheme>(r#""bls""#).is_err());
+    }
+
+    #[test]
+    fn test_wallet_manifest() {
+        let wallet = MultiChainWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let json = serde_json::to_string_pretty(&wallet).unwrap();
+        println!("{}", json);
+
+        let restored: MultiChainWallet = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, wallet);
+
+        let info: AccountInfo = serde_json::from_value(serde_json::to_value(&wallet.accounts[1]).unwrap()).unwrap();
+        assert_eq!(info.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    }
+}
@@ -0,0 +1,358 @@
+//! Shamir 비밀 분산 (GF(256), 임의 바이트 시크릿)
+//!
+//! 개인키나 시드 같은 원시 바이트를 threshold-of-count share로 나누고,
+//! threshold개 이상 모으면 복원 — HSM 키 세리머니처럼 니모닉이 아닌 키 단위로 나눌 때 사용
+//!
+//! SLIP-39와 달리 니모닉/패스프레이즈 암호화/그룹이 없음
+//! (니모닉 백업은 `slip39` 사용, 두 형식은 서로 호환되지 않음)
+//!
+//! ## 방식
+//! ```text
+//! 바이트마다 f(0) = 시크릿 바이트인 (threshold-1)차 랜덤 다항식 (GF(2^8), x^8 + x^4 + x^3 + x + 1)
+//! share i = (x = i, f(i)),  i = 1..=count
+//! ```
+//! threshold-1개 이하의 share로는 시크릿에 대한 정보를 전혀 얻을 수 없음
+//!
+//! ## share 형식
+//! ```text
+//! 식별자 (2) || threshold (1) || x (1) || 값 (시크릿 길이) || 체크섬 (4)
+//! 체크섬 = SHA-256(앞부분)의 앞 4바이트
+//! ```
+//! - 식별자: 분할마다 랜덤 → 다른 분할의 share를 섞으면 거부
+//! - 체크섬: 손으로 옮겨 적은 share의 오타 검출
+//! - threshold보다 많은 share를 넣으면 남는 share가 같은 다항식 위에 있는지도 확인
+//!
+//! ```
+//! use crypto_lib::shamir::{self, Share};
+//!
+//! let key = [0x42u8; 32];
+//! let shares = shamir::split(&key, 2, 3).unwrap();
+//! let hex: Vec<String> = shares.iter().map(|s| s.to_hex().to_string()).collect();
+//!
+//! let picked = [Share::from_hex(&hex[0]).unwrap(), Share::from_hex(&hex[2]).unwrap()];
+//! assert_eq!(shamir::combine(&picked).unwrap().as_slice(), &key);
+//! ```
+
+use std::fmt;
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Result};
+
+/// 체크섬 길이 (바이트)
+const CHECKSUM_LENGTH: usize = 4;
+/// 식별자 + threshold + x
+const HEADER_LENGTH: usize = 4;
+
+/// share 하나
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share {
+    identifier: u16,
+    threshold: u8,
+    index: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// 분할 식별자 (같은 분할의 share끼리 같음)
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// 복원에 필요한 share 개수
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// share 번호 (x 좌표, 1부터)
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// share 값 (시크릿과 같은 길이)
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// 직렬화 (체크섬 포함)
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(Vec::with_capacity(HEADER_LENGTH + self.value.len() + CHECKSUM_LENGTH));
+        out.extend_from_slice(&self.identifier.to_be_bytes());
+        out.push(self.threshold);
+        out.push(self.index);
+        out.extend_from_slice(&self.value);
+        let checksum = checksum(&out);
+        out.extend_from_slice(&checksum);
+        out
+    }
+
+    /// 역직렬화 (체크섬 검증)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() <= HEADER_LENGTH + CHECKSUM_LENGTH {
+            return Err(Error::Shamir(format!("share가 너무 짧습니다 ({}바이트)", bytes.len())));
+        }
+        let (body, expected) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+        if checksum(body) != expected {
+            return Err(Error::Shamir("share 체크섬 불일치 (옮겨 적은 값에 오타가 있는지 확인)".to_string()));
+        }
+
+        let share = Share {
+            identifier: u16::from_be_bytes([body[0], body[1]]),
+            threshold: body[2],
+            index: body[3],
+            value: Zeroizing::new(body[HEADER_LENGTH..].to_vec()),
+        };
+        if share.threshold == 0 || share.index == 0 {
+            return Err(Error::Shamir("share의 threshold와 번호는 1 이상이어야 합니다".to_string()));
+        }
+        Ok(share)
+    }
+
+    /// hex 문자열 (종이/HSM 세리머니 기록용)
+    pub fn to_hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(self.to_bytes().as_slice()))
+    }
+
+    /// hex 문자열에서 복원 (공백/줄바꿈은 무시)
+    pub fn from_hex(s: &str) -> Result<Self> {
+        let compact: Zeroizing<String> = Zeroizing::new(s.chars().filter(|c| !c.is_whitespace()).collect());
+        let bytes = Zeroizing::new(
+            hex::decode(compact.as_str()).map_err(|e| Error::Shamir(format!("share hex 디코딩 실패: {}", e)))?,
+        );
+        Share::from_bytes(&bytes)
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("identifier", &format_args!("{:04x}", self.identifier))
+            .field("threshold", &self.threshold)
+            .field("index", &self.index)
+            .field("value", &format_args!("<{}바이트>", self.value.len()))
+            .finish()
+    }
+}
+
+/// 시크릿을 threshold-of-count share로 분할
+///
+/// # Arguments
+///
+/// * `secret` - 임의 길이 (1바이트 이상) — 개인키, 시드, 엔트로피 등
+/// * `threshold` - 복원에 필요한 share 수 (1 이상)
+/// * `count` - 만들 share 수 (threshold 이상, 255 이하)
+pub fn split(secret: &[u8], threshold: u8, count: u8) -> Result<Vec<Share>> {
+    if secret.is_empty() {
+        return Err(Error::Shamir("시크릿이 비어 있습니다".to_string()));
+    }
+    if threshold == 0 || threshold > count {
+        return Err(Error::Shamir(format!("유효하지 않은 임계값/개수 ({}-of-{})", threshold, count)));
+    }
+
+    let mut rng = rand::thread_rng();
+    let identifier = rng.next_u32() as u16;
+
+    // x = 0 (시크릿)과 x = 1..threshold-1 (랜덤)이 (threshold-1)차 다항식을 결정
+    let mut base: Zeroizing<Vec<(u8, Vec<u8>)>> = Zeroizing::new(vec![(0, secret.to_vec())]);
+    for x in 1..threshold {
+        let mut value = vec![0u8; secret.len()];
+        rng.fill_bytes(&mut value);
+        base.push((x, value));
+    }
+
+    (1..=count)
+        .map(|x| Ok(Share { identifier, threshold, index: x, value: Zeroizing::new(interpolate(&base, x)?) }))
+        .collect()
+}
+
+/// share로 시크릿 복원
+///
+/// threshold개 이상 필요, 모든 share의 식별자/threshold/길이가 같아야 함
+/// threshold보다 많이 주면 나머지 share도 같은 다항식 위에 있는지 검증
+pub fn combine(shares: &[Share]) -> Result<Zeroizing<Vec<u8>>> {
+    let first = shares.first().ok_or_else(|| Error::Shamir("share가 없습니다".to_string()))?;
+    for share in shares {
+        if share.identifier != first.identifier {
+            return Err(Error::Shamir(format!(
+                "다른 분할의 share가 섞였습니다 ({:04x} ≠ {:04x})",
+                share.identifier, first.identifier
+            )));
+        }
+        if share.threshold != first.threshold || share.value.len() != first.value.len() {
+            return Err(Error::Shamir("share의 threshold/길이가 일치하지 않습니다".to_string()));
+        }
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if shares[..i].iter().any(|other| other.index == share.index) {
+            return Err(Error::Shamir(format!("share {}번이 중복되었습니다", share.index)));
+        }
+    }
+    let threshold = first.threshold as usize;
+    if shares.len() < threshold {
+        return Err(Error::Shamir(format!("share가 부족합니다 ({}/{})", shares.len(), threshold)));
+    }
+
+    let points: Zeroizing<Vec<(u8, Vec<u8>)>> =
+        Zeroizing::new(shares[..threshold].iter().map(|share| (share.index, share.value.to_vec())).collect());
+    for extra in &shares[threshold..] {
+        let expected = Zeroizing::new(interpolate(&points, extra.index)?);
+        if *expected != *extra.value {
+            return Err(Error::Shamir(format!("share {}번이 나머지 share와 맞지 않습니다", extra.index)));
+        }
+    }
+    Ok(Zeroizing::new(interpolate(&points, 0)?))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// GF(256) 보간 (slip39와 공유)
+// ═══════════════════════════════════════════════════════════════
+
+/// GF(256) 라그랑주 보간: share 점들을 지나는 다항식의 f(x)
+pub(crate) fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>> {
+    if let Some((_, value)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return Ok(value.clone());
+    }
+
+    let len = shares[0].1.len();
+    if shares.iter().any(|(_, value)| value.len() != len) {
+        return Err(Error::Shamir("share 길이가 일치하지 않습니다".to_string()));
+    }
+
+    let (exp, log) = gf256_tables();
+
+    // log(Π (x_i - x)) - GF(256)에서 뺄셈 = XOR
+    let log_prod: u32 = shares.iter().map(|(share_x, _)| log[(share_x ^ x) as usize] as u32).sum();
+
+    let mut result = vec![0u8; len];
+    for (share_x, value) in shares {
+        // 기저 다항식 L_i(x)의 로그
+        let log_basis = (log_prod as i64
+            - log[(share_x ^ x) as usize] as i64
+            - shares
+                .iter()
+                .map(|(other_x, _)| log[(share_x ^ other_x) as usize] as i64)
+                .sum::<i64>())
+        .rem_euclid(255);
+
+        for (r, &v) in result.iter_mut().zip(value) {
+            if v != 0 {
+                *r ^= exp[((log[v as usize] as i64 + log_basis) % 255) as usize];
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// GF(256) 지수/로그 테이블 (기약 다항식 x^8 + x^4 + x^3 + x + 1, 생성원 3)
+fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+
+    let mut poly: u16 = 1;
+    for (i, e) in exp.iter_mut().enumerate() {
+        *e = poly as u8;
+        log[poly as usize] = i as u8;
+
+        // poly × 3 = poly × 2 + poly
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+    }
+
+    (exp, log)
+}
+
+/// SHA-256 앞 4바이트
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    Sha256::digest(data)[..CHECKSUM_LENGTH].try_into().expect("4바이트")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_combine_all_subsets() {
+        let secret: Vec<u8> = (0..32).collect();
+        let shares = split(&secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert!(shares.iter().all(|s| s.value().len() == 32 && s.threshold() == 3));
+
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [shares[c].clone(), shares[a].clone(), shares[b].clone()];
+                    assert_eq!(combine(&subset).unwrap().as_slice(), secret.as_slice());
+                }
+            }
+        }
+        // 전부 넣어도 복원 (남는 share 일관성 검증 통과)
+        assert_eq!(combine(&shares).unwrap().as_slice(), secret.as_slice());
+        // 부족하면 실패
+        assert!(combine(&shares[..2]).is_err());
+    }
+
+    #[test]
+    fn test_edge_parameters() {
+        let seed = [0xabu8; 64];
+        // 1-of-n: 모든 share가 시크릿 그대로
+        let shares = split(&seed, 1, 3).unwrap();
+        assert!(shares.iter().all(|s| s.value() == seed));
+        assert_eq!(combine(&shares[2..]).unwrap().as_slice(), &seed);
+
+        // n-of-n, 최대 255개
+        let shares = split(&[7], 255, 255).unwrap();
+        assert_eq!(shares.last().unwrap().index(), 255);
+        assert_eq!(combine(&shares).unwrap().as_slice(), &[7]);
+
+        assert!(split(&[], 2, 3).is_err());
+        assert!(split(&seed, 0, 3).is_err());
+        assert!(split(&seed, 4, 3).is_err());
+    }
+
+    #[test]
+    fn test_share_encoding_and_checksum() {
+        let shares = split(b"raw private key material", 2, 3).unwrap();
+        let hex = shares[1].to_hex();
+        let parsed = Share::from_hex(&hex).unwrap();
+        assert_eq!(parsed, shares[1]);
+
+        // 공백으로 나눠 적은 형식도 허용
+        let spaced: String = hex.as_bytes().chunks(8).map(|c| std::str::from_utf8(c).unwrap()).collect::<Vec<_>>().join(" ");
+        assert_eq!(Share::from_hex(&spaced).unwrap(), shares[1]);
+
+        // 한 글자 오타 → 체크섬 오류
+        let mut typo = hex.to_string();
+        let replacement = if typo.ends_with('0') { "1" } else { "0" };
+        typo.replace_range(typo.len() - 1.., replacement);
+        assert!(Share::from_hex(&typo).is_err());
+        let mut bytes = shares[0].to_bytes();
+        bytes[6] ^= 0x01;
+        assert!(Share::from_bytes(&bytes).is_err());
+
+        // Debug에 값이 드러나지 않음
+        let debug = format!("{:?}", shares[0]);
+        assert!(!debug.contains(&hex::encode(shares[0].value())));
+    }
+
+    #[test]
+    fn test_rejects_mixed_or_inconsistent_shares() {
+        let secret = [0x11u8; 32];
+        let a = split(&secret, 2, 3).unwrap();
+        let mut b = split(&secret, 2, 3).unwrap();
+        b[1].identifier = a[0].identifier.wrapping_add(1);
+        assert!(combine(&[a[0].clone(), b[1].clone()]).is_err());
+
+        // 중복 share
+        assert!(combine(&[a[0].clone(), a[0].clone()]).is_err());
+
+        // 식별자를 맞춰도 다른 다항식의 share는 일관성 검증에서 걸림
+        let mut forged = b[2].clone();
+        forged.identifier = a[0].identifier;
+        assert!(combine(&[a[0].clone(), a[1].clone(), forged]).is_err());
+    }
+}
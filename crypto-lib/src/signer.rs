@@ -0,0 +1,319 @@
+//! Unified Signer
+//!
+//! 체인마다 다른 계정 타입을 같은 방식으로 서명/검증하기 위한 공통 트레이트
+//!
+//! ## 체인별 서명 방식
+//!
+//! | 계정 | 스킴 | 서명 대상 | 공개키 |
+//! |------|------|----------|--------|
+//! | Solana | Ed25519 | 메시지 그대로 | 32바이트 |
+//! | Sui | Ed25519 | 메시지 그대로 (intent 없음) | 32바이트 |
+//! | Substrate | Ed25519 | 메시지 그대로 | 32바이트 |
+//! | Bitcoin | secp256k1 ECDSA | SHA-256(메시지) | 33바이트 (압축) |
+//! | Cosmos | secp256k1 ECDSA | SHA-256(메시지) | 33바이트 (압축) |
+//! | EVM | secp256k1 ECDSA | Keccak-256(메시지) | 65바이트 (비압축) |
+//!
+//! ECDSA는 32바이트 다이제스트에만 서명할 수 있으므로 체인의 기본 해시를 먼저 적용
+//! (Sui intent 서명, EIP-191 등 체인 고유 형식은 각 체인 모듈의 함수 사용)
+//!
+//! ## 서명 직렬화
+//! - Ed25519: R || S (64바이트)
+//! - secp256k1: r || s compact (64바이트, low-S 정규화)
+//!
+//! 두 스킴 모두 64바이트라서 바이트만으로는 구분할 수 없음 → `Signature`가 스킴을 함께 보관
+
+use ed25519_dalek::{Signer as _, SigningKey, Verifier, VerifyingKey};
+use secp256k1::{Message, PublicKey};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::bitcoin::BitcoinAccount;
+use crate::cosmos::CosmosAccount;
+use crate::ecdsa;
+use crate::evm::{keccak256, EvmAccount};
+use crate::solana::SolanaAccount;
+use crate::substrate::SubstrateAccount;
+use crate::sui::SuiAccount;
+use crate::trace;
+use crate::wallet::Chain;
+use crate::error::{Error, Result};
+
+/// 서명 스킴
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningScheme {
+    /// Ed25519 (Solana, Sui, Substrate)
+    Ed25519,
+    /// secp256k1 ECDSA (Bitcoin, EVM, Cosmos)
+    Secp256k1,
+}
+
+/// 스킴이 표시된 서명
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signature {
+    /// Ed25519 서명 (R || S)
+    Ed25519([u8; 64]),
+    /// secp256k1 ECDSA compact 서명 (r || s)
+    Secp256k1([u8; 64]),
+}
+
+impl Signature {
+    /// 스킴과 64바이트로 서명 생성
+    pub fn from_bytes(scheme: SigningScheme, bytes: [u8; 64]) -> Self {
+        match scheme {
+            SigningScheme::Ed25519 => Signature::Ed25519(bytes),
+            SigningScheme::Secp256k1 => Signature::Secp256k1(bytes),
+        }
+    }
+
+    /// 서명 스킴
+    pub fn scheme(&self) -> SigningScheme {
+        match self {
+            Signature::Ed25519(_) => SigningScheme::Ed25519,
+            Signature::Secp256k1(_) => SigningScheme::Secp256k1,
+        }
+    }
+
+    /// 서명 바이트 (64바이트)
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        match self {
+            Signature::Ed25519(bytes) | Signature::Secp256k1(bytes) => bytes,
+        }
+    }
+
+    /// 서명을 hex로 반환
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.as_bytes())
+    }
+}
+
+/// 체인 공통 서명 트레이트
+pub trait Signer {
+    /// 서명 스킴
+    fn scheme(&self) -> SigningScheme;
+
+    /// 공개키 바이트 (체인의 기본 직렬화)
+    fn public_key_bytes(&self) -> Vec<u8>;
+
+    /// 메시지 서명 (ECDSA는 체인 기본 해시 적용 후 서명)
+    fn sign(&self, message: &[u8]) -> Result<Signature>;
+
+    /// 이 계정의 공개키로 서명 검증
+    fn verify(&self, message: &[u8], signature: &Signature) -> Result<()>;
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Ed25519 계정
+// ═══════════════════════════════════════════════════════════════
+
+/// Ed25519 계정 공통 구현 (개인키, 공개키 필드가 같은 구조)
+macro_rules! impl_ed25519_signer {
+    ($account:ty) => {
+        impl Signer for $account {
+            fn scheme(&self) -> SigningScheme {
+                SigningScheme::Ed25519
+            }
+
+            fn public_key_bytes(&self) -> Vec<u8> {
+                self.public_key.to_vec()
+            }
+
+            fn sign(&self, message: &[u8]) -> Result<Signature> {
+                Ok(ed25519_sign(&self.private_key, message))
+            }
+
+            fn verify(&self, message: &[u8], signature: &Signature) -> Result<()> {
+                ed25519_verify(&self.public_key, message, signature)
+            }
+        }
+    };
+}
+
+impl_ed25519_signer!(SolanaAccount);
+impl_ed25519_signer!(SuiAccount);
+impl_ed25519_signer!(SubstrateAccount);
+
+// ═══════════════════════════════════════════════════════════════
+// secp256k1 계정
+// ═══════════════════════════════════════════════════════════════
+
+impl Signer for BitcoinAccount {
+    fn scheme(&self) -> SigningScheme {
+        SigningScheme::Secp256k1
+    }
+
+    fn public_key_bytes(&self) -> Vec<u8> {
+        self.public_key.to_vec()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Signature> {
+        ecdsa_sign(&self.private_key, &Sha256::digest(message).into())
+    }
+
+    fn verify(&self, message: &[u8], signature: &Signature) -> Result<()> {
+        ecdsa_verify(&self.public_key, &Sha256::digest(message).into(), signature)
+    }
+}
+
+impl Signer for CosmosAccount {
+    fn scheme(&self) -> SigningScheme {
+        SigningScheme::Secp256k1
+    }
+
+    fn public_key_bytes(&self) -> Vec<u8> {
+        self.public_key.to_vec()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Signature> {
+        ecdsa_sign(&self.private_key, &Sha256::digest(message).into())
+    }
+
+    fn verify(&self, message: &[u8], signature: &Signature) -> Result<()> {
+        ecdsa_verify(&self.public_key, &Sha256::digest(message).into(), signature)
+    }
+}
+
+impl Signer for EvmAccount {
+    fn scheme(&self) -> SigningScheme {
+        SigningScheme::Secp256k1
+    }
+
+    fn public_key_bytes(&self) -> Vec<u8> {
+        self.public_key.to_vec()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Signature> {
+        ecdsa_sign(&self.private_key, &keccak256(message))
+    }
+
+    fn verify(&self, message: &[u8], signature: &Signature) -> Result<()> {
+        ecdsa_verify(&self.public_key, &keccak256(message), signature)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 체인별 서명 계정
+// ═══════════════════════════════════════════════════════════════
+
+/// 체인과 개인키로 서명 계정 생성 (바인딩에서 계정 타입을 몰라도 서명할 수 있도록)
+///
+/// 외부 등록 체인(`Chain::Custom`)은 서명 방식을 알 수 없으므로 `Error::Chain`
+pub fn signer_for(chain: Chain, private_key: [u8; 32]) -> Result<Box<dyn Signer + Send + Sync>> {
+    let private_key = Zeroizing::new(private_key);
+    Ok(match chain {
+        Chain::Bitcoin => Box::new(BitcoinAccount::from_private_key(*private_key)?),
+        Chain::Evm => Box::new(EvmAccount::from_private_key(*private_key)?),
+        Chain::Solana => Box::new(SolanaAccount::from_private_key(*private_key)?),
+        Chain::Sui => Box::new(SuiAccount::from_private_key(*private_key)?),
+        Chain::Cosmos(_) => Box::new(CosmosAccount::from_private_key(*private_key)?),
+        Chain::Substrate => Box::new(SubstrateAccount::from_private_key(*private_key)),
+        Chain::Custom(id) => return Err(Error::Chain(format!("서명을 지원하지 않는 체인입니다: {}", id))),
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn ed25519_sign(private_key: &[u8; 32], message: &[u8]) -> Signature {
+    let _span = trace::span!("ed25519.sign", message_len = message.len());
+    Signature::Ed25519(SigningKey::from_bytes(private_key).sign(message).to_bytes())
+}
+
+fn ed25519_verify(public_key: &[u8; 32], message: &[u8], signature: &Signature) -> Result<()> {
+    let Signature::Ed25519(bytes) = signature else {
+        return Err(Error::InvalidSignature(format!("Ed25519 서명이 아닙니다: {:?}", signature.scheme())));
+    };
+
+    VerifyingKey::from_bytes(public_key)
+        .map_err(|_| Error::InvalidKey("유효하지 않은 Ed25519 공개키".to_string()))?
+        .verify(message, &ed25519_dalek::Signature::from_bytes(bytes))
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+}
+
+/// 다이제스트에 ECDSA 서명 (RFC 6979 결정적 nonce, low-S)
+fn ecdsa_sign(private_key: &[u8; 32], digest: &[u8; 32]) -> Result<Signature> {
+    ecdsa::sign_compact(private_key, digest).map(Signature::Secp256k1)
+}
+
+/// 다이제스트의 ECDSA 서명 검증 (공개키는 압축/비압축 모두 허용)
+fn ecdsa_verify(public_key: &[u8], digest: &[u8; 32], signature: &Signature) -> Result<()> {
+    let Signature::Secp256k1(bytes) = signature else {
+        return Err(Error::InvalidSignature(format!("secp256k1 서명이 아닙니다: {:?}", signature.scheme())));
+    };
+
+    let public = PublicKey::from_slice(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))?;
+    let signature = secp256k1::ecdsa::Signature::from_compact(bytes).map_err(|_| Error::InvalidSignature("유효하지 않은 ECDSA 서명".to_string()))?;
+
+    ecdsa::context()
+        .verify_ecdsa(&Message::from_digest(*digest), &signature, &public)
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// 체인에 상관없이 서명 → 검증
+    fn sign_and_verify(signer: &dyn Signer) -> Signature {
+        let message = b"hello crypto-accounts";
+        let signature = signer.sign(message).unwrap();
+
+        assert_eq!(signature.scheme(), signer.scheme());
+        assert!(signer.verify(message, &signature).is_ok());
+        assert!(signer.verify(b"tampered", &signature).is_err());
+        signature
+    }
+
+    #[test]
+    fn test_all_chains() {
+        let signers: Vec<(&str, Box<dyn Signer>)> = vec![
+            ("Bitcoin", Box::new(BitcoinAccount::from_mnemonic(MNEMONIC, "").unwrap())),
+            ("EVM", Box::new(EvmAccount::from_mnemonic(MNEMONIC, "").unwrap())),
+            ("Cosmos", Box::new(CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap())),
+            ("Solana", Box::new(SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap())),
+            ("Sui", Box::new(SuiAccount::from_mnemonic(MNEMONIC, "").unwrap())),
+            ("Substrate", Box::new(SubstrateAccount::from_uri("//Alice").unwrap())),
+        ];
+
+        for (name, signer) in &signers {
+            let signature = sign_and_verify(signer.as_ref());
+            println!("{} ({:?}, 공개키 {}바이트): {}", name, signature.scheme(), signer.public_key_bytes().len(), signature.to_hex());
+        }
+    }
+
+    #[test]
+    fn test_scheme_mismatch() {
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let solana = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        // 같은 64바이트라도 스킴이 다르면 검증하지 않음
+        let signature = solana.sign(b"message").unwrap();
+        assert!(evm.verify(b"message", &Signature::Secp256k1(*signature.as_bytes())).is_err());
+        assert!(evm.verify(b"message", &signature).is_err());
+    }
+
+    #[test]
+    fn test_matches_chain_signing() {
+        // Solana 트레이트 서명 = 기존 sign_message
+        let solana = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(solana.sign(b"abc").unwrap(), Signature::Ed25519(solana.sign_message(b"abc")));
+
+        // ECDSA는 결정적 (RFC 6979)
+        let cosmos = CosmosAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(cosmos.sign(b"abc").unwrap(), cosmos.sign(b"abc").unwrap());
+    }
+
+    #[test]
+    fn test_signer_for() {
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let signer = signer_for(Chain::Evm, *evm.private_key).unwrap();
+        assert_eq!(signer.public_key_bytes(), evm.public_key_bytes());
+        assert_eq!(signer.sign(b"abc").unwrap(), evm.sign(b"abc").unwrap());
+
+        let signature = sign_and_verify(signer_for(Chain::Solana, [7u8; 32]).unwrap().as_ref());
+        assert_eq!(Signature::from_bytes(SigningScheme::Ed25519, *signature.as_bytes()), signature);
+        assert!(matches!(signer_for(Chain::Custom("dogecoin"), [7u8; 32]), Err(Error::Chain(_))));
+    }
+}
@@ -0,0 +1,626 @@
+//! # SLIP-10 Multi-Curve Key Derivation
+//!
+//! SLIP-10은 BIP-32를 여러 타원곡선으로 일반화한 표준입니다.
+//!
+//! ## 사용처
+//! - **Solana**: Ed25519 개인키 도출
+//! - **Sui**: Ed25519 / secp256r1(NIST P-256) 개인키 도출
+//! - **Aptos** 등 Ed25519 체인
+//!
+//! ## 곡선별 차이
+//!
+//! | 특성 | Ed25519 | secp256k1 | NIST P-256 |
+//! |------|---------|-----------|------------|
+//! | HMAC 키 | "ed25519 seed" | "Bitcoin seed" | "Nist256p1 seed" |
+//! | 도출 방식 | **강화만** | 일반 + 강화 | 일반 + 강화 |
+//! | 자식 개인키 | IL 그대로 | IL + k (mod n) | IL + k (mod n) |
+//! | 잘못된 키 처리 | 없음 (모든 32바이트 유효) | 재시도 | 재시도 |
+//!
+//! secp256k1 결과는 BIP-32와 완전히 같음
+//!
+//! ## 잘못된 키 재시도 (secp256k1, NIST P-256)
+//! - 마스터: IL = 0 또는 IL ≥ n이면 I = HMAC(곡선 키, I)로 다시 계산
+//! - 자식: IL ≥ n 또는 자식 키 = 0이면 data = 0x01 || IR || index로 다시 계산
+//!
+//! ## 왜 Ed25519는 강화 도출만 가능한가?
+//!
+//! Ed25519는 **Twisted Edwards Curve**로, secp256k1과 달리:
+//! - 개인키에서 공개키 도출 시 해싱 과정 포함
+//! - 개인키와 공개키 간 선형 관계 없음
+//! - 일반 도출(공개키만으로 자식 키 생성) 수학적으로 불가능
+//!
+//! 기존 Solana/Sui 도구와의 호환을 위해 Ed25519 경로의 일반 인덱스는 강화로 취급
+//!
+//! ## 참고 자료
+//! - [SLIP-10: Universal private key derivation from master private key](https://github.com/satoshilabs/slips/blob/master/slip-0010.md)
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+
+use hmac::digest::generic_array::GenericArray;
+use hmac::digest::FixedOutput;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+use crate::bip32::{ChildNumber, DerivationPath, IntoDerivationPath, HARDENED_OFFSET};
+use crate::ecdsa;
+use crate::secret::SecretBytes;
+use crate::trace;
+use crate::error::{Error, Result};
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// SLIP-10 타원곡선
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Curve {
+    /// Ed25519 (Solana, Sui, Aptos)
+    Ed25519,
+    /// secp256k1 (BIP-32와 동일)
+    Secp256k1,
+    /// NIST P-256 / secp256r1 (Sui secp256r1, 패스키)
+    Nist256p1,
+}
+
+impl Curve {
+    /// 마스터 키 HMAC 키
+    fn seed_key(&self) -> &'static [u8] {
+        match self {
+            Curve::Ed25519 => b"ed25519 seed",
+            Curve::Secp256k1 => b"Bitcoin seed",
+            Curve::Nist256p1 => b"Nist256p1 seed",
+        }
+    }
+}
+
+/// SLIP-10 확장 개인키 (개인키 + 체인코드)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slip10Key {
+    /// 곡선
+    pub curve: Curve,
+    /// 개인키 (32바이트)
+    pub private_key: SecretBytes<32>,
+    /// 체인코드 (32바이트)
+    pub chain_code: [u8; 32],
+}
+
+impl Slip10Key {
+    /// 시드에서 마스터 키 생성
+    pub fn master(seed: &[u8], curve: Curve) -> Result<Self> {
+        let (private_key, chain_code) = slip10_master_key(seed, curve)?;
+        Ok(Slip10Key { curve, private_key: SecretBytes::new(private_key), chain_code })
+    }
+
+    /// 자식 키 도출
+    pub fn derive_child(&self, index: ChildNumber) -> Result<Self> {
+        let (private_key, chain_code) = slip10_derive_child(&self.private_key, &self.chain_code, index, self.curve)?;
+        Ok(Slip10Key { curve: self.curve, private_key: SecretBytes::new(private_key), chain_code })
+    }
+
+    /// 경로로 자식 키 도출
+    pub fn derive_path(&self, path: impl IntoDerivationPath) -> Result<Self> {
+        let path = path.into_derivation_path()?;
+
+        let mut key = self.clone();
+        for &index in &path {
+            key = key.derive_child(index)?;
+        }
+        Ok(key)
+    }
+
+    /// SLIP-10 형식 공개키 (33바이트)
+    ///
+    /// - Ed25519: 0x00 || 공개키 32바이트
+    /// - secp256k1 / NIST P-256: 압축 공개키
+    pub fn public_key(&self) -> Result<[u8; 33]> {
+        public_key(&self.private_key, self.curve)
+    }
+}
+
+/// 한 시드의 마스터 키, 부모 키 캐시
+///
+/// 곡선별 마스터 키와 마지막 단계의 부모 키(예: m/44'/60'/0'/0)를 처음 도출할 때 보관
+/// → 같은 부모 아래에서 index만 바꾸는 도출은 자식 도출 한 번 (HMAC-SHA512 1회)
+///
+/// 캐시는 시드를 함께 보관하므로 다른 시드와 섞일 수 없음
+pub struct KeyCache {
+    seed: SecretBytes<64>,
+    keys: Mutex<HashMap<(Curve, DerivationPath), Slip10Key>>,
+}
+
+impl KeyCache {
+    /// BIP-39 시드(64바이트)로 빈 캐시 생성
+    pub fn new(seed: SecretBytes<64>) -> Self {
+        KeyCache { seed, keys: Mutex::new(HashMap::new()) }
+    }
+
+    /// 시드
+    pub fn seed(&self) -> &[u8] {
+        self.seed.as_slice()
+    }
+
+    /// 경로의 개인키 (`derive_key(seed, path, curve)`와 같은 결과)
+    pub fn derive_key(&self, path: impl IntoDerivationPath, curve: Curve) -> Result<[u8; 32]> {
+        let path = path.into_derivation_path()?;
+        let _span = trace::span!("slip10.derive_key", path = path, curve = curve);
+
+        let master = self.cached(curve, DerivationPath::master(), || Slip10Key::master(self.seed(), curve))?;
+        let Some((&last, parent)) = path.as_slice().split_last() else {
+            return Ok(*master.private_key);
+        };
+        let parent = self.cached(curve, parent.into(), || master.derive_path(DerivationPath::from(parent)))?;
+
+        Ok(*parent.derive_child(last)?.private_key)
+    }
+
+    /// 보관 중인 키 개수 (마스터 키 포함)
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// 보관 중인 키가 없는지
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn cached(&self, curve: Curve, path: DerivationPath, derive: impl FnOnce() -> Result<Slip10Key>) -> Result<Slip10Key> {
+        if let Some(key) = self.lock().get(&(curve, path.clone())) {
+            return Ok(key.clone());
+        }
+
+        // 도출하는 동안에는 잠그지 않음 (다른 스레드가 같은 키를 넣어도 결과는 같음)
+        let key = derive()?;
+        self.lock().insert((curve, path), key.clone());
+        Ok(key)
+    }
+
+    // 패닉한 스레드가 있어도 맵에는 완성된 키만 들어 있음
+    fn lock(&self) -> MutexGuard<'_, HashMap<(Curve, DerivationPath), Slip10Key>> {
+        self.keys.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clone for KeyCache {
+    fn clone(&self) -> Self {
+        KeyCache { seed: self.seed.clone(), keys: Mutex::new(self.lock().clone()) }
+    }
+}
+
+/// 시드와 키는 출력하지 않음
+impl fmt::Debug for KeyCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyCache").field("keys", &self.len()).finish_non_exhaustive()
+    }
+}
+
+/// 시드와 경로로 개인키 도출 (곡선 지정)
+///
+/// # Arguments
+///
+/// * `seed` - BIP-39 시드 (512비트 / 64바이트)
+/// * `path` - 도출 경로 (예: "m/44'/784'/0'/0/0" 또는 `DerivationPath`)
+/// * `curve` - 타원곡선
+pub fn derive_key(seed: &[u8], path: impl IntoDerivationPath, curve: Curve) -> Result<[u8; 32]> {
+    let path = path.into_derivation_path()?;
+    let _span = trace::span!("slip10.derive_key", path = path, curve = curve);
+    Ok(*Slip10Key::master(seed, curve)?.derive_path(path)?.private_key)
+}
+
+/// SLIP-10 Ed25519 개인키 도출
+///
+/// 시드와 경로로부터 Ed25519 개인키를 도출합니다.
+///
+/// # Arguments
+///
+/// * `seed` - BIP-39 시드 (512비트 / 64바이트)
+/// * `path` - 도출 경로 (예: "m/44'/501'/0'/0'" 또는 `DerivationPath`)
+///
+/// # Returns
+///
+/// Ed25519 개인키 (32바이트) 또는 오류 메시지
+///
+/// # Examples
+///
+/// ```
+/// use crypto_lib::slip10::derive_ed25519_key;
+/// use crypto_lib::bip39::mnemonic_to_seed;
+///
+/// let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+/// let seed = mnemonic_to_seed(mnemonic, "");
+///
+/// // Solana 경로
+/// let key = derive_ed25519_key(seed.as_slice(), "m/44'/501'/0'/0'").unwrap();
+/// assert_eq!(key.len(), 32);
+/// ```
+///
+/// # Errors
+///
+/// - 경로 파싱 실패 (잘못된 형식)
+/// - HMAC 초기화 실패
+pub fn derive_ed25519_key(seed: &[u8], path: impl IntoDerivationPath) -> Result<[u8; 32]> {
+    derive_key(seed, path, Curve::Ed25519)
+}
+
+/// SLIP-10 경로 파싱
+///
+/// BIP-44 스타일 경로를 인덱스 배열로 변환합니다.
+///
+/// # Arguments
+///
+/// * `path` - 도출 경로 (예: "m/44'/501'/0'/0'")
+///
+/// # Returns
+///
+/// 인덱스 배열 (예: [44, 501, 0, 0])
+///
+/// # Examples
+///
+/// ```
+/// use crypto_lib::slip10::parse_slip10_path;
+///
+/// let indices = parse_slip10_path("m/44'/501'/0'/0'").unwrap();
+/// assert_eq!(indices, vec![44, 501, 0, 0]);
+///
+/// // 강화 표시는 제거됨 (Ed25519는 모두 강화 도출)
+/// let indices2 = parse_slip10_path("m/44/501/0/0").unwrap();
+/// assert_eq!(indices2, vec![44, 501, 0, 0]);
+/// ```
+///
+/// # Errors
+///
+/// - 경로가 'm'으로 시작하지 않음
+/// - 유효하지 않은 인덱스 (숫자가 아님)
+pub fn parse_slip10_path(path: &str) -> Result<Vec<u32>> {
+    let path = path.into_derivation_path()?;
+    Ok(path.iter().map(ChildNumber::index).collect())
+}
+
+/// SLIP-10 형식 공개키 (33바이트)
+pub fn public_key(private_key: &[u8; 32], curve: Curve) -> Result<[u8; 33]> {
+    let mut result = [0u8; 33];
+
+    match curve {
+        Curve::Ed25519 => {
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(private_key);
+            result[1..].copy_from_slice(signing_key.verifying_key().as_bytes());
+        }
+        Curve::Secp256k1 => {
+            let secret = secp256k1::SecretKey::from_slice(private_key)
+                .map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 개인키".to_string()))?;
+            result.copy_from_slice(&secp256k1::PublicKey::from_secret_key(ecdsa::context(), &secret).serialize());
+        }
+        Curve::Nist256p1 => {
+            use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+            let secret = p256::SecretKey::from_slice(private_key)
+                .map_err(|_| Error::InvalidKey("유효하지 않은 P-256 개인키".to_string()))?;
+            result.copy_from_slice(secret.public_key().to_encoded_point(true).as_bytes());
+        }
+    }
+
+    Ok(result)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Internal Functions
+// ═══════════════════════════════════════════════════════════════
+
+/// SLIP-10 마스터 키 생성
+///
+/// HMAC-SHA512(key=곡선별 문자열, data=seed)
+/// secp256k1/P-256에서 IL이 유효한 개인키가 아니면 I를 데이터로 다시 HMAC
+///
+/// # Returns
+///
+/// (개인키 32바이트, 체인코드 32바이트)
+fn slip10_master_key(seed: &[u8], curve: Curve) -> Result<([u8; 32], [u8; 32])> {
+    let mut data = Zeroizing::new(seed.to_vec());
+
+    loop {
+        let result = hmac_sha512(curve.seed_key(), &data)?;
+        let (il, ir) = split_hmac(&result);
+
+        if curve == Curve::Ed25519 || is_valid_scalar(&il, curve) {
+            return Ok((il, ir));
+        }
+
+        data = Zeroizing::new(result.to_vec());
+    }
+}
+
+/// SLIP-10 자식 키 도출
+///
+/// # Arguments
+///
+/// * `parent_key` - 부모 개인키 (32바이트)
+/// * `parent_chain_code` - 부모 체인코드 (32바이트)
+/// * `index` - 자식 인덱스 (Ed25519는 항상 강화로 처리)
+/// * `curve` - 타원곡선
+///
+/// # Returns
+///
+/// (자식 개인키 32바이트, 자식 체인코드 32바이트)
+fn slip10_derive_child(
+    parent_key: &[u8; 32],
+    parent_chain_code: &[u8; 32],
+    index: ChildNumber,
+    curve: Curve,
+) -> Result<([u8; 32], [u8; 32])> {
+    // Ed25519는 강화 인덱스 (0x80000000 이상)만 사용
+    let index = match curve {
+        Curve::Ed25519 => index.index() | HARDENED_OFFSET,
+        _ => index.to_u32(),
+    };
+
+    // HMAC 입력
+    // - 강화: 0x00 + 개인키 + 인덱스
+    // - 일반: 압축 공개키 + 인덱스
+    // (개인키가 들어가므로 사용 후 지움)
+    let mut data = Zeroizing::new(Vec::with_capacity(37));
+    if index >= HARDENED_OFFSET {
+        data.push(0x00);
+        data.extend_from_slice(parent_key);
+    } else {
+        data.extend_from_slice(&public_key(parent_key, curve)?);
+    }
+    data.extend_from_slice(&index.to_be_bytes());
+
+    loop {
+        let result = hmac_sha512(parent_chain_code, &data)?;
+        let (il, ir) = split_hmac(&result);
+
+        if curve == Curve::Ed25519 {
+            return Ok((il, ir));
+        }
+
+        // 자식 키 = IL + 부모 키 (mod n), IL ≥ n이거나 결과가 0이면 재시도
+        if let Some(child_key) = add_scalars(&il, parent_key, curve) {
+            return Ok((child_key, ir));
+        }
+
+        data.clear();
+        data.push(0x01);
+        data.extend_from_slice(&ir);
+        data.extend_from_slice(&index.to_be_bytes());
+    }
+}
+
+/// HMAC-SHA512 (결과는 Drop 시 0으로 지워짐)
+fn hmac_sha512(key: &[u8], data: &[u8]) -> Result<Zeroizing<[u8; 64]>> {
+    let mut hmac = HmacSha512::new_from_slice(key)
+        .map_err(|e| Error::InvalidKey(format!("HMAC 초기화 실패: {}", e)))?;
+    hmac.update(data);
+
+    let mut result = Zeroizing::new([0u8; 64]);
+    hmac.finalize_into(GenericArray::from_mut_slice(result.as_mut_slice()));
+    Ok(result)
+}
+
+fn split_hmac(result: &[u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&result[..32]);
+    ir.copy_from_slice(&result[32..]);
+    (il, ir)
+}
+
+/// 0 < key < n 인지 검사
+fn is_valid_scalar(key: &[u8; 32], curve: Curve) -> bool {
+    match curve {
+        Curve::Ed25519 => true,
+        Curve::Secp256k1 => secp256k1::SecretKey::from_slice(key).is_ok(),
+        Curve::Nist256p1 => p256::SecretKey::from_slice(key).is_ok(),
+    }
+}
+
+/// (tweak + key) mod n - tweak ≥ n이거나 결과가 0이면 None
+fn add_scalars(tweak: &[u8; 32], key: &[u8; 32], curve: Curve) -> Option<[u8; 32]> {
+    match curve {
+        Curve::Ed25519 => None,
+        Curve::Secp256k1 => {
+            let tweak = secp256k1::Scalar::from_be_bytes(*tweak).ok()?;
+            let key = secp256k1::SecretKey::from_slice(key).ok()?;
+            key.add_tweak(&tweak).ok().map(|sum| sum.secret_bytes())
+        }
+        Curve::Nist256p1 => {
+            use p256::elliptic_curve::{ff::Field, PrimeField};
+            use p256::{FieldBytes, Scalar};
+
+            let tweak: Option<Scalar> = Scalar::from_repr(FieldBytes::from(*tweak)).into();
+            let key: Option<Scalar> = Scalar::from_repr(FieldBytes::from(*key)).into();
+            let sum = tweak? + key?;
+
+            if bool::from(sum.is_zero()) {
+                None
+            } else {
+                Some(sum.to_repr().into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SLIP-10 공식 테스트 벡터 1의 시드
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn test_slip10_master_key() {
+        // SLIP-10 공식 테스트 벡터
+        let seed = hex::decode(SEED).unwrap();
+        let (key, chain_code) = slip10_master_key(&seed, Curve::Ed25519).unwrap();
+
+        // SLIP-10 예상값 (Ed25519)
+        assert_eq!(
+            hex::encode(key),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex::encode(chain_code),
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb"
+        );
+    }
+
+    #[test]
+    fn test_ed25519_vector() {
+        // SLIP-10 테스트 벡터 1 (ed25519) m/0H
+        let seed = hex::decode(SEED).unwrap();
+        let key = derive_ed25519_key(&seed, "m/0'").unwrap();
+        assert_eq!(
+            hex::encode(key),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+
+        let master = Slip10Key::master(&seed, Curve::Ed25519).unwrap();
+        assert_eq!(
+            hex::encode(master.public_key().unwrap()),
+            "00a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_matches_bip32() {
+        // secp256k1 SLIP-10 = BIP-32
+        let seed = hex::decode(SEED).unwrap();
+        let path = "m/0'/1/2'/2/1000000000";
+
+        let slip10 = derive_key(&seed, path, Curve::Secp256k1).unwrap();
+        let bip32 = crate::bip32::master_key_from_seed(&seed).unwrap().derive_path(path).unwrap();
+        assert_eq!(slip10, *bip32.private_key);
+        assert_eq!(
+            hex::encode(slip10),
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+        );
+    }
+
+    #[test]
+    fn test_nist256p1_vector() {
+        // SLIP-10 테스트 벡터 1 (nist256p1)
+        let seed = hex::decode(SEED).unwrap();
+
+        let master = Slip10Key::master(&seed, Curve::Nist256p1).unwrap();
+        assert_eq!(
+            hex::encode(&master.private_key),
+            "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2"
+        );
+        assert_eq!(
+            hex::encode(master.chain_code),
+            "beeb672fe4621673f722f38529c07392fecaa61015c80c34f29ce8b41b3cb6ea"
+        );
+
+        let child = master.derive_path("m/0'").unwrap();
+        assert_eq!(
+            hex::encode(&child.private_key),
+            "6939694369114c67917a182c59ddb8cafc3004e63ca5d3b84403ba8613debc0c"
+        );
+        println!("m/0H 공개키: {}", hex::encode(child.public_key().unwrap()));
+    }
+
+    #[test]
+    fn test_nist256p1_retry() {
+        // SLIP-10 "derivation retry" 벡터 (nist256p1) - IL ≥ n 인 경우
+        let seed = hex::decode(SEED).unwrap();
+        let key = derive_key(&seed, "m/28578'/33941", Curve::Nist256p1).unwrap();
+        assert_eq!(
+            hex::encode(key),
+            "092154eed4af83e078ff9b84322015aefe5769e31270f62c3f66c33888335f3a"
+        );
+
+        // SLIP-10 "seed retry" 벡터 (nist256p1) - 마스터 IL ≥ n 인 경우
+        let seed = hex::decode("a7305bc8df8d0951f0cb224c0e95d7707cbdf2c6ce7e8d481fec69c7ff5e9446").unwrap();
+        let master = Slip10Key::master(&seed, Curve::Nist256p1).unwrap();
+        assert_eq!(
+            hex::encode(&master.private_key),
+            "3b8c18469a4634517d6d0b65448f8e6c62091b45540a1743c5846be55d47d88f"
+        );
+    }
+
+    #[test]
+    fn test_parse_slip10_path() {
+        // 강화 도출 표시 포함
+        let indices = parse_slip10_path("m/44'/501'/0'/0'").unwrap();
+        assert_eq!(indices, vec![44, 501, 0, 0]);
+
+        // 강화 도출 표시 없음 (Ed25519는 어차피 모두 강화)
+        let indices2 = parse_slip10_path("m/44/501/0/0").unwrap();
+        assert_eq!(indices2, vec![44, 501, 0, 0]);
+
+        // 'H' 표기
+        let indices3 = parse_slip10_path("m/44H/501H/0H/0H").unwrap();
+        assert_eq!(indices3, vec![44, 501, 0, 0]);
+    }
+
+    #[test]
+    fn test_derive_child() {
+        let parent_key = [0u8; 32];
+        let parent_chain_code = [1u8; 32];
+        let derive = |index| slip10_derive_child(&parent_key, &parent_chain_code, index, Curve::Ed25519).unwrap();
+
+        let (child_key, child_chain_code) = derive(ChildNumber::Hardened(0));
+
+        // 자식 키는 부모와 달라야 함
+        assert_ne!(child_key, parent_key);
+        assert_ne!(child_chain_code, parent_chain_code);
+
+        // 같은 입력은 같은 출력 (Ed25519는 일반 인덱스도 강화로 처리)
+        assert_eq!(derive(ChildNumber::Normal(0)), (child_key, child_chain_code));
+
+        // 다른 인덱스는 다른 출력
+        let (child_key3, _) = derive(ChildNumber::Hardened(1));
+        assert_ne!(child_key, child_key3);
+    }
+
+    #[test]
+    fn test_derive_ed25519_key() {
+        use crate::bip39::mnemonic_to_seed;
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "");
+
+        // Solana 경로
+        let key = derive_ed25519_key(seed.as_slice(), "m/44'/501'/0'/0'").unwrap();
+        assert_eq!(key.len(), 32);
+
+        // Sui 경로
+        let key2 = derive_ed25519_key(seed.as_slice(), "m/44'/784'/0'/0'/0'").unwrap();
+        assert_eq!(key2.len(), 32);
+
+        // 경로가 다르면 키도 다름
+        assert_ne!(key, key2);
+    }
+
+    #[test]
+    fn test_key_cache() {
+        use crate::bip39::mnemonic_to_seed;
+
+        let seed = mnemonic_to_seed(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        );
+        let cache = KeyCache::new(seed.clone());
+        assert!(cache.is_empty());
+
+        let paths = ["m/44'/60'/0'/0/0", "m/44'/60'/0'/0/1", "m/44'/118'/0'/0/0", "m/44'/501'/0'/0'", "m/44'/501'/1'/0'", "m"];
+        for curve in [Curve::Secp256k1, Curve::Ed25519, Curve::Nist256p1] {
+            for path in paths {
+                assert_eq!(cache.derive_key(path, curve).unwrap(), derive_key(seed.as_slice(), path, curve).unwrap(), "{:?} {}", curve, path);
+            }
+        }
+
+        // 곡선별 마스터 + 부모 4개 (m/44'/60'/0'/0, m/44'/118'/0'/0, m/44'/501'/0', m/44'/501'/1')
+        println!("{:?}", cache);
+        assert_eq!(cache.len(), 3 * 5);
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        // 'm'으로 시작하지 않음
+        assert!(parse_slip10_path("44'/501'/0'/0'").is_err());
+
+        // 유효하지 않은 인덱스
+        assert!(parse_slip10_path("m/abc/501/0/0").is_err());
+    }
+}
@@ -0,0 +1,814 @@
+//! SLIP-39: Shamir's Secret-Sharing for Mnemonic Codes
+//!
+//! 마스터 시크릿을 여러 니모닉(share)으로 나누고, 일정 개수 이상 모으면 복원
+//! Trezor Model T 등의 "Shamir Backup"과 호환
+//!
+//! ## 2단계 구조
+//! ```text
+//! 마스터 시크릿 → (패스프레이즈로 암호화) → EMS
+//! EMS → 그룹 T-of-N 분할 → 그룹 시크릿
+//! 그룹 시크릿 → 멤버 t-of-n 분할 → share 니모닉
+//! ```
+//! 예: 2-of-3 그룹 (본인 1-of-1, 가족 2-of-3, 변호사 2-of-2)
+//!
+//! ## 구성 요소
+//! - GF(256) 위의 라그랑주 보간 (Shamir)
+//! - 4라운드 Feistel 암호화 (PBKDF2-HMAC-SHA256)
+//! - RS1024 체크섬 (share 니모닉 마지막 3단어)
+//! - 1024개 단어 목록 (10비트/단어)
+//!
+//! 마스터 시크릿, share 값, 중간 계산값(EMS, 그룹 시크릿, Feistel 라운드)은 `Zeroizing`으로 감싸 drop 시 지움
+//!
+//! ## 참고 자료
+//! - [SLIP-39](https://github.com/satoshilabs/slips/blob/master/slip-0039.md)
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::shamir::interpolate;
+
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// SLIP-39 단어 목록 (1024개)
+pub const WORDLIST_SLIP39: &str = include_str!("wordlist/slip39.txt");
+
+/// 단어당 비트 수
+const RADIX_BITS: usize = 10;
+/// 식별자 비트 수
+const ID_LENGTH_BITS: usize = 15;
+/// 반복 지수 비트 수
+const ITERATION_EXP_LENGTH_BITS: usize = 4;
+/// 체크섬 단어 수
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+/// 메타데이터 단어 수 (식별자 + 플래그 + 반복 지수 + 그룹/멤버 정보)
+const METADATA_LENGTH_WORDS: usize = 4;
+/// 최소 share 단어 수 (128비트 시크릿)
+const MIN_MNEMONIC_LENGTH_WORDS: usize = 20;
+/// 최소 시크릿 길이 (바이트)
+const MIN_STRENGTH_BYTES: usize = 16;
+/// PBKDF2 기본 반복 횟수 (라운드 합계)
+const BASE_ITERATION_COUNT: u32 = 10000;
+/// Feistel 라운드 수
+const ROUND_COUNT: u8 = 4;
+/// 보간 시 시크릿/다이제스트 위치
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+const DIGEST_LENGTH_BYTES: usize = 4;
+/// 그룹/멤버 최대 개수
+const MAX_SHARE_COUNT: u8 = 16;
+
+/// 그룹 구성 (member_threshold-of-member_count)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroupSpec {
+    /// 복원에 필요한 멤버 수
+    pub member_threshold: u8,
+    /// 멤버 수
+    pub member_count: u8,
+}
+
+/// share 하나 (니모닉 하나에 대응, Debug는 share 값을 출력하지 않음)
+#[derive(Clone, PartialEq)]
+pub struct Share {
+    /// 식별자 (15비트) - 같은 시크릿의 share끼리 공유
+    pub identifier: u16,
+    /// extendable 플래그 - 식별자를 암호화 salt에 넣지 않음
+    pub extendable: bool,
+    /// 반복 지수 (PBKDF2 반복 = 10000 × 2^e)
+    pub iteration_exponent: u8,
+    /// 그룹 인덱스
+    pub group_index: u8,
+    /// 그룹 임계값
+    pub group_threshold: u8,
+    /// 그룹 수
+    pub group_count: u8,
+    /// 멤버 인덱스
+    pub member_index: u8,
+    /// 멤버 임계값
+    pub member_threshold: u8,
+    /// share 값
+    pub value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// share → 니모닉 (필드가 SLIP-39 범위를 벗어나면 에러)
+    pub fn to_mnemonic(&self) -> Result<Zeroizing<String>> {
+        self.check()?;
+        let wordlist = slip39_wordlist();
+
+        let id_exp = ((self.identifier as u64) << (ITERATION_EXP_LENGTH_BITS + 1))
+            | ((self.extendable as u64) << ITERATION_EXP_LENGTH_BITS)
+            | self.iteration_exponent as u64;
+        let group_member = ((self.group_index as u64) << 16)
+            | (((self.group_threshold - 1) as u64) << 12)
+            | (((self.group_count - 1) as u64) << 8)
+            | ((self.member_index as u64) << 4)
+            | (self.member_threshold - 1) as u64;
+
+        let mut indices = Zeroizing::new(int_to_indices(id_exp, 2));
+        indices.extend(int_to_indices(group_member, 2));
+        indices.extend(bytes_to_indices(&self.value).iter());
+
+        let checksum = rs1024_create_checksum(&indices, customization_string(self.extendable));
+        indices.extend(checksum);
+
+        Ok(Zeroizing::new(
+            indices
+                .iter()
+                .map(|&i| wordlist[i as usize])
+                .collect::<Vec<_>>()
+                .join(" "),
+        ))
+    }
+
+    /// 필드 범위 검사 (공개 필드라 직접 만든 share도 니모닉으로 인코딩 가능해야 함)
+    fn check(&self) -> Result<()> {
+        if self.identifier >= 1 << ID_LENGTH_BITS || self.iteration_exponent >= 1 << ITERATION_EXP_LENGTH_BITS {
+            return Err(Error::Shamir("식별자는 15비트, 반복 지수는 0~15여야 합니다".to_string()));
+        }
+        if self.group_threshold == 0 || self.group_threshold > self.group_count || self.group_count > MAX_SHARE_COUNT {
+            return Err(Error::Shamir(format!(
+                "그룹 임계값/개수가 유효하지 않습니다: {}-of-{}",
+                self.group_threshold, self.group_count
+            )));
+        }
+        if self.group_index >= self.group_count {
+            return Err(Error::Shamir(format!("그룹 인덱스 {}가 그룹 수 {} 이상입니다", self.group_index, self.group_count)));
+        }
+        if self.member_threshold == 0 || self.member_threshold > MAX_SHARE_COUNT || self.member_index >= MAX_SHARE_COUNT {
+            return Err(Error::Shamir(format!(
+                "멤버 임계값/인덱스가 유효하지 않습니다: threshold {}, index {}",
+                self.member_threshold, self.member_index
+            )));
+        }
+        if self.value.len() < MIN_STRENGTH_BYTES || !self.value.len().is_multiple_of(2) {
+            return Err(Error::Shamir(format!(
+                "share 값은 {}바이트 이상의 짝수 길이여야 합니다, 입력: {}바이트",
+                MIN_STRENGTH_BYTES,
+                self.value.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// 니모닉 → share (체크섬, 패딩 검증)
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self> {
+        let indices = Zeroizing::new(mnemonic_to_indices(mnemonic)?);
+
+        if indices.len() < MIN_MNEMONIC_LENGTH_WORDS {
+            return Err(Error::Shamir(format!(
+                "share는 최소 {}단어여야 합니다, 입력: {}단어",
+                MIN_MNEMONIC_LENGTH_WORDS,
+                indices.len()
+            )));
+        }
+
+        let padding_len = (RADIX_BITS * (indices.len() - METADATA_LENGTH_WORDS - CHECKSUM_LENGTH_WORDS)) % 16;
+        if padding_len > 8 {
+            return Err(Error::Shamir("유효하지 않은 share 길이".to_string()));
+        }
+
+        let id_exp = indices_to_int(&indices[..2]);
+        let identifier = (id_exp >> (ITERATION_EXP_LENGTH_BITS + 1)) as u16;
+        let extendable = (id_exp >> ITERATION_EXP_LENGTH_BITS) & 1 == 1;
+        let iteration_exponent = (id_exp & ((1 << ITERATION_EXP_LENGTH_BITS) - 1)) as u8;
+
+        if !rs1024_verify_checksum(&indices, customization_string(extendable)) {
+            return Err(Error::Shamir("share 체크섬이 일치하지 않습니다".to_string()));
+        }
+
+        let group_member = indices_to_int(&indices[2..4]);
+        let group_index = (group_member >> 16) as u8;
+        let group_threshold = ((group_member >> 12) & 0xf) as u8 + 1;
+        let group_count = ((group_member >> 8) & 0xf) as u8 + 1;
+        let member_index = ((group_member >> 4) & 0xf) as u8;
+        let member_threshold = (group_member & 0xf) as u8 + 1;
+
+        if group_count < group_threshold {
+            return Err(Error::Shamir("그룹 임계값이 그룹 수보다 큽니다".to_string()));
+        }
+
+        let value_indices = &indices[METADATA_LENGTH_WORDS..indices.len() - CHECKSUM_LENGTH_WORDS];
+        let value = Zeroizing::new(indices_to_bytes(value_indices, padding_len)?);
+
+        Ok(Share {
+            identifier,
+            extendable,
+            iteration_exponent,
+            group_index,
+            group_threshold,
+            group_count,
+            member_index,
+            member_threshold,
+            value,
+        })
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("identifier", &format_args!("{:04x}", self.identifier))
+            .field("extendable", &self.extendable)
+            .field("iteration_exponent", &self.iteration_exponent)
+            .field("group_index", &self.group_index)
+            .field("group_threshold", &self.group_threshold)
+            .field("group_count", &self.group_count)
+            .field("member_index", &self.member_index)
+            .field("member_threshold", &self.member_threshold)
+            .field("value", &format_args!("<{}바이트>", self.value.len()))
+            .finish()
+    }
+}
+
+/// 마스터 시크릿을 SLIP-39 share 니모닉으로 분할
+///
+/// 반환값: 그룹별 share 니모닉 목록
+///
+/// # Arguments
+///
+/// * `master_secret` - 16바이트 이상, 짝수 길이 (보통 16 또는 32바이트)
+/// * `passphrase` - 출력 가능한 ASCII만 허용
+/// * `group_threshold` - 복원에 필요한 그룹 수
+/// * `groups` - 그룹별 멤버 구성
+/// * `iteration_exponent` - PBKDF2 반복 지수 (0~15, 기본 1)
+/// * `extendable` - 같은 시크릿으로 나중에 share를 더 만들 수 있게 할지
+pub fn split_master_secret(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[GroupSpec],
+    iteration_exponent: u8,
+    extendable: bool,
+) -> Result<Vec<Vec<Zeroizing<String>>>> {
+    if master_secret.len() < MIN_STRENGTH_BYTES || !master_secret.len().is_multiple_of(2) {
+        return Err(Error::Shamir(format!(
+            "마스터 시크릿은 {}바이트 이상의 짝수 길이여야 합니다, 입력: {}바이트",
+            MIN_STRENGTH_BYTES,
+            master_secret.len()
+        )));
+    }
+    check_passphrase(passphrase)?;
+
+    if iteration_exponent >= 1 << ITERATION_EXP_LENGTH_BITS {
+        return Err(Error::Shamir("반복 지수는 0~15여야 합니다".to_string()));
+    }
+    if groups.is_empty() || groups.len() > MAX_SHARE_COUNT as usize {
+        return Err(Error::Shamir(format!("그룹 수는 1~{}개여야 합니다", MAX_SHARE_COUNT)));
+    }
+    if group_threshold == 0 || group_threshold as usize > groups.len() {
+        return Err(Error::Shamir("그룹 임계값은 1 이상, 그룹 수 이하여야 합니다".to_string()));
+    }
+    for group in groups {
+        if group.member_threshold == 0
+            || group.member_threshold > group.member_count
+            || group.member_count > MAX_SHARE_COUNT
+        {
+            return Err(Error::Shamir(format!("유효하지 않은 그룹 구성: {:?}", group)));
+        }
+        // 1-of-n (n > 1)은 같은 share를 복사하는 것과 같으므로 금지
+        if group.member_threshold == 1 && group.member_count > 1 {
+            return Err(Error::Shamir("1-of-n 그룹은 1-of-1만 허용됩니다".to_string()));
+        }
+    }
+
+    let mut id_bytes = [0u8; 2];
+    rand::thread_rng().fill_bytes(&mut id_bytes);
+    let identifier = u16::from_be_bytes(id_bytes) & ((1 << ID_LENGTH_BITS) - 1);
+
+    let encrypted = encrypt(master_secret, passphrase.as_bytes(), iteration_exponent, identifier, extendable);
+    let group_shares = split_secret(group_threshold, groups.len() as u8, &encrypted)?;
+
+    let mut result = Vec::with_capacity(groups.len());
+    for ((group_index, group_secret), group) in group_shares.iter().zip(groups) {
+        let member_shares = split_secret(group.member_threshold, group.member_count, group_secret)?;
+
+        let mnemonics = member_shares
+            .iter()
+            .map(|(member_index, value)| {
+                Share {
+                    identifier,
+                    extendable,
+                    iteration_exponent,
+                    group_index: *group_index,
+                    group_threshold,
+                    group_count: groups.len() as u8,
+                    member_index: *member_index,
+                    member_threshold: group.member_threshold,
+                    value: Zeroizing::new(value.clone()),
+                }
+                .to_mnemonic()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        result.push(mnemonics);
+    }
+
+    Ok(result)
+}
+
+/// share 니모닉들로 마스터 시크릿 복원
+///
+/// 그룹 임계값 이상의 그룹, 각 그룹은 멤버 임계값 이상의 share가 필요
+pub fn combine_mnemonics(mnemonics: &[&str], passphrase: &str) -> Result<Zeroizing<Vec<u8>>> {
+    check_passphrase(passphrase)?;
+
+    let shares = mnemonics
+        .iter()
+        .map(|m| Share::from_mnemonic(m))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let first = shares.first().ok_or_else(|| Error::Shamir("share가 없습니다".to_string()))?;
+
+    // 공통 파라미터 검사
+    for share in &shares {
+        if share.identifier != first.identifier
+            || share.extendable != first.extendable
+            || share.iteration_exponent != first.iteration_exponent
+        {
+            return Err(Error::Shamir("서로 다른 시크릿의 share가 섞여 있습니다".to_string()));
+        }
+        if share.group_threshold != first.group_threshold || share.group_count != first.group_count {
+            return Err(Error::Shamir("share의 그룹 파라미터가 일치하지 않습니다".to_string()));
+        }
+        if share.value.len() != first.value.len() {
+            return Err(Error::Shamir("share 길이가 일치하지 않습니다".to_string()));
+        }
+    }
+
+    // 그룹별로 모으기
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    for share in &shares {
+        let members = groups.entry(share.group_index).or_default();
+        if members.iter().any(|m| m.member_index == share.member_index) {
+            if members.iter().any(|m| m.member_index == share.member_index && m.value != share.value) {
+                return Err(Error::Shamir("같은 멤버 인덱스에 다른 share가 있습니다".to_string()));
+            }
+            continue;
+        }
+        members.push(share);
+    }
+
+    if groups.len() < first.group_threshold as usize {
+        return Err(Error::Shamir(format!(
+            "그룹이 부족합니다: {}개 필요, {}개 있음",
+            first.group_threshold,
+            groups.len()
+        )));
+    }
+
+    // 1단계: 그룹별 멤버 share → 그룹 시크릿
+    let mut group_secrets: Points = Zeroizing::new(Vec::new());
+    for (&group_index, members) in &groups {
+        let member_threshold = members[0].member_threshold;
+        if members.iter().any(|m| m.member_threshold != member_threshold) {
+            return Err(Error::Shamir(format!("그룹 {}의 멤버 임계값이 일치하지 않습니다", group_index)));
+        }
+        if members.len() < member_threshold as usize {
+            // 임계값 미달 그룹은 건너뜀 (다른 그룹으로 충분할 수 있음)
+            continue;
+        }
+
+        let member_shares: Points = Zeroizing::new(
+            members
+                .iter()
+                .take(member_threshold as usize)
+                .map(|m| (m.member_index, m.value.to_vec()))
+                .collect(),
+        );
+        group_secrets.push((group_index, recover_secret(member_threshold, &member_shares)?.to_vec()));
+    }
+
+    if group_secrets.len() < first.group_threshold as usize {
+        return Err(Error::Shamir(format!(
+            "완성된 그룹이 부족합니다: {}개 필요, {}개 있음",
+            first.group_threshold,
+            group_secrets.len()
+        )));
+    }
+    group_secrets.truncate(first.group_threshold as usize);
+
+    // 2단계: 그룹 시크릿 → EMS → 복호화
+    let encrypted = recover_secret(first.group_threshold, &group_secrets)?;
+    Ok(decrypt(
+        &encrypted,
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+    ))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Shamir 비밀 분산 (GF(256))
+// ═══════════════════════════════════════════════════════════════
+
+/// (x, share 값) 목록
+type Points = Zeroizing<Vec<(u8, Vec<u8>)>>;
+
+/// threshold-of-count 분할
+///
+/// x = 0..threshold-2는 랜덤, x = 254는 다이제스트, x = 255는 시크릿으로 두고
+/// 나머지 share를 보간으로 계산
+fn split_secret(threshold: u8, count: u8, secret: &[u8]) -> Result<Points> {
+    if threshold == 0 || threshold > count || count > MAX_SHARE_COUNT {
+        return Err(Error::Shamir("유효하지 않은 임계값/개수".to_string()));
+    }
+
+    if threshold == 1 {
+        return Ok(Zeroizing::new((0..count).map(|i| (i, secret.to_vec())).collect()));
+    }
+
+    let mut rng = rand::thread_rng();
+    let random_share_count = threshold - 2;
+
+    let mut shares: Points = Zeroizing::new(
+        (0..random_share_count)
+            .map(|i| {
+                let mut value = vec![0u8; secret.len()];
+                rng.fill_bytes(&mut value);
+                (i, value)
+            })
+            .collect(),
+    );
+
+    let mut random_part = Zeroizing::new(vec![0u8; secret.len() - DIGEST_LENGTH_BYTES]);
+    rng.fill_bytes(&mut random_part);
+
+    let mut digest_share = create_digest(&random_part, secret).to_vec();
+    digest_share.extend_from_slice(&random_part);
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, digest_share));
+    base_shares.push((SECRET_INDEX, secret.to_vec()));
+
+    for i in random_share_count..count {
+        shares.push((i, interpolate(&base_shares, i)?));
+    }
+
+    Ok(shares)
+}
+
+/// 임계값 개수의 share로 시크릿 복원 (다이제스트 검증)
+fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Zeroizing<Vec<u8>>> {
+    if threshold == 1 {
+        return Ok(Zeroizing::new(shares[0].1.clone()));
+    }
+
+    let secret = Zeroizing::new(interpolate(shares, SECRET_INDEX)?);
+    let digest_share = Zeroizing::new(interpolate(shares, DIGEST_INDEX)?);
+
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+    if digest != create_digest(random_part, &secret) {
+        return Err(Error::Shamir("share 다이제스트 검증 실패 (잘못된 share 조합)".to_string()));
+    }
+
+    Ok(secret)
+}
+
+/// HMAC-SHA256(key=random_part, msg=secret)의 앞 4바이트
+fn create_digest(random_part: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH_BYTES] {
+    let mut hmac = Hmac::<Sha256>::new_from_slice(random_part).expect("HMAC은 모든 키 길이 허용");
+    hmac.update(secret);
+    let result = hmac.finalize().into_bytes();
+
+    let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+    digest.copy_from_slice(&result[..DIGEST_LENGTH_BYTES]);
+    digest
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 암호화 (4라운드 Feistel)
+// ═══════════════════════════════════════════════════════════════
+
+fn encrypt(master_secret: &[u8], passphrase: &[u8], e: u8, identifier: u16, extendable: bool) -> Zeroizing<Vec<u8>> {
+    let half = master_secret.len() / 2;
+    let mut left = Zeroizing::new(master_secret[..half].to_vec());
+    let mut right = Zeroizing::new(master_secret[half..].to_vec());
+    let salt = encryption_salt(identifier, extendable);
+
+    for i in 0..ROUND_COUNT {
+        let f = round_function(i, passphrase, e, &salt, &right);
+        let new_right = Zeroizing::new(left.iter().zip(f.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>());
+        left = right;
+        right = new_right;
+    }
+
+    Zeroizing::new([right.as_slice(), left.as_slice()].concat())
+}
+
+fn decrypt(encrypted: &[u8], passphrase: &[u8], e: u8, identifier: u16, extendable: bool) -> Zeroizing<Vec<u8>> {
+    let half = encrypted.len() / 2;
+    let mut left = Zeroizing::new(encrypted[..half].to_vec());
+    let mut right = Zeroizing::new(encrypted[half..].to_vec());
+    let salt = encryption_salt(identifier, extendable);
+
+    for i in (0..ROUND_COUNT).rev() {
+        let f = round_function(i, passphrase, e, &salt, &right);
+        let new_right = Zeroizing::new(left.iter().zip(f.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>());
+        left = right;
+        right = new_right;
+    }
+
+    Zeroizing::new([right.as_slice(), left.as_slice()].concat())
+}
+
+/// F(i, R) = PBKDF2-HMAC-SHA256(i || passphrase, salt || R, (10000 << e) / 4)
+fn round_function(i: u8, passphrase: &[u8], e: u8, salt: &[u8], right: &[u8]) -> Zeroizing<Vec<u8>> {
+    let mut password = Zeroizing::new(vec![i]);
+    password.extend_from_slice(passphrase);
+
+    let mut round_salt = Zeroizing::new(salt.to_vec());
+    round_salt.extend_from_slice(right);
+
+    let iterations = (BASE_ITERATION_COUNT << e) / ROUND_COUNT as u32;
+    let mut output = Zeroizing::new(vec![0u8; right.len()]);
+    pbkdf2_hmac::<Sha256>(&password, &round_salt, iterations, &mut output);
+    output
+}
+
+/// extendable이면 빈 salt, 아니면 "shamir" || 식별자
+fn encryption_salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        Vec::new()
+    } else {
+        let mut salt = b"shamir".to_vec();
+        salt.extend_from_slice(&identifier.to_be_bytes());
+        salt
+    }
+}
+
+/// 출력 가능한 ASCII(0x20~0x7E)만 허용
+fn check_passphrase(passphrase: &str) -> Result<()> {
+    if passphrase.bytes().all(|b| (0x20..=0x7e).contains(&b)) {
+        Ok(())
+    } else {
+        Err(Error::Shamir("패스프레이즈는 출력 가능한 ASCII 문자만 사용할 수 있습니다".to_string()))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RS1024 체크섬
+// ═══════════════════════════════════════════════════════════════
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+fn rs1024_polymod(values: impl Iterator<Item = u32>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009,
+        0x1c0c2412, 0x38086c24, 0x3090fc48, 0x21b1f890, 0x3f3f120,
+    ];
+
+    let mut chk: u32 = 1;
+    for v in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xfffff) << 10) ^ v;
+        for (i, gen) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(data: &[u16], customization: &[u8]) -> Vec<u16> {
+    let values = customization
+        .iter()
+        .map(|&c| c as u32)
+        .chain(data.iter().map(|&d| d as u32))
+        .chain([0, 0, 0]);
+    let polymod = rs1024_polymod(values) ^ 1;
+
+    (0..CHECKSUM_LENGTH_WORDS)
+        .map(|i| ((polymod >> (10 * (2 - i))) & 1023) as u16)
+        .collect()
+}
+
+fn rs1024_verify_checksum(data: &[u16], customization: &[u8]) -> bool {
+    let values = customization
+        .iter()
+        .map(|&c| c as u32)
+        .chain(data.iter().map(|&d| d as u32));
+    rs1024_polymod(values) == 1
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 단어/비트 변환
+// ═══════════════════════════════════════════════════════════════
+
+fn slip39_wordlist() -> Vec<&'static str> {
+    WORDLIST_SLIP39.lines().collect()
+}
+
+fn mnemonic_to_indices(mnemonic: &str) -> Result<Vec<u16>> {
+    let wordlist = slip39_wordlist();
+    mnemonic
+        .split_whitespace()
+        .map(|word| {
+            let word = word.to_lowercase();
+            wordlist
+                .binary_search(&word.as_str())
+                .map(|i| i as u16)
+                .map_err(|_| Error::Shamir(format!("SLIP-39 단어 목록에 없는 단어: {}", word)))
+        })
+        .collect()
+}
+
+/// 정수 → 10비트 인덱스 (big-endian)
+fn int_to_indices(value: u64, count: usize) -> Vec<u16> {
+    (0..count)
+        .rev()
+        .map(|i| ((value >> (i * RADIX_BITS)) & 1023) as u16)
+        .collect()
+}
+
+fn indices_to_int(indices: &[u16]) -> u64 {
+    indices.iter().fold(0u64, |acc, &i| (acc << RADIX_BITS) | i as u64)
+}
+
+/// 바이트 → 10비트 인덱스 (앞쪽에 0 패딩)
+fn bytes_to_indices(bytes: &[u8]) -> Zeroizing<Vec<u16>> {
+    let bit_len = bytes.len() * 8;
+    let word_count = bit_len.div_ceil(RADIX_BITS);
+    let padding = word_count * RADIX_BITS - bit_len;
+
+    let mut bits = Zeroizing::new(vec![false; padding]);
+    for byte in bytes {
+        for i in (0..8).rev() {
+            bits.push((byte >> i) & 1 == 1);
+        }
+    }
+
+    Zeroizing::new(
+        bits.chunks(RADIX_BITS)
+            .map(|chunk| chunk.iter().fold(0u16, |acc, &bit| (acc << 1) | bit as u16))
+            .collect(),
+    )
+}
+
+/// 10비트 인덱스 → 바이트 (앞쪽 패딩 비트는 0이어야 함)
+fn indices_to_bytes(indices: &[u16], padding_len: usize) -> Result<Vec<u8>> {
+    let mut bits = Zeroizing::new(Vec::with_capacity(indices.len() * RADIX_BITS));
+    for index in indices {
+        for i in (0..RADIX_BITS).rev() {
+            bits.push((index >> i) & 1 == 1);
+        }
+    }
+
+    if bits[..padding_len].iter().any(|&bit| bit) {
+        return Err(Error::Shamir("share 패딩 비트가 0이 아닙니다".to_string()));
+    }
+
+    Ok(bits[padding_len..]
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wordlist() {
+        let wordlist = slip39_wordlist();
+        assert_eq!(wordlist.len(), 1024);
+
+        // 정렬 + 앞 4글자로 유일하게 구분
+        let mut sorted = wordlist.clone();
+        sorted.sort();
+        assert_eq!(sorted, wordlist);
+
+        let mut prefixes: Vec<&str> = wordlist.iter().map(|w| &w[..4.min(w.len())]).collect();
+        prefixes.dedup();
+        assert_eq!(prefixes.len(), 1024);
+    }
+
+    #[test]
+    fn test_vector_single_share() {
+        // SLIP-39 테스트 벡터 1: 분할 없음 (128비트)
+        let mnemonic = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+        let secret = combine_mnemonics(&[mnemonic], "TREZOR").unwrap();
+        assert_eq!(hex::encode(secret.as_slice()), "bb54aac4b89dc868ba37d9cc21b2cece");
+    }
+
+    #[test]
+    fn test_vector_invalid_checksum() {
+        // SLIP-39 테스트 벡터 2: 체크섬 오류 (마지막 단어만 다름)
+        let mnemonic = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney";
+        assert!(combine_mnemonics(&[mnemonic], "TREZOR").is_err());
+    }
+
+    #[test]
+    fn test_vector_two_of_three() {
+        // SLIP-39 테스트 벡터 4: 2-of-3 기본 분할 (128비트)
+        let mnemonics = [
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ];
+        let secret = combine_mnemonics(&mnemonics, "TREZOR").unwrap();
+        assert_eq!(hex::encode(secret.as_slice()), "b43ceb7e57a0ea8766221624d01b0864");
+
+        // share 하나로는 복원 불가
+        assert!(combine_mnemonics(&mnemonics[..1], "TREZOR").is_err());
+    }
+
+    #[test]
+    fn test_split_and_combine_groups() {
+        let secret = hex::decode("0c94b6c6f2c3c2b2b7d4a1e4b5c6d7e8").unwrap();
+        let groups = [
+            GroupSpec { member_threshold: 1, member_count: 1 },
+            GroupSpec { member_threshold: 2, member_count: 3 },
+            GroupSpec { member_threshold: 2, member_count: 2 },
+        ];
+
+        let shares = split_master_secret(&secret, "pass", 2, &groups, 0, true).unwrap();
+        assert_eq!(shares.len(), 3);
+        assert_eq!(shares[1].len(), 3);
+
+        for share in &shares[1] {
+            println!("가족 share: {}", share.as_str());
+            assert_eq!(share.split_whitespace().count(), 20);
+        }
+
+        // 그룹 0 (1개) + 그룹 1 (3개 중 2개)
+        let selected = [shares[0][0].as_str(), shares[1][0].as_str(), shares[1][2].as_str()];
+        assert_eq!(combine_mnemonics(&selected, "pass").unwrap().as_slice(), secret.as_slice());
+
+        // 그룹 1 + 그룹 2
+        let selected = [
+            shares[1][1].as_str(),
+            shares[1][2].as_str(),
+            shares[2][0].as_str(),
+            shares[2][1].as_str(),
+        ];
+        assert_eq!(combine_mnemonics(&selected, "pass").unwrap().as_slice(), secret.as_slice());
+
+        // 다른 패스프레이즈 → 다른 시크릿 (SLIP-39는 패스프레이즈 오류를 검출하지 않음)
+        assert_ne!(combine_mnemonics(&selected, "wrong").unwrap().as_slice(), secret.as_slice());
+
+        // 그룹 하나만으로는 부족
+        assert!(combine_mnemonics(&[shares[0][0].as_str()], "pass").is_err());
+    }
+
+    #[test]
+    fn test_split_256_bit() {
+        let secret = [0x42u8; 32];
+        let groups = [GroupSpec { member_threshold: 3, member_count: 5 }];
+
+        let shares = split_master_secret(&secret, "", 1, &groups, 0, false).unwrap();
+        assert_eq!(shares[0][0].split_whitespace().count(), 33);
+
+        let selected: Vec<&str> = shares[0].iter().skip(2).map(|s| s.as_str()).collect();
+        assert_eq!(combine_mnemonics(&selected, "").unwrap().as_slice(), secret.as_slice());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let secret = [0u8; 16];
+        let one_of_two = [GroupSpec { member_threshold: 1, member_count: 2 }];
+        assert!(split_master_secret(&secret, "", 1, &one_of_two, 0, false).is_err());
+
+        let single = [GroupSpec { member_threshold: 1, member_count: 1 }];
+        assert!(split_master_secret(&[0u8; 15], "", 1, &single, 0, false).is_err());
+        assert!(split_master_secret(&secret, "", 2, &single, 0, false).is_err());
+        assert!(split_master_secret(&secret, "비밀", 1, &single, 0, false).is_err());
+    }
+
+    #[test]
+    fn test_share_fields() {
+        let mnemonic = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+        let share = Share::from_mnemonic(mnemonic).unwrap();
+        assert_eq!(share.to_mnemonic().unwrap().as_str(), mnemonic);
+
+        // Debug에는 share 값이 나오지 않음
+        let debug = format!("{:?}", share);
+        assert!(debug.contains("value: <16바이트>"), "{}", debug);
+        assert!(!debug.contains(&format!("{:?}", share.value.as_slice())));
+
+        // 범위를 벗어난 필드는 패닉 대신 에러
+        let invalid = [
+            Share { group_threshold: 0, ..share.clone() },
+            Share { member_threshold: 0, ..share.clone() },
+            Share { group_count: 0, ..share.clone() },
+            Share { group_index: 1, ..share.clone() },
+            Share { member_threshold: 17, ..share.clone() },
+            Share { identifier: 1 << 15, ..share.clone() },
+            Share { value: Zeroizing::new(vec![0; 15]), ..share.clone() },
+        ];
+        for share in invalid {
+            assert!(share.to_mnemonic().is_err(), "{:?}", share);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Solana Vanity Address Grinding
+//!
+//! `solana-keygen grind`처럼 원하는 접두사/접미사를 가진 주소를 찾을 때까지
+//! 랜덤 키를 생성 (모든 CPU 코어 사용)
+//!
+//! ## 난이도
+//! Base58 문자 하나당 약 58배 → 접두사 4자 ≈ 1100만 번 시도
+//! 대소문자 무시 시 문자당 약 2배 쉬워짐
+//!
+//! ## 성능
+//! 반복마다 힙 할당이 없도록 개인키와 Base58 주소를 스택 버퍼에서 처리
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use rand::RngCore;
+use rayon::prelude::*;
+
+use super::SolanaAccount;
+
+/// Base58 알파벳 (0, O, I, l 제외)
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// 진행 상황 콜백 호출 간격 (스레드별 시도 횟수)
+const PROGRESS_INTERVAL: u64 = 1000;
+
+/// 주소 패턴
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrindPattern {
+    /// 접두사
+    StartsWith(String),
+    /// 접미사
+    EndsWith(String),
+    /// 접두사 + 접미사
+    StartsAndEndsWith(String, String),
+}
+
+/// 탐색 옵션
+#[derive(Debug, Clone)]
+pub struct GrindOptions {
+    /// 패턴 목록 (하나라도 맞으면 일치)
+    pub patterns: Vec<GrindPattern>,
+    /// 대소문자 무시
+    pub ignore_case: bool,
+    /// 찾을 계정 수
+    pub count: usize,
+}
+
+impl GrindPattern {
+    /// 주소(Base58 바이트)가 패턴과 일치하는지 검사
+    fn matches(&self, address: &[u8], ignore_case: bool) -> bool {
+        let eq = |a: &[u8], b: &[u8]| {
+            if ignore_case {
+                a.eq_ignore_ascii_case(b)
+            } else {
+                a == b
+            }
+        };
+        let starts = |prefix: &str| {
+            address.len() >= prefix.len() && eq(&address[..prefix.len()], prefix.as_bytes())
+        };
+        let ends = |suffix: &str| {
+            address.len() >= suffix.len()
+                && eq(&address[address.len() - suffix.len()..], suffix.as_bytes())
+        };
+
+        match self {
+            GrindPattern::StartsWith(prefix) => starts(prefix),
+            GrindPattern::EndsWith(suffix) => ends(suffix),
+            GrindPattern::StartsAndEndsWith(prefix, suffix) => starts(prefix) && ends(suffix),
+        }
+    }
+
+    /// 패턴 문자열들
+    fn parts(&self) -> Vec<&str> {
+        match self {
+            GrindPattern::StartsWith(s) | GrindPattern::EndsWith(s) => vec![s],
+            GrindPattern::StartsAndEndsWith(prefix, suffix) => vec![prefix, suffix],
+        }
+    }
+}
+
+/// 패턴과 일치하는 Solana 주소 탐색
+///
+/// `on_progress(총 시도 횟수)`를 주기적으로 호출하며, `false`를 반환하면 탐색 중단
+/// 중단 시 그때까지 찾은 계정만 반환
+pub fn grind<F>(options: &GrindOptions, on_progress: F) -> Result<Vec<SolanaAccount>>
+where
+    F: Fn(u64) -> bool + Sync,
+{
+    validate_options(options)?;
+
+    let attempts = AtomicU64::new(0);
+    let stop = AtomicBool::new(false);
+    let found = Mutex::new(Vec::with_capacity(options.count));
+
+    (0..rayon::current_num_threads()).into_par_iter().for_each(|_| {
+        let mut rng = rand::thread_rng();
+        let mut private_key = [0u8; 32];
+        let mut address = [0u8; 44]; // 32바이트 Base58 최대 44자
+        let mut local_attempts = 0u64;
+
+        while !stop.load(Ordering::Relaxed) {
+            rng.fill_bytes(&mut private_key);
+            let signing_key = SigningKey::from_bytes(&private_key);
+            let verifying_key: VerifyingKey = (&signing_key).into();
+
+            let len = bs58::encode(verifying_key.as_bytes())
+                .onto(&mut address[..])
+                .expect("44바이트 버퍼");
+
+            if options
+                .patterns
+                .iter()
+                .any(|pattern| pattern.matches(&address[..len], options.ignore_case))
+            {
+                let mut found = found.lock().expect("결과 잠금");
+                if found.len() < options.count {
+                    found.push(SolanaAccount::from_private_key(private_key).expect("무작위 개인키"));
+                }
+                if found.len() >= options.count {
+                    stop.store(true, Ordering::Relaxed);
+                }
+            }
+
+            local_attempts += 1;
+            if local_attempts == PROGRESS_INTERVAL {
+                let total = attempts.fetch_add(local_attempts, Ordering::Relaxed) + local_attempts;
+                local_attempts = 0;
+                if !on_progress(total) {
+                    stop.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+    });
+
+    Ok(found.into_inner().expect("결과 잠금"))
+}
+
+/// 옵션 검증 (Base58에 없는 문자는 절대 찾을 수 없음)
+fn validate_options(options: &GrindOptions) -> Result<()> {
+    if options.patterns.is_empty() {
+        return Err(Error::InvalidInput("패턴이 하나 이상 필요합니다".to_string()));
+    }
+    if options.count == 0 {
+        return Err(Error::InvalidInput("찾을 계정 수는 1 이상이어야 합니다".to_string()));
+    }
+
+    for pattern in &options.patterns {
+        for part in pattern.parts() {
+            if part.is_empty() {
+                return Err(Error::InvalidInput("빈 패턴은 사용할 수 없습니다".to_string()));
+            }
+            if let Some(c) = part.chars().find(|&c| !is_base58_char(c, options.ignore_case)) {
+                return Err(Error::Base58(format!("Base58에 없는 문자: '{}' (패턴: {})", c, part)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Base58 문자인지 검사 (대소문자 무시 시 어느 한쪽이라도 있으면 허용)
+fn is_base58_char(c: char, ignore_case: bool) -> bool {
+    if ignore_case {
+        BASE58_ALPHABET.contains(c.to_ascii_lowercase()) || BASE58_ALPHABET.contains(c.to_ascii_uppercase())
+    } else {
+        BASE58_ALPHABET.contains(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grind_prefix() {
+        let options = GrindOptions {
+            patterns: vec![GrindPattern::StartsWith("a".to_string())],
+            ignore_case: true,
+            count: 2,
+        };
+
+        let accounts = grind(&options, |_| true).unwrap();
+        assert_eq!(accounts.len(), 2);
+
+        for account in accounts {
+            println!("찾은 주소: {}", account.address());
+            assert!(account.address().to_lowercase().starts_with('a'));
+        }
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        let address = b"AbcXYZ";
+
+        assert!(GrindPattern::StartsWith("Abc".into()).matches(address, false));
+        assert!(!GrindPattern::StartsWith("abc".into()).matches(address, false));
+        assert!(GrindPattern::StartsWith("abc".into()).matches(address, true));
+        assert!(GrindPattern::EndsWith("xyz".into()).matches(address, true));
+        assert!(GrindPattern::StartsAndEndsWith("A".into(), "Z".into()).matches(address, false));
+        assert!(!GrindPattern::StartsAndEndsWith("A".into(), "Y".into()).matches(address, false));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let options = |pattern: &str, ignore_case| GrindOptions {
+            patterns: vec![GrindPattern::StartsWith(pattern.to_string())],
+            ignore_case,
+            count: 1,
+        };
+
+        // 0, O, I, l은 Base58에 없음
+        assert!(grind(&options("0", false), |_| true).is_err());
+        assert!(grind(&options("Sol", false), |_| true).is_err());
+
+        // 'S', 'o'는 Base58에 있고 'l'은 대문자 'L'로 찾을 수 있음
+        assert!(validate_options(&options("Sol", true)).is_ok());
+    }
+
+    #[test]
+    fn test_grind_cancel() {
+        // 사실상 찾을 수 없는 패턴 → 첫 진행 콜백에서 중단
+        let options = GrindOptions {
+            patterns: vec![GrindPattern::StartsWith("zzzzzzzzzz".to_string())],
+            ignore_case: false,
+            count: 1,
+        };
+
+        let accounts = grind(&options, |attempts| {
+            assert!(attempts >= PROGRESS_INTERVAL);
+            false
+        })
+        .unwrap();
+
+        assert!(accounts.is_empty());
+    }
+}
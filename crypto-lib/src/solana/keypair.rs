@@ -0,0 +1,211 @@
+//! Solana Keypair Files
+//!
+//! ## id.json (solana-keygen)
+//! 64바이트 keypair(개인키 32 + 공개키 32)를 숫자 JSON 배열로 저장
+//!
+//! ```text
+//! [174,47,154,...,23]
+//! ```
+//!
+//! ## Base58 keypair (Phantom, Solflare)
+//! 같은 64바이트 keypair를 Base58 문자열 하나로 인코딩 (약 88자)
+//!
+//! 불러올 때 뒤 32바이트 공개키가 개인키에서 도출한 공개키와 같은지 검증
+//! (`from_keypair_bytes`는 이미 디코딩한 64바이트에 같은 검증 적용)
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Result};
+
+use super::SolanaAccount;
+
+impl SolanaAccount {
+    /// 64바이트 keypair(개인키 + 공개키)에서 계정 생성 (`keypair_bytes`의 역)
+    ///
+    /// 공개키 절반이 개인키에서 도출한 공개키와 다르면 `Error::InvalidKey`
+    pub fn from_keypair_bytes(bytes: [u8; 64]) -> Result<Self> {
+        let bytes = Zeroizing::new(bytes);
+        keypair_from_bytes(bytes.as_slice())
+    }
+
+    /// solana-keygen id.json 형식 문자열 (64개 숫자 JSON 배열)
+    pub fn to_json_keypair(&self) -> String {
+        serde_json::to_string(&self.keypair_bytes().to_vec()).expect("숫자 배열 직렬화")
+    }
+
+    /// id.json 형식 문자열에서 계정 생성
+    pub fn from_json_keypair(json: &str) -> Result<Self> {
+        let bytes: Vec<u8> = serde_json::from_str(json)
+            .map_err(|e| Error::InvalidKey(format!("keypair JSON 파싱 실패: {}", e)))?;
+        keypair_from_bytes(&bytes)
+    }
+
+    /// Phantom/Solflare 내보내기 형식 (64바이트 keypair의 Base58)
+    pub fn to_base58_keypair(&self) -> String {
+        bs58::encode(self.keypair_bytes()).into_string()
+    }
+
+    /// Phantom/Solflare 내보내기 형식에서 계정 생성
+    ///
+    /// 32바이트 시드(개인키만)는 keypair와 구분하기 위해 오류로 처리
+    /// → 시드라면 디코딩 후 `from_private_key` 사용
+    pub fn from_base58_keypair(encoded: &str) -> Result<Self> {
+        let bytes = bs58::decode(encoded.trim())
+            .into_vec()
+            .map_err(|e| Error::Base58(format!("유효하지 않은 Base58: {}", e)))?;
+
+        match bytes.len() {
+            64 => keypair_from_bytes(&bytes),
+            32 => Err(Error::InvalidKey(
+                "32바이트 시드입니다 (64바이트 keypair 아님) - 개인키만 있다면 from_private_key를 사용하세요"
+                    .to_string(),
+            )),
+            len => Err(Error::InvalidKey(format!("keypair는 64바이트여야 합니다, 입력: {}바이트", len))),
+        }
+    }
+
+    /// id.json 파일 쓰기
+    ///
+    /// solana-keygen과 같이 유닉스에서는 소유자만 읽을 수 있게(0600) 생성
+    pub fn write_json_keypair(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options
+            .open(path)
+            .map_err(|e| Error::Io(format!("keypair 파일 생성 실패: {}", e)))?;
+        file.write_all(self.to_json_keypair().as_bytes())
+            .map_err(|e| Error::Io(format!("keypair 파일 쓰기 실패: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// id.json 파일 읽기
+    pub fn read_json_keypair(path: impl AsRef<Path>) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| Error::Io(format!("keypair 파일 읽기 실패: {}", e)))?;
+        Self::from_json_keypair(&json)
+    }
+}
+
+/// 64바이트 keypair → 계정 (공개키 일치 검증)
+fn keypair_from_bytes(bytes: &[u8]) -> Result<SolanaAccount> {
+    if bytes.len() != 64 {
+        return Err(Error::InvalidKey(format!(
+            "keypair는 64바이트여야 합니다 (개인키 + 공개키), 입력: {}바이트",
+            bytes.len()
+        )));
+    }
+
+    let mut private_key = [0u8; 32];
+    private_key.copy_from_slice(&bytes[..32]);
+    let account = SolanaAccount::from_private_key(private_key)?;
+
+    if account.public_key[..] != bytes[32..] {
+        return Err(Error::InvalidKey("keypair의 공개키가 개인키와 일치하지 않습니다".to_string()));
+    }
+
+    Ok(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_json_keypair_roundtrip() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        let json = account.to_json_keypair();
+        println!("id.json: {}", json);
+        assert!(json.starts_with('[') && json.ends_with(']'));
+        assert_eq!(json.split(',').count(), 64);
+
+        let restored = SolanaAccount::from_json_keypair(&json).unwrap();
+        assert_eq!(restored.address(), account.address());
+    }
+
+    #[test]
+    fn test_json_keypair_rejects_mismatched_pubkey() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let mut keypair = account.keypair_bytes();
+        keypair[63] ^= 0x01;
+
+        let json = serde_json::to_string(&keypair.to_vec()).unwrap();
+        assert!(SolanaAccount::from_json_keypair(&json).is_err());
+
+        // 길이 오류
+        assert!(SolanaAccount::from_json_keypair("[1,2,3]").is_err());
+    }
+
+    #[test]
+    fn test_keypair_bytes_roundtrip() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        let restored = SolanaAccount::from_keypair_bytes(account.keypair_bytes()).unwrap();
+        assert_eq!(restored.address(), account.address());
+        assert_eq!(restored.private_key, account.private_key);
+
+        let mut keypair = account.keypair_bytes();
+        keypair[32] ^= 0x01;
+        assert_eq!(
+            SolanaAccount::from_keypair_bytes(keypair).unwrap_err(),
+            Error::InvalidKey("keypair의 공개키가 개인키와 일치하지 않습니다".to_string())
+        );
+        assert!(SolanaAccount::from_keypair_bytes([0u8; 64]).is_err());
+    }
+
+    #[test]
+    fn test_base58_keypair_roundtrip() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        let encoded = account.to_base58_keypair();
+        println!("Base58 keypair: {}", encoded);
+
+        let restored = SolanaAccount::from_base58_keypair(&encoded).unwrap();
+        assert_eq!(restored.address(), account.address());
+    }
+
+    #[test]
+    fn test_base58_keypair_rejects_seed() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        // 32바이트 시드는 keypair로 받지 않음
+        let seed_only = bs58::encode(account.private_key).into_string();
+        let err = SolanaAccount::from_base58_keypair(&seed_only).unwrap_err();
+        assert!(err.to_string().contains("32바이트"));
+
+        assert!(SolanaAccount::from_base58_keypair("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_json_keypair_file() {
+        let path = std::env::temp_dir().join(format!("solana-id-test-{}.json", std::process::id()));
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        account.write_json_keypair(&path).unwrap();
+        let restored = SolanaAccount::read_json_keypair(&path).unwrap();
+        assert_eq!(restored.address(), account.address());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+}
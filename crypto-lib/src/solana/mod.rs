@@ -13,17 +13,37 @@
 //! ## SLIP-10 vs BIP-32
 //! - BIP-32: secp256k1 전용
 //! - SLIP-10: Ed25519용 수정 버전 (강화 도출만 지원)
+//!
+//! ## 지갑별 도출 방식
+//! 같은 니모닉이라도 지갑마다 주소가 다를 수 있음 → `SolanaDerivationScheme` 참고
+//!
+//! ## 하위 모듈
+//! - `grind`: vanity 주소 탐색 (solana-keygen grind)
+//! - `keypair`: keypair 가져오기/내보내기 (solana-keygen id.json, Phantom Base58)
+//! - `pda`: Program Derived Address, createWithSeed 주소 계산
+//! - `signing`: 오프라인 메시지/트랜잭션 서명
+
+pub mod grind;
+pub mod keypair;
+pub mod pda;
+pub mod signing;
 
 use ed25519_dalek::{SigningKey, VerifyingKey};
 
-use crate::bip39::mnemonic_to_seed;
-use crate::utils::slip10::derive_ed25519_key;
+use crate::bip32::{ChildNumber, DerivationPath, IntoDerivationPath};
+use crate::account_iter::AccountIter;
+use crate::bip39::{mnemonic_to_seed, mnemonic_to_seed_checked};
+use crate::slip10::derive_ed25519_key;
+use crate::secret::SecretBytes;
+use crate::error::{Error, Result};
 
 /// Solana 계정
-#[derive(Debug, Clone)]
+///
+/// `Debug`는 개인키를 가림 (`account` 모듈에서 구현)
+#[derive(Clone)]
 pub struct SolanaAccount {
     /// 개인키 (32바이트)
-    pub private_key: [u8; 32],
+    pub private_key: SecretBytes<32>,
     /// 공개키 (32바이트) = 주소
     pub public_key: [u8; 32],
 }
@@ -31,33 +51,140 @@ pub struct SolanaAccount {
 /// Solana 기본 도출 경로
 pub const SOLANA_PATH: &str = "m/44'/501'/0'/0'";
 
+/// Solana 키 도출 방식 (지갑마다 다름)
+///
+/// `index`는 지갑에서 "계정 N"에 해당
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolanaDerivationScheme {
+    /// SLIP-10 m/44'/501'/{index}'/0' - Phantom, Solflare, Backpack 기본값
+    Bip44Change,
+    /// SLIP-10 m/44'/501'/{index}' - Ledger Live, Trust Wallet
+    Bip44,
+    /// SLIP-10 m/44'/501' - Solflare의 예전 루트 경로 (계정 1개)
+    Bip44Root,
+    /// 도출 없음: BIP-39 시드의 앞 32바이트를 그대로 개인키로 사용
+    /// - `solana-keygen new` (경로 옵션 없이 생성한 공식 CLI 지갑, 계정 1개)
+    NoDerivation,
+}
+
+impl SolanaDerivationScheme {
+    /// 모든 도출 방식 (복구 시 순서대로 시도)
+    pub const ALL: [SolanaDerivationScheme; 4] = [
+        SolanaDerivationScheme::Bip44Change,
+        SolanaDerivationScheme::Bip44,
+        SolanaDerivationScheme::Bip44Root,
+        SolanaDerivationScheme::NoDerivation,
+    ];
+
+    /// 계정 인덱스의 SLIP-10 경로 (도출 없음이면 None)
+    pub fn path(&self, index: u32) -> Option<String> {
+        match self {
+            SolanaDerivationScheme::Bip44Change => Some(format!("m/44'/501'/{}'/0'", index)),
+            SolanaDerivationScheme::Bip44 => Some(format!("m/44'/501'/{}'", index)),
+            SolanaDerivationScheme::Bip44Root => Some("m/44'/501'".to_string()),
+            SolanaDerivationScheme::NoDerivation => None,
+        }
+    }
+
+    /// 계정 인덱스를 지원하는지 (여러 계정 도출 가능)
+    pub fn supports_index(&self) -> bool {
+        matches!(self, SolanaDerivationScheme::Bip44Change | SolanaDerivationScheme::Bip44)
+    }
+}
+
 impl SolanaAccount {
-    /// 개인키에서 Solana 계정 생성
-    pub fn from_private_key(private_key: [u8; 32]) -> Self {
+    /// 개인키(Ed25519 시드)에서 Solana 계정 생성
+    ///
+    /// 32바이트 모두 Ed25519 시드로 쓸 수 있지만, 0으로 채워진 키는 초기화되지 않은 입력으로 보고 오류
+    pub fn from_private_key(private_key: [u8; 32]) -> Result<Self> {
+        if private_key == [0u8; 32] {
+            return Err(Error::InvalidKey("Ed25519 개인키가 0입니다".to_string()));
+        }
         let signing_key = SigningKey::from_bytes(&private_key);
         let verifying_key: VerifyingKey = (&signing_key).into();
 
-        SolanaAccount {
-            private_key,
+        Ok(SolanaAccount {
+            private_key: SecretBytes::new(private_key),
             public_key: verifying_key.to_bytes(),
-        }
+        })
     }
 
     /// 시드에서 Solana 계정 생성 (기본 경로)
-    pub fn from_seed(seed: &[u8]) -> Result<Self, String> {
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
         Self::from_seed_with_path(seed, SOLANA_PATH)
     }
 
     /// 시드에서 특정 경로로 Solana 계정 생성 (SLIP-10)
-    pub fn from_seed_with_path(seed: &[u8], path: &str) -> Result<Self, String> {
+    pub fn from_seed_with_path(seed: &[u8], path: impl IntoDerivationPath) -> Result<Self> {
         let private_key = derive_ed25519_key(seed, path)?;
-        Ok(Self::from_private_key(private_key))
+        Self::from_private_key(private_key)
+    }
+
+    /// 시드에서 계정을 index 순서로 도출하는 반복자 (Phantom 방식 m/44'/501'/i'/0')
+    pub fn iter_from_seed(seed: &[u8]) -> Result<AccountIter<Self>> {
+        AccountIter::ed25519(seed, "m/44'/501'", "m/0'", 0, Self::from_private_key)
     }
 
     /// 니모닉에서 Solana 계정 생성
-    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, String> {
+    ///
+    /// BIP-39 체크섬까지 검증 (틀리면 `Error::InvalidMnemonic`)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// BIP-39 검증 없이 Solana 계정 생성 (`SOLANA_PATH`, Phantom 첫 계정)
+    pub fn from_mnemonic_unchecked(mnemonic: &str, passphrase: &str) -> Result<Self> {
         let seed = mnemonic_to_seed(mnemonic, passphrase);
-        Self::from_seed(&seed)
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// 니모닉에서 Solana 계정 생성 (m/44'/501'/{account}'/{change}')
+    ///
+    /// 다른 체인의 `(account, change, index)`와 달리 `index` 인자가 없음 — Solana 경로는 4단계라
+    /// 지갑의 "계정 N"은 account = N. SLIP-10은 강화 도출만 쓰며, 인덱스가 2^31 이상이면 `Error::InvalidPath`
+    pub fn from_mnemonic_with_index(mnemonic: &str, passphrase: &str, account: u32, change: u32) -> Result<Self> {
+        let path = DerivationPath::from(vec![
+            ChildNumber::Hardened(44),
+            ChildNumber::Hardened(501),
+            ChildNumber::hardened(account)?,
+            ChildNumber::hardened(change)?,
+        ]);
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
+    }
+
+    /// 니모닉에서 Solana 계정 생성 (도출 없음, solana-keygen 호환)
+    ///
+    /// `solana-keygen new`는 SLIP-10 경로 없이 시드의 앞 32바이트를 개인키로 사용
+    pub fn from_mnemonic_no_derivation(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+
+        let mut private_key = [0u8; 32];
+        private_key.copy_from_slice(&seed[..32]);
+        Self::from_private_key(private_key)
+    }
+
+    /// 니모닉에서 지갑별 도출 방식으로 Solana 계정 생성
+    ///
+    /// 계정이 하나뿐인 방식(`Bip44Root`, `NoDerivation`)에 index > 0을 주면 오류
+    pub fn from_mnemonic_with_scheme(
+        mnemonic: &str,
+        passphrase: &str,
+        scheme: SolanaDerivationScheme,
+        index: u32,
+    ) -> Result<Self> {
+        if index > 0 && !scheme.supports_index() {
+            return Err(Error::InvalidInput(format!("{:?} 방식은 계정 인덱스를 지원하지 않습니다", scheme)));
+        }
+
+        match scheme.path(index) {
+            Some(path) => {
+                let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+                Self::from_seed_with_path(seed.as_slice(), &path)
+            }
+            None => Self::from_mnemonic_no_derivation(mnemonic, passphrase),
+        }
     }
 
     /// 주소 반환 (Base58 인코딩된 공개키)
@@ -67,7 +194,7 @@ impl SolanaAccount {
 
     /// 개인키를 hex로 반환
     pub fn private_key_hex(&self) -> String {
-        hex::encode(self.private_key)
+        hex::encode(&self.private_key)
     }
 
     /// 공개키를 hex로 반환
@@ -79,12 +206,27 @@ impl SolanaAccount {
     /// Solana CLI 호환 형식
     pub fn keypair_bytes(&self) -> [u8; 64] {
         let mut keypair = [0u8; 64];
-        keypair[..32].copy_from_slice(&self.private_key);
+        keypair[..32].copy_from_slice(self.private_key.as_slice());
         keypair[32..].copy_from_slice(&self.public_key);
         keypair
     }
 }
 
+/// Base58 주소 → 32바이트 공개키
+pub fn decode_address(address: &str) -> Result<[u8; 32]> {
+    let bytes = bs58::decode(address.trim())
+        .into_vec()
+        .map_err(|e| Error::Base58(format!("유효하지 않은 Base58: {}", e)))?;
+
+    if bytes.len() != 32 {
+        return Err(Error::InvalidAddress(format!("Solana 주소는 32바이트여야 합니다, 입력: {}바이트", bytes.len())));
+    }
+
+    let mut public_key = [0u8; 32];
+    public_key.copy_from_slice(&bytes);
+    Ok(public_key)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,7 +255,7 @@ mod tests {
 
         for i in 0..5 {
             let path = format!("m/44'/501'/{}'/0'", i);
-            let account = SolanaAccount::from_seed_with_path(&seed, &path).unwrap();
+            let account = SolanaAccount::from_seed_with_path(seed.as_slice(), &path).unwrap();
 
             println!("경로: {}", path);
             println!("주소: {}", account.address());
@@ -121,6 +263,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_no_derivation() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "");
+
+        let account = SolanaAccount::from_mnemonic_no_derivation(mnemonic, "").unwrap();
+        println!("solana-keygen 주소: {}", account.address());
+
+        // 시드 앞 32바이트 = 개인키
+        assert_eq!(&account.private_key[..], &seed[..32]);
+
+        // SLIP-10 도출 주소와 다름
+        let derived = SolanaAccount::from_mnemonic(mnemonic, "").unwrap();
+        assert_ne!(account.address(), derived.address());
+    }
+
+    #[test]
+    fn test_invalid_private_key() {
+        assert_eq!(SolanaAccount::from_private_key([0u8; 32]).unwrap_err(), Error::InvalidKey("Ed25519 개인키가 0입니다".to_string()));
+        // Ed25519 시드는 0이 아니면 모두 유효
+        assert!(SolanaAccount::from_private_key([0xff; 32]).is_ok());
+    }
+
+    #[test]
+    fn test_derivation_schemes() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        println!("\n=== 지갑별 도출 방식 ===\n");
+
+        let mut addresses = Vec::new();
+        for scheme in SolanaDerivationScheme::ALL {
+            let account = SolanaAccount::from_mnemonic_with_scheme(mnemonic, "", scheme, 0).unwrap();
+            println!("{:?} ({:?}): {}", scheme, scheme.path(0), account.address());
+            addresses.push(account.address());
+        }
+
+        // 기본 경로 = Bip44Change 계정 0
+        let default = SolanaAccount::from_mnemonic(mnemonic, "").unwrap();
+        assert_eq!(addresses[0], default.address());
+        // Phantom에서 확인 가능한 주소
+        assert_eq!(addresses[0], "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+
+        // 방식마다 주소가 모두 다름
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses.len(), SolanaDerivationScheme::ALL.len());
+
+        // 계정 1개 방식은 index > 0 거부
+        assert!(SolanaAccount::from_mnemonic_with_scheme(mnemonic, "", SolanaDerivationScheme::NoDerivation, 1).is_err());
+        assert!(SolanaAccount::from_mnemonic_with_scheme(mnemonic, "", SolanaDerivationScheme::Bip44, 1).is_ok());
+    }
+
     #[test]
     fn test_keypair_format() {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
@@ -130,7 +324,7 @@ mod tests {
         assert_eq!(keypair.len(), 64);
 
         // 앞 32바이트 = 개인키
-        assert_eq!(&keypair[..32], &account.private_key);
+        assert_eq!(&keypair[..32], account.private_key.as_slice());
         // 뒤 32바이트 = 공개키
         assert_eq!(&keypair[32..], &account.public_key);
 
@@ -0,0 +1,201 @@
+//! Solana Program Derived Address (PDA)
+//!
+//! 프로그램이 "소유"하는 주소 - 대응하는 개인키가 없음
+//!
+//! ## 도출 과정
+//! 1. SHA-256(seed_1 || ... || seed_n || program_id || "ProgramDerivedAddress")
+//! 2. 결과가 Ed25519 곡선 위의 점이면 실패 (개인키가 존재할 수 있으므로)
+//! 3. `find_program_address`는 bump(255 → 0)를 마지막 시드로 붙여가며
+//!    곡선 밖의 첫 주소를 찾음
+//!
+//! ## 제약
+//! - 시드 최대 16개
+//! - 시드 하나당 최대 32바이트
+//!
+//! ## createWithSeed (legacy)
+//! PDA 이전의 시드 주소 방식 - 스테이크 계정 등에 사용
+//! - SHA-256(base || seed || owner), 곡선 검사 없음
+//! - base 계정의 서명으로 생성하므로 base의 개인키가 필요
+
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+
+use crate::error::{Error, Result};
+
+/// 시드 최대 개수
+pub const MAX_SEEDS: usize = 16;
+/// 시드 하나의 최대 길이 (바이트)
+pub const MAX_SEED_LEN: usize = 32;
+
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+/// 시드와 프로그램 ID로 PDA 계산
+///
+/// 결과가 Ed25519 곡선 위에 있으면 오류 (다른 시드/bump 필요)
+pub fn create_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Result<[u8; 32]> {
+    check_seeds(seeds, MAX_SEEDS)?;
+
+    let address = hash_program_address(seeds, None, program_id);
+    if is_on_curve(&address) {
+        return Err(Error::InvalidAddress("주소가 Ed25519 곡선 위에 있습니다 (유효한 PDA 아님)".to_string()));
+    }
+
+    Ok(address)
+}
+
+/// bump seed를 찾아 PDA 계산
+///
+/// bump를 255부터 줄여가며 곡선 밖의 첫 주소를 반환
+/// 반환값: (PDA, bump)
+pub fn find_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Result<([u8; 32], u8)> {
+    // bump가 마지막 시드로 추가되므로 시드는 하나 적어야 함
+    check_seeds(seeds, MAX_SEEDS - 1)?;
+
+    for bump in (0..=u8::MAX).rev() {
+        let address = hash_program_address(seeds, Some(bump), program_id);
+        if !is_on_curve(&address) {
+            return Ok((address, bump));
+        }
+    }
+
+    Err(Error::InvalidInput("유효한 bump seed를 찾지 못했습니다".to_string()))
+}
+
+/// createWithSeed 주소 계산 (SystemProgram.createAccountWithSeed)
+///
+/// address = SHA-256(base || seed || owner)
+pub fn create_with_seed(base: &[u8; 32], seed: &str, owner: &[u8; 32]) -> Result<[u8; 32]> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err(Error::InvalidInput(format!(
+            "시드는 최대 {}바이트입니다, 입력: {}바이트",
+            MAX_SEED_LEN,
+            seed.len()
+        )));
+    }
+
+    // PDA 해시와 충돌하지 않도록 owner가 PDA 마커로 끝나면 거부
+    if owner.ends_with(PDA_MARKER) {
+        return Err(Error::InvalidInput("유효하지 않은 owner (PDA 마커로 끝남)".to_string()));
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(base);
+    hasher.update(seed.as_bytes());
+    hasher.update(owner);
+
+    let mut address = [0u8; 32];
+    address.copy_from_slice(&hasher.finalize());
+    Ok(address)
+}
+
+/// 시드 개수/길이 검사
+fn check_seeds(seeds: &[&[u8]], max_seeds: usize) -> Result<()> {
+    if seeds.len() > max_seeds {
+        return Err(Error::InvalidInput(format!("시드는 최대 {}개입니다, 입력: {}개", max_seeds, seeds.len())));
+    }
+    if let Some(seed) = seeds.iter().find(|seed| seed.len() > MAX_SEED_LEN) {
+        return Err(Error::InvalidInput(format!(
+            "시드 하나는 최대 {}바이트입니다, 입력: {}바이트",
+            MAX_SEED_LEN,
+            seed.len()
+        )));
+    }
+    Ok(())
+}
+
+/// SHA-256(seeds || [bump] || program_id || "ProgramDerivedAddress")
+fn hash_program_address(seeds: &[&[u8]], bump: Option<u8>, program_id: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    if let Some(bump) = bump {
+        hasher.update([bump]);
+    }
+    hasher.update(program_id);
+    hasher.update(PDA_MARKER);
+
+    let mut address = [0u8; 32];
+    address.copy_from_slice(&hasher.finalize());
+    address
+}
+
+/// 32바이트가 Ed25519 곡선 위의 점(압축 표현)인지 검사
+pub fn is_on_curve(bytes: &[u8; 32]) -> bool {
+    CompressedEdwardsY(*bytes).decompress().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::decode_address;
+
+    #[test]
+    fn test_create_program_address() {
+        // solana-program 테스트 벡터
+        let program_id = decode_address("BPFLoaderUpgradeab1e11111111111111111111111").unwrap();
+        let public_key = decode_address("SeedPubey1111111111111111111111111111111111").unwrap();
+
+        let cases: [(&[&[u8]], &str); 4] = [
+            (&[b"", &[1]], "BwqrghZA2htAcqq8dzP1WDAhTXYTYWj7CHxF5j7TDBAe"),
+            (&["☉".as_bytes(), &[0]], "13yWmRpaTR4r5nAktwLqMpRNr28tnVUZw26rTvPSSB19"),
+            (&[b"Talking", b"Squirrels"], "2fnQrngrQT4SeLcdToJAD96phoEjNL2man2kfRLCASVk"),
+            (&[&public_key, &[1]], "976ymqVnfE32QFe6NfGDctSvVa36LWnvYxhU6G2232YL"),
+        ];
+
+        for (seeds, expected) in cases {
+            let address = create_program_address(seeds, &program_id).unwrap();
+            assert_eq!(bs58::encode(address).into_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_find_program_address() {
+        let program_id = decode_address("BPFLoaderUpgradeab1e11111111111111111111111").unwrap();
+
+        let (address, bump) = find_program_address(&[b"vault"], &program_id).unwrap();
+        println!("PDA: {} (bump {})", bs58::encode(address).into_string(), bump);
+
+        // 찾은 bump로 다시 계산하면 같은 주소
+        assert_eq!(create_program_address(&[b"vault", &[bump]], &program_id).unwrap(), address);
+        assert!(!is_on_curve(&address));
+    }
+
+    #[test]
+    fn test_create_with_seed() {
+        // solana-program 테스트 벡터
+        let default = [0u8; 32];
+        let address = create_with_seed(&default, "limber chicken: 4/45", &default).unwrap();
+        assert_eq!(
+            bs58::encode(address).into_string(),
+            "9h1HyLCW5dZnBVap8C5egQ9Z6pHyjsh5MNy83iPqqRuq"
+        );
+
+        // 스테이크 계정 예시
+        let base = crate::solana::SolanaAccount::from_private_key([1u8; 32]).unwrap();
+        let stake_program = decode_address("Stake11111111111111111111111111111111111111").unwrap();
+        let stake = create_with_seed(&base.public_key, "stake:0", &stake_program).unwrap();
+        println!("스테이크 계정: {}", bs58::encode(stake).into_string());
+
+        // 시드 길이 제한
+        assert!(create_with_seed(&default, &"x".repeat(MAX_SEED_LEN + 1), &default).is_err());
+    }
+
+    #[test]
+    fn test_seed_limits() {
+        let program_id = [0u8; 32];
+
+        let long_seed = [0u8; MAX_SEED_LEN + 1];
+        assert!(create_program_address(&[&long_seed], &program_id).is_err());
+
+        let too_many: Vec<&[u8]> = vec![b"a"; MAX_SEEDS + 1];
+        assert!(create_program_address(&too_many, &program_id).is_err());
+    }
+
+    #[test]
+    fn test_wallet_address_is_on_curve() {
+        // 일반 지갑 주소(Ed25519 공개키)는 곡선 위에 있음
+        let account = crate::solana::SolanaAccount::from_private_key([1u8; 32]).unwrap();
+        assert!(is_on_curve(&account.public_key));
+    }
+}
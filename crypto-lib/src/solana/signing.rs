@@ -0,0 +1,268 @@
+//! Solana Offline Signing
+//!
+//! Solana 트랜잭션 서명 = 직렬화된 메시지에 대한 Ed25519 서명 (해시 없이 그대로)
+//!
+//! ## 트랜잭션 와이어 포맷
+//! ```text
+//! compact-u16(서명 개수) || 서명 64바이트 × N || 메시지
+//! ```
+//!
+//! ## 메시지 구조
+//! ```text
+//! [0x80 | 버전]?            ← v0 이상일 때만 (legacy는 없음)
+//! 헤더 3바이트              ← 필요 서명 수, 읽기 전용 서명자 수, 읽기 전용 비서명자 수
+//! compact-u16(계정 수) || 공개키 32바이트 × M
+//! ...                       ← 최근 블록해시, 명령어 등
+//! ```
+//!
+//! 서명자는 계정 목록의 앞쪽(필요 서명 수만큼)에 위치
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+use crate::error::{Error, Result};
+use crate::trace;
+
+use super::{decode_address, SolanaAccount};
+
+impl SolanaAccount {
+    /// 메시지 서명 (Ed25519)
+    ///
+    /// 트랜잭션의 경우 `message`는 직렬화된 트랜잭션 메시지
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        let _span = trace::span!("ed25519.sign", message_len = message.len());
+        let signing_key = SigningKey::from_bytes(&self.private_key);
+        signing_key.sign(message).to_bytes()
+    }
+
+    /// base64 인코딩된 미서명 트랜잭션에 서명
+    ///
+    /// 이 계정이 트랜잭션의 필요 서명자인지 확인 후 메시지 부분에 서명
+    /// 반환값: Base58 서명 (트랜잭션 ID로도 사용됨)
+    pub fn sign_transaction_base64(&self, tx_base64: &str) -> Result<String> {
+        let tx = BASE64
+            .decode(tx_base64.trim())
+            .map_err(|e| Error::InvalidSignature(format!("유효하지 않은 base64: {}", e)))?;
+
+        let message = transaction_message(&tx)?;
+        let signers = required_signers(message)?;
+
+        if !signers.contains(&self.public_key) {
+            return Err(Error::InvalidSignature(format!("{}는 이 트랜잭션의 서명자가 아닙니다", self.address())));
+        }
+
+        Ok(bs58::encode(self.sign_message(message)).into_string())
+    }
+
+    /// 직렬화된 트랜잭션 메시지에 서명 → 브로드캐스트할 트랜잭션 (와이어 포맷)
+    ///
+    /// 서명 자리는 필요 서명 수만큼 만들고 이 계정의 자리에만 서명을 넣음
+    /// (다른 서명자가 있으면 나머지 자리는 0으로 남음 → 각 서명자가 채워야 함)
+    pub fn sign_transaction_message(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let signers = required_signers(message)?;
+        let position = signers
+            .iter()
+            .position(|signer| *signer == self.public_key)
+            .ok_or_else(|| Error::InvalidSignature(format!("{}는 이 트랜잭션의 서명자가 아닙니다", self.address())))?;
+
+        let mut tx = encode_compact_u16(signers.len() as u16);
+        for index in 0..signers.len() {
+            let signature = if index == position { self.sign_message(message) } else { [0u8; 64] };
+            tx.extend_from_slice(&signature);
+        }
+        tx.extend_from_slice(message);
+        Ok(tx)
+    }
+
+    /// 이 계정의 공개키로 서명 검증
+    pub fn verify_message(&self, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+        verify_with_public_key(&self.public_key, message, signature)
+    }
+}
+
+/// 서명 검증
+///
+/// `address`: 서명자 Base58 주소
+pub fn verify_message(address: &str, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+    verify_with_public_key(&decode_address(address)?, message, signature)
+}
+
+fn verify_with_public_key(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> Result<()> {
+    let verifying_key = VerifyingKey::from_bytes(public_key)
+        .map_err(|_| Error::InvalidKey("유효하지 않은 Ed25519 공개키".to_string()))?;
+
+    verifying_key
+        .verify(message, &Signature::from_bytes(signature))
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+}
+
+/// 직렬화된 트랜잭션에서 메시지 부분 추출 (서명 배열 건너뜀)
+pub fn transaction_message(tx: &[u8]) -> Result<&[u8]> {
+    let (signature_count, offset) = decode_compact_u16(tx)?;
+    let message_start = offset + signature_count as usize * 64;
+
+    if tx.len() <= message_start {
+        return Err(Error::Transaction("트랜잭션이 너무 짧습니다 (메시지 없음)".to_string()));
+    }
+
+    Ok(&tx[message_start..])
+}
+
+/// 메시지에서 필요 서명자 공개키 목록 추출
+fn required_signers(message: &[u8]) -> Result<Vec<[u8; 32]>> {
+    // 버전 메시지(v0)는 첫 바이트 최상위 비트가 1
+    let header_start = if message.first().is_some_and(|b| b & 0x80 != 0) { 1 } else { 0 };
+
+    let num_required = *message
+        .get(header_start)
+        .ok_or_else(|| Error::Transaction("메시지 헤더가 없습니다".to_string()))? as usize;
+
+    let keys_start = header_start + 3;
+    let keys_section = message.get(keys_start..).ok_or_else(|| Error::Transaction("메시지 헤더가 잘렸습니다".to_string()))?;
+    let (key_count, offset) = decode_compact_u16(keys_section)?;
+
+    if num_required > key_count as usize {
+        return Err(Error::Transaction("필요 서명 수가 계정 수보다 많습니다".to_string()));
+    }
+
+    let keys = &keys_section[offset..];
+    if keys.len() < num_required * 32 {
+        return Err(Error::Transaction("계정 목록이 잘렸습니다".to_string()));
+    }
+
+    Ok(keys
+        .chunks_exact(32)
+        .take(num_required)
+        .map(|chunk| {
+            let mut key = [0u8; 32];
+            key.copy_from_slice(chunk);
+            key
+        })
+        .collect())
+}
+
+/// compact-u16 (short_vec) 인코딩
+fn encode_compact_u16(mut value: u16) -> Vec<u8> {
+    let mut out = Vec::with_capacity(3);
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+    out
+}
+
+/// compact-u16 (short_vec) 디코딩
+///
+/// 7비트씩 little-endian, 최상위 비트 = 다음 바이트 있음 (최대 3바이트)
+/// 반환값: (값, 읽은 바이트 수)
+fn decode_compact_u16(data: &[u8]) -> Result<(u16, usize)> {
+    let mut value: u32 = 0;
+
+    for (i, &byte) in data.iter().take(3).enumerate() {
+        value |= ((byte & 0x7f) as u32) << (7 * i);
+        if byte & 0x80 == 0 {
+            return u16::try_from(value)
+                .map(|v| (v, i + 1))
+                .map_err(|_| Error::Transaction("compact-u16 값이 범위를 벗어났습니다".to_string()));
+        }
+    }
+
+    Err(Error::Transaction("유효하지 않은 compact-u16".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    /// 테스트용 legacy 미서명 트랜잭션 (서명자 1명, 계정 2개)
+    fn unsigned_transaction(signer: &[u8; 32]) -> Vec<u8> {
+        let mut message = vec![1, 0, 1]; // 헤더
+        message.push(2); // 계정 2개
+        message.extend_from_slice(signer);
+        message.extend_from_slice(&[0u8; 32]); // System Program
+        message.extend_from_slice(&[9u8; 32]); // 최근 블록해시
+        message.push(0); // 명령어 0개
+
+        let mut tx = vec![1]; // 서명 1개
+        tx.extend_from_slice(&[0u8; 64]); // 빈 서명 자리
+        tx.extend_from_slice(&message);
+        tx
+    }
+
+    #[test]
+    fn test_sign_and_verify_message() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let message = b"hello solana";
+
+        let signature = account.sign_message(message);
+        assert!(verify_message(&account.address(), message, &signature).is_ok());
+        assert!(verify_message(&account.address(), b"tampered", &signature).is_err());
+
+        assert!(account.verify_message(message, &signature).is_ok());
+        let other = SolanaAccount::from_mnemonic(MNEMONIC, "passphrase").unwrap();
+        assert!(other.verify_message(message, &signature).is_err());
+    }
+
+    #[test]
+    fn test_sign_transaction_base64() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let tx = unsigned_transaction(&account.public_key);
+
+        let signature = account.sign_transaction_base64(&BASE64.encode(&tx)).unwrap();
+        println!("서명 (Base58): {}", signature);
+
+        // 서명은 메시지 부분에 대한 것
+        let decoded = bs58::decode(&signature).into_vec().unwrap();
+        let mut signature_bytes = [0u8; 64];
+        signature_bytes.copy_from_slice(&decoded);
+
+        let message = transaction_message(&tx).unwrap();
+        assert_eq!(message[0], 1);
+        assert!(verify_message(&account.address(), message, &signature_bytes).is_ok());
+    }
+
+    #[test]
+    fn test_sign_transaction_rejects_non_signer() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let other = SolanaAccount::from_private_key([7u8; 32]).unwrap();
+        let tx = unsigned_transaction(&other.public_key);
+
+        assert!(account.sign_transaction_base64(&BASE64.encode(&tx)).is_err());
+    }
+
+    #[test]
+    fn test_sign_transaction_message() {
+        let account = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let unsigned = unsigned_transaction(&account.public_key);
+        let message = transaction_message(&unsigned).unwrap();
+
+        let tx = account.sign_transaction_message(message).unwrap();
+        assert_eq!(tx[0], 1);
+        assert_eq!(&tx[1..65], &account.sign_message(message));
+        assert_eq!(transaction_message(&tx).unwrap(), message);
+
+        let other = SolanaAccount::from_private_key([7u8; 32]).unwrap();
+        assert!(other.sign_transaction_message(message).is_err());
+    }
+
+    #[test]
+    fn test_compact_u16_roundtrip() {
+        for value in [0, 0x7f, 0x80, 0x3fff, 0x4000, 0xffff] {
+            let encoded = encode_compact_u16(value);
+            assert_eq!(decode_compact_u16(&encoded).unwrap(), (value, encoded.len()));
+        }
+    }
+
+    #[test]
+    fn test_decode_compact_u16() {
+        assert_eq!(decode_compact_u16(&[0x00]).unwrap(), (0, 1));
+        assert_eq!(decode_compact_u16(&[0x7f]).unwrap(), (0x7f, 1));
+        assert_eq!(decode_compact_u16(&[0x80, 0x01]).unwrap(), (0x80, 2));
+        assert_eq!(decode_compact_u16(&[0xff, 0xff, 0x03]).unwrap(), (0xffff, 3));
+        assert!(decode_compact_u16(&[0x80]).is_err());
+    }
+}
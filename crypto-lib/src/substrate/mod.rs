@@ -0,0 +1,294 @@
+//! Substrate Account Generation (Polkadot, Kusama 등)
+//!
+//! - 타원곡선: Ed25519 (subkey `--scheme ed25519`)
+//! - 해시: Blake2b
+//! - 주소 형식: SS58 (Base58 + 네트워크 접두사 + 체크섬)
+//! - 도출 경로: BIP-44 대신 Secret URI (`//hard/soft///password`) → `uri` 참고
+//!
+//! ## 시드 생성 (substrate-bip39)
+//! BIP-39와 달리 니모닉 문자열이 아닌 **엔트로피**를 PBKDF2에 넣음
+//! ```text
+//! seed = PBKDF2-HMAC-SHA512(엔트로피, "mnemonic" + password, 2048)
+//! Ed25519 시드 = seed[..32]
+//! ```
+//!
+//! ## 강화 도출 (Ed25519)
+//! ```text
+//! 자식 시드 = Blake2b-256(SCALE("Ed25519HDKD") || 부모 시드 || 체인코드)
+//! ```
+//! Ed25519는 일반(soft) 도출 불가 (SLIP-10과 같은 이유)
+//!
+//! ## SS58 주소
+//! ```text
+//! base58(접두사 || 공개키 || Blake2b-512("SS58PRE" || 접두사 || 공개키)[..2])
+//! ```
+//!
+//! ## 참고
+//! polkadot-js 기본값인 sr25519(Schnorrkel)는 아직 지원하지 않음
+//!
+//! ## 하위 모듈
+//! - `signing`: 메시지 서명/검증 (polkadot-js `<Bytes>` 감싸기 포함)
+//! - `uri`: Secret URI 파싱, junction 체인코드
+
+pub mod signing;
+pub mod uri;
+
+use blake2::{Blake2b512, Digest};
+use ed25519_dalek::SigningKey;
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+use crate::bip39::mnemonic_to_entropy;
+use crate::hash::blake2b256;
+use crate::secret::SecretBytes;
+use crate::error::{Error, Result};
+use self::uri::{scale_encode_str, DeriveJunction, SecretUri};
+
+/// SS58 접두사: Polkadot (1...)
+pub const SS58_POLKADOT: u16 = 0;
+/// SS58 접두사: Kusama (C..., D..., ...)
+pub const SS58_KUSAMA: u16 = 2;
+/// SS58 접두사: 일반 Substrate (5...)
+pub const SS58_GENERIC: u16 = 42;
+
+/// Substrate 계정 (Ed25519)
+///
+/// `Debug`는 개인키를 가림 (`account` 모듈에서 구현)
+#[derive(Clone)]
+pub struct SubstrateAccount {
+    /// Ed25519 시드 (32바이트)
+    pub private_key: SecretBytes<32>,
+    /// 공개키 (32바이트) = 계정 ID
+    pub public_key: [u8; 32],
+}
+
+impl SubstrateAccount {
+    /// Ed25519 시드에서 계정 생성
+    pub fn from_private_key(private_key: [u8; 32]) -> Self {
+        let public_key = SigningKey::from_bytes(&private_key).verifying_key().to_bytes();
+
+        SubstrateAccount {
+            private_key: SecretBytes::new(private_key),
+            public_key,
+        }
+    }
+
+    /// 니모닉에서 계정 생성 (도출 없음)
+    ///
+    /// 시드를 엔트로피에서 만들므로 항상 BIP-39 검증 (unchecked 변형 없음)
+    pub fn from_mnemonic(mnemonic: &str, password: &str) -> Result<Self> {
+        let entropy = mnemonic_to_entropy(mnemonic)?;
+        Ok(Self::from_private_key(seed_from_entropy(&entropy, password)))
+    }
+
+    /// Secret URI에서 계정 생성
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crypto_lib::substrate::{SubstrateAccount, SS58_GENERIC};
+    ///
+    /// // 개발용 니모닉의 Alice
+    /// let alice = SubstrateAccount::from_uri("//Alice").unwrap();
+    /// assert_eq!(alice.address(SS58_GENERIC), "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu");
+    /// ```
+    pub fn from_uri(uri: &str) -> Result<Self> {
+        let uri: SecretUri = uri.parse()?;
+
+        let root = match uri.phrase.strip_prefix("0x") {
+            Some(seed_hex) => {
+                if uri.password.is_some() {
+                    return Err(Error::InvalidInput("hex 시드에는 패스워드를 쓸 수 없습니다".to_string()));
+                }
+                let seed = hex::decode(seed_hex).map_err(|e| Error::Hex(format!("유효하지 않은 hex 시드: {}", e)))?;
+                let seed: [u8; 32] = seed
+                    .try_into()
+                    .map_err(|_| Error::InvalidKey("hex 시드는 32바이트여야 합니다".to_string()))?;
+                Self::from_private_key(seed)
+            }
+            None => Self::from_mnemonic(&uri.phrase, uri.password.as_deref().unwrap_or(""))?,
+        };
+
+        root.derive(&uri.junctions)
+    }
+
+    /// 도출 경로 적용 (Ed25519는 강화 도출만 가능)
+    pub fn derive(&self, junctions: &[DeriveJunction]) -> Result<Self> {
+        let mut private_key = self.private_key.clone();
+
+        for junction in junctions {
+            if !junction.hard {
+                return Err(Error::path(junction.to_string(), format!("Ed25519는 soft 도출을 지원하지 않습니다: {}", junction)));
+            }
+            private_key = SecretBytes::new(derive_hard_junction(&private_key, &junction.chain_code()));
+        }
+
+        Ok(Self::from_private_key(*private_key))
+    }
+
+    /// SS58 주소
+    pub fn address(&self, prefix: u16) -> String {
+        ss58_encode(&self.public_key, prefix)
+    }
+
+    /// 개인키(시드)를 hex로 반환
+    pub fn private_key_hex(&self) -> String {
+        hex::encode(&self.private_key)
+    }
+
+    /// 공개키를 hex로 반환
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.public_key)
+    }
+}
+
+/// substrate-bip39 시드 (엔트로피 기반 PBKDF2의 앞 32바이트)
+///
+/// 비밀번호는 NFKD 정규화하지 않음 (substrate-bip39/Polkadot.js와 같은 동작)
+pub fn seed_from_entropy(entropy: &[u8], password: &str) -> [u8; 32] {
+    let salt = format!("mnemonic{}", password);
+    let mut seed = [0u8; 64];
+    pbkdf2_hmac::<Sha512>(entropy, salt.as_bytes(), 2048, &mut seed);
+
+    let mut result = [0u8; 32];
+    result.copy_from_slice(&seed[..32]);
+    result
+}
+
+/// SS58 주소 인코딩
+///
+/// 접두사 0~63은 1바이트, 64~16383은 2바이트
+pub fn ss58_encode(public_key: &[u8; 32], prefix: u16) -> String {
+    let mut data = match prefix {
+        0..=63 => vec![prefix as u8],
+        _ => {
+            let ident = prefix & 0x3fff;
+            vec![
+                ((ident & 0b1111_1100) as u8 >> 2) | 0b0100_0000,
+                (ident >> 8) as u8 | ((ident & 0b11) << 6) as u8,
+            ]
+        }
+    };
+    data.extend_from_slice(public_key);
+
+    let checksum = ss58_checksum(&data);
+    data.extend_from_slice(&checksum[..2]);
+
+    bs58::encode(data).into_string()
+}
+
+/// SS58 주소 디코딩 → (공개키, 접두사)
+pub fn ss58_decode(address: &str) -> Result<([u8; 32], u16)> {
+    let data = bs58::decode(address.trim())
+        .into_vec()
+        .map_err(|_| Error::InvalidAddress(format!("유효하지 않은 SS58 주소: {}", address)))?;
+
+    let (prefix, prefix_len) = match data.first() {
+        Some(&first) if first < 64 => (first as u16, 1),
+        Some(&first) if first < 128 && data.len() > 1 => {
+            let second = data[1];
+            let lower = ((first & 0b0011_1111) << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            (lower as u16 | ((upper as u16) << 8), 2)
+        }
+        _ => return Err(Error::InvalidAddress(format!("유효하지 않은 SS58 접두사: {}", address))),
+    };
+
+    if data.len() != prefix_len + 32 + 2 {
+        return Err(Error::InvalidAddress(format!("SS58 주소 길이가 맞지 않습니다: {}바이트", data.len())));
+    }
+    let checksum = ss58_checksum(&data[..prefix_len + 32]);
+    if data[prefix_len + 32..] != checksum[..2] {
+        return Err(Error::InvalidAddress(format!("SS58 체크섬 불일치: {}", address)));
+    }
+
+    let public_key = data[prefix_len..prefix_len + 32].try_into().expect("32바이트");
+    Ok((public_key, prefix))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// Ed25519 강화 도출
+///
+/// Blake2b-256(SCALE("Ed25519HDKD") || 시드 || 체인코드)
+fn derive_hard_junction(seed: &[u8; 32], chain_code: &[u8; 32]) -> [u8; 32] {
+    let data = Zeroizing::new([&scale_encode_str("Ed25519HDKD")[..], seed, chain_code].concat());
+    blake2b256(&data)
+}
+
+/// SS58 체크섬 = Blake2b-512("SS58PRE" || data)
+pub(crate) fn ss58_checksum(data: &[u8]) -> [u8; 64] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(b"SS58PRE");
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dev_accounts() {
+        // subkey inspect --scheme ed25519 //Alice, //Bob
+        let alice = SubstrateAccount::from_uri("//Alice").unwrap();
+        println!("Alice: {} ({})", alice.address(SS58_GENERIC), alice.public_key_hex());
+        assert_eq!(alice.public_key_hex(), "88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee");
+        assert_eq!(alice.address(SS58_GENERIC), "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu");
+
+        let bob = SubstrateAccount::from_uri("//Bob").unwrap();
+        assert_eq!(bob.public_key_hex(), "d17c2d7823ebf260fd138f2d7e27d114c0145d968b5ff5006125f2414fadae69");
+
+        // 니모닉을 명시해도 같은 결과
+        let explicit = SubstrateAccount::from_uri(&format!("{}//Alice", uri::DEV_PHRASE)).unwrap();
+        assert_eq!(explicit.public_key, alice.public_key);
+    }
+
+    #[test]
+    fn test_uri_derivation() {
+        let root = SubstrateAccount::from_uri(uri::DEV_PHRASE).unwrap();
+        let alice = SubstrateAccount::from_uri("//Alice").unwrap();
+        assert_eq!(root.derive(&[DeriveJunction::hard("Alice")]).unwrap().public_key, alice.public_key);
+
+        // hex 시드에서 도출
+        let from_seed = SubstrateAccount::from_uri(&format!("0x{}//Alice", root.private_key_hex())).unwrap();
+        assert_eq!(from_seed.public_key, alice.public_key);
+
+        // 패스워드는 시드를 바꿈
+        let with_password = SubstrateAccount::from_uri("//Alice///password").unwrap();
+        assert_ne!(with_password.public_key, alice.public_key);
+
+        // Ed25519 soft 도출 불가
+        assert!(SubstrateAccount::from_uri("/Alice").is_err());
+        assert!(SubstrateAccount::from_uri("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_ss58_encode() {
+        // sr25519 Alice 공개키 (주소 인코딩만 검증)
+        let public_key: [u8; 32] = hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert_eq!(ss58_encode(&public_key, SS58_GENERIC), "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+        assert!(ss58_encode(&public_key, SS58_POLKADOT).starts_with('1'));
+
+        // 2바이트 접두사
+        let address = ss58_encode(&public_key, 1284);
+        println!("접두사 1284: {}", address);
+        assert_eq!(bs58::decode(&address).into_vec().unwrap().len(), 2 + 32 + 2);
+
+        // 디코딩 왕복
+        for prefix in [SS58_POLKADOT, SS58_KUSAMA, SS58_GENERIC, 64, 1284, 16383] {
+            assert_eq!(ss58_decode(&ss58_encode(&public_key, prefix)).unwrap(), (public_key, prefix));
+        }
+
+        let mut tampered = bs58::decode(&address).into_vec().unwrap();
+        tampered[10] ^= 1;
+        assert!(ss58_decode(&bs58::encode(tampered).into_string()).is_err());
+    }
+}
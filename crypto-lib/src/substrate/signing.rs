@@ -0,0 +1,90 @@
+//! Substrate Message Signing
+//!
+//! 메시지 그대로 Ed25519 서명 (해시 없음)
+//!
+//! ## polkadot-js `signRaw`
+//! 확장 프로그램은 임의 메시지를 `<Bytes>메시지</Bytes>`로 감싼 뒤 서명
+//! (감싼 메시지는 SCALE 인코딩된 extrinsic이 될 수 없으므로 트랜잭션으로 재사용 불가)
+//! → 검증 시 감싼 형태와 원본 모두 허용 (`signatureVerify`와 동일)
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+use crate::error::{Error, Result};
+
+use super::{ss58_decode, SubstrateAccount};
+
+/// polkadot-js signRaw 접두사
+pub const BYTES_PREFIX: &[u8] = b"<Bytes>";
+/// polkadot-js signRaw 접미사
+pub const BYTES_SUFFIX: &[u8] = b"</Bytes>";
+
+impl SubstrateAccount {
+    /// 메시지 서명 (Ed25519, 메시지 그대로)
+    pub fn sign_message(&self, message: &[u8]) -> [u8; 64] {
+        SigningKey::from_bytes(&self.private_key).sign(message).to_bytes()
+    }
+
+    /// polkadot-js signRaw 형식 서명 (`<Bytes>` 감싸기)
+    pub fn sign_raw(&self, message: &[u8]) -> [u8; 64] {
+        self.sign_message(&wrap_bytes(message))
+    }
+
+    /// 이 계정의 공개키로 서명 검증 (`<Bytes>` 감싼 서명도 허용)
+    pub fn verify_message(&self, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+        verify_with_public_key(&self.public_key, message, signature)
+    }
+}
+
+/// 서명 검증 (`address`: SS58 주소, 접두사 무관)
+pub fn verify_message(address: &str, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+    let (public_key, _) = ss58_decode(address)?;
+    verify_with_public_key(&public_key, message, signature)
+}
+
+/// `<Bytes>메시지</Bytes>` (이미 감싸져 있으면 그대로)
+pub fn wrap_bytes(message: &[u8]) -> Vec<u8> {
+    if message.starts_with(BYTES_PREFIX) && message.ends_with(BYTES_SUFFIX) {
+        return message.to_vec();
+    }
+
+    let mut wrapped = BYTES_PREFIX.to_vec();
+    wrapped.extend_from_slice(message);
+    wrapped.extend_from_slice(BYTES_SUFFIX);
+    wrapped
+}
+
+fn verify_with_public_key(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> Result<()> {
+    let verifying_key = VerifyingKey::from_bytes(public_key).map_err(|_| Error::InvalidKey("유효하지 않은 Ed25519 공개키".to_string()))?;
+    let signature = Signature::from_bytes(signature);
+
+    verifying_key
+        .verify(message, &signature)
+        .or_else(|_| verifying_key.verify(&wrap_bytes(message), &signature))
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::substrate::SS58_POLKADOT;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let alice = SubstrateAccount::from_uri("//Alice").unwrap();
+        let address = alice.address(SS58_POLKADOT);
+
+        let signature = alice.sign_message(b"hello");
+        assert!(alice.verify_message(b"hello", &signature).is_ok());
+        assert!(verify_message(&address, b"hello", &signature).is_ok());
+        assert!(verify_message(&address, b"hell0", &signature).is_err());
+
+        // signRaw 서명은 원본 메시지로 검증 가능
+        let raw = alice.sign_raw(b"hello");
+        assert_ne!(raw, signature);
+        assert!(verify_message(&address, b"hello", &raw).is_ok());
+        assert_eq!(wrap_bytes(b"<Bytes>hello</Bytes>"), b"<Bytes>hello</Bytes>");
+
+        let bob = SubstrateAccount::from_uri("//Bob").unwrap();
+        assert!(bob.verify_message(b"hello", &signature).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Substrate Secret URI
+//!
+//! polkadot-js, subkey에서 쓰는 키 도출 문법
+//!
+//! ```text
+//! <니모닉 또는 0x시드>//hard/soft///password
+//! ```
+//!
+//! - `//name`: 강화(hard) 도출
+//! - `/name`: 일반(soft) 도출
+//! - `///password`: BIP-39 패스워드 (마지막에 한 번만)
+//! - 니모닉을 생략하면 개발용 니모닉 (`DEV_PHRASE`) 사용 → `//Alice`
+//!
+//! ## Junction 체인코드 (32바이트)
+//! 1. 숫자면 u64 리틀엔디언 8바이트, 아니면 SCALE 문자열 (compact 길이 + UTF-8)
+//! 2. 32바이트 이하면 뒤를 0으로 채움
+//! 3. 32바이트를 넘으면 Blake2b-256 해시
+//!
+//! ```text
+//! "Alice" → 14 41 6c 69 63 65 00 00 ... (0x14 = 길이 5 << 2)
+//! "1"     → 01 00 00 00 00 00 00 00 ...
+//! ```
+
+use crate::error::{Error, Result};
+use crate::hash::blake2b256;
+
+use zeroize::Zeroize;
+
+/// 니모닉을 생략했을 때 사용하는 개발용 니모닉 (Alice, Bob, ...)
+pub const DEV_PHRASE: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+/// 도출 경로의 한 단계
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeriveJunction {
+    /// 원래 입력 (예: "Alice", "1")
+    pub name: String,
+    /// 강화 도출 여부 (`//`)
+    pub hard: bool,
+}
+
+impl DeriveJunction {
+    /// 강화 도출 단계
+    pub fn hard(name: impl Into<String>) -> Self {
+        DeriveJunction { name: name.into(), hard: true }
+    }
+
+    /// 일반 도출 단계
+    pub fn soft(name: impl Into<String>) -> Self {
+        DeriveJunction { name: name.into(), hard: false }
+    }
+
+    /// 도출에 사용하는 32바이트 체인코드
+    pub fn chain_code(&self) -> [u8; 32] {
+        let encoded = match self.name.parse::<u64>() {
+            Ok(number) => number.to_le_bytes().to_vec(),
+            Err(_) => scale_encode_str(&self.name),
+        };
+
+        let mut chain_code = [0u8; 32];
+        if encoded.len() > 32 {
+            chain_code.copy_from_slice(&blake2b256(&encoded));
+        } else {
+            chain_code[..encoded.len()].copy_from_slice(&encoded);
+        }
+        chain_code
+    }
+}
+
+impl std::fmt::Display for DeriveJunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let prefix = if self.hard { "//" } else { "/" };
+        write!(f, "{}{}", prefix, self.name)
+    }
+}
+
+/// 파싱된 Secret URI (drop 시 니모닉과 패스워드를 zeroize, Debug는 도출 경로만 출력)
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretUri {
+    /// 니모닉 (공백 정규화됨) 또는 0x로 시작하는 32바이트 hex 시드
+    pub phrase: String,
+    /// 도출 경로
+    pub junctions: Vec<DeriveJunction>,
+    /// BIP-39 패스워드
+    pub password: Option<String>,
+}
+
+impl std::str::FromStr for SecretUri {
+    type Err = Error;
+
+    /// "phrase//hard/soft///password" 파싱
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let (rest, password) = match uri.split_once("///") {
+            Some((rest, password)) => (rest, Some(password.to_string())),
+            None => (uri, None),
+        };
+
+        let (phrase, path) = match rest.find('/') {
+            Some(position) => rest.split_at(position),
+            None => (rest, ""),
+        };
+
+        // 공백 정규화 (여러 칸, 앞뒤 공백 제거)
+        let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+        let phrase = if phrase.is_empty() { DEV_PHRASE.to_string() } else { phrase };
+
+        Ok(SecretUri {
+            phrase,
+            junctions: parse_junctions(path)?,
+            password,
+        })
+    }
+}
+
+impl std::fmt::Debug for SecretUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretUri")
+            .field("phrase", &format_args!("<{}바이트>", self.phrase.len()))
+            .field("junctions", &self.junctions)
+            .field("password", &format_args!("{}", if self.password.is_some() { "Some(<REDACTED>)" } else { "None" }))
+            .finish()
+    }
+}
+
+impl Drop for SecretUri {
+    fn drop(&mut self) {
+        self.phrase.zeroize();
+        self.password.zeroize();
+    }
+}
+
+impl std::fmt::Display for SecretUri {
+    /// 정규화된 URI (예: "phrase//Alice/0///pw")
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.phrase)?;
+        for junction in &self.junctions {
+            write!(f, "{}", junction)?;
+        }
+        if let Some(password) = &self.password {
+            write!(f, "///{}", password)?;
+        }
+        Ok(())
+    }
+}
+
+/// 도출 경로 파싱 ("//hard/soft" → junction 목록)
+pub fn parse_junctions(path: &str) -> Result<Vec<DeriveJunction>> {
+    let mut junctions = Vec::new();
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        let part = rest
+            .strip_prefix('/')
+            .ok_or_else(|| Error::path(path, format!("도출 경로는 '/'로 시작해야 합니다: {}", path)))?;
+        let (part, hard) = match part.strip_prefix('/') {
+            Some(part) => (part, true),
+            None => (part, false),
+        };
+
+        let end = part.find('/').unwrap_or(part.len());
+        let name = &part[..end];
+        if name.is_empty() {
+            return Err(Error::path(path, format!("비어 있는 도출 단계: {}", path)));
+        }
+
+        junctions.push(DeriveJunction { name: name.to_string(), hard });
+        rest = &part[end..];
+    }
+
+    Ok(junctions)
+}
+
+/// SCALE 문자열 인코딩 (compact 길이 + UTF-8 바이트)
+pub(crate) fn scale_encode_str(value: &str) -> Vec<u8> {
+    let bytes = value.as_bytes();
+    let length = bytes.len() as u32;
+
+    let mut encoded = match length {
+        0..=0x3f => vec![(length << 2) as u8],
+        0x40..=0x3fff => ((length << 2) as u16 | 0b01).to_le_bytes().to_vec(),
+        _ => ((length << 2) | 0b10).to_le_bytes().to_vec(),
+    };
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uri() {
+        let uri: SecretUri = "//Alice".parse().unwrap();
+        assert_eq!(uri.phrase, DEV_PHRASE);
+        assert_eq!(uri.junctions, vec![DeriveJunction::hard("Alice")]);
+        assert_eq!(uri.password, None);
+
+        let uri: SecretUri = "  bottom  drive obey lake curtain smoke basket hold race lonely fit walk//polkadot/0//1///my/pass"
+            .parse()
+            .unwrap();
+        assert_eq!(uri.phrase, DEV_PHRASE);
+        assert_eq!(
+            uri.junctions,
+            vec![DeriveJunction::hard("polkadot"), DeriveJunction::soft("0"), DeriveJunction::hard("1")]
+        );
+        assert_eq!(uri.password.as_deref(), Some("my/pass"));
+
+        // 정규화된 형태
+        println!("정규화: {}", uri);
+        assert_eq!(uri.to_string(), format!("{}//polkadot/0//1///my/pass", DEV_PHRASE));
+
+        // Debug에는 니모닉과 패스워드가 나오지 않음
+        let debug = format!("{:?}", uri);
+        assert!(!debug.contains("bottom") && !debug.contains("my/pass"), "{}", debug);
+        assert!(debug.contains("Some(<REDACTED>)") && debug.contains("polkadot"));
+
+        // 빈 도출 단계
+        assert!("//Alice//".parse::<SecretUri>().is_err());
+    }
+
+    #[test]
+    fn test_junction_chain_code() {
+        // 문자열: compact 길이 + 바이트
+        let alice = DeriveJunction::hard("Alice").chain_code();
+        assert_eq!(&alice[..6], &[0x14, b'A', b'l', b'i', b'c', b'e']);
+        assert!(alice[6..].iter().all(|&b| b == 0));
+
+        // 숫자: u64 리틀엔디언
+        let mut expected = [0u8; 32];
+        expected[..2].copy_from_slice(&[0x01, 0x01]);
+        assert_eq!(DeriveJunction::soft("257").chain_code(), expected);
+
+        // 32바이트 초과 → Blake2b-256
+        let long = "a".repeat(40);
+        let expected = blake2b256(&scale_encode_str(&long));
+        assert_eq!(DeriveJunction::hard(long).chain_code(), expected);
+
+        // 31글자 문자열은 1 + 31 = 32바이트 → 해시하지 않음
+        let name = "b".repeat(31);
+        assert_eq!(DeriveJunction::hard(name.clone()).chain_code()[1..], *name.as_bytes());
+    }
+
+    #[test]
+    fn test_scale_compact_length() {
+        assert_eq!(scale_encode_str("")[..], [0x00]);
+        assert_eq!(scale_encode_str(&"x".repeat(63))[0], 0xfc);
+        assert_eq!(scale_encode_str(&"x".repeat(64))[..2], [0x01, 0x01]);
+    }
+}
@@ -0,0 +1,437 @@
+//! Sui Keystore Files (sui.keystore / sui.aliases)
+//!
+//! `sui client`가 사용하는 키스토어 파일 형식 읽기/쓰기
+//!
+//! ## sui.keystore
+//! base64 문자열의 JSON 배열. 각 항목 = base64(flag || 개인키)
+//!
+//! ```text
+//! [
+//!   "AKh3...(33바이트 base64)"
+//! ]
+//! ```
+//!
+//! ## sui.aliases
+//! 키스토어와 같은 디렉토리, 확장자만 `.aliases`인 파일
+//! 각 항목 = 별칭 + base64(flag || 공개키)
+//!
+//! ```text
+//! [
+//!   { "alias": "my-key", "public_key_base64": "AKh3..." }
+//! ]
+//! ```
+//!
+//! 별칭은 주소가 아니라 공개키로 키와 연결됨
+//!
+//! ## 지원하지 않는 스킴
+//! Secp256k1/Secp256r1 항목은 계정으로 만들지 않고 읽은 그대로 보관했다가 저장할 때 다시 씀
+//! (대응하는 별칭도 그대로 유지). 저장 순서는 Ed25519 항목 다음
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::{SignatureScheme, SuiAccount};
+
+/// sui.aliases 파일의 한 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AliasRecord {
+    alias: String,
+    public_key_base64: String,
+}
+
+/// 키스토어 항목 (별칭 + 계정)
+#[derive(Debug, Clone)]
+pub struct KeystoreEntry {
+    /// 별칭 (sui.aliases)
+    pub alias: String,
+    /// 계정
+    pub account: SuiAccount,
+}
+
+/// 지원하지 않는 스킴의 키스토어 항목 (읽은 그대로 보관)
+#[derive(Clone)]
+pub struct UnsupportedEntry {
+    /// 서명 스킴 플래그
+    pub flag: u8,
+    /// 원본 항목 base64(flag || 개인키)
+    entry: Zeroizing<String>,
+}
+
+impl fmt::Debug for UnsupportedEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnsupportedEntry").field("flag", &format_args!("0x{:02x}", self.flag)).finish_non_exhaustive()
+    }
+}
+
+/// Sui 키스토어 (sui.keystore + sui.aliases)
+#[derive(Debug, Clone, Default)]
+pub struct SuiKeystore {
+    /// 키 목록 (키스토어 파일 순서 유지)
+    pub entries: Vec<KeystoreEntry>,
+    /// 지원하지 않는 스킴의 항목 (저장 시 그대로 다시 씀)
+    pub unsupported: Vec<UnsupportedEntry>,
+    /// 어느 Ed25519 항목에도 대응하지 않는 별칭 (지원하지 않는 항목의 별칭 등)
+    other_aliases: Vec<AliasRecord>,
+}
+
+impl SuiAccount {
+    /// 키스토어 항목 형식으로 인코딩: base64(flag || 개인키)
+    pub fn to_keystore_entry(&self) -> Zeroizing<String> {
+        let mut data = Zeroizing::new(Vec::with_capacity(33));
+        data.push(SignatureScheme::Ed25519 as u8);
+        data.extend_from_slice(self.private_key.as_slice());
+        Zeroizing::new(BASE64.encode(data.as_slice()))
+    }
+
+    /// 키스토어 항목에서 계정 생성
+    ///
+    /// 현재 Ed25519(flag 0x00)만 지원
+    pub fn from_keystore_entry(entry: &str) -> Result<Self> {
+        let data = decode_keystore_entry(entry)?;
+        if data[0] != SignatureScheme::Ed25519 as u8 {
+            return Err(Error::InvalidKey(format!("지원하지 않는 서명 스킴 플래그: 0x{:02x}", data[0])));
+        }
+        Self::from_keystore_bytes(&data)
+    }
+
+    /// flag를 확인한 33바이트 항목에서 계정 생성
+    fn from_keystore_bytes(data: &[u8]) -> Result<Self> {
+        let mut private_key = Zeroizing::new([0u8; 32]);
+        private_key.copy_from_slice(&data[1..]);
+        Self::from_private_key(*private_key)
+    }
+
+    /// 별칭 파일용 공개키 인코딩: base64(flag || 공개키)
+    pub fn public_key_base64(&self) -> String {
+        let mut data = Vec::with_capacity(33);
+        data.push(SignatureScheme::Ed25519 as u8);
+        data.extend_from_slice(&self.public_key);
+        BASE64.encode(data)
+    }
+}
+
+impl SuiKeystore {
+    /// 빈 키스토어 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 키 추가
+    ///
+    /// 같은 주소나 같은 별칭이 이미 있으면 오류
+    pub fn add(&mut self, account: SuiAccount, alias: &str) -> Result<()> {
+        if self.get_by_alias(alias).is_some() || self.other_aliases.iter().any(|record| record.alias == alias) {
+            return Err(Error::Keystore(format!("이미 존재하는 별칭: {}", alias)));
+        }
+        if self.get_by_address(&account.address()).is_some() {
+            return Err(Error::Keystore(format!("이미 존재하는 주소: {}", account.address())));
+        }
+
+        self.entries.push(KeystoreEntry {
+            alias: alias.to_string(),
+            account,
+        });
+        Ok(())
+    }
+
+    /// 별칭으로 계정 조회
+    pub fn get_by_alias(&self, alias: &str) -> Option<&SuiAccount> {
+        self.entries
+            .iter()
+            .find(|entry| entry.alias == alias)
+            .map(|entry| &entry.account)
+    }
+
+    /// 주소(0x...)로 계정 조회
+    pub fn get_by_address(&self, address: &str) -> Option<&SuiAccount> {
+        let address = address.to_lowercase();
+        self.entries
+            .iter()
+            .find(|entry| entry.account.address() == address)
+            .map(|entry| &entry.account)
+    }
+
+    /// JSON 문자열에서 키스토어 생성
+    ///
+    /// 별칭 파일이 없거나 별칭이 빠진 키는 주소 기반 기본 별칭을 사용
+    /// 지원하지 않는 스킴의 항목은 `unsupported`에 그대로 보관
+    pub fn from_json(keystore_json: &str, aliases_json: Option<&str>) -> Result<Self> {
+        let keys: Zeroizing<Vec<String>> = Zeroizing::new(
+            serde_json::from_str(keystore_json).map_err(|e| Error::Keystore(format!("키스토어 JSON 파싱 실패: {}", e)))?,
+        );
+
+        let aliases: Vec<AliasRecord> = match aliases_json {
+            Some(json) => serde_json::from_str(json)
+                .map_err(|e| Error::Keystore(format!("별칭 JSON 파싱 실패: {}", e)))?,
+            None => Vec::new(),
+        };
+
+        let mut keystore = Self::new();
+        for key in keys.iter() {
+            let data = decode_keystore_entry(key)?;
+            if data[0] != SignatureScheme::Ed25519 as u8 {
+                keystore.unsupported.push(UnsupportedEntry { flag: data[0], entry: Zeroizing::new(key.trim().to_string()) });
+                continue;
+            }
+            let account = SuiAccount::from_keystore_bytes(&data)?;
+            let public_key_base64 = account.public_key_base64();
+
+            let alias = aliases
+                .iter()
+                .find(|record| record.public_key_base64 == public_key_base64)
+                .map(|record| record.alias.clone())
+                .unwrap_or_else(|| default_alias(&account));
+
+            keystore.add(account, &alias)?;
+        }
+
+        keystore.other_aliases = aliases
+            .into_iter()
+            .filter(|record| keystore.entries.iter().all(|entry| entry.account.public_key_base64() != record.public_key_base64))
+            .collect();
+
+        Ok(keystore)
+    }
+
+    /// sui.keystore JSON 문자열 생성
+    pub fn to_json(&self) -> Zeroizing<String> {
+        let keys: Vec<Zeroizing<String>> = self
+            .entries
+            .iter()
+            .map(|entry| entry.account.to_keystore_entry())
+            .chain(self.unsupported.iter().map(|entry| entry.entry.clone()))
+            .collect();
+        let keys: Vec<&str> = keys.iter().map(|key| key.as_str()).collect();
+
+        Zeroizing::new(serde_json::to_string_pretty(&keys).expect("문자열 배열 직렬화"))
+    }
+
+    /// sui.aliases JSON 문자열 생성
+    pub fn aliases_to_json(&self) -> String {
+        let aliases: Vec<AliasRecord> = self
+            .entries
+            .iter()
+            .map(|entry| AliasRecord {
+                alias: entry.alias.clone(),
+                public_key_base64: entry.account.public_key_base64(),
+            })
+            .chain(self.other_aliases.iter().cloned())
+            .collect();
+
+        serde_json::to_string_pretty(&aliases).expect("별칭 배열 직렬화")
+    }
+
+    /// 키스토어 파일 읽기 (같은 위치의 .aliases 파일도 함께 읽음)
+    pub fn load(keystore_path: impl AsRef<Path>) -> Result<Self> {
+        let keystore_path = keystore_path.as_ref();
+        let keystore_json = Zeroizing::new(
+            fs::read_to_string(keystore_path).map_err(|e| Error::Io(format!("키스토어 파일 읽기 실패: {}", e)))?,
+        );
+
+        let aliases_path = aliases_path(keystore_path);
+        let aliases_json = if aliases_path.exists() {
+            Some(
+                fs::read_to_string(&aliases_path)
+                    .map_err(|e| Error::Io(format!("별칭 파일 읽기 실패: {}", e)))?,
+            )
+        } else {
+            None
+        };
+
+        Self::from_json(&keystore_json, aliases_json.as_deref())
+    }
+
+    /// 키스토어 파일 쓰기 (같은 위치에 .aliases 파일도 함께 씀)
+    ///
+    /// 키스토어 파일은 평문 개인키를 담으므로 유닉스에서는 소유자만 읽을 수 있게(0600) 생성
+    pub fn save(&self, keystore_path: impl AsRef<Path>) -> Result<()> {
+        let keystore_path = keystore_path.as_ref();
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options
+            .open(keystore_path)
+            .map_err(|e| Error::Io(format!("키스토어 파일 생성 실패: {}", e)))?;
+        file.write_all(self.to_json().as_bytes())
+            .map_err(|e| Error::Io(format!("키스토어 파일 쓰기 실패: {}", e)))?;
+        fs::write(aliases_path(keystore_path), self.aliases_to_json())
+            .map_err(|e| Error::Io(format!("별칭 파일 쓰기 실패: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// 키스토어 경로 → 별칭 파일 경로 (sui.keystore → sui.aliases)
+pub fn aliases_path(keystore_path: &Path) -> PathBuf {
+    keystore_path.with_extension("aliases")
+}
+
+/// base64 디코딩 후 33바이트(flag + 개인키) 확인
+fn decode_keystore_entry(entry: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let data = Zeroizing::new(
+        BASE64
+            .decode(entry.trim())
+            .map_err(|e| Error::Keystore(format!("유효하지 않은 base64: {}", e)))?,
+    );
+
+    if data.len() != 33 {
+        return Err(Error::InvalidKey(format!(
+            "키스토어 항목은 33바이트여야 합니다 (flag + 개인키), 입력: {}바이트",
+            data.len()
+        )));
+    }
+    Ok(data)
+}
+
+/// 별칭이 없는 키의 기본 별칭 (주소 앞 8자리)
+fn default_alias(account: &SuiAccount) -> String {
+    format!("key-{}", &account.address_hex()[..8])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_keystore_entry_roundtrip() {
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+
+        let entry = account.to_keystore_entry();
+        println!("키스토어 항목: {}", entry.as_str());
+
+        // flag(0x00) + 개인키 = 33바이트
+        assert_eq!(BASE64.decode(entry.as_str()).unwrap().len(), 33);
+
+        let restored = SuiAccount::from_keystore_entry(&entry).unwrap();
+        assert_eq!(restored.address(), account.address());
+    }
+
+    #[test]
+    fn test_keystore_entry_rejects_other_schemes() {
+        // Secp256k1 플래그(0x01)
+        let mut data = vec![0x01];
+        data.extend_from_slice(&[7u8; 32]);
+        assert!(SuiAccount::from_keystore_entry(&BASE64.encode(data)).is_err());
+
+        // 길이 오류
+        assert!(SuiAccount::from_keystore_entry(&BASE64.encode([0u8; 32])).is_err());
+    }
+
+    #[test]
+    fn test_keystore_json_with_aliases() {
+        let first = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let second = SuiAccount::from_mnemonic(MNEMONIC, "passphrase").unwrap();
+
+        let mut keystore = SuiKeystore::new();
+        keystore.add(first.clone(), "main").unwrap();
+        keystore.add(second.clone(), "backup").unwrap();
+
+        // 중복 별칭/주소 거부
+        assert!(keystore.add(first.clone(), "other").is_err());
+        assert!(keystore.add(second.clone(), "main").is_err());
+
+        let keystore_json = keystore.to_json();
+        let aliases_json = keystore.aliases_to_json();
+        println!("sui.keystore:\n{}", keystore_json.as_str());
+        println!("sui.aliases:\n{}", aliases_json);
+
+        let restored = SuiKeystore::from_json(&keystore_json, Some(&aliases_json)).unwrap();
+        assert!(restored.unsupported.is_empty());
+        assert_eq!(restored.entries.len(), 2);
+        assert_eq!(restored.get_by_alias("main").unwrap().address(), first.address());
+        assert_eq!(restored.get_by_alias("backup").unwrap().address(), second.address());
+    }
+
+    #[test]
+    fn test_keystore_without_aliases() {
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let keystore_json = format!("[\"{}\"]", account.to_keystore_entry().as_str());
+
+        let keystore = SuiKeystore::from_json(&keystore_json, None).unwrap();
+        let alias = &keystore.entries[0].alias;
+
+        assert!(alias.starts_with("key-"));
+        assert!(keystore.get_by_address(&account.address()).is_some());
+    }
+
+    #[test]
+    fn test_keystore_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("sui-keystore-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sui.keystore");
+
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let mut keystore = SuiKeystore::new();
+        keystore.add(account.clone(), "main").unwrap();
+        keystore.save(&path).unwrap();
+
+        assert!(dir.join("sui.aliases").exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        let loaded = SuiKeystore::load(&path).unwrap();
+        assert_eq!(loaded.get_by_alias("main").unwrap().address(), account.address());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_keystore_keeps_unsupported_schemes() {
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        // Secp256k1(0x01), Secp256r1(0x02) 항목
+        let secp256k1 = BASE64.encode([[0x01].as_slice(), &[7u8; 32]].concat());
+        let secp256r1 = BASE64.encode([[0x02].as_slice(), &[9u8; 32]].concat());
+        let k1_public = BASE64.encode([[0x01].as_slice(), &[3u8; 33]].concat());
+
+        let keystore_json = format!("[\"{}\", \"{}\", \"{}\"]", secp256k1, account.to_keystore_entry().as_str(), secp256r1);
+        let aliases_json = format!(
+            r#"[{{"alias": "k1", "public_key_base64": "{}"}}, {{"alias": "main", "public_key_base64": "{}"}}]"#,
+            k1_public,
+            account.public_key_base64()
+        );
+
+        let mut keystore = SuiKeystore::from_json(&keystore_json, Some(&aliases_json)).unwrap();
+        assert_eq!(keystore.entries.len(), 1);
+        assert_eq!(keystore.get_by_alias("main").unwrap().address(), account.address());
+        assert_eq!(keystore.unsupported.iter().map(|entry| entry.flag).collect::<Vec<_>>(), vec![0x01, 0x02]);
+
+        // Debug에 개인키가 나오지 않음
+        assert!(!format!("{:?}", keystore).contains(&secp256k1));
+
+        // 지원하지 않는 항목의 별칭도 중복 거부
+        let other = SuiAccount::from_mnemonic(MNEMONIC, "passphrase").unwrap();
+        assert!(keystore.add(other, "k1").is_err());
+
+        // 저장 시 그대로 다시 씀
+        let keys: Vec<String> = serde_json::from_str(&keystore.to_json()).unwrap();
+        assert_eq!(keys, vec![account.to_keystore_entry().to_string(), secp256k1, secp256r1]);
+        let aliases: Vec<AliasRecord> = serde_json::from_str(&keystore.aliases_to_json()).unwrap();
+        assert_eq!(aliases.iter().map(|record| record.alias.as_str()).collect::<Vec<_>>(), vec!["main", "k1"]);
+        assert_eq!(aliases[1].public_key_base64, k1_public);
+
+        // 잘못된 길이/base64는 여전히 에러
+        assert!(SuiKeystore::from_json(&format!("[\"{}\"]", BASE64.encode([1u8; 32])), None).is_err());
+        assert!(SuiKeystore::from_json("[\"not base64!\"]", None).is_err());
+    }
+}
@@ -15,22 +15,40 @@
 //! - 0x01: Secp256k1
 //! - 0x02: Secp256r1
 //! - 0x03: MultiSig
+//!
+//! ## 공개키 전용 주소
+//! `SuiAddress::from_public_key`는 공개키와 스킴만으로 주소를 계산 (개인키 없음)
+//! - Ed25519: 32바이트
+//! - Secp256k1, Secp256r1: 압축 33바이트 (비압축 65바이트는 압축해서 해시)
+//!
+//! ## 하위 모듈
+//! - `keystore`: `sui client` 키스토어 파일 (sui.keystore, sui.aliases)
+//! - `signing`: intent 서명 (개인 메시지, 트랜잭션)
 
-use blake2::{Blake2b, Digest};
-use blake2::digest::consts::U32;
-use ed25519_dalek::{SigningKey, VerifyingKey};
+pub mod keystore;
+pub mod signing;
 
-use crate::bip39::mnemonic_to_seed;
-use crate::utils::slip10::derive_ed25519_key;
-use crate::utils::bech32::encode_bech32;
+use std::fmt;
+use std::str::FromStr;
+
+use ed25519_dalek::{SigningKey, VerifyingKey};
 
-type Blake2b256 = Blake2b<U32>;
+use crate::bip32::{ChildNumber, DerivationPath, IntoDerivationPath};
+use crate::account_iter::AccountIter;
+use crate::bip39::{mnemonic_to_seed, mnemonic_to_seed_checked};
+use crate::slip10::derive_ed25519_key;
+use crate::encoding::bech32::encode_bech32;
+use crate::hash::blake2b256;
+use crate::secret::SecretBytes;
+use crate::error::{Error, Result};
 
 /// Sui 계정
-#[derive(Debug, Clone)]
+///
+/// `Debug`는 개인키를 가림 (`account` 모듈에서 구현)
+#[derive(Clone)]
 pub struct SuiAccount {
     /// 개인키 (32바이트)
-    pub private_key: [u8; 32],
+    pub private_key: SecretBytes<32>,
     /// 공개키 (32바이트)
     pub public_key: [u8; 32],
     /// 주소 (32바이트) - Blake2b-256(flag + pubkey)
@@ -41,16 +59,63 @@ pub struct SuiAccount {
 pub const SUI_PATH: &str = "m/44'/784'/0'/0'/0'";
 
 /// 서명 스킴 플래그
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SignatureScheme {
     Ed25519 = 0x00,
     Secp256k1 = 0x01,
     Secp256r1 = 0x02,
 }
 
+impl SignatureScheme {
+    /// 모든 서명 스킴
+    pub const ALL: [SignatureScheme; 3] = [SignatureScheme::Ed25519, SignatureScheme::Secp256k1, SignatureScheme::Secp256r1];
+
+    /// 이름 ("ed25519", "secp256k1", "secp256r1")
+    pub fn name(&self) -> &'static str {
+        match self {
+            SignatureScheme::Ed25519 => "ed25519",
+            SignatureScheme::Secp256k1 => "secp256k1",
+            SignatureScheme::Secp256r1 => "secp256r1",
+        }
+    }
+
+    /// 플래그 바이트에서 생성 (알 수 없는 플래그는 오류)
+    pub fn from_flag(flag: u8) -> Result<Self> {
+        SignatureScheme::ALL
+            .into_iter()
+            .find(|scheme| *scheme as u8 == flag)
+            .ok_or_else(|| Error::InvalidInput(format!("알 수 없는 서명 스킴 플래그: 0x{:02x}", flag)))
+    }
+}
+
+impl fmt::Display for SignatureScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SignatureScheme {
+    type Err = Error;
+
+    /// 이름 파싱 (대소문자 무시, "p256"은 secp256r1)
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "ed25519" => Ok(SignatureScheme::Ed25519),
+            "secp256k1" => Ok(SignatureScheme::Secp256k1),
+            "secp256r1" | "p256" => Ok(SignatureScheme::Secp256r1),
+            _ => Err(Error::InvalidInput(format!("지원하지 않는 서명 스킴입니다: {}", s))),
+        }
+    }
+}
+
 impl SuiAccount {
-    /// 개인키에서 Sui 계정 생성
-    pub fn from_private_key(private_key: [u8; 32]) -> Self {
+    /// 개인키(Ed25519 시드)에서 Sui 계정 생성
+    ///
+    /// 32바이트 모두 Ed25519 시드로 쓸 수 있지만, 0으로 채워진 키는 초기화되지 않은 입력으로 보고 오류
+    pub fn from_private_key(private_key: [u8; 32]) -> Result<Self> {
+        if private_key == [0u8; 32] {
+            return Err(Error::InvalidKey("Ed25519 개인키가 0입니다".to_string()));
+        }
         let signing_key = SigningKey::from_bytes(&private_key);
         let verifying_key: VerifyingKey = (&signing_key).into();
         let public_key = verifying_key.to_bytes();
@@ -58,28 +123,56 @@ impl SuiAccount {
         // 주소 = Blake2b-256(flag + pubkey)
         let address = derive_sui_address(&public_key, SignatureScheme::Ed25519);
 
-        SuiAccount {
-            private_key,
+        Ok(SuiAccount {
+            private_key: SecretBytes::new(private_key),
             public_key,
             address,
-        }
+        })
     }
 
     /// 시드에서 Sui 계정 생성 (기본 경로)
-    pub fn from_seed(seed: &[u8]) -> Result<Self, String> {
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
         Self::from_seed_with_path(seed, SUI_PATH)
     }
 
     /// 시드에서 특정 경로로 Sui 계정 생성 (SLIP-10)
-    pub fn from_seed_with_path(seed: &[u8], path: &str) -> Result<Self, String> {
+    pub fn from_seed_with_path(seed: &[u8], path: impl IntoDerivationPath) -> Result<Self> {
         let private_key = derive_ed25519_key(seed, path)?;
-        Ok(Self::from_private_key(private_key))
+        Self::from_private_key(private_key)
+    }
+
+    /// 시드에서 계정을 index 순서로 도출하는 반복자 (m/44'/784'/0'/0'/i')
+    pub fn iter_from_seed(seed: &[u8]) -> Result<AccountIter<Self>> {
+        AccountIter::ed25519(seed, "m/44'/784'/0'/0'", "m", 0, Self::from_private_key)
     }
 
     /// 니모닉에서 Sui 계정 생성
-    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, String> {
+    ///
+    /// BIP-39 체크섬까지 검증 (틀리면 `Error::InvalidMnemonic`)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// BIP-39 검증 없이 Sui Ed25519 계정 생성 (`SUI_PATH`)
+    pub fn from_mnemonic_unchecked(mnemonic: &str, passphrase: &str) -> Result<Self> {
         let seed = mnemonic_to_seed(mnemonic, passphrase);
-        Self::from_seed(&seed)
+        Self::from_seed(seed.as_slice())
+    }
+
+    /// 니모닉에서 Sui 계정 생성 (m/44'/784'/{account}'/{change}'/{index}')
+    ///
+    /// SLIP-10은 강화 도출만 쓰며, 인덱스가 2^31 이상이면 `Error::InvalidPath`
+    pub fn from_mnemonic_with_index(mnemonic: &str, passphrase: &str, account: u32, change: u32, index: u32) -> Result<Self> {
+        let path = DerivationPath::from(vec![
+            ChildNumber::Hardened(44),
+            ChildNumber::Hardened(784),
+            ChildNumber::hardened(account)?,
+            ChildNumber::hardened(change)?,
+            ChildNumber::hardened(index)?,
+        ]);
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
     }
 
     /// 주소 반환 (0x 접두사)
@@ -94,7 +187,7 @@ impl SuiAccount {
 
     /// 개인키를 hex로 반환
     pub fn private_key_hex(&self) -> String {
-        hex::encode(self.private_key)
+        hex::encode(&self.private_key)
     }
 
     /// 공개키를 hex로 반환
@@ -106,7 +199,7 @@ impl SuiAccount {
     pub fn private_key_bech32(&self) -> String {
         // flag + private_key
         let mut data = vec![SignatureScheme::Ed25519 as u8];
-        data.extend_from_slice(&self.private_key);
+        data.extend_from_slice(self.private_key.as_slice());
 
         // Bech32 인코딩 (hrp = "suiprivkey")
         encode_bech32("suiprivkey", None, &data)
@@ -117,26 +210,86 @@ impl SuiAccount {
 // 주소 도출
 // ═══════════════════════════════════════════════════════════════
 
-/// Sui 주소 도출
+/// 공개키만으로 계산한 Sui 주소 (32바이트)
 ///
-/// address = Blake2b-256(flag || public_key)
-fn derive_sui_address(public_key: &[u8; 32], scheme: SignatureScheme) -> [u8; 32] {
-    let mut hasher = Blake2b256::new();
+/// 표시 형식은 `0x` + 소문자 hex 64자
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuiAddress(pub [u8; 32]);
+
+impl SuiAddress {
+    /// 공개키와 서명 스킴에서 주소 계산
+    ///
+    /// 곡선 위의 점이 아니거나 스킴과 길이가 맞지 않으면 `Error::InvalidKey`
+    pub fn from_public_key(public_key: &[u8], scheme: SignatureScheme) -> Result<Self> {
+        let invalid = || Error::InvalidKey(format!("유효하지 않은 {} 공개키", scheme));
+
+        let address = match scheme {
+            SignatureScheme::Ed25519 => {
+                let public_key: [u8; 32] = public_key.try_into().map_err(|_| {
+                    Error::InvalidKey(format!("Ed25519 공개키는 32바이트여야 합니다 (현재 {}바이트)", public_key.len()))
+                })?;
+                VerifyingKey::from_bytes(&public_key).map_err(|_| invalid())?;
+                derive_sui_address(&public_key, scheme)
+            }
+            SignatureScheme::Secp256k1 => {
+                let public = secp256k1::PublicKey::from_slice(public_key).map_err(|_| invalid())?;
+                derive_sui_address(&public.serialize(), scheme)
+            }
+            SignatureScheme::Secp256r1 => {
+                use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+                let public = p256::PublicKey::from_sec1_bytes(public_key).map_err(|_| invalid())?;
+                derive_sui_address(public.to_encoded_point(true).as_bytes(), scheme)
+            }
+        };
+        Ok(SuiAddress(address))
+    }
 
-    // flag + public_key
-    hasher.update([scheme as u8]);
-    hasher.update(public_key);
+    /// 주소 바이트
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
 
-    let result = hasher.finalize();
-    let mut address = [0u8; 32];
-    address.copy_from_slice(&result);
+impl From<&SuiAccount> for SuiAddress {
+    fn from(account: &SuiAccount) -> Self {
+        SuiAddress(account.address)
+    }
+}
 
-    address
+impl fmt::Display for SuiAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl FromStr for SuiAddress {
+    type Err = Error;
+
+    /// `0x` + hex 64자 (`0x`는 생략 가능, 대소문자 무시)
+    fn from_str(s: &str) -> Result<Self> {
+        let hex_part = s.trim().strip_prefix("0x").unwrap_or(s.trim());
+        let bytes = hex::decode(hex_part).map_err(|e| Error::InvalidAddress(format!("유효하지 않은 Sui 주소: {}", e)))?;
+        let address = bytes
+            .try_into()
+            .map_err(|bytes: Vec<u8>| Error::InvalidAddress(format!("Sui 주소는 32바이트여야 합니다 (현재 {}바이트)", bytes.len())))?;
+        Ok(SuiAddress(address))
+    }
+}
+
+/// Sui 주소 도출
+///
+/// address = Blake2b-256(flag || public_key)
+/// (secp256k1/secp256r1은 압축 공개키, 검증은 `SuiAddress::from_public_key`에서)
+pub(crate) fn derive_sui_address(public_key: &[u8], scheme: SignatureScheme) -> [u8; 32] {
+    // flag + public_key
+    blake2b256(&[&[scheme as u8][..], public_key].concat())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::encoding::bech32::{decode_bech32, Variant};
 
     #[test]
     fn test_sui_from_mnemonic() {
@@ -151,6 +304,64 @@ mod tests {
         println!("Bech32 개인키: {}", account.private_key_bech32());
     }
 
+    #[test]
+    fn test_signature_scheme_names() {
+        for scheme in SignatureScheme::ALL {
+            assert_eq!(scheme.to_string().parse::<SignatureScheme>(), Ok(scheme));
+            assert_eq!(SignatureScheme::from_flag(scheme as u8), Ok(scheme));
+        }
+        assert_eq!("P256".parse::<SignatureScheme>(), Ok(SignatureScheme::Secp256r1));
+        assert!("sr25519".parse::<SignatureScheme>().is_err());
+        assert!(SignatureScheme::from_flag(0x03).is_err());
+    }
+
+    #[test]
+    fn test_address_from_public_key() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = SuiAccount::from_mnemonic(mnemonic, "").unwrap();
+
+        let address = SuiAddress::from_public_key(&account.public_key, SignatureScheme::Ed25519).unwrap();
+        assert_eq!(address, SuiAddress::from(&account));
+        assert_eq!(address.to_string(), account.address());
+        assert_eq!(account.address().parse::<SuiAddress>(), Ok(address));
+
+        // secp256k1: flag 0x01 + 압축 공개키, 비압축 입력도 같은 주소
+        let secp = crate::ecdsa::public_key(&[0x11; 32]).unwrap();
+        let expected = blake2b256(&[&[0x01][..], &secp.serialize()].concat());
+        assert_eq!(SuiAddress::from_public_key(&secp.serialize(), SignatureScheme::Secp256k1).unwrap().0, expected);
+        assert_eq!(SuiAddress::from_public_key(&secp.serialize_uncompressed(), SignatureScheme::Secp256k1).unwrap().0, expected);
+
+        // secp256r1: flag 0x02 + 압축 공개키
+        let r1 = crate::slip10::public_key(&[0x11; 32], crate::slip10::Curve::Nist256p1).unwrap();
+        let r1_address = SuiAddress::from_public_key(&r1, SignatureScheme::Secp256r1).unwrap();
+        assert_eq!(r1_address.0, derive_sui_address(&r1, SignatureScheme::Secp256r1));
+        assert_ne!(r1_address.0, derive_sui_address(&r1, SignatureScheme::Secp256k1));
+
+        // 길이, 곡선 검증
+        assert!(SuiAddress::from_public_key(&secp.serialize(), SignatureScheme::Ed25519).is_err());
+        assert!(SuiAddress::from_public_key(&account.public_key, SignatureScheme::Secp256k1).is_err());
+        assert!(SuiAddress::from_public_key(&[0u8; 33], SignatureScheme::Secp256r1).is_err());
+        assert!("0x1234".parse::<SuiAddress>().is_err());
+    }
+
+    #[test]
+    fn test_private_key_bech32_round_trip() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = SuiAccount::from_mnemonic(mnemonic, "").unwrap();
+
+        let (hrp, data, variant) = decode_bech32(&account.private_key_bech32()).unwrap();
+        assert_eq!(hrp, "suiprivkey");
+        assert_eq!(variant, Variant::Bech32);
+        assert_eq!(data[0], SignatureScheme::Ed25519 as u8);
+        assert_eq!(data[1..], account.private_key[..]);
+    }
+
+    #[test]
+    fn test_invalid_private_key() {
+        assert_eq!(SuiAccount::from_private_key([0u8; 32]).unwrap_err(), Error::InvalidKey("Ed25519 개인키가 0입니다".to_string()));
+        assert!(SuiAccount::from_private_key([0xff; 32]).is_ok());
+    }
+
     #[test]
     fn test_sui_address_derivation() {
         // 알려진 공개키로 주소 도출 테스트
@@ -175,22 +386,11 @@ mod tests {
 
         for i in 0..5 {
             let path = format!("m/44'/784'/0'/0'/{}'", i);
-            let account = SuiAccount::from_seed_with_path(&seed, &path).unwrap();
+            let account = SuiAccount::from_seed_with_path(seed.as_slice(), &path).unwrap();
 
             println!("경로: {}", path);
             println!("주소: {}", account.address());
             println!();
         }
     }
-
-    #[test]
-    fn test_blake2b_hash() {
-        // Blake2b-256 기본 테스트
-        let mut hasher = Blake2b256::new();
-        hasher.update(b"test");
-        let result = hasher.finalize();
-
-        assert_eq!(result.len(), 32);
-        println!("Blake2b-256(\"test\"): {}", hex::encode(result));
-    }
 }
@@ -0,0 +1,273 @@
+//! Sui Intent Signing
+//!
+//! Sui는 서명 대상 앞에 intent(3바이트)를 붙여 용도를 구분함
+//! → 트랜잭션 서명을 개인 메시지로 재사용하는 공격 방지
+//!
+//! ## 서명 과정
+//! 1. intent(scope, version, app_id) || BCS(값) = IntentMessage
+//! 2. Blake2b-256(IntentMessage) = 다이제스트
+//!    (TransactionData는 이미 BCS 바이트이므로 그대로 붙임)
+//! 3. Ed25519 서명(다이제스트)
+//! 4. 직렬화 서명 = base64(flag || 서명 64바이트 || 공개키 32바이트)
+//!
+//! ## Intent Scope
+//! - 0: TransactionData
+//! - 3: PersonalMessage
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+
+use crate::error::{Error, Result};
+use crate::hash::blake2b256;
+use crate::trace;
+
+use super::{derive_sui_address, SignatureScheme, SuiAccount};
+
+/// Intent Scope (서명 대상의 종류)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntentScope {
+    /// BCS 직렬화된 TransactionData
+    TransactionData = 0,
+    /// 임의의 메시지 (dApp 로그인 등)
+    PersonalMessage = 3,
+}
+
+impl IntentScope {
+    /// intent 바이트 (scope, version=0, app_id=0 (Sui))
+    pub fn intent_bytes(&self) -> [u8; 3] {
+        [*self as u8, 0x00, 0x00]
+    }
+}
+
+impl SuiAccount {
+    /// 개인 메시지 서명 (PersonalMessage intent)
+    ///
+    /// 반환값: base64(flag || 서명 || 공개키)
+    pub fn sign_personal_message(&self, message: &[u8]) -> String {
+        // PersonalMessage는 메시지를 BCS vector<u8>로 감싸서 서명
+        let digest = intent_digest(IntentScope::PersonalMessage, &bcs_bytes(message));
+        self.sign_digest(&digest)
+    }
+
+    /// 트랜잭션 서명 (TransactionData intent)
+    ///
+    /// `tx_bytes`: BCS 직렬화된 TransactionData
+    /// 반환값: base64(flag || 서명 || 공개키) - `sui client execute-signed-tx`에 그대로 사용 가능
+    pub fn sign_transaction(&self, tx_bytes: &[u8]) -> String {
+        let digest = intent_digest(IntentScope::TransactionData, tx_bytes);
+        self.sign_digest(&digest)
+    }
+
+    /// base64 인코딩된 TransactionData 서명 (`sui client ... --serialize-unsigned-transaction` 출력)
+    pub fn sign_transaction_base64(&self, tx_base64: &str) -> Result<String> {
+        let tx_bytes = BASE64
+            .decode(tx_base64.trim())
+            .map_err(|e| Error::InvalidInput(format!("유효하지 않은 트랜잭션 base64: {}", e)))?;
+        Ok(self.sign_transaction(&tx_bytes))
+    }
+
+    /// 개인 메시지 서명 검증 (서명자가 이 계정인지까지 확인)
+    pub fn verify_personal_message(&self, message: &[u8], serialized_signature: &str) -> Result<()> {
+        self.check_signer(verify_personal_message(message, serialized_signature)?)
+    }
+
+    /// 트랜잭션 서명 검증 (서명자가 이 계정인지까지 확인)
+    pub fn verify_transaction(&self, tx_bytes: &[u8], serialized_signature: &str) -> Result<()> {
+        self.check_signer(verify_transaction(tx_bytes, serialized_signature)?)
+    }
+
+    fn check_signer(&self, signer: String) -> Result<()> {
+        if signer != self.address() {
+            return Err(Error::InvalidSignature(format!("서명자가 다릅니다: {}", signer)));
+        }
+        Ok(())
+    }
+
+    /// 다이제스트에 Ed25519 서명 후 Sui 직렬화 서명 형식으로 반환
+    fn sign_digest(&self, digest: &[u8; 32]) -> String {
+        let _span = trace::span!("ed25519.sign", message_len = digest.len());
+        let signing_key = SigningKey::from_bytes(&self.private_key);
+        let signature = signing_key.sign(digest);
+
+        let mut data = Vec::with_capacity(97);
+        data.push(SignatureScheme::Ed25519 as u8);
+        data.extend_from_slice(&signature.to_bytes());
+        data.extend_from_slice(&self.public_key);
+        BASE64.encode(data)
+    }
+}
+
+/// 개인 메시지 서명 검증
+///
+/// 성공 시 서명자의 Sui 주소(0x...) 반환
+pub fn verify_personal_message(message: &[u8], serialized_signature: &str) -> Result<String> {
+    let digest = intent_digest(IntentScope::PersonalMessage, &bcs_bytes(message));
+    verify_digest(&digest, serialized_signature)
+}
+
+/// 트랜잭션 서명 검증
+///
+/// 성공 시 서명자의 Sui 주소(0x...) 반환
+pub fn verify_transaction(tx_bytes: &[u8], serialized_signature: &str) -> Result<String> {
+    let digest = intent_digest(IntentScope::TransactionData, tx_bytes);
+    verify_digest(&digest, serialized_signature)
+}
+
+/// 트랜잭션 다이제스트 (Blake2b-256(intent || tx_bytes))
+///
+/// 서명 전 하드웨어 지갑 등에 표시해 확인하는 용도
+pub fn transaction_digest(tx_bytes: &[u8]) -> [u8; 32] {
+    intent_digest(IntentScope::TransactionData, tx_bytes)
+}
+
+/// 직렬화 서명 검증 후 서명자 주소 반환
+fn verify_digest(digest: &[u8; 32], serialized_signature: &str) -> Result<String> {
+    let data = BASE64
+        .decode(serialized_signature.trim())
+        .map_err(|e| Error::InvalidSignature(format!("유효하지 않은 base64: {}", e)))?;
+
+    if data.len() != 97 {
+        return Err(Error::InvalidSignature(format!(
+            "Ed25519 직렬화 서명은 97바이트여야 합니다 (flag + 서명 + 공개키), 입력: {}바이트",
+            data.len()
+        )));
+    }
+
+    if data[0] != SignatureScheme::Ed25519 as u8 {
+        return Err(Error::InvalidSignature(format!("지원하지 않는 서명 스킴 플래그: 0x{:02x}", data[0])));
+    }
+
+    let mut signature_bytes = [0u8; 64];
+    let mut public_key = [0u8; 32];
+    signature_bytes.copy_from_slice(&data[1..65]);
+    public_key.copy_from_slice(&data[65..]);
+
+    let verifying_key = VerifyingKey::from_bytes(&public_key)
+        .map_err(|_| Error::InvalidKey("유효하지 않은 Ed25519 공개키".to_string()))?;
+    let signature = Signature::from_bytes(&signature_bytes);
+
+    verifying_key
+        .verify(digest, &signature)
+        .map_err(|_| Error::InvalidSignature("서명 검증 실패".to_string()))?;
+
+    let address = derive_sui_address(&public_key, SignatureScheme::Ed25519);
+    Ok(format!("0x{}", hex::encode(address)))
+}
+
+/// Blake2b-256(intent || 값)
+fn intent_digest(scope: IntentScope, value: &[u8]) -> [u8; 32] {
+    blake2b256(&[&scope.intent_bytes()[..], value].concat())
+}
+
+/// BCS vector<u8> 직렬화: ULEB128(길이) || 바이트
+fn bcs_bytes(data: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(data.len() + 5);
+
+    let mut len = data.len();
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            result.push(byte);
+            break;
+        }
+        result.push(byte | 0x80); // 이어지는 바이트 있음
+    }
+
+    result.extend_from_slice(data);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_bcs_bytes_length_prefix() {
+        assert_eq!(bcs_bytes(b"hi"), vec![2, b'h', b'i']);
+
+        // 128바이트 → ULEB128 [0x80, 0x01]
+        let encoded = bcs_bytes(&[0u8; 128]);
+        assert_eq!(&encoded[..2], &[0x80, 0x01]);
+        assert_eq!(encoded.len(), 130);
+    }
+
+    #[test]
+    fn test_sign_personal_message() {
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let message = b"Sign in to example.com";
+
+        let signature = account.sign_personal_message(message);
+        println!("서명: {}", signature);
+
+        let decoded = BASE64.decode(&signature).unwrap();
+        assert_eq!(decoded.len(), 97);
+        assert_eq!(decoded[0], SignatureScheme::Ed25519 as u8);
+        assert_eq!(&decoded[65..], &account.public_key);
+
+        // 검증 → 서명자 주소
+        let signer = verify_personal_message(message, &signature).unwrap();
+        assert_eq!(signer, account.address());
+
+        // 다른 메시지는 검증 실패
+        assert!(verify_personal_message(b"other message", &signature).is_err());
+
+        // 계정 메서드는 서명자까지 확인
+        assert!(account.verify_personal_message(message, &signature).is_ok());
+        let other = SuiAccount::from_mnemonic(MNEMONIC, "passphrase").unwrap();
+        assert!(other.verify_personal_message(message, &signature).is_err());
+    }
+
+    #[test]
+    fn test_sign_transaction() {
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        // 실제 TransactionData 대신 임의의 BCS 바이트
+        let tx_bytes = [0x00, 0x00, 0x01, 0x02, 0x03];
+
+        let signature = account.sign_transaction(&tx_bytes);
+        assert_eq!(verify_transaction(&tx_bytes, &signature).unwrap(), account.address());
+
+        // base64 입력도 같은 서명 (Ed25519는 결정적 서명)
+        let from_base64 = account.sign_transaction_base64(&BASE64.encode(tx_bytes)).unwrap();
+        assert_eq!(from_base64, signature);
+
+        // 잘못된 base64는 서명 오류가 아니라 입력 오류
+        assert!(matches!(account.sign_transaction_base64("not base64!"), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_known_answer() {
+        // RFC 8032 테스트 1의 키, 독립 구현(RFC 8032 참조 Ed25519 + Blake2b-256)으로 계산한 값
+        let private_key: [u8; 32] = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let account = SuiAccount::from_private_key(private_key).unwrap();
+        assert_eq!(account.address(), "0x304af458e90e97c841685b8cbbc59b909f3e2cf150df590ada4c81452c29737d");
+
+        assert_eq!(
+            account.sign_personal_message(b"Sign in to example.com"),
+            "ANzD6w39K+XAyurDm5guSff2vYhjLVt114HDPw/w4ZDDPmPbtT8/b4kTu4WLWvAjTvOnqXk01FavcjxRvTdDIQvXWpgBgrEKt9VL/tPJZAc6DuFy89qmIyWvAhpo9wdRGg=="
+        );
+        assert_eq!(
+            account.sign_transaction(&[0x00, 0x00, 0x01, 0x02, 0x03]),
+            "ADOgXPFG6lvbSF9CxgcpRNhMNXcTtVziicjQTvPg3e2spvPhdMEn/CJd/c2JZFhvyH24jJ2AcpcqFUXHw63IwgbXWpgBgrEKt9VL/tPJZAc6DuFy89qmIyWvAhpo9wdRGg=="
+        );
+    }
+
+    #[test]
+    fn test_intent_scopes_are_not_interchangeable() {
+        let account = SuiAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let data = b"same bytes";
+
+        // 트랜잭션 서명을 개인 메시지 서명으로 재사용할 수 없음
+        let tx_signature = account.sign_transaction(data);
+        assert!(verify_personal_message(data, &tx_signature).is_err());
+
+        let message_signature = account.sign_personal_message(data);
+        assert!(verify_transaction(data, &message_signature).is_err());
+    }
+}
@@ -0,0 +1,490 @@
+//! Public Test Vectors (feature = "test-vectors")
+//!
+//! 표준 테스트 벡터와 니모닉 → 주소 기준값을 공개해 크레이트를 쓰는 쪽에서도 같은 적합성 검사를 실행할 수 있게 함
+//!
+//! ## 포함된 벡터
+//!
+//! | 상수 | 출처 | 내용 |
+//! |------|------|------|
+//! | `BIP39_VECTORS` | Trezor `vectors.json` (영어) | 엔트로피 → 니모닉 → 시드 (패스프레이즈 "TREZOR") |
+//! | `BIP32_VECTORS` | BIP-32 Test vector 1, 2 | 시드 → 경로별 xpub/xprv |
+//! | `SLIP10_VECTORS` | SLIP-10 Test vector 1 (ed25519, secp256k1) | 시드 → 경로별 체인코드/개인키/공개키 |
+//! | `ADDRESS_VECTORS` | Ian Coleman BIP39, 지갑 앱, `subkey` | 니모닉 + 경로 → 체인별 주소 |
+//!
+//! ## 사용
+//! `check_all()`은 모든 벡터를 이 크레이트로 다시 계산해 비교 (첫 불일치를 `Error`로 반환)
+//! 직접 감싼 도출 코드가 있으면 상수만 가져와 같은 방식으로 비교하면 됨
+//!
+//! ```
+//! use crypto_lib::test_vectors::{self, ADDRESS_VECTORS};
+//! use crypto_lib::wallet::CachedWallet;
+//!
+//! test_vectors::check_all().unwrap();
+//!
+//! for vector in ADDRESS_VECTORS {
+//!     let wallet = CachedWallet::from_mnemonic(vector.mnemonic, vector.passphrase).unwrap();
+//!     assert_eq!(wallet.derive_path(vector.chain, vector.path).unwrap().address, vector.address);
+//! }
+//! ```
+
+use crate::bip32::master_key_from_seed;
+use crate::bip39::{entropy_to_mnemonic, mnemonic_to_entropy, mnemonic_to_seed};
+use crate::bitcoin::Network;
+use crate::cosmos::CosmosChain;
+use crate::slip10::{Curve, Slip10Key};
+use crate::wallet::{CachedWallet, Chain};
+use crate::error::{Error, Result};
+
+/// BIP-39 벡터
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bip39Vector {
+    /// 엔트로피 hex
+    pub entropy: &'static str,
+    /// 영어 니모닉
+    pub mnemonic: &'static str,
+    /// 패스프레이즈
+    pub passphrase: &'static str,
+    /// 시드 hex (64바이트)
+    pub seed: &'static str,
+}
+
+/// BIP-32 벡터 (시드 하나에서 이어지는 경로들)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bip32Vector {
+    /// 시드 hex
+    pub seed: &'static str,
+    /// 경로별 확장 키
+    pub chain: &'static [Bip32Step],
+}
+
+/// BIP-32 경로 하나의 확장 키
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bip32Step {
+    /// 도출 경로
+    pub path: &'static str,
+    /// 메인넷 xpub
+    pub xpub: &'static str,
+    /// 메인넷 xprv
+    pub xprv: &'static str,
+}
+
+/// SLIP-10 벡터
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slip10Vector {
+    /// 곡선
+    pub curve: Curve,
+    /// 시드 hex
+    pub seed: &'static str,
+    /// 경로별 키
+    pub chain: &'static [Slip10Step],
+}
+
+/// SLIP-10 경로 하나의 키
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slip10Step {
+    /// 도출 경로
+    pub path: &'static str,
+    /// 체인코드 hex
+    pub chain_code: &'static str,
+    /// 개인키 hex
+    pub private_key: &'static str,
+    /// SLIP-10 형식 공개키 hex (33바이트, Ed25519는 0x00 접두사)
+    pub public_key: &'static str,
+}
+
+/// 니모닉 → 주소 벡터
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressVector {
+    /// 체인
+    pub chain: Chain,
+    /// 니모닉
+    pub mnemonic: &'static str,
+    /// 패스프레이즈
+    pub passphrase: &'static str,
+    /// 도출 경로 (Substrate는 Secret URI 경로)
+    pub path: &'static str,
+    /// 기대 주소
+    pub address: &'static str,
+}
+
+/// BIP-39 기본 테스트 니모닉 (엔트로피 0)
+pub const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Substrate 개발용 니모닉 (`//Alice`, `//Bob`의 기준)
+pub const SUBSTRATE_DEV_MNEMONIC: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+/// Trezor BIP-39 벡터 중 반복 패턴 엔트로피 (12/18/24단어 × 0x00, 0x7f, 0x80, 0xff)
+pub const BIP39_VECTORS: &[Bip39Vector] = &[
+    Bip39Vector {
+        entropy: "00000000000000000000000000000000",
+        mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        passphrase: "TREZOR",
+        seed: "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    },
+    Bip39Vector {
+        entropy: "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        mnemonic: "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        passphrase: "TREZOR",
+        seed: "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+    },
+    Bip39Vector {
+        entropy: "80808080808080808080808080808080",
+        mnemonic: "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        passphrase: "TREZOR",
+        seed: "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+    },
+    Bip39Vector {
+        entropy: "ffffffffffffffffffffffffffffffff",
+        mnemonic: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        passphrase: "TREZOR",
+        seed: "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+    },
+    Bip39Vector {
+        entropy: "000000000000000000000000000000000000000000000000",
+        mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent",
+        passphrase: "TREZOR",
+        seed: "035895f2f481b1b0f01fcf8c289c794660b289981a78f8106447707fdd9666ca06da5a9a565181599b79f53b844d8a71dd9f439c52a3d7b3e8a79c906ac845fa",
+    },
+    Bip39Vector {
+        entropy: "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        mnemonic: "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will",
+        passphrase: "TREZOR",
+        seed: "f2b94508732bcbacbcc020faefecfc89feafa6649a5491b8c952cede496c214a0c7b3c392d168748f2d4a612bada0753b52a1c7ac53c1e93abd5c6320b9e95dd",
+    },
+    Bip39Vector {
+        entropy: "808080808080808080808080808080808080808080808080",
+        mnemonic: "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter always",
+        passphrase: "TREZOR",
+        seed: "107d7c02a5aa6f38c58083ff74f04c607c2d2c0ecc55501dadd72d025b751bc27fe913ffb796f841c49b1d33b610cf0e91d3aa239027f5e99fe4ce9e5088cd65",
+    },
+    Bip39Vector {
+        entropy: "ffffffffffffffffffffffffffffffffffffffffffffffff",
+        mnemonic: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+        passphrase: "TREZOR",
+        seed: "0cd6e5d827bb62eb8fc1e262254223817fd068a74b5b449cc2f667c3f1f985a76379b43348d952e2265b4cd129090758b3e3c2c49103b5051aac2eaeb890a528",
+    },
+    Bip39Vector {
+        entropy: "0000000000000000000000000000000000000000000000000000000000000000",
+        mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        passphrase: "TREZOR",
+        seed: "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+    },
+    Bip39Vector {
+        entropy: "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        mnemonic: "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        passphrase: "TREZOR",
+        seed: "bc09fca1804f7e69da93c2f2028eb238c227f2e9dda30cd63699232578480a4021b146ad717fbb7e451ce9eb835f43620bf5c514db0f8add49f5d121449d3e87",
+    },
+    Bip39Vector {
+        entropy: "8080808080808080808080808080808080808080808080808080808080808080",
+        mnemonic: "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        passphrase: "TREZOR",
+        seed: "c0c519bd0e91a2ed54357d9d1ebef6f5af218a153624cf4f2da911a0ed8f7a09e2ef61af0aca007096df430022f7a2b6fb91661a9589097069720d015e4e982f",
+    },
+    Bip39Vector {
+        entropy: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        mnemonic: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        passphrase: "TREZOR",
+        seed: "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+    },
+];
+
+/// BIP-32 Test vector 1, 2
+pub const BIP32_VECTORS: &[Bip32Vector] = &[
+    Bip32Vector {
+        seed: "000102030405060708090a0b0c0d0e0f",
+        chain: &[
+            Bip32Step {
+                path: "m",
+                xpub: "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+                xprv: "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+            },
+            Bip32Step {
+                path: "m/0'",
+                xpub: "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+                xprv: "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+            },
+            Bip32Step {
+                path: "m/0'/1",
+                xpub: "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+                xprv: "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+            },
+            Bip32Step {
+                path: "m/0'/1/2'",
+                xpub: "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+                xprv: "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+            },
+            Bip32Step {
+                path: "m/0'/1/2'/2",
+                xpub: "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+                xprv: "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+            },
+            Bip32Step {
+                path: "m/0'/1/2'/2/1000000000",
+                xpub: "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+                xprv: "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+            },
+        ],
+    },
+    Bip32Vector {
+        seed: "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+        chain: &[
+            Bip32Step {
+                path: "m",
+                xpub: "xpub661MyMwAqRbcFW31YEwpkMuc5THy2PSt5bDMsktWQcFF8syAmRUapSCGu8ED9W6oDMSgv6Zz8idoc4a6mr8BDzTJY47LJhkJ8UB7WEGuduB",
+                xprv: "xprv9s21ZrQH143K31xYSDQpPDxsXRTUcvj2iNHm5NUtrGiGG5e2DtALGdso3pGz6ssrdK4PFmM8NSpSBHNqPqm55Qn3LqFtT2emdEXVYsCzC2U",
+            },
+            Bip32Step {
+                path: "m/0",
+                xpub: "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH",
+                xprv: "xprv9vHkqa6EV4sPZHYqZznhT2NPtPCjKuDKGY38FBWLvgaDx45zo9WQRUT3dKYnjwih2yJD9mkrocEZXo1ex8G81dwSM1fwqWpWkeS3v86pgKt",
+            },
+            Bip32Step {
+                path: "m/0/2147483647'",
+                xpub: "xpub6ASAVgeehLbnwdqV6UKMHVzgqAG8Gr6riv3Fxxpj8ksbH9ebxaEyBLZ85ySDhKiLDBrQSARLq1uNRts8RuJiHjaDMBU4Zn9h8LZNnBC5y4a",
+                xprv: "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9",
+            },
+            Bip32Step {
+                path: "m/0/2147483647'/1",
+                xpub: "xpub6DF8uhdarytz3FWdA8TvFSvvAh8dP3283MY7p2V4SeE2wyWmG5mg5EwVvmdMVCQcoNJxGoWaU9DCWh89LojfZ537wTfunKau47EL2dhHKon",
+                xprv: "xprv9zFnWC6h2cLgpmSA46vutJzBcfJ8yaJGg8cX1e5StJh45BBciYTRXSd25UEPVuesF9yog62tGAQtHjXajPPdbRCHuWS6T8XA2ECKADdw4Ef",
+            },
+            Bip32Step {
+                path: "m/0/2147483647'/1/2147483646'",
+                xpub: "xpub6ERApfZwUNrhLCkDtcHTcxd75RbzS1ed54G1LkBUHQVHQKqhMkhgbmJbZRkrgZw4koxb5JaHWkY4ALHY2grBGRjaDMzQLcgJvLJuZZvRcEL",
+                xprv: "xprvA1RpRA33e1JQ7ifknakTFpgNXPmW2YvmhqLQYMmrj4xJXXWYpDPS3xz7iAxn8L39njGVyuoseXzU6rcxFLJ8HFsTjSyQbLYnMpCqE2VbFWc",
+            },
+            Bip32Step {
+                path: "m/0/2147483647'/1/2147483646'/2",
+                xpub: "xpub6FnCn6nSzZAw5Tw7cgR9bi15UV96gLZhjDstkXXxvCLsUXBGXPdSnLFbdpq8p9HmGsApME5hQTZ3emM2rnY5agb9rXpVGyy3bdW6EEgAtqt",
+                xprv: "xprvA2nrNbFZABcdryreWet9Ea4LvTJcGsqrMzxHx98MMrotbir7yrKCEXw7nadnHM8Dq38EGfSh6dqA9QWTyefMLEcBYJUuekgW4BYPJcr9E7j",
+            },
+        ],
+    },
+];
+
+/// SLIP-10 Test vector 1 (ed25519, secp256k1)
+pub const SLIP10_VECTORS: &[Slip10Vector] = &[
+    Slip10Vector {
+        curve: Curve::Ed25519,
+        seed: "000102030405060708090a0b0c0d0e0f",
+        chain: &[
+            Slip10Step {
+                path: "m",
+                chain_code: "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+                private_key: "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+                public_key: "00a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed",
+            },
+            Slip10Step {
+                path: "m/0'",
+                chain_code: "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+                private_key: "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+                public_key: "008c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c",
+            },
+            Slip10Step {
+                path: "m/0'/1'",
+                chain_code: "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+                private_key: "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+                public_key: "001932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187",
+            },
+            Slip10Step {
+                path: "m/0'/1'/2'",
+                chain_code: "2e69929e00b5ab250f49c3fb1c12f252de4fed2c1db88387094a0f8c4c9ccd6c",
+                private_key: "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+                public_key: "00ae98736566d30ed0e9d2f4486a64bc95740d89c7db33f52121f8ea8f76ff0fc1",
+            },
+            Slip10Step {
+                path: "m/0'/1'/2'/2'",
+                chain_code: "8f6d87f93d750e0efccda017d662a1b31a266e4a6f5993b15f5c1f07f74dd5cc",
+                private_key: "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+                public_key: "008abae2d66361c879b900d204ad2cc4984fa2aa344dd7ddc46007329ac76c429c",
+            },
+            Slip10Step {
+                path: "m/0'/1'/2'/2'/1000000000'",
+                chain_code: "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+                private_key: "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+                public_key: "003c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a",
+            },
+        ],
+    },
+    Slip10Vector {
+        curve: Curve::Secp256k1,
+        seed: "000102030405060708090a0b0c0d0e0f",
+        chain: &[
+            Slip10Step {
+                path: "m",
+                chain_code: "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+                private_key: "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+                public_key: "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2",
+            },
+            Slip10Step {
+                path: "m/0'",
+                chain_code: "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+                private_key: "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                public_key: "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56",
+            },
+            Slip10Step {
+                path: "m/0'/1",
+                chain_code: "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+                private_key: "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+                public_key: "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+            },
+            Slip10Step {
+                path: "m/0'/1/2'",
+                chain_code: "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+                private_key: "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+                public_key: "0357bfe1e341d01c69fe5654309956cbea516822fba8a601743a012a7896ee8dc2",
+            },
+            Slip10Step {
+                path: "m/0'/1/2'/2",
+                chain_code: "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+                private_key: "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+                public_key: "02e8445082a72f29b75ca48748a914df60622a609cacfce8ed0e35804560741d29",
+            },
+            Slip10Step {
+                path: "m/0'/1/2'/2/1000000000",
+                chain_code: "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+                private_key: "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+                public_key: "022a471424da5e657499d1ff51cb43c47481a03b1e77f951fe64cec9f5a48f7011",
+            },
+        ],
+    },
+];
+
+/// 체인별 주소 기준값
+pub const ADDRESS_VECTORS: &[AddressVector] = &[
+    // Ian Coleman BIP39, BIP-84 테스트 벡터
+    AddressVector {
+        chain: Chain::Bitcoin,
+        mnemonic: TEST_MNEMONIC,
+        passphrase: "",
+        path: "m/84'/0'/0'/0/0",
+        address: "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+    },
+    // Ian Coleman BIP39, MetaMask
+    AddressVector {
+        chain: Chain::Evm,
+        mnemonic: TEST_MNEMONIC,
+        passphrase: "",
+        path: "m/44'/60'/0'/0/0",
+        address: "0x9858EfFD232B4033E47d90003D41EC34EcaEda94",
+    },
+    // Phantom, Solflare
+    AddressVector {
+        chain: Chain::Solana,
+        mnemonic: TEST_MNEMONIC,
+        passphrase: "",
+        path: "m/44'/501'/0'/0'",
+        address: "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk",
+    },
+    // Sui CLI (`sui keytool import`)
+    AddressVector {
+        chain: Chain::Sui,
+        mnemonic: TEST_MNEMONIC,
+        passphrase: "",
+        path: "m/44'/784'/0'/0'/0'",
+        address: "0x5e93a736d04fbb25737aa40bee40171ef79f65fae833749e3c089fe7cc2161f1",
+    },
+    // Keplr, gaiad `keys add --recover`
+    AddressVector {
+        chain: Chain::Cosmos(CosmosChain::CosmosHub),
+        mnemonic: TEST_MNEMONIC,
+        passphrase: "",
+        path: "m/44'/118'/0'/0/0",
+        address: "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4",
+    },
+    // subkey inspect --scheme ed25519 //Alice
+    AddressVector {
+        chain: Chain::Substrate,
+        mnemonic: SUBSTRATE_DEV_MNEMONIC,
+        passphrase: "",
+        path: "//Alice",
+        address: "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu",
+    },
+];
+
+/// 모든 벡터 확인
+pub fn check_all() -> Result<()> {
+    check_bip39()?;
+    check_bip32()?;
+    check_slip10()?;
+    check_addresses()
+}
+
+/// BIP-39: 엔트로피 ↔ 니모닉, 니모닉 → 시드
+pub fn check_bip39() -> Result<()> {
+    for vector in BIP39_VECTORS {
+        let entropy = decode_hex(vector.entropy)?;
+        expect("BIP-39 니모닉", vector.entropy, &entropy_to_mnemonic(&entropy)?, vector.mnemonic)?;
+        expect("BIP-39 엔트로피", vector.mnemonic, &hex::encode(mnemonic_to_entropy(vector.mnemonic)?), vector.entropy)?;
+        expect("BIP-39 시드", vector.mnemonic, &hex::encode(mnemonic_to_seed(vector.mnemonic, vector.passphrase)), vector.seed)?;
+    }
+    Ok(())
+}
+
+/// BIP-32: 경로별 xprv/xpub
+pub fn check_bip32() -> Result<()> {
+    for vector in BIP32_VECTORS {
+        let master = master_key_from_seed(&decode_hex(vector.seed)?)?;
+        for step in vector.chain {
+            let key = master.derive_path(step.path)?;
+            expect("BIP-32 xprv", step.path, &key.to_base58(Network::Mainnet), step.xprv)?;
+            expect("BIP-32 xpub", step.path, &key.to_public().to_base58(Network::Mainnet), step.xpub)?;
+        }
+    }
+    Ok(())
+}
+
+/// SLIP-10: 경로별 체인코드/개인키/공개키
+pub fn check_slip10() -> Result<()> {
+    for vector in SLIP10_VECTORS {
+        let master = Slip10Key::master(&decode_hex(vector.seed)?, vector.curve)?;
+        for step in vector.chain {
+            let key = master.derive_path(step.path)?;
+            expect("SLIP-10 체인코드", step.path, &hex::encode(key.chain_code), step.chain_code)?;
+            expect("SLIP-10 개인키", step.path, &hex::encode(*key.private_key), step.private_key)?;
+            expect("SLIP-10 공개키", step.path, &hex::encode(key.public_key()?), step.public_key)?;
+        }
+    }
+    Ok(())
+}
+
+/// 니모닉 + 경로 → 주소
+pub fn check_addresses() -> Result<()> {
+    for vector in ADDRESS_VECTORS {
+        let wallet = CachedWallet::from_mnemonic(vector.mnemonic, vector.passphrase)?;
+        let address = wallet.derive_path(vector.chain, vector.path)?.address;
+        expect(&format!("{} 주소", vector.chain.id()), vector.path, &address, vector.address)?;
+    }
+    Ok(())
+}
+
+fn expect(what: &str, input: &str, actual: &str, expected: &str) -> Result<()> {
+    if actual != expected {
+        return Err(Error::InvalidInput(format!("{} 불일치 ({}): {} ≠ {}", what, input, actual, expected)));
+    }
+    Ok(())
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>> {
+    hex::decode(value).map_err(|e| Error::InvalidInput(format!("유효하지 않은 hex: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_all() {
+        check_bip39().unwrap();
+        check_bip32().unwrap();
+        check_slip10().unwrap();
+        check_addresses().unwrap();
+    }
+
+    #[test]
+    fn test_mismatch_is_reported() {
+        let error = expect("BIP-32 xpub", "m/0'", "xpub1", "xpub2").unwrap_err();
+        assert_eq!(error, Error::InvalidInput("BIP-32 xpub 불일치 (m/0'): xpub1 ≠ xpub2".to_string()));
+    }
+}
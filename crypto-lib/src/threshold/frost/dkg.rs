@@ -0,0 +1,418 @@
+//! FROST Distributed Key Generation (Pedersen DKG + 지식 증명)
+//!
+//! 딜러 없이 n명이 함께 t-of-n 키를 생성 — 그룹 개인키는 어느 참여자에게도 존재한 적 없음
+//!
+//! ## 3단계
+//! ```text
+//! part1  참여자 i: 무작위 다항식 fᵢ (차수 t-1), commitment Cᵢ = [aᵢ₀·G, …, aᵢ,t-1·G]
+//!        aᵢ₀의 지식 증명 (R = k·G, μ = k + aᵢ₀·c,  c = H(CONTEXT || "dkg" || i || Cᵢ₀ || R))
+//!        → Round1Package 브로드캐스트
+//! part2  다른 참여자들의 증명 검증 → 참여자 j마다 fᵢ(j)를 1:1로 전송 (Round2Package, 암호화 채널 필수)
+//! part3  받은 조각 확인: fⱼ(i)·G = Σₖ Cⱼₖ·i^k
+//!        sᵢ = Σⱼ fⱼ(i),  Y = Σⱼ Cⱼ₀,  Yₗ = Σⱼ Σₖ Cⱼₖ·l^k
+//! ```
+//!
+//! 지식 증명은 다른 참여자의 commitment를 보고 자기 몫을 조작해 그룹 키를 고르는 공격(rogue key)을 막음
+//!
+//! 참여자 번호는 1..=n 이어야 하고, part2/part3에는 자신을 뺀 n-1명의 패키지를 모두 넘김
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use curve25519_dalek::edwards::EdwardsPoint;
+use curve25519_dalek::scalar::Scalar;
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{
+    check_parameters, decode_point, decode_scalar, evaluate_commitment, evaluate_polynomial, hash_to_scalar,
+    random_scalar, Identifier, KeyPackage, PublicKeyPackage, CONTEXT,
+};
+use crate::error::{Error, Result};
+use crate::secret::SecretBytes;
+use crate::threshold::{header, Reader};
+
+const KIND_ROUND1_PACKAGE: u8 = 0x06;
+const KIND_ROUND2_PACKAGE: u8 = 0x07;
+
+/// part1의 비밀 상태 (다항식 계수) — part2에서 소비
+pub struct Round1Secret {
+    identifier: Identifier,
+    min_signers: u16,
+    max_signers: u16,
+    coefficients: Zeroizing<Vec<Scalar>>,
+}
+
+/// part1 브로드캐스트 (commitment + 지식 증명)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round1Package {
+    identifier: Identifier,
+    commitment: Vec<[u8; 32]>,
+    proof_r: [u8; 32],
+    proof_mu: [u8; 32],
+}
+
+/// part2의 비밀 상태 (자기 자신에게 줄 조각 fᵢ(i)) — part3에서 사용
+pub struct Round2Secret {
+    identifier: Identifier,
+    min_signers: u16,
+    max_signers: u16,
+    commitment: Vec<EdwardsPoint>,
+    own_share: Scalar,
+}
+
+/// part2에서 참여자 j에게만 보내는 조각 fᵢ(j) (비밀)
+#[derive(Clone)]
+pub struct Round2Package {
+    sender: Identifier,
+    receiver: Identifier,
+    signing_share: SecretBytes<32>,
+}
+
+/// 1단계: 다항식과 commitment, 지식 증명 생성
+pub fn part1(identifier: Identifier, min_signers: u16, max_signers: u16) -> Result<(Round1Secret, Round1Package)> {
+    check_parameters(min_signers, max_signers)?;
+    check_identifier(identifier, max_signers)?;
+
+    let coefficients = Zeroizing::new((0..min_signers).map(|_| random_scalar()).collect::<Vec<_>>());
+    let commitment: Vec<[u8; 32]> =
+        coefficients.iter().map(|a| EdwardsPoint::mul_base(a).compress().to_bytes()).collect();
+
+    let mut k = random_scalar();
+    let proof_r = EdwardsPoint::mul_base(&k).compress().to_bytes();
+    let c = proof_challenge(identifier, &commitment[0], &proof_r);
+    let proof_mu = (k + coefficients[0] * c).to_bytes();
+    k.zeroize();
+
+    Ok((
+        Round1Secret { identifier, min_signers, max_signers, coefficients },
+        Round1Package { identifier, commitment, proof_r, proof_mu },
+    ))
+}
+
+/// 2단계: 다른 참여자의 증명을 검증하고 각자에게 보낼 조각 계산
+pub fn part2(secret: Round1Secret, round1_packages: &[Round1Package]) -> Result<(Round2Secret, Vec<Round2Package>)> {
+    let commitments = check_round1(secret.identifier, secret.min_signers, secret.max_signers, round1_packages)?;
+
+    let packages = commitments
+        .keys()
+        .map(|&receiver| {
+            let mut share = evaluate_polynomial(&secret.coefficients, receiver.scalar());
+            let package =
+                Round2Package { sender: secret.identifier, receiver, signing_share: SecretBytes::new(share.to_bytes()) };
+            share.zeroize();
+            package
+        })
+        .collect();
+
+    let round2_secret = Round2Secret {
+        identifier: secret.identifier,
+        min_signers: secret.min_signers,
+        max_signers: secret.max_signers,
+        commitment: secret.coefficients.iter().map(EdwardsPoint::mul_base).collect(),
+        own_share: evaluate_polynomial(&secret.coefficients, secret.identifier.scalar()),
+    };
+    Ok((round2_secret, packages))
+}
+
+/// 3단계: 받은 조각을 commitment로 확인하고 키 조각/그룹 공개 정보 완성
+pub fn part3(
+    secret: &Round2Secret,
+    round1_packages: &[Round1Package],
+    round2_packages: &[Round2Package],
+) -> Result<(KeyPackage, PublicKeyPackage)> {
+    let mut commitments = check_round1(secret.identifier, secret.min_signers, secret.max_signers, round1_packages)?;
+    if round2_packages.len() != commitments.len() {
+        return Err(Error::InvalidInput(format!(
+            "DKG 2단계 패키지가 {}개 필요합니다 (현재 {}개)",
+            commitments.len(),
+            round2_packages.len()
+        )));
+    }
+
+    let mut signing_share = secret.own_share;
+    let mut received = Vec::with_capacity(round2_packages.len());
+    for package in round2_packages {
+        if package.receiver != secret.identifier {
+            return Err(Error::InvalidInput(format!("참여자 {}에게 보낸 조각입니다", package.receiver)));
+        }
+        if received.contains(&package.sender) {
+            return Err(Error::InvalidInput(format!("참여자 {}의 조각이 중복되었습니다", package.sender)));
+        }
+        let commitment = commitments
+            .get(&package.sender)
+            .ok_or_else(|| Error::InvalidInput(format!("참여자 {}의 1단계 패키지가 없습니다", package.sender)))?;
+
+        let share = decode_scalar(&package.signing_share, "DKG 조각")?;
+        if EdwardsPoint::mul_base(&share) != evaluate_commitment(commitment, secret.identifier.scalar()) {
+            return Err(Error::InvalidKey(format!("참여자 {}가 보낸 조각이 commitment와 맞지 않습니다", package.sender)));
+        }
+        signing_share += share;
+        received.push(package.sender);
+    }
+
+    commitments.insert(secret.identifier, secret.commitment.clone());
+    let group_public_key: EdwardsPoint = commitments.values().map(|c| c[0]).sum();
+    let verifying_shares = (1..=secret.max_signers)
+        .map(|l| {
+            let identifier = Identifier(l);
+            let share: EdwardsPoint = commitments.values().map(|c| evaluate_commitment(c, identifier.scalar())).sum();
+            (identifier, share.compress().to_bytes())
+        })
+        .collect();
+
+    let key_package = KeyPackage::new(secret.identifier, &signing_share, &group_public_key, secret.min_signers);
+    signing_share.zeroize();
+    let public = PublicKeyPackage {
+        min_signers: secret.min_signers,
+        group_public_key: key_package.group_public_key,
+        verifying_shares,
+    };
+    debug_assert_eq!(public.verifying_shares.get(&secret.identifier), Some(&key_package.verifying_share));
+    Ok((key_package, public))
+}
+
+impl Round1Package {
+    /// 보낸 참여자
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// 직렬화 (종류 0x06)
+    ///
+    /// ```text
+    /// 번호 (2) || t (2) || Cᵢₖ (32) × t || R (32) || μ (32)
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_ROUND1_PACKAGE);
+        out.extend_from_slice(&self.identifier.0.to_be_bytes());
+        out.extend_from_slice(&(self.commitment.len() as u16).to_be_bytes());
+        for point in &self.commitment {
+            out.extend_from_slice(point);
+        }
+        out.extend_from_slice(&self.proof_r);
+        out.extend_from_slice(&self.proof_mu);
+        out
+    }
+
+    /// 역직렬화 (증명 검증은 `part2`/`part3`에서)
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_ROUND1_PACKAGE, "FROST DKG Round1Package")?;
+        let identifier = Identifier::new(reader.u16()?)?;
+        let length = reader.u16()?;
+        let commitment = (0..length).map(|_| reader.array()).collect::<Result<Vec<_>>>()?;
+        let (proof_r, proof_mu) = (reader.array()?, reader.array()?);
+        reader.finish()?;
+        Ok(Round1Package { identifier, commitment, proof_r, proof_mu })
+    }
+}
+
+impl Round2Package {
+    /// 보낸 참여자
+    pub fn sender(&self) -> Identifier {
+        self.sender
+    }
+
+    /// 받을 참여자
+    pub fn receiver(&self) -> Identifier {
+        self.receiver
+    }
+
+    /// 직렬화 (종류 0x07, 비밀): 보낸 번호 (2) || 받을 번호 (2) || fᵢ(j) (32)
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(header(KIND_ROUND2_PACKAGE));
+        out.extend_from_slice(&self.sender.0.to_be_bytes());
+        out.extend_from_slice(&self.receiver.0.to_be_bytes());
+        out.extend_from_slice(self.signing_share.as_slice());
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_ROUND2_PACKAGE, "FROST DKG Round2Package")?;
+        let sender = Identifier::new(reader.u16()?)?;
+        let receiver = Identifier::new(reader.u16()?)?;
+        let signing_share = SecretBytes::new(reader.array()?);
+        reader.finish()?;
+        Ok(Round2Package { sender, receiver, signing_share })
+    }
+}
+
+impl fmt::Debug for Round2Package {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Round2Package")
+            .field("sender", &self.sender)
+            .field("receiver", &self.receiver)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Round2Secret {
+    fn drop(&mut self) {
+        self.own_share.zeroize();
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn check_identifier(identifier: Identifier, max_signers: u16) -> Result<()> {
+    if identifier.0 > max_signers {
+        return Err(Error::InvalidInput(format!("DKG 참여자 번호는 1..={} 이어야 합니다: {}", max_signers, identifier)));
+    }
+    Ok(())
+}
+
+/// c = H(CONTEXT || "dkg" || i || Cᵢ₀ || R)
+fn proof_challenge(identifier: Identifier, constant_commitment: &[u8; 32], proof_r: &[u8; 32]) -> Scalar {
+    hash_to_scalar(&[CONTEXT, b"dkg", identifier.scalar().as_bytes(), constant_commitment, proof_r])
+}
+
+/// 다른 n-1명의 1단계 패키지 검증 → 참여자별 commitment
+fn check_round1(
+    identifier: Identifier,
+    min_signers: u16,
+    max_signers: u16,
+    packages: &[Round1Package],
+) -> Result<BTreeMap<Identifier, Vec<EdwardsPoint>>> {
+    if packages.len() != usize::from(max_signers) - 1 {
+        return Err(Error::InvalidInput(format!(
+            "DKG 1단계 패키지가 {}개 필요합니다 (현재 {}개)",
+            max_signers - 1,
+            packages.len()
+        )));
+    }
+
+    let mut commitments = BTreeMap::new();
+    for package in packages {
+        check_identifier(package.identifier, max_signers)?;
+        if package.identifier == identifier {
+            return Err(Error::InvalidInput("자신의 1단계 패키지는 넘기지 않습니다".to_string()));
+        }
+        if package.commitment.len() != usize::from(min_signers) {
+            return Err(Error::InvalidInput(format!(
+                "참여자 {}의 commitment 길이가 t = {}와 다릅니다",
+                package.identifier, min_signers
+            )));
+        }
+
+        let commitment =
+            package.commitment.iter().map(|point| decode_point(point, "DKG commitment")).collect::<Result<Vec<_>>>()?;
+        let r = decode_point(&package.proof_r, "DKG 증명")?;
+        let mu = decode_scalar(&package.proof_mu, "DKG 증명")?;
+        let c = proof_challenge(package.identifier, &package.commitment[0], &package.proof_r);
+        if EdwardsPoint::mul_base(&mu) - commitment[0] * c != r {
+            return Err(Error::InvalidSignature(format!("참여자 {}의 지식 증명이 유효하지 않습니다", package.identifier)));
+        }
+
+        if commitments.insert(package.identifier, commitment).is_some() {
+            return Err(Error::InvalidInput(format!("참여자 {}의 1단계 패키지가 중복되었습니다", package.identifier)));
+        }
+    }
+    Ok(commitments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::frost::SigningPackage;
+
+    /// n명의 DKG를 메모리 안에서 실행
+    fn run_dkg(min_signers: u16, max_signers: u16) -> (Vec<KeyPackage>, Vec<PublicKeyPackage>) {
+        let ids: Vec<Identifier> = (1..=max_signers).map(|i| Identifier::new(i).unwrap()).collect();
+        let (secrets, round1): (Vec<_>, Vec<_>) = ids.iter().map(|&id| part1(id, min_signers, max_signers).unwrap()).unzip();
+
+        let others = |id: Identifier| round1.iter().filter(|p| p.identifier != id).cloned().collect::<Vec<_>>();
+        let (round2_secrets, round2): (Vec<_>, Vec<_>) =
+            secrets.into_iter().zip(&ids).map(|(secret, &id)| part2(secret, &others(id)).unwrap()).unzip();
+        let round2: Vec<Round2Package> = round2.into_iter().flatten().collect();
+
+        round2_secrets
+            .iter()
+            .zip(&ids)
+            .map(|(secret, &id)| {
+                let inbox: Vec<Round2Package> = round2.iter().filter(|p| p.receiver == id).cloned().collect();
+                part3(secret, &others(id), &inbox).unwrap()
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn test_dkg_and_sign() {
+        let (shares, publics) = run_dkg(2, 3);
+        // 모든 참여자가 같은 그룹 공개 정보를 얻음
+        assert!(publics.iter().all(|p| *p == publics[0]));
+        let public = &publics[0];
+        for share in &shares {
+            assert_eq!(public.verifying_share(share.identifier()), Some(share.verifying_share()));
+        }
+
+        let (nonces_2, commitments_2) = shares[1].commit();
+        let (nonces_3, commitments_3) = shares[2].commit();
+        let package = SigningPackage::new(&[commitments_2, commitments_3], b"dkg").unwrap();
+        let signature_shares = [shares[1].sign(&package, nonces_2).unwrap(), shares[2].sign(&package, nonces_3).unwrap()];
+        let signature = public.aggregate(&package, &signature_shares).unwrap();
+        public.verify(b"dkg", &signature).unwrap();
+    }
+
+    #[test]
+    fn test_dkg_rejects_bad_proof() {
+        let ids: Vec<Identifier> = (1..=3).map(|i| Identifier::new(i).unwrap()).collect();
+        let round1: Vec<Round1Package> = ids.iter().map(|&id| part1(id, 2, 3).unwrap().1).collect();
+
+        // 다른 참여자의 증명을 가져다 씀
+        let mut forged = round1[1].clone();
+        forged.proof_mu = round1[2].proof_mu;
+        match part2(part1(ids[0], 2, 3).unwrap().0, &[forged, round1[2].clone()]) {
+            Err(Error::InvalidSignature(message)) => assert!(message.contains("참여자 2")),
+            other => panic!("위조 증명을 통과시킴: {:?}", other.map(|_| ())),
+        }
+
+        // 패키지 수가 모자람, 자기 패키지를 넘김, 차수가 다름
+        assert!(part2(part1(ids[0], 2, 3).unwrap().0, &round1[1..2]).is_err());
+        assert!(part2(part1(ids[0], 2, 3).unwrap().0, &round1[..2]).is_err());
+        assert!(part2(part1(ids[0], 3, 3).unwrap().0, &round1[1..]).is_err());
+    }
+
+    #[test]
+    fn test_dkg_rejects_bad_share() {
+        let ids: Vec<Identifier> = (1..=3).map(|i| Identifier::new(i).unwrap()).collect();
+        let (secrets, round1): (Vec<_>, Vec<_>) = ids.iter().map(|&id| part1(id, 2, 3).unwrap()).unzip();
+        let others = |id: Identifier| round1.iter().filter(|p| p.identifier != id).cloned().collect::<Vec<_>>();
+        let mut results: Vec<_> = secrets.into_iter().zip(&ids).map(|(secret, &id)| part2(secret, &others(id)).unwrap()).collect();
+
+        // 참여자 1이 참여자 2에게 보낸 조각만 변조
+        let inbox_of = |results: &[(Round2Secret, Vec<Round2Package>)], id: Identifier| -> Vec<Round2Package> {
+            results.iter().flat_map(|(_, packages)| packages.iter().filter(|p| p.receiver == id).cloned()).collect()
+        };
+        let index = results[0].1.iter().position(|p| p.receiver == ids[1]).unwrap();
+        results[0].1[index].signing_share = SecretBytes::new(Scalar::ONE.to_bytes());
+
+        match part3(&results[1].0, &others(ids[1]), &inbox_of(&results, ids[1])) {
+            Err(Error::InvalidKey(message)) => assert!(message.contains("참여자 1")),
+            other => panic!("변조된 조각을 통과시킴: {:?}", other.map(|_| ())),
+        }
+        // 다른 참여자는 영향 없음
+        assert!(part3(&results[2].0, &others(ids[2]), &inbox_of(&results, ids[2])).is_ok());
+        // 조각 중복
+        let inbox = inbox_of(&results, ids[2]);
+        assert!(part3(&results[2].0, &others(ids[2]), &[inbox[0].clone(), inbox[0].clone()]).is_err());
+    }
+
+    #[test]
+    fn test_package_serialization() {
+        let (secret, package) = part1(Identifier::new(1).unwrap(), 2, 3).unwrap();
+        assert_eq!(Round1Package::from_bytes(&package.to_bytes()).unwrap(), package);
+
+        let (_, other) = part1(Identifier::new(2).unwrap(), 2, 3).unwrap();
+        let (_, third) = part1(Identifier::new(3).unwrap(), 2, 3).unwrap();
+        let (_, round2) = part2(secret, &[other, third]).unwrap();
+        let restored = Round2Package::from_bytes(&round2[0].to_bytes()).unwrap();
+        assert_eq!((restored.sender(), restored.receiver()), (round2[0].sender(), round2[0].receiver()));
+        assert_eq!(restored.signing_share, round2[0].signing_share);
+        assert!(!format!("{:?}", restored).contains(&hex::encode(restored.signing_share.as_slice())));
+
+        assert!(Round2Package::from_bytes(&package.to_bytes()).is_err());
+        assert!(part1(Identifier::new(4).unwrap(), 2, 3).is_err());
+    }
+}
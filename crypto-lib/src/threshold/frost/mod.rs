@@ -0,0 +1,962 @@
+//! FROST Threshold Ed25519 (RFC 9591, FROST(Ed25519, SHA-512))
+//!
+//! n명 중 t명이 모이면 서명할 수 있는 Ed25519 키 — 결과는 보통 Ed25519 서명이라
+//! Solana, Sui 등에서 그대로 검증됨
+//!
+//! ## 키 생성
+//! | 방식 | 함수 | 특징 |
+//! |------|------|------|
+//! | 신뢰 딜러 | `trusted_dealer_keygen` | 한 곳에서 비밀 다항식을 만들어 조각을 나눠줌 (생성 순간엔 딜러가 키를 앎) |
+//! | 기존 키 분할 | `split_ed25519_key` | 이미 쓰고 있는 Solana/Sui 키를 분할 (원본 시드는 분할 후 폐기) |
+//! | DKG | `dkg` 모듈 | 참여자끼리 3단계로 생성, 전체 키는 어디에도 존재한 적 없음 |
+//!
+//! ```text
+//! f(x) = s + a₁x + … + a_{t-1}x^{t-1}      s = 그룹 개인키 스칼라
+//! 참여자 i의 조각 sᵢ = f(i),  검증키 Yᵢ = sᵢ·G,  그룹 공개키 Y = s·G
+//! ```
+//!
+//! ## 서명 (2라운드)
+//! ```text
+//! 1라운드  각 참여자: nonce (dᵢ, eᵢ) 생성 → commitment (Dᵢ = dᵢ·G, Eᵢ = eᵢ·G) 공개
+//! 코디네이터: commitment 목록 + 메시지 = SigningPackage
+//! 2라운드  ρᵢ = H1(Y || H4(msg) || H5(commitments) || i)       binding factor
+//!          R  = Σ (Dⱼ + ρⱼ·Eⱼ)                                 그룹 commitment
+//!          c  = H2(R || Y || msg)                              Ed25519 챌린지
+//!          zᵢ = dᵢ + eᵢ·ρᵢ + λᵢ·sᵢ·c                            λᵢ = 라그랑주 계수
+//! 집계     z = Σ zᵢ,  서명 = R || z   (zᵢ·G = Dᵢ + ρᵢ·Eᵢ + c·λᵢ·Yᵢ 로 조각마다 검증)
+//! ```
+//!
+//! nonce는 한 번만 사용 — `SigningNonces`는 `sign`에서 소비되고 직렬화할 수 없음
+//!
+//! ```
+//! use crypto_lib::threshold::frost::{self, SigningPackage};
+//!
+//! let (shares, public) = frost::trusted_dealer_keygen(2, 3).unwrap();
+//!
+//! let (nonces_1, commitments_1) = shares[0].commit();
+//! let (nonces_3, commitments_3) = shares[2].commit();
+//! let package = SigningPackage::new(&[commitments_1, commitments_3], b"hello").unwrap();
+//!
+//! let signature_shares = [shares[0].sign(&package, nonces_1).unwrap(), shares[2].sign(&package, nonces_3).unwrap()];
+//! let signature = public.aggregate(&package, &signature_shares).unwrap();
+//! public.verify(b"hello", &signature).unwrap();
+//! ```
+
+pub mod dkg;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+use ed25519_dalek::{Signature, VerifyingKey};
+use rand::RngCore;
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{header, Reader};
+use crate::error::{Error, Result};
+use crate::secret::SecretBytes;
+use crate::sui::{SignatureScheme, SuiAddress};
+
+/// RFC 9591 ciphersuite context string
+const CONTEXT: &[u8] = b"FROST-ED25519-SHA512-v1";
+
+const KIND_KEY_PACKAGE: u8 = 0x01;
+const KIND_PUBLIC_KEY_PACKAGE: u8 = 0x02;
+const KIND_SIGNING_COMMITMENTS: u8 = 0x03;
+const KIND_SIGNING_PACKAGE: u8 = 0x04;
+const KIND_SIGNATURE_SHARE: u8 = 0x05;
+
+/// 참여자 번호 (1부터, 다항식의 x 좌표)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identifier(u16);
+
+impl Identifier {
+    /// 0은 그룹 비밀 f(0)의 자리라 사용할 수 없음
+    pub fn new(value: u16) -> Result<Self> {
+        if value == 0 {
+            return Err(Error::InvalidInput("FROST 참여자 번호는 1 이상이어야 합니다".to_string()));
+        }
+        Ok(Identifier(value))
+    }
+
+    /// 번호
+    pub fn value(self) -> u16 {
+        self.0
+    }
+
+    fn scalar(self) -> Scalar {
+        Scalar::from(u64::from(self.0))
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 키 조각
+// ═══════════════════════════════════════════════════════════════
+
+/// 참여자 한 명의 키 조각 (비밀)
+///
+/// `Debug`는 조각을 가림
+#[derive(Clone)]
+pub struct KeyPackage {
+    identifier: Identifier,
+    signing_share: SecretBytes<32>,
+    verifying_share: [u8; 32],
+    group_public_key: [u8; 32],
+    min_signers: u16,
+}
+
+/// 그룹 공개 정보 (코디네이터, 검증자용)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKeyPackage {
+    min_signers: u16,
+    group_public_key: [u8; 32],
+    verifying_shares: BTreeMap<Identifier, [u8; 32]>,
+}
+
+impl KeyPackage {
+    fn new(identifier: Identifier, signing_share: &Scalar, group_public_key: &EdwardsPoint, min_signers: u16) -> Self {
+        KeyPackage {
+            identifier,
+            signing_share: SecretBytes::new(signing_share.to_bytes()),
+            verifying_share: EdwardsPoint::mul_base(signing_share).compress().to_bytes(),
+            group_public_key: group_public_key.compress().to_bytes(),
+            min_signers,
+        }
+    }
+
+    /// 참여자 번호
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// 검증키 Yᵢ = sᵢ·G
+    pub fn verifying_share(&self) -> &[u8; 32] {
+        &self.verifying_share
+    }
+
+    /// 그룹 공개키 (= Ed25519 공개키, Solana 주소)
+    pub fn group_public_key(&self) -> &[u8; 32] {
+        &self.group_public_key
+    }
+
+    /// 서명에 필요한 최소 참여자 수 t
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    /// 1라운드: 일회용 nonce와 공개할 commitment 생성
+    pub fn commit(&self) -> (SigningNonces, SigningCommitments) {
+        let mut random = Zeroizing::new([0u8; 64]);
+        rand::thread_rng().fill_bytes(&mut random[..]);
+        self.commit_with_randomness(random[..32].try_into().expect("32바이트"), random[32..].try_into().expect("32바이트"))
+    }
+
+    /// 난수를 받아 nonce 생성 (`commit`의 본체, 테스트 벡터에서 난수 주입)
+    fn commit_with_randomness(&self, hiding_random: &[u8; 32], binding_random: &[u8; 32]) -> (SigningNonces, SigningCommitments) {
+        let share = self.share();
+        let hiding = nonce_generate(hiding_random, &share);
+        let binding = nonce_generate(binding_random, &share);
+        let commitments = SigningCommitments {
+            identifier: self.identifier,
+            hiding: EdwardsPoint::mul_base(&hiding).compress().to_bytes(),
+            binding: EdwardsPoint::mul_base(&binding).compress().to_bytes(),
+        };
+        (SigningNonces { hiding, binding, commitments }, commitments)
+    }
+
+    /// 2라운드: 서명 조각 zᵢ 계산 (nonce는 여기서 소비됨)
+    pub fn sign(&self, package: &SigningPackage, nonces: SigningNonces) -> Result<SignatureShare> {
+        if package.commitments.len() < usize::from(self.min_signers) {
+            return Err(Error::InvalidInput(format!(
+                "서명 참여자가 {}명뿐입니다 (최소 {}명)",
+                package.commitments.len(),
+                self.min_signers
+            )));
+        }
+        if package.commitments.get(&self.identifier) != Some(&nonces.commitments) {
+            return Err(Error::InvalidInput(format!(
+                "서명 패키지에 참여자 {}의 commitment가 없거나 이 nonce와 다릅니다",
+                self.identifier
+            )));
+        }
+
+        let factors = binding_factors(&self.group_public_key, package);
+        let group_commitment = group_commitment(package, &factors);
+        let c = challenge(&group_commitment, &self.group_public_key, &package.message);
+        let lambda = lagrange_coefficient(self.identifier, package.commitments.keys().copied());
+
+        let z = nonces.hiding + nonces.binding * factors[&self.identifier] + lambda * self.share() * c;
+        Ok(SignatureShare { identifier: self.identifier, share: z.to_bytes() })
+    }
+
+    /// 직렬화 (종류 0x01, 비밀 — 저장 시 `keystore` 등으로 암호화)
+    ///
+    /// ```text
+    /// t (2) || 번호 (2) || 조각 sᵢ (32) || 그룹 공개키 (32)
+    /// ```
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(header(KIND_KEY_PACKAGE));
+        out.extend_from_slice(&self.min_signers.to_be_bytes());
+        out.extend_from_slice(&self.identifier.0.to_be_bytes());
+        out.extend_from_slice(self.signing_share.as_slice());
+        out.extend_from_slice(&self.group_public_key);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_KEY_PACKAGE, "FROST KeyPackage")?;
+        let min_signers = reader.u16()?;
+        let identifier = Identifier::new(reader.u16()?)?;
+        let share = SecretBytes::new(reader.array()?);
+        let group_public_key = decode_point(&reader.array()?, "그룹 공개키")?;
+        reader.finish()?;
+
+        if min_signers < 2 {
+            return Err(Error::InvalidInput(format!("FROST 최소 서명자 수가 유효하지 않습니다: {}", min_signers)));
+        }
+        Ok(KeyPackage::new(identifier, &decode_scalar(&share, "키 조각")?, &group_public_key, min_signers))
+    }
+
+    fn share(&self) -> Scalar {
+        Scalar::from_canonical_bytes(*self.signing_share).expect("생성 시 검증됨")
+    }
+}
+
+impl fmt::Debug for KeyPackage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPackage")
+            .field("identifier", &self.identifier)
+            .field("group_public_key", &hex::encode(self.group_public_key))
+            .field("min_signers", &self.min_signers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PublicKeyPackage {
+    fn from_key_packages(packages: &[KeyPackage]) -> Self {
+        PublicKeyPackage {
+            min_signers: packages[0].min_signers,
+            group_public_key: packages[0].group_public_key,
+            verifying_shares: packages.iter().map(|p| (p.identifier, p.verifying_share)).collect(),
+        }
+    }
+
+    /// 그룹 공개키 (= Ed25519 공개키)
+    pub fn group_public_key(&self) -> &[u8; 32] {
+        &self.group_public_key
+    }
+
+    /// 서명에 필요한 최소 참여자 수 t
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    /// 전체 참여자 수 n
+    pub fn max_signers(&self) -> u16 {
+        self.verifying_shares.len() as u16
+    }
+
+    /// 참여자의 검증키
+    pub fn verifying_share(&self, identifier: Identifier) -> Option<&[u8; 32]> {
+        self.verifying_shares.get(&identifier)
+    }
+
+    /// 그룹 공개키의 Solana 주소
+    pub fn solana_address(&self) -> String {
+        bs58::encode(self.group_public_key).into_string()
+    }
+
+    /// 그룹 공개키의 Sui 주소
+    pub fn sui_address(&self) -> String {
+        SuiAddress::from_public_key(&self.group_public_key, SignatureScheme::Ed25519)
+            .expect("생성 시 검증된 공개키")
+            .to_string()
+    }
+
+    /// 서명 조각 하나 검증 (zᵢ·G = Dᵢ + ρᵢ·Eᵢ + c·λᵢ·Yᵢ)
+    pub fn verify_share(&self, package: &SigningPackage, share: &SignatureShare) -> Result<()> {
+        let factors = binding_factors(&self.group_public_key, package);
+        let group_commitment = group_commitment(package, &factors);
+        let c = challenge(&group_commitment, &self.group_public_key, &package.message);
+        self.verify_share_with(package, share, &factors, c)
+    }
+
+    /// 서명 조각을 모아 Ed25519 서명 (R || z) 생성
+    ///
+    /// 잘못된 조각이 있으면 어느 참여자인지 담은 `InvalidSignature`
+    pub fn aggregate(&self, package: &SigningPackage, shares: &[SignatureShare]) -> Result<[u8; 64]> {
+        if package.commitments.len() < usize::from(self.min_signers) {
+            return Err(Error::InvalidInput(format!(
+                "서명 참여자가 {}명뿐입니다 (최소 {}명)",
+                package.commitments.len(),
+                self.min_signers
+            )));
+        }
+        let mut ids: Vec<Identifier> = shares.iter().map(|s| s.identifier).collect();
+        ids.sort();
+        if !ids.iter().copied().eq(package.commitments.keys().copied()) {
+            return Err(Error::InvalidInput("서명 조각과 commitment의 참여자 목록이 다릅니다".to_string()));
+        }
+
+        let factors = binding_factors(&self.group_public_key, package);
+        let group_commitment = group_commitment(package, &factors);
+        let c = challenge(&group_commitment, &self.group_public_key, &package.message);
+
+        let mut z = Scalar::ZERO;
+        for share in shares {
+            self.verify_share_with(package, share, &factors, c)?;
+            z += decode_scalar(&share.share, "서명 조각")?;
+        }
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(group_commitment.compress().as_bytes());
+        signature[32..].copy_from_slice(z.as_bytes());
+        self.verify(&package.message, &signature)?;
+        Ok(signature)
+    }
+
+    /// Ed25519 서명 검증 (strict: 작은 위수 점, 비정규 s 거부)
+    pub fn verify(&self, message: &[u8], signature: &[u8; 64]) -> Result<()> {
+        let key = VerifyingKey::from_bytes(&self.group_public_key).expect("생성 시 검증된 공개키");
+        key.verify_strict(message, &Signature::from_bytes(signature))
+            .map_err(|_| Error::InvalidSignature("FROST 그룹 서명 검증 실패".to_string()))
+    }
+
+    /// 직렬화 (종류 0x02)
+    ///
+    /// ```text
+    /// t (2) || n (2) || 그룹 공개키 (32) || (번호 (2) || 검증키 (32)) × n
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_PUBLIC_KEY_PACKAGE);
+        out.extend_from_slice(&self.min_signers.to_be_bytes());
+        out.extend_from_slice(&self.max_signers().to_be_bytes());
+        out.extend_from_slice(&self.group_public_key);
+        for (identifier, share) in &self.verifying_shares {
+            out.extend_from_slice(&identifier.0.to_be_bytes());
+            out.extend_from_slice(share);
+        }
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_PUBLIC_KEY_PACKAGE, "FROST PublicKeyPackage")?;
+        let min_signers = reader.u16()?;
+        let max_signers = reader.u16()?;
+        check_parameters(min_signers, max_signers)?;
+        let group_public_key = reader.array()?;
+        decode_point(&group_public_key, "그룹 공개키")?;
+
+        let mut verifying_shares = BTreeMap::new();
+        for _ in 0..max_signers {
+            let identifier = Identifier::new(reader.u16()?)?;
+            let share = reader.array()?;
+            decode_point(&share, "검증키")?;
+            if verifying_shares.insert(identifier, share).is_some() {
+                return Err(Error::InvalidInput(format!("참여자 {}가 중복되었습니다", identifier)));
+            }
+        }
+        reader.finish()?;
+        Ok(PublicKeyPackage { min_signers, group_public_key, verifying_shares })
+    }
+
+    fn verify_share_with(
+        &self,
+        package: &SigningPackage,
+        share: &SignatureShare,
+        factors: &BTreeMap<Identifier, Scalar>,
+        c: Scalar,
+    ) -> Result<()> {
+        let invalid = || Error::InvalidSignature(format!("참여자 {}의 서명 조각이 유효하지 않습니다", share.identifier));
+        let (Some(commitments), Some(verifying_share)) =
+            (package.commitments.get(&share.identifier), self.verifying_shares.get(&share.identifier))
+        else {
+            return Err(Error::InvalidInput(format!("참여자 {}는 이 서명에 참여하지 않았습니다", share.identifier)));
+        };
+
+        let z = decode_scalar(&share.share, "서명 조각").map_err(|_| invalid())?;
+        let lambda = lagrange_coefficient(share.identifier, package.commitments.keys().copied());
+        let expected = commitments.hiding_point() + commitments.binding_point() * factors[&share.identifier]
+            + decode_point(verifying_share, "검증키")? * (c * lambda);
+        if EdwardsPoint::mul_base(&z) != expected {
+            return Err(invalid());
+        }
+        Ok(())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 신뢰 딜러 키 생성
+// ═══════════════════════════════════════════════════════════════
+
+/// 무작위 그룹 키를 t-of-n으로 생성
+pub fn trusted_dealer_keygen(min_signers: u16, max_signers: u16) -> Result<(Vec<KeyPackage>, PublicKeyPackage)> {
+    split(random_scalar(), min_signers, max_signers)
+}
+
+/// 기존 Ed25519 개인키(32바이트 시드, Solana/Sui 계정의 `private_key`)를 t-of-n으로 분할
+///
+/// 그룹 공개키 = 원래 공개키 → 주소가 그대로 유지됨.
+/// 조각을 모아도 시드가 아닌 스칼라만 복원되므로, 분할 후 원본 시드는 폐기
+pub fn split_ed25519_key(private_key: &[u8; 32], min_signers: u16, max_signers: u16) -> Result<(Vec<KeyPackage>, PublicKeyPackage)> {
+    let mut hash: [u8; 64] = Sha512::digest(private_key).into();
+    let mut lower: [u8; 32] = hash[..32].try_into().expect("32바이트");
+    lower[0] &= 248;
+    lower[31] &= 127;
+    lower[31] |= 64;
+    let secret = Scalar::from_bytes_mod_order(lower);
+    hash.zeroize();
+    lower.zeroize();
+    split(secret, min_signers, max_signers)
+}
+
+fn split(mut secret: Scalar, min_signers: u16, max_signers: u16) -> Result<(Vec<KeyPackage>, PublicKeyPackage)> {
+    check_parameters(min_signers, max_signers)?;
+
+    let mut coefficients = Zeroizing::new(vec![secret]);
+    coefficients.extend((1..min_signers).map(|_| random_scalar()));
+    secret.zeroize();
+
+    let group_public_key = EdwardsPoint::mul_base(&coefficients[0]);
+    let packages: Vec<KeyPackage> = (1..=max_signers)
+        .map(|i| {
+            let identifier = Identifier(i);
+            let mut share = evaluate_polynomial(&coefficients, identifier.scalar());
+            let package = KeyPackage::new(identifier, &share, &group_public_key, min_signers);
+            share.zeroize();
+            package
+        })
+        .collect();
+
+    let public = PublicKeyPackage::from_key_packages(&packages);
+    Ok((packages, public))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 서명 라운드 메시지
+// ═══════════════════════════════════════════════════════════════
+
+/// 1라운드 비밀 nonce (dᵢ, eᵢ) — 한 번의 `sign`에만 사용
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+    commitments: SigningCommitments,
+}
+
+impl SigningNonces {
+    /// 이 nonce의 commitment
+    pub fn commitments(&self) -> &SigningCommitments {
+        &self.commitments
+    }
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+impl fmt::Debug for SigningNonces {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningNonces").field("commitments", &self.commitments).finish_non_exhaustive()
+    }
+}
+
+/// 1라운드 공개 commitment (Dᵢ, Eᵢ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningCommitments {
+    identifier: Identifier,
+    hiding: [u8; 32],
+    binding: [u8; 32],
+}
+
+impl SigningCommitments {
+    /// 참여자 번호
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// 직렬화 (종류 0x03): 번호 (2) || Dᵢ (32) || Eᵢ (32)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_SIGNING_COMMITMENTS);
+        self.write(&mut out);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_SIGNING_COMMITMENTS, "FROST SigningCommitments")?;
+        let commitments = SigningCommitments::read(&mut reader)?;
+        reader.finish()?;
+        Ok(commitments)
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.identifier.0.to_be_bytes());
+        out.extend_from_slice(&self.hiding);
+        out.extend_from_slice(&self.binding);
+    }
+
+    fn read(reader: &mut Reader<'_>) -> Result<Self> {
+        let identifier = Identifier::new(reader.u16()?)?;
+        let (hiding, binding) = (reader.array()?, reader.array()?);
+        decode_point(&hiding, "hiding commitment")?;
+        decode_point(&binding, "binding commitment")?;
+        Ok(SigningCommitments { identifier, hiding, binding })
+    }
+
+    fn hiding_point(&self) -> EdwardsPoint {
+        decode_point(&self.hiding, "hiding commitment").expect("생성 시 검증됨")
+    }
+
+    fn binding_point(&self) -> EdwardsPoint {
+        decode_point(&self.binding, "binding commitment").expect("생성 시 검증됨")
+    }
+}
+
+/// 코디네이터가 참여자에게 보내는 서명 요청 (commitment 목록 + 메시지)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningPackage {
+    commitments: BTreeMap<Identifier, SigningCommitments>,
+    message: Vec<u8>,
+}
+
+impl SigningPackage {
+    /// commitment 목록과 메시지 (참여자 번호 중복 시 오류)
+    pub fn new(commitments: &[SigningCommitments], message: &[u8]) -> Result<Self> {
+        let mut map = BTreeMap::new();
+        for commitment in commitments {
+            if map.insert(commitment.identifier, *commitment).is_some() {
+                return Err(Error::InvalidInput(format!("참여자 {}의 commitment가 중복되었습니다", commitment.identifier)));
+            }
+        }
+        Ok(SigningPackage { commitments: map, message: message.to_vec() })
+    }
+
+    /// 서명할 메시지 (Solana는 직렬화된 트랜잭션 메시지, Sui는 intent 다이제스트)
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// 참여자 번호 (오름차순)
+    pub fn signers(&self) -> impl Iterator<Item = Identifier> + '_ {
+        self.commitments.keys().copied()
+    }
+
+    /// 직렬화 (종류 0x04)
+    ///
+    /// ```text
+    /// 참여자 수 (2) || commitment (66) × 참여자 수 || 메시지 길이 (4) || 메시지
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_SIGNING_PACKAGE);
+        out.extend_from_slice(&(self.commitments.len() as u16).to_be_bytes());
+        for commitment in self.commitments.values() {
+            commitment.write(&mut out);
+        }
+        out.extend_from_slice(&(self.message.len() as u32).to_be_bytes());
+        out.extend_from_slice(&self.message);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_SIGNING_PACKAGE, "FROST SigningPackage")?;
+        let count = reader.u16()?;
+        let commitments = (0..count).map(|_| SigningCommitments::read(&mut reader)).collect::<Result<Vec<_>>>()?;
+        let length = reader.u32()? as usize;
+        let message = reader.bytes(length)?;
+        reader.finish()?;
+        SigningPackage::new(&commitments, message)
+    }
+}
+
+/// 2라운드 서명 조각 zᵢ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureShare {
+    identifier: Identifier,
+    share: [u8; 32],
+}
+
+impl SignatureShare {
+    /// 참여자 번호
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// 직렬화 (종류 0x05): 번호 (2) || zᵢ (32)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_SIGNATURE_SHARE);
+        out.extend_from_slice(&self.identifier.0.to_be_bytes());
+        out.extend_from_slice(&self.share);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_SIGNATURE_SHARE, "FROST SignatureShare")?;
+        let identifier = Identifier::new(reader.u16()?)?;
+        let share = reader.array()?;
+        reader.finish()?;
+        decode_scalar(&share, "서명 조각")?;
+        Ok(SignatureShare { identifier, share })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn check_parameters(min_signers: u16, max_signers: u16) -> Result<()> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(Error::InvalidInput(format!(
+            "FROST는 2 ≤ t ≤ n 이어야 합니다 (t = {}, n = {})",
+            min_signers, max_signers
+        )));
+    }
+    Ok(())
+}
+
+/// SHA-512(parts...) mod L
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize().into())
+}
+
+fn hash(parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+fn random_scalar() -> Scalar {
+    let mut bytes = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let scalar = Scalar::from_bytes_mod_order_wide(&bytes);
+    bytes.zeroize();
+    scalar
+}
+
+/// RFC 9591 nonce_generate: H3(random(32) || 조각)
+fn nonce_generate(random: &[u8; 32], secret: &Scalar) -> Scalar {
+    hash_to_scalar(&[CONTEXT, b"nonce", random, secret.as_bytes()])
+}
+
+/// ρᵢ = H1(Y || H4(msg) || H5(commitment 목록) || i)
+fn binding_factors(group_public_key: &[u8; 32], package: &SigningPackage) -> BTreeMap<Identifier, Scalar> {
+    let mut encoded = Vec::with_capacity(package.commitments.len() * 96);
+    for (identifier, commitment) in &package.commitments {
+        encoded.extend_from_slice(identifier.scalar().as_bytes());
+        encoded.extend_from_slice(&commitment.hiding);
+        encoded.extend_from_slice(&commitment.binding);
+    }
+    let message_hash = hash(&[CONTEXT, b"msg", &package.message]);
+    let commitment_hash = hash(&[CONTEXT, b"com", &encoded]);
+
+    package
+        .commitments
+        .keys()
+        .map(|identifier| {
+            let rho = hash_to_scalar(&[
+                CONTEXT,
+                b"rho",
+                group_public_key,
+                &message_hash,
+                &commitment_hash,
+                identifier.scalar().as_bytes(),
+            ]);
+            (*identifier, rho)
+        })
+        .collect()
+}
+
+/// R = Σ (Dᵢ + ρᵢ·Eᵢ)
+fn group_commitment(package: &SigningPackage, factors: &BTreeMap<Identifier, Scalar>) -> EdwardsPoint {
+    package
+        .commitments
+        .values()
+        .map(|c| c.hiding_point() + c.binding_point() * factors[&c.identifier])
+        .sum()
+}
+
+/// Ed25519 챌린지 H2(R || Y || msg) — 일반 Ed25519 검증과 같은 식
+fn challenge(group_commitment: &EdwardsPoint, group_public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    hash_to_scalar(&[group_commitment.compress().as_bytes(), group_public_key, message])
+}
+
+/// λᵢ = Π_{j≠i} xⱼ / (xⱼ - xᵢ)
+fn lagrange_coefficient(identifier: Identifier, signers: impl Iterator<Item = Identifier>) -> Scalar {
+    let x = identifier.scalar();
+    let (mut numerator, mut denominator) = (Scalar::ONE, Scalar::ONE);
+    for other in signers.filter(|&j| j != identifier) {
+        numerator *= other.scalar();
+        denominator *= other.scalar() - x;
+    }
+    numerator * denominator.invert()
+}
+
+/// f(x) (Horner)
+fn evaluate_polynomial(coefficients: &[Scalar], x: Scalar) -> Scalar {
+    coefficients.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+}
+
+/// Σ Cₖ·x^k (Horner)
+fn evaluate_commitment(commitment: &[EdwardsPoint], x: Scalar) -> EdwardsPoint {
+    commitment.iter().rev().fold(EdwardsPoint::identity(), |acc, c| acc * x + c)
+}
+
+/// 압축 점 복원 (무한원점, 작은 위수 점 거부)
+fn decode_point(bytes: &[u8; 32], name: &str) -> Result<EdwardsPoint> {
+    CompressedEdwardsY(*bytes)
+        .decompress()
+        .filter(|point| !point.is_small_order())
+        .ok_or_else(|| Error::InvalidKey(format!("유효하지 않은 Ed25519 {}", name)))
+}
+
+/// 정규 스칼라 (< L)
+fn decode_scalar(bytes: &[u8; 32], name: &str) -> Result<Scalar> {
+    Option::from(Scalar::from_canonical_bytes(*bytes)).ok_or_else(|| Error::InvalidKey(format!("유효하지 않은 {} 스칼라", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::SolanaAccount;
+
+    /// 주어진 참여자들로 서명
+    fn sign_with(shares: &[&KeyPackage], public: &PublicKeyPackage, message: &[u8]) -> Result<[u8; 64]> {
+        let (nonces, commitments): (Vec<_>, Vec<_>) = shares.iter().map(|share| share.commit()).unzip();
+        let package = SigningPackage::new(&commitments, message)?;
+        let signature_shares = shares
+            .iter()
+            .zip(nonces)
+            .map(|(share, nonces)| share.sign(&package, nonces))
+            .collect::<Result<Vec<_>>>()?;
+        public.aggregate(&package, &signature_shares)
+    }
+
+    fn scalar(hex_str: &str) -> Scalar {
+        Scalar::from_canonical_bytes(hex::decode(hex_str).unwrap().try_into().unwrap()).unwrap()
+    }
+
+    fn bytes32(hex_str: &str) -> [u8; 32] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    /// RFC 9591 Appendix E.1 FROST(Ed25519, SHA-512), 2-of-3, 참여자 1과 3
+    #[test]
+    fn test_rfc9591_vectors() {
+        let secret = scalar("7b1c33d3f5291d85de664833beb1ad469f7fb6025a0ec78b3a790c6e13a98304");
+        let coefficient = scalar("178199860edd8c62f5212ee91eff1295d0d670ab4ed4506866bae57e7030b204");
+        let group_public_key = EdwardsPoint::mul_base(&secret);
+        assert_eq!(
+            hex::encode(group_public_key.compress().as_bytes()),
+            "15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673"
+        );
+
+        let shares = [
+            "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509",
+            "a91e66e012e4364ac9aaa405fcafd370402d9859f7b6685c07eed76bf409e80d",
+            "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02",
+        ];
+        let packages: Vec<KeyPackage> = (1..=3u16)
+            .map(|i| {
+                let share = evaluate_polynomial(&[secret, coefficient], Identifier(i).scalar());
+                assert_eq!(hex::encode(share.as_bytes()), shares[usize::from(i) - 1]);
+                KeyPackage::new(Identifier(i), &share, &group_public_key, 2)
+            })
+            .collect();
+        let public = PublicKeyPackage::from_key_packages(&packages);
+
+        // (참여자, hiding 난수, binding 난수, hiding nonce, binding nonce, D, E, ρ, 서명 조각)
+        let signers = [
+            (
+                0,
+                "0fd2e39e111cdc266f6c0f4d0fd45c947761f1f5d3cb583dfcb9bbaf8d4c9fec",
+                "69cd85f631d5f7f2721ed5e40519b1366f340a87c2f6856363dbdcda348a7501",
+                "812d6104142944d5a55924de6d49940956206909f2acaeedecda2b726e630407",
+                "b1110165fc2334149750b28dd813a39244f315cff14d4e89e6142f262ed83301",
+                "b5aa8ab305882a6fc69cbee9327e5a45e54c08af61ae77cb8207be3d2ce13de3",
+                "67e98ab55aa310c3120418e5050c9cf76cf387cb20ac9e4b6fdb6f82a469f932",
+                "f2cb9d7dd9beff688da6fcc83fa89046b3479417f47f55600b106760eb3b5603",
+                "001719ab5a53ee1a12095cd088fd149702c0720ce5fd2f29dbecf24b7281b603",
+            ),
+            (
+                2,
+                "86d64a260059e495d0fb4fcc17ea3da7452391baa494d4b00321098ed2a0062f",
+                "13e6b25afb2eba51716a9a7d44130c0dbae0004a9ef8d7b5550c8a0e07c61775",
+                "c256de65476204095ebdc01bd11dc10e57b36bc96284595b8215222374f99c0e",
+                "243d71944d929063bc51205714ae3c2218bd3451d0214dfb5aeec2a90c35180d",
+                "cfbdb165bd8aad6eb79deb8d287bcc0ab6658ae57fdcc98ed12c0669e90aec91",
+                "7487bc41a6e712eea2f2af24681b58b1cf1da278ea11fe4e8b78398965f13552",
+                "b087686bf35a13f3dc78e780a34b0fe8a77fef1b9938c563f5573d71d8d7890f",
+                "bd86125de990acc5e1f13781d8e32c03a9bbd4c53539bbc106058bfd14326007",
+            ),
+        ];
+
+        let mut round_one = Vec::new();
+        for (index, hiding_random, binding_random, hiding, binding, hiding_commitment, binding_commitment, ..) in signers {
+            let (nonces, commitments) = packages[index].commit_with_randomness(&bytes32(hiding_random), &bytes32(binding_random));
+            assert_eq!(hex::encode(nonces.hiding.as_bytes()), hiding);
+            assert_eq!(hex::encode(nonces.binding.as_bytes()), binding);
+            assert_eq!(hex::encode(commitments.hiding), hiding_commitment);
+            assert_eq!(hex::encode(commitments.binding), binding_commitment);
+            round_one.push((nonces, commitments));
+        }
+
+        let package = SigningPackage::new(&[round_one[0].1, round_one[1].1], b"test").unwrap();
+        let factors = binding_factors(&public.group_public_key, &package);
+
+        let mut signature_shares = Vec::new();
+        for ((index, .., binding_factor, sig_share), (nonces, _)) in signers.into_iter().zip(round_one) {
+            let identifier = packages[index].identifier();
+            assert_eq!(hex::encode(factors[&identifier].as_bytes()), binding_factor);
+            let share = packages[index].sign(&package, nonces).unwrap();
+            assert_eq!(hex::encode(share.share), sig_share);
+            signature_shares.push(share);
+        }
+
+        let signature = public.aggregate(&package, &signature_shares).unwrap();
+        assert_eq!(
+            hex::encode(signature),
+            "36282629c383bb820a88b71cae937d41f2f2adfcc3d02e55507e2fb9e2dd3cbebd9d2b0844e49ae0f3fa935161e1419aab7b47d21a37ebeae1f17d4987b3160b"
+        );
+    }
+
+    #[test]
+    fn test_trusted_dealer_any_subset() {
+        let (shares, public) = trusted_dealer_keygen(3, 5).unwrap();
+        assert_eq!((public.min_signers(), public.max_signers()), (3, 5));
+
+        for subset in [[0, 1, 2], [0, 2, 4], [1, 3, 4], [2, 3, 4]] {
+            let signers: Vec<&KeyPackage> = subset.iter().map(|&i| &shares[i]).collect();
+            let signature = sign_with(&signers, &public, b"threshold").unwrap();
+
+            // 일반 Ed25519 검증기로 검증
+            let key = VerifyingKey::from_bytes(public.group_public_key()).unwrap();
+            key.verify_strict(b"threshold", &Signature::from_bytes(&signature)).unwrap();
+        }
+
+        // t명보다 많아도 됨
+        let all: Vec<&KeyPackage> = shares.iter().collect();
+        assert!(sign_with(&all, &public, b"threshold").is_ok());
+        // t명보다 적으면 서명하지 않음
+        assert!(sign_with(&[&shares[0], &shares[1]], &public, b"threshold").is_err());
+    }
+
+    #[test]
+    fn test_split_solana_key() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = SolanaAccount::from_mnemonic(mnemonic, "").unwrap();
+
+        let (shares, public) = split_ed25519_key(&account.private_key, 2, 3).unwrap();
+        assert_eq!(public.group_public_key(), &account.public_key);
+        assert_eq!(public.solana_address(), account.address());
+        assert_eq!(public.sui_address(), SuiAddress::from_public_key(&account.public_key, SignatureScheme::Ed25519).unwrap().to_string());
+
+        let signature = sign_with(&[&shares[1], &shares[2]], &public, b"solana tx message").unwrap();
+        crate::solana::signing::verify_message(&account.address(), b"solana tx message", &signature).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_share_detected() {
+        let (shares, public) = trusted_dealer_keygen(2, 3).unwrap();
+        let (nonces_1, commitments_1) = shares[0].commit();
+        let (nonces_2, commitments_2) = shares[1].commit();
+        let package = SigningPackage::new(&[commitments_1, commitments_2], b"msg").unwrap();
+
+        let share_1 = shares[0].sign(&package, nonces_1).unwrap();
+        let mut share_2 = shares[1].sign(&package, nonces_2).unwrap();
+        public.verify_share(&package, &share_1).unwrap();
+        public.verify_share(&package, &share_2).unwrap();
+
+        share_2.share = (Scalar::from_canonical_bytes(share_2.share).unwrap() + Scalar::ONE).to_bytes();
+        match public.aggregate(&package, &[share_1, share_2]) {
+            Err(Error::InvalidSignature(message)) => assert!(message.contains("참여자 2")),
+            other => panic!("잘못된 조각을 찾지 못함: {:?}", other),
+        }
+        // 조각이 빠지면 집계하지 않음
+        assert!(public.aggregate(&package, &[share_1]).is_err());
+    }
+
+    #[test]
+    fn test_nonce_must_match_package() {
+        let (shares, _) = trusted_dealer_keygen(2, 3).unwrap();
+        let (nonces_1, _) = shares[0].commit();
+        let (_, commitments_1) = shares[0].commit();
+        let (_, commitments_2) = shares[1].commit();
+        let package = SigningPackage::new(&[commitments_1, commitments_2], b"msg").unwrap();
+
+        assert!(shares[0].sign(&package, nonces_1).is_err());
+        assert!(SigningPackage::new(&[commitments_1, commitments_1], b"msg").is_err());
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let (shares, public) = trusted_dealer_keygen(2, 3).unwrap();
+
+        let bytes = shares[0].to_bytes();
+        assert_eq!(&bytes[..6], b"CLTH\x01\x01");
+        let restored = KeyPackage::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.identifier(), shares[0].identifier());
+        assert_eq!(restored.verifying_share(), shares[0].verifying_share());
+        assert_eq!(PublicKeyPackage::from_bytes(&public.to_bytes()).unwrap(), public);
+
+        // 네트워크로 주고받는 라운드 메시지
+        let (nonces_1, commitments_1) = restored.commit();
+        let (nonces_3, commitments_3) = shares[2].commit();
+        let commitments_1 = SigningCommitments::from_bytes(&commitments_1.to_bytes()).unwrap();
+        let package = SigningPackage::new(&[commitments_1, commitments_3], b"over the wire").unwrap();
+        let package = SigningPackage::from_bytes(&package.to_bytes()).unwrap();
+        assert_eq!(package.signers().map(Identifier::value).collect::<Vec<_>>(), [1, 3]);
+
+        let share_1 = SignatureShare::from_bytes(&restored.sign(&package, nonces_1).unwrap().to_bytes()).unwrap();
+        let share_3 = shares[2].sign(&package, nonces_3).unwrap();
+        let signature = public.aggregate(&package, &[share_3, share_1]).unwrap();
+        public.verify(b"over the wire", &signature).unwrap();
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        let (shares, public) = trusted_dealer_keygen(2, 3).unwrap();
+        let bytes = shares[0].to_bytes();
+
+        // 종류가 다름
+        assert!(PublicKeyPackage::from_bytes(&bytes).is_err());
+        // 버전이 다름
+        let mut future = bytes.to_vec();
+        future[4] = 2;
+        assert!(KeyPackage::from_bytes(&future).is_err());
+        // 잘림, 뒤에 남음
+        assert!(KeyPackage::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut long = public.to_bytes();
+        long.push(0);
+        assert!(PublicKeyPackage::from_bytes(&long).is_err());
+        // 참여자 번호 0
+        let mut zero = bytes.to_vec();
+        zero[8..10].copy_from_slice(&[0, 0]);
+        assert!(KeyPackage::from_bytes(&zero).is_err());
+
+        assert!(trusted_dealer_keygen(1, 3).is_err());
+        assert!(trusted_dealer_keygen(4, 3).is_err());
+        assert!(Identifier::new(0).is_err());
+        assert!(!format!("{:?}", shares[0]).contains(&hex::encode(shares[0].signing_share.as_slice())));
+    }
+}
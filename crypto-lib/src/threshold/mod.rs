@@ -0,0 +1,99 @@
+//! Threshold Signing
+//!
+//! 개인키 하나를 여러 참여자(서버)가 나눠 갖고 함께 서명 — 어느 한 곳이 털려도 키가 노출되지 않음
+//!
+//! | 모듈 | 곡선 | 방식 | 결과 서명 | 대상 체인 |
+//! |------|------|------|-----------|-----------|
+//! | `frost` | Ed25519 | t-of-n 임계 서명 (RFC 9591), 딜러 또는 DKG 키 생성 | 일반 Ed25519 서명 | Solana, Sui |
+//! | `musig2` | secp256k1 | n-of-n 공동 서명 (BIP-327), 2라운드 | BIP-340 Schnorr 서명 | Bitcoin (Taproot) |
+//!
+//! 결과 서명은 단일 키 서명과 구별되지 않으므로 체인 쪽 변경이 필요 없음
+//!
+//! ## 직렬화 형식 (버전 1)
+//! 참여자끼리 주고받거나 저장하는 값은 모두 같은 헤더로 시작
+//! ```text
+//! "CLTH" (4) || 버전 (1) || 종류 (1) || 본문
+//! ```
+//!
+//! | 종류 | 값 | 비밀 |
+//! |------|----|------|
+//! | 0x01 | FROST `KeyPackage` (참여자 키 조각) | ✅ |
+//! | 0x02 | FROST `PublicKeyPackage` (그룹 공개키, 참여자 검증키) | |
+//! | 0x03 | FROST `SigningCommitments` (1라운드) | |
+//! | 0x04 | FROST `SigningPackage` (코디네이터 → 참여자) | |
+//! | 0x05 | FROST `SignatureShare` (2라운드) | |
+//! | 0x06 | FROST DKG `Round1Package` (브로드캐스트) | |
+//! | 0x07 | FROST DKG `Round2Package` (1:1, 암호화 채널로 전송) | ✅ |
+//! | 0x11 | MuSig2 `PublicNonce` (1라운드) | |
+//! | 0x12 | MuSig2 `AggregateNonce` | |
+//! | 0x13 | MuSig2 `PartialSignature` (2라운드) | |
+//!
+//! 정수는 모두 빅엔디언, 스칼라/점은 각 곡선의 표준 인코딩
+
+pub mod frost;
+pub mod musig2;
+
+use crate::error::{Error, Result};
+
+/// 직렬화 매직 바이트
+pub const MAGIC: &[u8; 4] = b"CLTH";
+/// 현재 직렬화 형식 버전
+pub const VERSION: u8 = 1;
+
+/// 헤더만 채운 버퍼
+fn header(kind: u8) -> Vec<u8> {
+    let mut out = Vec::with_capacity(128);
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    out.push(kind);
+    out
+}
+
+/// 헤더를 확인하고 본문을 앞에서부터 읽는 리더
+struct Reader<'a> {
+    data: &'a [u8],
+    name: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], kind: u8, name: &'static str) -> Result<Self> {
+        let mut reader = Reader { data, name };
+        if reader.bytes(4)? != MAGIC {
+            return Err(Error::InvalidInput(format!("{}: CLTH 형식이 아닙니다", name)));
+        }
+        match reader.array::<2>()? {
+            [VERSION, k] if k == kind => Ok(reader),
+            [VERSION, k] => Err(Error::InvalidInput(format!("{}: 종류가 다릅니다 (0x{:02x})", name, k))),
+            [v, _] => Err(Error::InvalidInput(format!("{}: 지원하지 않는 버전 {}", name, v))),
+        }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(Error::InvalidInput(format!("{}: 데이터가 잘렸습니다", self.name)));
+        }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.bytes(N)?.try_into().expect("N바이트"))
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    /// 남은 바이트가 없어야 함
+    fn finish(self) -> Result<()> {
+        if !self.data.is_empty() {
+            return Err(Error::InvalidInput(format!("{}: 뒤에 {}바이트가 남았습니다", self.name, self.data.len())));
+        }
+        Ok(())
+    }
+}
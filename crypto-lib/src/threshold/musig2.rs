@@ -0,0 +1,877 @@
+//! MuSig2 (BIP-327) — secp256k1 n-of-n Schnorr 공동 서명
+//!
+//! 여러 참여자의 공개키를 하나의 x-only 키로 합치고, 2라운드로 BIP-340 서명 하나를 만듦
+//! → Taproot 키 경로 지출에서 단일 서명과 구별되지 않음 (수수료, 프라이버시 이득)
+//!
+//! ## 키 집계 (KeyAgg)
+//! ```text
+//! L  = H_tag("KeyAgg list", P₁ || … || Pₙ)
+//! aᵢ = H_tag("KeyAgg coefficient", L || Pᵢ)   (두 번째로 다른 키는 aᵢ = 1)
+//! Q  = Σ aᵢ·Pᵢ                                 (+ 선택적 tweak: Taproot TapTweak 등)
+//! ```
+//! 공개키 순서가 다르면 다른 키가 됨 — 모든 참여자가 같은 순서를 사용해야 함
+//!
+//! ## 서명
+//! ```text
+//! 1라운드  각자: nonce (k₁, k₂) → 공개 nonce (R₁ = k₁·G, R₂ = k₂·G) 교환
+//!          집계 nonce = (Σ R₁ᵢ, Σ R₂ᵢ)
+//! 2라운드  b = H_tag("MuSig/noncecoef", 집계 nonce || Q.x || m)
+//!          R = R₁ + b·R₂,  e = H_tag("BIP0340/challenge", R.x || Q.x || m)
+//!          sᵢ = k₁ + b·k₂ + e·aᵢ·dᵢ   (R, Q의 y 패리티에 따라 부호 조정)
+//! 집계     서명 = R.x || Σ sᵢ + e·g·tacc
+//! ```
+//!
+//! ## 대상
+//! - Bitcoin Taproot: `with_taproot_tweak` 뒤 `p2tr_address`로 공동 보관 주소
+//! - Cosmos SDK 기본 secp256k1 계정은 ECDSA로 검증하므로 MuSig2 서명을 받지 않음
+//!   (Cosmos 공동 보관은 체인의 멀티시그 계정 사용)
+//!
+//! 메시지는 32바이트 (Taproot sighash)
+//!
+//! ```
+//! use crypto_lib::bitcoin::BitcoinAccount;
+//! use crypto_lib::threshold::musig2::{self, AggregateNonce, KeyAggContext, Session};
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let alice = BitcoinAccount::from_mnemonic_with_index(mnemonic, "", 0, 0, 0).unwrap();
+//! let bob = BitcoinAccount::from_mnemonic_with_index(mnemonic, "", 0, 0, 1).unwrap();
+//!
+//! let key_agg = KeyAggContext::new(&[alice.public_key, bob.public_key]).unwrap().with_taproot_tweak(None).unwrap();
+//! let message = [0x42; 32];
+//!
+//! let (alice_secret, alice_nonce) = musig2::nonce_gen(&alice.private_key, &key_agg, &message).unwrap();
+//! let (bob_secret, bob_nonce) = musig2::nonce_gen(&bob.private_key, &key_agg, &message).unwrap();
+//! let session = Session::new(&key_agg, &AggregateNonce::new(&[alice_nonce, bob_nonce]).unwrap(), &message).unwrap();
+//!
+//! let partials = [session.sign(alice_secret, &alice.private_key).unwrap(), session.sign(bob_secret, &bob.private_key).unwrap()];
+//! let signature = session.aggregate(&partials).unwrap();
+//! crypto_lib::schnorr::verify(&key_agg.x_only_public_key(), &message, &signature).unwrap();
+//! ```
+
+use std::fmt;
+
+use rand::RngCore;
+use secp256k1::constants::{CURVE_ORDER, ONE};
+use secp256k1::{PublicKey, SecretKey};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{header, Reader};
+use crate::bitcoin::{encode_segwit_address, Network};
+use crate::ecdsa;
+use crate::error::{Error, Result};
+use crate::schnorr::{self, tagged_hash};
+use crate::secret::SecretBytes;
+
+const KIND_PUBLIC_NONCE: u8 = 0x11;
+const KIND_AGGREGATE_NONCE: u8 = 0x12;
+const KIND_PARTIAL_SIGNATURE: u8 = 0x13;
+
+// ═══════════════════════════════════════════════════════════════
+// 키 집계
+// ═══════════════════════════════════════════════════════════════
+
+/// 집계 공개키와 tweak 누적값 (BIP-327 KeyGen Context)
+#[derive(Clone)]
+pub struct KeyAggContext {
+    public_keys: Vec<[u8; 33]>,
+    second_key: Option<[u8; 33]>,
+    list_hash: [u8; 32],
+    q: PublicKey,
+    gacc: Scalar,
+    tacc: Scalar,
+}
+
+impl KeyAggContext {
+    /// 압축 공개키 목록을 집계 (순서가 결과에 영향)
+    pub fn new(public_keys: &[[u8; 33]]) -> Result<Self> {
+        if public_keys.is_empty() {
+            return Err(Error::InvalidInput("MuSig2 공개키가 없습니다".to_string()));
+        }
+        let points = public_keys.iter().map(parse_public_key).collect::<Result<Vec<_>>>()?;
+
+        let list_hash = tagged_hash("KeyAgg list", &public_keys.concat());
+        let second_key = public_keys.iter().find(|pk| **pk != public_keys[0]).copied();
+        let mut context = KeyAggContext {
+            public_keys: public_keys.to_vec(),
+            second_key,
+            list_hash,
+            q: points[0],
+            gacc: Scalar::one(),
+            tacc: Scalar::ZERO,
+        };
+
+        let q = points
+            .iter()
+            .zip(public_keys)
+            .map(|(point, pk)| point_mul(Some(*point), context.coefficient_unchecked(pk)))
+            .fold(None, point_add);
+        context.q = q.ok_or_else(|| Error::InvalidKey("집계 공개키가 무한원점입니다".to_string()))?;
+        Ok(context)
+    }
+
+    /// tweak 적용 (x_only = true면 BIP-340 방식: Q의 y가 홀수일 때 먼저 부정)
+    pub fn with_tweak(mut self, tweak: &[u8; 32], x_only: bool) -> Result<Self> {
+        let t = Scalar::from_bytes(tweak)?;
+        let g = if x_only && !has_even_y(&self.q) { Scalar::one().neg() } else { Scalar::one() };
+
+        self.q = point_add(point_mul(Some(self.q), g), base_mul(t))
+            .ok_or_else(|| Error::InvalidKey("tweak 결과가 무한원점입니다".to_string()))?;
+        self.gacc = g.mul(self.gacc);
+        self.tacc = t.add(g.mul(self.tacc));
+        Ok(self)
+    }
+
+    /// Taproot 키 경로 tweak (BIP-341 TapTweak, 스크립트 경로가 없으면 None)
+    pub fn with_taproot_tweak(self, merkle_root: Option<&[u8; 32]>) -> Result<Self> {
+        let tweak = schnorr::taproot_tweak(&self.x_only_public_key(), merkle_root);
+        self.with_tweak(&tweak, true)
+    }
+
+    /// 집계(및 tweak 적용) 공개키 — 압축 33바이트
+    pub fn aggregate_public_key(&self) -> [u8; 33] {
+        self.q.serialize()
+    }
+
+    /// 서명 검증에 쓰는 x-only 공개키
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        xbytes(&self.q)
+    }
+
+    /// 현재 키를 출력 키로 쓰는 P2TR 주소 (보통 `with_taproot_tweak` 뒤에 사용)
+    pub fn p2tr_address(&self, network: Network) -> String {
+        encode_segwit_address(network, 1, &self.x_only_public_key())
+    }
+
+    /// 집계에 참여한 공개키 (입력 순서)
+    pub fn public_keys(&self) -> &[[u8; 33]] {
+        &self.public_keys
+    }
+
+    /// 참여자의 집계 계수 aᵢ (목록에 없으면 오류)
+    fn coefficient(&self, public_key: &[u8; 33]) -> Result<Scalar> {
+        if !self.public_keys.contains(public_key) {
+            return Err(Error::InvalidKey(format!("집계에 참여하지 않은 공개키: {}", hex::encode(public_key))));
+        }
+        Ok(self.coefficient_unchecked(public_key))
+    }
+
+    fn coefficient_unchecked(&self, public_key: &[u8; 33]) -> Scalar {
+        if self.second_key.as_ref() == Some(public_key) {
+            return Scalar::one();
+        }
+        let mut data = self.list_hash.to_vec();
+        data.extend_from_slice(public_key);
+        Scalar::reduce(tagged_hash("KeyAgg coefficient", &data))
+    }
+}
+
+impl fmt::Debug for KeyAggContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyAggContext")
+            .field("signers", &self.public_keys.len())
+            .field("aggregate_public_key", &hex::encode(self.aggregate_public_key()))
+            .finish_non_exhaustive()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// nonce
+// ═══════════════════════════════════════════════════════════════
+
+/// 비밀 nonce (k₁, k₂) — 한 번의 `Session::sign`에만 사용, 직렬화 불가
+pub struct SecretNonce {
+    k1: SecretBytes<32>,
+    k2: SecretBytes<32>,
+    public_key: [u8; 33],
+}
+
+/// 공개 nonce (R₁, R₂) — 다른 참여자에게 보냄
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicNonce([u8; 66]);
+
+/// 집계 nonce (Σ R₁, Σ R₂) — 코디네이터가 계산해 배포하거나 각자 계산
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AggregateNonce([u8; 66]);
+
+/// 부분 서명 sᵢ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialSignature([u8; 32]);
+
+/// 새 nonce 생성 (BIP-327 NonceGen, 개인키/집계 키/메시지를 섞어 난수 생성기 결함에 대비)
+pub fn nonce_gen(secret_key: &[u8; 32], key_agg: &KeyAggContext, message: &[u8; 32]) -> Result<(SecretNonce, PublicNonce)> {
+    let mut rand = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut rand);
+    let public_key = ecdsa::public_key(secret_key)?.serialize();
+    let result = nonce_gen_with_rand(
+        rand,
+        Some(secret_key),
+        &public_key,
+        Some(&key_agg.x_only_public_key()),
+        Some(message),
+        None,
+    );
+    rand.zeroize();
+    result
+}
+
+/// BIP-327 NonceGen (rand'를 주입, 테스트 벡터용)
+fn nonce_gen_with_rand(
+    rand: [u8; 32],
+    secret_key: Option<&[u8; 32]>,
+    public_key: &[u8; 33],
+    aggregate_key: Option<&[u8; 32]>,
+    message: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> Result<(SecretNonce, PublicNonce)> {
+    let mut seed = match secret_key {
+        Some(secret_key) => {
+            let mask = tagged_hash("MuSig/aux", &rand);
+            std::array::from_fn(|i| secret_key[i] ^ mask[i])
+        }
+        None => rand,
+    };
+
+    let mut data = Zeroizing::new(seed.to_vec());
+    seed.zeroize();
+    data.push(33);
+    data.extend_from_slice(public_key);
+    let aggregate_key = aggregate_key.map_or(&[][..], |key| &key[..]);
+    data.push(aggregate_key.len() as u8);
+    data.extend_from_slice(aggregate_key);
+    match message {
+        Some(message) => {
+            data.push(1);
+            data.extend_from_slice(&(message.len() as u64).to_be_bytes());
+            data.extend_from_slice(message);
+        }
+        None => data.push(0),
+    }
+    let extra_in = extra_in.unwrap_or_default();
+    data.extend_from_slice(&(extra_in.len() as u32).to_be_bytes());
+    data.extend_from_slice(extra_in);
+
+    let mut nonce = |i: u8| {
+        data.push(i);
+        let k = Scalar::reduce(tagged_hash("MuSig/nonce", &data));
+        data.pop();
+        k
+    };
+    let (k1, k2) = (nonce(0), nonce(1));
+    let (Some(r1), Some(r2)) = (base_mul(k1), base_mul(k2)) else {
+        return Err(Error::InvalidKey("MuSig2 nonce가 0입니다".to_string()));
+    };
+
+    let mut public = [0u8; 66];
+    public[..33].copy_from_slice(&r1.serialize());
+    public[33..].copy_from_slice(&r2.serialize());
+    Ok((
+        SecretNonce { k1: SecretBytes::new(k1.to_bytes()), k2: SecretBytes::new(k2.to_bytes()), public_key: *public_key },
+        PublicNonce(public),
+    ))
+}
+
+impl SecretNonce {
+    /// 이 nonce를 만든 참여자의 공개키
+    pub fn public_key(&self) -> &[u8; 33] {
+        &self.public_key
+    }
+}
+
+impl fmt::Debug for SecretNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretNonce").field("public_key", &hex::encode(self.public_key)).finish_non_exhaustive()
+    }
+}
+
+impl PublicNonce {
+    /// BIP-327 66바이트 형식 (R₁ || R₂, 두 점 모두 유효해야 함)
+    pub fn new(bytes: [u8; 66]) -> Result<Self> {
+        parse_public_key(bytes[..33].try_into().expect("33바이트"))?;
+        parse_public_key(bytes[33..].try_into().expect("33바이트"))?;
+        Ok(PublicNonce(bytes))
+    }
+
+    /// BIP-327 66바이트 형식
+    pub fn as_bytes(&self) -> &[u8; 66] {
+        &self.0
+    }
+
+    /// 직렬화 (종류 0x11): R₁ (33) || R₂ (33)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_PUBLIC_NONCE);
+        out.extend_from_slice(&self.0);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_PUBLIC_NONCE, "MuSig2 PublicNonce")?;
+        let bytes = reader.array()?;
+        reader.finish()?;
+        PublicNonce::new(bytes)
+    }
+}
+
+impl AggregateNonce {
+    /// 공개 nonce 합산 (BIP-327 NonceAgg)
+    pub fn new(nonces: &[PublicNonce]) -> Result<Self> {
+        if nonces.is_empty() {
+            return Err(Error::InvalidInput("MuSig2 공개 nonce가 없습니다".to_string()));
+        }
+        let mut out = [0u8; 66];
+        for (j, range) in [(0..33), (33..66)].into_iter().enumerate() {
+            let sum = nonces
+                .iter()
+                .map(|nonce| parse_public_key(nonce.0[range.clone()].try_into().expect("33바이트")).ok())
+                .fold(None, point_add);
+            out[j * 33..(j + 1) * 33].copy_from_slice(&cbytes_ext(sum));
+        }
+        Ok(AggregateNonce(out))
+    }
+
+    /// BIP-327 66바이트 형식 (무한원점은 33바이트 0)
+    pub fn from_raw(bytes: [u8; 66]) -> Result<Self> {
+        cpoint_ext(bytes[..33].try_into().expect("33바이트"))?;
+        cpoint_ext(bytes[33..].try_into().expect("33바이트"))?;
+        Ok(AggregateNonce(bytes))
+    }
+
+    /// BIP-327 66바이트 형식
+    pub fn as_bytes(&self) -> &[u8; 66] {
+        &self.0
+    }
+
+    /// 직렬화 (종류 0x12): R₁ (33) || R₂ (33)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_AGGREGATE_NONCE);
+        out.extend_from_slice(&self.0);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_AGGREGATE_NONCE, "MuSig2 AggregateNonce")?;
+        let bytes = reader.array()?;
+        reader.finish()?;
+        AggregateNonce::from_raw(bytes)
+    }
+}
+
+impl PartialSignature {
+    /// 32바이트 sᵢ (n 미만)
+    pub fn new(bytes: [u8; 32]) -> Result<Self> {
+        Scalar::from_bytes(&bytes)?;
+        Ok(PartialSignature(bytes))
+    }
+
+    /// 32바이트 sᵢ
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// 직렬화 (종류 0x13): sᵢ (32)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = header(KIND_PARTIAL_SIGNATURE);
+        out.extend_from_slice(&self.0);
+        out
+    }
+
+    /// 역직렬화
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(data, KIND_PARTIAL_SIGNATURE, "MuSig2 PartialSignature")?;
+        let bytes = reader.array()?;
+        reader.finish()?;
+        PartialSignature::new(bytes)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 서명 세션
+// ═══════════════════════════════════════════════════════════════
+
+/// 집계 키 + 집계 nonce + 메시지로 정해지는 서명 세션 (BIP-327 Session Context)
+#[derive(Debug, Clone)]
+pub struct Session {
+    key_agg: KeyAggContext,
+    message: [u8; 32],
+    b: Scalar,
+    r: PublicKey,
+    e: Scalar,
+}
+
+impl Session {
+    /// 세션 값 (b, R, e) 계산
+    pub fn new(key_agg: &KeyAggContext, aggregate_nonce: &AggregateNonce, message: &[u8; 32]) -> Result<Self> {
+        let q = xbytes(&key_agg.q);
+        let b = Scalar::reduce(tagged_hash("MuSig/noncecoef", &[&aggregate_nonce.0[..], &q, message].concat()));
+
+        let r1 = cpoint_ext(aggregate_nonce.0[..33].try_into().expect("33바이트"))?;
+        let r2 = cpoint_ext(aggregate_nonce.0[33..].try_into().expect("33바이트"))?;
+        // 최종 nonce가 무한원점이면 G 사용 (BIP-327: 악의적 참여자가 있어도 진행 가능하게)
+        let r = point_add(r1, point_mul(r2, b)).unwrap_or_else(|| base_mul(Scalar::one()).expect("G"));
+        let e = Scalar::reduce(tagged_hash("BIP0340/challenge", &[&xbytes(&r)[..], &q, message].concat()));
+
+        Ok(Session { key_agg: key_agg.clone(), message: *message, b, r, e })
+    }
+
+    /// 부분 서명 (비밀 nonce는 여기서 소비)
+    pub fn sign(&self, secret_nonce: SecretNonce, secret_key: &[u8; 32]) -> Result<PartialSignature> {
+        let k1 = Scalar::from_bytes(&secret_nonce.k1)?;
+        let k2 = Scalar::from_bytes(&secret_nonce.k2)?;
+        if k1.is_zero() || k2.is_zero() {
+            return Err(Error::InvalidKey("MuSig2 비밀 nonce가 0입니다".to_string()));
+        }
+
+        let d = Scalar(Some(ecdsa::secret_key(secret_key)?));
+        let public_key = ecdsa::public_key(secret_key)?.serialize();
+        if public_key != secret_nonce.public_key {
+            return Err(Error::InvalidKey("비밀 nonce를 만든 키와 서명 키가 다릅니다".to_string()));
+        }
+        let a = self.key_agg.coefficient(&public_key)?;
+
+        let (k1_signed, k2_signed) = if has_even_y(&self.r) { (k1, k2) } else { (k1.neg(), k2.neg()) };
+        let g = if has_even_y(&self.key_agg.q) { Scalar::one() } else { Scalar::one().neg() };
+        let d = g.mul(self.key_agg.gacc).mul(d);
+        let s = k1_signed.add(self.b.mul(k2_signed)).add(self.e.mul(a).mul(d));
+        let partial = PartialSignature(s.to_bytes());
+
+        // 자기 부분 서명 검증 (계산 오류가 상대에게 전달되지 않게)
+        let mut public_nonce = [0u8; 66];
+        public_nonce[..33].copy_from_slice(&base_mul(k1).expect("0 아님").serialize());
+        public_nonce[33..].copy_from_slice(&base_mul(k2).expect("0 아님").serialize());
+        self.verify_partial(&partial, &PublicNonce(public_nonce), &public_key)?;
+        Ok(partial)
+    }
+
+    /// 참여자 한 명의 부분 서명 검증 (BIP-327 PartialSigVerify)
+    pub fn verify_partial(&self, partial: &PartialSignature, public_nonce: &PublicNonce, public_key: &[u8; 33]) -> Result<()> {
+        let s = Scalar::from_bytes(&partial.0)?;
+        let r1 = parse_public_key(public_nonce.0[..33].try_into().expect("33바이트"))?;
+        let r2 = parse_public_key(public_nonce.0[33..].try_into().expect("33바이트"))?;
+        let effective_nonce = point_add(Some(r1), point_mul(Some(r2), self.b));
+        let effective_nonce = if has_even_y(&self.r) { effective_nonce } else { effective_nonce.map(|p| p.negate(ecdsa::context())) };
+
+        let g = if has_even_y(&self.key_agg.q) { Scalar::one() } else { Scalar::one().neg() };
+        let a = self.key_agg.coefficient(public_key)?;
+        let point = parse_public_key(public_key)?;
+        let expected = point_add(effective_nonce, point_mul(Some(point), self.e.mul(a).mul(g).mul(self.key_agg.gacc)));
+
+        if base_mul(s) != expected {
+            return Err(Error::InvalidSignature(format!("부분 서명이 유효하지 않습니다 (공개키 {})", hex::encode(public_key))));
+        }
+        Ok(())
+    }
+
+    /// 부분 서명 합산 → BIP-340 서명 (집계 x-only 키로 검증 후 반환)
+    pub fn aggregate(&self, partials: &[PartialSignature]) -> Result<[u8; 64]> {
+        let g = if has_even_y(&self.key_agg.q) { Scalar::one() } else { Scalar::one().neg() };
+        let mut s = self.e.mul(g).mul(self.key_agg.tacc);
+        for partial in partials {
+            s = s.add(Scalar::from_bytes(&partial.0)?);
+        }
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&xbytes(&self.r));
+        signature[32..].copy_from_slice(&s.to_bytes());
+        schnorr::verify(&self.key_agg.x_only_public_key(), &self.message, &signature)?;
+        Ok(signature)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수 (mod n 스칼라, 무한원점을 포함한 점 연산)
+// ═══════════════════════════════════════════════════════════════
+
+/// mod n 스칼라 — `SecretKey`는 0을 표현하지 못하므로 None = 0
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Scalar(Option<SecretKey>);
+
+impl Scalar {
+    const ZERO: Scalar = Scalar(None);
+
+    fn one() -> Self {
+        Scalar(Some(SecretKey::from_slice(&ONE).expect("1")))
+    }
+
+    /// 0 ≤ x < n 만 허용
+    fn from_bytes(bytes: &[u8; 32]) -> Result<Self> {
+        if *bytes == [0u8; 32] {
+            return Ok(Scalar::ZERO);
+        }
+        SecretKey::from_slice(bytes)
+            .map(|key| Scalar(Some(key)))
+            .map_err(|_| Error::InvalidInput("스칼라가 곡선 위수 n 이상입니다".to_string()))
+    }
+
+    /// int(bytes) mod n (해시 출력 < 2^256 < 2n 이므로 n을 한 번 빼면 충분)
+    fn reduce(mut bytes: [u8; 32]) -> Self {
+        if bytes >= CURVE_ORDER {
+            let mut borrow = 0i16;
+            for i in (0..32).rev() {
+                let diff = i16::from(bytes[i]) - i16::from(CURVE_ORDER[i]) - borrow;
+                borrow = i16::from(diff < 0);
+                bytes[i] = diff.rem_euclid(256) as u8;
+            }
+        }
+        Scalar::from_bytes(&bytes).expect("n 미만")
+    }
+
+    fn to_bytes(self) -> [u8; 32] {
+        self.0.map_or([0u8; 32], |key| key.secret_bytes())
+    }
+
+    fn is_zero(self) -> bool {
+        self.0.is_none()
+    }
+
+    fn add(self, other: Scalar) -> Scalar {
+        match (self.0, other.0) {
+            (None, _) => other,
+            (_, None) => self,
+            (Some(a), Some(b)) => Scalar(a.add_tweak(&b.into()).ok()),
+        }
+    }
+
+    fn mul(self, other: Scalar) -> Scalar {
+        match (self.0, other.0) {
+            (Some(a), Some(b)) => Scalar(Some(a.mul_tweak(&b.into()).expect("소수 위수에서 0이 아닌 두 수의 곱"))),
+            _ => Scalar::ZERO,
+        }
+    }
+
+    fn neg(self) -> Scalar {
+        Scalar(self.0.map(SecretKey::negate))
+    }
+}
+
+impl fmt::Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Scalar(..)")
+    }
+}
+
+fn parse_public_key(bytes: &[u8; 33]) -> Result<PublicKey> {
+    PublicKey::from_slice(bytes).map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))
+}
+
+/// 33바이트 0 = 무한원점 (BIP-327 cpoint_ext)
+fn cpoint_ext(bytes: &[u8; 33]) -> Result<Option<PublicKey>> {
+    if *bytes == [0u8; 33] {
+        return Ok(None);
+    }
+    parse_public_key(bytes).map(Some)
+}
+
+fn cbytes_ext(point: Option<PublicKey>) -> [u8; 33] {
+    point.map_or([0u8; 33], |p| p.serialize())
+}
+
+fn xbytes(point: &PublicKey) -> [u8; 32] {
+    point.serialize()[1..].try_into().expect("32바이트")
+}
+
+fn has_even_y(point: &PublicKey) -> bool {
+    point.serialize()[0] == 0x02
+}
+
+fn point_add(a: Option<PublicKey>, b: Option<PublicKey>) -> Option<PublicKey> {
+    match (a, b) {
+        (None, p) | (p, None) => p,
+        (Some(a), Some(b)) => a.combine(&b).ok(),
+    }
+}
+
+fn point_mul(point: Option<PublicKey>, scalar: Scalar) -> Option<PublicKey> {
+    point?.mul_tweak(ecdsa::context(), &scalar.0?.into()).ok()
+}
+
+fn base_mul(scalar: Scalar) -> Option<PublicKey> {
+    scalar.0.map(|key| PublicKey::from_secret_key(ecdsa::context(), &key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitcoin::BitcoinAccount;
+
+    fn pk(hex_str: &str) -> [u8; 33] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    fn run(secret_keys: &[[u8; 32]], key_agg: &KeyAggContext, message: &[u8; 32]) -> Result<[u8; 64]> {
+        let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+            secret_keys.iter().map(|sk| nonce_gen(sk, key_agg, message).unwrap()).unzip();
+        let session = Session::new(key_agg, &AggregateNonce::new(&public_nonces)?, message)?;
+        let partials = secret_nonces
+            .into_iter()
+            .zip(secret_keys)
+            .map(|(nonce, sk)| session.sign(nonce, sk))
+            .collect::<Result<Vec<_>>>()?;
+        session.aggregate(&partials)
+    }
+
+    #[test]
+    fn test_key_agg_vectors() {
+        // BIP-327 key_agg_vectors.json
+        let keys = [
+            pk("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            pk("03DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"),
+            pk("023590A94E768F8E1815C2F24B4D80A8E3149316C3518CE7B7AD338368D038CA66"),
+        ];
+        let cases: [(&[usize], &str); 4] = [
+            (&[0, 1, 2], "90539EEDE565F5D054F32CC0C220126889ED1E5D193BAF15AEF344FE59D4610C"),
+            (&[2, 1, 0], "6204DE8B083426DC6EAF9502D27024D53FC826BF7D2012148A0575435DF54B2B"),
+            (&[0, 0, 0], "B436E3BAD62B8CD409969A224731C193D051162D8C5AE8B109306127DA3AA935"),
+            (&[0, 0, 1, 1], "69BC22BFA5D106306E48A20679DE1D7389386124D07571D0D872686028C26A3E"),
+        ];
+        for (indices, expected) in cases {
+            let list: Vec<[u8; 33]> = indices.iter().map(|&i| keys[i]).collect();
+            assert_eq!(hex::encode_upper(KeyAggContext::new(&list).unwrap().x_only_public_key()), expected);
+        }
+
+        // 곡선 위에 없는 점, 빈 목록
+        let mut invalid = keys[0];
+        invalid[0] = 0x04;
+        assert!(KeyAggContext::new(&[keys[0], invalid]).is_err());
+        assert!(KeyAggContext::new(&[]).is_err());
+    }
+
+    fn bytes<const N: usize>(hex_str: &str) -> [u8; N] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    /// BIP-327 97바이트 secnonce (k₁ || k₂ || 공개키)
+    fn secret_nonce(hex_str: &str) -> SecretNonce {
+        let raw: [u8; 97] = bytes(hex_str);
+        SecretNonce {
+            k1: SecretBytes::new(raw[..32].try_into().unwrap()),
+            k2: SecretBytes::new(raw[32..64].try_into().unwrap()),
+            public_key: raw[64..].try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_nonce_gen_vectors() {
+        // BIP-327 nonce_gen_vectors.json (rand' = 0x0f × 32)
+        let pk_02 = pk("024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766");
+        let msg_38 = [0x26; 38];
+        // (sk, pk, aggpk, msg, extra_in, secnonce, pubnonce)
+        type Case<'a> = (Option<&'a [u8; 32]>, [u8; 33], Option<&'a [u8; 32]>, Option<&'a [u8]>, Option<&'a [u8]>, &'a str, &'a str);
+        let cases: [Case; 4] = [
+            (
+                Some(&[0x02; 32]),
+                pk_02,
+                Some(&[0x07; 32]),
+                Some(&[0x01; 32]),
+                Some(&[0x08; 32]),
+                "B114E502BEAA4E301DD08A50264172C84E41650E6CB726B410C0694D59EFFB6495B5CAF28D045B973D63E3C99A44B807BDE375FD6CB39E46DC4A511708D0E9D2024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766",
+                "02F7BE7089E8376EB355272368766B17E88E7DB72047D05E56AA881EA52B3B35DF02C29C8046FDD0DED4C7E55869137200FBDBFE2EB654267B6D7013602CAED3115A",
+            ),
+            (
+                Some(&[0x02; 32]),
+                pk_02,
+                Some(&[0x07; 32]),
+                Some(&[]),
+                Some(&[0x08; 32]),
+                "E862B068500320088138468D47E0E6F147E01B6024244AE45EAC40ACE5929B9F0789E051170B9E705D0B9EB49049A323BBBBB206D8E05C19F46C6228742AA7A9024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766",
+                "023034FA5E2679F01EE66E12225882A7A48CC66719B1B9D3B6C4DBD743EFEDA2C503F3FD6F01EB3A8E9CB315D73F1F3D287CAFBB44AB321153C6287F407600205109",
+            ),
+            (
+                Some(&[0x02; 32]),
+                pk_02,
+                Some(&[0x07; 32]),
+                Some(&msg_38),
+                Some(&[0x08; 32]),
+                "3221975ACBDEA6820EABF02A02B7F27D3A8EF68EE42787B88CBEFD9AA06AF3632EE85B1A61D8EF31126D4663A00DD96E9D1D4959E72D70FE5EBB6E7696EBA66F024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766",
+                "02E5BBC21C69270F59BD634FCBFA281BE9D76601295345112C58954625BF23793A021307511C79F95D38ACACFF1B4DA98228B77E65AA216AD075E9673286EFB4EAF3",
+            ),
+            (
+                None,
+                pk("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+                None,
+                None,
+                None,
+                "89BDD787D0284E5E4D5FC572E49E316BAB7E21E3B1830DE37DFE80156FA41A6D0B17AE8D024C53679699A6FD7944D9C4A366B514BAF43088E0708B1023DD289702F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "02C96E7CB1E8AA5DAC64D872947914198F607D90ECDE5200DE52978AD5DED63C000299EC5117C2D29EDEE8A2092587C3909BE694D5CFF0667D6C02EA4059F7CD9786",
+            ),
+        ];
+        for (secret_key, public_key, aggregate_key, message, extra_in, expected_secret, expected_public) in cases {
+            let (secret, public) = nonce_gen_with_rand([0x0f; 32], secret_key, &public_key, aggregate_key, message, extra_in).unwrap();
+            let secret = [secret.k1.as_slice(), secret.k2.as_slice(), &secret.public_key[..]].concat();
+            assert_eq!(hex::encode_upper(secret), expected_secret);
+            assert_eq!(hex::encode_upper(public.as_bytes()), expected_public);
+        }
+    }
+
+    #[test]
+    fn test_sign_verify_vectors() {
+        // BIP-327 sign_verify_vectors.json (메시지는 32바이트만 지원하므로 msg_index 0인 경우)
+        let secret_key = bytes("7FB9E0E687ADA1EEBF7ECFE2F21E73EBDB51A7D450948DFE8D76D7F2D1007671");
+        let keys = [
+            "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+            "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA661",
+            // 곡선 위에 없는 점
+            "020000000000000000000000000000000000000000000000000000000000000007",
+        ]
+        .map(pk);
+        let secnonce = "508B81A611F100A6B2B6B29656590898AF488BCF2E1F55CF22E5CFB84421FE61FA27FD49B1D50085B481285E1CA205D55C82CC1B31FF5CD54A489829355901F703935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9";
+        let nonces: [[u8; 66]; 5] = [
+            "0337C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA0287BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+            "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F817980279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            "032DE2662628C90B03F5E720284EB52FF7D71F4284F627B68A853D78C78E1FFE9303E4C5524E83FFE1493B9077CF1CA6BEB2090C93D930321071AD40B2F44E599046",
+            "0237C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA0387BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+            // 곡선 위에 없는 점
+            "0200000000000000000000000000000000000000000000000000000000000000090287BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+        ]
+        .map(bytes);
+        let aggregate_nonces: [[u8; 66]; 5] = [
+            "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61037496A3CC86926D452CAFCFD55D25972CA1675D549310DE296BFF42F72EEEA8C9",
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "048465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61037496A3CC86926D452CAFCFD55D25972CA1675D549310DE296BFF42F72EEEA8C9",
+            "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61020000000000000000000000000000000000000000000000000000000000000009",
+            "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD6102FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+        ]
+        .map(bytes);
+        let message = bytes("F95466D086770E689964664219266FE5ED215C92AE20BAB5C9D79ADDDDF3C0CF");
+        assert_eq!(ecdsa::public_key(&secret_key).unwrap().serialize(), keys[0]);
+
+        let session = |key_indices: &[usize], aggregate_nonce: usize| {
+            let key_agg = KeyAggContext::new(&key_indices.iter().map(|&i| keys[i]).collect::<Vec<_>>())?;
+            Session::new(&key_agg, &AggregateNonce::from_raw(aggregate_nonces[aggregate_nonce])?, &message)
+        };
+
+        // 유효한 부분 서명 (키 순서, 서명자 위치, 무한원점 집계 nonce)
+        let valid: [(&[usize], &[usize], usize, &str); 4] = [
+            (&[0, 1, 2], &[0, 1, 2], 0, "012ABBCB52B3016AC03AD82395A1A415C48B93DEF78718E62A7A90052FE224FB"),
+            (&[1, 0, 2], &[1, 0, 2], 0, "9FF2F7AAA856150CC8819254218D3ADEEB0535269051897724F9DB3789513A52"),
+            (&[1, 2, 0], &[1, 2, 0], 0, "FA23C359F6FAC4E7796BB93BC9F0532A95468C539BA20FF86D7C76ED92227900"),
+            (&[0, 1], &[0, 3], 1, "AE386064B26105404798F75DE2EB9AF5EDA5387B064B83D049CB7C5E08879531"),
+        ];
+        for (key_indices, nonce_indices, aggregate_nonce, expected) in valid {
+            let public_nonces: Vec<PublicNonce> = nonce_indices.iter().map(|&i| PublicNonce::new(nonces[i]).unwrap()).collect();
+            assert_eq!(AggregateNonce::new(&public_nonces).unwrap().as_bytes(), &aggregate_nonces[aggregate_nonce]);
+
+            let session = session(key_indices, aggregate_nonce).unwrap();
+            let partial = session.sign(secret_nonce(secnonce), &secret_key).unwrap();
+            assert_eq!(hex::encode_upper(partial.as_bytes()), expected);
+            session.verify_partial(&partial, &PublicNonce::new(nonces[0]).unwrap(), &keys[0]).unwrap();
+        }
+
+        // 서명 실패: 서명자 키가 목록에 없음, 잘못된 공개키, 잘못된 집계 nonce, 0인 비밀 nonce
+        assert!(session(&[1, 2], 0).unwrap().sign(secret_nonce(secnonce), &secret_key).is_err());
+        assert!(session(&[1, 0, 3], 0).is_err());
+        for aggregate_nonce in 2..5 {
+            assert!(session(&[1, 2, 0], aggregate_nonce).is_err());
+        }
+        let zero_nonce = format!("{}{}", "00".repeat(64), &secnonce[128..]);
+        assert!(session(&[0, 1, 2], 0).unwrap().sign(secret_nonce(&zero_nonce), &secret_key).is_err());
+
+        // 검증 실패: 부호가 반대인 서명, 다른 서명자, n 이상
+        let session_012 = session(&[0, 1, 2], 0).unwrap();
+        let verify = |sig: &str, signer: usize| {
+            session_012.verify_partial(&PartialSignature(bytes(sig)), &PublicNonce::new(nonces[signer]).unwrap(), &keys[signer])
+        };
+        assert!(matches!(verify("97AC833ADCB1AFA42EBF9E0725616F3C9A0D5B614F6FE283CEAAA37A8FFAF406", 0), Err(Error::InvalidSignature(_))));
+        assert!(matches!(verify("68537CC5234E505BD14061F8DA9E90C220A181855FD8BDB7F127BB12403B4D3B", 1), Err(Error::InvalidSignature(_))));
+        assert!(verify("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141", 0).is_err());
+        assert!(PartialSignature::new(bytes("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")).is_err());
+
+        // 검증 오류: 곡선 위에 없는 공개 nonce, 공개키
+        assert!(PublicNonce::new(nonces[4]).is_err());
+        assert!(session(&[3, 1, 2], 0).is_err());
+    }
+
+    #[test]
+    fn test_sign_with_derived_keys() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let accounts: Vec<BitcoinAccount> =
+            (0..3).map(|i| BitcoinAccount::from_mnemonic_with_index(mnemonic, "", 0, 0, i).unwrap()).collect();
+        let public_keys: Vec<[u8; 33]> = accounts.iter().map(|a| a.public_key).collect();
+        let secret_keys: Vec<[u8; 32]> = accounts.iter().map(|a| *a.private_key).collect();
+        let message = [0x5a; 32];
+
+        let key_agg = KeyAggContext::new(&public_keys).unwrap();
+        let signature = run(&secret_keys, &key_agg, &message).unwrap();
+        schnorr::verify(&key_agg.x_only_public_key(), &message, &signature).unwrap();
+
+        // Taproot 키 경로: tweak한 출력 키로 검증
+        let taproot = key_agg.clone().with_taproot_tweak(None).unwrap();
+        let signature = run(&secret_keys, &taproot, &message).unwrap();
+        schnorr::verify(&taproot.x_only_public_key(), &message, &signature).unwrap();
+        let (output_key, _) = schnorr::taproot_tweak_public_key(&key_agg.x_only_public_key(), None).unwrap();
+        assert_eq!(taproot.x_only_public_key(), output_key);
+        assert!(taproot.p2tr_address(Network::Mainnet).starts_with("bc1p"));
+
+        // 일반(비 x-only) tweak을 섞어도 서명 가능
+        let tweaked = key_agg.with_tweak(&[7; 32], false).unwrap().with_tweak(&[9; 32], true).unwrap();
+        assert!(run(&secret_keys, &tweaked, &message).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_partial_signature() {
+        let secret_keys = [[0x11; 32], [0x22; 32]];
+        let public_keys: Vec<[u8; 33]> = secret_keys.iter().map(|sk| ecdsa::public_key(sk).unwrap().serialize()).collect();
+        let key_agg = KeyAggContext::new(&public_keys).unwrap();
+        let message = [1; 32];
+
+        let (secret_1, public_1) = nonce_gen(&secret_keys[0], &key_agg, &message).unwrap();
+        let (secret_2, public_2) = nonce_gen(&secret_keys[1], &key_agg, &message).unwrap();
+        let session = Session::new(&key_agg, &AggregateNonce::new(&[public_1, public_2]).unwrap(), &message).unwrap();
+
+        let partial_1 = session.sign(secret_1, &secret_keys[0]).unwrap();
+        session.verify_partial(&partial_1, &public_1, &public_keys[0]).unwrap();
+        // 다른 참여자의 nonce/공개키로는 검증 실패
+        assert!(session.verify_partial(&partial_1, &public_2, &public_keys[0]).is_err());
+        assert!(session.verify_partial(&partial_1, &public_1, &public_keys[1]).is_err());
+
+        // 다른 키의 nonce로 서명 거부, 집계에 없는 키 거부
+        assert!(session.sign(secret_2, &secret_keys[0]).is_err());
+        let (outsider_secret, _) = nonce_gen(&[0x33; 32], &key_agg, &message).unwrap();
+        assert!(session.sign(outsider_secret, &[0x33; 32]).is_err());
+
+        // 부분 서명이 모자라면 최종 검증 실패
+        assert!(session.aggregate(&[partial_1]).is_err());
+    }
+
+    #[test]
+    fn test_round_serialization() {
+        let key_agg = KeyAggContext::new(&[ecdsa::public_key(&[0x11; 32]).unwrap().serialize()]).unwrap();
+        let (secret, public) = nonce_gen(&[0x11; 32], &key_agg, &[0; 32]).unwrap();
+        assert_eq!(secret.public_key(), &key_agg.public_keys()[0]);
+        assert!(!format!("{:?}", secret).contains(&hex::encode(secret.k1.as_slice())));
+
+        let bytes = public.to_bytes();
+        assert_eq!(&bytes[..6], b"CLTH\x01\x11");
+        assert_eq!(PublicNonce::from_bytes(&bytes).unwrap(), public);
+        assert_eq!(PublicNonce::new(*public.as_bytes()).unwrap(), public);
+
+        let aggregate = AggregateNonce::new(&[public]).unwrap();
+        assert_eq!(aggregate.as_bytes(), public.as_bytes());
+        assert_eq!(AggregateNonce::from_bytes(&aggregate.to_bytes()).unwrap(), aggregate);
+
+        let session = Session::new(&key_agg, &aggregate, &[0; 32]).unwrap();
+        let partial = session.sign(secret, &[0x11; 32]).unwrap();
+        assert_eq!(PartialSignature::from_bytes(&partial.to_bytes()).unwrap(), partial);
+
+        // 종류 불일치, n 이상 부분 서명
+        assert!(AggregateNonce::from_bytes(&bytes).is_err());
+        assert!(PartialSignature::new([0xff; 32]).is_err());
+        // 서로 상쇄되는 nonce의 합은 무한원점 (33바이트 0)
+        let mut negated = *public.as_bytes();
+        negated[0] ^= 1;
+        negated[33] ^= 1;
+        let infinity = AggregateNonce::new(&[public, PublicNonce::new(negated).unwrap()]).unwrap();
+        assert_eq!(infinity.as_bytes(), &[0u8; 66]);
+        assert!(Session::new(&key_agg, &infinity, &[0; 32]).is_ok());
+    }
+
+    #[test]
+    fn test_scalar_reduce() {
+        assert_eq!(Scalar::reduce(CURVE_ORDER).to_bytes(), [0u8; 32]);
+        let mut above = CURVE_ORDER;
+        above[31] += 5;
+        assert_eq!(Scalar::reduce(above).to_bytes()[31], 5);
+        assert_eq!(Scalar::one().neg().add(Scalar::one()).to_bytes(), [0u8; 32]);
+    }
+}
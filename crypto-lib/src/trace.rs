@@ -0,0 +1,181 @@
+//! tracing 계측 (선택, feature = "tracing")
+//!
+//! 키 도출, 서명, 키스토어 KDF를 `DEBUG` 레벨 span으로 감쌈
+//! → 느린 PBKDF2/scrypt/Argon2 호출을 서비스 쪽 subscriber에서 확인
+//! (소요 시간은 subscriber가 span 시작/종료로 측정, 예: `tracing_subscriber::fmt().with_span_events(FmtSpan::CLOSE)`)
+//!
+//! | span | 필드 |
+//! |------|------|
+//! | `bip39.mnemonic_to_seed` | `words`, `passphrase` (있는지 여부만) |
+//! | `bip32.derive_path` | `path` |
+//! | `slip10.derive_key` | `path`, `curve` |
+//! | `chain.derive_account` | `chain`, `path` (BIP-32 경로일 때만) |
+//! | `ecdsa.sign` / `schnorr.sign` / `ed25519.sign` | `message_len` (Ed25519만) |
+//! | `keystore.encrypt` / `keystore.decrypt` | `format` |
+//! | `keystore.kdf` | `kdf`, KDF 파라미터 |
+//!
+//! ## 비밀값이 필드에 들어가지 않는 보장
+//! span 필드는 `PublicField`를 구현한 타입만 받음 (feature를 끈 빌드에서도 타입 검사)
+//! - 숫자, bool, `&'static str`, `DerivationPath`, `Curve`
+//! - 바이트 배열/슬라이스, `SecretBytes`, 런타임 문자열(니모닉, 비밀번호, Substrate `///password` 경로)은 구현하지 않음
+//! - 공개 값인 런타임 문자열(체인 id)은 호출하는 곳에서 `Public(...)`으로 명시
+
+use std::fmt;
+
+use crate::bip32::DerivationPath;
+use crate::slip10::Curve;
+
+/// span 필드로 기록해도 되는 값
+pub(crate) trait PublicField {
+    fn fmt_field(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+macro_rules! impl_public_field_display {
+    ($($ty:ty),*) => {
+        $(impl PublicField for $ty {
+            fn fmt_field(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        })*
+    };
+}
+
+impl_public_field_display!(u8, u16, u32, u64, usize, bool, &'static str, DerivationPath);
+
+impl PublicField for Curve {
+    fn fmt_field(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl<T: PublicField> PublicField for Option<T> {
+    fn fmt_field(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => value.fmt_field(f),
+            None => f.write_str("-"),
+        }
+    }
+}
+
+/// 호출하는 곳에서 공개 값임을 확인한 문자열 (체인 id 등)
+pub(crate) struct Public<'a>(pub &'a str);
+
+impl PublicField for Public<'_> {
+    fn fmt_field(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// `PublicField`를 `Display`로 기록하는 래퍼
+pub(crate) struct Field<'a, T: ?Sized>(&'a T);
+
+impl<T: PublicField + ?Sized> fmt::Display for Field<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_field(f)
+    }
+}
+
+/// 필드 값 검사 (`PublicField`가 아니면 컴파일 오류)
+pub(crate) fn field<T: PublicField + ?Sized>(value: &T) -> Field<'_, T> {
+    Field(value)
+}
+
+/// feature를 끈 빌드의 span 자리 (아무것도 하지 않음)
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct NoSpan;
+
+/// `DEBUG` span을 만들고 들어감 — 반환값을 `let _span = ...`으로 스코프 끝까지 유지
+///
+/// ```ignore
+/// let _span = trace::span!("slip10.derive_key", path = path, curve = curve);
+/// ```
+#[cfg(feature = "tracing")]
+macro_rules! span {
+    ($name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        ::tracing::debug_span!($name $(, $key = ::tracing::field::display($crate::trace::field(&$value)))*).entered()
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! span {
+    ($name:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        $(let _ = $crate::trace::field(&$value);)*
+        $crate::trace::NoSpan
+    }};
+}
+
+pub(crate) use span;
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field as TracingField, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::bitcoin::BitcoinAccount;
+    use crate::keystore::{self, KeystoreParams, KeystoreSecret};
+    use crate::signer::Signer;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const PASSPHRASE: &str = "correct horse battery staple";
+
+    /// span 이름과 필드를 문자열로 모으는 subscriber
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<String>>>);
+
+    struct Visitor<'a>(&'a mut String);
+
+    impl Visit for Visitor<'_> {
+        fn record_debug(&mut self, field: &TracingField, value: &dyn std::fmt::Debug) {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Collector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut line = span.metadata().name().to_string();
+            span.record(&mut Visitor(&mut line));
+            let mut spans = self.0.lock().unwrap();
+            spans.push(line);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_spans_without_secrets() {
+        let collector = Collector::default();
+        let account = tracing::subscriber::with_default(collector.clone(), || {
+            let account = BitcoinAccount::from_mnemonic(MNEMONIC, PASSPHRASE).unwrap();
+            account.sign(b"hello").unwrap();
+            let secret = KeystoreSecret::Mnemonic(zeroize::Zeroizing::new(MNEMONIC.to_string()));
+            let params = KeystoreParams { m_cost: 8, t_cost: 1, p_cost: 1 };
+            let data = keystore::encrypt(&secret, PASSPHRASE, params).unwrap();
+            keystore::decrypt(&data, PASSPHRASE).unwrap();
+            account
+        });
+
+        let spans = collector.0.lock().unwrap().join("\n");
+        for name in ["bip39.mnemonic_to_seed", "bip32.derive_path", "ecdsa.sign", "keystore.encrypt", "keystore.kdf"] {
+            assert!(spans.contains(name), "{} 없음:\n{}", name, spans);
+        }
+        assert!(spans.contains("words=12 passphrase=true"));
+        assert!(spans.contains("kdf=argon2id m_cost=8"));
+
+        // 니모닉 단어, 패스프레이즈, 개인키가 어디에도 없음
+        assert!(!spans.contains("abandon"));
+        assert!(!spans.contains("horse"));
+        assert!(!spans.contains(&hex::encode(account.private_key.as_slice())));
+    }
+}
@@ -0,0 +1,481 @@
+//! Payment URI
+//!
+//! 주소 + 금액/라벨/메모로 결제 요청 URI를 만들고, 받은 URI를 다시 분해
+//!
+//! | 체인 | 표준 | 형식 | 금액 단위 |
+//! |------|------|------|-----------|
+//! | Bitcoin | BIP-21 | `bitcoin:<주소>?amount=0.01&label=..&message=..` | BTC (소수 8자리) |
+//! | EVM | EIP-681 | `ethereum:<주소>[@chain_id]?value=<wei>` | wei 정수 (`2.014e18` 허용) |
+//! | Solana | Solana Pay | `solana:<주소>?amount=1.5&spl-token=..&label=..&message=..&memo=..` | SOL (소수 9자리) |
+//!
+//! ## 금액
+//! `PaymentRequest::amount`는 항상 기본 단위(BTC, ETH, SOL)의 10진수 문자열
+//! 부동소수점을 거치지 않고 최소 단위(satoshi, wei, lamport) 정수로 바꿔 검사
+//!
+//! ## 생략되는 필드
+//! - EIP-681에는 라벨/메시지가 없어 `to_uri()`에 포함되지 않음
+//! - ERC-20 `transfer` 같은 함수 호출 URI는 지원하지 않음
+//!
+//! ```
+//! use crypto_lib::uri::{PaymentRequest, PaymentScheme};
+//!
+//! let request = PaymentRequest::new(PaymentScheme::Bitcoin, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu")
+//!     .amount("0.0015")
+//!     .label("Coffee");
+//! let uri = request.to_uri().unwrap();
+//! assert_eq!(uri, "bitcoin:bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu?amount=0.0015&label=Coffee");
+//! assert_eq!(PaymentRequest::parse(&uri).unwrap(), request);
+//! ```
+
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use crate::account::Account;
+use crate::error::{Error, Result};
+
+/// URI 스킴 (체인)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaymentScheme {
+    /// BIP-21 `bitcoin:`
+    Bitcoin,
+    /// EIP-681 `ethereum:` (모든 EVM 체인, `chain_id`로 구분)
+    Ethereum,
+    /// Solana Pay `solana:`
+    Solana,
+}
+
+impl PaymentScheme {
+    /// URI 스킴 문자열
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentScheme::Bitcoin => "bitcoin",
+            PaymentScheme::Ethereum => "ethereum",
+            PaymentScheme::Solana => "solana",
+        }
+    }
+
+    /// 기본 단위의 소수 자릿수 (BTC 8, ETH 18, SOL 9)
+    pub fn decimals(self) -> u32 {
+        match self {
+            PaymentScheme::Bitcoin => 8,
+            PaymentScheme::Ethereum => 18,
+            PaymentScheme::Solana => 9,
+        }
+    }
+
+    /// 체인 레지스트리 id → 스킴
+    pub fn from_chain_id(chain_id: &str) -> Option<Self> {
+        match chain_id {
+            "bitcoin" => Some(PaymentScheme::Bitcoin),
+            "evm" => Some(PaymentScheme::Ethereum),
+            "solana" => Some(PaymentScheme::Solana),
+            _ => None,
+        }
+    }
+}
+
+/// 결제 요청
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentRequest {
+    /// URI 스킴
+    pub scheme: PaymentScheme,
+    /// 받는 주소
+    pub address: String,
+    /// 금액 (기본 단위 10진수, 예: "0.01")
+    pub amount: Option<String>,
+    /// 받는 사람 이름 (BIP-21, Solana Pay)
+    pub label: Option<String>,
+    /// 결제 설명 (BIP-21, Solana Pay)
+    pub message: Option<String>,
+    /// 트랜잭션 메모 (Solana Pay)
+    pub memo: Option<String>,
+    /// EVM 체인 id (EIP-681 `@chain_id`, 없으면 지갑의 현재 체인)
+    pub chain_id: Option<u64>,
+    /// SPL 토큰 민트 주소 (Solana Pay `spl-token`)
+    pub spl_token: Option<String>,
+}
+
+impl PaymentRequest {
+    /// 주소만 있는 요청
+    pub fn new(scheme: PaymentScheme, address: impl Into<String>) -> Self {
+        PaymentRequest {
+            scheme,
+            address: address.into(),
+            amount: None,
+            label: None,
+            message: None,
+            memo: None,
+            chain_id: None,
+            spl_token: None,
+        }
+    }
+
+    /// 계정의 기본 주소로 요청 생성 (Bitcoin, EVM, Solana 계정)
+    pub fn for_account<A: Account + ?Sized>(account: &A) -> Result<Self> {
+        let scheme = PaymentScheme::from_chain_id(account.chain_id())
+            .ok_or_else(|| Error::Chain(format!("결제 URI를 지원하지 않는 체인입니다: {}", account.chain_id())))?;
+        Ok(PaymentRequest::new(scheme, account.address()))
+    }
+
+    /// 금액 (기본 단위 10진수)
+    pub fn amount(mut self, amount: impl Into<String>) -> Self {
+        self.amount = Some(amount.into());
+        self
+    }
+
+    /// 라벨
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// 메시지
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// 메모 (Solana Pay)
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = Some(memo.into());
+        self
+    }
+
+    /// EVM 체인 id (EIP-681)
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// SPL 토큰 민트 (Solana Pay)
+    pub fn spl_token(mut self, mint: impl Into<String>) -> Self {
+        self.spl_token = Some(mint.into());
+        self
+    }
+
+    /// URI 문자열 생성
+    ///
+    /// 금액 형식이 잘못되었거나 스킴에 없는 필드(예: Bitcoin의 `memo`)가 있으면 에러
+    pub fn to_uri(&self) -> Result<String> {
+        if self.address.is_empty() {
+            return Err(Error::InvalidAddress("주소가 비어 있습니다".to_string()));
+        }
+        self.check_fields()?;
+
+        let mut params: Vec<(&str, String)> = Vec::new();
+        let mut uri = format!("{}:{}", self.scheme.as_str(), self.address);
+
+        match self.scheme {
+            PaymentScheme::Bitcoin | PaymentScheme::Solana => {
+                if let Some(amount) = &self.amount {
+                    // 토큰 금액은 민트의 소수 자릿수를 모르므로 자릿수 제한 없이 정규화
+                    let decimals = if self.spl_token.is_some() { u32::MAX } else { self.scheme.decimals() };
+                    params.push(("amount", normalize_decimal(amount, decimals)?));
+                }
+                if let Some(mint) = &self.spl_token {
+                    params.push(("spl-token", mint.clone()));
+                }
+                if let Some(label) = &self.label {
+                    params.push(("label", label.clone()));
+                }
+                if let Some(message) = &self.message {
+                    params.push(("message", message.clone()));
+                }
+                if let Some(memo) = &self.memo {
+                    params.push(("memo", memo.clone()));
+                }
+            }
+            PaymentScheme::Ethereum => {
+                if let Some(chain_id) = self.chain_id {
+                    write!(uri, "@{}", chain_id).expect("String 쓰기");
+                }
+                if let Some(amount) = &self.amount {
+                    params.push(("value", parse_decimal(amount, 18)?.to_string()));
+                }
+            }
+        }
+
+        for (i, (key, value)) in params.iter().enumerate() {
+            uri.push(if i == 0 { '?' } else { '&' });
+            uri.push_str(key);
+            uri.push('=');
+            uri.push_str(&percent_encode(value));
+        }
+        Ok(uri)
+    }
+
+    /// URI 파싱 (스킴은 대소문자 무관)
+    pub fn parse(uri: &str) -> Result<Self> {
+        let uri = uri.trim();
+        let (scheme, rest) = uri
+            .split_once(':')
+            .ok_or_else(|| Error::InvalidInput(format!("URI 스킴이 없습니다: {}", uri)))?;
+        let scheme = match scheme.to_ascii_lowercase().as_str() {
+            "bitcoin" => PaymentScheme::Bitcoin,
+            "ethereum" => PaymentScheme::Ethereum,
+            "solana" => PaymentScheme::Solana,
+            other => return Err(Error::InvalidInput(format!("지원하지 않는 URI 스킴입니다: {}", other))),
+        };
+
+        let (target, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let mut request = PaymentRequest::new(scheme, "");
+
+        request.address = match scheme {
+            PaymentScheme::Ethereum => {
+                let target = target.strip_prefix("pay-").unwrap_or(target);
+                if target.contains('/') {
+                    return Err(Error::InvalidInput("EIP-681 함수 호출 URI는 지원하지 않습니다".to_string()));
+                }
+                let (address, chain_id) = target.split_once('@').map_or((target, None), |(a, c)| (a, Some(c)));
+                if let Some(chain_id) = chain_id {
+                    request.chain_id =
+                        Some(chain_id.parse().map_err(|_| Error::InvalidInput(format!("유효하지 않은 체인 id: {}", chain_id)))?);
+                }
+                address.to_string()
+            }
+            _ => percent_decode(target)?,
+        };
+        if request.address.is_empty() {
+            return Err(Error::InvalidAddress("URI에 주소가 없습니다".to_string()));
+        }
+
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value)?;
+
+            match (scheme, key) {
+                (PaymentScheme::Bitcoin, "amount") => request.amount = Some(normalize_decimal(&value, 8)?),
+                (PaymentScheme::Solana, "amount") => request.amount = Some(value),
+                (PaymentScheme::Ethereum, "value") => request.amount = Some(format_decimal(parse_scientific(&value)?, 18)),
+                (PaymentScheme::Bitcoin | PaymentScheme::Solana, "label") => request.label = Some(value),
+                (PaymentScheme::Bitcoin | PaymentScheme::Solana, "message") => request.message = Some(value),
+                (PaymentScheme::Solana, "memo") => request.memo = Some(value),
+                (PaymentScheme::Solana, "spl-token") => request.spl_token = Some(value),
+                // BIP-21: 모르는 req- 파라미터가 있으면 요청 전체를 거부
+                (PaymentScheme::Bitcoin, key) if key.starts_with("req-") => {
+                    return Err(Error::InvalidInput(format!("지원하지 않는 필수 파라미터입니다: {}", key)))
+                }
+                // 그 밖의 파라미터는 무시 (Solana Pay reference, EIP-681 gas 등)
+                _ => {}
+            }
+        }
+
+        // Solana 금액은 토큰 여부가 정해진 뒤 검사
+        if scheme == PaymentScheme::Solana {
+            if let Some(amount) = &request.amount {
+                let decimals = if request.spl_token.is_some() { u32::MAX } else { 9 };
+                request.amount = Some(normalize_decimal(amount, decimals)?);
+            }
+        }
+
+        Ok(request)
+    }
+
+    /// 스킴에 없는 필드 검사
+    fn check_fields(&self) -> Result<()> {
+        let unsupported = match self.scheme {
+            PaymentScheme::Bitcoin => vec![("memo", self.memo.is_some()), ("chain_id", self.chain_id.is_some()), ("spl_token", self.spl_token.is_some())],
+            PaymentScheme::Ethereum => vec![("memo", self.memo.is_some()), ("spl_token", self.spl_token.is_some())],
+            PaymentScheme::Solana => vec![("chain_id", self.chain_id.is_some())],
+        };
+        match unsupported.iter().find(|(_, set)| *set) {
+            Some((field, _)) => Err(Error::InvalidInput(format!("{} URI에는 {} 필드가 없습니다", self.scheme.as_str(), field))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        PaymentRequest::parse(s)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 금액
+// ═══════════════════════════════════════════════════════════════
+
+/// "1.25" → 최소 단위 정수 (decimals = 8이면 125000000)
+fn parse_decimal(amount: &str, decimals: u32) -> Result<u128> {
+    let invalid = || Error::InvalidInput(format!("유효하지 않은 금액: {}", amount));
+
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if (whole.is_empty() && fraction.is_empty()) || !whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() as u32 > decimals {
+        return Err(Error::InvalidInput(format!("금액의 소수점 아래 자릿수가 {}자리를 넘습니다: {}", decimals, amount)));
+    }
+
+    let scale = 10u128.checked_pow(decimals).ok_or_else(invalid)?;
+    let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };
+    let fraction_units = if fraction.is_empty() {
+        0
+    } else {
+        let digits: u128 = fraction.parse().map_err(|_| invalid())?;
+        digits * 10u128.pow(decimals - fraction.len() as u32)
+    };
+
+    whole.checked_mul(scale).and_then(|w| w.checked_add(fraction_units)).ok_or_else(invalid)
+}
+
+/// 최소 단위 정수 → "1.25" (뒤쪽 0 제거)
+fn format_decimal(units: u128, decimals: u32) -> String {
+    let scale = 10u128.pow(decimals);
+    let (whole, fraction) = (units / scale, units % scale);
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// 10진수 금액 정규화 ("01.50" → "1.5"), `decimals = u32::MAX`면 자릿수 제한 없음
+fn normalize_decimal(amount: &str, decimals: u32) -> Result<String> {
+    if decimals == u32::MAX {
+        let fraction_len = amount.split_once('.').map_or(0, |(_, f)| f.trim_end_matches('0').len() as u32);
+        return normalize_decimal(amount, fraction_len);
+    }
+    Ok(format_decimal(parse_decimal(amount, decimals)?, decimals))
+}
+
+/// EIP-681 숫자: 정수 또는 `2.014e18` 형식 → 정수
+fn parse_scientific(value: &str) -> Result<u128> {
+    match value.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => {
+            let exponent: u32 = exponent
+                .parse()
+                .map_err(|_| Error::InvalidInput(format!("유효하지 않은 지수: {}", value)))?;
+            parse_decimal(mantissa, exponent)
+        }
+        None => parse_decimal(value, 0),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 퍼센트 인코딩 (RFC 3986)
+// ═══════════════════════════════════════════════════════════════
+
+/// unreserved(ALPHA, DIGIT, `-._~`) 외의 바이트는 %XX
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            write!(encoded, "%{:02X}", byte).expect("String 쓰기");
+        }
+    }
+    encoded
+}
+
+/// %XX → 바이트 (`+`는 공백으로 바꾸지 않음)
+fn percent_decode(value: &str) -> Result<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value
+                .get(i + 1..i + 3)
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .ok_or_else(|| Error::InvalidInput(format!("유효하지 않은 퍼센트 인코딩: {}", value)))?;
+            decoded.push(hex);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| Error::InvalidInput(format!("UTF-8이 아닌 URI 값: {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::mnemonic_to_seed;
+    use crate::evm::EvmAccount;
+
+    #[test]
+    fn test_bip21() {
+        // BIP-21 예시
+        let uri = "bitcoin:175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W?amount=50&label=Luke-Jr&message=Donation%20for%20project%20xyz";
+        let request = PaymentRequest::parse(uri).unwrap();
+        println!("{:?}", request);
+        assert_eq!(request.address, "175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W");
+        assert_eq!(request.amount.as_deref(), Some("50"));
+        assert_eq!(request.label.as_deref(), Some("Luke-Jr"));
+        assert_eq!(request.message.as_deref(), Some("Donation for project xyz"));
+        assert_eq!(request.to_uri().unwrap(), uri);
+
+        // 대문자 QR 형식
+        let upper: PaymentRequest = "BITCOIN:BC1QCR8TE4KR609GCAWUTMRZA0J4XV80JY8Z306FYU?amount=0.10000000".parse().unwrap();
+        assert_eq!(upper.amount.as_deref(), Some("0.1"));
+
+        assert!(PaymentRequest::parse("bitcoin:175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W?req-somethingyoudontunderstand=50").is_err());
+        assert!(PaymentRequest::parse("bitcoin:175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W?somethingyoudontunderstand=50").is_ok());
+        assert!(PaymentRequest::parse("bitcoin:175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W?amount=0.000000001").is_err());
+        assert!(PaymentRequest::parse("bitcoin:175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W?amount=1,5").is_err());
+    }
+
+    #[test]
+    fn test_eip681() {
+        // EIP-681 예시
+        let request = PaymentRequest::parse("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359?value=2.014e18").unwrap();
+        assert_eq!(request.address, "0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359");
+        assert_eq!(request.amount.as_deref(), Some("2.014"));
+        assert_eq!(request.to_uri().unwrap(), "ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359?value=2014000000000000000");
+
+        let seed = mnemonic_to_seed("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", "");
+        let account = EvmAccount::from_seed(seed.as_slice()).unwrap();
+        let uri = PaymentRequest::for_account(&account).unwrap().chain_id(137).amount("0.5").to_uri().unwrap();
+        println!("{}", uri);
+        assert_eq!(uri, "ethereum:0x9858EfFD232B4033E47d90003D41EC34EcaEda94@137?value=500000000000000000");
+
+        let parsed = PaymentRequest::parse("ethereum:pay-0x9858EfFD232B4033E47d90003D41EC34EcaEda94@137?value=5e17").unwrap();
+        assert_eq!(parsed.chain_id, Some(137));
+        assert_eq!(parsed.amount.as_deref(), Some("0.5"));
+
+        assert!(PaymentRequest::parse("ethereum:0x9858EfFD232B4033E47d90003D41EC34EcaEda94/transfer?uint256=1").is_err());
+        assert!(PaymentRequest::parse("ethereum:0x9858EfFD232B4033E47d90003D41EC34EcaEda94?value=1.5e0").is_err());
+    }
+
+    #[test]
+    fn test_solana_pay() {
+        // Solana Pay 명세 예시
+        let uri = "solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?amount=1&label=Michael&message=Thanks%20for%20all%20the%20fish&memo=OrderId12345";
+        let request = PaymentRequest::parse(uri).unwrap();
+        assert_eq!(request.memo.as_deref(), Some("OrderId12345"));
+        assert_eq!(request.to_uri().unwrap(), uri);
+
+        // SPL 토큰 금액은 소수 자릿수 제한 없음
+        let usdc = PaymentRequest::new(PaymentScheme::Solana, "mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN")
+            .amount("0.010")
+            .spl_token("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        let uri = usdc.to_uri().unwrap();
+        println!("{}", uri);
+        assert_eq!(
+            uri,
+            "solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?amount=0.01&spl-token=EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        );
+        assert_eq!(PaymentRequest::parse(&uri).unwrap().amount.as_deref(), Some("0.01"));
+
+        assert!(PaymentRequest::parse("solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?amount=0.0000000001").is_err());
+    }
+
+    #[test]
+    fn test_unsupported_fields() {
+        let memo = PaymentRequest::new(PaymentScheme::Bitcoin, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu").memo("x");
+        assert!(matches!(memo.to_uri(), Err(Error::InvalidInput(_))));
+        assert!(PaymentRequest::new(PaymentScheme::Solana, "").to_uri().is_err());
+        assert!(PaymentRequest::parse("litecoin:abc").is_err());
+        assert!(PaymentRequest::parse("bitcoin:abc?label=%E0%A4").is_err());
+
+        let label = PaymentRequest::new(PaymentScheme::Bitcoin, "bc1q").label("커피 & 빵").to_uri().unwrap();
+        assert_eq!(PaymentRequest::parse(&label).unwrap().label.as_deref(), Some("커피 & 빵"));
+    }
+}
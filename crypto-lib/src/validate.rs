@@ -0,0 +1,451 @@
+//! Address Validation
+//!
+//! 사용자가 입력한 주소(출금 주소 등)가 체인 형식에 맞는지 검사
+//!
+//! ## 검사 항목
+//!
+//! | 체인 | 인코딩 | 체크섬 | 길이 | 접두사 |
+//! |------|--------|--------|------|--------|
+//! | Bitcoin | Bech32/Bech32m, Base58Check | BIP-173/350, 이중 SHA-256 | witness 프로그램 20/32바이트 | `bc`, 버전 0x00/0x05 (`validate_bitcoin_address`: 네트워크별) |
+//! | EVM | 0x + hex | EIP-55 (대소문자가 섞인 경우) | 20바이트 | `0x` |
+//! | Solana | Base58 | 없음 | 32바이트 | - |
+//! | Sui | 0x + hex | 없음 | 32바이트 | `0x` |
+//! | Cosmos SDK | Bech32 | BIP-173 | 20/32바이트 | 체인 HRP |
+//! | Substrate | SS58 | Blake2b-512 | 32바이트 공개키 | 모든 SS58 접두사 허용 |
+//!
+//! 형식 검사만 하므로 주소의 소유자가 있는지, 자금을 받을 수 있는지는 알 수 없음
+//!
+//! ## 키-주소 일치 확인
+//! `verify_key_matches_address`는 서명(출금) 직전에 키가 정말 그 주소의 키인지 확인
+//! → 경로나 체인을 잘못 고른 키로 서명하는 사고를 자금 이동 전에 잡음
+//! 다르면 `Error::AddressMismatch`에 키에서 도출한 주소를 담아 반환
+//!
+//! Bitcoin은 같은 키의 메인넷 P2WPKH, P2PKH, P2TR(키 경로) 주소를 모두 일치로 봄
+//! hex/Bech32 주소는 대소문자를 무시하고 비교, Base58 주소는 그대로 비교
+//!
+//! ```
+//! use crypto_lib::validate::{validate_address, AddressError};
+//! use crypto_lib::wallet::Chain;
+//!
+//! assert!(validate_address(Chain::Evm, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94").is_ok());
+//! assert_eq!(
+//!     validate_address(Chain::Evm, "0x9858effD232B4033E47d90003D41EC34EcaEda94"),
+//!     Err(AddressError::InvalidCapitalization),
+//! );
+//! ```
+
+use std::fmt;
+
+use ::bech32::primitives::decode::{CheckedHrpstring, SegwitHrpstring, UncheckedHrpstring};
+use ::bech32::{Bech32, Bech32m};
+
+use crate::bitcoin::{self, Network};
+use crate::hash::{hash160, sha256d};
+use crate::encoding::base58check;
+use crate::evm::to_checksum_address;
+use crate::schnorr::TaprootOutputKey;
+use crate::substrate::ss58_checksum;
+use crate::wallet::Chain;
+use crate::error::{Error, Result};
+
+/// 주소 검증 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    /// 빈 문자열
+    Empty,
+    /// 인코딩 오류 (허용되지 않는 문자, 구분자 없음, 대소문자 혼용 등)
+    InvalidEncoding { encoding: &'static str, message: String },
+    /// 체크섬 불일치
+    InvalidChecksum,
+    /// 디코딩한 데이터 길이 (바이트)
+    InvalidLength { expected: usize, actual: usize },
+    /// 다른 네트워크/체인의 접두사 (HRP, 버전 바이트, `0x`)
+    WrongPrefix { expected: String, actual: String },
+    /// EIP-55 체크섬 대문자 위치가 틀림
+    InvalidCapitalization,
+    /// 주소 형식을 알 수 없는 체인 (`Chain::Custom`)
+    UnsupportedChain(String),
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::Empty => write!(f, "주소가 비어 있습니다"),
+            AddressError::InvalidEncoding { encoding, message } => write!(f, "유효하지 않은 {} 인코딩: {}", encoding, message),
+            AddressError::InvalidChecksum => write!(f, "주소 체크섬이 일치하지 않습니다"),
+            AddressError::InvalidLength { expected, actual } => {
+                write!(f, "주소 데이터는 {}바이트여야 합니다, 입력: {}바이트", expected, actual)
+            }
+            AddressError::WrongPrefix { expected, actual } => {
+                write!(f, "주소 접두사가 다릅니다: {} (기대값 {})", actual, expected)
+            }
+            AddressError::InvalidCapitalization => write!(f, "EIP-55 체크섬 대소문자가 일치하지 않습니다"),
+            AddressError::UnsupportedChain(chain) => write!(f, "주소 검증을 지원하지 않는 체인입니다: {}", chain),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+impl From<AddressError> for Error {
+    fn from(e: AddressError) -> Self {
+        Error::InvalidAddress(e.to_string())
+    }
+}
+
+/// 주소와 비교할 키
+#[derive(Debug, Clone, Copy)]
+pub enum KeyMaterial<'a> {
+    /// 32바이트 개인키 (체인 곡선의 키)
+    PrivateKey(&'a [u8; 32]),
+    /// 체인 고유 형식 공개키 (secp256k1 압축/비압축, Ed25519 32바이트)
+    PublicKey(&'a [u8]),
+}
+
+/// 키가 주소의 키인지 확인하고 키에서 도출한 주소 반환
+///
+/// 다르면 `Error::AddressMismatch`, 키가 유효하지 않거나 체인이 등록되지 않았으면 해당 오류
+pub fn verify_key_matches_address(chain: Chain, key: KeyMaterial<'_>, address: &str) -> Result<String> {
+    let spec = chain.spec()?;
+    let public_key = match key {
+        KeyMaterial::PrivateKey(private_key) => spec.public_key(private_key)?,
+        KeyMaterial::PublicKey(public_key) => public_key.to_vec(),
+    };
+    let derived = spec.format_address(&public_key)?;
+    let address = address.trim();
+
+    // Base58 주소는 대소문자가 데이터의 일부
+    let case_sensitive = matches!(chain, Chain::Solana | Chain::Substrate | Chain::Custom(_));
+    let same = |candidate: &str| if case_sensitive { candidate == address } else { candidate.eq_ignore_ascii_case(address) };
+
+    if same(&derived) {
+        return Ok(derived);
+    }
+    if chain == Chain::Bitcoin {
+        if let Some(other) = bitcoin_addresses(&public_key)?.into_iter().find(|candidate| candidate == address) {
+            return Ok(other);
+        }
+    }
+    Err(Error::AddressMismatch { expected: address.to_string(), derived })
+}
+
+/// P2WPKH 외의 같은 키 Bitcoin 메인넷 주소 (P2PKH, P2TR 키 경로)
+fn bitcoin_addresses(public_key: &[u8]) -> Result<[String; 2]> {
+    let public_key: [u8; 33] = secp256k1::PublicKey::from_slice(public_key)
+        .map_err(|_| Error::InvalidKey("유효하지 않은 secp256k1 공개키".to_string()))?
+        .serialize();
+    let legacy = base58check::encode(&[Network::Mainnet.p2pkh_version()], &hash160(&public_key));
+    let taproot = bitcoin::encode_segwit_address(Network::Mainnet, 1, &TaprootOutputKey::from_public_key(&public_key, None)?.output_key);
+    Ok([legacy, taproot.to_lowercase()])
+}
+
+/// 체인 주소 형식 검사
+///
+/// 앞뒤 공백은 허용하지 않음 (붙여넣은 값은 호출자가 `trim()`)
+pub fn validate_address(chain: Chain, address: &str) -> Result<(), AddressError> {
+    if address.is_empty() {
+        return Err(AddressError::Empty);
+    }
+
+    match chain {
+        Chain::Bitcoin => validate_bitcoin(address),
+        Chain::Evm => validate_evm(address),
+        Chain::Solana => validate_solana(address),
+        Chain::Sui => validate_sui(address),
+        Chain::Cosmos(cosmos) => validate_cosmos(address, cosmos.hrp()),
+        Chain::Substrate => validate_ss58(address),
+        Chain::Custom(id) => Err(AddressError::UnsupportedChain(id.to_string())),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 체인별 검사
+// ═══════════════════════════════════════════════════════════════
+
+/// Bitcoin 메인넷: Base58Check (1..., 3...) 또는 SegWit (bc1...)
+fn validate_bitcoin(address: &str) -> Result<(), AddressError> {
+    validate_bitcoin_address(address, Network::Mainnet)
+}
+
+/// 네트워크를 지정한 Bitcoin 주소 검증 (테스트넷/Signet `tb1...`, `m...`, `n...`, `2...`, Regtest `bcrt1...`)
+pub fn validate_bitcoin_address(address: &str, network: Network) -> Result<(), AddressError> {
+    if address.is_empty() {
+        return Err(AddressError::Empty);
+    }
+
+    // Base58Check 주소는 버전 바이트 때문에 숫자나 m/n으로 시작, Bech32는 HRP(문자)로 시작
+    if address.starts_with(|c: char| c.is_ascii_digit() || c == 'm' || c == 'n') {
+        return validate_base58check(address, network);
+    }
+
+    let unchecked = UncheckedHrpstring::new(address).map_err(|e| encoding_error("Bech32", e))?;
+    let hrp = unchecked.hrp().to_lowercase();
+    if hrp != network.bech32_hrp() {
+        return Err(AddressError::WrongPrefix { expected: network.bech32_hrp().to_string(), actual: hrp });
+    }
+
+    // witness 버전 0은 Bech32, 1 이상은 Bech32m 체크섬 (BIP-350)
+    let checksum_ok = match unchecked.witness_version().map(|version| version.to_u8()) {
+        Some(0) => unchecked.has_valid_checksum::<Bech32>(),
+        Some(_) => unchecked.has_valid_checksum::<Bech32m>(),
+        None => false,
+    };
+    if !checksum_ok {
+        return Err(AddressError::InvalidChecksum);
+    }
+
+    let segwit = SegwitHrpstring::new(address).map_err(|e| encoding_error("SegWit", e))?;
+    let program_len = segwit.byte_iter().len();
+    match (segwit.witness_version().to_u8(), program_len) {
+        (0, 20) | (0, 32) => Ok(()),
+        (0, actual) => Err(AddressError::InvalidLength { expected: 20, actual }),
+        (1, 32) => Ok(()),
+        (1, actual) => Err(AddressError::InvalidLength { expected: 32, actual }),
+        // 아직 정의되지 않은 witness 버전 (BIP-350: 2~40바이트)
+        _ => Ok(()),
+    }
+}
+
+/// Base58Check P2PKH (메인넷 0x00) / P2SH (메인넷 0x05)
+fn validate_base58check(address: &str, network: Network) -> Result<(), AddressError> {
+    let data = bs58::decode(address).into_vec().map_err(|e| encoding_error("Base58", e))?;
+    if data.len() != 25 {
+        return Err(AddressError::InvalidLength { expected: 25, actual: data.len() });
+    }
+    if sha256d(&data[..21])[..4] != data[21..] {
+        return Err(AddressError::InvalidChecksum);
+    }
+    match data[0] {
+        version if version == network.p2pkh_version() || version == network.p2sh_version() => Ok(()),
+        version => Err(AddressError::WrongPrefix {
+            expected: format!("0x{:02x} 또는 0x{:02x}", network.p2pkh_version(), network.p2sh_version()),
+            actual: format!("0x{:02x}", version),
+        }),
+    }
+}
+
+/// 0x + 40자리 hex, 대소문자가 섞였으면 EIP-55 체크섬 검사
+fn validate_evm(address: &str) -> Result<(), AddressError> {
+    let bytes = decode_0x_hex(address, 20)?;
+    let digits = &address[2..];
+
+    let single_case = !digits.bytes().any(|b| b.is_ascii_lowercase()) || !digits.bytes().any(|b| b.is_ascii_uppercase());
+    if single_case || to_checksum_address(&bytes.try_into().expect("20바이트"))[2..] == *digits {
+        Ok(())
+    } else {
+        Err(AddressError::InvalidCapitalization)
+    }
+}
+
+/// 0x + 64자리 hex
+fn validate_sui(address: &str) -> Result<(), AddressError> {
+    decode_0x_hex(address, 32).map(|_| ())
+}
+
+/// Base58 32바이트 공개키 (체크섬 없음)
+fn validate_solana(address: &str) -> Result<(), AddressError> {
+    let data = bs58::decode(address).into_vec().map_err(|e| encoding_error("Base58", e))?;
+    if data.len() != 32 {
+        return Err(AddressError::InvalidLength { expected: 32, actual: data.len() });
+    }
+    Ok(())
+}
+
+/// Bech32 (체인 HRP, 20바이트 계정 또는 32바이트 모듈/컨트랙트 주소)
+fn validate_cosmos(address: &str, expected_hrp: &str) -> Result<(), AddressError> {
+    let unchecked = UncheckedHrpstring::new(address).map_err(|e| encoding_error("Bech32", e))?;
+    let hrp = unchecked.hrp().to_lowercase();
+    if hrp != expected_hrp {
+        return Err(AddressError::WrongPrefix { expected: expected_hrp.to_string(), actual: hrp });
+    }
+    if !unchecked.has_valid_checksum::<Bech32>() {
+        return Err(AddressError::InvalidChecksum);
+    }
+
+    let checked = CheckedHrpstring::new::<Bech32>(address).map_err(|e| encoding_error("Bech32", e))?;
+    match checked.byte_iter().len() {
+        20 | 32 => Ok(()),
+        actual => Err(AddressError::InvalidLength { expected: 20, actual }),
+    }
+}
+
+/// SS58: 접두사(1~2바이트) + 공개키 32바이트 + 체크섬 2바이트
+fn validate_ss58(address: &str) -> Result<(), AddressError> {
+    let data = bs58::decode(address).into_vec().map_err(|e| encoding_error("Base58", e))?;
+
+    let prefix_len = match data.first() {
+        Some(&first) if first < 64 => 1,
+        Some(&first) if first < 128 => 2,
+        Some(&first) => {
+            return Err(AddressError::WrongPrefix { expected: "SS58 접두사 (0~16383)".to_string(), actual: format!("0x{:02x}", first) })
+        }
+        None => return Err(AddressError::Empty),
+    };
+    if data.len() != prefix_len + 32 + 2 {
+        return Err(AddressError::InvalidLength { expected: prefix_len + 32 + 2, actual: data.len() });
+    }
+    if data[prefix_len + 32..] != ss58_checksum(&data[..prefix_len + 32])[..2] {
+        return Err(AddressError::InvalidChecksum);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// "0x" + hex → 바이트 (길이 검사)
+fn decode_0x_hex(address: &str, expected: usize) -> Result<Vec<u8>, AddressError> {
+    let digits = address.strip_prefix("0x").ok_or_else(|| AddressError::WrongPrefix {
+        expected: "0x".to_string(),
+        actual: address.chars().take(2).collect(),
+    })?;
+    if digits.len() % 2 == 1 {
+        return Err(AddressError::InvalidEncoding { encoding: "hex", message: "hex 자릿수가 홀수입니다".to_string() });
+    }
+    let bytes = hex::decode(digits).map_err(|e| encoding_error("hex", e))?;
+    if bytes.len() != expected {
+        return Err(AddressError::InvalidLength { expected, actual: bytes.len() });
+    }
+    Ok(bytes)
+}
+
+fn encoding_error(encoding: &'static str, e: impl fmt::Display) -> AddressError {
+    AddressError::InvalidEncoding { encoding, message: e.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::CosmosChain;
+
+    #[test]
+    fn test_valid_addresses() {
+        let cases = [
+            (Chain::Bitcoin, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"),
+            (Chain::Bitcoin, "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"),
+            (Chain::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"),
+            (Chain::Bitcoin, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+            (Chain::Evm, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"),
+            (Chain::Evm, "0x9858effd232b4033e47d90003d41ec34ecaeda94"),
+            (Chain::Solana, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"),
+            (Chain::Sui, "0x2e62960b9c4b1f7d85b8c0f95d7b6f7bc4a1a6c1a0a2b8e1f9c2d4e8a1b3c5d7"),
+            (Chain::Cosmos(CosmosChain::CosmosHub), "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"),
+            (Chain::Substrate, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+        ];
+        for (chain, address) in cases {
+            println!("{:<10} {}", chain.id(), address);
+            assert_eq!(validate_address(chain, address), Ok(()), "{}", address);
+        }
+    }
+
+    #[test]
+    fn test_bitcoin_errors() {
+        assert_eq!(validate_address(Chain::Bitcoin, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyv"), Err(AddressError::InvalidChecksum));
+        assert!(matches!(
+            validate_address(Chain::Bitcoin, "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"),
+            Err(AddressError::WrongPrefix { ref actual, .. }) if actual == "tb"
+        ));
+        assert!(matches!(
+            validate_address(Chain::Bitcoin, "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"),
+            Err(AddressError::WrongPrefix { .. })
+        ));
+        assert_eq!(validate_address(Chain::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"), Err(AddressError::InvalidChecksum));
+        assert!(matches!(validate_address(Chain::Bitcoin, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN0"), Err(AddressError::InvalidEncoding { .. })));
+
+        // 테스트 네트워크 주소는 네트워크를 지정해 검증
+        assert_eq!(validate_bitcoin_address("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", Network::Testnet), Ok(()));
+        assert_eq!(validate_bitcoin_address("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", Network::Signet), Ok(()));
+        assert_eq!(validate_bitcoin_address("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn", Network::Regtest), Ok(()));
+        assert!(matches!(
+            validate_bitcoin_address("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", Network::Regtest),
+            Err(AddressError::WrongPrefix { ref expected, .. }) if expected == "bcrt"
+        ));
+        assert!(matches!(
+            validate_bitcoin_address("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", Network::Testnet),
+            Err(AddressError::WrongPrefix { .. })
+        ));
+
+        // Taproot 주소를 Bech32(v0) 체크섬으로 만든 경우 (BIP-350 테스트 벡터)
+        assert_eq!(
+            validate_address(Chain::Bitcoin, "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"),
+            Err(AddressError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn test_evm_errors() {
+        assert_eq!(validate_address(Chain::Evm, "0x9858effD232B4033E47d90003D41EC34EcaEda94"), Err(AddressError::InvalidCapitalization));
+        assert_eq!(validate_address(Chain::Evm, "0x9858EfFD232B4033E47d90003D41EC34EcaEda"), Err(AddressError::InvalidLength { expected: 20, actual: 19 }));
+        assert!(matches!(validate_address(Chain::Evm, "9858EfFD232B4033E47d90003D41EC34EcaEda94"), Err(AddressError::WrongPrefix { .. })));
+        assert!(matches!(validate_address(Chain::Evm, "0xZZ58EfFD232B4033E47d90003D41EC34EcaEda94"), Err(AddressError::InvalidEncoding { .. })));
+    }
+
+    #[test]
+    fn test_other_chain_errors() {
+        assert_eq!(
+            validate_address(Chain::Solana, "1111111111111111111111111111111"),
+            Err(AddressError::InvalidLength { expected: 32, actual: 31 })
+        );
+        assert!(matches!(
+            validate_address(Chain::Cosmos(CosmosChain::Osmosis), "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"),
+            Err(AddressError::WrongPrefix { ref expected, .. }) if expected == "osmo"
+        ));
+        assert_eq!(
+            validate_address(Chain::Cosmos(CosmosChain::CosmosHub), "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal5"),
+            Err(AddressError::InvalidChecksum)
+        );
+        assert_eq!(
+            validate_address(Chain::Substrate, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"),
+            Err(AddressError::InvalidChecksum)
+        );
+        assert_eq!(validate_address(Chain::Sui, ""), Err(AddressError::Empty));
+        assert!(matches!(validate_address(Chain::Custom("near"), "alice.near"), Err(AddressError::UnsupportedChain(_))));
+    }
+
+    #[test]
+    fn test_verify_key_matches_address() {
+        use crate::bitcoin::BitcoinAccount;
+        use crate::evm::EvmAccount;
+        use crate::solana::SolanaAccount;
+
+        const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let evm = EvmAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let address = evm.address_checksummed();
+        assert_eq!(verify_key_matches_address(Chain::Evm, KeyMaterial::PrivateKey(&evm.private_key), &address).unwrap(), address);
+        assert!(verify_key_matches_address(Chain::Evm, KeyMaterial::PublicKey(&evm.public_key), &address.to_lowercase()).is_ok());
+
+        // 다른 index의 키 → 도출 주소를 담은 오류
+        let other = EvmAccount::from_mnemonic_with_index(MNEMONIC, "", 0, 0, 1).unwrap();
+        assert_eq!(
+            verify_key_matches_address(Chain::Evm, KeyMaterial::PrivateKey(&other.private_key), &address),
+            Err(Error::AddressMismatch { expected: address.clone(), derived: other.address_checksummed() })
+        );
+
+        // Bitcoin: 같은 키의 SegWit, Legacy, Taproot 모두 일치
+        let bitcoin = BitcoinAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        for address in [bitcoin.address(), bitcoin.address_legacy(Network::Mainnet), bitcoin.address_taproot(Network::Mainnet).unwrap()] {
+            assert_eq!(verify_key_matches_address(Chain::Bitcoin, KeyMaterial::PublicKey(&bitcoin.public_key), &address).unwrap(), address);
+        }
+        assert!(matches!(
+            verify_key_matches_address(Chain::Bitcoin, KeyMaterial::PublicKey(&bitcoin.public_key), &bitcoin.address_segwit(Network::Testnet)),
+            Err(Error::AddressMismatch { .. })
+        ));
+
+        // Base58은 대소문자 구분
+        let solana = SolanaAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        assert!(verify_key_matches_address(Chain::Solana, KeyMaterial::PrivateKey(&solana.private_key), &solana.address()).is_ok());
+        assert!(verify_key_matches_address(Chain::Solana, KeyMaterial::PrivateKey(&solana.private_key), &solana.address().to_lowercase()).is_err());
+
+        // 곡선이 맞지 않는 공개키
+        assert!(matches!(verify_key_matches_address(Chain::Sui, KeyMaterial::PublicKey(&evm.public_key), "0x00"), Err(Error::InvalidKey(_))));
+    }
+
+    #[test]
+    fn test_into_crate_error() {
+        let err: Error = validate_address(Chain::Evm, "0x1234").unwrap_err().into();
+        assert!(matches!(err, Error::InvalidAddress(_)));
+        println!("{}", err);
+    }
+}
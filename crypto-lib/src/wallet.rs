@@ -0,0 +1,786 @@
+//! Multi-Chain Wallet
+//!
+//! 니모닉 하나로 지원하는 모든 체인의 기본 계정을 한 번에 도출
+//!
+//! ## 동작
+//! 1. 니모닉 검증 (단어 수, 단어 목록, 체크섬)
+//! 2. BIP-39 시드를 한 번만 계산해 모든 BIP-32/SLIP-10 체인에서 재사용
+//! 3. Substrate는 시드 대신 엔트로피 기반 미니 시크릿 사용 (Polkadot.js/subkey 방식)
+//!
+//! 체인별 도출은 `chain` 레지스트리의 구현을 사용 → `Chain::Custom`으로 외부 등록 체인도 포함 가능
+//!
+//! 결과에는 주소와 공개키만 담음 (개인키 없음)
+//!
+//! ```text
+//! Bitcoin     m/84'/0'/0'/0/0     bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu
+//! EVM         m/44'/60'/0'/0/0    0x9858EfFD232B4033E47d90003D41EC34EcaEda94
+//! ...
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use zeroize::Zeroizing;
+
+use crate::account::Account;
+use crate::bip32::{master_key_from_seed, ChildNumber, DerivationPath, HARDENED_OFFSET};
+use crate::bitcoin;
+use crate::bip39::{mnemonic_to_seed, validate_mnemonic};
+use crate::chain::{self as registry, ChainAccount, SeedSource};
+use crate::cosmos::CosmosChain;
+use crate::network::Network;
+use crate::slip10::{Curve, KeyCache};
+use crate::wallet_preset::{self, WalletApp};
+use crate::error::{Error, Result};
+
+/// 지원 체인
+///
+/// 각 값은 `chain` 레지스트리의 체인 구현을 가리킴
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Chain {
+    /// Bitcoin (Native SegWit)
+    Bitcoin,
+    /// EVM (Ethereum, Polygon, BSC 등)
+    Evm,
+    /// Solana
+    Solana,
+    /// Sui (Ed25519)
+    Sui,
+    /// Cosmos SDK 체인
+    Cosmos(CosmosChain),
+    /// Substrate (Ed25519, 범용 SS58 접두사 42)
+    Substrate,
+    /// `chain::register`로 등록한 외부 체인 (레지스트리 id)
+    Custom(&'static str),
+}
+
+/// 기본으로 도출하는 체인 목록
+pub const DEFAULT_CHAINS: [Chain; 6] = [
+    Chain::Bitcoin,
+    Chain::Evm,
+    Chain::Solana,
+    Chain::Sui,
+    Chain::Cosmos(CosmosChain::CosmosHub),
+    Chain::Substrate,
+];
+
+impl Chain {
+    /// 레지스트리 id
+    pub fn id(&self) -> &'static str {
+        match self {
+            Chain::Bitcoin => "bitcoin",
+            Chain::Evm => "evm",
+            Chain::Solana => "solana",
+            Chain::Sui => "sui",
+            Chain::Cosmos(chain) => chain.id(),
+            Chain::Substrate => "substrate",
+            Chain::Custom(id) => id,
+        }
+    }
+
+    /// 레지스트리 id → 체인 (대소문자 무시, 등록되지 않은 id는 `None`)
+    ///
+    /// 내장 체인이 아니면 레지스트리에 등록된 경우에만 `Chain::Custom`으로 반환
+    pub fn from_id(id: &str) -> Option<Chain> {
+        let builtin = [Chain::Bitcoin, Chain::Evm, Chain::Solana, Chain::Sui, Chain::Substrate]
+            .into_iter()
+            .chain(CosmosChain::ALL.into_iter().map(Chain::Cosmos))
+            .find(|chain| chain.id().eq_ignore_ascii_case(id));
+        if builtin.is_some() {
+            return builtin;
+        }
+
+        let spec = registry::get(id)?;
+        Some(Chain::Custom(intern_id(spec.id())))
+    }
+
+    /// 레지스트리의 체인 구현
+    pub fn spec(&self) -> Result<Arc<dyn registry::Chain>> {
+        registry::get(self.id()).ok_or_else(|| Error::Chain(format!("등록되지 않은 체인입니다: {}", self.id())))
+    }
+
+    /// 표시용 이름 (등록되지 않은 체인은 id)
+    pub fn name(&self) -> String {
+        self.spec().map(|spec| spec.name().to_string()).unwrap_or_else(|_| self.id().to_string())
+    }
+
+    /// 기본 도출 경로 (Substrate는 Secret URI 경로, 기본값은 도출 없음 = 빈 문자열)
+    pub fn default_path(&self) -> Result<String> {
+        Ok(self.spec()?.default_path())
+    }
+}
+
+/// 외부 체인 id를 `&'static str`로 보관
+///
+/// 등록된 체인 id만 들어오므로 누수량은 레지스트리 크기로 제한됨
+fn intern_id(id: &str) -> &'static str {
+    static IDS: OnceLock<Mutex<Vec<&'static str>>> = OnceLock::new();
+
+    let mut ids = IDS.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(interned) = ids.iter().find(|interned| **interned == id) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(id.to_string().into_boxed_str());
+    ids.push(interned);
+    interned
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+impl FromStr for Chain {
+    type Err = Error;
+
+    /// 레지스트리 id 파싱 (`from_id`), Cosmos 체인은 표시 이름과 HRP도 허용
+    fn from_str(s: &str) -> Result<Self> {
+        Chain::from_id(s)
+            .or_else(|| s.parse().ok().map(Chain::Cosmos))
+            .ok_or_else(|| Error::Chain(format!("등록되지 않은 체인입니다: {}", s)))
+    }
+}
+
+/// 도출한 계정 정보 (개인키 없음)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountInfo {
+    /// 체인
+    pub chain: Chain,
+    /// 도출 경로
+    pub path: String,
+    /// 주소
+    pub address: String,
+    /// 공개키 hex (체인 고유 형식: EVM은 비압축 65바이트, secp256k1은 압축 33바이트, Ed25519는 32바이트)
+    pub public_key: String,
+}
+
+/// 니모닉 하나에서 도출한 여러 체인의 계정
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiChainWallet {
+    /// 체인별 계정 (요청한 순서)
+    pub accounts: Vec<AccountInfo>,
+}
+
+impl MultiChainWallet {
+    /// 기본 체인 전체의 계정 도출
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        Self::from_mnemonic_with_chains(mnemonic, passphrase, &DEFAULT_CHAINS)
+    }
+
+    /// 지정한 체인들의 계정 도출
+    ///
+    /// Substrate는 `passphrase`를 Secret URI 비밀번호(`///password`)로 사용
+    pub fn from_mnemonic_with_chains(mnemonic: &str, passphrase: &str, chains: &[Chain]) -> Result<Self> {
+        CachedWallet::from_mnemonic(mnemonic, passphrase)?.multi_chain(chains)
+    }
+
+    /// 특정 체인의 계정 정보
+    pub fn get(&self, chain: Chain) -> Option<&AccountInfo> {
+        self.accounts.iter().find(|account| account.chain == chain)
+    }
+}
+
+impl fmt::Display for MultiChainWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for account in &self.accounts {
+            writeln!(f, "{:<12}{:<20}{}", account.chain.name(), account.path, account.address)?;
+        }
+        Ok(())
+    }
+}
+
+/// 체인 하나의 기본 계정 도출
+fn derive_account_info(chain: Chain, source: &SeedSource<'_>, network: Network) -> Result<AccountInfo> {
+    let spec = chain.spec()?;
+    let path = spec.network_path(0, 0, network);
+    let account = spec.derive_network_account(source, &path, network)?;
+
+    Ok(AccountInfo {
+        chain,
+        path,
+        address: account.address,
+        public_key: hex::encode(&account.public_key),
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 캐시 지갑
+// ═══════════════════════════════════════════════════════════════
+
+/// 시드와 키 캐시를 보관하는 지갑
+///
+/// 개별 계정 생성자(`from_mnemonic_for_chain` 등)는 호출할 때마다 PBKDF2 시드(HMAC-SHA512 2048회)와 마스터 키를 다시 계산
+/// 한 니모닉에서 여러 체인/index를 도출할 때는 이 타입으로 비용을 한 번만 지불
+/// - 시드: 생성 시 한 번
+/// - 곡선별 마스터 키, 마지막 단계의 부모 키 (예: m/44'/60'/0'/0): 첫 도출 시 계산해 보관 (`slip10::KeyCache`)
+///
+/// 단일 계정 결과 타입인 `Wallet`과 구분
+///
+/// ```
+/// use crypto_lib::wallet::{CachedWallet, Chain};
+///
+/// let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+/// let wallet = CachedWallet::from_mnemonic(mnemonic, "").unwrap();
+///
+/// // 두 번째부터는 m/44'/60'/0'/0 에서 자식 도출 한 번
+/// let addresses: Vec<String> = (0..10).map(|i| wallet.derive(Chain::Evm, 0, i).unwrap().address).collect();
+/// assert_eq!(addresses[0], "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+/// ```
+#[derive(Clone)]
+pub struct CachedWallet {
+    mnemonic: Zeroizing<String>,
+    passphrase: Zeroizing<String>,
+    keys: KeyCache,
+    network: Network,
+}
+
+impl CachedWallet {
+    /// 니모닉 + 패스프레이즈로 생성 (니모닉 검증, 시드 계산)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        validate_mnemonic(mnemonic)?;
+        let keys = KeyCache::new(mnemonic_to_seed(mnemonic, passphrase));
+
+        Ok(CachedWallet {
+            mnemonic: Zeroizing::new(mnemonic.to_string()),
+            passphrase: Zeroizing::new(passphrase.to_string()),
+            keys,
+            network: Network::Mainnet,
+        })
+    }
+
+    /// 주소와 경로를 만들 네트워크 (기본값: 메인넷, `network` 모듈 참고)
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// 네트워크
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// 체인의 account/index 계정
+    pub fn derive(&self, chain: Chain, account: u32, index: u32) -> Result<ChainAccount> {
+        if account >= HARDENED_OFFSET || index >= HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("account/index는 2^31 미만이어야 합니다: {}/{}", account, index)));
+        }
+        let spec = chain.spec()?;
+        spec.derive_network_account(&self.source(), &spec.network_path(account, index, self.network), self.network)
+    }
+
+    /// 체인의 임의 경로 계정
+    pub fn derive_path(&self, chain: Chain, path: &str) -> Result<ChainAccount> {
+        chain.spec()?.derive_network_account(&self.source(), path, self.network)
+    }
+
+    /// 지갑 앱 관례의 계정 번호 계정 (`wallet_preset` 참고)
+    ///
+    /// 예: Ledger Live의 Ethereum 계정 2 → m/44'/60'/2'/0/0, MetaMask의 계정 2 → m/44'/60'/0'/0/2
+    pub fn derive_for_wallet(&self, wallet: WalletApp, chain: Chain, index: u32) -> Result<ChainAccount> {
+        if index >= HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("index는 2^31 미만이어야 합니다: {}", index)));
+        }
+        self.derive_path(chain, &wallet_preset::path_for_wallet(wallet, chain, index)?)
+    }
+
+    /// 체인의 계정 레벨 xpub (기본 경로의 앞 세 단계: Bitcoin m/84'/0'/account', EVM m/44'/60'/account')
+    ///
+    /// 결과는 체인별 watch-only 계정(`EvmWatchAccount::from_xpub` 등)에 그대로 넣어
+    /// `m/0/index` 주소를 도출할 수 있음 (`derive(chain, account, index)`와 같은 주소)
+    /// 모든 단계가 강화 도출인 Ed25519 체인은 xpub이 없으므로 에러
+    pub fn account_xpub(&self, chain: Chain, account: u32) -> Result<String> {
+        if account >= HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("account는 2^31 미만이어야 합니다: {}", account)));
+        }
+        let spec = chain.spec()?;
+        let path: DerivationPath = spec.network_path(account, 0, self.network).parse()?;
+        let (account_path, address_path) = path.as_slice().split_at(path.len().min(3));
+        if spec.curve() != Curve::Secp256k1 || address_path.iter().any(ChildNumber::is_hardened) {
+            return Err(Error::Chain(format!("{}는 계정 xpub으로 주소를 도출할 수 없습니다 (경로 {})", chain, path)));
+        }
+        self.account_xpub_at(account_path)
+    }
+
+    /// purpose/coin type을 지정한 계정 레벨 xpub (m/purpose'/coin'/account')
+    ///
+    /// 예: Bitcoin Taproot `(86, 0, 0)`, Legacy `(44, 0, 0)`, 테스트넷 `(84, 1, 0)`
+    /// coin type 1(Bitcoin 테스트넷)은 tpub, 그 외는 xpub
+    pub fn account_xpub_with_purpose(&self, purpose: u32, coin_type: u32, account: u32) -> Result<String> {
+        if purpose >= HARDENED_OFFSET || coin_type >= HARDENED_OFFSET || account >= HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("purpose/coin type/account는 2^31 미만이어야 합니다: {}/{}/{}", purpose, coin_type, account)));
+        }
+        self.account_xpub_at(&[ChildNumber::Hardened(purpose), ChildNumber::Hardened(coin_type), ChildNumber::Hardened(account)])
+    }
+
+    fn account_xpub_at(&self, path: &[ChildNumber]) -> Result<String> {
+        let network = match path.get(1) {
+            Some(ChildNumber::Hardened(1)) => bitcoin::Network::Testnet,
+            _ => bitcoin::Network::Mainnet,
+        };
+        let key = master_key_from_seed(self.keys.seed())?.derive_path(DerivationPath::from(path))?;
+        Ok(key.to_public().to_base58(network))
+    }
+
+    /// 여러 체인의 기본 계정
+    pub fn multi_chain(&self, chains: &[Chain]) -> Result<MultiChainWallet> {
+        let source = self.source();
+        let accounts = chains
+            .iter()
+            .map(|&chain| derive_account_info(chain, &source, self.network))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(MultiChainWallet { accounts })
+    }
+
+    /// 체인 구현에 넘기는 도출 입력 (키 캐시 포함)
+    pub fn source(&self) -> SeedSource<'_> {
+        SeedSource::with_keys(&self.mnemonic, &self.passphrase, &self.keys)
+    }
+}
+
+/// 니모닉, 패스프레이즈, 시드는 출력하지 않음
+impl fmt::Debug for CachedWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedWallet").field("keys", &self.keys).finish_non_exhaustive()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 단일 계정 빌더
+// ═══════════════════════════════════════════════════════════════
+
+/// 빌더로 도출한 단일 체인 계정
+#[derive(Debug, Clone)]
+pub struct Wallet {
+    /// 체인
+    pub chain: Chain,
+    /// 네트워크
+    pub network: Network,
+    /// BIP-44 account
+    pub account: u32,
+    /// 주소 index
+    pub index: u32,
+    /// 도출한 키와 주소
+    pub key: ChainAccount,
+}
+
+impl Wallet {
+    /// 빌더 생성
+    ///
+    /// ```
+    /// use crypto_lib::cosmos::CosmosChain;
+    /// use crypto_lib::wallet::{Chain, Wallet};
+    ///
+    /// let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    /// let wallet = Wallet::builder()
+    ///     .mnemonic(mnemonic)
+    ///     .passphrase("")
+    ///     .chain(Chain::Cosmos(CosmosChain::Osmosis))
+    ///     .account(2)
+    ///     .index(7)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(wallet.path(), "m/44'/118'/2'/0/7");
+    /// assert!(wallet.address().starts_with("osmo1"));
+    /// ```
+    pub fn builder() -> WalletBuilder {
+        WalletBuilder::default()
+    }
+
+    /// 주소
+    pub fn address(&self) -> &str {
+        &self.key.address
+    }
+
+    /// 도출 경로
+    pub fn path(&self) -> &str {
+        &self.key.path
+    }
+}
+
+impl Account for Wallet {
+    fn chain_id(&self) -> &str {
+        self.key.chain_id()
+    }
+
+    fn address(&self) -> String {
+        self.key.address.clone()
+    }
+
+    fn public_key_bytes(&self) -> &[u8] {
+        self.key.public_key_bytes()
+    }
+
+    fn private_key_bytes(&self) -> &[u8] {
+        self.key.private_key_bytes()
+    }
+}
+
+/// `Wallet` 빌더
+///
+/// - 입력은 설정할 때 바로 검증하고, 남아 있는 에러 중 먼저 생긴 것을 `build()`에서 반환
+///   (에러는 필드마다 보관, 같은 setter에 올바른 값을 다시 주면 지워짐)
+/// - BIP-39 시드는 첫 `build()`에서 한 번만 계산해 보관 → 체인/account/index만 바꿔 다시 `build()`해도 재사용
+///   (마스터/부모 키도 함께 캐시, 니모닉이나 패스프레이즈를 바꾸면 다시 계산)
+#[derive(Clone, Default)]
+pub struct WalletBuilder {
+    mnemonic: Option<Zeroizing<String>>,
+    passphrase: Zeroizing<String>,
+    chain: Option<Chain>,
+    network: Network,
+    account: u32,
+    index: u32,
+    errors: Vec<(Field, Error)>,
+    keys: OnceLock<KeyCache>,
+}
+
+/// 검증하는 `WalletBuilder` 필드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Mnemonic,
+    Chain,
+    Account,
+    Index,
+}
+
+impl WalletBuilder {
+    /// BIP-39 니모닉 (바로 검증)
+    pub fn mnemonic(mut self, mnemonic: &str) -> Self {
+        self.record(Field::Mnemonic, validate_mnemonic(mnemonic).map_err(Error::from));
+        self.mnemonic = Some(Zeroizing::new(mnemonic.to_string()));
+        self.keys = OnceLock::new();
+        self
+    }
+
+    /// BIP-39 패스프레이즈 (기본값: 빈 문자열)
+    pub fn passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = Zeroizing::new(passphrase.to_string());
+        self.keys = OnceLock::new();
+        self
+    }
+
+    /// 체인 (등록되지 않은 `Chain::Custom`이면 에러)
+    pub fn chain(mut self, chain: Chain) -> Self {
+        self.record(Field::Chain, chain.spec().map(|_| ()));
+        self.chain = Some(chain);
+        self
+    }
+
+    /// 네트워크 (기본값: 메인넷)
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// BIP-44 account (기본값: 0)
+    pub fn account(mut self, account: u32) -> Self {
+        let result = if account < HARDENED_OFFSET {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!("account는 2^31 미만이어야 합니다: {}", account)))
+        };
+        self.record(Field::Account, result);
+        self.account = account;
+        self
+    }
+
+    /// 주소 index (기본값: 0)
+    pub fn index(mut self, index: u32) -> Self {
+        let result = if index < HARDENED_OFFSET {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!("index는 2^31 미만이어야 합니다: {}", index)))
+        };
+        self.record(Field::Index, result);
+        self.index = index;
+        self
+    }
+
+    /// 계정 도출
+    pub fn build(&self) -> Result<Wallet> {
+        if let Some((_, e)) = self.errors.first() {
+            return Err(e.clone());
+        }
+        let mnemonic = self.mnemonic.as_deref().ok_or_else(|| Error::InvalidInput("니모닉이 설정되지 않았습니다".to_string()))?;
+        let chain = self.chain.ok_or_else(|| Error::InvalidInput("체인이 설정되지 않았습니다".to_string()))?;
+
+        let keys = self.keys.get_or_init(|| KeyCache::new(mnemonic_to_seed(mnemonic, &self.passphrase)));
+        let source = SeedSource::with_keys(mnemonic, &self.passphrase, keys);
+
+        let spec = chain.spec()?;
+        let path = spec.network_path(self.account, self.index, self.network);
+        let key = spec.derive_network_account(&source, &path, self.network)?;
+
+        Ok(Wallet { chain, network: self.network, account: self.account, index: self.index, key })
+    }
+
+    // 필드의 이전 검증 결과를 지우고, 실패했으면 뒤에 추가
+    fn record(&mut self, field: Field, result: Result<()>) {
+        self.errors.retain(|(f, _)| *f != field);
+        if let Err(e) = result {
+            self.errors.push((field, e));
+        }
+    }
+}
+
+/// 니모닉, 패스프레이즈, 시드는 출력하지 않음
+impl fmt::Debug for WalletBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalletBuilder")
+            .field("mnemonic", &self.mnemonic.as_ref().map(|_| "***"))
+            .field("chain", &self.chain)
+            .field("network", &self.network)
+            .field("account", &self.account)
+            .field("index", &self.index)
+            .field("errors", &self.errors)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::MnemonicError;
+    use crate::substrate::{SubstrateAccount, SS58_GENERIC};
+    use crate::sui::SuiAccount;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_from_mnemonic() {
+        let wallet = MultiChainWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        println!("{}", wallet);
+
+        assert_eq!(wallet.accounts.len(), DEFAULT_CHAINS.len());
+        assert_eq!(wallet.get(Chain::Bitcoin).unwrap().address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(wallet.get(Chain::Evm).unwrap().address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(wallet.get(Chain::Solana).unwrap().address, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+        assert_eq!(wallet.get(Chain::Cosmos(CosmosChain::CosmosHub)).unwrap().address, "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4");
+
+        // 개별 생성자와 같은 결과
+        assert_eq!(wallet.get(Chain::Sui).unwrap().address, SuiAccount::from_mnemonic(MNEMONIC, "").unwrap().address());
+        assert_eq!(
+            wallet.get(Chain::Substrate).unwrap().address,
+            SubstrateAccount::from_mnemonic(MNEMONIC, "").unwrap().address(SS58_GENERIC)
+        );
+    }
+
+    #[test]
+    fn test_cached_wallet() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let uncached = SeedSource::new(MNEMONIC, "", seed.as_slice());
+
+        for chain in DEFAULT_CHAINS {
+            let spec = chain.spec().unwrap();
+            for index in 0..3 {
+                let account = wallet.derive(chain, 1, index).unwrap();
+                let expected = spec.derive_account(&uncached, &spec.path(1, index)).unwrap();
+                println!("{:<10} {:<22} {}", chain.id(), account.path, account.address);
+                assert_eq!((account.path, account.address), (expected.path, expected.address));
+            }
+        }
+        println!("{:?}", wallet);
+
+        assert_eq!(wallet.multi_chain(&DEFAULT_CHAINS).unwrap(), MultiChainWallet::from_mnemonic(MNEMONIC, "").unwrap());
+        assert_eq!(wallet.derive_path(Chain::Evm, "m/44'/60'/0'/0/0").unwrap().address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert!(matches!(wallet.derive(Chain::Evm, HARDENED_OFFSET, 0), Err(Error::InvalidInput(_))));
+        assert!(CachedWallet::from_mnemonic("abandon", "").is_err());
+    }
+
+    #[test]
+    fn test_network() {
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+        assert_eq!(wallet.network(), Network::Mainnet);
+
+        // Bitcoin만 경로(coin type 1)와 HRP가 바뀌고, 나머지 체인은 메인넷과 같음
+        let testnet = wallet.clone().with_network(Network::Testnet);
+        assert_eq!(testnet.derive(Chain::Bitcoin, 0, 0).unwrap().address, "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl");
+        assert!(wallet.clone().with_network(Network::Devnet).derive(Chain::Bitcoin, 0, 0).unwrap().address.starts_with("bcrt1q"));
+        for chain in [Chain::Evm, Chain::Solana, Chain::Sui, Chain::Cosmos(CosmosChain::CosmosHub), Chain::Substrate] {
+            assert_eq!(testnet.derive(chain, 0, 1).unwrap().address, wallet.derive(chain, 0, 1).unwrap().address);
+        }
+
+        let multi = testnet.multi_chain(&[Chain::Bitcoin]).unwrap();
+        assert_eq!(multi.get(Chain::Bitcoin).unwrap().path, "m/84'/1'/0'/0/0");
+        assert!(testnet.account_xpub(Chain::Bitcoin, 0).unwrap().starts_with("tpub"));
+
+        let built = Wallet::builder().mnemonic(MNEMONIC).chain(Chain::Bitcoin).network(Network::Testnet).build().unwrap();
+        assert_eq!(built.network, Network::Testnet);
+        assert_eq!(built.address(), "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl");
+    }
+
+    #[test]
+    fn test_account_xpub() {
+        use crate::bitcoin::BitcoinWatchAccount;
+        use crate::cosmos::CosmosWatchAccount;
+        use crate::evm::EvmWatchAccount;
+
+        let wallet = CachedWallet::from_mnemonic(MNEMONIC, "").unwrap();
+
+        // BIP-84 계정 0 (zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs의 xpub 표기)
+        let xpub = wallet.account_xpub(Chain::Bitcoin, 0).unwrap();
+        assert_eq!(xpub, "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V");
+        assert_eq!(wallet.account_xpub_with_purpose(84, 0, 0).unwrap(), xpub);
+
+        // watch-only 계정과 개인키 도출이 같은 주소
+        let watch = BitcoinWatchAccount::from_xpub(&xpub).unwrap();
+        let evm = EvmWatchAccount::from_xpub(&wallet.account_xpub(Chain::Evm, 1).unwrap()).unwrap();
+        let cosmos = CosmosWatchAccount::from_xpub(&wallet.account_xpub(Chain::Cosmos(CosmosChain::CosmosHub), 0).unwrap()).unwrap();
+        for index in 0..3 {
+            assert_eq!(watch.address(index).unwrap(), wallet.derive(Chain::Bitcoin, 0, index).unwrap().address);
+            assert_eq!(evm.address(index).unwrap(), wallet.derive(Chain::Evm, 1, index).unwrap().address);
+            assert_eq!(cosmos.address(index).unwrap(), wallet.derive(Chain::Cosmos(CosmosChain::CosmosHub), 0, index).unwrap().address);
+        }
+
+        // BIP-86 테스트 벡터
+        let taproot = wallet.account_xpub_with_purpose(86, 0, 0).unwrap();
+        assert_eq!(taproot, "xpub6BgBgsespWvERF3LHQu6CnqdvfEvtMcQjYrcRzx53QJjSxarj2afYWcLteoGVky7D3UKDP9QyrLprQ3VCECoY49yfdDEHGCtMMj92pReUsQ");
+        assert_eq!(
+            BitcoinWatchAccount::from_xpub(&taproot).unwrap().address_taproot_at("m/0/0").unwrap(),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+
+        // 테스트넷 coin type → tpub
+        assert!(wallet.account_xpub_with_purpose(84, 1, 0).unwrap().starts_with("tpub"));
+
+        // Ed25519 체인은 계정 xpub 없음
+        assert!(matches!(wallet.account_xpub(Chain::Solana, 0), Err(Error::Chain(_))));
+        assert!(matches!(wallet.account_xpub(Chain::Sui, 0), Err(Error::Chain(_))));
+        assert!(wallet.account_xpub(Chain::Evm, HARDENED_OFFSET).is_err());
+    }
+
+    #[test]
+    fn test_selected_chains() {
+        let chains = [Chain::Cosmos(CosmosChain::Osmosis), Chain::Cosmos(CosmosChain::Terra)];
+        let wallet = MultiChainWallet::from_mnemonic_with_chains(MNEMONIC, "", &chains).unwrap();
+
+        assert_eq!(wallet.accounts.len(), 2);
+        assert!(wallet.accounts[0].address.starts_with("osmo1"));
+        assert_eq!(wallet.accounts[1].path, "m/44'/330'/0'/0/0");
+        assert!(wallet.get(Chain::Bitcoin).is_none());
+    }
+
+    #[test]
+    fn test_custom_chain() {
+        let err = MultiChainWallet::from_mnemonic_with_chains(MNEMONIC, "", &[Chain::Custom("wallet-test-chain")]).unwrap_err();
+        assert!(matches!(err, Error::Chain(_)));
+
+        struct TestChain;
+        impl registry::Chain for TestChain {
+            fn id(&self) -> &str {
+                "wallet-test-chain"
+            }
+            fn name(&self) -> &str {
+                "Test"
+            }
+            fn coin_type(&self) -> u32 {
+                1
+            }
+            fn curve(&self) -> crate::slip10::Curve {
+                crate::slip10::Curve::Secp256k1
+            }
+            fn format_address(&self, public_key: &[u8]) -> Result<String> {
+                Ok(format!("test:{}", hex::encode(public_key)))
+            }
+        }
+        registry::register(TestChain).unwrap();
+
+        let wallet = MultiChainWallet::from_mnemonic_with_chains(MNEMONIC, "", &[Chain::Custom("wallet-test-chain")]).unwrap();
+        let account = &wallet.accounts[0];
+        assert_eq!(account.path, "m/44'/1'/0'/0/0");
+        assert_eq!(account.address, format!("test:{}", account.public_key));
+        assert_eq!(Chain::Custom("wallet-test-chain").name(), "Test");
+        assert_eq!("Wallet-Test-Chain".parse::<Chain>(), Ok(Chain::Custom("wallet-test-chain")));
+    }
+
+    #[test]
+    fn test_parse_chain() {
+        for chain in DEFAULT_CHAINS {
+            assert_eq!(chain.id().parse::<Chain>(), Ok(chain));
+        }
+        assert_eq!("Osmosis".parse::<Chain>(), Ok(Chain::Cosmos(CosmosChain::Osmosis)));
+        assert_eq!("Cosmos Hub".parse::<Chain>(), Ok(Chain::Cosmos(CosmosChain::CosmosHub)));
+        assert_eq!("dogecoin".parse::<Chain>(), Err(Error::Chain("등록되지 않은 체인입니다: dogecoin".to_string())));
+    }
+
+    #[test]
+    fn test_builder() {
+        let builder = Wallet::builder()
+            .mnemonic(MNEMONIC)
+            .passphrase("")
+            .chain(Chain::Cosmos(CosmosChain::Osmosis))
+            .account(2)
+            .index(7);
+        println!("{:?}", builder);
+        assert!(!format!("{:?}", builder).contains("abandon"));
+
+        let wallet = builder.build().unwrap();
+        println!("{} {}", wallet.path(), wallet.address());
+        assert_eq!(wallet.path(), "m/44'/118'/2'/0/7");
+        assert_eq!(wallet.chain_id(), "osmosis");
+
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let expected = crate::cosmos::CosmosAccount::from_seed_with_path(seed.as_slice(), "m/44'/118'/2'/0/7").unwrap();
+        assert_eq!(wallet.address(), expected.address_for_chain(CosmosChain::Osmosis));
+
+        let default = Wallet::builder().mnemonic(MNEMONIC).chain(Chain::Evm).build().unwrap();
+        assert_eq!(default.address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    }
+
+    #[test]
+    fn test_builder_reuses_seed() {
+        let builder = Wallet::builder().mnemonic(MNEMONIC).chain(Chain::Bitcoin);
+        assert!(builder.keys.get().is_none());
+
+        let first = builder.build().unwrap();
+        let seed = builder.keys.get().unwrap().seed().to_vec();
+
+        // 체인/index만 바꾸면 시드와 키 캐시 유지
+        let builder = builder.chain(Chain::Solana).index(1);
+        assert_eq!(builder.keys.get().map(|keys| keys.seed()), Some(seed.as_slice()));
+        let second = builder.build().unwrap();
+        assert_ne!(first.address(), second.address());
+        assert_eq!(second.path(), "m/44'/501'/0'/1'");
+
+        // 패스프레이즈를 바꾸면 다시 계산
+        let builder = builder.passphrase("TREZOR");
+        assert!(builder.keys.get().is_none());
+        assert_ne!(builder.build().unwrap().address(), second.address());
+    }
+
+    #[test]
+    fn test_builder_validates_eagerly() {
+        let err = Wallet::builder().mnemonic("abandon abandon abandon").chain(Chain::Evm).build().unwrap_err();
+        println!("{}", err);
+        assert!(matches!(err, Error::InvalidMnemonic(MnemonicError::InvalidWordCount(3))));
+
+        // 먼저 생긴 에러를 반환
+        let builder = Wallet::builder().index(HARDENED_OFFSET).mnemonic("abandon abandon abandon");
+        assert!(builder.build().unwrap_err().to_string().contains("index"));
+
+        // 같은 필드를 다시 올바르게 설정하면 그 에러는 지워짐
+        let builder = builder.index(0);
+        assert!(matches!(builder.build(), Err(Error::InvalidMnemonic(_))));
+        let builder = builder.mnemonic(MNEMONIC).chain(Chain::Custom("no-such-chain")).chain(Chain::Evm);
+        assert_eq!(builder.build().unwrap().address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        // 다른 필드의 에러는 남음
+        let builder = builder.account(HARDENED_OFFSET).index(HARDENED_OFFSET).index(1);
+        assert!(builder.build().unwrap_err().to_string().contains("account"));
+
+        assert!(Wallet::builder().chain(Chain::Custom("no-such-chain")).mnemonic(MNEMONIC).build().is_err());
+        assert!(Wallet::builder().chain(Chain::Evm).build().unwrap_err().to_string().contains("니모닉"));
+        assert!(Wallet::builder().mnemonic(MNEMONIC).build().unwrap_err().to_string().contains("체인"));
+    }
+
+    #[test]
+    fn test_invalid_mnemonic() {
+        assert!(MultiChainWallet::from_mnemonic("abandon abandon abandon", "").is_err());
+        assert!(MultiChainWallet::from_mnemonic(&MNEMONIC.replace("about", "abandon"), "").is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Wallet Presets
+//!
+//! 지갑 앱마다 다른 도출 경로 관례를 (지갑, 체인) → (경로, 주소 알고리즘)으로 정리한 레지스트리
+//! "지갑 X에서 보이는 주소와 다르다" 같은 복구 문의에서 어떤 경로를 써야 하는지 바로 찾기 위한 용도
+//!
+//! ## 경로 관례
+//! 같은 "계정 N"이라도 지갑마다 증가시키는 단계가 다름
+//!
+//! | 지갑 | EVM | Solana | Cosmos | Bitcoin | Sui |
+//! |------|-----|--------|--------|---------|-----|
+//! | MetaMask | m/44'/60'/0'/0/{index} | - | - | - | - |
+//! | Keplr | - | - | m/44'/{coin}'/0'/0/{index} | - | - |
+//! | Phantom | m/44'/60'/0'/0/{index} | m/44'/501'/{index}'/0' | - | - | m/44'/784'/{index}'/0'/0' |
+//! | Ledger Live | m/44'/60'/{index}'/0/0 | m/44'/501'/{index}' | m/44'/{coin}'/{index}'/0/0 | m/84'/0'/{index}'/0/0 | m/44'/784'/{index}'/0'/0' |
+//! | Trust Wallet | m/44'/60'/0'/0/{index} | m/44'/501'/{index}' | m/44'/{coin}'/0'/0/{index} | m/84'/0'/0'/0/{index} | m/44'/784'/{index}'/0'/0' |
+//! | Sui Wallet | - | - | - | - | m/44'/784'/{index}'/0'/0' |
+//!
+//! `{coin}`은 Cosmos 체인별 coin type (Cosmos Hub 118, Terra 330, ...)
+//! 주소는 각 체인 구현(`chain` 레지스트리)으로 계산
+//!
+//! ```
+//! use crypto_lib::wallet::{CachedWallet, Chain};
+//! use crypto_lib::wallet_preset::WalletApp;
+//!
+//! let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+//! let wallet = CachedWallet::from_mnemonic(mnemonic, "").unwrap();
+//!
+//! // Ledger Live의 두 번째 Ethereum 계정
+//! let account = wallet.derive_for_wallet(WalletApp::LedgerLive, Chain::Evm, 1).unwrap();
+//! assert_eq!(account.path, "m/44'/60'/1'/0/0");
+//! ```
+
+use std::fmt;
+
+use crate::wallet::Chain;
+use crate::error::{Error, Result};
+
+/// 지갑 앱
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalletApp {
+    /// Keplr (Cosmos 계열)
+    Keplr,
+    /// MetaMask (EVM)
+    MetaMask,
+    /// Phantom (Solana, EVM, Sui)
+    Phantom,
+    /// Ledger Live (하드웨어 지갑 기본 앱)
+    LedgerLive,
+    /// Trust Wallet
+    TrustWallet,
+    /// Sui Wallet (Slush)
+    SuiWallet,
+}
+
+impl WalletApp {
+    /// 모든 지갑
+    pub const ALL: [WalletApp; 6] = [
+        WalletApp::Keplr,
+        WalletApp::MetaMask,
+        WalletApp::Phantom,
+        WalletApp::LedgerLive,
+        WalletApp::TrustWallet,
+        WalletApp::SuiWallet,
+    ];
+
+    /// 식별자 (소문자)
+    pub fn id(&self) -> &'static str {
+        match self {
+            WalletApp::Keplr => "keplr",
+            WalletApp::MetaMask => "metamask",
+            WalletApp::Phantom => "phantom",
+            WalletApp::LedgerLive => "ledger-live",
+            WalletApp::TrustWallet => "trust",
+            WalletApp::SuiWallet => "sui-wallet",
+        }
+    }
+
+    /// 표시 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            WalletApp::Keplr => "Keplr",
+            WalletApp::MetaMask => "MetaMask",
+            WalletApp::Phantom => "Phantom",
+            WalletApp::LedgerLive => "Ledger Live",
+            WalletApp::TrustWallet => "Trust Wallet",
+            WalletApp::SuiWallet => "Sui Wallet",
+        }
+    }
+
+    /// 식별자로 찾기 (대소문자 무시)
+    pub fn from_id(id: &str) -> Option<WalletApp> {
+        WalletApp::ALL.into_iter().find(|wallet| wallet.id().eq_ignore_ascii_case(id))
+    }
+}
+
+impl fmt::Display for WalletApp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 프리셋이 적용되는 체인 계열
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChainFamily {
+    /// Bitcoin 메인넷
+    Bitcoin,
+    /// EVM
+    Evm,
+    /// Solana
+    Solana,
+    /// Sui
+    Sui,
+    /// Cosmos SDK 체인 전체 (coin type은 체인별)
+    Cosmos,
+}
+
+impl ChainFamily {
+    /// 체인의 계열 (Substrate, 외부 체인은 None)
+    pub fn of(chain: Chain) -> Option<ChainFamily> {
+        match chain {
+            Chain::Bitcoin => Some(ChainFamily::Bitcoin),
+            Chain::Evm => Some(ChainFamily::Evm),
+            Chain::Solana => Some(ChainFamily::Solana),
+            Chain::Sui => Some(ChainFamily::Sui),
+            Chain::Cosmos(_) => Some(ChainFamily::Cosmos),
+            Chain::Substrate | Chain::Custom(_) => None,
+        }
+    }
+
+    /// 주소 알고리즘
+    pub fn address_algorithm(&self) -> AddressAlgorithm {
+        match self {
+            ChainFamily::Bitcoin => AddressAlgorithm::BitcoinP2wpkh,
+            ChainFamily::Evm => AddressAlgorithm::EvmKeccak,
+            ChainFamily::Solana => AddressAlgorithm::SolanaBase58,
+            ChainFamily::Sui => AddressAlgorithm::SuiBlake2b,
+            ChainFamily::Cosmos => AddressAlgorithm::CosmosBech32,
+        }
+    }
+}
+
+/// 공개키 → 주소 알고리즘 (복구 문의 응대용 설명)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressAlgorithm {
+    /// secp256k1, Keccak-256(비압축 공개키) 마지막 20바이트, EIP-55 체크섬
+    EvmKeccak,
+    /// secp256k1, Bech32(HRP, RIPEMD160(SHA256(압축 공개키)))
+    CosmosBech32,
+    /// secp256k1, P2WPKH Bech32 (bc1q...)
+    BitcoinP2wpkh,
+    /// Ed25519, 공개키 Base58
+    SolanaBase58,
+    /// Ed25519, BLAKE2b-256(0x00 || 공개키) hex
+    SuiBlake2b,
+}
+
+impl AddressAlgorithm {
+    /// 한 줄 설명
+    pub fn description(&self) -> &'static str {
+        match self {
+            AddressAlgorithm::EvmKeccak => "secp256k1, Keccak-256(비압축 공개키) 마지막 20바이트, EIP-55",
+            AddressAlgorithm::CosmosBech32 => "secp256k1, Bech32(RIPEMD160(SHA256(압축 공개키)))",
+            AddressAlgorithm::BitcoinP2wpkh => "secp256k1, P2WPKH Bech32 (bc1q)",
+            AddressAlgorithm::SolanaBase58 => "Ed25519, 공개키 Base58",
+            AddressAlgorithm::SuiBlake2b => "Ed25519, BLAKE2b-256(0x00 || 공개키)",
+        }
+    }
+}
+
+/// 지갑 + 체인 계열의 도출 관례
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalletPreset {
+    /// 지갑
+    pub wallet: WalletApp,
+    /// 체인 계열
+    pub family: ChainFamily,
+    /// 경로 템플릿 (`{index}`: 지갑의 계정 번호, `{coin}`: 체인 coin type)
+    pub path: &'static str,
+}
+
+impl WalletPreset {
+    /// 체인과 계정 번호의 도출 경로
+    pub fn path_for(&self, chain: Chain, index: u32) -> Result<String> {
+        if ChainFamily::of(chain) != Some(self.family) {
+            return Err(Error::Chain(format!("{} 프리셋은 {} 체인에 쓸 수 없습니다", self.wallet, chain.id())));
+        }
+        let coin_type = chain.spec()?.coin_type();
+        Ok(self.path.replace("{coin}", &coin_type.to_string()).replace("{index}", &index.to_string()))
+    }
+
+    /// 주소 알고리즘
+    pub fn address_algorithm(&self) -> AddressAlgorithm {
+        self.family.address_algorithm()
+    }
+}
+
+/// 알려진 지갑 관례
+pub const PRESETS: &[WalletPreset] = &[
+    WalletPreset { wallet: WalletApp::Keplr, family: ChainFamily::Cosmos, path: "m/44'/{coin}'/0'/0/{index}" },
+    WalletPreset { wallet: WalletApp::MetaMask, family: ChainFamily::Evm, path: "m/44'/60'/0'/0/{index}" },
+    WalletPreset { wallet: WalletApp::Phantom, family: ChainFamily::Evm, path: "m/44'/60'/0'/0/{index}" },
+    WalletPreset { wallet: WalletApp::Phantom, family: ChainFamily::Solana, path: "m/44'/501'/{index}'/0'" },
+    WalletPreset { wallet: WalletApp::Phantom, family: ChainFamily::Sui, path: "m/44'/784'/{index}'/0'/0'" },
+    WalletPreset { wallet: WalletApp::LedgerLive, family: ChainFamily::Bitcoin, path: "m/84'/0'/{index}'/0/0" },
+    WalletPreset { wallet: WalletApp::LedgerLive, family: ChainFamily::Evm, path: "m/44'/60'/{index}'/0/0" },
+    WalletPreset { wallet: WalletApp::LedgerLive, family: ChainFamily::Solana, path: "m/44'/501'/{index}'" },
+    WalletPreset { wallet: WalletApp::LedgerLive, family: ChainFamily::Cosmos, path: "m/44'/{coin}'/{index}'/0/0" },
+    WalletPreset { wallet: WalletApp::LedgerLive, family: ChainFamily::Sui, path: "m/44'/784'/{index}'/0'/0'" },
+    WalletPreset { wallet: WalletApp::TrustWallet, family: ChainFamily::Bitcoin, path: "m/84'/0'/0'/0/{index}" },
+    WalletPreset { wallet: WalletApp::TrustWallet, family: ChainFamily::Evm, path: "m/44'/60'/0'/0/{index}" },
+    WalletPreset { wallet: WalletApp::TrustWallet, family: ChainFamily::Solana, path: "m/44'/501'/{index}'" },
+    WalletPreset { wallet: WalletApp::TrustWallet, family: ChainFamily::Cosmos, path: "m/44'/{coin}'/0'/0/{index}" },
+    WalletPreset { wallet: WalletApp::TrustWallet, family: ChainFamily::Sui, path: "m/44'/784'/{index}'/0'/0'" },
+    WalletPreset { wallet: WalletApp::SuiWallet, family: ChainFamily::Sui, path: "m/44'/784'/{index}'/0'/0'" },
+];
+
+/// 지갑 + 체인의 프리셋
+pub fn find(wallet: WalletApp, chain: Chain) -> Option<&'static WalletPreset> {
+    let family = ChainFamily::of(chain)?;
+    PRESETS.iter().find(|preset| preset.wallet == wallet && preset.family == family)
+}
+
+/// 체인에 대한 모든 지갑 프리셋 (같은 주소를 여러 지갑 관례로 찾아볼 때)
+pub fn presets_for(chain: Chain) -> Vec<&'static WalletPreset> {
+    let family = ChainFamily::of(chain);
+    PRESETS.iter().filter(|preset| Some(preset.family) == family).collect()
+}
+
+/// 지갑 + 체인의 계정 번호 경로 (프리셋이 없으면 `Error::Chain`)
+pub fn path_for_wallet(wallet: WalletApp, chain: Chain, index: u32) -> Result<String> {
+    find(wallet, chain)
+        .ok_or_else(|| Error::Chain(format!("{}의 {} 도출 관례가 등록되어 있지 않습니다", wallet, chain.id())))?
+        .path_for(chain, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::CosmosChain;
+    use crate::wallet::CachedWallet;
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(path_for_wallet(WalletApp::MetaMask, Chain::Evm, 3).unwrap(), "m/44'/60'/0'/0/3");
+        assert_eq!(path_for_wallet(WalletApp::LedgerLive, Chain::Evm, 3).unwrap(), "m/44'/60'/3'/0/0");
+        assert_eq!(path_for_wallet(WalletApp::Phantom, Chain::Solana, 1).unwrap(), "m/44'/501'/1'/0'");
+        assert_eq!(path_for_wallet(WalletApp::TrustWallet, Chain::Solana, 1).unwrap(), "m/44'/501'/1'");
+        assert_eq!(path_for_wallet(WalletApp::Keplr, Chain::Cosmos(CosmosChain::Terra), 2).unwrap(), "m/44'/330'/0'/0/2");
+        assert_eq!(path_for_wallet(WalletApp::SuiWallet, Chain::Sui, 1).unwrap(), "m/44'/784'/1'/0'/0'");
+
+        assert!(matches!(path_for_wallet(WalletApp::MetaMask, Chain::Solana, 0), Err(Error::Chain(_))));
+        assert!(find(WalletApp::Keplr, Chain::Evm).is_none());
+        assert!(matches!(find(WalletApp::Keplr, Chain::Cosmos(CosmosChain::Osmosis)).unwrap().path_for(Chain::Evm, 0), Err(Error::Chain(_))));
+    }
+
+    #[test]
+    fn test_registry() {
+        // 지갑 + 계열 조합은 하나씩만
+        for (i, a) in PRESETS.iter().enumerate() {
+            assert!(PRESETS[i + 1..].iter().all(|b| (a.wallet, a.family) != (b.wallet, b.family)), "{:?}", a);
+        }
+
+        for preset in presets_for(Chain::Solana) {
+            println!("{:<12} {:<22} {}", preset.wallet, preset.path, preset.address_algorithm().description());
+        }
+        assert_eq!(presets_for(Chain::Solana).len(), 3);
+        assert!(presets_for(Chain::Substrate).is_empty());
+        assert_eq!(WalletApp::from_id("Ledger-Live"), Some(WalletApp::LedgerLive));
+    }
+
+    #[test]
+    fn test_derive_for_wallet() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet = CachedWallet::from_mnemonic(mnemonic, "").unwrap();
+
+        // 계정 0은 기본 경로와 같은 지갑이 많음
+        let metamask = wallet.derive_for_wallet(WalletApp::MetaMask, Chain::Evm, 0).unwrap();
+        let ledger = wallet.derive_for_wallet(WalletApp::LedgerLive, Chain::Evm, 0).unwrap();
+        assert_eq!(metamask.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(ledger.address, metamask.address);
+
+        // 계정 1부터 갈라짐
+        let metamask = wallet.derive_for_wallet(WalletApp::MetaMask, Chain::Evm, 1).unwrap();
+        let ledger = wallet.derive_for_wallet(WalletApp::LedgerLive, Chain::Evm, 1).unwrap();
+        println!("MetaMask    {} {}", metamask.path, metamask.address);
+        println!("Ledger Live {} {}", ledger.path, ledger.address);
+        assert_ne!(metamask.address, ledger.address);
+
+        let phantom = wallet.derive_for_wallet(WalletApp::Phantom, Chain::Solana, 0).unwrap();
+        assert_eq!(phantom.address, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+
+        assert!(matches!(wallet.derive_for_wallet(WalletApp::Keplr, Chain::Bitcoin, 0), Err(Error::Chain(_))));
+    }
+}
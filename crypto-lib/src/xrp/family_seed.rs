@@ -0,0 +1,161 @@
+//! XRP Family Seed (s...)
+//!
+//! rippled `wallet_propose`가 내보내는 16바이트 시드
+//!
+//! ```text
+//! 인코딩:  Base58Check(Ripple 알파벳, 버전 0x21, 16바이트)  → s... (29자)
+//! 패스프레이즈 시드:  SHA512Half(passphrase)[..16]
+//! ```
+//!
+//! ## 루트 키 도출 (secp256k1, rippled `generateKeyPair`)
+//! ```text
+//! private_gen = SHA512Half(seed || seq_be32)              seq = 0, 1, ... (유효한 스칼라가 나올 때까지)
+//! public_gen  = 압축 공개키(private_gen)
+//! tweak       = SHA512Half(public_gen || 0_be32 || sub_be32)  sub = 0, 1, ...
+//! 계정 개인키 = private_gen + tweak (mod n)
+//! ```
+//!
+//! Ed25519 시드 (sEd...)는 지원하지 않음 — 디코딩 시 명시적으로 거부
+
+use rand::RngCore;
+
+use super::sha512_half;
+use crate::ecdsa;
+use crate::encoding::base58check::{self, Alphabet};
+use crate::error::{Error, Result};
+use crate::schnorr::add_tweak_private_key;
+use crate::secret::SecretBytes;
+
+/// secp256k1 패밀리 시드 버전 바이트 (s...)
+const FAMILY_SEED_VERSION: u8 = 0x21;
+
+/// Ed25519 시드 버전 바이트 (sEd...)
+const ED25519_SEED_VERSION: [u8; 3] = [0x01, 0xE1, 0x4B];
+
+/// 16바이트 secp256k1 패밀리 시드
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FamilySeed(SecretBytes<16>);
+
+impl FamilySeed {
+    /// 16바이트 원본에서 생성
+    pub fn new(bytes: [u8; 16]) -> Self {
+        FamilySeed(SecretBytes::new(bytes))
+    }
+
+    /// 무작위 시드 생성
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let seed = FamilySeed::new(bytes);
+        zeroize::Zeroize::zeroize(&mut bytes);
+        seed
+    }
+
+    /// 패스프레이즈에서 생성 (rippled `wallet_propose <passphrase>`)
+    ///
+    /// 사람이 고른 문구는 추측하기 쉬우므로 테스트/레거시 복원 용도로만 사용
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        let hash = SecretBytes::new(sha512_half(passphrase.as_bytes()));
+        FamilySeed::new(hash[..16].try_into().expect("16바이트"))
+    }
+
+    /// s... 문자열 디코딩
+    pub fn decode(seed: &str) -> Result<Self> {
+        let data = base58check::decode_with_alphabet(seed, Alphabet::Ripple)
+            .map_err(|e| Error::InvalidKey(format!("유효하지 않은 XRP 시드: {}", e)))?;
+        if data.starts_with(&ED25519_SEED_VERSION) {
+            return Err(Error::InvalidKey("Ed25519 시드 (sEd...)는 지원하지 않습니다".to_string()));
+        }
+        match data.split_first() {
+            Some((&FAMILY_SEED_VERSION, bytes)) => Ok(FamilySeed(SecretBytes::from_slice(bytes)?)),
+            _ => Err(Error::InvalidKey("XRP 패밀리 시드가 아닙니다 (s...로 시작해야 함)".to_string())),
+        }
+    }
+
+    /// s... 문자열 인코딩
+    pub fn encode(&self) -> String {
+        base58check::encode_with_alphabet(&[FAMILY_SEED_VERSION], self.as_bytes(), Alphabet::Ripple)
+    }
+
+    /// 16바이트 원본
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// 루트 계정 개인키 (시퀀스 0)
+    pub fn root_private_key(&self) -> Result<SecretBytes<32>> {
+        let private_gen = first_valid_scalar(self.as_bytes())?;
+        let public_gen = ecdsa::public_key(&private_gen)?.serialize();
+
+        let mut prefix = [0u8; 37];
+        prefix[..33].copy_from_slice(&public_gen);
+        // prefix[33..37] = 계정 시퀀스 0
+        let tweak = first_valid_scalar(&prefix)?;
+
+        Ok(SecretBytes::new(add_tweak_private_key(&private_gen, &tweak)?))
+    }
+}
+
+/// SHA512Half(prefix || seq_be32)가 유효한 secp256k1 스칼라가 될 때까지 seq 증가
+fn first_valid_scalar(prefix: &[u8]) -> Result<SecretBytes<32>> {
+    let mut buf = Vec::with_capacity(prefix.len() + 4);
+    for seq in 0u32..=u32::MAX {
+        buf.clear();
+        buf.extend_from_slice(prefix);
+        buf.extend_from_slice(&seq.to_be_bytes());
+        let candidate = SecretBytes::new(sha512_half(&buf));
+        zeroize::Zeroize::zeroize(&mut buf);
+        if ecdsa::secret_key(&candidate).is_ok() {
+            return Ok(candidate);
+        }
+    }
+    Err(Error::InvalidKey("유효한 secp256k1 스칼라를 찾지 못했습니다".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xrp::XrpAccount;
+
+    #[test]
+    fn test_masterpassphrase() {
+        // rippled 제네시스 계정 (wallet_propose masterpassphrase)
+        let seed = FamilySeed::from_passphrase("masterpassphrase");
+        assert_eq!(hex::encode_upper(seed.as_bytes()), "DEDCE9CE67B451D852FD4E846FCDE31C");
+        assert_eq!(seed.encode(), "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+
+        let account = XrpAccount::from_family_seed(&seed).unwrap();
+        assert_eq!(account.address(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_eq!(account.public_key_hex(), "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020");
+        assert_eq!(account.private_key_hex(), "1acaaedece405b2a958212629e16f2eb46b153eee94cdd350fdeff52795525b7");
+
+        let imported = XrpAccount::from_family_seed_str("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(imported.address(), account.address());
+    }
+
+    #[test]
+    fn test_generate_roundtrip() {
+        let seed = FamilySeed::generate();
+        let encoded = seed.encode();
+        assert!(encoded.starts_with('s'));
+        assert_eq!(encoded.len(), 29);
+        assert_eq!(FamilySeed::decode(&encoded).unwrap(), seed);
+        assert_ne!(FamilySeed::generate(), seed);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        // 체크섬 오류
+        assert!(FamilySeed::decode("snoPBrXtMeMyMHUVTgbuqAfg1SUTc").is_err());
+        // 주소는 시드가 아님
+        assert!(FamilySeed::decode("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").is_err());
+
+        // Ed25519 시드는 명확한 메시지로 거부
+        let ed25519 = base58check::encode_with_alphabet(&ED25519_SEED_VERSION, &[7u8; 16], Alphabet::Ripple);
+        assert!(ed25519.starts_with("sEd"));
+        match FamilySeed::decode(&ed25519) {
+            Err(Error::InvalidKey(message)) => assert!(message.contains("Ed25519")),
+            other => panic!("Ed25519 시드 거부 실패: {:?}", other),
+        }
+    }
+}
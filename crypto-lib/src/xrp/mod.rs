@@ -0,0 +1,191 @@
+//! XRP Ledger Account
+//!
+//! - 타원곡선: secp256k1
+//! - 해시: SHA-256 + RIPEMD-160 (Account ID)
+//! - 주소 형식: Base58Check (Ripple 알파벳, 버전 0x00) → r...
+//! - BIP-44 경로: m/44'/144'/0'/0/0 (Ledger, Xaman 등 니모닉 지갑)
+//!
+//! ## 키를 얻는 두 가지 방법
+//! | 입력 | 도출 | 사용처 |
+//! |------|------|--------|
+//! | BIP-39 니모닉 | BIP-32 m/44'/144'/{account}'/0/{index} | 하드웨어 지갑, 최근 모바일 지갑 |
+//! | 패밀리 시드 (s...) | rippled 루트 키 도출 (`family_seed` 모듈) | rippled `wallet_propose`, 거래소/커스터디 |
+//!
+//! 두 방식 모두 같은 `XrpAccount`가 되므로 주소/서명 코드는 공유
+//! (패밀리 시드와 니모닉 사이에는 변환이 없음 — 같은 키를 표현하는 다른 형식이 아님)
+//!
+//! ## 하위 모듈
+//! - `family_seed`: 16바이트 시드의 s... 인코딩/디코딩, 루트 키 쌍 도출
+
+pub mod family_seed;
+
+use crate::bip32::{master_key_from_seed, DerivationPath, ExtendedPrivateKey, IntoDerivationPath};
+use crate::bip39::{mnemonic_to_seed, mnemonic_to_seed_checked};
+use crate::hash::hash160;
+use crate::ecdsa;
+use crate::encoding::base58check::{self, Alphabet};
+use crate::secret::SecretBytes;
+use crate::error::{Error, Result};
+
+pub use self::family_seed::FamilySeed;
+
+/// XRP Ledger 기본 도출 경로
+pub const XRP_PATH: &str = "m/44'/144'/0'/0/0";
+
+/// 클래식 주소 버전 바이트 (r...)
+const ACCOUNT_ID_VERSION: u8 = 0x00;
+
+/// XRP Ledger 계정 (secp256k1)
+#[derive(Clone)]
+pub struct XrpAccount {
+    /// 개인키 (32바이트)
+    pub private_key: SecretBytes<32>,
+    /// 공개키 (33바이트, 압축)
+    pub public_key: [u8; 33],
+    /// Account ID = RIPEMD-160(SHA-256(공개키))
+    pub account_id: [u8; 20],
+}
+
+impl XrpAccount {
+    /// 개인키에서 계정 생성
+    pub fn from_private_key(private_key: [u8; 32]) -> Result<Self> {
+        let public_key = ecdsa::public_key(&private_key)?.serialize();
+        Ok(XrpAccount {
+            private_key: SecretBytes::new(private_key),
+            public_key,
+            account_id: hash160(&public_key),
+        })
+    }
+
+    /// 확장 개인키에서 계정 생성
+    pub fn from_extended_key(extended_key: &ExtendedPrivateKey) -> Result<Self> {
+        Self::from_private_key(*extended_key.private_key)
+    }
+
+    /// 시드에서 특정 경로로 계정 생성 (BIP-32)
+    pub fn from_seed_with_path(seed: &[u8], path: impl IntoDerivationPath) -> Result<Self> {
+        Self::from_extended_key(&master_key_from_seed(seed)?.derive_path(path)?)
+    }
+
+    /// 니모닉에서 계정 생성 (m/44'/144'/0'/0/0)
+    ///
+    /// BIP-39 체크섬까지 검증 (틀리면 `Error::InvalidMnemonic`)
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), XRP_PATH)
+    }
+
+    /// BIP-39 검증 없이 XRP 계정 생성 (`XRP_PATH`)
+    pub fn from_mnemonic_unchecked(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        let seed = mnemonic_to_seed(mnemonic, passphrase);
+        Self::from_seed_with_path(seed.as_slice(), XRP_PATH)
+    }
+
+    /// 니모닉에서 계정 생성 (m/44'/144'/{account}'/{change}/{index})
+    ///
+    /// XRP 지갑은 보통 change = 0만 사용. 인덱스가 2^31 이상이면 `Error::InvalidPath`
+    pub fn from_mnemonic_with_index(mnemonic: &str, passphrase: &str, account: u32, change: u32, index: u32) -> Result<Self> {
+        let path = DerivationPath::checked_bip_purpose(44, 144, account, change, index)?;
+        let seed = mnemonic_to_seed_checked(mnemonic, passphrase)?;
+        Self::from_seed_with_path(seed.as_slice(), path)
+    }
+
+    /// 패밀리 시드의 루트 계정 (rippled `wallet_propose`와 같은 주소)
+    pub fn from_family_seed(seed: &FamilySeed) -> Result<Self> {
+        Self::from_private_key(*seed.root_private_key()?)
+    }
+
+    /// s... 문자열에서 루트 계정 생성
+    pub fn from_family_seed_str(seed: &str) -> Result<Self> {
+        Self::from_family_seed(&FamilySeed::decode(seed)?)
+    }
+
+    /// 클래식 주소 (r...)
+    pub fn address(&self) -> String {
+        encode_address(&self.account_id)
+    }
+
+    /// 공개키 hex (대문자, rippled 출력 형식)
+    pub fn public_key_hex(&self) -> String {
+        hex::encode_upper(self.public_key)
+    }
+
+    /// 개인키 hex
+    pub fn private_key_hex(&self) -> String {
+        hex::encode(self.private_key.as_slice())
+    }
+
+    /// 다이제스트에 ECDSA 서명 (r || s)
+    pub fn sign_ecdsa_compact(&self, digest: &[u8; 32]) -> Result<[u8; 64]> {
+        ecdsa::sign_compact(&self.private_key, digest)
+    }
+}
+
+/// Account ID → 클래식 주소 (r...)
+pub fn encode_address(account_id: &[u8; 20]) -> String {
+    base58check::encode_with_alphabet(&[ACCOUNT_ID_VERSION], account_id, Alphabet::Ripple)
+}
+
+/// 클래식 주소 (r...) → Account ID
+pub fn decode_address(address: &str) -> Result<[u8; 20]> {
+    let data = base58check::decode_with_alphabet(address, Alphabet::Ripple)
+        .map_err(|e| Error::InvalidAddress(format!("유효하지 않은 XRP 주소: {}", e)))?;
+    match data.split_first() {
+        Some((&ACCOUNT_ID_VERSION, account_id)) if account_id.len() == 20 => Ok(account_id.try_into().expect("20바이트")),
+        _ => Err(Error::InvalidAddress(format!("XRP 클래식 주소가 아닙니다: {}", address))),
+    }
+}
+
+/// SHA-512의 앞 32바이트 (rippled `sha512Half`)
+pub(crate) fn sha512_half(data: &[u8]) -> [u8; 32] {
+    use sha2::{Digest, Sha512};
+    Sha512::digest(data)[..32].try_into().expect("32바이트")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_from_mnemonic() {
+        let account = XrpAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        println!("XRP: {} {}", account.address(), account.public_key_hex());
+
+        // BIP-44 m/44'/144'/0'/0/0 테스트 벡터
+        assert_eq!(account.address(), "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+        assert_eq!(account.public_key_hex(), "031D68BC1A142E6766B2BDFB006CCFE135EF2E0E2E94ABB5CF5C9AB6104776FBAE");
+        assert_eq!(decode_address(&account.address()).unwrap(), account.account_id);
+
+        let seed = mnemonic_to_seed(MNEMONIC, "");
+        let second = XrpAccount::from_mnemonic_with_index(MNEMONIC, "", 0, 0, 1).unwrap();
+        assert_eq!(second.public_key, XrpAccount::from_seed_with_path(seed.as_slice(), "m/44'/144'/0'/0/1").unwrap().public_key);
+        assert_ne!(second.address(), account.address());
+    }
+
+    #[test]
+    fn test_address_encoding() {
+        // ACCOUNT_ZERO, ACCOUNT_ONE (XRP Ledger 특수 주소)
+        assert_eq!(encode_address(&[0; 20]), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+        let mut one = [0u8; 20];
+        one[19] = 1;
+        assert_eq!(encode_address(&one), "rrrrrrrrrrrrrrrrrrrrBZbvji");
+
+        assert!(decode_address("rrrrrrrrrrrrrrrrrrrrrhoLvTq").is_err());
+        // Bitcoin 알파벳 주소는 체크섬이 맞지 않음
+        assert!(decode_address("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").is_err());
+    }
+
+    #[test]
+    fn test_sign() {
+        let account = XrpAccount::from_mnemonic(MNEMONIC, "").unwrap();
+        let digest = sha512_half(b"transaction");
+        let signature = account.sign_ecdsa_compact(&digest).unwrap();
+
+        let message = secp256k1::Message::from_digest(digest);
+        let signature = secp256k1::ecdsa::Signature::from_compact(&signature).unwrap();
+        let public_key = secp256k1::PublicKey::from_slice(&account.public_key).unwrap();
+        assert!(ecdsa::context().verify_ecdsa(&message, &signature, &public_key).is_ok());
+    }
+}
@@ -0,0 +1,302 @@
+//! # crypto-mobile
+//!
+//! crypto-lib의 모바일(UniFFI) 바인딩
+//!
+//! Kotlin/Swift 래퍼를 생성하기 위한 UniFFI 인터페이스 (proc-macro 방식)
+//!
+//! ## 바인딩 생성
+//! ```text
+//! cargo build --release -p crypto-mobile
+//! uniffi-bindgen generate --library target/release/libcrypto_mobile.so --language kotlin --out-dir out/
+//! uniffi-bindgen generate --library target/release/libcrypto_mobile.dylib --language swift --out-dir out/
+//! ```
+//!
+//! ## 핸들 기반 접근
+//! 개인키와 시드는 Rust 객체 안에만 있고, 모바일 쪽은 객체 핸들(`Arc`)만 보관
+//!
+//! | 객체 | 보관하는 비밀값 | 밖으로 나가는 값 |
+//! |------|----------------|------------------|
+//! | `MobileWallet` | 니모닉, 패스프레이즈, 시드 | 주소, 경로, 공개키 |
+//! | `MobileAccount` | 개인키 | 주소, 경로, 공개키, 서명 |
+//!
+//! 개인키/시드를 반환하는 메서드는 없음
+//! 예외는 백업용 `generate_mnemonic()` 하나 (사용자가 니모닉을 적어야 하므로)
+//!
+//! ```text
+//! // Kotlin
+//! val wallet = MobileWallet(mnemonic, "")
+//! val account = wallet.derive("evm", 0u, 0u)
+//! val signature = account.sign("hello".toByteArray())
+//! ```
+
+use std::sync::Arc;
+
+use zeroize::Zeroizing;
+
+use crypto_lib::bip32::HARDENED_OFFSET;
+use crypto_lib::bip39::{self, mnemonic_to_seed, MnemonicType};
+use crypto_lib::chain::SeedSource;
+use crypto_lib::signer::{signer_for, Signature, Signer};
+use crypto_lib::slip10::KeyCache;
+use crypto_lib::validate;
+use crypto_lib::wallet::{Chain, DEFAULT_CHAINS};
+use crypto_lib::error::Error;
+
+uniffi::setup_scaffolding!();
+
+/// 모바일 쪽으로 던지는 에러 (메시지는 `Error`의 `Display`)
+#[derive(Debug, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum MobileError {
+    /// 니모닉 검증 실패
+    InvalidMnemonic(String),
+    /// 알 수 없거나 지원하지 않는 체인
+    InvalidChain(String),
+    /// 주소 형식 오류
+    InvalidAddress(String),
+    /// 키 도출/서명 실패, 잘못된 입력
+    Failed(String),
+}
+
+impl std::fmt::Display for MobileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MobileError::InvalidMnemonic(message)
+            | MobileError::InvalidChain(message)
+            | MobileError::InvalidAddress(message)
+            | MobileError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for MobileError {}
+
+impl From<Error> for MobileError {
+    fn from(e: Error) -> Self {
+        let message = e.to_string();
+        match e {
+            Error::InvalidMnemonic(_) => MobileError::InvalidMnemonic(message),
+            Error::Chain(_) => MobileError::InvalidChain(message),
+            Error::InvalidAddress(_) => MobileError::InvalidAddress(message),
+            _ => MobileError::Failed(message),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, MobileError>;
+
+/// 계정 정보 (개인키 없음)
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct MobileAccountInfo {
+    /// 체인 id
+    pub chain: String,
+    /// 도출 경로
+    pub path: String,
+    /// 주소
+    pub address: String,
+    /// 공개키 hex
+    pub public_key: String,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 지갑 핸들
+// ═══════════════════════════════════════════════════════════════
+
+/// 니모닉에서 만든 지갑 (시드는 생성 시 한 번만 계산, 마스터/부모 키는 캐시)
+#[derive(uniffi::Object)]
+pub struct MobileWallet {
+    mnemonic: Zeroizing<String>,
+    passphrase: Zeroizing<String>,
+    keys: KeyCache,
+}
+
+#[uniffi::export]
+impl MobileWallet {
+    /// 니모닉 + 패스프레이즈로 지갑 생성 (니모닉 검증)
+    #[uniffi::constructor]
+    pub fn new(mnemonic: String, passphrase: String) -> Result<Arc<Self>> {
+        let mnemonic = Zeroizing::new(mnemonic);
+        let passphrase = Zeroizing::new(passphrase);
+        bip39::validate_mnemonic(&mnemonic).map_err(Error::from)?;
+
+        let keys = KeyCache::new(mnemonic_to_seed(&mnemonic, &passphrase));
+        Ok(Arc::new(MobileWallet { mnemonic, passphrase, keys }))
+    }
+
+    /// 체인의 account/index 계정 핸들
+    pub fn derive(&self, chain: String, account: u32, index: u32) -> Result<Arc<MobileAccount>> {
+        if account >= HARDENED_OFFSET || index >= HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("account/index는 2^31 미만이어야 합니다: {}/{}", account, index)).into());
+        }
+
+        let chain = parse_chain(&chain)?;
+        let spec = chain.spec()?;
+        let derived = spec.derive_account(&self.source(), &spec.path(account, index))?;
+        let signer = signer_for(chain, *derived.private_key)?;
+
+        Ok(Arc::new(MobileAccount {
+            chain: chain.id().to_string(),
+            path: derived.path.clone(),
+            address: derived.address.clone(),
+            signer,
+        }))
+    }
+
+    /// 여러 체인의 기본 계정 정보 (`chains`가 비어 있으면 기본 체인 전체)
+    pub fn accounts(&self, chains: Vec<String>) -> Result<Vec<MobileAccountInfo>> {
+        let chains = if chains.is_empty() {
+            DEFAULT_CHAINS.to_vec()
+        } else {
+            chains.iter().map(|id| parse_chain(id)).collect::<Result<Vec<_>>>()?
+        };
+
+        chains
+            .into_iter()
+            .map(|chain| {
+                let spec = chain.spec()?;
+                let derived = spec.derive_account(&self.source(), &spec.default_path())?;
+                Ok(MobileAccountInfo {
+                    chain: chain.id().to_string(),
+                    path: derived.path.clone(),
+                    address: derived.address.clone(),
+                    public_key: hex::encode(&derived.public_key),
+                })
+            })
+            .collect()
+    }
+}
+
+impl MobileWallet {
+    fn source(&self) -> SeedSource<'_> {
+        SeedSource::with_keys(&self.mnemonic, &self.passphrase, &self.keys)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 계정 핸들
+// ═══════════════════════════════════════════════════════════════
+
+/// 서명 가능한 계정 (개인키는 `signer` 안에만 있음)
+#[derive(uniffi::Object)]
+pub struct MobileAccount {
+    chain: String,
+    path: String,
+    address: String,
+    signer: Box<dyn Signer + Send + Sync>,
+}
+
+#[uniffi::export]
+impl MobileAccount {
+    /// 체인 id
+    pub fn chain(&self) -> String {
+        self.chain.clone()
+    }
+
+    /// 도출 경로
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// 주소
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// 공개키 (체인의 기본 직렬화)
+    pub fn public_key(&self) -> Vec<u8> {
+        self.signer.public_key_bytes()
+    }
+
+    /// 메시지 서명 (64바이트, 체인 기본 해시 적용: `signer` 모듈 참고)
+    pub fn sign(&self, message: Vec<u8>) -> Result<Vec<u8>> {
+        Ok(self.signer.sign(&message)?.as_bytes().to_vec())
+    }
+
+    /// 이 계정의 공개키로 서명 검증
+    pub fn verify(&self, message: Vec<u8>, signature: Vec<u8>) -> bool {
+        let Ok(bytes) = <[u8; 64]>::try_from(signature.as_slice()) else {
+            return false;
+        };
+        self.signer.verify(&message, &Signature::from_bytes(self.signer.scheme(), bytes)).is_ok()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// 백업용 새 영어 니모닉 (12 또는 24단어)
+#[uniffi::export]
+pub fn generate_mnemonic(words: u32) -> Result<String> {
+    let mnemonic_type = match words {
+        12 => MnemonicType::Words12,
+        24 => MnemonicType::Words24,
+        _ => return Err(Error::InvalidInput(format!("단어 수는 12 또는 24여야 합니다: {}", words)).into()),
+    };
+    Ok(bip39::generate_mnemonic(mnemonic_type).0)
+}
+
+/// 주소 형식 검증
+#[uniffi::export]
+pub fn validate_address(chain: String, address: String) -> Result<()> {
+    validate::validate_address(parse_chain(&chain)?, &address).map_err(Error::from)?;
+    Ok(())
+}
+
+fn parse_chain(id: &str) -> Result<Chain> {
+    Chain::from_id(id).ok_or_else(|| MobileError::InvalidChain(format!("알 수 없는 체인입니다: {}", id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_wallet_handles() {
+        let wallet = MobileWallet::new(MNEMONIC.to_string(), String::new()).unwrap();
+
+        let evm = wallet.derive("evm".to_string(), 0, 0).unwrap();
+        assert_eq!(evm.address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(evm.path(), "m/44'/60'/0'/0/0");
+
+        for info in wallet.accounts(Vec::new()).unwrap() {
+            println!("{:<10} {:<22} {}", info.chain, info.path, info.address);
+        }
+        assert_eq!(wallet.accounts(vec!["osmosis".to_string()]).unwrap()[0].chain, "osmosis");
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let wallet = MobileWallet::new(MNEMONIC.to_string(), String::new()).unwrap();
+
+        for info in wallet.accounts(Vec::new()).unwrap() {
+            let account = wallet.derive(info.chain.clone(), 0, 0).unwrap();
+            assert_eq!(hex::encode(account.public_key()), info.public_key, "{}", info.chain);
+        }
+
+        for chain in ["bitcoin", "evm", "solana", "sui", "osmosis", "substrate"] {
+            let account = wallet.derive(chain.to_string(), 0, 1).unwrap();
+            let signature = account.sign(b"hello".to_vec()).unwrap();
+            assert_eq!(signature.len(), 64);
+            assert!(account.verify(b"hello".to_vec(), signature.clone()), "{}", chain);
+            assert!(!account.verify(b"other".to_vec(), signature), "{}", chain);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(MobileWallet::new("abandon".to_string(), String::new()), Err(MobileError::InvalidMnemonic(_))));
+
+        let wallet = MobileWallet::new(MNEMONIC.to_string(), String::new()).unwrap();
+        assert!(matches!(wallet.derive("dogecoin".to_string(), 0, 0), Err(MobileError::InvalidChain(_))));
+        assert!(matches!(wallet.derive("evm".to_string(), HARDENED_OFFSET, 0), Err(MobileError::Failed(_))));
+
+        assert!(validate_address("evm".to_string(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94".to_string()).is_ok());
+        let err = validate_address("evm".to_string(), "0x1234".to_string()).unwrap_err();
+        println!("{}", err);
+        assert!(matches!(err, MobileError::InvalidAddress(_)));
+        assert_eq!(generate_mnemonic(12).unwrap().split_whitespace().count(), 12);
+    }
+}
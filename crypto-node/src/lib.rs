@@ -0,0 +1,315 @@
+//! # crypto-node
+//!
+//! crypto-lib의 Node.js(N-API) 바인딩
+//!
+//! TypeScript 서비스가 Rust 서비스와 같은 도출 코드를 쓰도록 하기 위한 napi-rs 래퍼
+//! (JS 쪽에서 경로/주소 인코딩을 다시 구현하지 않음 → 스택 간 주소 불일치 방지)
+//!
+//! ## 빌드
+//! ```text
+//! cd crypto-node
+//! npx napi build --platform --release   # crypto-node.<platform>.node + index.d.ts
+//! ```
+//!
+//! ## TypeScript에서 사용
+//! ```text
+//! import { generateMnemonic, NodeWallet } from "crypto-node";
+//!
+//! const wallet = new NodeWallet(generateMnemonic(24), "");
+//! for (const info of wallet.accounts(["bitcoin", "evm", "osmosis"])) {
+//!     console.log(info.chain, info.path, info.address);
+//! }
+//!
+//! const account = wallet.derive("evm", 0, 0);
+//! const signature = account.sign(Buffer.from("hello"));   // 64바이트 Buffer
+//! account.verify(Buffer.from("hello"), signature);        // true
+//! ```
+//!
+//! ## 핸들 기반 접근
+//! 시드와 개인키는 Rust 객체 안에만 있고, JS 쪽은 클래스 인스턴스만 보관
+//! (모바일 바인딩 `crypto-mobile`과 같은 구조)
+//!
+//! | 클래스 | 보관하는 비밀값 | 밖으로 나가는 값 |
+//! |--------|----------------|------------------|
+//! | `NodeWallet` | 니모닉, 패스프레이즈, 시드 | 주소, 경로, 공개키 |
+//! | `NodeAccount` | 개인키 | 주소, 경로, 공개키, 서명 |
+//!
+//! 서명은 네트워크 없이 로컬에서만 수행 (오프라인 서명)
+//!
+//! ## 타입 변환
+//! - 체인은 문자열 id (`Chain::id()`: "bitcoin", "evm", "cosmoshub", "osmosis", ...)
+//! - 바이트는 `Buffer`, 공개키 hex는 `string`
+//! - 에러는 `Error` 메시지를 담은 JS `Error`로 throw
+
+use napi::bindgen_prelude::Buffer;
+use napi_derive::napi;
+use zeroize::Zeroizing;
+
+use crypto_lib::bip32::HARDENED_OFFSET;
+use crypto_lib::bip39::{self, mnemonic_to_seed, MnemonicType};
+use crypto_lib::chain::SeedSource;
+use crypto_lib::signer::{signer_for, Signature, Signer};
+use crypto_lib::slip10::KeyCache;
+use crypto_lib::validate;
+use crypto_lib::wallet::{Chain, DEFAULT_CHAINS};
+use crypto_lib::Error;
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// JS로 넘기는 계정 정보 (개인키 없음)
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInfo {
+    /// 체인 id
+    pub chain: String,
+    /// 도출 경로
+    pub path: String,
+    /// 주소
+    pub address: String,
+    /// 공개키 hex
+    pub public_key: String,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 지갑 핸들
+// ═══════════════════════════════════════════════════════════════
+
+/// 니모닉에서 만든 지갑 (시드는 생성 시 한 번만 계산, 마스터/부모 키는 캐시)
+#[napi]
+pub struct NodeWallet {
+    mnemonic: Zeroizing<String>,
+    passphrase: Zeroizing<String>,
+    keys: KeyCache,
+}
+
+#[napi]
+impl NodeWallet {
+    /// 니모닉 + 패스프레이즈로 지갑 생성 (니모닉 검증)
+    #[napi(constructor)]
+    pub fn new(mnemonic: String, passphrase: Option<String>) -> napi::Result<Self> {
+        Self::create(mnemonic, passphrase.unwrap_or_default()).map_err(js_error)
+    }
+
+    /// 체인의 account/index 계정 핸들
+    #[napi]
+    pub fn derive(&self, chain: String, account: u32, index: u32) -> napi::Result<NodeAccount> {
+        self.account(&chain, account, index).map_err(js_error)
+    }
+
+    /// 여러 체인의 기본 계정 정보 (`chains`가 없거나 비어 있으면 기본 체인 전체)
+    #[napi]
+    pub fn accounts(&self, chains: Option<Vec<String>>) -> napi::Result<Vec<AccountInfo>> {
+        self.infos(&chains.unwrap_or_default()).map_err(js_error)
+    }
+}
+
+impl NodeWallet {
+    fn create(mnemonic: String, passphrase: String) -> Result<Self> {
+        let mnemonic = Zeroizing::new(mnemonic);
+        let passphrase = Zeroizing::new(passphrase);
+        bip39::validate_mnemonic(&mnemonic)?;
+
+        let keys = KeyCache::new(mnemonic_to_seed(&mnemonic, &passphrase));
+        Ok(NodeWallet { mnemonic, passphrase, keys })
+    }
+
+    fn account(&self, chain: &str, account: u32, index: u32) -> Result<NodeAccount> {
+        if account >= HARDENED_OFFSET || index >= HARDENED_OFFSET {
+            return Err(Error::InvalidInput(format!("account/index는 2^31 미만이어야 합니다: {}/{}", account, index)));
+        }
+
+        let chain = parse_chain(chain)?;
+        let spec = chain.spec()?;
+        let derived = spec.derive_account(&self.source(), &spec.path(account, index))?;
+        let signer = signer_for(chain, *derived.private_key)?;
+
+        Ok(NodeAccount {
+            chain: chain.id().to_string(),
+            path: derived.path.clone(),
+            address: derived.address.clone(),
+            signer,
+        })
+    }
+
+    fn infos(&self, chains: &[String]) -> Result<Vec<AccountInfo>> {
+        let chains = if chains.is_empty() {
+            DEFAULT_CHAINS.to_vec()
+        } else {
+            chains.iter().map(|id| parse_chain(id)).collect::<Result<Vec<_>>>()?
+        };
+
+        chains
+            .into_iter()
+            .map(|chain| {
+                let spec = chain.spec()?;
+                let derived = spec.derive_account(&self.source(), &spec.default_path())?;
+                Ok(AccountInfo {
+                    chain: chain.id().to_string(),
+                    path: derived.path.clone(),
+                    address: derived.address.clone(),
+                    public_key: hex::encode(&derived.public_key),
+                })
+            })
+            .collect()
+    }
+
+    fn source(&self) -> SeedSource<'_> {
+        SeedSource::with_keys(&self.mnemonic, &self.passphrase, &self.keys)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 계정 핸들
+// ═══════════════════════════════════════════════════════════════
+
+/// 서명 가능한 계정 (개인키는 `signer` 안에만 있음)
+#[napi]
+pub struct NodeAccount {
+    chain: String,
+    path: String,
+    address: String,
+    signer: Box<dyn Signer + Send + Sync>,
+}
+
+#[napi]
+impl NodeAccount {
+    /// 체인 id
+    #[napi(getter)]
+    pub fn chain(&self) -> String {
+        self.chain.clone()
+    }
+
+    /// 도출 경로
+    #[napi(getter)]
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// 주소
+    #[napi(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// 공개키 (체인의 기본 직렬화)
+    #[napi(getter)]
+    pub fn public_key(&self) -> Buffer {
+        self.signer.public_key_bytes().into()
+    }
+
+    /// 메시지 서명 (64바이트, 체인 기본 해시 적용: `crypto_lib::signer` 참고)
+    #[napi]
+    pub fn sign(&self, message: Buffer) -> napi::Result<Buffer> {
+        self.sign_bytes(&message).map(Buffer::from).map_err(js_error)
+    }
+
+    /// 이 계정의 공개키로 서명 검증
+    #[napi]
+    pub fn verify(&self, message: Buffer, signature: Buffer) -> bool {
+        self.verify_bytes(&message, &signature)
+    }
+}
+
+impl NodeAccount {
+    fn sign_bytes(&self, message: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.signer.sign(message)?.as_bytes().to_vec())
+    }
+
+    fn verify_bytes(&self, message: &[u8], signature: &[u8]) -> bool {
+        let Ok(bytes) = <[u8; 64]>::try_from(signature) else {
+            return false;
+        };
+        self.signer.verify(message, &Signature::from_bytes(self.signer.scheme(), bytes)).is_ok()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// 백업용 새 영어 니모닉 (12 또는 24단어)
+#[napi]
+pub fn generate_mnemonic(words: u32) -> napi::Result<String> {
+    mnemonic_for(words).map_err(js_error)
+}
+
+/// 니모닉 검증 (단어 수, 단어 목록, 체크섬), 실패하면 throw
+#[napi]
+pub fn validate_mnemonic(mnemonic: String) -> napi::Result<()> {
+    bip39::validate_mnemonic(&mnemonic).map_err(|e| js_error(e.into()))
+}
+
+/// 주소 형식 검증, 실패하면 throw
+#[napi]
+pub fn validate_address(chain: String, address: String) -> napi::Result<()> {
+    let chain = parse_chain(&chain).map_err(js_error)?;
+    validate::validate_address(chain, &address).map_err(|e| js_error(e.into()))
+}
+
+/// 기본 체인 id 목록
+#[napi]
+pub fn supported_chains() -> Vec<String> {
+    DEFAULT_CHAINS.iter().map(|chain| chain.id().to_string()).collect()
+}
+
+fn mnemonic_for(words: u32) -> Result<String> {
+    let mnemonic_type = match words {
+        12 => MnemonicType::Words12,
+        24 => MnemonicType::Words24,
+        _ => return Err(Error::InvalidInput(format!("단어 수는 12 또는 24여야 합니다: {}", words))),
+    };
+    Ok(bip39::generate_mnemonic(mnemonic_type).0)
+}
+
+/// crypto-lib 에러 → JS `Error` (메시지는 `Error`의 `Display`)
+fn js_error(e: Error) -> napi::Error {
+    napi::Error::from_reason(e.to_string())
+}
+
+fn parse_chain(id: &str) -> Result<Chain> {
+    Chain::from_id(id).ok_or_else(|| Error::Chain(format!("알 수 없는 체인입니다: {}", id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_wallet_handles() {
+        let wallet = NodeWallet::create(MNEMONIC.to_string(), String::new()).unwrap();
+
+        let evm = wallet.account("evm", 0, 0).unwrap();
+        assert_eq!(evm.address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(evm.path(), "m/44'/60'/0'/0/0");
+
+        for info in wallet.infos(&[]).unwrap() {
+            println!("{:<10} {:<22} {}", info.chain, info.path, info.address);
+        }
+        assert_eq!(wallet.infos(&["osmosis".to_string()]).unwrap()[0].chain, "osmosis");
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let wallet = NodeWallet::create(MNEMONIC.to_string(), String::new()).unwrap();
+
+        for chain in ["bitcoin", "evm", "solana", "sui", "osmosis", "substrate"] {
+            let account = wallet.account(chain, 0, 1).unwrap();
+            let signature = account.sign_bytes(b"hello").unwrap();
+            assert_eq!(signature.len(), 64);
+            assert!(account.verify_bytes(b"hello", &signature), "{}", chain);
+            assert!(!account.verify_bytes(b"other", &signature), "{}", chain);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(NodeWallet::create("abandon".to_string(), String::new()), Err(Error::InvalidMnemonic(_))));
+
+        let wallet = NodeWallet::create(MNEMONIC.to_string(), String::new()).unwrap();
+        assert!(matches!(wallet.account("dogecoin", 0, 0), Err(Error::Chain(_))));
+        assert!(matches!(wallet.account("evm", HARDENED_OFFSET, 0), Err(Error::InvalidInput(_))));
+        assert!(matches!(mnemonic_for(15), Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,197 @@
+//! # crypto-wasm
+//!
+//! crypto-lib의 브라우저용 WASM 바인딩
+//!
+//! 브라우저에서 니모닉 생성, 멀티체인 도출, 주소 검증을 쓰기 위한 wasm-bindgen 래퍼
+//!
+//! ## 빌드
+//! ```text
+//! wasm-pack build crypto-wasm --target web
+//! ```
+//! secp256k1 C 코드를 wasm32로 컴파일하므로 clang이 필요
+//!
+//! ## JS에서 사용
+//! ```text
+//! import init, { generateMnemonic, deriveAccounts, validateAddress } from "./pkg/crypto_wasm.js";
+//!
+//! await init();
+//! const mnemonic = generateMnemonic(12);
+//! for (const account of deriveAccounts(mnemonic, "", ["bitcoin", "evm", "osmosis"])) {
+//!     console.log(account.chain, account.path, account.address, account.publicKey);
+//! }
+//! validateAddress("evm", "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"); // 실패하면 Error throw
+//! ```
+//!
+//! ## 타입 변환
+//! - 체인은 문자열 id (`Chain::id()`: "bitcoin", "evm", "cosmoshub", "osmosis", ...)
+//! - 계정은 getter가 있는 `WasmAccount` 객체 (camelCase, 개인키 없음)
+//! - 에러는 `Error` 메시지를 담은 JS `Error`로 throw
+//!
+//! 각 함수는 `crypto_lib::Result`를 반환하는 내부 함수 + 얇은 `#[wasm_bindgen]` 래퍼로 나뉨
+//! (네이티브 테스트에서는 `JsError`를 만들 수 없으므로 내부 함수만 검사)
+
+use wasm_bindgen::prelude::*;
+
+use crypto_lib::bip39::{generate_mnemonic as generate, validate_mnemonic as validate, MnemonicType};
+use crypto_lib::validate::validate_address as validate_chain_address;
+use crypto_lib::wallet::{AccountInfo, Chain, MultiChainWallet, Wallet, DEFAULT_CHAINS};
+use crypto_lib::error::{Error, Result};
+
+/// JS로 넘기는 계정 정보 (개인키 없음)
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmAccount {
+    chain: String,
+    path: String,
+    address: String,
+    public_key: String,
+}
+
+#[wasm_bindgen]
+impl WasmAccount {
+    /// 체인 id
+    #[wasm_bindgen(getter)]
+    pub fn chain(&self) -> String {
+        self.chain.clone()
+    }
+
+    /// 도출 경로
+    #[wasm_bindgen(getter)]
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// 주소
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// 공개키 hex
+    #[wasm_bindgen(getter, js_name = publicKey)]
+    pub fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+}
+
+impl From<AccountInfo> for WasmAccount {
+    fn from(info: AccountInfo) -> Self {
+        WasmAccount { chain: info.chain.id().to_string(), path: info.path, address: info.address, public_key: info.public_key }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// JS 함수
+// ═══════════════════════════════════════════════════════════════
+
+/// 새 영어 니모닉 (12 또는 24단어)
+#[wasm_bindgen(js_name = generateMnemonic)]
+pub fn generate_mnemonic(words: u32) -> std::result::Result<String, JsError> {
+    Ok(mnemonic_for(words)?)
+}
+
+/// 니모닉 검증 (단어 수, 단어 목록, 체크섬), 실패하면 throw
+#[wasm_bindgen(js_name = validateMnemonic)]
+pub fn validate_mnemonic(mnemonic: &str) -> std::result::Result<(), JsError> {
+    Ok(validate(mnemonic).map_err(Error::from)?)
+}
+
+/// 여러 체인의 기본 계정 도출 (`chains`가 비어 있으면 기본 체인 전체)
+#[wasm_bindgen(js_name = deriveAccounts)]
+pub fn derive_accounts(mnemonic: &str, passphrase: &str, chains: Vec<String>) -> std::result::Result<Vec<WasmAccount>, JsError> {
+    Ok(accounts_for(mnemonic, passphrase, &chains)?)
+}
+
+/// 한 체인의 account/index 계정 도출
+#[wasm_bindgen(js_name = deriveAccount)]
+pub fn derive_account(mnemonic: &str, passphrase: &str, chain: &str, account: u32, index: u32) -> std::result::Result<WasmAccount, JsError> {
+    Ok(account_for(mnemonic, passphrase, chain, account, index)?)
+}
+
+/// 주소 형식 검증, 실패하면 throw
+#[wasm_bindgen(js_name = validateAddress)]
+pub fn validate_address(chain: &str, address: &str) -> std::result::Result<(), JsError> {
+    Ok(validate_chain_address(parse_chain(chain)?, address).map_err(Error::from)?)
+}
+
+/// 기본 체인 id 목록
+#[wasm_bindgen(js_name = supportedChains)]
+pub fn supported_chains() -> Vec<String> {
+    DEFAULT_CHAINS.iter().map(|chain| chain.id().to_string()).collect()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// 내부 함수
+// ═══════════════════════════════════════════════════════════════
+
+fn mnemonic_for(words: u32) -> Result<String> {
+    let mnemonic_type = match words {
+        12 => MnemonicType::Words12,
+        24 => MnemonicType::Words24,
+        _ => return Err(Error::InvalidInput(format!("단어 수는 12 또는 24여야 합니다: {}", words))),
+    };
+    Ok(generate(mnemonic_type).0)
+}
+
+fn accounts_for(mnemonic: &str, passphrase: &str, chains: &[String]) -> Result<Vec<WasmAccount>> {
+    let chains = if chains.is_empty() {
+        DEFAULT_CHAINS.to_vec()
+    } else {
+        chains.iter().map(|id| parse_chain(id)).collect::<Result<Vec<_>>>()?
+    };
+
+    let wallet = MultiChainWallet::from_mnemonic_with_chains(mnemonic, passphrase, &chains)?;
+    Ok(wallet.accounts.into_iter().map(WasmAccount::from).collect())
+}
+
+fn account_for(mnemonic: &str, passphrase: &str, chain: &str, account: u32, index: u32) -> Result<WasmAccount> {
+    let chain = parse_chain(chain)?;
+    let wallet = Wallet::builder().mnemonic(mnemonic).passphrase(passphrase).chain(chain).account(account).index(index).build()?;
+
+    Ok(WasmAccount {
+        chain: chain.id().to_string(),
+        path: wallet.key.path.clone(),
+        address: wallet.key.address.clone(),
+        public_key: hex::encode(&wallet.key.public_key),
+    })
+}
+
+fn parse_chain(id: &str) -> Result<Chain> {
+    Chain::from_id(id).ok_or_else(|| Error::Chain(format!("알 수 없는 체인입니다: {}", id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_mnemonic_for() {
+        let mnemonic = mnemonic_for(24).unwrap();
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+        assert!(validate(&mnemonic).is_ok());
+        assert!(matches!(mnemonic_for(15), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_accounts_for() {
+        let accounts = accounts_for(MNEMONIC, "", &["evm".to_string(), "osmosis".to_string()]).unwrap();
+        for account in &accounts {
+            println!("{:<8} {:<20} {}", account.chain(), account.path(), account.address());
+        }
+        assert_eq!(accounts[0].address(), "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert!(accounts[1].address().starts_with("osmo1"));
+
+        assert_eq!(accounts_for(MNEMONIC, "", &[]).unwrap().len(), DEFAULT_CHAINS.len());
+        assert!(matches!(accounts_for(MNEMONIC, "", &["dogecoin".to_string()]), Err(Error::Chain(_))));
+    }
+
+    #[test]
+    fn test_account_for() {
+        let account = account_for(MNEMONIC, "", "evm", 0, 1).unwrap();
+        assert_eq!(account.path(), "m/44'/60'/0'/0/1");
+        assert!(validate_chain_address(Chain::Evm, &account.address()).is_ok());
+        assert!(account_for("abandon", "", "evm", 0, 0).is_err());
+    }
+}